@@ -0,0 +1,56 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+#[global_allocator]
+static ALLOCATOR: BenchmarkAllocator = BenchmarkAllocator;
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn adder_benchmark_test() {
+    let mut world = world();
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:owner", Account::new().nonce(1))
+                .new_address("address:owner", 1, "sc:adder"),
+        )
+        .sc_deploy(
+            ScDeployStep::new()
+                .from("address:owner")
+                .code(adder_code)
+                .argument("0"),
+        );
+
+    let add_report = world.benchmark_sc_call("add", 100, |index| {
+        ScCallStep::new()
+            .from("address:owner")
+            .to("sc:adder")
+            .function("add")
+            .argument(index.to_string().as_str())
+            .expect(TxExpect::ok().no_result())
+    });
+    println!("{add_report}");
+    assert_eq!(add_report.num_executions, 100);
+    assert!(add_report.executions_per_second() > 0.0);
+    assert!(add_report.allocations_per_execution().unwrap() > 0.0);
+
+    // 0 + 1 + ... + 99
+    let sum_report = world.benchmark_sc_query("getSum", 10, |_| {
+        ScQueryStep::new()
+            .to("sc:adder")
+            .function("getSum")
+            .expect(TxExpect::ok().result("4950"))
+    });
+    println!("{sum_report}");
+    assert_eq!(sum_report.num_executions, 10);
+}
@@ -0,0 +1,116 @@
+use adder::*;
+use multiversx_sc::{storage::mappers::SingleValue, types::BigUint};
+use multiversx_sc_scenario::{api::StaticApi, num_bigint, scenario_model::*, *};
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn adder_blackbox_chained() {
+    let mut world = world();
+    let owner_address = "address:owner";
+    let mut adder_contract = ContractInfo::<adder::Proxy<StaticApi>>::new("sc:adder");
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(owner_address, Account::new().nonce(1).balance("100"))
+            .new_address(owner_address, 1, "sc:adder"),
+    );
+
+    let (new_address, ()) = world
+        .tx()
+        .from(owner_address)
+        .code(&adder_code)
+        .deploy(adder_contract.init(5u32))
+        .returns();
+    assert_eq!(new_address, adder_contract.to_address());
+
+    world
+        .tx()
+        .from(owner_address)
+        .call(adder_contract.add(3u32))
+        .run();
+
+    let sum: SingleValue<BigUint<StaticApi>> = world.tx().query(adder_contract.sum()).returns();
+    assert_eq!(sum.into(), BigUint::<StaticApi>::from(8u32));
+
+    world
+        .tx()
+        .from(owner_address)
+        .to(&adder_contract)
+        .code(&adder_code)
+        .upgrade(adder_contract.init(20u32))
+        .run();
+
+    world
+        .tx()
+        .query(adder_contract.sum())
+        .expect(TxExpect::ok().result("20"))
+        .run();
+
+    world
+        .tx()
+        .from(owner_address)
+        .egld_value(1u32)
+        .call(adder_contract.add(1u32))
+        .expect(TxExpect::err(
+            10,
+            "str:function does not accept EGLD payment",
+        ))
+        .run();
+}
+
+#[test]
+fn adder_blackbox_chained_result_handlers() {
+    let mut world = world();
+    let owner_address = "address:owner";
+    let mut adder_contract = ContractInfo::<adder::Proxy<StaticApi>>::new("sc:adder");
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(owner_address, Account::new().nonce(1))
+            .new_address(owner_address, 1, "sc:adder"),
+    );
+
+    let (new_address, ()) = world
+        .tx()
+        .from(owner_address)
+        .code(&adder_code)
+        .deploy(adder_contract.init(5u32))
+        .run_with((ReturnsNewAddress, ReturnsResult));
+    assert_eq!(new_address, adder_contract.to_address());
+
+    let (raw_result, logs) = world
+        .tx()
+        .from(owner_address)
+        .call(adder_contract.add(3u32))
+        .run_with((ReturnsRawResult, ReturnsLogs));
+    assert!(raw_result.is_empty());
+    assert!(logs.is_empty());
+
+    let sum = world
+        .tx()
+        .query(adder_contract.sum())
+        .run_with(ReturnsResultAs::<SingleValue<num_bigint::BigUint>>::new());
+    let sum: num_bigint::BigUint = sum.into();
+    assert_eq!(sum, num_bigint::BigUint::from(8u32));
+
+    let status = world
+        .tx()
+        .from(owner_address)
+        .to(&adder_contract)
+        .code(&adder_code)
+        .upgrade(adder_contract.init(20u32))
+        .expect(TxExpect::ok())
+        .run_with(ReturnsStatus);
+    assert!(status.is_success());
+}
@@ -1,4 +1,4 @@
-use multiversx_sc_scenario::{scenario_model::*, *};
+use multiversx_sc_scenario::{multiversx_chain_vm::types::VMAddress, scenario_model::*, *};
 
 const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
 
@@ -51,3 +51,28 @@ fn adder_blackbox_raw() {
                 ),
         );
 }
+
+#[test]
+fn adder_blackbox_new_address_generator() {
+    let mut world = world();
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    world
+        .new_address_generator(|_creator: &VMAddress, _nonce: u64| {
+            AddressKey::from("sc:adder").to_vm_address()
+        })
+        .set_state_step(SetStateStep::new().put_account("address:owner", Account::new().nonce(1)))
+        .sc_deploy(
+            ScDeployStep::new()
+                .from("address:owner")
+                .code(adder_code)
+                .argument("5")
+                .expect(TxExpect::ok().no_result()),
+        )
+        .sc_query(
+            ScQueryStep::new()
+                .to("sc:adder")
+                .function("getSum")
+                .expect(TxExpect::ok().result("5")),
+        );
+}
@@ -0,0 +1,74 @@
+use multiversx_sc_scenario::{multiversx_chain_vm::world_mock::GasSchedule, scenario_model::*, *};
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+const GAS_LIMIT: u64 = 5_000_000;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    blockchain.gas_schedule(GasSchedule::default());
+    blockchain
+}
+
+fn deploy(world: &mut ScenarioWorld) {
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:owner", Account::new().nonce(1))
+                .new_address("address:owner", 1, "sc:adder"),
+        )
+        .sc_deploy(
+            ScDeployStep::new()
+                .from("address:owner")
+                .code(adder_code)
+                .argument("5")
+                .gas_limit(GAS_LIMIT),
+        );
+}
+
+fn add_step(value: &str, gas_limit: u64) -> ScCallStep {
+    ScCallStep::new()
+        .from("address:owner")
+        .to("sc:adder")
+        .function("add")
+        .argument(value)
+        .gas_limit(gas_limit)
+}
+
+#[test]
+fn adder_gas_used_test() {
+    let mut world = world();
+    deploy(&mut world);
+
+    let mut step = add_step("3", GAS_LIMIT).expect(TxExpect::ok().no_result());
+    world.sc_call(&mut step);
+    let gas_used = step.response().gas;
+
+    let gas_schedule = GasSchedule::default();
+    assert!(gas_used >= gas_schedule.storage_load + gas_schedule.storage_store);
+    assert!(gas_used < GAS_LIMIT);
+
+    // same operations, same gas
+    world.sc_call(
+        add_step("4", GAS_LIMIT).expect(TxExpect::ok().no_result().gas(GAS_LIMIT - gas_used)),
+    );
+    world.check_state_step(CheckStateStep::new().put_account(
+        "sc:adder",
+        CheckAccount::new().check_storage("str:sum", "12"),
+    ));
+}
+
+#[test]
+fn adder_out_of_gas_test() {
+    let mut world = world();
+    deploy(&mut world);
+
+    world.sc_call(add_step("3", 10_000).expect(TxExpect::err(5, "str:not enough gas")));
+    world.check_state_step(CheckStateStep::new().put_account(
+        "sc:adder",
+        CheckAccount::new().check_storage("str:sum", "5"),
+    ));
+}
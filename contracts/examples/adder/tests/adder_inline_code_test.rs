@@ -0,0 +1,67 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+/// Stands for code generated during the test, there is no file behind it.
+const ADDER_CODE: &[u8] = b"\x00asm generated adder code";
+const ADDER_CODE_HEX: &str = "0x0061736d2067656e65726174656420616464657220636f6465";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.register_contract_code(ADDER_CODE, adder::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn adder_inline_code_set_state() {
+    let mut world = world();
+
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:owner", Account::new().nonce(1))
+                .put_account(
+                    "sc:adder",
+                    Account::new().code(ADDER_CODE).owner("address:owner"),
+                ),
+        )
+        .sc_call(
+            ScCallStep::new()
+                .from("address:owner")
+                .to("sc:adder")
+                .function("add")
+                .argument("3")
+                .expect(TxExpect::ok().no_result()),
+        )
+        .check_state_step(
+            CheckStateStep::new().put_account(
+                "sc:adder",
+                CheckAccount::new()
+                    .code(ADDER_CODE_HEX)
+                    .check_storage("str:sum", "3"),
+            ),
+        );
+}
+
+#[test]
+fn adder_inline_code_hex_deploy() {
+    let mut world = world();
+
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:owner", Account::new().nonce(1))
+                .new_address("address:owner", 1, "sc:adder"),
+        )
+        .sc_deploy(
+            ScDeployStep::new()
+                .from("address:owner")
+                .code(ADDER_CODE_HEX)
+                .argument("5")
+                .expect(TxExpect::ok().no_result()),
+        )
+        .sc_query(
+            ScQueryStep::new()
+                .to("sc:adder")
+                .function("getSum")
+                .expect(TxExpect::ok().result("5")),
+        );
+}
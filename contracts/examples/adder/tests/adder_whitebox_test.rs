@@ -48,3 +48,48 @@ fn adder_whitebox() {
                 ),
         );
 }
+
+#[test]
+fn adder_whitebox_storage_access() {
+    let mut world = world();
+    let adder_whitebox = WhiteboxContract::new("sc:adder", adder::contract_obj);
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+    let adder_address = AddressValue::from("sc:adder").to_vm_address();
+
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:owner", Account::new().nonce(1))
+                .new_address("address:owner", 1, "sc:adder"),
+        )
+        .whitebox_deploy(
+            &adder_whitebox,
+            ScDeployStep::new().from("address:owner").code(adder_code),
+            |sc| {
+                sc.init(5u32.into());
+            },
+        )
+        .whitebox_query_check(
+            &adder_whitebox,
+            |sc| {
+                let _ = sc.sum().get();
+            },
+            |tx_result| {
+                tx_result.assert_no_storage_writes();
+                assert!(tx_result.storage_access.is_read(&adder_address, b"sum"));
+            },
+        )
+        .whitebox_call_check(
+            &adder_whitebox,
+            ScCallStep::new().from("address:owner"),
+            |sc| sc.add(3u32.into()),
+            |tx_result| {
+                tx_result.assert_ok();
+                tx_result.assert_storage_writes_with_prefixes(&adder_address, &[b"sum"]);
+                assert_eq!(
+                    tx_result.storage_access.written_keys(&adder_address),
+                    vec![&b"sum"[..]]
+                );
+            },
+        );
+}
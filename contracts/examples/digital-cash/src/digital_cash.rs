@@ -11,6 +11,19 @@ use deposit_info::{DepositInfo, Fee};
 pub const SECONDS_PER_ROUND: u64 = 6;
 pub use multiversx_sc::api::{ED25519_KEY_BYTE_LEN, ED25519_SIGNATURE_BYTE_LEN};
 
+multiversx_sc::error_messages! {
+    pub DigitalCashErrors {
+        ERR_FEES_NOT_COVERED = "fees not covered",
+        ERR_ZERO_AMOUNT = "amount must be greater than 0",
+        ERR_KEY_ALREADY_USED = "key already used",
+        ERR_DEPOSIT_FEE_NOT_COVERED = "cannot deposit funds without covering the fee cost first",
+        ERR_NON_EXISTENT_KEY = "non-existent key",
+        ERR_WITHDRAWAL_NOT_AVAILABLE = "withdrawal has not been available yet",
+        ERR_DEPOSIT_EXPIRED = "deposit expired",
+        ERR_FORWARD_FEE_NOT_COVERED = "cannot forward funds without the owner covering the fee cost first",
+    }
+}
+
 #[multiversx_sc::contract]
 pub trait DigitalCash {
     #[init]
@@ -23,25 +36,25 @@ pub trait DigitalCash {
     #[endpoint]
     #[payable("*")]
     fn fund(&self, address: ManagedAddress, valability: u64) {
-        require!(!self.deposit(&address).is_empty(), "fees not covered");
+        require!(!self.deposit(&address).is_empty(), ERR_FEES_NOT_COVERED);
 
         let esdt_payment = self.call_value().all_esdt_transfers().clone_value();
         let egld_payment = self.call_value().egld_value().clone_value();
 
         let num_tokens = (egld_payment != BigUint::zero()) as usize + esdt_payment.len();
 
-        require!(num_tokens > 0, "amount must be greater than 0");
+        require!(num_tokens > 0, ERR_ZERO_AMOUNT);
 
         let fee = self.fee().get();
 
         self.deposit(&address).update(|deposit| {
             require!(
                 deposit.egld_funds == BigUint::zero() && deposit.esdt_funds.is_empty(),
-                "key already used"
+                ERR_KEY_ALREADY_USED
             );
             require!(
                 fee * num_tokens as u64 <= deposit.fees.value,
-                "cannot deposit funds without covering the fee cost first"
+                ERR_DEPOSIT_FEE_NOT_COVERED
             );
 
             deposit.fees.num_token_to_transfer += num_tokens;
@@ -54,7 +67,7 @@ pub trait DigitalCash {
 
     #[endpoint]
     fn withdraw(&self, address: ManagedAddress) {
-        require!(!self.deposit(&address).is_empty(), "non-existent key");
+        require!(!self.deposit(&address).is_empty(), ERR_NON_EXISTENT_KEY);
 
         let block_round = self.blockchain().get_block_round();
 
@@ -62,7 +75,7 @@ pub trait DigitalCash {
 
         require!(
             deposit.expiration_round < block_round,
-            "withdrawal has not been available yet"
+            ERR_WITHDRAWAL_NOT_AVAILABLE
         );
 
         let egld_funds = deposit.egld_funds + deposit.fees.value;
@@ -85,7 +98,7 @@ pub trait DigitalCash {
         address: ManagedAddress,
         signature: ManagedByteArray<Self::Api, ED25519_SIGNATURE_BYTE_LEN>,
     ) {
-        require!(!self.deposit(&address).is_empty(), "non-existent key");
+        require!(!self.deposit(&address).is_empty(), ERR_NON_EXISTENT_KEY);
 
         let caller_address = self.blockchain().get_caller();
         self.require_signature(&address, &caller_address, signature);
@@ -95,7 +108,7 @@ pub trait DigitalCash {
         let fee = self.fee().get();
 
         self.deposit(&address).update(|deposit| {
-            require!(deposit.expiration_round >= block_round, "deposit expired");
+            require!(deposit.expiration_round >= block_round, ERR_DEPOSIT_EXPIRED);
             let num_tokens_transfered = &deposit.get_num_tokens();
             let fee_cost = fee * *num_tokens_transfered as u64;
 
@@ -180,7 +193,7 @@ pub trait DigitalCash {
     ) {
         require!(
             !self.deposit(&forward_address).is_empty(),
-            "cannot deposit funds without covering the fee cost first"
+            ERR_DEPOSIT_FEE_NOT_COVERED
         );
 
         let caller_address = self.blockchain().get_caller();
@@ -192,11 +205,11 @@ pub trait DigitalCash {
         self.deposit(&forward_address).update(|deposit| {
             require!(
                 deposit.egld_funds == BigUint::zero() && deposit.esdt_funds.is_empty(),
-                "key already used"
+                ERR_KEY_ALREADY_USED
             );
             require!(
                 &fee * num_tokens as u64 <= deposit.fees.value,
-                "cannot forward funds without the owner covering the fee cost first"
+                ERR_FORWARD_FEE_NOT_COVERED
             );
 
             deposit.fees.num_token_to_transfer += num_tokens;
@@ -232,11 +245,11 @@ pub trait DigitalCash {
         token: EgldOrEsdtTokenIdentifier,
         nonce: u64,
     ) -> BigUint {
-        require!(!self.deposit(&address).is_empty(), "non-existent key");
+        require!(!self.deposit(&address).is_empty(), ERR_NON_EXISTENT_KEY);
 
         let mut amount = BigUint::zero();
 
-        require!(!self.deposit(&address).is_empty(), "non-existent key");
+        require!(!self.deposit(&address).is_empty(), ERR_NON_EXISTENT_KEY);
 
         let deposit = self.deposit(&address).get();
         if token.is_egld() {
@@ -1,3 +1,4 @@
+mod multisig_interact_board;
 mod multisig_interact_cli;
 mod multisig_interact_config;
 mod multisig_interact_nfts;
@@ -18,7 +19,7 @@ use multiversx_sc_scenario::{
     standalone::retrieve_account_as_scenario_set_state, test_wallets,
 };
 use multiversx_sc_snippets::{
-    dns_address_for_name, env_logger,
+    contract_code, dns_address_for_name, env_logger,
     multiversx_sc::{
         codec::multi_types::MultiValueVec, storage::mappers::SingleValue, types::Address,
     },
@@ -41,9 +42,15 @@ async fn main() {
 
     let cli = multisig_interact_cli::InteractCli::parse();
     match &cli.command {
+        Some(multisig_interact_cli::InteractCliCommand::AddBoardMember(args)) => {
+            multisig_interact.add_board_member(&args.address).await;
+        },
         Some(multisig_interact_cli::InteractCliCommand::Board) => {
             multisig_interact.print_board().await;
         },
+        Some(multisig_interact_cli::InteractCliCommand::ChangeQuorum(args)) => {
+            multisig_interact.change_quorum(args.quorum).await;
+        },
         Some(multisig_interact_cli::InteractCliCommand::Deploy) => {
             multisig_interact.deploy().await;
         },
@@ -76,9 +83,31 @@ async fn main() {
         Some(multisig_interact_cli::InteractCliCommand::NftSpecial) => {
             multisig_interact.set_special_role().await;
         },
+        Some(multisig_interact_cli::InteractCliCommand::Pending) => {
+            multisig_interact.print_pending_actions().await;
+        },
+        Some(multisig_interact_cli::InteractCliCommand::Perform(args)) => {
+            multisig_interact
+                .perform_action(args.action_id, "30,000,000")
+                .await;
+        },
+        Some(multisig_interact_cli::InteractCliCommand::PerformBatch(args)) => {
+            multisig_interact
+                .perform_pending_actions(&args.action_ids)
+                .await;
+        },
         Some(multisig_interact_cli::InteractCliCommand::Quorum) => {
             multisig_interact.print_quorum().await;
         },
+        Some(multisig_interact_cli::InteractCliCommand::RemoveUser(args)) => {
+            multisig_interact.remove_user(&args.address).await;
+        },
+        Some(multisig_interact_cli::InteractCliCommand::Sign(args)) => {
+            multisig_interact.sign_action(args.action_id).await;
+        },
+        Some(multisig_interact_cli::InteractCliCommand::SignBatch(args)) => {
+            multisig_interact.sign_actions(&args.action_ids).await;
+        },
         Some(multisig_interact_cli::InteractCliCommand::UnwrapEgld) => {
             multisig_interact.unwrap_egld().await;
         },
@@ -109,10 +138,7 @@ impl MultisigInteract {
             .with_tracer(INTERACTOR_SCENARIO_TRACE_PATH)
             .await;
         let wallet_address = interactor.register_wallet(test_wallets::mike());
-        let multisig_code = BytesValue::interpret_from(
-            "file:../output/multisig.wasm",
-            &InterpreterContext::default(),
-        );
+        let multisig_code = contract_code("multisig");
 
         Self {
             interactor,
@@ -276,13 +302,13 @@ impl MultisigInteract {
     }
 
     async fn perform_actions(&mut self, actions: Vec<usize>, gas_expr: &str) {
+        let actions = self.sign_batch(&actions).await;
+        if actions.is_empty() {
+            return;
+        }
+
         let mut steps = Vec::new();
         for action_id in actions.iter() {
-            if !self.quorum_reached(*action_id).await && !self.sign(*action_id).await {
-                continue;
-            }
-            println!("quorum reached for action `{action_id}`");
-
             let typed_sc_call = ScCallStep::new()
                 .call(self.state.multisig().perform_action_endpoint(action_id))
                 .from(&self.wallet_address)
@@ -295,11 +321,11 @@ impl MultisigInteract {
             .multi_sc_exec(StepBuffer::from_sc_call_vec(&mut steps))
             .await;
 
-        for (i, action_id) in actions.iter().enumerate() {
-            if !steps[i].response().is_success() {
+        for (action_id, step) in actions.iter().zip(steps.iter()) {
+            if !step.response().is_success() {
                 println!(
                     "perform action `{action_id}` failed with: {}",
-                    steps[i].response().tx_error
+                    step.response().tx_error
                 );
                 continue;
             }
@@ -321,41 +347,81 @@ impl MultisigInteract {
     }
 
     async fn sign(&mut self, action_id: usize) -> bool {
-        println!("signing action `{action_id}`...");
+        !self.sign_batch(&[action_id]).await.is_empty()
+    }
+
+    /// Signs the actions that have not reached quorum with all the board wallets that have not signed them yet.
+    /// The signatures are all sent at once, then the quorum is checked for each action.
+    ///
+    /// Returns the actions that reached quorum.
+    async fn sign_batch(&mut self, action_ids: &[usize]) -> Vec<usize> {
+        let board = self.board();
+        let mut signatures = Vec::new();
         let mut steps = Vec::new();
-        for signer in self.board().iter() {
-            if self.signed(signer, action_id).await {
-                println!(
-                    "{} - already signed action `{action_id}`",
-                    bech32::encode(signer)
-                );
+        for &action_id in action_ids {
+            if self.quorum_reached(action_id).await {
+                println!("quorum already reached for action `{action_id}`");
                 continue;
             }
 
-            let typed_sc_call = ScCallStep::new()
-                .call(self.state.multisig().sign(action_id))
-                .from(signer)
-                .gas_limit("15,000,000");
-
-            steps.push(typed_sc_call);
+            for signer in board.iter() {
+                if self.signed(signer, action_id).await {
+                    println!(
+                        "{} - already signed action `{action_id}`",
+                        bech32::encode(signer)
+                    );
+                    continue;
+                }
+
+                let typed_sc_call = ScCallStep::new()
+                    .call(self.state.multisig().sign(action_id))
+                    .from(signer)
+                    .gas_limit("15,000,000");
+
+                signatures.push((signer.clone(), action_id));
+                steps.push(typed_sc_call);
+            }
         }
 
-        self.interactor
-            .multi_sc_exec(StepBuffer::from_sc_call_vec(&mut steps))
-            .await;
+        if !steps.is_empty() {
+            println!("sending {} signatures...", steps.len());
+            self.interactor
+                .multi_sc_exec(StepBuffer::from_sc_call_vec(&mut steps))
+                .await;
+        }
 
-        for step in steps.iter() {
-            if !step.response().is_success() {
+        let num_signatures = steps.len();
+        for (i, ((signer, action_id), step)) in signatures.iter().zip(steps.iter()).enumerate() {
+            let progress = format!("[{}/{num_signatures}]", i + 1);
+            if step.response().is_success() {
+                println!(
+                    "{progress} {} - signed action `{action_id}`",
+                    bech32::encode(signer)
+                );
+            } else {
                 println!(
-                    "perform sign `{action_id}` failed with: {}",
+                    "{progress} {} - sign action `{action_id}` failed with: {}",
+                    bech32::encode(signer),
                     step.response().tx_error
                 );
-                return false;
             }
         }
 
-        println!("successfully performed sign action `{action_id}`");
-        true
+        let mut quorum_reached_actions = Vec::new();
+        for &action_id in action_ids {
+            if self.quorum_reached(action_id).await {
+                quorum_reached_actions.push(action_id);
+            } else {
+                println!("quorum not reached for action `{action_id}`");
+            }
+        }
+        println!(
+            "quorum reached for {}/{} actions",
+            quorum_reached_actions.len(),
+            action_ids.len()
+        );
+
+        quorum_reached_actions
     }
 
     async fn dns_register(&mut self, name: &str) {
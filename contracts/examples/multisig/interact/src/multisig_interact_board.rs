@@ -0,0 +1,179 @@
+use multisig::action::{Action, ActionFullInfo};
+use multiversx_sc_snippets::{multiversx_sc::types::BigUint, multiversx_sc_scenario::num_bigint};
+
+use super::*;
+
+impl MultisigInteract {
+    pub async fn add_board_member(&mut self, board_member: &str) {
+        println!("proposing add board member `{board_member}`...");
+        let action_id: usize = self
+            .interactor
+            .sc_call_get_result(
+                ScCallStep::new()
+                    .call(
+                        self.state
+                            .multisig()
+                            .propose_add_board_member(bech32::decode(board_member)),
+                    )
+                    .from(&self.wallet_address)
+                    .gas_limit("10,000,000")
+                    .expect(
+                        TxExpect::ok()
+                            .additional_error_message("propose add board member failed with: "),
+                    ),
+            )
+            .await
+            .result
+            .unwrap();
+
+        println!("successfully proposed add board member action `{action_id}`");
+    }
+
+    pub async fn remove_user(&mut self, user: &str) {
+        println!("proposing remove user `{user}`...");
+        let action_id: usize = self
+            .interactor
+            .sc_call_get_result(
+                ScCallStep::new()
+                    .call(
+                        self.state
+                            .multisig()
+                            .propose_remove_user(bech32::decode(user)),
+                    )
+                    .from(&self.wallet_address)
+                    .gas_limit("10,000,000")
+                    .expect(
+                        TxExpect::ok()
+                            .additional_error_message("propose remove user failed with: "),
+                    ),
+            )
+            .await
+            .result
+            .unwrap();
+
+        println!("successfully proposed remove user action `{action_id}`");
+    }
+
+    pub async fn change_quorum(&mut self, new_quorum: usize) {
+        println!("proposing change quorum to `{new_quorum}`...");
+        let action_id: usize = self
+            .interactor
+            .sc_call_get_result(
+                ScCallStep::new()
+                    .call(self.state.multisig().propose_change_quorum(new_quorum))
+                    .from(&self.wallet_address)
+                    .gas_limit("10,000,000")
+                    .expect(
+                        TxExpect::ok()
+                            .additional_error_message("propose change quorum failed with: "),
+                    ),
+            )
+            .await
+            .result
+            .unwrap();
+
+        println!("successfully proposed change quorum action `{action_id}`");
+    }
+
+    pub async fn sign_action(&mut self, action_id: usize) {
+        if self.quorum_reached(action_id).await {
+            println!("quorum already reached for action `{action_id}`");
+            return;
+        }
+
+        self.sign(action_id).await;
+    }
+
+    /// Signs the given actions, or all the pending actions if none are given.
+    pub async fn sign_actions(&mut self, action_ids: &[usize]) {
+        let action_ids = self.action_ids_or_pending(action_ids).await;
+        println!("signing {} actions...", action_ids.len());
+        self.sign_batch(&action_ids).await;
+    }
+
+    /// Signs and performs the given actions, or all the pending actions if none are given.
+    pub async fn perform_pending_actions(&mut self, action_ids: &[usize]) {
+        let action_ids = self.action_ids_or_pending(action_ids).await;
+        println!("performing {} actions...", action_ids.len());
+        self.perform_actions(action_ids, "30,000,000").await;
+    }
+
+    async fn action_ids_or_pending(&mut self, action_ids: &[usize]) -> Vec<usize> {
+        if !action_ids.is_empty() {
+            return action_ids.to_vec();
+        }
+
+        self.pending_actions()
+            .await
+            .iter()
+            .map(|action_full_info| action_full_info.action_id)
+            .collect()
+    }
+
+    async fn pending_actions(&mut self) -> Vec<ActionFullInfo<StaticApi>> {
+        let pending_actions: MultiValueVec<ActionFullInfo<StaticApi>> = self
+            .interactor
+            .quick_query(self.state.multisig().get_pending_action_full_info())
+            .await;
+        pending_actions.into_vec()
+    }
+
+    pub async fn print_pending_actions(&mut self) {
+        let pending_actions = self.pending_actions().await;
+        if pending_actions.is_empty() {
+            println!("no pending actions");
+            return;
+        }
+
+        for action_full_info in pending_actions {
+            let action_id = action_full_info.action_id;
+            let quorum_reached = self.quorum_reached(action_id).await;
+            println!(
+                "action `{action_id}`: {} (quorum reached: {quorum_reached})",
+                action_description(&action_full_info.action_data)
+            );
+            for signer in action_full_info.signers.iter() {
+                println!("    signed by: {}", bech32::encode(&signer.to_address()));
+            }
+        }
+    }
+}
+
+fn action_description(action: &Action<StaticApi>) -> String {
+    match action {
+        Action::Nothing => "nothing".to_string(),
+        Action::AddBoardMember(address) => {
+            format!("add board member {}", bech32::encode(&address.to_address()))
+        },
+        Action::AddProposer(address) => {
+            format!("add proposer {}", bech32::encode(&address.to_address()))
+        },
+        Action::RemoveUser(address) => {
+            format!("remove user {}", bech32::encode(&address.to_address()))
+        },
+        Action::ChangeQuorum(new_quorum) => format!("change quorum to {new_quorum}"),
+        Action::SendTransferExecute(call_data) => format!(
+            "transfer-execute to {} with EGLD value {} calling `{}`",
+            bech32::encode(&call_data.to.to_address()),
+            egld_amount_description(&call_data.egld_amount),
+            call_data.endpoint_name
+        ),
+        Action::SendAsyncCall(call_data) => format!(
+            "async call to {} with EGLD value {} calling `{}`",
+            bech32::encode(&call_data.to.to_address()),
+            egld_amount_description(&call_data.egld_amount),
+            call_data.endpoint_name
+        ),
+        Action::SCDeployFromSource { source, .. } => format!(
+            "deploy from source {}",
+            bech32::encode(&source.to_address())
+        ),
+        Action::SCUpgradeFromSource { sc_address, .. } => {
+            format!("upgrade {}", bech32::encode(&sc_address.to_address()))
+        },
+    }
+}
+
+fn egld_amount_description(amount: &BigUint<StaticApi>) -> String {
+    num_bigint::BigUint::from_bytes_be(amount.to_bytes_be().as_slice()).to_string()
+}
@@ -12,8 +12,12 @@ pub struct InteractCli {
 /// Multisig Interact CLI Commands
 #[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
 pub enum InteractCliCommand {
+    #[command(name = "add-board-member", about = "Propose adding a board member")]
+    AddBoardMember(UserArgs),
     #[command(name = "board", about = "Print board")]
     Board,
+    #[command(name = "change-quorum", about = "Propose changing the quorum")]
+    ChangeQuorum(ChangeQuorumArgs),
     #[command(name = "deploy", about = "Deploy contract")]
     Deploy,
     #[command(name = "dns-register", about = "Register DNS")]
@@ -40,8 +44,29 @@ pub enum InteractCliCommand {
     NftItems,
     #[command(name = "nft-special", about = "Set special role")]
     NftSpecial,
+    #[command(name = "pending", about = "Print pending actions and their signers")]
+    Pending,
+    #[command(name = "perform", about = "Perform action")]
+    Perform(ActionArgs),
+    #[command(
+        name = "perform-batch",
+        about = "Sign and perform actions, all pending actions by default"
+    )]
+    PerformBatch(ActionBatchArgs),
     #[command(name = "quorum", about = "Print quorum")]
     Quorum,
+    #[command(
+        name = "remove-user",
+        about = "Propose removing a board member or proposer"
+    )]
+    RemoveUser(UserArgs),
+    #[command(name = "sign", about = "Sign action with all board wallets")]
+    Sign(ActionArgs),
+    #[command(
+        name = "sign-batch",
+        about = "Sign actions with all board wallets at once, all pending actions by default"
+    )]
+    SignBatch(ActionBatchArgs),
     #[command(name = "unwrap-egld", about = "Unwrap EGLD")]
     UnwrapEgld,
     #[command(
@@ -66,3 +91,36 @@ pub struct MultiDeployArgs {
     #[arg(short = 'c', long = "count", verbatim_doc_comment)]
     pub count: u8,
 }
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct UserArgs {
+    /// The bech32 address of the user
+    #[arg(short = 'a', long = "address", verbatim_doc_comment)]
+    pub address: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ChangeQuorumArgs {
+    /// The new number of required signatures
+    #[arg(short = 'q', long = "quorum", verbatim_doc_comment)]
+    pub quorum: usize,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ActionArgs {
+    /// The id of the proposed action
+    #[arg(short = 'i', long = "action-id", verbatim_doc_comment)]
+    pub action_id: usize,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ActionBatchArgs {
+    /// The ids of the proposed actions, separated by commas
+    #[arg(
+        short = 'i',
+        long = "action-ids",
+        value_delimiter = ',',
+        verbatim_doc_comment
+    )]
+    pub action_ids: Vec<usize>,
+}
@@ -47,7 +47,7 @@ impl<M: ManagedTypeApi> Action<M> {
 }
 
 /// Not used internally, just to retrieve results via endpoint.
-#[derive(TopEncode, TypeAbi)]
+#[derive(TopEncode, TopDecode, TypeAbi)]
 pub struct ActionFullInfo<M: ManagedTypeApi> {
     pub action_id: usize,
     pub action_data: Action<M>,
@@ -1,7 +1,7 @@
 use adder::ProxyTrait as _;
 use multisig::{
-    multisig_perform::ProxyTrait as _, multisig_propose::ProxyTrait as _, user_role::UserRole,
-    ProxyTrait as _,
+    multisig_perform::ProxyTrait as _, multisig_propose::ProxyTrait as _,
+    multisig_state::ProxyTrait as _, user_role::UserRole, ProxyTrait as _,
 };
 use multiversx_sc::{
     codec::{
@@ -577,3 +577,57 @@ fn test_deploy_and_upgrade_from_source() {
             .put_account(ADDER_ADDRESS_EXPR, CheckAccount::new().code(factorial_code)),
     );
 }
+
+#[test]
+fn test_change_quorum_expect_result() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    const NEW_BOARD_MEMBER_ADDRESS_EXPR: &str = "address:new-board-member";
+    let new_board_member_address = AddressValue::from(NEW_BOARD_MEMBER_ADDRESS_EXPR).to_address();
+    state.world.set_state_step(
+        SetStateStep::new().put_account(NEW_BOARD_MEMBER_ADDRESS_EXPR, Account::new().nonce(1)),
+    );
+
+    let action_id = state.propose_add_board_member(new_board_member_address);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    let new_quorum = 2;
+    let action_id = state.propose_change_quorum(new_quorum);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    state.world.sc_query(
+        ScQueryStep::new()
+            .call(state.multisig_contract.get_action_last_index())
+            .expect_result(action_id),
+    );
+    state.world.sc_query(
+        ScQueryStep::new()
+            .call(state.multisig_contract.quorum())
+            .expect_result(SingleValue::from(new_quorum)),
+    );
+}
+
+#[test]
+fn test_change_quorum_expect_error() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    let action_id = state.propose_change_quorum(2);
+    state.sign(action_id);
+    state.world.sc_call(
+        ScCallStep::new()
+            .from(BOARD_MEMBER_ADDRESS_EXPR)
+            .call(state.multisig_contract.perform_action_endpoint(action_id))
+            .expect_error(4, "quorum cannot exceed board size"),
+    );
+
+    state.world.sc_call(
+        ScCallStep::new()
+            .from(BOARD_MEMBER_ADDRESS_EXPR)
+            .call(state.multisig_contract.sign(action_id + 1))
+            .expect_error(4, "action does not exist"),
+    );
+}
@@ -113,7 +113,7 @@ pub trait ValidationModule: common::CommonModule {
         let caller = &self.blockchain().get_caller();
 
         for order in orders.iter() {
-            if order.match_provider != ManagedAddress::zero() {
+            if !order.match_provider.is_zero() {
                 require!(
                     &order.match_provider == caller,
                     "Caller is not matched order id"
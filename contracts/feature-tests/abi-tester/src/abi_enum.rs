@@ -4,8 +4,13 @@ multiversx_sc::derive_imports!();
 /// Its only purpose is to test that the ABI generator works fine.
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
 pub enum AbiEnum {
+    /// Variant docs also end up in the ABI.
     Nothing,
     Something(i32),
     SomethingMore(u8, OnlyShowsUpAsNested08),
-    SomeStruct { a: u16, b: OnlyShowsUpAsNested09 },
+    SomeStruct {
+        a: u16,
+        /// As do the docs of the variant fields.
+        b: OnlyShowsUpAsNested09,
+    },
 }
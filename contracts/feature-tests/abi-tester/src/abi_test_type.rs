@@ -1,7 +1,7 @@
 use crate::only_nested::*;
 use multiversx_sc::{
     api::ManagedTypeApi,
-    types::{BigUint, Box, ManagedBuffer},
+    types::{BigUint, Box, ManagedBuffer, ManagedVec},
 };
 multiversx_sc::derive_imports!();
 
@@ -33,3 +33,14 @@ pub struct AbiManagedVecItem {
     pub value1: u32,
     pub value2: u32,
 }
+
+/// Tests that generic types get a separate ABI type for each set of type arguments.
+/// The API type argument does not show up in the name.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct AbiGenericType<
+    M: ManagedTypeApi,
+    T: multiversx_sc::codec::NestedEncode + multiversx_sc::codec::NestedDecode,
+> {
+    pub value: T,
+    pub values: ManagedVec<M, u64>,
+}
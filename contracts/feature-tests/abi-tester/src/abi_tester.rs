@@ -22,6 +22,10 @@ pub trait AbiTester {
     #[payable("EGLD")]
     fn init(&self, _constructor_arg_1: i32, _constructor_arg_2: OnlyShowsUpInConstructor) {}
 
+    /// Gets called instead of the constructor on upgrade.
+    #[upgrade]
+    fn upgrade(&self, _upgrade_arg: i32) {}
+
     /// Example endpoint docs.
     #[endpoint]
     #[output_name("single output")]
@@ -40,6 +44,14 @@ pub trait AbiTester {
     #[only_owner]
     fn take_managed_type(&self, _arg: AbiManagedType<Self::Api>) {}
 
+    #[endpoint]
+    fn take_generic_types(
+        &self,
+        _arg_u32: AbiGenericType<Self::Api, u32>,
+        _arg_nested: AbiGenericType<Self::Api, Option<AbiGenericType<Self::Api, BigUint>>>,
+    ) {
+    }
+
     #[endpoint]
     #[output_name("multi-result-1")]
     #[output_name("multi-result-2")]
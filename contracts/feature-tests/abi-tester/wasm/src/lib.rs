@@ -5,9 +5,10 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           27
+// Upgrade:                              1
+// Endpoints:                           28
 // Async Callback (empty):               1
-// Total number of exported functions:  29
+// Total number of exported functions:  31
 
 #![no_std]
 #![allow(internal_features)]
@@ -20,9 +21,11 @@ multiversx_sc_wasm_adapter::endpoints! {
     abi_tester
     (
         init => init
+        upgrade => upgrade
         echo_abi_test_type => echo_abi_test_type
         echo_enum => echo_enum
         take_managed_type => take_managed_type
+        take_generic_types => take_generic_types
         multi_result_3 => multi_result_3
         multi_result_4 => multi_result_4
         var_args => var_args
@@ -0,0 +1,78 @@
+use multiversx_sc_snippets::{
+    multiversx_sc::types::{
+        BigUint, EsdtTokenPayment, ManagedAddress, ManagedBuffer, ManagedVec, TokenIdentifier,
+    },
+    multiversx_sc_scenario::{
+        api::StaticApi,
+        scenario_model::{Account, AddressValue, Log, ScCallStep, SetStateStep, TxExpect},
+        ScenarioWorld,
+    },
+};
+
+// The output of `sc-meta snippets` for `event_c`, the meta tests keep it in sync with the generator.
+include!("event_decoders/event_c.rs");
+
+const BASIC_FEATURES_ADDRESS_EXPR: &str = "sc:basic-features";
+const BASIC_FEATURES_PATH_EXPR: &str = "file:output/basic-features.wasm";
+const CALLER_ADDRESS_EXPR: &str = "address:an_account";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+
+    blockchain.register_contract(BASIC_FEATURES_PATH_EXPR, basic_features::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn decode_event_c_multi_transfer() {
+    let mut world = world();
+    let basic_features_code = world.code_expression(BASIC_FEATURES_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(
+                CALLER_ADDRESS_EXPR,
+                Account::new()
+                    .esdt_balance("str:TOKA-000000", 1000u64)
+                    .esdt_balance("str:TOKB-000000", 1000u64),
+            )
+            .put_account(
+                BASIC_FEATURES_ADDRESS_EXPR,
+                Account::new().code(basic_features_code),
+            ),
+    );
+
+    let mut logs = Vec::new();
+    world.sc_call_use_raw_response(
+        ScCallStep::new()
+            .from(CALLER_ADDRESS_EXPR)
+            .to(BASIC_FEATURES_ADDRESS_EXPR)
+            .esdt_transfer("str:TOKA-000000", 0u64, 100u64)
+            .esdt_transfer("str:TOKB-000000", 0u64, 200u64)
+            .function("logEventC")
+            .argument("6")
+            .argument("str:event data")
+            .expect(TxExpect::ok()),
+        |response| logs = response.logs.clone(),
+    );
+
+    let events = logs
+        .iter()
+        .filter_map(EventCEvent::decode)
+        .collect::<Vec<_>>();
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+
+    let caller = AddressValue::from(CALLER_ADDRESS_EXPR).to_address();
+    assert_eq!(event.caller, ManagedAddress::<StaticApi>::from(caller));
+    assert_eq!(event.egld_value, BigUint::<StaticApi>::zero());
+    assert_eq!(
+        event.esdt_transfers,
+        ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::from(vec![
+            EsdtTokenPayment::new(TokenIdentifier::from("TOKA-000000"), 0, 100u64.into()),
+            EsdtTokenPayment::new(TokenIdentifier::from("TOKB-000000"), 0, 200u64.into()),
+        ])
+    );
+    assert_eq!(event.id, 6);
+    assert_eq!(event.data, ManagedBuffer::<StaticApi>::from("event data"));
+}
@@ -0,0 +1,34 @@
+fn decode_event_value<T: multiversx_sc_snippets::multiversx_sc::codec::TopDecode>(
+    bytes: &[u8],
+) -> Option<T> {
+    T::top_decode(bytes).ok()
+}
+
+#[derive(Debug)]
+pub struct EventCEvent {
+    pub caller: ManagedAddress<StaticApi>,
+    pub egld_value: BigUint<StaticApi>,
+    pub esdt_transfers: ManagedVec<StaticApi, EsdtTokenPayment<StaticApi>>,
+    pub id: u32,
+    pub data: ManagedBuffer<StaticApi>,
+}
+
+impl EventCEvent {
+    pub const IDENTIFIER: &'static [u8] = b"event_c";
+
+    pub fn decode(log: &Log) -> Option<Self> {
+        let mut topics = log.topics.iter().map(|topic| topic.value.as_slice());
+        if topics.next()? != Self::IDENTIFIER {
+            return None;
+        }
+
+        Some(EventCEvent {
+            caller: decode_event_value(topics.next()?)?,
+            egld_value: decode_event_value(topics.next()?)?,
+            esdt_transfers: decode_event_value(topics.next()?)?,
+            id: decode_event_value(topics.next()?)?,
+            data: decode_event_value(log.data.value.as_slice())?,
+        })
+    }
+}
+
@@ -18,6 +18,16 @@ pub trait BlockchainApiFeatures {
         self.blockchain().get_shard_of_address(address)
     }
 
+    #[endpoint]
+    fn get_current_shard(&self) -> u32 {
+        self.blockchain().get_current_shard()
+    }
+
+    #[endpoint]
+    fn is_same_shard(&self, address: &ManagedAddress) -> bool {
+        self.blockchain().is_same_shard(address)
+    }
+
     #[endpoint]
     fn is_smart_contract(&self, address: &ManagedAddress) -> bool {
         self.blockchain().is_smart_contract(address)
@@ -36,4 +36,14 @@ pub trait EventFeatures {
     ) {
         self.event_b(arg1, arg2, data.into_vec());
     }
+
+    /// Besides its own topic, also logs the caller and the payment.
+    #[event("event_c", standard_topics)]
+    fn event_c(&self, #[indexed] id: u32, data: &ManagedBuffer);
+
+    #[payable("*")]
+    #[endpoint(logEventC)]
+    fn log_event_c(&self, id: u32, data: ManagedBuffer) {
+        self.event_c(id, &data);
+    }
 }
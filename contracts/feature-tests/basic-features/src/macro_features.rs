@@ -21,5 +21,17 @@ pub trait Macros {
         sc_panic!("sc_panic test");
     }
 
+    /// Can also be called by its previous name.
+    #[endpoint(echoRenamed, alias = "echo_before_rename")]
+    fn echo_renamed(&self, value: u32) -> u32 {
+        value
+    }
+
+    #[deprecated_endpoint(since = "0.44.0", note = "Use `echoRenamed` instead.")]
+    #[endpoint]
+    fn echo_deprecated(&self, value: u32) -> u32 {
+        value
+    }
+
     // TODO: add panic formatting here?
 }
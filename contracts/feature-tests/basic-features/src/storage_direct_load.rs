@@ -90,4 +90,9 @@ pub trait StorageLoadFeatures {
         );
         ManagedBuffer::from_handle(value_handle)
     }
+
+    #[endpoint]
+    fn load_from_address_typed(&self, address: ManagedAddress, key: ManagedBuffer) -> BigUint {
+        self.blockchain().read_storage_from_address(&address, key)
+    }
 }
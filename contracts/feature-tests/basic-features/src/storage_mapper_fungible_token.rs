@@ -101,6 +101,23 @@ pub trait FungibleTokenMapperFeatures:
         }
     }
 
+    #[endpoint]
+    fn transfer_ownership_fungible(&self, new_owner: ManagedAddress) {
+        let cb = FungibleTokenMapperFeatures::callbacks(self).transfer_ownership_callback();
+        self.fungible_token_mapper()
+            .transfer_ownership(&new_owner, Some(cb));
+    }
+
+    #[callback]
+    fn transfer_ownership_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                self.ownership_transferred().set(true);
+            },
+            ManagedAsyncCallResult::Err(_) => {},
+        }
+    }
+
     #[endpoint]
     fn mint_fungible(&self, amount: BigUint) -> EsdtTokenPayment<Self::Api> {
         self.fungible_token_mapper().mint(amount)
@@ -147,4 +164,7 @@ pub trait FungibleTokenMapperFeatures:
 
     #[storage_mapper("rolesSet")]
     fn roles_set(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("ownershipTransferred")]
+    fn ownership_transferred(&self) -> SingleValueMapper<bool>;
 }
@@ -61,4 +61,22 @@ pub trait SingleValueMapperFeatures {
     fn raw_byte_length_single_value_mapper(&self) -> usize {
         self.map_my_single_value_mapper().raw_byte_length()
     }
+
+    #[aggregated_view]
+    #[storage_mapper("user_token_balance")]
+    fn user_token_balance(
+        &self,
+        user: &ManagedAddress,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[endpoint]
+    fn user_token_balance_set(
+        &self,
+        user: ManagedAddress,
+        token_id: TokenIdentifier,
+        amount: BigUint,
+    ) {
+        self.user_token_balance(&user, &token_id).set(amount);
+    }
 }
@@ -4,6 +4,7 @@ multiversx_sc::imports!();
 #[multiversx_sc::module]
 pub trait VecMapperFeatures {
     #[view]
+    #[paged_view]
     #[storage_mapper("vec_mapper")]
     fn vec_mapper(&self) -> VecMapper<u32>;
 
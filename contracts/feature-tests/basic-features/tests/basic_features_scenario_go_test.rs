@@ -154,6 +154,11 @@ fn echo_nothing_go() {
     world().run("scenarios/echo_nothing.scen.json");
 }
 
+#[test]
+fn echo_renamed_alias_go() {
+    world().run("scenarios/echo_renamed_alias.scen.json");
+}
+
 #[test]
 fn echo_tuple_into_multiresult_go() {
     world().run("scenarios/echo_tuple_into_multiresult.scen.json");
@@ -335,6 +340,11 @@ fn storage_map_3_go() {
     world().run("scenarios/storage_map3.scen.json");
 }
 
+#[test]
+fn storage_mapper_aggregated_view_go() {
+    world().run("scenarios/storage_mapper_aggregated_view.scen.json");
+}
+
 #[test]
 fn storage_mapper_fungible_token_go() {
     world().run("scenarios/storage_mapper_fungible_token.scen.json");
@@ -170,6 +170,11 @@ fn echo_nothing_rs() {
     world().run("scenarios/echo_nothing.scen.json");
 }
 
+#[test]
+fn echo_renamed_alias_rs() {
+    world().run("scenarios/echo_renamed_alias.scen.json");
+}
+
 #[test]
 fn echo_tuple_into_multiresult_rs() {
     world().run("scenarios/echo_tuple_into_multiresult.scen.json");
@@ -333,6 +338,11 @@ fn storage_load_from_address_rs() {
     world().run("scenarios/storage_load_from_address.scen.json");
 }
 
+#[test]
+fn storage_load_from_address_not_readable_rs() {
+    world().run("scenarios/storage_load_from_address_not_readable.scen.json");
+}
+
 #[test]
 fn storage_managed_address_rs() {
     world().run("scenarios/storage_managed_address.scen.json");
@@ -353,6 +363,11 @@ fn storage_map_3_rs() {
     world().run("scenarios/storage_map3.scen.json");
 }
 
+#[test]
+fn storage_mapper_aggregated_view_rs() {
+    world().run("scenarios/storage_mapper_aggregated_view.scen.json");
+}
+
 #[test]
 #[ignore]
 fn storage_mapper_fungible_token_rs() {
@@ -454,3 +469,8 @@ fn storage_usize_bad_rs() {
 fn struct_eq_rs() {
     world().run("scenarios/struct_eq.scen.json");
 }
+
+#[test]
+fn upgrade_contract_bad_code_metadata_rs() {
+    world().run("scenarios/upgrade_contract_bad_code_metadata.scen.json");
+}
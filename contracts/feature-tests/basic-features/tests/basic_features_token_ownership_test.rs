@@ -0,0 +1,79 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+const BASIC_FEATURES_PATH_EXPR: &str = "file:output/basic-features.wasm";
+const ESDT_SYSTEM_SC_MOCK_PATH_EXPR: &str =
+    "file:../esdt-system-sc-mock/output/esdt-system-sc-mock.wasm";
+const ESDT_SYSTEM_SC_ADDRESS_EXPR: &str =
+    "bech32:erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u";
+
+/// The fungible token mapper of the contract holds `TICKER-444444`, owned by the contract.
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+
+    blockchain.register_contract(BASIC_FEATURES_PATH_EXPR, basic_features::ContractBuilder);
+    blockchain.register_contract(
+        ESDT_SYSTEM_SC_MOCK_PATH_EXPR,
+        esdt_system_sc_mock::ContractBuilder,
+    );
+
+    let basic_features_code = blockchain.code_expression(BASIC_FEATURES_PATH_EXPR);
+    let esdt_system_sc_code = blockchain.code_expression(ESDT_SYSTEM_SC_MOCK_PATH_EXPR);
+    blockchain
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:an_account", Account::new().nonce(0))
+                .put_account(
+                    ESDT_SYSTEM_SC_ADDRESS_EXPR,
+                    Account::new().nonce(0).code(esdt_system_sc_code),
+                )
+                .put_account(
+                    "sc:basic-features",
+                    Account::new().nonce(0).code(basic_features_code),
+                )
+                .new_token_identifier("TICKER-444444"),
+        )
+        .sc_call(
+            ScCallStep::new()
+                .from("address:an_account")
+                .to("sc:basic-features")
+                .function("issue_and_set_all_roles_fungible")
+                .argument("str:TICKER")
+                .expect(TxExpect::ok()),
+        );
+    assert!(blockchain.is_token_owner("str:TICKER-444444", "sc:basic-features"));
+
+    blockchain
+}
+
+#[test]
+fn fungible_token_ownership_transfer_test() {
+    let mut world = world();
+    world.sc_call(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("transfer_ownership_fungible")
+            .argument("address:an_account")
+            .expect(TxExpect::ok()),
+    );
+
+    assert!(!world.is_token_owner("str:TICKER-444444", "sc:basic-features"));
+    assert!(world.is_token_owner("str:TICKER-444444", "address:an_account"));
+}
+
+#[test]
+fn fungible_token_ownership_transfer_invalid_address_test() {
+    let mut world = world();
+    world.sc_call(
+        ScCallStep::new()
+            .from("sc:basic-features")
+            .to(ESDT_SYSTEM_SC_ADDRESS_EXPR)
+            .function("transferOwnership")
+            .argument("str:TICKER-444444")
+            .argument("0x0102")
+            .expect(TxExpect::user_error("str:invalid new owner address")),
+    );
+
+    assert!(world.is_token_owner("str:TICKER-444444", "sc:basic-features"));
+}
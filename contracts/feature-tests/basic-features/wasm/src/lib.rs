@@ -5,9 +5,10 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                          340
+// Endpoints:                          350
+// Endpoint aliases:                     1
 // Async Callback:                       1
-// Total number of exported functions: 342
+// Total number of exported functions: 353
 
 #![no_std]
 #![allow(internal_features)]
@@ -124,6 +125,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         get_caller => get_caller
         get_owner_address => get_owner_address
         get_shard_of_address => get_shard_of_address
+        get_current_shard => get_current_shard
+        is_same_shard => is_same_shard
         is_smart_contract => is_smart_contract
         get_state_root_hash => get_state_root_hash
         get_tx_hash => get_tx_hash
@@ -195,10 +198,14 @@ multiversx_sc_wasm_adapter::endpoints! {
         logEventA => log_event_a
         logEventARepeat => log_event_a_repeat
         logEventB => log_event_b
+        logEventC => log_event_c
         only_owner_endpoint => only_owner_endpoint
         only_user_account_endpoint => only_user_account_endpoint
         require_equals => require_equals
         sc_panic => sc_panic
+        echoRenamed => echo_renamed
+        echo_before_rename => echo_renamed
+        echo_deprecated => echo_deprecated
         maddress_from_array => maddress_from_array
         maddress_from_managed_buffer => maddress_from_managed_buffer
         mbuffer_new => mbuffer_new
@@ -238,6 +245,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         load_map2 => load_map2
         load_map3 => load_map3
         load_from_address_raw => load_from_address_raw
+        load_from_address_typed => load_from_address_typed
         store_bytes => store_bytes
         store_big_uint => store_big_uint
         store_big_int => store_big_int
@@ -310,7 +318,10 @@ multiversx_sc_wasm_adapter::endpoints! {
         is_empty_single_value_mapper => is_empty_single_value_mapper
         is_empty_at_address_single_value_mapper => is_empty_at_address_single_value_mapper
         raw_byte_length_single_value_mapper => raw_byte_length_single_value_mapper
+        user_token_balance_aggregated => user_token_balance_aggregated
+        user_token_balance_set => user_token_balance_set
         vec_mapper => vec_mapper
+        vec_mapper_paged => vec_mapper_paged
         vec_mapper_push => vec_mapper_push
         vec_mapper_get => vec_mapper_get
         vec_mapper_get_at_address => vec_mapper_get_at_address
@@ -332,6 +343,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         issue_fungible_custom_callback => issue_fungible_custom_callback
         issue_and_set_all_roles_fungible => issue_and_set_all_roles_fungible
         set_local_roles_fungible => set_local_roles_fungible
+        transfer_ownership_fungible => transfer_ownership_fungible
         mint_fungible => mint_fungible
         mint_and_send_fungible => mint_and_send_fungible
         burn_fungible => burn_fungible
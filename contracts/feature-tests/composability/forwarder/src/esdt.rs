@@ -82,6 +82,39 @@ pub trait ForwarderEsdtModule: storage::ForwarderStorageModule {
         );
     }
 
+    /// Payments with a zero amount are skipped.
+    #[endpoint]
+    fn send_esdt_direct_non_zero_multi(
+        &self,
+        to: ManagedAddress,
+        token_payments: MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>>,
+    ) {
+        let mut all_token_payments = ManagedVec::new();
+
+        for multi_arg in token_payments.into_iter() {
+            let (token_identifier, token_nonce, amount) = multi_arg.into_tuple();
+            let payment = EsdtTokenPayment::new(token_identifier, token_nonce, amount);
+
+            all_token_payments.push(payment);
+        }
+
+        self.send().direct_non_zero_multi(&to, &all_token_payments);
+    }
+
+    /// Recipients with a zero amount are skipped.
+    #[endpoint]
+    fn send_esdt_non_zero_batch(
+        &self,
+        token_id: TokenIdentifier,
+        payouts: MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>>,
+    ) {
+        self.send().direct_non_zero_esdt_batch(
+            &token_id,
+            0,
+            payouts.into_iter().map(|payout| payout.into_tuple()),
+        );
+    }
+
     #[payable("EGLD")]
     #[endpoint]
     fn issue_fungible_token(
@@ -143,6 +176,35 @@ pub trait ForwarderEsdtModule: storage::ForwarderStorageModule {
         }
     }
 
+    #[endpoint]
+    fn request_token_decimals(&self, token_identifier: TokenIdentifier) {
+        self.send()
+            .esdt_system_sc_proxy()
+            .get_token_properties(&token_identifier)
+            .async_call()
+            .with_callback(self.callbacks().token_decimals_callback(&token_identifier))
+            .call_and_exit()
+    }
+
+    #[callback]
+    fn token_decimals_callback(
+        &self,
+        token_identifier: &TokenIdentifier,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(properties) => {
+                if let Some(num_decimals) = token_properties_num_decimals(properties) {
+                    self.token_decimals(token_identifier).set(num_decimals);
+                }
+                self.last_error_message().clear();
+            },
+            ManagedAsyncCallResult::Err(message) => {
+                self.last_error_message().set(&message.err_msg);
+            },
+        }
+    }
+
     #[endpoint]
     fn local_mint(&self, token_identifier: TokenIdentifier, amount: BigUint) {
         self.send().esdt_local_mint(&token_identifier, 0, &amount);
@@ -38,4 +38,14 @@ pub trait Forwarder:
     fn send_egld(&self, to: &ManagedAddress, amount: &BigUint) {
         self.send().direct_egld(to, amount);
     }
+
+    /// Recipients with a zero amount are skipped.
+    #[endpoint]
+    fn send_egld_non_zero_batch(
+        &self,
+        payouts: MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>>,
+    ) {
+        self.send()
+            .direct_non_zero_egld_batch(payouts.into_iter().map(|payout| payout.into_tuple()));
+    }
 }
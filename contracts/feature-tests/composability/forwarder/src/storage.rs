@@ -9,4 +9,8 @@ pub trait ForwarderStorageModule {
     #[view(lastErrorMessage)]
     #[storage_mapper("lastErrorMessage")]
     fn last_error_message(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[view(tokenDecimals)]
+    #[storage_mapper("tokenDecimals")]
+    fn token_decimals(&self, token_identifier: &TokenIdentifier) -> SingleValueMapper<usize>;
 }
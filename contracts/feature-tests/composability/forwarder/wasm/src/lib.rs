@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           66
+// Endpoints:                           71
 // Async Callback:                       1
-// Total number of exported functions:  68
+// Total number of exported functions:  73
 
 #![no_std]
 #![allow(internal_features)]
@@ -21,6 +21,7 @@ multiversx_sc_wasm_adapter::endpoints! {
     (
         init => init
         send_egld => send_egld
+        send_egld_non_zero_batch => send_egld_non_zero_batch
         echo_arguments_sync => echo_arguments_sync
         echo_arguments_sync_twice => echo_arguments_sync_twice
         forward_sync_accept_funds => forward_sync_accept_funds
@@ -59,7 +60,10 @@ multiversx_sc_wasm_adapter::endpoints! {
         send_esdt_with_fees => send_esdt_with_fees
         send_esdt_twice => send_esdt_twice
         send_esdt_direct_multi_transfer => send_esdt_direct_multi_transfer
+        send_esdt_direct_non_zero_multi => send_esdt_direct_non_zero_multi
+        send_esdt_non_zero_batch => send_esdt_non_zero_batch
         issue_fungible_token => issue_fungible_token
+        request_token_decimals => request_token_decimals
         local_mint => local_mint
         local_burn => local_burn
         get_esdt_local_roles => get_esdt_local_roles
@@ -86,6 +90,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         unsetLocalRoles => unset_local_roles
         lastIssuedToken => last_issued_token
         lastErrorMessage => last_error_message
+        tokenDecimals => token_decimals
     )
 }
 
@@ -53,6 +53,26 @@ pub trait CallPromisesDirectModule {
             .register_promise();
     }
 
+    #[endpoint]
+    fn promise_raw_separate_callbacks(
+        &self,
+        to: ManagedAddress,
+        endpoint_name: ManagedBuffer,
+        gas_limit: u64,
+        extra_gas_for_callback: u64,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.send()
+            .contract_call::<()>(to, endpoint_name)
+            .with_raw_arguments(args.to_arg_buffer())
+            .with_gas_limit(gas_limit)
+            .async_call_promise()
+            .with_extra_gas_for_callback(extra_gas_for_callback)
+            .with_callback(self.callbacks().success_callback(3001))
+            .with_error_callback(self.callbacks().error_callback(3001))
+            .register_promise();
+    }
+
     #[promises_callback]
     fn the_one_callback(
         &self,
@@ -63,6 +83,34 @@ pub trait CallPromisesDirectModule {
         self.async_call_event_callback(arg1, arg2, &result.into_vec_of_buffers());
     }
 
+    #[promises_callback]
+    fn success_callback(
+        &self,
+        #[call_result] result: MultiValueEncoded<ManagedBuffer>,
+        arg: usize,
+    ) {
+        self.async_call_success_event(arg, &result.into_vec_of_buffers());
+    }
+
+    #[promises_callback]
+    fn error_callback(&self, #[call_result] result: MultiValueEncoded<ManagedBuffer>, arg: usize) {
+        self.async_call_error_event(arg, &result.into_vec_of_buffers());
+    }
+
+    #[event("async_call_success_event")]
+    fn async_call_success_event(
+        &self,
+        #[indexed] arg: usize,
+        arguments: &ManagedVec<Self::Api, ManagedBuffer>,
+    );
+
+    #[event("async_call_error_event")]
+    fn async_call_error_event(
+        &self,
+        #[indexed] arg: usize,
+        arguments: &ManagedVec<Self::Api, ManagedBuffer>,
+    );
+
     #[event("async_call_event_callback")]
     fn async_call_event_callback(
         &self,
@@ -48,6 +48,43 @@ pub trait CallPromisesModule {
             .register_promise()
     }
 
+    #[endpoint]
+    fn forward_promise_echo_args(
+        &self,
+        to: ManagedAddress,
+        gas_limit: u64,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.vault_proxy()
+            .contract(to)
+            .echo_arguments(args)
+            .with_gas_limit(gas_limit)
+            .async_call_promise()
+            .with_callback(self.callbacks().echo_args_outcome_callback())
+            .with_extra_gas_for_callback(10_000_000)
+            .register_promise()
+    }
+
+    /// Only keeps the outcome category.
+    #[promises_callback]
+    fn echo_args_outcome_callback(
+        &self,
+        #[call_result] result: CallbackResult<MultiValueEncoded<ManagedBuffer>>,
+    ) {
+        let outcome: &[u8] = match result {
+            CallbackResult::Ok(_) => b"success",
+            CallbackResult::Err(CallbackError::UserError(_)) => b"user error",
+            CallbackResult::Err(CallbackError::OutOfGas(_)) => b"out of gas",
+            CallbackResult::Err(CallbackError::ExecutionFailed(_)) => b"execution failed",
+            CallbackResult::Err(CallbackError::Other { .. }) => b"other",
+        };
+        self.echo_args_outcome().set(ManagedBuffer::from(outcome));
+    }
+
+    #[view]
+    #[storage_mapper("echo_args_outcome")]
+    fn echo_args_outcome(&self) -> SingleValueMapper<ManagedBuffer>;
+
     #[promises_callback]
     fn retrieve_funds_callback(&self) {
         let (token, nonce, payment) = self.call_value().egld_or_single_esdt().into_tuple();
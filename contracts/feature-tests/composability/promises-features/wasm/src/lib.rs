@@ -5,10 +5,10 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            7
+// Endpoints:                           10
 // Async Callback (empty):               1
-// Promise callbacks:                    2
-// Total number of exported functions:  11
+// Promise callbacks:                    5
+// Total number of exported functions:  17
 
 #![no_std]
 #![allow(internal_features)]
@@ -23,13 +23,19 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         forward_promise_accept_funds => forward_promise_accept_funds
         forward_promise_retrieve_funds => forward_promise_retrieve_funds
+        forward_promise_echo_args => forward_promise_echo_args
+        echo_args_outcome => echo_args_outcome
         callback_data => callback_data
         callback_data_at_index => callback_data_at_index
         clear_callback_data => clear_callback_data
         promise_raw_single_token => promise_raw_single_token
         promise_raw_multi_transfer => promise_raw_multi_transfer
+        promise_raw_separate_callbacks => promise_raw_separate_callbacks
+        echo_args_outcome_callback => echo_args_outcome_callback
         retrieve_funds_callback => retrieve_funds_callback
         the_one_callback => the_one_callback
+        success_callback => success_callback
+        error_callback => error_callback
     )
 }
 
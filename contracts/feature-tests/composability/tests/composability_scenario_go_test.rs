@@ -10,6 +10,12 @@ fn promises_multi_transfer_go() {
     world().run("scenarios-promises/promises_multi_transfer.scen.json");
 }
 
+#[ignore = "currently compatible with VM 1.5, not with VM 1.4"]
+#[test]
+fn promises_separate_callbacks_go() {
+    world().run("scenarios-promises/promises_separate_callbacks.scen.json");
+}
+
 #[ignore = "currently compatible with VM 1.5, not with VM 1.4"]
 #[test]
 fn promises_single_transfer_go() {
@@ -74,6 +80,11 @@ fn forw_raw_contract_deploy_go() {
     world().run("scenarios/forw_raw_contract_deploy.scen.json");
 }
 
+#[test]
+fn forw_raw_contract_deploy_from_source_err_go() {
+    world().run("scenarios/forw_raw_contract_deploy_from_source_err.scen.json");
+}
+
 #[test]
 fn forw_raw_contract_upgrade_go() {
     world().run("scenarios/forw_raw_contract_upgrade.scen.json");
@@ -16,10 +16,17 @@ fn world() -> ScenarioWorld {
         "file:forwarder/output/forwarder.wasm",
         forwarder::ContractBuilder,
     );
-    blockchain.register_contract(
-        "file:forwarder-raw/output/forwarder-raw.wasm",
+
+    // only the main output, the alternative constructors and upgrade function are in the other ones
+    blockchain
+        .set_current_dir_from_workspace("contracts/feature-tests/composability/forwarder-raw");
+    blockchain.register_partial_contract::<forwarder_raw::AbiProvider, _>(
+        "file:output/forwarder-raw.wasm",
         forwarder_raw::ContractBuilder,
+        "forwarder-raw",
     );
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
     blockchain.register_contract(
         "file:promises-features/output/promises-features.wasm",
         promises_features::ContractBuilder,
@@ -46,6 +53,11 @@ fn promises_multi_transfer_rs() {
     world().run("scenarios-promises/promises_multi_transfer.scen.json");
 }
 
+#[test]
+fn promises_separate_callbacks_rs() {
+    world().run("scenarios-promises/promises_separate_callbacks.scen.json");
+}
+
 #[test]
 #[ignore = "not yet supported"]
 fn promises_single_transfer_rs() {
@@ -107,6 +119,11 @@ fn forw_raw_contract_deploy_rs() {
     world().run("scenarios/forw_raw_contract_deploy.scen.json");
 }
 
+#[test]
+fn forw_raw_contract_deploy_from_source_err_rs() {
+    world().run("scenarios/forw_raw_contract_deploy_from_source_err.scen.json");
+}
+
 #[test]
 fn forw_raw_contract_upgrade_rs() {
     world().run("scenarios/forw_raw_contract_upgrade.scen.json");
@@ -373,6 +390,11 @@ fn forwarder_get_esdt_local_roles_rs() {
     world().run("scenarios/forwarder_get_esdt_local_roles.scen.json");
 }
 
+#[test]
+fn forwarder_get_token_decimals_rs() {
+    world().run("scenarios/forwarder_get_token_decimals.scen.json");
+}
+
 #[test]
 fn forwarder_get_esdt_token_data_rs() {
     world().run("scenarios/forwarder_get_esdt_token_data.scen.json");
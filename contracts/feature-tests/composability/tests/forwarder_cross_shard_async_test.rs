@@ -0,0 +1,238 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+const FORWARDER_PATH_EXPR: &str = "file:forwarder/output/forwarder.wasm";
+const VAULT_PATH_EXPR: &str = "file:vault/output/vault.wasm";
+
+// the shard is given by the last byte of the address
+const FORWARDER_ADDRESS_EXPR: &str = "sc:forwarder#00";
+const VAULT_ADDRESS_EXPR: &str = "sc:vault#01";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(FORWARDER_PATH_EXPR, forwarder::ContractBuilder);
+    blockchain.register_contract(VAULT_PATH_EXPR, vault::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn forwarder_cross_shard_async_retrieve_egld_test() {
+    let mut world = world();
+    let forwarder_code = world.code_expression(FORWARDER_PATH_EXPR);
+    let vault_code = world.code_expression(VAULT_PATH_EXPR);
+
+    world
+        .num_shards(2)
+        .simulate_cross_shard_async()
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:a_user", Account::new().nonce(0))
+                .put_account(
+                    FORWARDER_ADDRESS_EXPR,
+                    Account::new().nonce(0).code(forwarder_code),
+                )
+                .put_account(
+                    VAULT_ADDRESS_EXPR,
+                    Account::new().nonce(0).balance("1000").code(vault_code),
+                ),
+        )
+        .sc_call(
+            ScCallStep::new()
+                .from("address:a_user")
+                .to(FORWARDER_ADDRESS_EXPR)
+                .function("forward_async_retrieve_funds")
+                .argument(VAULT_ADDRESS_EXPR)
+                .argument("str:EGLD")
+                .argument("0")
+                .argument("1000")
+                .expect(TxExpect::ok().no_result()),
+        );
+
+    // the async call only reaches the vault in the next block
+    assert_eq!(world.num_pending_cross_shard_calls(), 1);
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("0"))
+            .put_account(VAULT_ADDRESS_EXPR, CheckAccount::new().balance("1000")),
+    );
+
+    // the funds are sent, the callback only comes back in the block after
+    let responses = world.next_block();
+    assert_eq!(responses.len(), 1);
+    assert!(responses[0].is_success());
+    assert_eq!(world.num_pending_cross_shard_calls(), 1);
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("1000"))
+            .put_account(VAULT_ADDRESS_EXPR, CheckAccount::new().balance("0")),
+    );
+
+    let responses = world.next_block();
+    assert_eq!(responses.len(), 1);
+    assert!(responses[0].is_success());
+    assert_eq!(world.num_pending_cross_shard_calls(), 0);
+    world.sc_query(
+        ScQueryStep::new()
+            .to(FORWARDER_ADDRESS_EXPR)
+            .function("callback_data_at_index")
+            .argument("1")
+            .expect(
+                TxExpect::ok()
+                    .result("str:retrieve_funds_callback")
+                    .result("str:EGLD")
+                    .result("0")
+                    .result("1000"),
+            ),
+    );
+}
+
+#[test]
+fn forwarder_cross_shard_async_accept_egld_test() {
+    let mut world = world();
+    let forwarder_code = world.code_expression(FORWARDER_PATH_EXPR);
+    let vault_code = world.code_expression(VAULT_PATH_EXPR);
+
+    world
+        .num_shards(2)
+        .simulate_cross_shard_async()
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:a_user", Account::new().nonce(0).balance("1000"))
+                .put_account(
+                    FORWARDER_ADDRESS_EXPR,
+                    Account::new().nonce(0).code(forwarder_code),
+                )
+                .put_account(VAULT_ADDRESS_EXPR, Account::new().nonce(0).code(vault_code)),
+        )
+        .sc_call(
+            ScCallStep::new()
+                .from("address:a_user")
+                .to(FORWARDER_ADDRESS_EXPR)
+                .egld_value("1000")
+                .function("forward_async_accept_funds")
+                .argument(VAULT_ADDRESS_EXPR)
+                .expect(TxExpect::ok().no_result()),
+        );
+
+    // the value left the forwarder together with the call, but has not reached the vault yet
+    assert_eq!(world.num_pending_cross_shard_calls(), 1);
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("0"))
+            .put_account(VAULT_ADDRESS_EXPR, CheckAccount::new().balance("0")),
+    );
+
+    let responses = world.next_block();
+    assert_eq!(responses.len(), 1);
+    assert!(responses[0].is_success());
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("0"))
+            .put_account(VAULT_ADDRESS_EXPR, CheckAccount::new().balance("1000")),
+    );
+
+    let responses = world.next_block();
+    assert_eq!(responses.len(), 1);
+    assert!(responses[0].is_success());
+    assert_eq!(world.num_pending_cross_shard_calls(), 0);
+}
+
+#[test]
+fn forwarder_cross_shard_async_refund_egld_test() {
+    let mut world = world();
+    let forwarder_code = world.code_expression(FORWARDER_PATH_EXPR);
+
+    // the other forwarder has no accept_funds endpoint, so the call fails
+    world
+        .num_shards(2)
+        .simulate_cross_shard_async()
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:a_user", Account::new().nonce(0).balance("1000"))
+                .put_account(
+                    FORWARDER_ADDRESS_EXPR,
+                    Account::new().nonce(0).code(forwarder_code.clone()),
+                )
+                .put_account(
+                    "sc:other-forwarder#01",
+                    Account::new().nonce(0).code(forwarder_code),
+                ),
+        )
+        .sc_call(
+            ScCallStep::new()
+                .from("address:a_user")
+                .to(FORWARDER_ADDRESS_EXPR)
+                .egld_value("1000")
+                .function("forward_async_accept_funds")
+                .argument("sc:other-forwarder#01")
+                .expect(TxExpect::ok().no_result()),
+        );
+
+    assert_eq!(world.num_pending_cross_shard_calls(), 1);
+    world.check_state_step(
+        CheckStateStep::new().put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("0")),
+    );
+
+    // the call fails, the value is still in flight, on its way back
+    let responses = world.next_block();
+    assert_eq!(responses.len(), 1);
+    assert!(!responses[0].is_success());
+    assert_eq!(world.num_pending_cross_shard_calls(), 1);
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("0"))
+            .put_account("sc:other-forwarder#01", CheckAccount::new().balance("0")),
+    );
+
+    // the refund arrives with the callback
+    world.next_block();
+    assert_eq!(world.num_pending_cross_shard_calls(), 0);
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("1000"))
+            .put_account("sc:other-forwarder#01", CheckAccount::new().balance("0")),
+    );
+}
+
+#[test]
+fn forwarder_same_shard_async_retrieve_egld_test() {
+    let mut world = world();
+    let forwarder_code = world.code_expression(FORWARDER_PATH_EXPR);
+    let vault_code = world.code_expression(VAULT_PATH_EXPR);
+
+    world
+        .num_shards(2)
+        .simulate_cross_shard_async()
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:a_user", Account::new().nonce(0))
+                .put_account(
+                    "sc:forwarder#01",
+                    Account::new().nonce(0).code(forwarder_code),
+                )
+                .put_account(
+                    VAULT_ADDRESS_EXPR,
+                    Account::new().nonce(0).balance("1000").code(vault_code),
+                ),
+        )
+        .sc_call(
+            ScCallStep::new()
+                .from("address:a_user")
+                .to("sc:forwarder#01")
+                .function("forward_async_retrieve_funds")
+                .argument(VAULT_ADDRESS_EXPR)
+                .argument("str:EGLD")
+                .argument("0")
+                .argument("1000")
+                .expect(TxExpect::ok().no_result()),
+        );
+
+    // same shard, everything is executed right away
+    assert_eq!(world.num_pending_cross_shard_calls(), 0);
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account("sc:forwarder#01", CheckAccount::new().balance("1000"))
+            .put_account(VAULT_ADDRESS_EXPR, CheckAccount::new().balance("0")),
+    );
+}
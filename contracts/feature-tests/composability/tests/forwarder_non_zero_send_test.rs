@@ -0,0 +1,144 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+const FORWARDER_PATH_EXPR: &str = "file:forwarder/output/forwarder.wasm";
+const FWD_TOKEN_EXPR: &str = "str:FWD-TOKEN";
+/// Not held by the forwarder, so any transfer of it fails, even with a zero amount.
+const MISSING_TOKEN_EXPR: &str = "str:MISSING-TOKEN";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(FORWARDER_PATH_EXPR, forwarder::ContractBuilder);
+
+    let forwarder_code = blockchain.code_expression(FORWARDER_PATH_EXPR);
+    blockchain.set_state_step(
+        SetStateStep::new()
+            .put_account("address:a_user", Account::new().nonce(0))
+            .put_account("address:user_1", Account::new().nonce(0))
+            .put_account("address:user_2", Account::new().nonce(0))
+            .put_account(
+                "sc:forwarder",
+                Account::new()
+                    .nonce(0)
+                    .balance("1000")
+                    .esdt_balance(FWD_TOKEN_EXPR, "1000")
+                    .code(forwarder_code),
+            ),
+    );
+    blockchain
+}
+
+#[test]
+fn forwarder_direct_non_zero_multi_test() {
+    let mut world = world();
+
+    world.sc_call(
+        ScCallStep::new()
+            .from("address:a_user")
+            .to("sc:forwarder")
+            .function("send_esdt_direct_non_zero_multi")
+            .argument("address:user_1")
+            .argument(FWD_TOKEN_EXPR)
+            .argument("0")
+            .argument("100")
+            .argument(MISSING_TOKEN_EXPR)
+            .argument("0")
+            .argument("0")
+            .expect(TxExpect::ok().no_result()),
+    );
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                "address:user_1",
+                CheckAccount::new().esdt_balance(FWD_TOKEN_EXPR, "100"),
+            )
+            .put_account(
+                "sc:forwarder",
+                CheckAccount::new().esdt_balance(FWD_TOKEN_EXPR, "900"),
+            ),
+    );
+}
+
+#[test]
+fn forwarder_direct_non_zero_multi_all_zero_test() {
+    let mut world = world();
+
+    world.sc_call(
+        ScCallStep::new()
+            .from("address:a_user")
+            .to("sc:forwarder")
+            .function("send_esdt_direct_non_zero_multi")
+            .argument("address:user_1")
+            .argument(MISSING_TOKEN_EXPR)
+            .argument("0")
+            .argument("0")
+            .expect(TxExpect::ok().no_result()),
+    );
+}
+
+#[test]
+fn forwarder_direct_non_zero_egld_batch_test() {
+    let mut world = world();
+
+    world.sc_call(
+        ScCallStep::new()
+            .from("address:a_user")
+            .to("sc:forwarder")
+            .function("send_egld_non_zero_batch")
+            .argument("address:user_1")
+            .argument("300")
+            .argument("address:user_2")
+            .argument("0")
+            .expect(TxExpect::ok().no_result()),
+    );
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account("address:user_1", CheckAccount::new().balance("300"))
+            .put_account("address:user_2", CheckAccount::new().balance("0"))
+            .put_account("sc:forwarder", CheckAccount::new().balance("700")),
+    );
+}
+
+#[test]
+fn forwarder_direct_non_zero_esdt_batch_test() {
+    let mut world = world();
+
+    world.sc_call(
+        ScCallStep::new()
+            .from("address:a_user")
+            .to("sc:forwarder")
+            .function("send_esdt_non_zero_batch")
+            .argument(FWD_TOKEN_EXPR)
+            .argument("address:user_1")
+            .argument("0")
+            .argument("address:user_2")
+            .argument("250")
+            .expect(TxExpect::ok().no_result()),
+    );
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                "address:user_2",
+                CheckAccount::new().esdt_balance(FWD_TOKEN_EXPR, "250"),
+            )
+            .put_account(
+                "sc:forwarder",
+                CheckAccount::new().esdt_balance(FWD_TOKEN_EXPR, "750"),
+            ),
+    );
+
+    // a batch of zero amounts does not attempt any transfer
+    world.sc_call(
+        ScCallStep::new()
+            .from("address:a_user")
+            .to("sc:forwarder")
+            .function("send_esdt_non_zero_batch")
+            .argument(MISSING_TOKEN_EXPR)
+            .argument("address:user_1")
+            .argument("0")
+            .argument("address:user_2")
+            .argument("0")
+            .expect(TxExpect::ok().no_result()),
+    );
+}
@@ -0,0 +1,74 @@
+use multiversx_sc_scenario::{multiversx_chain_vm::world_mock::GasSchedule, scenario_model::*, *};
+
+const FORWARDER_PATH_EXPR: &str = "file:forwarder/output/forwarder.wasm";
+const VAULT_PATH_EXPR: &str = "file:vault/output/vault.wasm";
+
+/// Only the storage writes cost gas, to keep the numbers readable.
+const STORAGE_STORE_COST: u64 = 100_000;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(FORWARDER_PATH_EXPR, forwarder::ContractBuilder);
+    blockchain.register_contract(VAULT_PATH_EXPR, vault::ContractBuilder);
+    blockchain.gas_schedule(GasSchedule {
+        storage_load: 0,
+        storage_load_per_byte: 0,
+        storage_store: STORAGE_STORE_COST,
+        storage_store_per_byte: 0,
+        managed_type_op: 0,
+        builtin_call: 0,
+        contract_call: 0,
+    });
+
+    let forwarder_code = blockchain.code_expression(FORWARDER_PATH_EXPR);
+    let vault_code = blockchain.code_expression(VAULT_PATH_EXPR);
+    blockchain.set_state_step(
+        SetStateStep::new()
+            .put_account("address:a_user", Account::new().nonce(0))
+            .put_account("sc:forwarder", Account::new().nonce(0).code(forwarder_code))
+            .put_account("sc:vault", Account::new().nonce(0).code(vault_code)),
+    );
+    blockchain
+}
+
+/// `echo_arguments_sync` gives half of its gas to the vault, which writes to storage once.
+fn echo_arguments_sync_step(gas_limit: u64) -> ScCallStep {
+    ScCallStep::new()
+        .from("address:a_user")
+        .to("sc:forwarder")
+        .function("echo_arguments_sync")
+        .argument("sc:vault")
+        .argument("1")
+        .gas_limit(gas_limit)
+}
+
+#[test]
+fn forwarder_sync_call_within_requested_gas_test() {
+    let mut world = world();
+
+    let gas_limit = 3 * STORAGE_STORE_COST;
+    world.sc_call(
+        echo_arguments_sync_step(gas_limit).expect(
+            TxExpect::ok()
+                .no_result()
+                .gas(gas_limit - STORAGE_STORE_COST),
+        ),
+    );
+}
+
+#[test]
+fn forwarder_sync_call_over_requested_gas_test() {
+    let mut world = world();
+
+    // enough gas for the whole transaction, but not in the half given to the vault
+    world.sc_call(
+        echo_arguments_sync_step(3 * STORAGE_STORE_COST / 2)
+            .expect(TxExpect::err(5, "str:not enough gas")),
+    );
+    world.check_state_step(CheckStateStep::new().put_account(
+        "sc:vault",
+        CheckAccount::new().check_storage("str:call_counts|nested:str:echo_arguments", ""),
+    ));
+}
@@ -0,0 +1,89 @@
+use multiversx_sc_scenario::{multiversx_chain_vm::world_mock::GasSchedule, scenario_model::*, *};
+
+const PROMISES_FEATURES_PATH_EXPR: &str = "file:promises-features/output/promises-features.wasm";
+const VAULT_PATH_EXPR: &str = "file:vault/output/vault.wasm";
+
+/// Only the storage writes cost gas, so the vault runs out of gas when writing the call count.
+const STORAGE_STORE_COST: u64 = 100_000;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(
+        PROMISES_FEATURES_PATH_EXPR,
+        promises_features::ContractBuilder,
+    );
+    blockchain.register_contract(VAULT_PATH_EXPR, vault::ContractBuilder);
+    blockchain.gas_schedule(GasSchedule {
+        storage_load: 0,
+        storage_load_per_byte: 0,
+        storage_store: STORAGE_STORE_COST,
+        storage_store_per_byte: 0,
+        managed_type_op: 0,
+        builtin_call: 0,
+        contract_call: 0,
+    });
+
+    let promises_features_code = blockchain.code_expression(PROMISES_FEATURES_PATH_EXPR);
+    let vault_code = blockchain.code_expression(VAULT_PATH_EXPR);
+    blockchain.set_state_step(
+        SetStateStep::new()
+            .put_account("address:a_user", Account::new().nonce(0))
+            .put_account(
+                "sc:promises-features",
+                Account::new().nonce(0).code(promises_features_code),
+            )
+            .put_account("sc:vault", Account::new().nonce(0).code(vault_code)),
+    );
+    blockchain
+}
+
+/// The callback stores the outcome category of the call to the vault.
+fn forward_promise_echo_args_step(vault_gas_limit: u64) -> ScCallStep {
+    ScCallStep::new()
+        .from("address:a_user")
+        .to("sc:promises-features")
+        .function("forward_promise_echo_args")
+        .argument("sc:vault")
+        .argument(vault_gas_limit.to_string().as_str())
+        .argument("str:arg")
+        .gas_limit(20_000_000)
+        .expect(TxExpect::ok())
+}
+
+#[test]
+fn promises_callback_result_success_test() {
+    let mut world = world();
+
+    world.sc_call(forward_promise_echo_args_step(2 * STORAGE_STORE_COST));
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                "sc:promises-features",
+                CheckAccount::new().check_storage("str:echo_args_outcome", "str:success"),
+            )
+            .put_account(
+                "sc:vault",
+                CheckAccount::new().check_storage("str:call_counts|nested:str:echo_arguments", "1"),
+            ),
+    );
+}
+
+#[test]
+fn promises_callback_result_out_of_gas_test() {
+    let mut world = world();
+
+    world.sc_call(forward_promise_echo_args_step(STORAGE_STORE_COST / 2));
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                "sc:promises-features",
+                CheckAccount::new().check_storage("str:echo_args_outcome", "str:out of gas"),
+            )
+            .put_account(
+                "sc:vault",
+                CheckAccount::new().check_storage("str:call_counts|nested:str:echo_arguments", ""),
+            ),
+    );
+}
@@ -29,7 +29,7 @@ pub trait TransferRoleFeatures:
             return;
         }
 
-        if !self.blockchain().is_smart_contract(&dest) {
+        if !dest.is_smart_contract() {
             self.transfer_to_user(original_caller, dest, payments.clone_value(), endpoint_name);
         } else {
             let mut args_buffer = ManagedArgBuffer::new();
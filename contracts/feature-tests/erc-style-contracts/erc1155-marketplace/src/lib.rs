@@ -127,7 +127,7 @@ pub trait Erc1155Marketplace {
     fn set_token_ownership_contract_address_endpoint(&self, new_address: ManagedAddress) {
         require!(!new_address.is_zero(), "Cannot set to zero address");
         require!(
-            self.blockchain().is_smart_contract(&new_address),
+            new_address.is_smart_contract(),
             "The provided address is not a smart contract"
         );
 
@@ -51,7 +51,7 @@ pub trait Erc1155 {
     ) {
         self.try_reserve_fungible(&from, &type_id, &amount);
 
-        if self.blockchain().is_smart_contract(&to) {
+        if to.is_smart_contract() {
             self.peform_async_call_single_transfer(from, to, type_id, amount, data);
         } else {
             self.increase_balance(&to, &type_id, &amount);
@@ -68,7 +68,7 @@ pub trait Erc1155 {
     ) {
         self.try_reserve_non_fungible(&from, &type_id, &nft_id);
 
-        if self.blockchain().is_smart_contract(&to) {
+        if to.is_smart_contract() {
             self.peform_async_call_single_transfer(from, to, type_id, nft_id, data);
         } else {
             let amount = BigUint::from(1u32);
@@ -88,7 +88,7 @@ pub trait Erc1155 {
         data: ManagedBuffer,
     ) {
         let caller = self.blockchain().get_caller();
-        let is_receiver_smart_contract = self.blockchain().is_smart_contract(&to);
+        let is_receiver_smart_contract = to.is_smart_contract();
 
         require!(
             caller == from || self.is_approved(&caller, &from).get(),
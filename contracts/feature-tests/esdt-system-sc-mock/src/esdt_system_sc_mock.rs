@@ -79,6 +79,9 @@ pub trait PayableFeatures {
     ) {
     }
 
+    #[endpoint(transferOwnership)]
+    fn transfer_ownership(&self, _token_id: TokenIdentifier, _new_owner: ManagedAddress) {}
+
     #[payable("EGLD")]
     #[endpoint(registerAndSetAllRoles)]
     fn register_and_set_all_roles(
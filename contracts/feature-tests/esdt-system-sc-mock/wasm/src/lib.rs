@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            6
+// Endpoints:                            7
 // Async Callback (empty):               1
-// Total number of exported functions:   8
+// Total number of exported functions:   9
 
 #![no_std]
 #![allow(internal_features)]
@@ -25,6 +25,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         issueSemiFungible => issue_semi_fungible
         registerMetaESDT => issue_meta_esdt
         setSpecialRole => set_special_roles
+        transferOwnership => transfer_ownership
         registerAndSetAllRoles => register_and_set_all_roles
     )
 }
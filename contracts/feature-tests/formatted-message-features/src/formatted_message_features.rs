@@ -1,6 +1,18 @@
 #![no_std]
 
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(SCError, Debug, PartialEq, Eq)]
+pub enum DivisionError {
+    /// The divisor was zero.
+    #[sc_error(code = 1, message = "division by zero")]
+    DivisionByZero,
+
+    /// The dividend is not a multiple of the divisor.
+    #[sc_error(code = 2, message = "inexact division")]
+    InexactDivision,
+}
 
 #[multiversx_sc::contract]
 pub trait FormattedMessageFeatures {
@@ -51,6 +63,32 @@ pub trait FormattedMessageFeatures {
         sc_panic!(DecodeError::UNSUPPORTED_OPERATION,);
     }
 
+    #[endpoint]
+    fn static_message_with_code(&self) {
+        sc_panic!(code = 12, "Static error");
+    }
+
+    #[endpoint]
+    fn dynamic_message_with_code(&self, bytes: ManagedBuffer) {
+        require!(
+            bytes.is_empty(),
+            code = 13,
+            "Got this buffer: {}. I don't like it, ERROR!",
+            bytes
+        );
+    }
+
+    #[endpoint]
+    fn exact_division(&self, dividend: u32, divisor: u32) -> Result<u32, DivisionError> {
+        if divisor == 0 {
+            return Result::Err(DivisionError::DivisionByZero);
+        }
+        if dividend % divisor != 0 {
+            return Result::Err(DivisionError::InexactDivision);
+        }
+        Result::Ok(dividend / divisor)
+    }
+
     /// TODO: figure out a way to test this.
     #[endpoint]
     fn print_message(&self, x: i32) {
@@ -107,4 +145,21 @@ pub trait FormattedMessageFeatures {
         let message = sc_format!("ManagedBuffer hex: {:x}", x);
         message
     }
+
+    #[endpoint]
+    fn print_message_zero_padded(&self, x: i32) {
+        sc_print!("Printing x: {:08}", x);
+    }
+
+    #[endpoint]
+    fn format_message_hex_zero_padded(&self, x: u32) -> ManagedBuffer {
+        let message = sc_format!("u32 hex: {:08x}", x);
+        message
+    }
+
+    #[endpoint]
+    fn format_message_decimals(&self, amount: BigUint) -> ManagedBuffer {
+        let message = sc_format!("Amount: {:.6}", amount);
+        message
+    }
 }
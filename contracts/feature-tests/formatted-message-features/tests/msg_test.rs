@@ -1,4 +1,5 @@
 use formatted_message_features::*;
+use multiversx_sc::types::BigUint;
 use multiversx_sc_scenario::api::StaticApi;
 
 fn check_printed_and_clear(expected: &str) {
@@ -80,3 +81,70 @@ fn test_print_codecs() {
     fmf.print_message_codec(i32::MIN);
     check_printed_and_clear("Printing x: 80000000");
 }
+
+#[test]
+fn test_print_zero_padded() {
+    let fmf = formatted_message_features::contract_obj::<StaticApi>();
+
+    fmf.print_message_zero_padded(5);
+    check_printed_and_clear("Printing x: 00000005");
+
+    fmf.print_message_zero_padded(-5);
+    check_printed_and_clear("Printing x: -0000005");
+
+    fmf.print_message_zero_padded(123456789);
+    check_printed_and_clear("Printing x: 123456789");
+}
+
+#[test]
+fn test_format_hex_zero_padded() {
+    let fmf = formatted_message_features::contract_obj::<StaticApi>();
+
+    let message = fmf.format_message_hex_zero_padded(0xab);
+    assert_eq!(message.to_boxed_bytes().as_slice(), b"u32 hex: 000000ab");
+
+    let message = fmf.format_message_hex_zero_padded(u32::MAX);
+    assert_eq!(message.to_boxed_bytes().as_slice(), b"u32 hex: ffffffff");
+}
+
+#[test]
+fn test_format_decimals() {
+    let fmf = formatted_message_features::contract_obj::<StaticApi>();
+
+    let message = fmf.format_message_decimals(BigUint::from(1_500_000u64));
+    assert_eq!(message.to_boxed_bytes().as_slice(), b"Amount: 1.500000");
+
+    let message = fmf.format_message_decimals(BigUint::from(25u64));
+    assert_eq!(message.to_boxed_bytes().as_slice(), b"Amount: 0.000025");
+
+    let message = fmf.format_message_decimals(BigUint::zero());
+    assert_eq!(message.to_boxed_bytes().as_slice(), b"Amount: 0.000000");
+
+    let message = fmf.format_message_decimals(BigUint::from(123_000_000_000u64));
+    assert_eq!(
+        message.to_boxed_bytes().as_slice(),
+        b"Amount: 123000.000000"
+    );
+}
+
+#[test]
+fn test_exact_division_errors() {
+    use multiversx_sc::types::CodedSCError;
+
+    let fmf = formatted_message_features::contract_obj::<StaticApi>();
+
+    assert_eq!(fmf.exact_division(12, 4), Ok(3));
+    assert_eq!(
+        fmf.exact_division(12, 0),
+        Err(DivisionError::DivisionByZero)
+    );
+    assert_eq!(
+        fmf.exact_division(12, 5),
+        Err(DivisionError::InexactDivision)
+    );
+
+    let err = DivisionError::InexactDivision;
+    assert_eq!(err.error_code(), 2);
+    assert_eq!(err.error_message(), "inexact division");
+    assert_eq!(err.signalled_message(), "[2] inexact division");
+}
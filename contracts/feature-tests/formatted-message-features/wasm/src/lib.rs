@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           16
+// Endpoints:                           22
 // Async Callback (empty):               1
-// Total number of exported functions:  18
+// Total number of exported functions:  24
 
 #![no_std]
 #![allow(internal_features)]
@@ -26,6 +26,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         dynamic_message_multiple => dynamic_message_multiple
         dynamic_message_ascii => dynamic_message_ascii
         decode_error_message => decode_error_message
+        static_message_with_code => static_message_with_code
+        dynamic_message_with_code => dynamic_message_with_code
+        exact_division => exact_division
         print_message => print_message
         print_message_hex => print_message_hex
         print_message_binary => print_message_binary
@@ -36,6 +39,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         format_message_i64 => format_message_i64
         format_message_managed_buffer => format_message_managed_buffer
         format_message_managed_buffer_hex => format_message_managed_buffer_hex
+        print_message_zero_padded => print_message_zero_padded
+        format_message_hex_zero_padded => format_message_hex_zero_padded
+        format_message_decimals => format_message_decimals
     )
 }
 
@@ -3,6 +3,12 @@
 
 multiversx_sc::imports!();
 
+/// Token group accepted by `payable_token_group`, alongside EGLD.
+pub const STABLECOINS: &[&str] = &["USDC-123456", "USDT-123456"];
+
+/// Wrapped EGLD token, accepted by `payable_egld_or_wegld` interchangeably with EGLD.
+pub const WEGLD_TOKEN_ID: &[u8] = b"WEGLD-123456";
+
 /// Contract that only tests the call value features,
 /// i.e. the framework/Arwen functionality for accepting EGLD and ESDT payments.
 #[multiversx_sc::contract]
@@ -113,6 +119,13 @@ pub trait PayableFeatures {
         (payment.clone_value(), token).into()
     }
 
+    #[endpoint]
+    #[payable("*")]
+    fn payable_egld_or_wegld(&self) -> BigUint {
+        let wegld_token_id = TokenIdentifier::from(WEGLD_TOKEN_ID);
+        self.call_value().egld_or_wrapped_egld(&wegld_token_id)
+    }
+
     #[endpoint]
     #[payable("PAYABLE-FEATURES-TOKEN")]
     fn payable_token_1(
@@ -150,4 +163,20 @@ pub trait PayableFeatures {
         let token = self.call_value().single_esdt().token_identifier;
         (payment, token).into()
     }
+
+    #[endpoint]
+    #[payable("PAYABLE-FEATURES-TOKEN", "OTHER-TOKEN")]
+    fn payable_token_list(&self) -> ManagedVec<EsdtTokenPayment> {
+        self.call_value().all_esdt_transfers().clone_value()
+    }
+
+    #[endpoint]
+    #[payable("EGLD", STABLECOINS)]
+    fn payable_token_group(&self) -> MultiValue2<BigUint, ManagedVec<EsdtTokenPayment>> {
+        (
+            self.call_value().egld_value().clone_value(),
+            self.call_value().all_esdt_transfers().clone_value(),
+        )
+            .into()
+    }
 }
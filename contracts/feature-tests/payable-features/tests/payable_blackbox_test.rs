@@ -43,3 +43,26 @@ fn payable_multi() {
                 ),
         );
 }
+
+#[test]
+fn payable_token_group_abi() {
+    use multiversx_sc::contract_base::ContractAbiProvider;
+
+    let abi = payable_features::AbiProvider::abi();
+    let payable_in_tokens = |endpoint_name: &str| {
+        abi.endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == endpoint_name)
+            .unwrap()
+            .payable_in_tokens
+    };
+
+    assert_eq!(
+        payable_in_tokens("payable_token_list"),
+        &["PAYABLE-FEATURES-TOKEN", "OTHER-TOKEN"]
+    );
+    assert_eq!(
+        payable_in_tokens("payable_token_group"),
+        &["EGLD", "USDC-123456", "USDT-123456"]
+    );
+}
@@ -49,6 +49,11 @@ fn payable_egld_4_go() {
     world().run("scenarios/payable_egld_4.scen.json");
 }
 
+#[test]
+fn payable_egld_or_wegld_go() {
+    world().run("scenarios/payable_egld_or_wegld.scen.json");
+}
+
 #[test]
 fn payable_multi_array_go() {
     world().run("scenarios/payable_multi_array.scen.json");
@@ -78,3 +83,8 @@ fn payable_token_3_go() {
 fn payable_token_4_go() {
     world().run("scenarios/payable_token_4.scen.json");
 }
+
+#[test]
+fn payable_token_list_go() {
+    world().run("scenarios/payable_token_list.scen.json");
+}
@@ -55,6 +55,11 @@ fn payable_egld_4_rs() {
     world().run("scenarios/payable_egld_4.scen.json");
 }
 
+#[test]
+fn payable_egld_or_wegld_rs() {
+    world().run("scenarios/payable_egld_or_wegld.scen.json");
+}
+
 #[test]
 fn payable_multi_array_rs() {
     world().run("scenarios/payable_multi_array.scen.json");
@@ -84,3 +89,8 @@ fn payable_token_3_rs() {
 fn payable_token_4_rs() {
     world().run("scenarios/payable_token_4.scen.json");
 }
+
+#[test]
+fn payable_token_list_rs() {
+    world().run("scenarios/payable_token_list.scen.json");
+}
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           15
+// Endpoints:                           18
 // Async Callback (empty):               1
-// Total number of exported functions:  17
+// Total number of exported functions:  20
 
 #![no_std]
 #![allow(internal_features)]
@@ -31,10 +31,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         payable_egld_2 => payable_egld_2
         payable_egld_3 => payable_egld_3
         payable_egld_4 => payable_egld_4
+        payable_egld_or_wegld => payable_egld_or_wegld
         payable_token_1 => payable_token_1
         payable_token_2 => payable_token_2
         payable_token_3 => payable_token_3
         payable_token_4 => payable_token_4
+        payable_token_list => payable_token_list
+        payable_token_group => payable_token_group
     )
 }
 
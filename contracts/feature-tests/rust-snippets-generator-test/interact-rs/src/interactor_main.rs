@@ -5,7 +5,7 @@ use rust_snippets_generator_test::{ProxyTrait as _, *};
 use multiversx_sc_snippets::{
     env_logger,
     erdrs::wallet::Wallet,
-    multiversx_sc::{codec::multi_types::*, types::*},
+    multiversx_sc::{codec::multi_types::*, esdt::EsdtIssueCost, types::*},
     multiversx_sc_scenario::{
         api::StaticApi,
         bech32,
@@ -23,7 +23,7 @@ const SC_ADDRESS: &str = "";
 const SYSTEM_SC_BECH32: &str = "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u";
 const DEFAULT_ADDRESS_EXPR: &str =
     "0x0000000000000000000000000000000000000000000000000000000000000000";
-const TOKEN_ISSUE_COST: u64 = 50_000_000_000_000_000;
+const TOKEN_ISSUE_COST: u64 = EsdtIssueCost::Devnet.egld_amount();
 
 type ContractType = ContractInfo<rust_snippets_generator_test::Proxy<StaticApi>>;
 
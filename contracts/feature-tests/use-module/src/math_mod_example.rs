@@ -0,0 +1,56 @@
+multiversx_sc::imports!();
+
+use multiversx_sc_modules::math;
+
+/// Exposes the math module functions, to be checked against reference values in scenarios.
+#[multiversx_sc::module]
+pub trait MathModExample: math::TwapModule {
+    #[view(mathPowFixed)]
+    fn math_pow_fixed(&self, base: BigUint, exponent: u64, precision: BigUint) -> BigUint {
+        math::pow_fixed(&base, exponent, &precision)
+    }
+
+    #[view(mathCompound)]
+    fn math_compound(
+        &self,
+        principal: BigUint,
+        rate_per_period: BigUint,
+        num_periods: u64,
+        precision: BigUint,
+    ) -> BigUint {
+        math::compound(&principal, &rate_per_period, num_periods, &precision)
+    }
+
+    /// Compounds every second, from the given timestamp until the current block timestamp.
+    #[view(mathCompoundSince)]
+    fn math_compound_since(
+        &self,
+        principal: BigUint,
+        rate_per_second: BigUint,
+        start_timestamp: u64,
+        precision: BigUint,
+    ) -> BigUint {
+        math::compound_over_elapsed_time(
+            &principal,
+            &rate_per_second,
+            start_timestamp,
+            self.blockchain().get_block_timestamp(),
+            &precision,
+        )
+    }
+
+    #[endpoint(twapInit)]
+    fn twap_init_endpoint(&self, capacity: usize) {
+        self.twap_init(capacity);
+    }
+
+    #[endpoint(twapRecordPrice)]
+    fn twap_record_price_endpoint(&self, price: BigUint) {
+        self.twap_record_price(price);
+    }
+
+    #[view(twapPrice)]
+    fn twap_price_view(&self, window_seconds: u64) -> BigUint {
+        self.twap_price(window_seconds)
+    }
+}
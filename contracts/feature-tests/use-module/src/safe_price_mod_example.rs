@@ -0,0 +1,72 @@
+multiversx_sc::imports!();
+
+use multiversx_sc_modules::safe_price;
+
+/// Tests the safe price consumer module.
+///
+/// Also acts as a mock safe price oracle, which values any input at a fixed rate,
+/// so the contract can be configured to query itself.
+#[multiversx_sc::module]
+pub trait SafePriceModExample: safe_price::SafePriceConsumerModule {
+    #[endpoint(setMockSafePrice)]
+    fn set_mock_safe_price(&self, output_token_id: TokenIdentifier, rate_per_unit: BigUint) {
+        self.mock_safe_price_output_token().set(output_token_id);
+        self.mock_safe_price_rate().set(rate_per_unit);
+    }
+
+    #[view(getSafePriceByDefaultOffset)]
+    fn mock_safe_price_by_default_offset(
+        &self,
+        _pair_address: ManagedAddress,
+        input_payment: EsdtTokenPayment,
+    ) -> EsdtTokenPayment {
+        self.mock_safe_price(&input_payment, 1)
+    }
+
+    /// Longer windows are modelled as a slightly lower price, to tell the two views apart.
+    #[view(getSafePriceByTimestampOffset)]
+    fn mock_safe_price_by_timestamp_offset(
+        &self,
+        _pair_address: ManagedAddress,
+        timestamp_offset: u64,
+        input_payment: EsdtTokenPayment,
+    ) -> EsdtTokenPayment {
+        self.mock_safe_price(&input_payment, timestamp_offset)
+    }
+
+    fn mock_safe_price(&self, input: &EsdtTokenPayment, divisor: u64) -> EsdtTokenPayment {
+        let amount = &input.amount * &self.mock_safe_price_rate().get() / divisor;
+        EsdtTokenPayment::new(self.mock_safe_price_output_token().get(), 0, amount)
+    }
+
+    #[endpoint(safePriceLive)]
+    fn safe_price_live(&self, token_id: TokenIdentifier, amount: BigUint) -> EsdtTokenPayment {
+        self.get_safe_price(&EsdtTokenPayment::new(token_id, 0, amount))
+    }
+
+    #[view(safePriceCached)]
+    fn safe_price_cached(&self, token_id: TokenIdentifier, amount: BigUint) -> EsdtTokenPayment {
+        self.get_cached_safe_price(&EsdtTokenPayment::new(token_id, 0, amount))
+    }
+
+    #[endpoint(safePriceNormalized)]
+    fn safe_price_normalized(
+        &self,
+        token_id: TokenIdentifier,
+        amount: BigUint,
+        output_decimals: usize,
+        target_decimals: usize,
+    ) -> BigUint {
+        self.get_safe_price_normalized(
+            &EsdtTokenPayment::new(token_id, 0, amount),
+            output_decimals,
+            target_decimals,
+        )
+    }
+
+    #[storage_mapper("mock_safe_price:output_token")]
+    fn mock_safe_price_output_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("mock_safe_price:rate")]
+    fn mock_safe_price_rate(&self) -> SingleValueMapper<BigUint>;
+}
@@ -7,11 +7,13 @@ mod internal_mod_b;
 mod internal_mod_c;
 mod internal_mod_d;
 mod internal_mod_init;
+mod math_mod_example;
 mod ongoing_operation_mod_example;
 mod only_admin_derived_mod;
 mod only_admin_mod;
 mod only_owner_derived_mod;
 mod only_owner_mod;
+mod safe_price_mod_example;
 pub mod token_merge_mod_impl;
 
 multiversx_sc::imports!();
@@ -22,7 +24,13 @@ multiversx_sc::imports!();
 /// - FeaturesModule
 /// - EsdtModule
 /// - GovernanceModule
+/// - TwapModule
 /// - PauseModule
+/// - PermitModule
+/// - RateLimitModule
+/// - SafePriceConsumerModule
+/// - CallerAllowlistModule
+/// - TimelockModule
 #[multiversx_sc::contract]
 pub trait UseModule:
     ContractBase
@@ -36,7 +44,9 @@ pub trait UseModule:
     + only_owner_derived_mod::OnlyOwnerDerivedTestModule
     + only_admin_mod::OnlyAdminTestModule
     + only_admin_derived_mod::OnlyAdminDerivedTestModule
+    + math_mod_example::MathModExample
     + ongoing_operation_mod_example::OngoingOperationModExample
+    + safe_price_mod_example::SafePriceModExample
     + token_merge_mod_impl::TokenMergeModImpl
     + multiversx_sc_modules::claim_developer_rewards::ClaimDeveloperRewardsModule
     + multiversx_sc_modules::dns::DnsModule
@@ -45,13 +55,19 @@ pub trait UseModule:
     + multiversx_sc_modules::governance::GovernanceModule
     + multiversx_sc_modules::governance::governance_configurable::GovernanceConfigurablePropertiesModule
     + multiversx_sc_modules::governance::governance_events::GovernanceEventsModule
+    + multiversx_sc_modules::math::TwapModule
     + multiversx_sc_modules::pause::PauseModule
+    + multiversx_sc_modules::permit::PermitModule
+    + multiversx_sc_modules::rate_limit::RateLimitModule
+    + multiversx_sc_modules::safe_price::SafePriceConsumerModule
     + multiversx_sc_modules::staking::StakingModule
     + multiversx_sc_modules::token_merge::TokenMergeModule
     + multiversx_sc_modules::token_merge::merged_token_setup::MergedTokenSetupModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::only_admin::OnlyAdminModule
     + multiversx_sc_modules::ongoing_operation::OngoingOperationModule
+    + multiversx_sc_modules::caller_allowlist::CallerAllowlistModule
+    + multiversx_sc_modules::timelock::TimelockModule
 {
     /// Validates that the "featureName" feature is on.
     /// Uses the `feature_guard!` macro.
@@ -64,4 +80,50 @@ pub trait UseModule:
     fn check_pause(&self) -> SCResult<bool> {
         Ok(self.is_paused())
     }
+
+    /// Counts the calls, subject to the limits of the "claim" action.
+    #[endpoint(rateLimitedClaim)]
+    fn rate_limited_claim(&self) -> u64 {
+        self.require_caller_rate_limit(&ManagedBuffer::from(b"claim"));
+        self.num_rate_limited_claims().update(|num_claims| {
+            *num_claims += 1;
+            *num_claims
+        })
+    }
+
+    #[view(getNumRateLimitedClaims)]
+    #[storage_mapper("num_rate_limited_claims")]
+    fn num_rate_limited_claims(&self) -> SingleValueMapper<u64>;
+
+    /// Spends from the allowance that the owner granted the caller via a permit.
+    #[endpoint(spendPermitAllowance)]
+    fn spend_permit_allowance_endpoint(
+        &self,
+        owner: ManagedAddress,
+        token_id: TokenIdentifier,
+        amount: BigUint,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.spend_permit_allowance(&owner, &caller, &token_id, &amount);
+    }
+
+    /// Can only be called by the contracts allowed for this endpoint.
+    #[endpoint(allowlistedCall)]
+    fn allowlisted_call(&self) {
+        self.require_caller_allowed(&ManagedBuffer::from(b"allowlistedCall"));
+    }
+
+    /// Only takes effect once the operation was queued and the timelock delay has passed.
+    #[only_owner]
+    #[endpoint(setTimelockedFee)]
+    fn set_timelocked_fee(&self, fee: BigUint) {
+        let mut arguments = ManagedArgBuffer::new();
+        arguments.push_arg(&fee);
+        self.execute_timelocked_operation(&ManagedBuffer::from(b"setTimelockedFee"), &arguments);
+        self.timelocked_fee().set(fee);
+    }
+
+    #[view(getTimelockedFee)]
+    #[storage_mapper("timelocked_fee")]
+    fn timelocked_fee(&self) -> SingleValueMapper<BigUint>;
 }
@@ -0,0 +1,27 @@
+use multiversx_sc_meta::abi_json;
+use multiversx_sc_scenario::*;
+
+/// Fails whenever the contract interface changes without the snapshots being updated,
+/// with `sc-meta all abi-snapshot`.
+#[test]
+fn use_module_abi_snapshot_test() {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+
+    let multi_contract_config = multiversx_sc_meta::multi_contract_config::<use_module::AbiProvider>(
+        blockchain
+            .current_dir()
+            .join("multicontract.toml")
+            .to_str()
+            .unwrap(),
+    );
+    for contract_name in ["use-module", "use-module-view"] {
+        let output_contract = multi_contract_config.find_contract(contract_name);
+        abi_json::check_abi_snapshot(
+            &output_contract.abi,
+            blockchain
+                .current_dir()
+                .join(output_contract.abi_snapshot_name()),
+        );
+    }
+}
@@ -4,6 +4,11 @@ fn world() -> ScenarioWorld {
     ScenarioWorld::vm_go()
 }
 
+#[test]
+fn use_module_caller_allowlist_go() {
+    world().run("scenarios/use_module_caller_allowlist.scen.json");
+}
+
 #[test]
 fn use_module_claim_developer_rewards_go() {
     world().run("scenarios/use_module_claim_developer_rewards.scen.json");
@@ -24,6 +29,11 @@ fn use_module_internal_go() {
     world().run("scenarios/use_module_internal.scen.json");
 }
 
+#[test]
+fn use_module_math_go() {
+    world().run("scenarios/use_module_math.scen.json");
+}
+
 #[test]
 fn use_module_no_endpoint_go() {
     world().run("scenarios/use_module_no_endpoint.scen.json");
@@ -48,3 +58,23 @@ fn use_module_only_owner_go() {
 fn use_module_pause_go() {
     world().run("scenarios/use_module_pause.scen.json");
 }
+
+#[test]
+fn use_module_permit_go() {
+    world().run("scenarios/use_module_permit.scen.json");
+}
+
+#[test]
+fn use_module_rate_limit_go() {
+    world().run("scenarios/use_module_rate_limit.scen.json");
+}
+
+#[test]
+fn use_module_safe_price_go() {
+    world().run("scenarios/use_module_safe_price.scen.json");
+}
+
+#[test]
+fn use_module_timelock_go() {
+    world().run("scenarios/use_module_timelock.scen.json");
+}
@@ -43,6 +43,11 @@ fn world() -> ScenarioWorld {
     blockchain
 }
 
+#[test]
+fn use_module_caller_allowlist_rs() {
+    world().run("scenarios/use_module_caller_allowlist.scen.json");
+}
+
 #[test]
 fn use_module_claim_developer_rewards_rs() {
     world().run("scenarios/use_module_claim_developer_rewards.scen.json");
@@ -63,6 +68,11 @@ fn use_module_internal_rs() {
     world().run("scenarios/use_module_internal.scen.json");
 }
 
+#[test]
+fn use_module_math_rs() {
+    world().run("scenarios/use_module_math.scen.json");
+}
+
 #[test]
 fn use_module_no_endpoint_rs() {
     world().run("scenarios/use_module_no_endpoint.scen.json");
@@ -89,3 +99,23 @@ fn use_module_only_owner_rs() {
 fn use_module_pause_rs() {
     world().run("scenarios/use_module_pause.scen.json");
 }
+
+#[test]
+fn use_module_permit_rs() {
+    world().run("scenarios/use_module_permit.scen.json");
+}
+
+#[test]
+fn use_module_rate_limit_rs() {
+    world().run("scenarios/use_module_rate_limit.scen.json");
+}
+
+#[test]
+fn use_module_safe_price_rs() {
+    world().run("scenarios/use_module_safe_price.scen.json");
+}
+
+#[test]
+fn use_module_timelock_rs() {
+    world().run("scenarios/use_module_timelock.scen.json");
+}
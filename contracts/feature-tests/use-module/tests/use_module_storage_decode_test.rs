@@ -0,0 +1,48 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+const USE_MODULE_PATH_EXPR: &str = "file:output/use-module.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain.register_contract_abi::<use_module::AbiProvider>(USE_MODULE_PATH_EXPR);
+    blockchain
+}
+
+#[test]
+fn use_module_storage_decode_test() {
+    let mut world = world();
+    let use_module_code = world.code_expression(USE_MODULE_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:owner", Account::new())
+            .put_account(
+                "sc:use-module",
+                Account::new().code(use_module_code).owner("address:owner"),
+            ),
+    );
+    world.sc_call(
+        ScCallStep::new()
+            .from("address:owner")
+            .to("sc:use-module")
+            .function("setCooldown")
+            .argument("str:claim")
+            .argument("100"),
+    );
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        world.check_state_step(CheckStateStep::new().put_account(
+            "sc:use-module",
+            CheckAccount::new().check_storage("str:rate_limit:cooldown|nested:str:claim", "200"),
+        ));
+    }));
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+
+    // the key and the actual value are also shown decoded, based on the ABI
+    assert!(message.contains(
+        "storage str:rate_limit:cooldown|nested:str:claim (cooldown(action: \"claim\"))"
+    ));
+    assert!(message.contains("have: 0x64 (100)"));
+}
@@ -0,0 +1,71 @@
+use multiversx_sc::contract_base::ContractAbiProvider;
+
+mod whitelist_module {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::module]
+    pub trait WhitelistModule {
+        #[storage_mapper("members")]
+        fn whitelist(&self) -> UnorderedSetMapper<ManagedAddress>;
+    }
+}
+
+mod members_view_module {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::module]
+    pub trait MembersViewModule {
+        #[storage_is_empty("members")]
+        fn no_members(&self) -> bool;
+    }
+}
+
+mod registry_module {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::module]
+    pub trait RegistryModule {
+        #[storage_mapper("members")]
+        fn registry(&self) -> UnorderedSetMapper<ManagedAddress>;
+    }
+}
+
+mod shared_storage {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::contract]
+    pub trait SharedStorage:
+        super::whitelist_module::WhitelistModule + super::members_view_module::MembersViewModule
+    {
+        #[init]
+        fn init(&self) {}
+    }
+}
+
+mod clashing_storage {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::contract]
+    pub trait ClashingStorage:
+        super::whitelist_module::WhitelistModule + super::registry_module::RegistryModule
+    {
+        #[init]
+        fn init(&self) {}
+    }
+}
+
+#[test]
+fn storage_key_shared_across_modules_test() {
+    let abi = shared_storage::AbiProvider::abi();
+    assert_eq!(abi.storage_keys.len(), 1);
+    assert_eq!(abi.storage_keys[0].name, "whitelist");
+    assert_eq!(abi.storage_keys[0].mapper, Some("UnorderedSetMapper"));
+}
+
+#[test]
+#[should_panic(
+    expected = "Storage key `members` is used by both method `whitelist` and method `registry`: only one storage mapper can be declared per key."
+)]
+fn storage_key_clash_across_modules_test() {
+    let _ = clashing_storage::AbiProvider::abi();
+}
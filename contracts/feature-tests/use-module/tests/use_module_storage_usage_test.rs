@@ -0,0 +1,78 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+const USE_MODULE_PATH_EXPR: &str = "file:output/use-module.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn use_module_storage_usage_test() {
+    let mut world = world();
+    let mut use_module_account = Account::new()
+        .code(world.code_expression(USE_MODULE_PATH_EXPR))
+        .owner("address:owner");
+    use_module_account.storage.insert(
+        BytesKey::from("str:unknown-key"),
+        BytesValue::from("str:abc"),
+    );
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:owner", Account::new())
+            .put_account("address:user1", Account::new())
+            .put_account("address:user2", Account::new())
+            .put_account("sc:use-module", use_module_account)
+            // a zero timestamp would leave the last call storage empty
+            .block_timestamp(100),
+    );
+    world.sc_call(
+        ScCallStep::new()
+            .from("address:owner")
+            .to("sc:use-module")
+            .function("setCooldown")
+            .argument("str:claim")
+            .argument("100"),
+    );
+    for user in ["address:user1", "address:user2"] {
+        world.sc_call(
+            ScCallStep::new()
+                .from(user)
+                .to("sc:use-module")
+                .function("rateLimitedClaim"),
+        );
+    }
+
+    let report = world.storage_usage::<use_module::AbiProvider>("sc:use-module");
+
+    // one entry per user, under the base key, followed by the action and the address
+    let last_call_timestamp = report.get("last_call_timestamp").unwrap();
+    assert_eq!(last_call_timestamp.num_entries, 2);
+    assert_eq!(
+        last_call_timestamp.key_bytes,
+        2 * ("rate_limit:last_call_timestamp".len() + 4 + "claim".len() + 32)
+    );
+
+    let cooldown = report.get("cooldown").unwrap();
+    assert_eq!(cooldown.num_entries, 1);
+    assert_eq!(cooldown.value_bytes, 1);
+
+    assert_eq!(
+        report.get("num_rate_limited_claims").unwrap().num_entries,
+        1
+    );
+    assert_eq!(report.get("max_calls_per_block").unwrap().num_entries, 0);
+    assert!(report.get("not_a_mapper").is_none());
+
+    assert_eq!(report.unattributed.num_entries, 1);
+    assert_eq!(report.unattributed.total_bytes(), "unknown-key".len() + 3);
+    assert_eq!(report.total().num_entries, 5);
+
+    assert_eq!(report.mappers[0].name, "last_call_timestamp");
+    assert!(report
+        .to_string()
+        .contains("last_call_timestamp (\"rate_limit:last_call_timestamp\"): 2 entries"));
+}
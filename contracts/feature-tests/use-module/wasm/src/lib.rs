@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           62
+// Endpoints:                          105
 // Async Callback:                       1
-// Total number of exported functions:  64
+// Total number of exported functions: 107
 
 #![no_std]
 #![allow(internal_features)]
@@ -22,6 +22,12 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => constructor_in_a_module
         checkFeatureGuard => check_feature_guard
         checkPause => check_pause
+        rateLimitedClaim => rate_limited_claim
+        getNumRateLimitedClaims => num_rate_limited_claims
+        spendPermitAllowance => spend_permit_allowance_endpoint
+        allowlistedCall => allowlisted_call
+        setTimelockedFee => set_timelocked_fee
+        getTimelockedFee => timelocked_fee
         call_contract_base_full_path_endpoint => call_contract_base_full_path_endpoint
         call_contract_base_endpoint => call_contract_base_endpoint
         call_mod_a => call_mod_a
@@ -31,7 +37,19 @@ multiversx_sc_wasm_adapter::endpoints! {
         call_derived_not_owner_only => call_derived_not_owner_only
         only_admin_mod_endpoint => only_admin_mod_endpoint
         call_derived_not_admin_only => call_derived_not_admin_only
+        mathPowFixed => math_pow_fixed
+        mathCompound => math_compound
+        mathCompoundSince => math_compound_since
+        twapInit => twap_init_endpoint
+        twapRecordPrice => twap_record_price_endpoint
+        twapPrice => twap_price_view
         countTo100 => count_to_100
+        setMockSafePrice => set_mock_safe_price
+        getSafePriceByDefaultOffset => mock_safe_price_by_default_offset
+        getSafePriceByTimestampOffset => mock_safe_price_by_timestamp_offset
+        safePriceLive => safe_price_live
+        safePriceCached => safe_price_cached
+        safePriceNormalized => safe_price_normalized
         mergeTokens => merge_tokens_endpoint
         mergeTokensCustomAttributes => merge_tokens_custom_attributes_endpoint
         splitTokens => split_tokens_endpoint
@@ -66,9 +84,24 @@ multiversx_sc_wasm_adapter::endpoints! {
         getVotingDelayInBlocks => voting_delay_in_blocks
         getVotingPeriodInBlocks => voting_period_in_blocks
         getLockTimeAfterVotingEndsInBlocks => lock_time_after_voting_ends_in_blocks
+        getTwapCapacity => twap_capacity
+        getTwapNumObservations => twap_num_observations
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
+        permit => permit_endpoint
+        getPermitMessage => permit_message
+        getPermitNonce => permit_nonce
+        getPermitAllowance => permit_allowance
+        setCooldown => set_cooldown_endpoint
+        setMaxCallsPerBlock => set_max_calls_per_block_endpoint
+        getCooldown => cooldown
+        getMaxCallsPerBlock => max_calls_per_block
+        getLastCallTimestamp => last_call_timestamp
+        setSafePriceFeed => set_safe_price_feed
+        refreshSafePrice => refresh_safe_price
+        getSafePriceFeed => safe_price_feed
+        getCachedSafePrice => safe_price_cache
         stake => stake
         unstake => unstake
         voteSlashMember => vote_slash_member
@@ -82,6 +115,16 @@ multiversx_sc_wasm_adapter::endpoints! {
         addAdmin => add_admin
         removeAdmin => remove_admin
         getAdmins => admins
+        addAllowedCallers => add_allowed_callers
+        removeAllowedCallers => remove_allowed_callers
+        isCallerAllowed => is_caller_allowed
+        getAllowedCallers => allowed_callers
+        queueOperation => queue_operation
+        cancelOperation => cancel_operation
+        setTimelockDelay => set_timelock_delay
+        getPendingOperations => get_pending_operations
+        isOperationReady => is_operation_ready
+        getTimelockDelay => timelock_delay
     )
 }
 
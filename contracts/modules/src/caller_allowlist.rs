@@ -0,0 +1,81 @@
+multiversx_sc::imports!();
+
+/// Standard smart contract module that, when added to a smart contract,
+/// restricts which contracts are allowed to call certain endpoints.
+///
+/// It formalizes the common "only pair contracts may call" pattern.
+/// Allowlists are kept per endpoint name, and only smart contract addresses can be registered.
+///
+/// It offers:
+/// * endpoints where the owner (usually a governance contract) can register or unregister callers, in batches
+/// * events for each registered or unregistered caller
+/// * a method to be called at the beginning of restricted endpoints
+///
+#[multiversx_sc::module]
+pub trait CallerAllowlistModule {
+    /// Checks that the caller is registered for the given endpoint.
+    fn require_caller_allowed(&self, endpoint_name: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.allowed_callers(endpoint_name).contains(&caller),
+            "caller not allowed"
+        );
+    }
+
+    #[only_owner]
+    #[endpoint(addAllowedCallers)]
+    fn add_allowed_callers(
+        &self,
+        endpoint_name: ManagedBuffer,
+        callers: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let mut allowed_callers = self.allowed_callers(&endpoint_name);
+        for caller in callers {
+            require!(
+                caller.is_smart_contract(),
+                "only smart contracts can be allowed callers"
+            );
+            if allowed_callers.insert(caller.clone()) {
+                self.allowed_caller_added_event(&endpoint_name, &caller);
+            }
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeAllowedCallers)]
+    fn remove_allowed_callers(
+        &self,
+        endpoint_name: ManagedBuffer,
+        callers: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let mut allowed_callers = self.allowed_callers(&endpoint_name);
+        for caller in callers {
+            if allowed_callers.swap_remove(&caller) {
+                self.allowed_caller_removed_event(&endpoint_name, &caller);
+            }
+        }
+    }
+
+    #[view(isCallerAllowed)]
+    fn is_caller_allowed(&self, endpoint_name: ManagedBuffer, address: ManagedAddress) -> bool {
+        self.allowed_callers(&endpoint_name).contains(&address)
+    }
+
+    #[event("allowedCallerAdded")]
+    fn allowed_caller_added_event(
+        &self,
+        #[indexed] endpoint_name: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+    );
+
+    #[event("allowedCallerRemoved")]
+    fn allowed_caller_removed_event(
+        &self,
+        #[indexed] endpoint_name: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+    );
+
+    #[view(getAllowedCallers)]
+    #[storage_mapper("caller_allowlist_module:allowed_callers")]
+    fn allowed_callers(&self, endpoint_name: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+}
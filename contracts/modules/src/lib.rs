@@ -2,16 +2,22 @@
 #![feature(trait_alias)]
 
 pub mod bonding_curve;
+pub mod caller_allowlist;
 pub mod claim_developer_rewards;
 pub mod default_issue_callbacks;
 pub mod dns;
 pub mod esdt;
 pub mod features;
 pub mod governance;
+pub mod math;
 pub mod ongoing_operation;
 pub mod only_admin;
 pub mod pause;
+pub mod permit;
+pub mod rate_limit;
+pub mod safe_price;
 pub mod staking;
+pub mod timelock;
 pub mod token_merge;
 pub mod transfer_role_proxy;
 pub mod users;
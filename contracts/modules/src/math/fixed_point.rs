@@ -0,0 +1,62 @@
+multiversx_sc::imports!();
+
+/// Multiplies two fixed-point numbers, rounding down.
+///
+/// The precision is the representation of 1, e.g. 10^18 for 18 decimals.
+pub fn mul_fixed<M: ManagedTypeApi>(
+    a: &BigUint<M>,
+    b: &BigUint<M>,
+    precision: &BigUint<M>,
+) -> BigUint<M> {
+    &(a * b) / precision
+}
+
+/// Raises a fixed-point number to an integer power, by repeated squaring.
+///
+/// Every intermediate product is rounded down, so the result can be slightly below the exact value.
+pub fn pow_fixed<M: ManagedTypeApi>(
+    base: &BigUint<M>,
+    exponent: u64,
+    precision: &BigUint<M>,
+) -> BigUint<M> {
+    let mut result = precision.clone();
+    let mut base = base.clone();
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_fixed(&result, &base, precision);
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = mul_fixed(&base, &base, precision);
+        }
+    }
+    result
+}
+
+/// The value of a principal after compounding at a fixed-point rate for a number of periods.
+///
+/// E.g. a rate of 10^16 with a precision of 10^18 means 1% per period.
+pub fn compound<M: ManagedTypeApi>(
+    principal: &BigUint<M>,
+    rate_per_period: &BigUint<M>,
+    num_periods: u64,
+    precision: &BigUint<M>,
+) -> BigUint<M> {
+    let factor = pow_fixed(&(precision + rate_per_period), num_periods, precision);
+    mul_fixed(principal, &factor, precision)
+}
+
+/// The value of a principal after compounding every second between two timestamps.
+///
+/// No interest is added if the end timestamp is not after the start timestamp.
+pub fn compound_over_elapsed_time<M: ManagedTypeApi>(
+    principal: &BigUint<M>,
+    rate_per_second: &BigUint<M>,
+    start_timestamp: u64,
+    end_timestamp: u64,
+    precision: &BigUint<M>,
+) -> BigUint<M> {
+    let elapsed_seconds = end_timestamp.saturating_sub(start_timestamp);
+    compound(principal, rate_per_second, elapsed_seconds, precision)
+}
@@ -0,0 +1,5 @@
+mod fixed_point;
+mod twap;
+
+pub use fixed_point::*;
+pub use twap::*;
@@ -0,0 +1,126 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// A price recorded at some point in time,
+/// together with the sum of all previous prices weighted by the time they were in effect.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct TwapObservation<M: ManagedTypeApi> {
+    pub timestamp: u64,
+    pub price: BigUint<M>,
+    pub price_cumulative: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> TwapObservation<M> {
+    /// The cumulative price at a later moment, assuming the price didn't change in the meantime.
+    pub fn price_cumulative_at(&self, timestamp: u64) -> BigUint<M> {
+        &self.price_cumulative + &(&self.price * (timestamp - self.timestamp))
+    }
+}
+
+/// Standard smart contract module that, when added to a smart contract, offers a time-weighted average price (TWAP) oracle.
+///
+/// Prices are recorded in a ring buffer of observations, with a fixed capacity.
+/// The oldest observations get overwritten, so the capacity limits how far back the average can go.
+///
+/// It offers:
+/// * a method to record the current price, e.g. after every swap
+/// * a method to compute the average price over a window of time ending now
+///
+#[multiversx_sc::module]
+pub trait TwapModule {
+    /// Sets the number of observations kept. Can only be done before recording any price.
+    fn twap_init(&self, capacity: usize) {
+        require!(capacity > 0, "TWAP capacity must be positive");
+        require!(
+            self.twap_num_observations().get() == 0,
+            "TWAP observations already recorded"
+        );
+        self.twap_capacity().set(capacity);
+    }
+
+    /// Records the price at the current block timestamp.
+    ///
+    /// Recording several prices in the same block only keeps the last one.
+    fn twap_record_price(&self, price: BigUint) {
+        let capacity = self.twap_capacity().get();
+        require!(capacity > 0, "TWAP capacity not set");
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let num_observations = self.twap_num_observations().get();
+        if num_observations == 0 {
+            self.twap_observation(0).set(TwapObservation {
+                timestamp: current_timestamp,
+                price,
+                price_cumulative: BigUint::zero(),
+            });
+            self.twap_newest_index().set(0);
+            self.twap_num_observations().set(1);
+            return;
+        }
+
+        let newest_index = self.twap_newest_index().get();
+        let newest = self.twap_observation(newest_index).get();
+        if newest.timestamp == current_timestamp {
+            self.twap_observation(newest_index)
+                .set(TwapObservation { price, ..newest });
+            return;
+        }
+
+        let next_index = (newest_index + 1) % capacity;
+        self.twap_observation(next_index).set(TwapObservation {
+            timestamp: current_timestamp,
+            price,
+            price_cumulative: newest.price_cumulative_at(current_timestamp),
+        });
+        self.twap_newest_index().set(next_index);
+        if num_observations < capacity {
+            self.twap_num_observations().set(num_observations + 1);
+        }
+    }
+
+    /// The average price over the last `window_seconds` seconds, weighted by time.
+    ///
+    /// Fails if the oldest observation kept is more recent than the start of the window.
+    fn twap_price(&self, window_seconds: u64) -> BigUint {
+        require!(window_seconds > 0, "TWAP window must be positive");
+        let num_observations = self.twap_num_observations().get();
+        require!(num_observations > 0, "no TWAP observations");
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            current_timestamp >= window_seconds,
+            "TWAP window exceeds the chain history"
+        );
+        let window_start = current_timestamp - window_seconds;
+
+        let capacity = self.twap_capacity().get();
+        let newest_index = self.twap_newest_index().get();
+        let newest = self.twap_observation(newest_index).get();
+        let price_cumulative_now = newest.price_cumulative_at(current_timestamp);
+
+        for age in 0..num_observations {
+            let index = (newest_index + capacity - age) % capacity;
+            let observation = self.twap_observation(index).get();
+            if observation.timestamp <= window_start {
+                let price_cumulative_start = observation.price_cumulative_at(window_start);
+                return (price_cumulative_now - price_cumulative_start) / window_seconds;
+            }
+        }
+
+        sc_panic!("not enough TWAP observations for the given window")
+    }
+
+    #[view(getTwapCapacity)]
+    #[storage_mapper("twap:capacity")]
+    fn twap_capacity(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("twap:observation")]
+    fn twap_observation(&self, index: usize) -> SingleValueMapper<TwapObservation<Self::Api>>;
+
+    #[storage_mapper("twap:newest_index")]
+    fn twap_newest_index(&self) -> SingleValueMapper<usize>;
+
+    #[view(getTwapNumObservations)]
+    #[storage_mapper("twap:num_observations")]
+    fn twap_num_observations(&self) -> SingleValueMapper<usize>;
+}
@@ -0,0 +1,148 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Prepended to the encoded permit message, before signing.
+///
+/// Makes sure that a permit signature can never be mistaken for the signature of anything else, e.g. a transaction.
+pub const PERMIT_MESSAGE_PREFIX: &[u8] = b"permit:";
+
+/// The approval that token owners sign off-chain.
+///
+/// It is bound to the contract that accepts it, and to the current permit nonce of the owner,
+/// so it can only be used once, and only in one place.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct PermitMessage<M: ManagedTypeApi> {
+    pub contract: ManagedAddress<M>,
+    pub owner: ManagedAddress<M>,
+    pub spender: ManagedAddress<M>,
+    pub token_id: TokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub nonce: u64,
+    pub deadline: u64,
+}
+
+impl<M: ManagedTypeApi> PermitMessage<M> {
+    /// The bytes that the owner needs to sign: the prefix, followed by the top-encoded message.
+    pub fn to_signed_bytes(&self) -> ManagedBuffer<M> {
+        let mut signed_bytes = ManagedBuffer::new_from_bytes(PERMIT_MESSAGE_PREFIX);
+        let mut encoded = ManagedBuffer::new();
+        let _ = self.top_encode(&mut encoded);
+        signed_bytes.append(&encoded);
+        signed_bytes
+    }
+}
+
+/// Standard smart contract module that, when added to a smart contract, allows token owners
+/// to grant allowances by signing a message off-chain, instead of sending a transaction.
+///
+/// Anyone can submit the signed permit, e.g. a relayer, or the spender itself,
+/// so the owner does not need to pay any gas to authorize the spender.
+///
+/// The signature is verified using the ed25519 hook, against the owner address,
+/// which is the owner's public key.
+///
+/// It offers:
+/// * an endpoint that accepts signed permits and records the allowance
+/// * a method to spend from an allowance, to be used by the contract when acting on behalf of the owner
+/// * views for the allowances, nonces and the message that needs to be signed
+///
+#[multiversx_sc::module]
+pub trait PermitModule {
+    /// Verifies the owner signature and sets the allowance of the spender.
+    ///
+    /// The previous allowance, if any, is overwritten, not increased.
+    #[endpoint(permit)]
+    fn permit_endpoint(
+        &self,
+        owner: ManagedAddress,
+        spender: ManagedAddress,
+        token_id: TokenIdentifier,
+        amount: BigUint,
+        deadline: u64,
+        signature: ManagedBuffer,
+    ) {
+        require!(
+            self.blockchain().get_block_timestamp() <= deadline,
+            "permit expired"
+        );
+
+        let message = self.permit_message(owner, spender, token_id, amount, deadline);
+        self.crypto().verify_ed25519(
+            message.owner.as_managed_buffer(),
+            &message.to_signed_bytes(),
+            &signature,
+        );
+
+        self.permit_nonce(&message.owner).set(message.nonce + 1);
+        self.permit_allowance(&message.owner, &message.spender, &message.token_id)
+            .set(&message.amount);
+        self.permit_event(
+            &message.owner,
+            &message.spender,
+            &message.token_id,
+            &message.amount,
+        );
+    }
+
+    /// Deducts an amount from the allowance the owner granted the spender.
+    ///
+    /// Fails if the allowance is not large enough.
+    fn spend_permit_allowance(
+        &self,
+        owner: &ManagedAddress,
+        spender: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        self.permit_allowance(owner, spender, token_id)
+            .update(|allowance| {
+                require!(&*allowance >= amount, "insufficient allowance");
+                *allowance -= amount;
+            });
+    }
+
+    /// The message the owner needs to sign, for the next permit.
+    #[view(getPermitMessage)]
+    fn permit_message(
+        &self,
+        owner: ManagedAddress,
+        spender: ManagedAddress,
+        token_id: TokenIdentifier,
+        amount: BigUint,
+        deadline: u64,
+    ) -> PermitMessage<Self::Api> {
+        let nonce = self.permit_nonce(&owner).get();
+        PermitMessage {
+            contract: self.blockchain().get_sc_address(),
+            owner,
+            spender,
+            token_id,
+            amount,
+            nonce,
+            deadline,
+        }
+    }
+
+    #[event("permit")]
+    fn permit_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] spender: &ManagedAddress,
+        #[indexed] token_id: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Number of permits already used by the owner. The next permit needs to be signed with this nonce.
+    #[view(getPermitNonce)]
+    #[storage_mapper("permit_module:nonce")]
+    fn permit_nonce(&self, owner: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[view(getPermitAllowance)]
+    #[storage_mapper("permit_module:allowance")]
+    fn permit_allowance(
+        &self,
+        owner: &ManagedAddress,
+        spender: &ManagedAddress,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,100 @@
+multiversx_sc::imports!();
+
+/// Standard smart contract module that, when added to a smart contract, offers rate limiting for endpoints.
+///
+/// Limits are configured per action, where an action is an arbitrary name, usually the endpoint name.
+/// Two kinds of limits are provided:
+/// * a per-address cooldown: an address needs to wait a number of seconds between two calls
+/// * a per-block call cap: at most a number of calls can be made in the same block, by all addresses
+///
+/// A value of 0 disables the corresponding limit. Both limits are disabled by default.
+///
+/// It offers:
+/// * endpoints where the owner can configure the limits
+/// * a method to be called at the beginning of rate limited endpoints, e.g. faucets or claims
+///
+#[multiversx_sc::module]
+pub trait RateLimitModule {
+    /// Checks and records a call of the given action by the given address.
+    /// Fails if either the address cooldown or the block call cap forbid it.
+    fn require_rate_limit(&self, action: &ManagedBuffer, address: &ManagedAddress) {
+        self.require_cooldown_passed(action, address);
+        self.require_block_call_cap(action);
+    }
+
+    /// Checks and records a call of the given action by the caller.
+    fn require_caller_rate_limit(&self, action: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_rate_limit(action, &caller);
+    }
+
+    fn require_cooldown_passed(&self, action: &ManagedBuffer, address: &ManagedAddress) {
+        let cooldown = self.cooldown(action).get();
+        if cooldown == 0 {
+            return;
+        }
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let last_call_mapper = self.last_call_timestamp(action, address);
+        if !last_call_mapper.is_empty() {
+            require!(
+                current_timestamp >= last_call_mapper.get() + cooldown,
+                "action on cooldown"
+            );
+        }
+        last_call_mapper.set(current_timestamp);
+    }
+
+    fn require_block_call_cap(&self, action: &ManagedBuffer) {
+        let max_calls = self.max_calls_per_block(action).get();
+        if max_calls == 0 {
+            return;
+        }
+
+        let current_block = self.blockchain().get_block_nonce();
+        let block_calls_mapper = self.block_calls(action);
+        let mut num_calls = 0;
+        if !block_calls_mapper.is_empty() {
+            let (last_block, last_block_num_calls) = block_calls_mapper.get();
+            if last_block == current_block {
+                num_calls = last_block_num_calls;
+            }
+        }
+        require!(num_calls < max_calls, "block call cap reached");
+        block_calls_mapper.set((current_block, num_calls + 1));
+    }
+
+    /// Time an address needs to wait before calling the action again, in seconds.
+    #[only_owner]
+    #[endpoint(setCooldown)]
+    fn set_cooldown_endpoint(&self, action: ManagedBuffer, cooldown_seconds: u64) {
+        self.cooldown(&action).set(cooldown_seconds);
+    }
+
+    /// Maximum number of calls of the action allowed in the same block.
+    #[only_owner]
+    #[endpoint(setMaxCallsPerBlock)]
+    fn set_max_calls_per_block_endpoint(&self, action: ManagedBuffer, max_calls: u64) {
+        self.max_calls_per_block(&action).set(max_calls);
+    }
+
+    #[view(getCooldown)]
+    #[storage_mapper("rate_limit:cooldown")]
+    fn cooldown(&self, action: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[view(getMaxCallsPerBlock)]
+    #[storage_mapper("rate_limit:max_calls_per_block")]
+    fn max_calls_per_block(&self, action: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[view(getLastCallTimestamp)]
+    #[storage_mapper("rate_limit:last_call_timestamp")]
+    fn last_call_timestamp(
+        &self,
+        action: &ManagedBuffer,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+
+    /// The last block in which the action was called, and the number of calls in that block.
+    #[storage_mapper("rate_limit:block_calls")]
+    fn block_calls(&self, action: &ManagedBuffer) -> SingleValueMapper<(u64, u64)>;
+}
@@ -0,0 +1,206 @@
+pub mod safe_price_view_proxy {
+    multiversx_sc::imports!();
+
+    /// Views of the xExchange safe price view contract.
+    ///
+    /// Both return the value of the input payment in the other token of the pair,
+    /// computed from the time-weighted average reserves of the pair.
+    #[multiversx_sc::proxy]
+    pub trait SafePriceView {
+        #[view(getSafePriceByDefaultOffset)]
+        fn get_safe_price_by_default_offset(
+            &self,
+            pair_address: ManagedAddress,
+            input_payment: EsdtTokenPayment,
+        ) -> EsdtTokenPayment;
+
+        #[view(getSafePriceByTimestampOffset)]
+        fn get_safe_price_by_timestamp_offset(
+            &self,
+            pair_address: ManagedAddress,
+            timestamp_offset: u64,
+            input_payment: EsdtTokenPayment,
+        ) -> EsdtTokenPayment;
+    }
+}
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Where and how to get the safe price of a token.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct SafePriceFeed<M: ManagedTypeApi> {
+    pub oracle_address: ManagedAddress<M>,
+    pub pair_address: ManagedAddress<M>,
+
+    /// The averaging window, in seconds. 0 means the oracle default.
+    pub timestamp_offset: u64,
+
+    /// How long a cached price can be used, in seconds.
+    pub max_staleness: u64,
+
+    /// The input amount the cached price is obtained for, e.g. one whole token.
+    pub reference_amount: BigUint<M>,
+}
+
+/// The last price obtained from the oracle, for the reference amount of the feed.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct CachedSafePrice<M: ManagedTypeApi> {
+    pub input_amount: BigUint<M>,
+    pub output: EsdtTokenPayment<M>,
+    pub timestamp: u64,
+}
+
+/// Converts an amount between two decimal representations, rounding down.
+pub fn normalize_decimals<M: ManagedTypeApi>(
+    amount: &BigUint<M>,
+    from_decimals: usize,
+    to_decimals: usize,
+) -> BigUint<M> {
+    if from_decimals <= to_decimals {
+        amount * &BigUint::from(10u32).pow((to_decimals - from_decimals) as u32)
+    } else {
+        amount / &BigUint::from(10u32).pow((from_decimals - to_decimals) as u32)
+    }
+}
+
+/// Standard smart contract module that consumes xExchange-style safe price oracles.
+///
+/// Safe prices are time-weighted averages, so they cannot be moved within a single transaction,
+/// which makes them suitable for valuing collateral.
+///
+/// The oracle can only be called synchronously from the same shard.
+/// Anyone can cache the price of the reference amount configured for a token,
+/// so that it can also be used later,
+/// as long as it is not older than the maximum staleness configured for the token.
+///
+/// It offers:
+/// * an endpoint where the owner can configure the price feed of each token
+/// * an endpoint where anyone can refresh the cached price of a token
+/// * methods to get the live or cached price, and to normalize amounts to a number of decimals
+///
+#[multiversx_sc::module]
+pub trait SafePriceConsumerModule {
+    #[proxy]
+    fn safe_price_view_proxy(&self, to: ManagedAddress) -> safe_price_view_proxy::Proxy<Self::Api>;
+
+    /// Queries the oracle for the value of the input payment.
+    fn get_safe_price(&self, input: &EsdtTokenPayment) -> EsdtTokenPayment {
+        let feed_mapper = self.safe_price_feed(&input.token_identifier);
+        require!(!feed_mapper.is_empty(), "no safe price feed for token");
+        require!(input.amount > 0u32, "zero input amount");
+        self.query_safe_price(&feed_mapper.get(), input)
+    }
+
+    fn query_safe_price(
+        &self,
+        feed: &SafePriceFeed<Self::Api>,
+        input: &EsdtTokenPayment,
+    ) -> EsdtTokenPayment {
+        let mut proxy = self.safe_price_view_proxy(feed.oracle_address.clone());
+        let output: EsdtTokenPayment = if feed.timestamp_offset == 0 {
+            proxy
+                .get_safe_price_by_default_offset(feed.pair_address.clone(), input.clone())
+                .execute_on_dest_context()
+        } else {
+            proxy
+                .get_safe_price_by_timestamp_offset(
+                    feed.pair_address.clone(),
+                    feed.timestamp_offset,
+                    input.clone(),
+                )
+                .execute_on_dest_context()
+        };
+        require!(output.amount > 0u32, "invalid safe price");
+        output
+    }
+
+    /// Values the input payment using the last cached price, scaled to the input amount.
+    ///
+    /// Fails if the cached price is older than the maximum staleness of the feed.
+    fn get_cached_safe_price(&self, input: &EsdtTokenPayment) -> EsdtTokenPayment {
+        let feed_mapper = self.safe_price_feed(&input.token_identifier);
+        require!(!feed_mapper.is_empty(), "no safe price feed for token");
+        let cache_mapper = self.safe_price_cache(&input.token_identifier);
+        require!(!cache_mapper.is_empty(), "no cached safe price");
+
+        let cached = cache_mapper.get();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            current_timestamp <= cached.timestamp + feed_mapper.get().max_staleness,
+            "safe price is stale"
+        );
+
+        let amount = &cached.output.amount * &input.amount / &cached.input_amount;
+        EsdtTokenPayment::new(cached.output.token_identifier, 0, amount)
+    }
+
+    /// The value of the input payment, converted to a given number of decimals.
+    fn get_safe_price_normalized(
+        &self,
+        input: &EsdtTokenPayment,
+        output_decimals: usize,
+        target_decimals: usize,
+    ) -> BigUint {
+        let output = self.get_safe_price(input);
+        normalize_decimals(&output.amount, output_decimals, target_decimals)
+    }
+
+    #[only_owner]
+    #[endpoint(setSafePriceFeed)]
+    fn set_safe_price_feed(
+        &self,
+        token_id: TokenIdentifier,
+        oracle_address: ManagedAddress,
+        pair_address: ManagedAddress,
+        timestamp_offset: u64,
+        max_staleness: u64,
+        reference_amount: BigUint,
+    ) {
+        require!(oracle_address.is_smart_contract(), "invalid oracle address");
+        require!(reference_amount > 0u32, "zero reference amount");
+        self.safe_price_feed(&token_id).set(SafePriceFeed {
+            oracle_address,
+            pair_address,
+            timestamp_offset,
+            max_staleness,
+            reference_amount,
+        });
+        self.safe_price_cache(&token_id).clear();
+    }
+
+    /// Updates the cached price of a token.
+    ///
+    /// The price is always obtained for the reference amount of the feed,
+    /// so that callers cannot skew later quotes through rounding on tiny amounts.
+    #[endpoint(refreshSafePrice)]
+    fn refresh_safe_price(&self, token_id: TokenIdentifier) -> EsdtTokenPayment {
+        let feed_mapper = self.safe_price_feed(&token_id);
+        require!(!feed_mapper.is_empty(), "no safe price feed for token");
+        let feed = feed_mapper.get();
+
+        let input = EsdtTokenPayment::new(token_id, 0, feed.reference_amount.clone());
+        let output = self.query_safe_price(&feed, &input);
+        self.safe_price_cache(&input.token_identifier)
+            .set(CachedSafePrice {
+                input_amount: input.amount,
+                output: output.clone(),
+                timestamp: self.blockchain().get_block_timestamp(),
+            });
+        output
+    }
+
+    #[view(getSafePriceFeed)]
+    #[storage_mapper("safe_price:feed")]
+    fn safe_price_feed(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<SafePriceFeed<Self::Api>>;
+
+    #[view(getCachedSafePrice)]
+    #[storage_mapper("safe_price:cache")]
+    fn safe_price_cache(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<CachedSafePrice<Self::Api>>;
+}
@@ -0,0 +1,189 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type TimelockOperationId = u64;
+
+/// The action name under which changes of the delay itself are queued.
+pub const SET_TIMELOCK_DELAY_ACTION: &[u8] = b"setTimelockDelay";
+
+/// An owner operation that was announced, but not yet executed.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct TimelockOperation<M: ManagedTypeApi> {
+    /// Arbitrary name, usually the name of the endpoint that performs the operation.
+    pub action: ManagedBuffer<M>,
+    /// The top-encoded arguments the operation will be executed with.
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+    /// The operation cannot be executed before this timestamp, in seconds.
+    pub ready_timestamp: u64,
+}
+
+impl<M: ManagedTypeApi> TimelockOperation<M> {
+    fn has_arguments(&self, arguments: &ManagedArgBuffer<M>) -> bool {
+        self.arguments.len() == arguments.len()
+            && self
+                .arguments
+                .iter()
+                .zip(arguments.raw_arg_iter())
+                .all(|(stored, arg)| *stored == *arg)
+    }
+}
+
+/// Standard smart contract module that, when added to a smart contract,
+/// delays selected owner operations, so users get the chance to react before they take effect.
+///
+/// Operations are identified by an action name, usually the endpoint name, and by their arguments.
+/// The owner first queues the operation, then, once the delay has passed, calls the timelocked endpoint
+/// with exactly the same arguments. Queued operations can be cancelled at any time before that.
+///
+/// A delay of 0 disables the timelock, which is the default. Changing the delay is itself timelocked.
+/// The delay is applied when queueing, so changing it does not affect operations that are already queued.
+///
+/// It offers:
+/// * endpoints where the owner can queue and cancel operations, and change the delay
+/// * events for each queued, executed or cancelled operation
+/// * views of the pending operations
+/// * a method to be called at the beginning of timelocked endpoints
+///
+#[multiversx_sc::module]
+pub trait TimelockModule {
+    /// Checks that the operation was queued with the same arguments and that its delay has passed,
+    /// then removes it from the queue.
+    ///
+    /// The arguments need to be pushed to the buffer in the same order as the endpoint arguments.
+    fn execute_timelocked_operation(
+        &self,
+        action: &ManagedBuffer,
+        arguments: &ManagedArgBuffer<Self::Api>,
+    ) {
+        if self.timelock_delay().get() == 0 {
+            return;
+        }
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut found_not_ready = false;
+        let mut ready_operation_id = None;
+        for (operation_id, operation) in self.pending_operations().iter() {
+            if operation.action != *action || !operation.has_arguments(arguments) {
+                continue;
+            }
+            if operation.ready_timestamp <= current_timestamp {
+                ready_operation_id = Some(operation_id);
+                break;
+            }
+            found_not_ready = true;
+        }
+
+        let operation_id = match ready_operation_id {
+            Some(operation_id) => operation_id,
+            None => {
+                require!(!found_not_ready, "timelocked operation not ready");
+                sc_panic!("timelocked operation not queued");
+            },
+        };
+        self.pending_operations().remove(&operation_id);
+        self.operation_executed_event(operation_id, action);
+    }
+
+    /// Announces an owner operation. It can be executed once the current delay has passed.
+    #[only_owner]
+    #[endpoint(queueOperation)]
+    fn queue_operation(
+        &self,
+        action: ManagedBuffer,
+        arguments: MultiValueEncoded<ManagedBuffer>,
+    ) -> TimelockOperationId {
+        let ready_timestamp = self.blockchain().get_block_timestamp() + self.timelock_delay().get();
+        let operation_id = self.last_operation_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        let operation = TimelockOperation {
+            action,
+            arguments: arguments.into_vec_of_buffers(),
+            ready_timestamp,
+        };
+        self.operation_queued_event(
+            operation_id,
+            &operation.action,
+            ready_timestamp,
+            &operation.arguments,
+        );
+        self.pending_operations().insert(operation_id, operation);
+        operation_id
+    }
+
+    #[only_owner]
+    #[endpoint(cancelOperation)]
+    fn cancel_operation(&self, operation_id: TimelockOperationId) {
+        let operation = self
+            .pending_operations()
+            .remove(&operation_id)
+            .unwrap_or_else(|| sc_panic!("timelocked operation not found"));
+        self.operation_cancelled_event(operation_id, &operation.action);
+    }
+
+    /// Time between queueing an operation and executing it, in seconds.
+    #[only_owner]
+    #[endpoint(setTimelockDelay)]
+    fn set_timelock_delay(&self, delay_seconds: u64) {
+        let mut arguments = ManagedArgBuffer::new();
+        arguments.push_arg(delay_seconds);
+        self.execute_timelocked_operation(
+            &ManagedBuffer::from(SET_TIMELOCK_DELAY_ACTION),
+            &arguments,
+        );
+        self.timelock_delay().set(delay_seconds);
+    }
+
+    #[view(getPendingOperations)]
+    fn get_pending_operations(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<TimelockOperationId, TimelockOperation<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for (operation_id, operation) in self.pending_operations().iter() {
+            result.push((operation_id, operation).into());
+        }
+        result
+    }
+
+    #[view(isOperationReady)]
+    fn is_operation_ready(&self, operation_id: TimelockOperationId) -> bool {
+        match self.pending_operations().get(&operation_id) {
+            Some(operation) => operation.ready_timestamp <= self.blockchain().get_block_timestamp(),
+            None => false,
+        }
+    }
+
+    #[event("timelockOperationQueued")]
+    fn operation_queued_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] action: &ManagedBuffer,
+        #[indexed] ready_timestamp: u64,
+        arguments: &ManagedVec<ManagedBuffer>,
+    );
+
+    #[event("timelockOperationExecuted")]
+    fn operation_executed_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] action: &ManagedBuffer,
+    );
+
+    #[event("timelockOperationCancelled")]
+    fn operation_cancelled_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] action: &ManagedBuffer,
+    );
+
+    #[view(getTimelockDelay)]
+    #[storage_mapper("timelock_module:delay")]
+    fn timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timelock_module:last_operation_id")]
+    fn last_operation_id(&self) -> SingleValueMapper<TimelockOperationId>;
+
+    #[storage_mapper("timelock_module:pending_operations")]
+    fn pending_operations(&self) -> MapMapper<TimelockOperationId, TimelockOperation<Self::Api>>;
+}
@@ -40,6 +40,36 @@ where
     }
 }
 
+/// Error types that endpoints can return, as the `Err` of a `Result`.
+///
+/// They never get encoded as results. Instead, the output signals them, which stops execution.
+pub trait SignalError {
+    fn signal_error_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler;
+}
+
+/// Allows endpoints to return `Result`.
+///
+/// The error gets signalled, it is never returned as a regular result.
+impl<T, E> TopEncodeMulti for Result<T, E>
+where
+    T: TopEncodeMulti,
+    E: SignalError,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        match self {
+            Ok(t) => t.multi_encode_or_handle_err(output, h),
+            Err(e) => e.signal_error_or_handle_err(output, h),
+        }
+    }
+}
+
 pub fn multi_encode_iter_or_handle_err<T, Iter, O, H>(
     iterator: Iter,
     output: &mut O,
@@ -56,3 +86,44 @@ where
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    struct TestError;
+
+    impl SignalError for TestError {
+        fn signal_error_or_handle_err<O, H>(
+            &self,
+            _output: &mut O,
+            h: H,
+        ) -> Result<(), H::HandledErr>
+        where
+            O: TopEncodeMultiOutput,
+            H: EncodeErrorHandler,
+        {
+            Err(h.handle_error(EncodeError::UNSUPPORTED_OPERATION))
+        }
+    }
+
+    #[test]
+    fn test_result_ok_encoded() {
+        let mut output = Vec::<Vec<u8>>::new();
+        let result: Result<u32, TestError> = Ok(5);
+        result.multi_encode(&mut output).unwrap();
+        assert_eq!(output, [[5u8].to_vec()]);
+    }
+
+    #[test]
+    fn test_result_err_signalled() {
+        let mut output = Vec::<Vec<u8>>::new();
+        let result: Result<u32, TestError> = Err(TestError);
+        assert_eq!(
+            result.multi_encode(&mut output),
+            Err(EncodeError::UNSUPPORTED_OPERATION)
+        );
+        assert!(output.is_empty());
+    }
+}
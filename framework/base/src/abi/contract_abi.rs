@@ -7,9 +7,11 @@ pub struct ContractAbi {
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub constructors: Vec<EndpointAbi>,
+    pub upgrade_constructors: Vec<EndpointAbi>,
     pub endpoints: Vec<EndpointAbi>,
     pub promise_callbacks: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
+    pub storage_keys: Vec<StorageKeyAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
 }
@@ -18,10 +20,15 @@ impl ContractAbi {
     pub fn coalesce(&mut self, other: Self) {
         self.constructors
             .extend_from_slice(other.constructors.as_slice());
+        self.upgrade_constructors
+            .extend_from_slice(other.upgrade_constructors.as_slice());
         self.endpoints.extend_from_slice(other.endpoints.as_slice());
         self.events.extend_from_slice(other.events.as_slice());
         self.promise_callbacks
             .extend_from_slice(other.promise_callbacks.as_slice());
+        for storage_key in other.storage_keys {
+            self.add_storage_key(storage_key);
+        }
         self.has_callback |= other.has_callback;
         self.type_descriptions.insert_all(&other.type_descriptions);
     }
@@ -32,6 +39,38 @@ impl ContractAbi {
         T::provide_type_descriptions(&mut self.type_descriptions);
     }
 
+    /// Storage getters, setters and mappers can share the same key, it is only recorded once.
+    /// If one of them is a mapper, it is the one recorded, since it describes how the data is laid out.
+    ///
+    /// Panics if two different mappers use the same key, or if the declarations disagree on
+    /// the layout, the value type or the number of key arguments, since they would corrupt each other's data.
+    /// The same declaration can be added several times, e.g. when a module is included by several others.
+    pub fn add_storage_key(&mut self, storage_key: StorageKeyAbi) {
+        let existing = match self
+            .storage_keys
+            .iter_mut()
+            .find(|sk| sk.key == storage_key.key)
+        {
+            Some(existing) => existing,
+            None => {
+                self.storage_keys.push(storage_key);
+                return;
+            },
+        };
+        if *existing == storage_key {
+            return;
+        }
+        if let Some(clash) = existing.clash(&storage_key) {
+            panic!(
+                "Storage key `{}` is used by both method `{}` and method `{}`: {clash}.",
+                storage_key.key, existing.name, storage_key.name,
+            );
+        }
+        if existing.mapper.is_none() && storage_key.mapper.is_some() {
+            *existing = storage_key;
+        }
+    }
+
     /// Contract main crate name.
     pub fn get_crate_name(&self) -> &str {
         self.build_info.contract_crate.name
@@ -49,10 +88,11 @@ impl ContractAbi {
         }
     }
 
-    /// All exported functions: init, endpoints, promises callbacks.
+    /// All exported functions: init, upgrade, endpoints, promises callbacks.
     pub fn iter_all_exports(&self) -> impl Iterator<Item = &EndpointAbi> {
         self.constructors
             .iter()
+            .chain(self.upgrade_constructors.iter())
             .chain(self.endpoints.iter())
             .chain(self.promise_callbacks.iter())
     }
@@ -30,10 +30,20 @@ pub enum EndpointMutabilityAbi {
 pub enum EndpointTypeAbi {
     #[default]
     Init,
+    Upgrade,
     Endpoint,
     PromisesCallback,
 }
 
+/// Marks an endpoint that is kept for backwards compatibility, but should no longer be called.
+#[derive(Clone, Default, Debug)]
+pub struct EndpointDeprecationAbi {
+    /// The contract version that deprecated the endpoint.
+    pub since: &'static str,
+    /// Optional hint for callers, e.g. which endpoint to use instead. Empty if missing.
+    pub note: &'static str,
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct EndpointAbi {
     pub docs: &'static [&'static str],
@@ -42,11 +52,17 @@ pub struct EndpointAbi {
     pub only_owner: bool,
     pub only_admin: bool,
     pub labels: &'static [&'static str],
+    /// Additional names under which the endpoint can be called, usually its names prior to a rename.
+    pub aliases: &'static [&'static str],
+    pub deprecated: Option<EndpointDeprecationAbi>,
+    /// Marked with `#[test_endpoint]`. Only exported by output contracts that enable `test-endpoints`.
+    pub test_only: bool,
     pub endpoint_type: EndpointTypeAbi,
     pub mutability: EndpointMutabilityAbi,
     pub payable_in_tokens: &'static [&'static str],
     pub inputs: Vec<InputAbi>,
     pub outputs: OutputAbis,
+    pub errors: Vec<ErrorAbi>,
 }
 
 impl EndpointAbi {
@@ -63,6 +79,11 @@ impl EndpointAbi {
             .extend_from_slice(T::output_abis(output_names).as_slice());
     }
 
+    /// Only called for endpoints returning `Result<T, E>`.
+    pub fn add_errors<E: SCErrorAbi>(&mut self) {
+        self.errors.extend(E::error_abis());
+    }
+
     pub fn endpoint_with_name_and_labels(
         name: &'static str,
         labels: &'static [&'static str],
@@ -0,0 +1,60 @@
+use super::*;
+use alloc::vec::Vec;
+
+struct EndpointOrigin {
+    endpoint_name: &'static str,
+    trait_name: &'static str,
+    rust_method_name: &'static str,
+    labels: &'static [&'static str],
+}
+
+/// Keeps track of the contract or module trait that declared each endpoint,
+/// while the ABIs of a contract and of its modules are being merged.
+///
+/// Two traits declaring the same endpoint name would otherwise only surface as a confusing error,
+/// so the clash is reported with both traits and both methods involved.
+///
+/// Endpoints with different labels can share a name, since they end up in different output contracts.
+#[derive(Default)]
+pub struct EndpointOrigins {
+    origins: Vec<EndpointOrigin>,
+}
+
+impl EndpointOrigins {
+    /// Registers all exported functions of a contract or module ABI, together with their aliases.
+    ///
+    /// Panics if any of them was already declared by another trait.
+    pub fn add_all(&mut self, abi: &ContractAbi) {
+        for endpoint in abi.iter_all_exports() {
+            for &endpoint_name in core::iter::once(&endpoint.name).chain(endpoint.aliases) {
+                self.add(EndpointOrigin {
+                    endpoint_name,
+                    trait_name: abi.name,
+                    rust_method_name: endpoint.rust_method_name,
+                    labels: endpoint.labels,
+                });
+            }
+        }
+    }
+
+    fn add(&mut self, origin: EndpointOrigin) {
+        if let Some(existing) = self.origins.iter().find(|existing| {
+            existing.endpoint_name == origin.endpoint_name
+                && same_labels(existing.labels, origin.labels)
+        }) {
+            panic!(
+                "Endpoint `{}` is declared twice: by method `{}` in `{}` and by method `{}` in `{}`.",
+                origin.endpoint_name,
+                existing.rust_method_name,
+                existing.trait_name,
+                origin.rust_method_name,
+                origin.trait_name,
+            );
+        }
+        self.origins.push(origin);
+    }
+}
+
+fn same_labels(labels1: &[&str], labels2: &[&str]) -> bool {
+    labels1.len() == labels2.len() && labels1.iter().all(|label| labels2.contains(label))
+}
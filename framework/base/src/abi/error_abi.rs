@@ -0,0 +1,39 @@
+use alloc::vec::Vec;
+
+use crate::{
+    api::{ErrorApi, ManagedTypeApi},
+    types::{ManagedSCError, StaticSCError},
+};
+
+/// One of the errors an endpoint can return, identified by a stable code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorAbi {
+    pub docs: &'static [&'static str],
+    pub name: &'static str,
+    pub code: u32,
+    pub message: &'static str,
+}
+
+/// Error types that endpoints can return, as `Result<T, E>`.
+///
+/// Error enums get it via `#[derive(SCError)]`, which lists all their variants.
+pub trait SCErrorAbi {
+    fn error_abis() -> Vec<ErrorAbi>;
+}
+
+/// Free-form errors, no codes to list.
+impl SCErrorAbi for StaticSCError {
+    fn error_abis() -> Vec<ErrorAbi> {
+        Vec::new()
+    }
+}
+
+/// Free-form errors, no codes to list.
+impl<M> SCErrorAbi for ManagedSCError<M>
+where
+    M: ManagedTypeApi + ErrorApi,
+{
+    fn error_abis() -> Vec<ErrorAbi> {
+        Vec::new()
+    }
+}
@@ -1,4 +1,9 @@
 use super::*;
+use crate::{
+    api::ManagedTypeApi,
+    log_util::STANDARD_EVENT_TOPIC_NAMES,
+    types::{BigUint, EsdtTokenPayment, ManagedAddress, ManagedVec},
+};
 use alloc::vec::Vec;
 
 #[derive(Clone, Debug)]
@@ -23,4 +28,12 @@ impl EventAbi {
             indexed,
         });
     }
+
+    /// Declares the topics pushed by events with `standard_topics`, ahead of the explicit ones.
+    pub fn add_standard_topics<M: ManagedTypeApi>(&mut self) {
+        let [caller, egld_value, esdt_transfers] = STANDARD_EVENT_TOPIC_NAMES;
+        self.add_input::<ManagedAddress<M>>(caller, true);
+        self.add_input::<BigUint<M>>(egld_value, true);
+        self.add_input::<ManagedVec<M, EsdtTokenPayment<M>>>(esdt_transfers, true);
+    }
 }
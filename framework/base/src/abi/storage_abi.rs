@@ -0,0 +1,80 @@
+use alloc::{format, string::String};
+
+/// Storage entry point declared by a contract or module, i.e. a storage mapper, getter, setter, etc.
+///
+/// Only part of the ABI JSON when explicitly requested, via `sc-meta abi --storage`.
+/// It is used in tests and tooling, to figure out which part of the code a storage entry belongs to,
+/// or to compute the storage keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageKeyAbi {
+    /// Name of the method declaring the storage.
+    pub name: &'static str,
+
+    /// The base key. Arguments, as well as the suffixes used internally by mappers, are appended to it.
+    pub key: &'static str,
+
+    /// The storage mapper type, e.g. `"VecMapper"`. `None` for getters, setters, etc.
+    pub mapper: Option<&'static str>,
+
+    /// The arguments appended to the base key, nested-encoded, in order.
+    pub inputs: &'static [StorageKeyInputAbi],
+
+    /// The type of the stored value, or of the items, for mappers that hold several.
+    ///
+    /// Inferred from the Rust type, `None` if unknown.
+    pub value_type: Option<&'static str>,
+}
+
+impl StorageKeyAbi {
+    /// How the data is laid out under the base key, e.g. `"VecMapper"`.
+    ///
+    /// Getters and setters store the value directly under the key, like a `SingleValueMapper`.
+    /// `None` for the methods that only check or clear the key, which work with any layout.
+    pub fn storage_layout(&self) -> Option<&'static str> {
+        match self.mapper {
+            Some(mapper) => Some(mapper),
+            None if self.value_type.is_some() => Some("SingleValueMapper"),
+            None => None,
+        }
+    }
+
+    /// Why two declarations cannot share the same base key, `None` if they can.
+    ///
+    /// Only one mapper is allowed per key, the other declarations must agree with it
+    /// on the layout, the value type and the number of key arguments.
+    pub fn clash(&self, other: &StorageKeyAbi) -> Option<String> {
+        if self.mapper.is_some() && other.mapper.is_some() {
+            return Some("only one storage mapper can be declared per key".into());
+        }
+        if let (Some(layout), Some(other_layout)) = (self.storage_layout(), other.storage_layout())
+        {
+            if layout != other_layout {
+                return Some(format!("layouts `{layout}` and `{other_layout}` differ"));
+            }
+        }
+        if let (Some(value_type), Some(other_value_type)) = (self.value_type, other.value_type) {
+            if value_type != other_value_type {
+                return Some(format!(
+                    "value types `{value_type}` and `{other_value_type}` differ"
+                ));
+            }
+        }
+        if self.inputs.len() != other.inputs.len() {
+            return Some(format!(
+                "{} and {} key arguments",
+                self.inputs.len(),
+                other.inputs.len()
+            ));
+        }
+        None
+    }
+}
+
+/// Argument of a storage method that is part of the key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageKeyInputAbi {
+    pub arg_name: &'static str,
+
+    /// Inferred from the Rust type, since storage arguments are not required to implement `TypeAbi`.
+    pub type_name: &'static str,
+}
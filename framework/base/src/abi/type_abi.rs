@@ -64,3 +64,29 @@ pub fn type_name_optional<T: TypeAbi>() -> TypeName {
     repr.push('>');
     repr
 }
+
+/// Name of a generic type, as it appears in the ABI, for one concrete set of type arguments.
+///
+/// E.g. `Pair<u32, List<BigUint>>` becomes `Pair_u32_List_BigUint`.
+/// The brackets and commas of the type argument names are replaced,
+/// so that each expansion gets its own readable entry in the ABI type section.
+pub fn type_name_monomorphized(base_name: &str, type_arg_names: &[TypeName]) -> TypeName {
+    let mut repr = TypeName::from(base_name);
+    for type_arg_name in type_arg_names {
+        repr.push('_');
+        let mut last_is_separator = true;
+        for c in type_arg_name.chars() {
+            if c.is_ascii_alphanumeric() {
+                repr.push(c);
+                last_is_separator = false;
+            } else if !last_is_separator {
+                repr.push('_');
+                last_is_separator = true;
+            }
+        }
+        if last_is_separator {
+            repr.pop();
+        }
+    }
+    repr
+}
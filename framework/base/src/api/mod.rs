@@ -14,6 +14,7 @@ mod send_api;
 mod storage_api;
 pub mod uncallable;
 mod vm_api;
+mod vm_hooks_version;
 
 pub use blockchain_api::*;
 pub use builtin_function_names::*;
@@ -30,3 +31,4 @@ pub use print_api::*;
 pub use send_api::*;
 pub use storage_api::*;
 pub use vm_api::VMApi;
+pub use vm_hooks_version::*;
@@ -0,0 +1,32 @@
+/// Environment variable through which sc-meta passes the configured EI version (e.g. `"1.2"`) to the contract build.
+///
+/// The EI (environment interface) version determines which VM hooks are available on the target VM.
+pub const TARGET_EI_VERSION_ENV_VAR: &str = "MX_SC_TARGET_EI_VERSION";
+
+/// Name of the `--cfg` key through which sc-meta passes the EI version of the `--target-vm`, e.g. `mx_target_ei="1.2"`.
+///
+/// Framework APIs that need a newer EI version are compile errors when it is set.
+pub const TARGET_EI_CFG: &str = "mx_target_ei";
+
+/// The EI version the contract is built for, as configured in sc-meta (`ei` in multicontract.toml).
+///
+/// It is `None` when not building via sc-meta, e.g. in tests, or when the EI check is explicitly ignored.
+/// In these cases all VM hooks are assumed to be available.
+pub const TARGET_EI_VERSION: Option<&str> = option_env!("MX_SC_TARGET_EI_VERSION");
+
+/// Parses an EI version name, such as `"1.3"`, into its major and minor components.
+pub fn parse_ei_version(name: &str) -> Option<(u32, u32)> {
+    let (major, minor) = name.split_once('.')?;
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+/// Capability query: whether the VM hooks introduced in the given EI version are available on the target VM.
+///
+/// Framework and contract code can use it to pick between a newer hook and a fallback.
+/// Since the target version is a constant, the unused branch gets optimized away.
+pub fn target_ei_supports(major: u32, minor: u32) -> bool {
+    match TARGET_EI_VERSION.and_then(parse_ei_version) {
+        Some(target) => target >= (major, minor),
+        None => true,
+    }
+}
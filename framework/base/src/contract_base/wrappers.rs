@@ -3,6 +3,7 @@ mod call_value_wrapper;
 mod callback_args_wrapper;
 mod crypto_wrapper;
 mod error_helper;
+mod error_message_table;
 mod send_raw_wrapper;
 mod send_wrapper;
 mod serializer;
@@ -12,7 +13,8 @@ pub use blockchain_wrapper::BlockchainWrapper;
 pub use call_value_wrapper::CallValueWrapper;
 pub use callback_args_wrapper::CallbackArgApiWrapper;
 pub use crypto_wrapper::CryptoWrapper;
-pub use error_helper::ErrorHelper;
+pub use error_helper::{split_error_code, ErrorHelper};
+pub use error_message_table::{error_message_ends, ErrorMessage, ErrorMessageTable};
 pub(crate) use send_raw_wrapper::SendRawWrapper;
 pub use send_wrapper::SendWrapper;
 pub use serializer::{ExitCodecErrorHandler, ManagedSerializer};
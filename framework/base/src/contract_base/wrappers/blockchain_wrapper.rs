@@ -8,7 +8,7 @@ use crate::{
     },
     codec::TopDecode,
     err_msg::{ONLY_OWNER_CALLER, ONLY_USER_ACCOUNT_CALLER},
-    storage::{self},
+    storage::{self, StorageKey},
     types::{
         BigUint, EgldOrEsdtTokenIdentifier, EsdtLocalRoleFlags, EsdtTokenData, EsdtTokenType,
         ManagedAddress, ManagedBuffer, ManagedByteArray, ManagedType, ManagedVec, TokenIdentifier,
@@ -103,6 +103,18 @@ where
         A::blockchain_api_impl().get_shard_of_address(address.get_handle())
     }
 
+    /// The shard of the current contract.
+    pub fn get_current_shard(&self) -> u32 {
+        self.get_shard_of_address(&self.get_sc_address())
+    }
+
+    /// True if the given address is in the same shard as the current contract.
+    ///
+    /// Synchronous calls are only possible in this case.
+    pub fn is_same_shard(&self, address: &ManagedAddress<A>) -> bool {
+        self.get_shard_of_address(address) == self.get_current_shard()
+    }
+
     #[deprecated(
         since = "0.41.0",
         note = "Please use method `is_smart_contract` instead."
@@ -410,6 +422,20 @@ where
         //wrap
         BigUint::from_handle(result_handle)
     }
+
+    /// Reads a value from the storage of another contract, and deserializes it to the provided type.
+    ///
+    /// The target contract must be readable (see `CodeMetadata::READABLE`),
+    /// otherwise its storage is seen as empty.
+    /// This is a synchronous read, so it only works if both contracts are in the same shard.
+    pub fn read_storage_from_address<K, V>(&self, address: &ManagedAddress<A>, storage_key: K) -> V
+    where
+        K: Into<StorageKey<A>>,
+        V: TopDecode,
+    {
+        let key: StorageKey<A> = storage_key.into();
+        storage::storage_get_from_address(address.as_ref(), key.as_ref())
+    }
 }
 
 fn esdt_is_frozen(properties_bytes: &[u8; 2]) -> bool {
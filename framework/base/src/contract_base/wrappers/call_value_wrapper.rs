@@ -7,7 +7,7 @@ use crate::{
     },
     err_msg,
     types::{
-        BigUint, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, EgldOrMultiEsdtPayment,
+        BigUint, Egld, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, EgldOrMultiEsdtPayment,
         EsdtTokenPayment, ManagedRef, ManagedVec, TokenIdentifier,
     },
 };
@@ -43,6 +43,11 @@ where
         unsafe { ManagedRef::wrap_handle(call_value_handle) }
     }
 
+    /// The EGLD call value, as an `Egld` amount.
+    pub fn egld_amount(&self) -> Egld<A> {
+        Egld::from_wei(self.egld_value().clone_value())
+    }
+
     /// Returns all ESDT transfers that accompany this SC call.
     /// Will return 0 results if nothing was transfered, or just EGLD.
     /// Fully managed underlying types, very efficient.
@@ -129,6 +134,42 @@ where
         (payment.token_identifier, payment.amount)
     }
 
+    /// Accepts and returns either an EGLD payment, or a single ESDT token,
+    /// with wrapped EGLD treated the same as EGLD.
+    ///
+    /// If the received ESDT is the given wrapped EGLD token, the result is an EGLD payment of the same amount,
+    /// so that callers do not need to handle the two cases separately.
+    ///
+    /// Will halt execution if more than one ESDT transfer was received.
+    ///
+    /// In case no transfer of value happen, it will return a payment of 0 EGLD.
+    pub fn egld_or_single_esdt_normalized(
+        &self,
+        wrapped_egld_token_id: &TokenIdentifier<A>,
+    ) -> EgldOrEsdtTokenPayment<A> {
+        let payment = self.egld_or_single_esdt();
+        if payment.token_identifier == *wrapped_egld_token_id {
+            EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, payment.amount)
+        } else {
+            payment
+        }
+    }
+
+    /// Expects either an EGLD payment, or a single transfer of the given wrapped EGLD token.
+    ///
+    /// Returns the amount, which is the same for the two, since wrapped EGLD is exchanged 1:1.
+    ///
+    /// Will halt execution if any other token was received, or more than one ESDT transfer.
+    ///
+    /// In case no transfer of value happen, it will return 0.
+    pub fn egld_or_wrapped_egld(&self, wrapped_egld_token_id: &TokenIdentifier<A>) -> BigUint<A> {
+        let payment = self.egld_or_single_esdt_normalized(wrapped_egld_token_id);
+        if !payment.token_identifier.is_egld() {
+            A::error_api_impl().signal_error(err_msg::EGLD_OR_WRAPPED_EGLD_EXPECTED.as_bytes());
+        }
+        payment.amount
+    }
+
     /// Accepts any sort of patyment, which is either:
     /// - EGLD (can be zero in case of no payment whatsoever);
     /// - Multi-ESDT (one or more ESDT transfers).
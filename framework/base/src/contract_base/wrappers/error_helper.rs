@@ -4,9 +4,29 @@ use crate::codec::{DecodeError, EncodeError};
 
 use crate::{
     api::{ErrorApiImpl, ManagedTypeApi},
+    contract_base::{ErrorMessage, ErrorMessageTable},
+    formatter::FormatBuffer,
     types::{heap::BoxedBytes, ManagedBuffer, ManagedSCError, ManagedType},
 };
 
+const ERROR_CODE_PREFIX: &[u8] = b"[";
+const ERROR_CODE_SUFFIX: &[u8] = b"] ";
+
+/// Extracts the error code from an error message produced by `require!` or `sc_panic!` with `code = ...`.
+///
+/// Returns the code and the rest of the message, or `None` if the message carries no code.
+pub fn split_error_code(message: &[u8]) -> Option<(u32, &[u8])> {
+    let rest = message.strip_prefix(ERROR_CODE_PREFIX)?;
+    let code_len = rest.iter().position(|byte| !byte.is_ascii_digit())?;
+    if code_len == 0 {
+        return None;
+    }
+    let (code_digits, rest) = rest.split_at(code_len);
+    let rest = rest.strip_prefix(ERROR_CODE_SUFFIX)?;
+    let code = core::str::from_utf8(code_digits).ok()?.parse().ok()?;
+    Some((code, rest))
+}
+
 #[derive(Default)]
 pub struct ErrorHelper<M: ManagedTypeApi> {
     _phantom: PhantomData<M>,
@@ -29,6 +49,13 @@ impl<M: ManagedTypeApi> ErrorHelper<M> {
     {
         message.signal_error_with_message()
     }
+
+    /// Error codes are written at the start of the message, since errors only carry a message.
+    pub fn append_error_code<F: FormatBuffer>(buffer: &mut F, code: u32) {
+        buffer.append_ascii(ERROR_CODE_PREFIX);
+        buffer.append_display(&code);
+        buffer.append_ascii(ERROR_CODE_SUFFIX);
+    }
 }
 
 /// Indicates how an object can be used as the basis for performing `signal_error` with itself as message.
@@ -50,6 +77,16 @@ impl<M: ManagedTypeApi> IntoSignalError<M> for &[u8] {
     }
 }
 
+impl<M, T> IntoSignalError<M> for ErrorMessage<T>
+where
+    M: ManagedTypeApi,
+    T: ErrorMessageTable,
+{
+    fn signal_error_with_message(self) -> ! {
+        M::error_api_impl().signal_error(self.as_bytes())
+    }
+}
+
 impl<M: ManagedTypeApi> IntoSignalError<M> for BoxedBytes {
     #[inline]
     fn signal_error_with_message(self) -> ! {
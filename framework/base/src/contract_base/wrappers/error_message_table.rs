@@ -0,0 +1,84 @@
+use core::marker::PhantomData;
+
+/// A table of error messages, all stored one after the other in a single byte string.
+///
+/// Not implemented by hand, but generated by the `error_messages!` macro.
+pub trait ErrorMessageTable: 'static {
+    /// All messages, concatenated.
+    const MESSAGES: &'static [u8];
+
+    /// Where each message ends in `MESSAGES`. Each message starts where the previous one ends.
+    const MESSAGE_ENDS: &'static [u32];
+}
+
+/// Computes the `MESSAGE_ENDS` of an error message table, from the lengths of the messages.
+#[doc(hidden)]
+pub const fn error_message_ends<const N: usize>(lengths: [usize; N]) -> [u32; N] {
+    let mut ends = [0u32; N];
+    let mut end = 0usize;
+    let mut i = 0;
+    while i < N {
+        end += lengths[i];
+        ends[i] = end as u32;
+        i += 1;
+    }
+    ends
+}
+
+/// Refers to a message in an error message table by its index.
+///
+/// Signaling it only passes the index around, the message is looked up once, in a single place per table.
+pub struct ErrorMessage<T: ErrorMessageTable> {
+    index: u16,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: ErrorMessageTable> ErrorMessage<T> {
+    #[doc(hidden)]
+    pub const fn new(index: u16) -> Self {
+        ErrorMessage {
+            index,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    pub fn as_bytes(&self) -> &'static [u8] {
+        let index = self.index as usize;
+        let start = if index == 0 {
+            0
+        } else {
+            T::MESSAGE_ENDS[index - 1] as usize
+        };
+        let end = T::MESSAGE_ENDS[index] as usize;
+        &T::MESSAGES[start..end]
+    }
+}
+
+impl<T: ErrorMessageTable> Clone for ErrorMessage<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ErrorMessageTable> Copy for ErrorMessage<T> {}
+
+impl<T: ErrorMessageTable> PartialEq for ErrorMessage<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T: ErrorMessageTable> Eq for ErrorMessage<T> {}
+
+impl<T: ErrorMessageTable> core::fmt::Debug for ErrorMessage<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ErrorMessage")
+            .field("index", &self.index)
+            .field("message", &core::str::from_utf8(self.as_bytes()))
+            .finish()
+    }
+}
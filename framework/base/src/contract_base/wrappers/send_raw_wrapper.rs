@@ -147,7 +147,9 @@ where
         )
     }
 
+    /// Needs VM 1.5 (EI 1.3), so it is not available when building with an older `--target-vm`.
     #[allow(clippy::too_many_arguments)]
+    #[cfg(not(any(mx_target_ei = "1.0", mx_target_ei = "1.1", mx_target_ei = "1.2")))]
     pub fn create_async_call_raw(
         &self,
         to: &ManagedAddress<A>,
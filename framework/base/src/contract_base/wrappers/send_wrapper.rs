@@ -13,8 +13,9 @@ use crate::{
     codec,
     esdt::ESDTSystemSmartContractProxy,
     types::{
-        BigUint, ContractCall, ContractCallNoPayment, EgldOrEsdtTokenIdentifier, EsdtTokenPayment,
-        ManagedAddress, ManagedArgBuffer, ManagedBuffer, ManagedType, ManagedVec, TokenIdentifier,
+        BigUint, ContractCall, ContractCallNoPayment, Egld, EgldOrEsdtTokenIdentifier,
+        EsdtTokenPayment, ManagedAddress, ManagedArgBuffer, ManagedBuffer, ManagedType, ManagedVec,
+        TokenAmount, TokenIdentifier,
     },
 };
 
@@ -72,7 +73,13 @@ where
 
     /// Sends EGLD to a given address, directly.
     /// Used especially for sending EGLD to regular accounts.
+    ///
+    /// The amount is in the smallest unit, 10^-18 EGLD.
     #[inline]
+    #[cfg_attr(
+        feature = "strict-amounts",
+        deprecated(note = "use direct_egld_amount, which takes an Egld amount")
+    )]
     pub fn direct_egld(&self, to: &ManagedAddress<A>, amount: &BigUint<A>) {
         self.send_raw_wrapper().direct_egld(to, amount, Empty)
     }
@@ -81,12 +88,40 @@ where
     /// Used especially for sending EGLD to regular accounts.
     ///
     /// If the amount is 0, it returns without error.
+    #[cfg_attr(
+        feature = "strict-amounts",
+        deprecated(note = "use direct_non_zero_egld_amount, which takes an Egld amount")
+    )]
     pub fn direct_non_zero_egld(&self, to: &ManagedAddress<A>, amount: &BigUint<A>) {
         if amount == &0 {
             return;
         }
 
-        self.direct_egld(to, amount)
+        self.send_raw_wrapper().direct_egld(to, amount, Empty)
+    }
+
+    /// Sends an EGLD amount to a given address, directly.
+    #[inline]
+    pub fn direct_egld_amount(&self, to: &ManagedAddress<A>, amount: &Egld<A>) {
+        self.send_raw_wrapper()
+            .direct_egld(to, amount.as_wei(), Empty)
+    }
+
+    /// Sends an EGLD amount to a given address, directly.
+    ///
+    /// If the amount is 0, it returns without error.
+    pub fn direct_non_zero_egld_amount(&self, to: &ManagedAddress<A>, amount: &Egld<A>) {
+        if amount.is_zero() {
+            return;
+        }
+
+        self.direct_egld_amount(to, amount)
+    }
+
+    /// Sends a fungible ESDT amount to a given address, directly.
+    #[inline]
+    pub fn direct_token_amount(&self, to: &ManagedAddress<A>, amount: &TokenAmount<A>) {
+        self.direct_esdt(to, amount.token_identifier(), 0, amount.amount());
     }
 
     /// Sends either EGLD, ESDT or NFT to the target address,
@@ -299,6 +334,74 @@ where
         );
     }
 
+    /// Sends multiple ESDT tokens to a target address.
+    ///
+    /// Payments with a zero amount are skipped. If no payments remain, it returns without error.
+    pub fn direct_non_zero_multi(
+        &self,
+        to: &ManagedAddress<A>,
+        payments: &ManagedVec<A, EsdtTokenPayment<A>>,
+    ) {
+        let mut non_zero_payments = ManagedVec::new();
+        for payment in payments {
+            if payment.amount != 0 {
+                non_zero_payments.push(payment);
+            }
+        }
+
+        if non_zero_payments.is_empty() {
+            return;
+        }
+
+        self.direct_multi(to, &non_zero_payments);
+    }
+
+    /// Sends the same token (EGLD, ESDT or NFT) to multiple recipients, each with its own amount.
+    ///
+    /// Recipients with a zero amount are skipped.
+    pub fn direct_non_zero_batch<I>(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier<A>,
+        nonce: u64,
+        payouts: I,
+    ) where
+        I: IntoIterator<Item = (ManagedAddress<A>, BigUint<A>)>,
+    {
+        for (to, amount) in payouts {
+            self.direct_non_zero(&to, token, nonce, &amount);
+        }
+    }
+
+    /// Sends EGLD to multiple recipients, each with its own amount.
+    ///
+    /// Recipients with a zero amount are skipped.
+    #[inline]
+    pub fn direct_non_zero_egld_batch<I>(&self, payouts: I)
+    where
+        I: IntoIterator<Item = (ManagedAddress<A>, BigUint<A>)>,
+    {
+        self.direct_non_zero_batch(&EgldOrEsdtTokenIdentifier::egld(), 0, payouts);
+    }
+
+    /// Sends an ESDT or NFT to multiple recipients, each with its own amount.
+    ///
+    /// Recipients with a zero amount are skipped.
+    #[inline]
+    pub fn direct_non_zero_esdt_batch<I>(
+        &self,
+        token_identifier: &TokenIdentifier<A>,
+        nonce: u64,
+        payouts: I,
+    ) where
+        I: IntoIterator<Item = (ManagedAddress<A>, BigUint<A>)>,
+    {
+        self.direct_non_zero_batch(
+            &EgldOrEsdtTokenIdentifier::esdt(token_identifier.clone()),
+            nonce,
+            payouts,
+        );
+    }
+
     /// Performs a simple ESDT/NFT transfer, but via async call.  
     ///
     /// As with any async call, this immediately terminates the execution of the current call,
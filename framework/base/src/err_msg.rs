@@ -1,6 +1,11 @@
 pub const PANIC_OCCURRED: &str = "panic occurred";
 pub const MEM_ALLOC_ERROR: &str = "memory allocation error";
+pub const MEM_ALLOC_FORBIDDEN: &str = "memory allocation forbidden";
+pub const MEM_ALLOC_ZEROED_FORBIDDEN: &str = "memory allocation forbidden (alloc_zeroed)";
+pub const MEM_REALLOC_FORBIDDEN: &str = "memory allocation forbidden (realloc)";
+pub const MEM_DEALLOC_FORBIDDEN: &str = "memory allocation forbidden (dealloc)";
 
+pub const NON_PAYABLE_FUNC_EGLD: &str = "function does not accept EGLD payment";
 pub const NON_PAYABLE_FUNC_ESDT: &str = "function does not accept ESDT payment";
 pub const BAD_TOKEN_PROVIDED: &str = "bad call value token provided";
 pub const BAD_TOKEN_TICKER_FORMAT: &[u8] = b"bad token ticker format";
@@ -9,6 +14,7 @@ pub const TOO_MANY_ESDT_TRANSFERS: &str = "too many ESDT transfers";
 pub const ESDT_INVALID_TOKEN_INDEX: &str = "invalid token index";
 pub const INCORRECT_NUM_ESDT_TRANSFERS: &str = "incorrect number of ESDT transfers";
 pub static FUNGIBLE_TOKEN_EXPECTED_ERR_MSG: &str = "fungible ESDT token expected";
+pub const EGLD_OR_WRAPPED_EGLD_EXPECTED: &str = "EGLD or wrapped EGLD payment expected";
 
 pub const ARG_WRONG_NUMBER: &str = "wrong number of arguments";
 pub const ARG_ASYNC_WRONG_NUMBER: &[u8] = b"wrong number of arguments provided to async call";
@@ -39,6 +45,7 @@ pub const VALUE_EXCEEDS_SLICE: &[u8] = b"value exceeds target slice";
 pub const CAST_TO_I64_ERROR: &[u8] = b"cast to i64 error";
 pub const BIG_UINT_EXCEEDS_SLICE: &[u8] = b"big uint as_bytes exceed target slice";
 pub const BIG_UINT_SUB_NEGATIVE: &[u8] = b"cannot subtract because result would be negative";
+pub const TOKEN_AMOUNT_MISMATCH: &[u8] = b"cannot combine amounts of different tokens";
 
 pub const DESERIALIZATION_INVALID_BYTE: &str = "call data deserialization error: not a valid byte";
 pub const DESERIALIZATION_NOT_32_BYTES: &str =
@@ -49,6 +56,8 @@ pub const DESERIALIZATION_ARG_OUT_OF_RANGE: &str =
     "call data deserialization error: argument out of range";
 
 pub const CALLBACK_BAD_FUNC: &[u8] = b"no callback function with that name exists in contract";
+pub const CALLBACK_CLOSURE_ARGS_MISMATCH: &[u8] =
+    b"success and error callbacks must receive the same closure arguments";
 
 pub const RECIPIENT_ADDRESS_NOT_SET: &str = "recipient address not set";
 pub static ONLY_OWNER_CALLER: &[u8] = b"Endpoint can only be called by owner";
@@ -0,0 +1,37 @@
+use crate::{api::ManagedTypeApi, types::BigUint};
+
+/// The default `baseIssuingCost` of the ESDT system smart contract: 0.05 EGLD.
+pub const ESDT_DEFAULT_ISSUE_COST: u64 = 50_000_000_000_000_000;
+
+/// The EGLD amount that needs to be paid to the ESDT system smart contract to issue a new token.
+///
+/// It is a system SC configuration (`baseIssuingCost`), so it can differ from chain to chain.
+/// Contracts cannot read it on-chain, so it is best to either forward the payment of the caller,
+/// or to use one of these variants explicitly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EsdtIssueCost {
+    Mainnet,
+    Devnet,
+    Testnet,
+
+    /// Any other chain, e.g. a local testnet, with its own system SC configuration.
+    Custom(u64),
+}
+
+impl EsdtIssueCost {
+    /// The issue cost, in EGLD denomination (10^-18 EGLD).
+    pub const fn egld_amount(&self) -> u64 {
+        match self {
+            EsdtIssueCost::Mainnet | EsdtIssueCost::Devnet | EsdtIssueCost::Testnet => {
+                ESDT_DEFAULT_ISSUE_COST
+            },
+            EsdtIssueCost::Custom(amount) => *amount,
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> From<EsdtIssueCost> for BigUint<M> {
+    fn from(issue_cost: EsdtIssueCost) -> Self {
+        BigUint::from(issue_cost.egld_amount())
+    }
+}
@@ -1,5 +1,7 @@
+mod issue_cost;
 mod properties;
 mod system_sc_proxy;
 
+pub use issue_cost::{EsdtIssueCost, ESDT_DEFAULT_ISSUE_COST};
 pub use properties::*;
-pub use system_sc_proxy::ESDTSystemSmartContractProxy;
+pub use system_sc_proxy::{token_properties_num_decimals, ESDTSystemSmartContractProxy};
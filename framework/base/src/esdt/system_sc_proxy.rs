@@ -4,10 +4,10 @@ use super::properties::*;
 use hex_literal::hex;
 
 use crate::{
-    api::{CallTypeApi, SendApi},
+    api::{CallTypeApi, ManagedTypeApi, SendApi},
     types::{
         BigUint, ContractCall, ContractCallNoPayment, ContractCallWithEgld, EsdtLocalRole,
-        EsdtTokenType, ManagedAddress, ManagedBuffer, TokenIdentifier,
+        EsdtTokenType, ManagedAddress, ManagedBuffer, MultiValueEncoded, TokenIdentifier,
     },
 };
 
@@ -25,6 +25,8 @@ const ISSUE_AND_SET_ALL_ROLES_ENDPOINT_NAME: &str = "registerAndSetAllRoles";
 /// Proxy for the ESDT system smart contract.
 /// Unlike other contract proxies, this one has a fixed address,
 /// so the proxy object doesn't really contain any data, it is more of a placeholder.
+///
+/// The issue cost arguments accept either an EGLD amount or an `EsdtIssueCost`, e.g. `EsdtIssueCost::Mainnet`.
 pub struct ESDTSystemSmartContractProxy<SA>
 where
     SA: SendApi + 'static,
@@ -53,14 +55,14 @@ where
     /// which causes it to issue a new fungible ESDT token.
     pub fn issue_fungible(
         self,
-        issue_cost: BigUint<SA>,
+        issue_cost: impl Into<BigUint<SA>>,
         token_display_name: &ManagedBuffer<SA>,
         token_ticker: &ManagedBuffer<SA>,
         initial_supply: &BigUint<SA>,
         properties: FungibleTokenProperties,
     ) -> ContractCallWithEgld<SA, ()> {
         self.issue(
-            issue_cost,
+            issue_cost.into(),
             EsdtTokenType::Fungible,
             token_display_name,
             token_ticker,
@@ -84,14 +86,14 @@ where
     /// which causes it to issue a new non-fungible ESDT token.
     pub fn issue_non_fungible(
         self,
-        issue_cost: BigUint<SA>,
+        issue_cost: impl Into<BigUint<SA>>,
         token_display_name: &ManagedBuffer<SA>,
         token_ticker: &ManagedBuffer<SA>,
         properties: NonFungibleTokenProperties,
     ) -> ContractCallWithEgld<SA, ()> {
         let zero = BigUint::zero();
         self.issue(
-            issue_cost,
+            issue_cost.into(),
             EsdtTokenType::NonFungible,
             token_display_name,
             token_ticker,
@@ -115,14 +117,14 @@ where
     /// which causes it to issue a new semi-fungible ESDT token.
     pub fn issue_semi_fungible(
         self,
-        issue_cost: BigUint<SA>,
+        issue_cost: impl Into<BigUint<SA>>,
         token_display_name: &ManagedBuffer<SA>,
         token_ticker: &ManagedBuffer<SA>,
         properties: SemiFungibleTokenProperties,
     ) -> ContractCallWithEgld<SA, ()> {
         let zero = BigUint::zero();
         self.issue(
-            issue_cost,
+            issue_cost.into(),
             EsdtTokenType::SemiFungible,
             token_display_name,
             token_ticker,
@@ -146,14 +148,14 @@ where
     /// which causes it to register a new Meta ESDT token.
     pub fn register_meta_esdt(
         self,
-        issue_cost: BigUint<SA>,
+        issue_cost: impl Into<BigUint<SA>>,
         token_display_name: &ManagedBuffer<SA>,
         token_ticker: &ManagedBuffer<SA>,
         properties: MetaTokenProperties,
     ) -> ContractCallWithEgld<SA, ()> {
         let zero = BigUint::zero();
         self.issue(
-            issue_cost,
+            issue_cost.into(),
             EsdtTokenType::Meta,
             token_display_name,
             token_ticker,
@@ -175,7 +177,7 @@ where
 
     pub fn issue_and_set_all_roles(
         self,
-        issue_cost: BigUint<SA>,
+        issue_cost: impl Into<BigUint<SA>>,
         token_display_name: ManagedBuffer<SA>,
         token_ticker: ManagedBuffer<SA>,
         token_type: EsdtTokenType,
@@ -186,7 +188,7 @@ where
         let mut contract_call = ContractCallWithEgld::new(
             esdt_system_sc_address,
             ISSUE_AND_SET_ALL_ROLES_ENDPOINT_NAME,
-            issue_cost,
+            issue_cost.into(),
         );
 
         contract_call.proxy_arg(&token_display_name);
@@ -457,6 +459,22 @@ where
         contract_call
     }
 
+    /// Produces a contract call to the ESDT system SC, which returns the token properties.
+    ///
+    /// The ESDT system SC is on the metachain, so this can only be called asynchronously.
+    /// Use `token_properties_num_decimals` to extract the number of decimals from the result.
+    pub fn get_token_properties(
+        self,
+        token_identifier: &TokenIdentifier<SA>,
+    ) -> ContractCallNoPayment<SA, MultiValueEncoded<SA, ManagedBuffer<SA>>> {
+        let mut contract_call =
+            ContractCallNoPayment::new(self.esdt_system_sc_address(), "getTokenProperties");
+
+        contract_call.proxy_arg(token_identifier);
+
+        contract_call
+    }
+
     pub fn esdt_system_sc_address(&self) -> ManagedAddress<SA> {
         ManagedAddress::new_from_bytes(&ESDT_SYSTEM_SC_ADDRESS_ARRAY)
     }
@@ -537,3 +555,39 @@ fn append_token_property_arguments<SA, CC>(
         set_token_property(contract_call, "canAddSpecialRoles", can_add_special_roles);
     }
 }
+
+const NUM_DECIMALS_PROPERTY_PREFIX: &[u8] = b"NumDecimals-";
+const MAX_NUM_DECIMALS_PROPERTY_LENGTH: usize = 32;
+
+/// Extracts the number of decimals from the result of a `getTokenProperties` call to the ESDT system SC.
+pub fn token_properties_num_decimals<M: ManagedTypeApi>(
+    properties: MultiValueEncoded<M, ManagedBuffer<M>>,
+) -> Option<usize> {
+    for property in properties.into_vec_of_buffers().iter() {
+        if property.len() > MAX_NUM_DECIMALS_PROPERTY_LENGTH {
+            continue;
+        }
+        let mut buffer = [0u8; MAX_NUM_DECIMALS_PROPERTY_LENGTH];
+        let property_bytes = property.load_to_byte_array(&mut buffer);
+        if let Some(digits) = property_bytes.strip_prefix(NUM_DECIMALS_PROPERTY_PREFIX) {
+            return parse_decimal_digits(digits);
+        }
+    }
+    None
+}
+
+fn parse_decimal_digits(digits: &[u8]) -> Option<usize> {
+    if digits.is_empty() {
+        return None;
+    }
+    let mut result = 0usize;
+    for digit in digits {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        result = result
+            .checked_mul(10)?
+            .checked_add((digit - b'0') as usize)?;
+    }
+    Some(result)
+}
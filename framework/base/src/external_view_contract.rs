@@ -40,6 +40,9 @@ pub fn external_view_contract_constructor_abi() -> EndpointAbi {
         only_owner: false,
         only_admin: false,
         labels: &[],
+        aliases: &[],
+        deprecated: None,
+        test_only: false,
         mutability: EndpointMutabilityAbi::Mutable,
         endpoint_type: EndpointTypeAbi::Init,
         payable_in_tokens: &[],
@@ -49,5 +52,6 @@ pub fn external_view_contract_constructor_abi() -> EndpointAbi {
             multi_arg: false,
         }].to_vec(),
         outputs: OutputAbis::new(),
+        errors: crate::types::heap::Vec::new(),
     }
 }
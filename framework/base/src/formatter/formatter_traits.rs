@@ -29,6 +29,17 @@ pub trait FormatBuffer: Default {
     fn append_binary<T: SCBinary>(&mut self, item: &T);
 
     fn append_codec<T: SCCodec>(&mut self, item: &T);
+
+    /// Formats as `{:0N}`: left-pads with zeroes up to `width` characters, sign included.
+    fn append_display_zero_padded<T: SCDisplay>(&mut self, item: &T, width: usize);
+
+    /// Formats as `{:0Nx}`: left-pads with zeroes up to `width` characters.
+    fn append_lower_hex_zero_padded<T: SCLowerHex>(&mut self, item: &T, width: usize);
+
+    /// Formats as `{:.N}`: the integer is interpreted as a fixed-point number with `num_decimals` decimals.
+    ///
+    /// E.g. a token amount of `1500000` with 6 decimals gets formatted as `1.500000`.
+    fn append_display_decimals<T: SCDisplay>(&mut self, item: &T, num_decimals: usize);
 }
 
 #[derive(Default)]
@@ -49,6 +60,15 @@ impl FormatBuffer for FormatBufferIgnore {
 
     #[inline]
     fn append_codec<T: SCCodec>(&mut self, _item: &T) {}
+
+    #[inline]
+    fn append_display_zero_padded<T: SCDisplay>(&mut self, _item: &T, _width: usize) {}
+
+    #[inline]
+    fn append_lower_hex_zero_padded<T: SCLowerHex>(&mut self, _item: &T, _width: usize) {}
+
+    #[inline]
+    fn append_display_decimals<T: SCDisplay>(&mut self, _item: &T, _num_decimals: usize) {}
 }
 
 pub trait SCDisplay {
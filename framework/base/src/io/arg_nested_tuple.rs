@@ -2,10 +2,9 @@ use super::{EndpointDynArgLoader, EndpointSingleArgLoader};
 use crate::{
     api::{
         EndpointArgumentApi, EndpointArgumentApiImpl, ErrorApi, ErrorApiImpl, ManagedTypeApi,
-        StaticVarApiImpl, VMApi,
+        StaticVarApiImpl,
     },
     codec::{DecodeError, TopDecodeMulti, TopDecodeMultiInput},
-    contract_base::CallbackArgApiWrapper,
     err_msg,
     io::{ArgErrorHandler, ArgId},
 };
@@ -173,12 +172,16 @@ where
     N::next_single_arg(0, arg_names)
 }
 
+/// Used for loading the closure arguments of promises callbacks, not available on VMs older than 1.5.
+#[cfg(not(any(mx_target_ei = "1.0", mx_target_ei = "1.1", mx_target_ei = "1.2")))]
 #[inline(always)]
 pub fn load_callback_closure_args<AA, N>(arg_names: N::ArgNames) -> N
 where
-    AA: VMApi,
-    N: ArgNestedTuple<CallbackArgApiWrapper<AA>>,
+    AA: crate::api::VMApi,
+    N: ArgNestedTuple<crate::contract_base::CallbackArgApiWrapper<AA>>,
 {
+    use crate::contract_base::CallbackArgApiWrapper;
+
     CallbackArgApiWrapper::<AA>::argument_api_impl().endpoint_init();
     load_endpoint_args::<CallbackArgApiWrapper<AA>, N>(arg_names)
 }
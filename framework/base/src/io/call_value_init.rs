@@ -61,6 +61,108 @@ where
     }
 }
 
+/// Called initially in the generated code whenever a `#[payable(...)]` annotation with several tokens,
+/// or with constant token groups, is provided.
+///
+/// All received ESDT transfers must be among the accepted tokens.
+/// EGLD is only accepted if "EGLD" is one of them.
+pub fn payable_token_list<A>(accepted_tokens: &[&str])
+where
+    A: CallValueApi + ManagedTypeApi + ErrorApi,
+{
+    let call_value = CallValueWrapper::<A>::new();
+    let transfers = call_value.all_esdt_transfers();
+    if transfers.is_empty() {
+        if *call_value.egld_value() > 0u32 && !is_token_accepted::<A>(accepted_tokens, None) {
+            A::error_api_impl().signal_error(err_msg::NON_PAYABLE_FUNC_EGLD.as_bytes());
+        }
+        return;
+    }
+
+    for transfer in transfers.iter() {
+        if !is_token_accepted::<A>(
+            accepted_tokens,
+            Some(transfer.token_identifier.get_handle()),
+        ) {
+            A::error_api_impl().signal_error(err_msg::BAD_TOKEN_PROVIDED.as_bytes());
+        }
+    }
+}
+
+/// Passing no token handle checks for EGLD.
+fn is_token_accepted<A>(
+    accepted_tokens: &[&str],
+    opt_token_handle: Option<A::ManagedBufferHandle>,
+) -> bool
+where
+    A: ManagedTypeApi,
+{
+    let accepted_token_handle: A::ManagedBufferHandle =
+        use_raw_handle(const_handles::MBUF_TEMPORARY_1);
+    for accepted_token in accepted_tokens {
+        match &opt_token_handle {
+            None => {
+                if *accepted_token == "EGLD" {
+                    return true;
+                }
+            },
+            Some(token_handle) => {
+                A::managed_type_impl()
+                    .mb_overwrite(accepted_token_handle.clone(), accepted_token.as_bytes());
+                if A::managed_type_impl().mb_eq(token_handle.clone(), accepted_token_handle.clone())
+                {
+                    return true;
+                }
+            },
+        }
+    }
+    false
+}
+
+/// Number of tokens accepted by a `#[payable(...)]` annotation with several tokens or with constant token groups.
+///
+/// Evaluated at compile time, to size the array produced by `accepted_tokens`.
+pub const fn accepted_tokens_len(token_groups: &[&[&str]]) -> usize {
+    let mut len = 0;
+    let mut group_index = 0;
+    while group_index < token_groups.len() {
+        len += token_groups[group_index].len();
+        group_index += 1;
+    }
+    len
+}
+
+/// Flattens the tokens and constant token groups of a `#[payable(...)]` annotation, keeping their order.
+///
+/// Evaluated at compile time, so a `"*"` or an empty token name in any of the groups is a compilation error.
+pub const fn accepted_tokens<const N: usize>(
+    token_groups: &[&[&'static str]],
+) -> [&'static str; N] {
+    let mut result = [""; N];
+    let mut index = 0;
+    let mut group_index = 0;
+    while group_index < token_groups.len() {
+        let group = token_groups[group_index];
+        let mut token_index = 0;
+        while token_index < group.len() {
+            let token = group[token_index].as_bytes();
+            assert!(
+                !token.is_empty(),
+                "empty token name not allowed in #[payable] attribute"
+            );
+            assert!(
+                !(token.len() == 1 && token[0] == b'*'),
+                "\"*\" not allowed in #[payable] token groups"
+            );
+            result[index] = group[token_index];
+            index += 1;
+            token_index += 1;
+        }
+        group_index += 1;
+    }
+    result
+}
+
 /// Initializes an argument annotated with `#[payment_amount]` or `#[payment]`.
 pub fn arg_payment_amount<A>() -> BigUint<A>
 where
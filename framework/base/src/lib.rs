@@ -14,6 +14,15 @@
 #![feature(is_sorted)]
 pub use multiversx_sc_derive::{self as derive, contract, module, proxy};
 
+// `mx_target_ei` is set by `sc-meta build --target-vm`, promises need VM 1.5 (EI 1.3).
+#[cfg(all(
+    feature = "promises",
+    any(mx_target_ei = "1.0", mx_target_ei = "1.1", mx_target_ei = "1.2")
+))]
+compile_error!(
+    "the `promises` feature is not available on the target VM, it requires VM 1.5 or newer"
+);
+
 // re-export basic heap types
 extern crate alloc;
 
@@ -4,9 +4,12 @@ use crate::{
     api::{ErrorApi, LogApi, LogApiImpl, ManagedTypeApi},
     contract_base::ExitCodecErrorHandler,
     err_msg,
-    types::{ManagedBuffer, ManagedType, ManagedVec},
+    types::{BigUint, EsdtTokenPayment, ManagedAddress, ManagedBuffer, ManagedType, ManagedVec},
 };
 
+/// The names of the standard topics, in the order in which they are pushed.
+pub const STANDARD_EVENT_TOPIC_NAMES: [&str; 3] = ["caller", "egld_value", "esdt_transfers"];
+
 pub fn event_topic_accumulator<A>(event_identifier: &[u8]) -> ManagedVec<A, ManagedBuffer<A>>
 where
     A: ErrorApi + ManagedTypeApi,
@@ -27,6 +30,22 @@ where
     );
 }
 
+/// Pushes the standard topics of events declared with `standard_topics`: caller, EGLD value and ESDT transfers.
+///
+/// All ESDT transfers are pushed as a single topic, so multi-transfers are logged in full.
+pub fn serialize_standard_event_topics<A>(
+    accumulator: &mut ManagedVec<A, ManagedBuffer<A>>,
+    caller: &ManagedAddress<A>,
+    egld_value: &BigUint<A>,
+    esdt_transfers: &ManagedVec<A, EsdtTokenPayment<A>>,
+) where
+    A: ErrorApi + ManagedTypeApi,
+{
+    serialize_event_topic(accumulator, caller);
+    serialize_event_topic(accumulator, egld_value);
+    serialize_event_topic(accumulator, esdt_transfers);
+}
+
 pub fn serialize_log_data<T, A>(data: T) -> ManagedBuffer<A>
 where
     T: TopEncode,
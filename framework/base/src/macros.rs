@@ -19,7 +19,7 @@ macro_rules! imports {
                 TopEncode,
             },
             contract_base::{ContractBase, ProxyObjBase},
-            err_msg,
+            err_msg, error_messages,
             esdt::*,
             io::*,
             non_zero_usize,
@@ -44,7 +44,7 @@ macro_rules! derive_imports {
                 NestedDecode, NestedEncode, TopDecode, TopDecodeOrDefault, TopEncode,
                 TopEncodeOrDefault,
             },
-            derive::{ManagedVecItem, TypeAbi},
+            derive::{ManagedVecItem, SCError, TypeAbi},
         };
     };
 }
@@ -85,6 +85,20 @@ macro_rules! require_old {
 
 #[macro_export]
 macro_rules! sc_panic {
+    (code = $code:expr, $msg:tt, $($arg:expr),+ $(,)?) => {{
+        let mut ___buffer___ =
+            multiversx_sc::types::ManagedBufferCachedBuilder::<Self::Api>::new_from_slice(&[]);
+        multiversx_sc::contract_base::ErrorHelper::<Self::Api>::append_error_code(&mut ___buffer___, $code);
+        multiversx_sc::derive::format_receiver_args!(___buffer___, $msg, $($arg),+);
+        multiversx_sc::contract_base::ErrorHelper::<Self::Api>::signal_error_with_message(___buffer___.into_managed_buffer());
+    }};
+    (code = $code:expr, $msg:literal $(,)?) => {{
+        let mut ___buffer___ =
+            multiversx_sc::types::ManagedBufferCachedBuilder::<Self::Api>::new_from_slice(&[]);
+        multiversx_sc::contract_base::ErrorHelper::<Self::Api>::append_error_code(&mut ___buffer___, $code);
+        multiversx_sc::formatter::FormatBuffer::append_ascii(&mut ___buffer___, $msg.as_bytes());
+        multiversx_sc::contract_base::ErrorHelper::<Self::Api>::signal_error_with_message(___buffer___.into_managed_buffer());
+    }};
     ($msg:tt, $($arg:expr),+ $(,)?) => {{
         let mut ___buffer___ =
             multiversx_sc::types::ManagedBufferCachedBuilder::<Self::Api>::new_from_slice(&[]);
@@ -119,10 +133,23 @@ macro_rules! sc_panic {
 /// fn only_accept_zero(&self, x: i32, message: &ManagedBuffer<Self::Api>) {
 ///     require!(x == 0, message,);
 /// }
+///
+/// fn only_accept_small(&self, x: i32) {
+///     require!(x < 100, code = 7, "value {} too large", x);
+/// }
 /// # }
 /// ```
+///
+/// A numeric error code can optionally be given before the message, as `code = <u32>`.
+/// It ends up at the start of the error message, as `[<code>] `, so clients can branch on it,
+/// without parsing the rest of the message.
 #[macro_export]
 macro_rules! require {
+    ($expression:expr, code = $code:expr, $($msg_tokens:tt),+  $(,)?) => {
+        if (!($expression)) {
+            multiversx_sc::sc_panic!(code = $code, $($msg_tokens),+);
+        }
+    };
     ($expression:expr, $($msg_tokens:tt),+  $(,)?) => {
         if (!($expression)) {
             multiversx_sc::sc_panic!($($msg_tokens),+);
@@ -130,6 +157,59 @@ macro_rules! require {
     };
 }
 
+/// Declares error messages that are stored together, in a single static byte string, and referred to by index.
+///
+/// It is an opt-in alternative to string literals in `require!` and `sc_panic!`, that keeps all messages in one place.
+/// The messages are unchanged, so is the behavior of the contract.
+///
+/// It is not a size optimization in itself: the table adds 4 bytes per message, as well as the lookup.
+/// Use `sc-meta all build --size-report` to compare, `contracts/examples/digital-cash` has an example report.
+///
+/// Example:
+///
+/// ```rust
+/// # use multiversx_sc::{error_messages, require};
+/// error_messages! {
+///     pub ExampleErrors {
+///         ERR_NOT_POSITIVE = "only positive values accepted",
+///         ERR_TOO_LARGE = "value too large",
+///     }
+/// }
+///
+/// # pub trait ExampleContract: multiversx_sc::contract_base::ContractBase
+/// # {
+/// fn only_accept_small_positive(&self, x: i32) {
+///     require!(x > 0, ERR_NOT_POSITIVE);
+///     require!(x < 100, ERR_TOO_LARGE);
+/// }
+/// # }
+/// ```
+///
+/// The table type can be used to check the size of the data segment, e.g. with `ExampleErrors::MESSAGES.len()`.
+#[macro_export]
+macro_rules! error_messages {
+    ($vis:vis $table:ident { $($name:ident = $msg:literal),+ $(,)? }) => {
+        // The variants only serve to number the messages,
+        // so that the indices are not computed recursively, one message at a time.
+        #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+        #[repr(u16)]
+        $vis enum $table {
+            $($name),+
+        }
+
+        impl multiversx_sc::contract_base::ErrorMessageTable for $table {
+            const MESSAGES: &'static [u8] = concat!($($msg),+).as_bytes();
+            const MESSAGE_ENDS: &'static [u32] =
+                &multiversx_sc::contract_base::error_message_ends([$($msg.len()),+]);
+        }
+
+        $(
+            $vis const $name: multiversx_sc::contract_base::ErrorMessage<$table> =
+                multiversx_sc::contract_base::ErrorMessage::new($table::$name as u16);
+        )+
+    };
+}
+
 #[macro_export]
 macro_rules! sc_print {
     ($msg:tt, $($arg:expr),* $(,)?) => {{
@@ -0,0 +1,31 @@
+use super::SingleValueMapper;
+use crate::{
+    api::StorageMapperApi,
+    codec::{TopDecode, TopEncode, TopEncodeMulti},
+};
+
+/// Storage mappers that hold one value per storage key, which can be read for several keys at once.
+///
+/// It is what the `#[aggregated_view]` annotation relies on,
+/// to generate a view that returns e.g. all the token positions of a user in one call.
+pub trait AggregatedMapper<SA>
+where
+    SA: StorageMapperApi,
+{
+    type Value: TopEncodeMulti;
+
+    /// The value stored under the mapper key.
+    fn aggregated_value(&self) -> Self::Value;
+}
+
+impl<SA, T> AggregatedMapper<SA> for SingleValueMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    type Value = T;
+
+    fn aggregated_value(&self) -> T {
+        self.get()
+    }
+}
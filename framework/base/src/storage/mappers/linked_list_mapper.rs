@@ -440,6 +440,29 @@ where
         Iter::new_from_node_id(self, node_id)
     }
 
+    /// Creates a cursor positioned before the front of the list.
+    ///
+    /// Unlike the iterator, the cursor does not borrow the mapper,
+    /// so the list can be modified while traversing it.
+    pub fn cursor(&self) -> LinkedListCursor {
+        LinkedListCursor::new()
+    }
+
+    /// Removes all elements for which the predicate returns `false`, in order, from front to back.
+    ///
+    /// Kept elements are not written back to storage.
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut cursor = self.cursor();
+        while let Some(node) = cursor.next(self) {
+            if !predicate(node.get_value_as_ref()) {
+                self.remove_node(&node);
+            }
+        }
+    }
+
     pub fn check_internal_consistency(&self) -> bool {
         let info = self.get_info();
         let mut front = info.front;
@@ -501,6 +524,64 @@ where
     }
 }
 
+/// Position in a linked list, that allows modifying the list during traversal.
+///
+/// The node last returned by the cursor can be removed, changed,
+/// or have new nodes inserted after it, and traversal continues with its (new) successor.
+/// Other nodes can also be removed, except for the successor of a node that was itself removed,
+/// in which case the traversal ends early.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LinkedListCursor {
+    started: bool,
+    current_id: u32,
+    next_id: u32,
+}
+
+impl LinkedListCursor {
+    fn new() -> Self {
+        LinkedListCursor {
+            started: false,
+            current_id: NULL_ENTRY,
+            next_id: NULL_ENTRY,
+        }
+    }
+
+    /// The id of the node last returned, or 0 (the null entry) if traversal has not started.
+    pub fn current_node_id(&self) -> u32 {
+        self.current_id
+    }
+
+    /// Moves to the next node and returns it, or `None` when the end of the list is reached.
+    pub fn next<SA, T>(
+        &mut self,
+        linked_list: &LinkedListMapper<SA, T>,
+    ) -> Option<LinkedListNode<T>>
+    where
+        SA: StorageMapperApi,
+        T: TopEncode + TopDecode + NestedEncode + NestedDecode + Clone,
+    {
+        let next_id = if !self.started {
+            self.started = true;
+            linked_list.get_info().front
+        } else if let Some(current) = linked_list.get_node_by_id(self.current_id) {
+            current.next_id
+        } else {
+            self.next_id
+        };
+
+        if next_id == NULL_ENTRY {
+            self.current_id = NULL_ENTRY;
+            self.next_id = NULL_ENTRY;
+            return None;
+        }
+
+        let node = linked_list.get_node_by_id(next_id)?;
+        self.current_id = node.node_id;
+        self.next_id = node.next_id;
+        Some(node)
+    }
+}
+
 impl<'a, SA, T> IntoIterator for &'a LinkedListMapper<SA, T>
 where
     SA: StorageMapperApi,
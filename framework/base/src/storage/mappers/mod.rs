@@ -1,8 +1,10 @@
+mod aggregated_mapper;
 mod bi_di_mapper;
 mod linked_list_mapper;
 mod map_mapper;
 mod map_storage_mapper;
 mod mapper;
+mod paged_mapper;
 mod queue_mapper;
 mod set_mapper;
 mod single_value_mapper;
@@ -13,11 +15,13 @@ mod user_mapper;
 mod vec_mapper;
 mod whitelist_mapper;
 
+pub use aggregated_mapper::AggregatedMapper;
 pub use bi_di_mapper::BiDiMapper;
-pub use linked_list_mapper::{LinkedListMapper, LinkedListNode};
+pub use linked_list_mapper::{LinkedListCursor, LinkedListMapper, LinkedListNode};
 pub use map_mapper::MapMapper;
 pub use map_storage_mapper::MapStorageMapper;
 pub use mapper::{StorageClearable, StorageMapper};
+pub use paged_mapper::PagedMapper;
 pub use queue_mapper::QueueMapper;
 pub use set_mapper::SetMapper;
 pub use single_value_mapper::{SingleValue, SingleValueMapper};
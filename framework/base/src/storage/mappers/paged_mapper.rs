@@ -0,0 +1,101 @@
+use super::{MapMapper, QueueMapper, SetMapper, UnorderedSetMapper, VecMapper};
+use crate::{
+    api::StorageMapperApi,
+    codec::{
+        multi_types::MultiValue2, NestedDecode, NestedEncode, TopDecode, TopEncode, TopEncodeMulti,
+    },
+    types::MultiValueEncoded,
+};
+
+/// Storage mappers whose contents can be returned page by page.
+///
+/// It is what the `#[paged_view]` annotation relies on,
+/// to generate a view that only returns part of a collection that might be too large to return whole.
+pub trait PagedMapper<SA>
+where
+    SA: StorageMapperApi,
+{
+    type Item: TopEncodeMulti;
+
+    /// Returns at most `size` items, skipping the first `from`.
+    fn paged(&self, from: usize, size: usize) -> MultiValueEncoded<SA, Self::Item>;
+}
+
+fn page_index_range(len: usize, from: usize, size: usize) -> core::ops::RangeInclusive<usize> {
+    let first = from.saturating_add(1);
+    let last = from.saturating_add(size).min(len);
+    first..=last
+}
+
+impl<SA, T> PagedMapper<SA> for VecMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    type Item = T;
+
+    fn paged(&self, from: usize, size: usize) -> MultiValueEncoded<SA, T> {
+        let mut result = MultiValueEncoded::new();
+        for index in page_index_range(self.len(), from, size) {
+            result.push(self.get_unchecked(index));
+        }
+        result
+    }
+}
+
+impl<SA, T> PagedMapper<SA> for UnorderedSetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    type Item = T;
+
+    fn paged(&self, from: usize, size: usize) -> MultiValueEncoded<SA, T> {
+        let mut result = MultiValueEncoded::new();
+        for index in page_index_range(self.len(), from, size) {
+            result.push(self.get_by_index(index));
+        }
+        result
+    }
+}
+
+impl<SA, T> PagedMapper<SA> for SetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    type Item = T;
+
+    fn paged(&self, from: usize, size: usize) -> MultiValueEncoded<SA, T> {
+        self.iter().skip(from).take(size).collect()
+    }
+}
+
+impl<SA, T> PagedMapper<SA> for QueueMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    type Item = T;
+
+    fn paged(&self, from: usize, size: usize) -> MultiValueEncoded<SA, T> {
+        self.iter().skip(from).take(size).collect()
+    }
+}
+
+impl<SA, K, V> PagedMapper<SA> for MapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode,
+    V: TopEncode + TopDecode,
+{
+    type Item = MultiValue2<K, V>;
+
+    fn paged(&self, from: usize, size: usize) -> MultiValueEncoded<SA, MultiValue2<K, V>> {
+        self.iter()
+            .skip(from)
+            .take(size)
+            .map(MultiValue2::from)
+            .collect()
+    }
+}
@@ -94,6 +94,30 @@ where
         async_call.call_and_exit()
     }
 
+    /// Hands over the management of the token to another address, via the ESDT system SC.
+    ///
+    /// The contract must be the current token owner. Any roles it holds are kept.
+    /// If the new owner is a contract, it can start managing the token by calling `set_token_id` on its own mapper.
+    fn transfer_ownership(
+        &self,
+        new_owner: &ManagedAddress<SA>,
+        opt_callback: Option<CallbackClosure<SA>>,
+    ) -> ! {
+        self.require_issued_or_set();
+
+        let system_sc_proxy = ESDTSystemSmartContractProxy::<SA>::new_proxy_obj();
+        let token_id = self.get_token_id_ref();
+        let mut async_call = system_sc_proxy
+            .transfer_ownership(token_id, new_owner)
+            .async_call();
+
+        if let Some(cb) = opt_callback {
+            async_call = async_call.with_callback(cb);
+        }
+
+        async_call.call_and_exit()
+    }
+
     fn get_sc_address() -> ManagedAddress<SA> {
         let b_wrapper = BlockchainWrapper::new();
         b_wrapper.get_sc_address()
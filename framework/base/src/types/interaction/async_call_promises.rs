@@ -1,6 +1,5 @@
 use crate::{
     api::CallTypeApi,
-    contract_base::SendRawWrapper,
     types::{BigUint, CallbackClosure, ManagedAddress, ManagedArgBuffer, ManagedBuffer},
 };
 
@@ -17,6 +16,7 @@ where
     pub(crate) explicit_gas_limit: u64,
     pub(crate) extra_gas_for_callback: u64,
     pub(crate) callback_call: Option<CallbackClosure<SA>>,
+    pub(crate) error_callback_call: Option<CallbackClosure<SA>>,
 }
 
 #[allow(clippy::return_self_not_must_use)]
@@ -31,26 +31,70 @@ where
         }
     }
 
+    /// Sets a separate callback, only called if the async call fails.
+    ///
+    /// The callback set via `with_callback` is then only called on success.
+    /// If no other callback is set, nothing gets called on success.
+    ///
+    /// The VM keeps a single set of closure arguments for both callbacks,
+    /// so if both are set, they must be called with the same arguments.
+    pub fn with_error_callback(self, error_callback_call: CallbackClosure<SA>) -> Self {
+        AsyncCallPromises {
+            error_callback_call: Some(error_callback_call),
+            ..self
+        }
+    }
+
     #[inline]
     pub fn with_extra_gas_for_callback(mut self, gas_limit: u64) -> Self {
         self.extra_gas_for_callback = gas_limit;
         self
     }
 
+    #[cfg(not(any(mx_target_ei = "1.0", mx_target_ei = "1.1", mx_target_ei = "1.2")))]
     pub fn register_promise(self) {
-        use crate::{api::const_handles, types::ManagedType};
+        use crate::{
+            api::{const_handles, ErrorApiImpl},
+            contract_base::SendRawWrapper,
+            err_msg,
+            types::ManagedType,
+        };
 
         let mut cb_closure_args_serialized =
             ManagedBuffer::<SA>::from_raw_handle(const_handles::MBUF_TEMPORARY_1);
-        let callback_name;
-        if let Some(callback_call) = self.callback_call {
-            callback_name = callback_call.callback_name;
-            callback_call
-                .closure_args
-                .serialize_overwrite(&mut cb_closure_args_serialized);
-        } else {
-            callback_name = "";
-            cb_closure_args_serialized.overwrite(&[]);
+        let success_callback_name;
+        let error_callback_name;
+        match (self.callback_call, self.error_callback_call) {
+            (Some(callback_call), None) => {
+                success_callback_name = callback_call.callback_name;
+                error_callback_name = callback_call.callback_name;
+                callback_call
+                    .closure_args
+                    .serialize_overwrite(&mut cb_closure_args_serialized);
+            },
+            (callback_call, Some(error_callback_call)) => {
+                error_callback_name = error_callback_call.callback_name;
+                error_callback_call
+                    .closure_args
+                    .serialize_overwrite(&mut cb_closure_args_serialized);
+                if let Some(callback_call) = callback_call {
+                    success_callback_name = callback_call.callback_name;
+                    let mut success_closure_args_serialized = ManagedBuffer::<SA>::new();
+                    callback_call
+                        .closure_args
+                        .serialize_overwrite(&mut success_closure_args_serialized);
+                    if success_closure_args_serialized != cb_closure_args_serialized {
+                        SA::error_api_impl().signal_error(err_msg::CALLBACK_CLOSURE_ARGS_MISMATCH);
+                    }
+                } else {
+                    success_callback_name = "";
+                }
+            },
+            (None, None) => {
+                success_callback_name = "";
+                error_callback_name = "";
+                cb_closure_args_serialized.overwrite(&[]);
+            },
         }
 
         SendRawWrapper::<SA>::new().create_async_call_raw(
@@ -58,8 +102,8 @@ where
             &self.egld_payment,
             &self.endpoint_name,
             &self.arg_buffer,
-            callback_name,
-            callback_name,
+            success_callback_name,
+            error_callback_name,
             self.explicit_gas_limit,
             self.extra_gas_for_callback,
             &cb_closure_args_serialized,
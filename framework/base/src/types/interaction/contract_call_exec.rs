@@ -60,6 +60,7 @@ where
             explicit_gas_limit: self.basic.explicit_gas_limit,
             extra_gas_for_callback: 0,
             callback_call: None,
+            error_callback_call: None,
         }
     }
 
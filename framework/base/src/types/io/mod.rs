@@ -1,6 +1,7 @@
 mod codec_multi_value_aliases;
 mod operation_completion_status;
 mod sc_error;
+mod sc_error_coded;
 mod sc_error_managed;
 mod sc_error_static;
 mod sc_result;
@@ -8,6 +9,7 @@ mod sc_result;
 pub use codec_multi_value_aliases::*;
 pub use operation_completion_status::OperationCompletionStatus;
 pub use sc_error::SCError;
+pub use sc_error_coded::CodedSCError;
 pub use sc_error_managed::ManagedSCError;
 pub use sc_error_static::StaticSCError;
 pub use sc_result::SCResult;
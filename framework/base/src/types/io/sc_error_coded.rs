@@ -0,0 +1,15 @@
+use super::SCError;
+
+/// Errors with a stable numeric code, usually enums obtained via `#[derive(SCError)]`.
+///
+/// They get signalled as `[<code>] <message>`, the same as `require!` with `code = ...`,
+/// so clients and tests can identify them without relying on the message text.
+pub trait CodedSCError: SCError {
+    fn error_code(&self) -> u32;
+
+    /// The message, without the code.
+    fn error_message(&self) -> &'static str;
+
+    /// The message exactly as signalled, including the code.
+    fn signalled_message(&self) -> &'static str;
+}
@@ -1,4 +1,6 @@
-use crate::codec::{EncodeErrorHandler, TopEncodeMulti, TopEncodeMultiOutput, TryStaticCast};
+use crate::codec::{
+    EncodeErrorHandler, SignalError, TopEncodeMulti, TopEncodeMultiOutput, TryStaticCast,
+};
 use alloc::{string::String, vec::Vec};
 
 use crate::{
@@ -129,3 +131,16 @@ where
         output.push_multi_specialized(self, h)
     }
 }
+
+impl<M> SignalError for ManagedSCError<M>
+where
+    M: ManagedTypeApi + ErrorApi,
+{
+    fn signal_error_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        output.push_multi_specialized(self, h)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::codec::{self, DecodeError, EncodeError, TopEncodeMulti, TryStaticCast};
+use crate::codec::{self, DecodeError, EncodeError, SignalError, TopEncodeMulti, TryStaticCast};
 
 use crate::api::{EndpointFinishApi, ErrorApiImpl};
 
@@ -69,3 +69,13 @@ impl TopEncodeMulti for StaticSCError {
         output.push_multi_specialized(self, h)
     }
 }
+
+impl SignalError for StaticSCError {
+    fn signal_error_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: codec::TopEncodeMultiOutput,
+        H: codec::EncodeErrorHandler,
+    {
+        output.push_multi_specialized(self, h)
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    api::{use_raw_handle, BigIntApiImpl, ManagedTypeApi, StaticVarApiImpl},
+    types::{BigUint, ManagedBuffer, ManagedType},
+};
+
+/// Digits are accumulated in a `u64` before being added to the `BigUint`, to save on big number operations.
+const MAX_DIGITS_PER_CHUNK: u32 = 18;
+
+/// Larger exponents are rejected, to keep the cost of parsing untrusted input bounded.
+const MAX_SCIENTIFIC_EXPONENT: u32 = 255;
+
+const LOAD_BATCH_SIZE: usize = 32;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DecimalPart {
+    Integer,
+    Fraction,
+    ExponentSign,
+    Exponent,
+}
+
+/// Accumulates the digits of a decimal string, one byte at a time.
+struct DecimalParser<M: ManagedTypeApi> {
+    part: DecimalPart,
+    valid: bool,
+    mantissa: BigUint<M>,
+    chunk: u64,
+    chunk_len: u32,
+    num_integer_digits: u32,
+    num_fraction_digits: u32,
+    exponent_negative: bool,
+    num_exponent_digits: u32,
+    exponent: u32,
+}
+
+impl<M: ManagedTypeApi> DecimalParser<M> {
+    fn new() -> Self {
+        DecimalParser {
+            part: DecimalPart::Integer,
+            valid: true,
+            mantissa: BigUint::zero(),
+            chunk: 0,
+            chunk_len: 0,
+            num_integer_digits: 0,
+            num_fraction_digits: 0,
+            exponent_negative: false,
+            num_exponent_digits: 0,
+            exponent: 0,
+        }
+    }
+
+    fn push_byte(&mut self, byte: u8) {
+        if !self.valid {
+            return;
+        }
+        match (self.part, byte) {
+            (DecimalPart::Integer, b'0'..=b'9') => {
+                self.push_mantissa_digit(byte - b'0');
+                self.num_integer_digits += 1;
+            },
+            (DecimalPart::Fraction, b'0'..=b'9') => {
+                self.push_mantissa_digit(byte - b'0');
+                self.num_fraction_digits += 1;
+            },
+            (DecimalPart::Integer, b'.') if self.num_integer_digits > 0 => {
+                self.part = DecimalPart::Fraction;
+            },
+            (DecimalPart::Integer, b'e' | b'E') if self.num_integer_digits > 0 => {
+                self.part = DecimalPart::ExponentSign;
+            },
+            (DecimalPart::Fraction, b'e' | b'E') if self.num_fraction_digits > 0 => {
+                self.part = DecimalPart::ExponentSign;
+            },
+            (DecimalPart::ExponentSign, b'+' | b'-') => {
+                self.exponent_negative = byte == b'-';
+                self.part = DecimalPart::Exponent;
+            },
+            (DecimalPart::ExponentSign | DecimalPart::Exponent, b'0'..=b'9') => {
+                self.part = DecimalPart::Exponent;
+                self.exponent = self.exponent * 10 + (byte - b'0') as u32;
+                self.num_exponent_digits += 1;
+                if self.exponent > MAX_SCIENTIFIC_EXPONENT {
+                    self.valid = false;
+                }
+            },
+            _ => {
+                self.valid = false;
+            },
+        }
+    }
+
+    fn push_mantissa_digit(&mut self, digit: u8) {
+        self.chunk = self.chunk * 10 + digit as u64;
+        self.chunk_len += 1;
+        if self.chunk_len == MAX_DIGITS_PER_CHUNK {
+            self.flush_chunk();
+        }
+    }
+
+    fn flush_chunk(&mut self) {
+        if self.chunk_len == 0 {
+            return;
+        }
+        self.mantissa *= 10u64.pow(self.chunk_len);
+        self.mantissa += self.chunk;
+        self.chunk = 0;
+        self.chunk_len = 0;
+    }
+
+    fn finish(mut self, num_decimals: u32) -> Option<BigUint<M>> {
+        let complete = match self.part {
+            DecimalPart::Integer => self.num_integer_digits > 0,
+            DecimalPart::Fraction => self.num_fraction_digits > 0,
+            DecimalPart::ExponentSign => false,
+            DecimalPart::Exponent => self.num_exponent_digits > 0,
+        };
+        if !self.valid || !complete {
+            return None;
+        }
+        self.flush_chunk();
+
+        // the value is mantissa * 10^(num_decimals + exponent - num_fraction_digits)
+        let mut scale = num_decimals as i64 - self.num_fraction_digits as i64;
+        if self.exponent_negative {
+            scale -= self.exponent as i64;
+        } else {
+            scale += self.exponent as i64;
+        }
+
+        if scale >= 0 {
+            Some(self.mantissa * &ten_pow(scale as u32))
+        } else {
+            // only trailing zeros can be dropped, anything else would lose precision
+            let divisor = ten_pow(scale.unsigned_abs() as u32);
+            if &self.mantissa % &divisor == 0u32 {
+                Some(self.mantissa / &divisor)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn ten_pow<M: ManagedTypeApi>(exp: u32) -> BigUint<M> {
+    BigUint::from(10u32).pow(exp)
+}
+
+impl<M: ManagedTypeApi> BigUint<M> {
+    /// Parses an amount written in decimal notation, e.g. `"1.5"`, or in scientific notation, e.g. `"1.5e3"`,
+    /// and scales it to a number with `num_decimals` decimals, i.e. `"1.5"` with 2 decimals yields 150.
+    ///
+    /// Meant for amounts received as text, e.g. from bridges.
+    /// Returns `None` if the text is not a valid non-negative number,
+    /// or if it has more significant decimals than `num_decimals`, instead of rounding.
+    pub fn from_decimal_str(decimal_str: &ManagedBuffer<M>, num_decimals: u32) -> Option<Self> {
+        let mut parser = DecimalParser::new();
+        decimal_str.for_each_batch::<LOAD_BATCH_SIZE, _>(|batch| {
+            for byte in batch {
+                parser.push_byte(*byte);
+            }
+        });
+        parser.finish(num_decimals)
+    }
+
+    /// Formats the number as a decimal, considering that it has `num_decimals` decimals,
+    /// i.e. 150 with 2 decimals is shown as `"1.5"`.
+    ///
+    /// Trailing zeros are left out, so the result can be parsed back with `from_decimal_str`.
+    pub fn to_decimal_display(&self, num_decimals: u32) -> ManagedBuffer<M> {
+        let divisor = ten_pow(num_decimals);
+        let mut result = (self / &divisor).to_decimal_buffer();
+        let mut fraction = self % &divisor;
+        if fraction == 0u32 {
+            return result;
+        }
+
+        let mut num_fraction_digits = num_decimals;
+        while &fraction % 10u32 == 0u32 {
+            fraction /= 10u32;
+            num_fraction_digits -= 1;
+        }
+        let fraction_digits = fraction.to_decimal_buffer();
+
+        result.append_bytes(b".");
+        for _ in fraction_digits.len() as u32..num_fraction_digits {
+            result.append_bytes(b"0");
+        }
+        result.append(&fraction_digits);
+        result
+    }
+
+    fn to_decimal_buffer(&self) -> ManagedBuffer<M> {
+        let str_handle: M::ManagedBufferHandle =
+            use_raw_handle(M::static_var_api_impl().next_handle());
+        M::managed_type_impl().bi_to_string(self.handle.clone(), str_handle.clone());
+        ManagedBuffer::from_handle(str_handle)
+    }
+}
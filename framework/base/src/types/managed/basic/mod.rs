@@ -8,6 +8,7 @@ mod big_int_sign;
 mod big_num_cmp;
 mod big_uint;
 mod big_uint_cmp;
+mod big_uint_decimal;
 mod big_uint_operators;
 mod cast_to_i64;
 mod elliptic_curve;
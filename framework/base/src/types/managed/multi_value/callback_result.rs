@@ -0,0 +1,247 @@
+use crate::{
+    abi::{TypeAbi, TypeName},
+    api::ManagedTypeApi,
+    codec::{
+        DecodeErrorHandler, EncodeErrorHandler, TopDecodeMulti, TopDecodeMultiInput,
+        TopEncodeMulti, TopEncodeMultiOutput,
+    },
+    types::ManagedBuffer,
+};
+
+use super::{ManagedAsyncCallError, ManagedAsyncCallResult};
+
+const USER_ERROR_CODE: u32 = 4;
+const OUT_OF_GAS_CODE: u32 = 5;
+const EXECUTION_FAILED_CODE: u32 = 10;
+
+/// Categorized version of the error received by an async call callback.
+pub enum CallbackError<M>
+where
+    M: ManagedTypeApi,
+{
+    /// The callee signalled an error explicitly, e.g. via `require!` or `sc_panic!`.
+    UserError(ManagedBuffer<M>),
+
+    /// The callee ran out of gas.
+    OutOfGas(ManagedBuffer<M>),
+
+    /// The VM could not complete the execution, e.g. a failed builtin function or an invalid call.
+    ExecutionFailed(ManagedBuffer<M>),
+
+    /// Any other VM return code.
+    Other {
+        err_code: u32,
+        err_msg: ManagedBuffer<M>,
+    },
+}
+
+impl<M> CallbackError<M>
+where
+    M: ManagedTypeApi,
+{
+    pub fn err_code(&self) -> u32 {
+        match self {
+            CallbackError::UserError(_) => USER_ERROR_CODE,
+            CallbackError::OutOfGas(_) => OUT_OF_GAS_CODE,
+            CallbackError::ExecutionFailed(_) => EXECUTION_FAILED_CODE,
+            CallbackError::Other { err_code, .. } => *err_code,
+        }
+    }
+
+    pub fn err_msg(&self) -> &ManagedBuffer<M> {
+        match self {
+            CallbackError::UserError(err_msg)
+            | CallbackError::OutOfGas(err_msg)
+            | CallbackError::ExecutionFailed(err_msg)
+            | CallbackError::Other { err_msg, .. } => err_msg,
+        }
+    }
+
+    #[inline]
+    pub fn is_user_error(&self) -> bool {
+        matches!(self, CallbackError::UserError(_))
+    }
+
+    #[inline]
+    pub fn is_out_of_gas(&self) -> bool {
+        matches!(self, CallbackError::OutOfGas(_))
+    }
+
+    #[inline]
+    pub fn is_execution_failed(&self) -> bool {
+        matches!(self, CallbackError::ExecutionFailed(_))
+    }
+}
+
+impl<M> From<ManagedAsyncCallError<M>> for CallbackError<M>
+where
+    M: ManagedTypeApi,
+{
+    fn from(error: ManagedAsyncCallError<M>) -> Self {
+        match error.err_code {
+            USER_ERROR_CODE => CallbackError::UserError(error.err_msg),
+            OUT_OF_GAS_CODE => CallbackError::OutOfGas(error.err_msg),
+            EXECUTION_FAILED_CODE => CallbackError::ExecutionFailed(error.err_msg),
+            err_code => CallbackError::Other {
+                err_code,
+                err_msg: error.err_msg,
+            },
+        }
+    }
+}
+
+impl<M> From<CallbackError<M>> for ManagedAsyncCallError<M>
+where
+    M: ManagedTypeApi,
+{
+    fn from(error: CallbackError<M>) -> Self {
+        let err_code = error.err_code();
+        let err_msg = match error {
+            CallbackError::UserError(err_msg)
+            | CallbackError::OutOfGas(err_msg)
+            | CallbackError::ExecutionFailed(err_msg)
+            | CallbackError::Other { err_msg, .. } => err_msg,
+        };
+        ManagedAsyncCallError { err_code, err_msg }
+    }
+}
+
+/// Typed alternative to `ManagedAsyncCallResult`, to be used as `#[call_result]` argument in callbacks.
+///
+/// Has the same encoding, but the error is split into categories.
+pub enum CallbackResult<M, T>
+where
+    M: ManagedTypeApi,
+{
+    Ok(T),
+    Err(CallbackError<M>),
+}
+
+impl<M, T> CallbackResult<M, T>
+where
+    M: ManagedTypeApi,
+{
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        matches!(self, CallbackResult::Ok(_))
+    }
+
+    #[inline]
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+
+    pub fn ok(self) -> Option<T> {
+        match self {
+            CallbackResult::Ok(result) => Some(result),
+            CallbackResult::Err(_) => None,
+        }
+    }
+
+    pub fn err(self) -> Option<CallbackError<M>> {
+        match self {
+            CallbackResult::Ok(_) => None,
+            CallbackResult::Err(error) => Some(error),
+        }
+    }
+
+    pub fn into_result(self) -> Result<T, CallbackError<M>> {
+        match self {
+            CallbackResult::Ok(result) => Ok(result),
+            CallbackResult::Err(error) => Err(error),
+        }
+    }
+
+    pub fn map<U, F>(self, f: F) -> CallbackResult<M, U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            CallbackResult::Ok(result) => CallbackResult::Ok(f(result)),
+            CallbackResult::Err(error) => CallbackResult::Err(error),
+        }
+    }
+
+    /// Returns the result, or computes a fallback value from the error.
+    pub fn unwrap_or_else<F>(self, f: F) -> T
+    where
+        F: FnOnce(CallbackError<M>) -> T,
+    {
+        match self {
+            CallbackResult::Ok(result) => result,
+            CallbackResult::Err(error) => f(error),
+        }
+    }
+}
+
+impl<M, T> From<ManagedAsyncCallResult<M, T>> for CallbackResult<M, T>
+where
+    M: ManagedTypeApi,
+{
+    fn from(result: ManagedAsyncCallResult<M, T>) -> Self {
+        match result {
+            ManagedAsyncCallResult::Ok(result) => CallbackResult::Ok(result),
+            ManagedAsyncCallResult::Err(error) => CallbackResult::Err(error.into()),
+        }
+    }
+}
+
+impl<M, T> From<CallbackResult<M, T>> for ManagedAsyncCallResult<M, T>
+where
+    M: ManagedTypeApi,
+{
+    fn from(result: CallbackResult<M, T>) -> Self {
+        match result {
+            CallbackResult::Ok(result) => ManagedAsyncCallResult::Ok(result),
+            CallbackResult::Err(error) => ManagedAsyncCallResult::Err(error.into()),
+        }
+    }
+}
+
+impl<M, T> TopDecodeMulti for CallbackResult<M, T>
+where
+    M: ManagedTypeApi,
+    T: TopDecodeMulti,
+{
+    fn multi_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeMultiInput,
+        H: DecodeErrorHandler,
+    {
+        ManagedAsyncCallResult::<M, T>::multi_decode_or_handle_err(input, h).map(Self::from)
+    }
+}
+
+impl<M, T> TopEncodeMulti for CallbackResult<M, T>
+where
+    M: ManagedTypeApi,
+    T: TopEncodeMulti,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        match self {
+            CallbackResult::Ok(result) => {
+                0u32.multi_encode_or_handle_err(output, h)?;
+                result.multi_encode_or_handle_err(output, h)?;
+            },
+            CallbackResult::Err(error) => {
+                error.err_code().multi_encode_or_handle_err(output, h)?;
+                error.err_msg().multi_encode_or_handle_err(output, h)?;
+            },
+        }
+        Ok(())
+    }
+}
+
+impl<M, T> TypeAbi for CallbackResult<M, T>
+where
+    M: ManagedTypeApi,
+    T: TypeAbi,
+{
+    fn type_name() -> TypeName {
+        ManagedAsyncCallResult::<M, T>::type_name()
+    }
+}
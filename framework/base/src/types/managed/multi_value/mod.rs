@@ -1,4 +1,5 @@
 mod async_call_result_managed;
+mod callback_result;
 mod esdt_token_payment_multi_value;
 mod multi_value_encoded;
 mod multi_value_encoded_iter;
@@ -6,6 +7,7 @@ mod multi_value_managed_vec;
 mod multi_value_managed_vec_counted;
 
 pub use async_call_result_managed::{ManagedAsyncCallError, ManagedAsyncCallResult};
+pub use callback_result::{CallbackError, CallbackResult};
 pub use esdt_token_payment_multi_value::{EsdtTokenPaymentMultiArg, EsdtTokenPaymentMultiValue};
 pub use multi_value_encoded::{ManagedMultiResultVec, ManagedVarArgs, MultiValueEncoded};
 pub use multi_value_encoded_iter::MultiValueEncodedIterator;
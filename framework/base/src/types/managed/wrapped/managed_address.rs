@@ -2,7 +2,7 @@ use core::convert::{TryFrom, TryInto};
 
 use crate::{
     abi::{TypeAbi, TypeName},
-    api::ManagedTypeApi,
+    api::{BlockchainApi, BlockchainApiImpl, ManagedTypeApi},
     codec::{
         CodecFrom, CodecFromSelf, DecodeError, DecodeErrorHandler, EncodeErrorHandler,
         NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode,
@@ -66,6 +66,27 @@ where
     }
 }
 
+impl<M> ManagedAddress<M>
+where
+    M: BlockchainApi,
+{
+    /// True if the address belongs to a smart contract, i.e. it starts with the reserved zero bytes.
+    ///
+    /// Only looks at the address itself, the contract does not need to exist.
+    #[inline]
+    pub fn is_smart_contract(&self) -> bool {
+        M::blockchain_api_impl().is_smart_contract(self.get_handle())
+    }
+
+    /// The shard the address belongs to, according to the protocol sharding rules.
+    ///
+    /// System smart contracts are on the metachain, with the shard id `u32::MAX`.
+    #[inline]
+    pub fn shard_id(&self) -> u32 {
+        M::blockchain_api_impl().get_shard_of_address(self.get_handle())
+    }
+}
+
 impl<M> From<&Address> for ManagedAddress<M>
 where
     M: ManagedTypeApi,
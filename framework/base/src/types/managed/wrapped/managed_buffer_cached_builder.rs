@@ -11,6 +11,9 @@ use crate::{
 
 const HEX_CONVERSION_BUFFER_LEN: usize = 32;
 const BIN_CONVERSION_BUFFER_LEN: usize = 32;
+const ZEROES: &[u8] = b"00000000000000000000000000000000";
+const MINUS_SYMBOL: &[u8] = b"-";
+const DECIMAL_POINT: &[u8] = b".";
 
 pub struct ManagedBufferCachedBuilder<M>
 where
@@ -114,6 +117,84 @@ where
         });
     }
 
+    fn append_zeroes(&mut self, mut count: usize) {
+        while count > 0 {
+            let chunk_len = core::cmp::min(count, ZEROES.len());
+            self.append_bytes(&ZEROES[..chunk_len]);
+            count -= chunk_len;
+        }
+    }
+
+    /// Formats the item separately, since its length needs to be known upfront.
+    ///
+    /// Splits off the minus sign, if present.
+    fn format_signed_digits<F>(format: F) -> (bool, ManagedBuffer<M>)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let mut item_builder = Self::default();
+        format(&mut item_builder);
+        let formatted = item_builder.into_managed_buffer();
+
+        let mut first_byte = [0u8; 1];
+        let negative =
+            formatted.load_slice(0, &mut first_byte[..]).is_ok() && first_byte[..] == *MINUS_SYMBOL;
+        if negative {
+            let digits = formatted
+                .copy_slice(1, formatted.len() - 1)
+                .unwrap_or_default();
+            (true, digits)
+        } else {
+            (false, formatted)
+        }
+    }
+
+    fn append_zero_padded<F>(&mut self, width: usize, format: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let (negative, digits) = Self::format_signed_digits(format);
+        let mut len = digits.len();
+        if negative {
+            self.append_bytes(MINUS_SYMBOL);
+            len += 1;
+        }
+        if width > len {
+            self.append_zeroes(width - len);
+        }
+        self.append_managed_buffer(&digits);
+    }
+
+    fn append_decimals<F>(&mut self, num_decimals: usize, format: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let (negative, digits) = Self::format_signed_digits(format);
+        if negative {
+            self.append_bytes(MINUS_SYMBOL);
+        }
+
+        let len = digits.len();
+        if len > num_decimals {
+            let integer_part = digits.copy_slice(0, len - num_decimals).unwrap_or_default();
+            self.append_managed_buffer(&integer_part);
+        } else {
+            self.append_zeroes(1);
+        }
+
+        if num_decimals > 0 {
+            self.append_bytes(DECIMAL_POINT);
+            if num_decimals > len {
+                self.append_zeroes(num_decimals - len);
+            }
+            let fractional_len = core::cmp::min(len, num_decimals);
+            let fractional_part = digits
+                .copy_slice(len - fractional_len, fractional_len)
+                .unwrap_or_default();
+            self.append_managed_buffer(&fractional_part);
+        }
+    }
+
     /// Converts the input to binary ASCII and adds it to the current buffer.
     pub fn append_managed_buffer_binary(&mut self, item: &ManagedBuffer<M>) {
         item.for_each_batch::<BIN_CONVERSION_BUFFER_LEN, _>(|batch| {
@@ -199,4 +280,16 @@ impl<M: ManagedTypeApi> FormatBuffer for ManagedBufferCachedBuilder<M> {
     fn append_codec<T: SCCodec>(&mut self, item: &T) {
         item.fmt(self);
     }
+
+    fn append_display_zero_padded<T: SCDisplay>(&mut self, item: &T, width: usize) {
+        self.append_zero_padded(width, |f| SCDisplay::fmt(item, f));
+    }
+
+    fn append_lower_hex_zero_padded<T: SCLowerHex>(&mut self, item: &T, width: usize) {
+        self.append_zero_padded(width, |f| SCLowerHex::fmt(item, f));
+    }
+
+    fn append_display_decimals<T: SCDisplay>(&mut self, item: &T, num_decimals: usize) {
+        self.append_decimals(num_decimals, |f| SCDisplay::fmt(item, f));
+    }
 }
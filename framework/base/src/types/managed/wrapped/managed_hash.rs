@@ -0,0 +1,208 @@
+use crate::{
+    abi::{TypeAbi, TypeName},
+    api::ManagedTypeApi,
+    codec::{
+        CodecFrom, CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
+        NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+        TopEncodeOutput,
+    },
+    formatter::{hex_util::encode_bytes_as_hex, FormatByteReceiver, SCLowerHex},
+    types::{heap::H256, ManagedBuffer, ManagedByteArray, ManagedType},
+};
+
+macro_rules! managed_hash {
+    ($(#[$attr:meta])* $name:ident, $len:expr, $type_name:expr) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Clone)]
+        pub struct $name<M: ManagedTypeApi> {
+            bytes: ManagedByteArray<M, $len>,
+        }
+
+        impl<M: ManagedTypeApi> $name<M> {
+            #[inline]
+            pub fn new_from_bytes(bytes: &[u8; $len]) -> Self {
+                $name {
+                    bytes: ManagedByteArray::new_from_bytes(bytes),
+                }
+            }
+
+            #[inline]
+            pub fn zero() -> Self {
+                Self::new_from_bytes(&[0u8; $len])
+            }
+
+            #[inline]
+            pub fn is_zero(&self) -> bool {
+                self.bytes.buffer == &[0u8; $len]
+            }
+
+            #[inline]
+            pub fn as_managed_buffer(&self) -> &ManagedBuffer<M> {
+                self.bytes.as_managed_buffer()
+            }
+
+            #[inline]
+            pub fn as_managed_byte_array(&self) -> &ManagedByteArray<M, $len> {
+                &self.bytes
+            }
+
+            #[inline]
+            pub fn to_byte_array(&self) -> [u8; $len] {
+                self.bytes.to_byte_array()
+            }
+        }
+
+        impl<M: ManagedTypeApi> From<ManagedByteArray<M, $len>> for $name<M> {
+            #[inline]
+            fn from(bytes: ManagedByteArray<M, $len>) -> Self {
+                $name { bytes }
+            }
+        }
+
+        impl<M: ManagedTypeApi> From<$name<M>> for ManagedByteArray<M, $len> {
+            #[inline]
+            fn from(hash: $name<M>) -> Self {
+                hash.bytes
+            }
+        }
+
+        impl<M: ManagedTypeApi> From<&[u8; $len]> for $name<M> {
+            #[inline]
+            fn from(bytes: &[u8; $len]) -> Self {
+                Self::new_from_bytes(bytes)
+            }
+        }
+
+        impl<M: ManagedTypeApi> From<[u8; $len]> for $name<M> {
+            #[inline]
+            fn from(bytes: [u8; $len]) -> Self {
+                Self::new_from_bytes(&bytes)
+            }
+        }
+
+        impl<M: ManagedTypeApi> ManagedType<M> for $name<M> {
+            type OwnHandle = M::ManagedBufferHandle;
+
+            #[inline]
+            fn from_handle(handle: M::ManagedBufferHandle) -> Self {
+                $name {
+                    bytes: ManagedByteArray::from_handle(handle),
+                }
+            }
+
+            fn get_handle(&self) -> M::ManagedBufferHandle {
+                self.bytes.get_handle()
+            }
+
+            fn transmute_from_handle_ref(handle_ref: &M::ManagedBufferHandle) -> &Self {
+                unsafe { core::mem::transmute(handle_ref) }
+            }
+        }
+
+        impl<M: ManagedTypeApi> Default for $name<M> {
+            #[inline]
+            fn default() -> Self {
+                Self::zero()
+            }
+        }
+
+        impl<M: ManagedTypeApi> PartialEq for $name<M> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.bytes == other.bytes
+            }
+        }
+
+        impl<M: ManagedTypeApi> Eq for $name<M> {}
+
+        impl<M: ManagedTypeApi> TopEncode for $name<M> {
+            #[inline]
+            fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+            where
+                O: TopEncodeOutput,
+                H: EncodeErrorHandler,
+            {
+                self.bytes.top_encode_or_handle_err(output, h)
+            }
+        }
+
+        impl<M: ManagedTypeApi> TopDecode for $name<M> {
+            fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+            where
+                I: TopDecodeInput,
+                H: DecodeErrorHandler,
+            {
+                Ok($name {
+                    bytes: ManagedByteArray::top_decode_or_handle_err(input, h)?,
+                })
+            }
+        }
+
+        impl<M: ManagedTypeApi> NestedEncode for $name<M> {
+            #[inline]
+            fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+            where
+                O: NestedEncodeOutput,
+                H: EncodeErrorHandler,
+            {
+                self.bytes.dep_encode_or_handle_err(dest, h)
+            }
+        }
+
+        impl<M: ManagedTypeApi> NestedDecode for $name<M> {
+            fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+            where
+                I: NestedDecodeInput,
+                H: DecodeErrorHandler,
+            {
+                Ok($name {
+                    bytes: ManagedByteArray::dep_decode_or_handle_err(input, h)?,
+                })
+            }
+        }
+
+        impl<M: ManagedTypeApi> TypeAbi for $name<M> {
+            fn type_name() -> TypeName {
+                $type_name
+            }
+        }
+
+        impl<M: ManagedTypeApi> SCLowerHex for $name<M> {
+            fn fmt<F: FormatByteReceiver>(&self, f: &mut F) {
+                SCLowerHex::fmt(&self.bytes, f)
+            }
+        }
+
+        impl<M: ManagedTypeApi> core::fmt::Debug for $name<M> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("handle", &self.bytes.buffer.handle)
+                    .field("hex-value", &encode_bytes_as_hex(&self.to_byte_array()))
+                    .finish()
+            }
+        }
+
+        impl<M: ManagedTypeApi> CodecFromSelf for $name<M> {}
+
+        impl<M: ManagedTypeApi> CodecFrom<[u8; $len]> for $name<M> {}
+
+        impl<M: ManagedTypeApi> CodecFrom<ManagedByteArray<M, $len>> for $name<M> {}
+
+        impl<M: ManagedTypeApi> CodecFrom<$name<M>> for ManagedByteArray<M, $len> {}
+    };
+}
+
+managed_hash! {
+    /// A 32-byte hash, e.g. the result of `sha256` or `keccak256`, kept in a managed buffer.
+    ///
+    /// Encodes exactly like a `ManagedByteArray<M, 32>`, and appears as `H256` in the ABI.
+    Hash32, 32, H256::type_name()
+}
+
+managed_hash! {
+    /// A 20-byte hash, e.g. the result of `ripemd160`, kept in a managed buffer.
+    ///
+    /// Encodes exactly like a `ManagedByteArray<M, 20>`, and appears as `H160` in the ABI.
+    Hash20, 20, TypeName::from("H160")
+}
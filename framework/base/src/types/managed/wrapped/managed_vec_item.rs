@@ -3,8 +3,8 @@ use core::{borrow::Borrow, mem::MaybeUninit};
 use crate::{
     api::ManagedTypeApi,
     types::{
-        BigInt, BigUint, EllipticCurve, ManagedAddress, ManagedBuffer, ManagedByteArray,
-        ManagedRef, ManagedType, ManagedVec, TokenIdentifier,
+        BigInt, BigUint, EllipticCurve, Hash20, Hash32, ManagedAddress, ManagedBuffer,
+        ManagedByteArray, ManagedRef, ManagedType, ManagedVec, TokenIdentifier,
     },
 };
 
@@ -194,6 +194,8 @@ impl_managed_type! {BigUint}
 impl_managed_type! {BigInt}
 impl_managed_type! {EllipticCurve}
 impl_managed_type! {ManagedAddress}
+impl_managed_type! {Hash32}
+impl_managed_type! {Hash20}
 impl_managed_type! {TokenIdentifier}
 
 impl<M, const N: usize> ManagedVecItem for ManagedByteArray<M, N>
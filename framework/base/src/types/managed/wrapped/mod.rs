@@ -7,6 +7,7 @@ mod esdt_token_payment;
 mod managed_address;
 mod managed_buffer_cached_builder;
 mod managed_byte_array;
+mod managed_hash;
 mod managed_option;
 mod managed_ref;
 mod managed_vec;
@@ -16,6 +17,7 @@ mod managed_vec_ref;
 mod managed_vec_ref_iter;
 pub(crate) mod preloaded_managed_buffer;
 mod randomness_source;
+mod token_amount;
 mod token_identifier;
 
 pub use egld_or_esdt_token_identifier::EgldOrEsdtTokenIdentifier;
@@ -28,6 +30,7 @@ pub use managed_address::ManagedAddress;
 pub use managed_buffer_cached_builder::ManagedBufferCachedBuilder;
 pub(crate) use managed_byte_array::ManagedBufferSizeContext;
 pub use managed_byte_array::ManagedByteArray;
+pub use managed_hash::{Hash20, Hash32};
 pub use managed_option::ManagedOption;
 pub use managed_ref::ManagedRef;
 pub use managed_vec::ManagedVec;
@@ -36,4 +39,5 @@ pub use managed_vec_owned_iter::ManagedVecOwnedIterator;
 pub use managed_vec_ref::ManagedVecRef;
 pub use managed_vec_ref_iter::ManagedVecRefIterator;
 pub use randomness_source::RandomnessSource;
+pub use token_amount::{Egld, TokenAmount, EGLD_NUM_DECIMALS};
 pub use token_identifier::TokenIdentifier;
@@ -0,0 +1,379 @@
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, Mul, Sub, SubAssign},
+};
+
+use crate::{
+    abi::{TypeAbi, TypeName},
+    api::{ErrorApiImpl, ManagedTypeApi},
+    codec::*,
+    err_msg,
+    types::{BigUint, EsdtTokenPayment, TokenIdentifier},
+};
+
+/// Number of decimals of EGLD: 1 EGLD is 10^18 of its smallest unit.
+pub const EGLD_NUM_DECIMALS: u32 = 18;
+
+/// An amount of EGLD, kept in its smallest unit (10^-18 EGLD), the way the VM handles it.
+///
+/// Unlike a plain `BigUint`, it can only be added to or subtracted from other EGLD amounts,
+/// so it cannot be mixed up with token amounts, or with a number of whole EGLD.
+/// Conversions to and from other scales are explicit.
+///
+/// Encodes and decodes exactly like a `BigUint`.
+#[repr(transparent)]
+#[derive(Clone, Debug)]
+pub struct Egld<M: ManagedTypeApi> {
+    wei: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> Egld<M> {
+    #[inline]
+    pub fn zero() -> Self {
+        Egld {
+            wei: BigUint::zero(),
+        }
+    }
+
+    /// Amount given in the smallest unit, 10^-18 EGLD, as returned by the VM.
+    #[inline]
+    pub fn from_wei(wei: BigUint<M>) -> Self {
+        Egld { wei }
+    }
+
+    /// Amount given in whole EGLD.
+    pub fn from_egld(egld: u64) -> Self {
+        Egld {
+            wei: BigUint::from(egld) * BigUint::from(10u32).pow(EGLD_NUM_DECIMALS),
+        }
+    }
+
+    /// The amount in the smallest unit, 10^-18 EGLD, as expected by the VM.
+    #[inline]
+    pub fn as_wei(&self) -> &BigUint<M> {
+        &self.wei
+    }
+
+    #[inline]
+    pub fn into_wei(self) -> BigUint<M> {
+        self.wei
+    }
+
+    /// The amount in whole EGLD, rounded down.
+    pub fn to_whole_egld(&self) -> BigUint<M> {
+        &self.wei / &BigUint::from(10u32).pow(EGLD_NUM_DECIMALS)
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.wei == 0u32
+    }
+}
+
+impl<M: ManagedTypeApi> Default for Egld<M> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<M: ManagedTypeApi> PartialEq for Egld<M> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.wei == other.wei
+    }
+}
+
+impl<M: ManagedTypeApi> Eq for Egld<M> {}
+
+impl<M: ManagedTypeApi> PartialOrd for Egld<M> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M: ManagedTypeApi> Ord for Egld<M> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.wei.cmp(&other.wei)
+    }
+}
+
+macro_rules! egld_binary_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<M: ManagedTypeApi> $trait<Egld<M>> for Egld<M> {
+            type Output = Egld<M>;
+
+            #[inline]
+            fn $method(self, other: Egld<M>) -> Egld<M> {
+                Egld::from_wei(self.wei.$method(other.wei))
+            }
+        }
+
+        impl<'b, M: ManagedTypeApi> $trait<&'b Egld<M>> for Egld<M> {
+            type Output = Egld<M>;
+
+            #[inline]
+            fn $method(self, other: &Egld<M>) -> Egld<M> {
+                Egld::from_wei(self.wei.$method(&other.wei))
+            }
+        }
+
+        impl<'a, 'b, M: ManagedTypeApi> $trait<&'b Egld<M>> for &'a Egld<M> {
+            type Output = Egld<M>;
+
+            #[inline]
+            fn $method(self, other: &Egld<M>) -> Egld<M> {
+                Egld::from_wei((&self.wei).$method(&other.wei))
+            }
+        }
+
+        impl<M: ManagedTypeApi> $assign_trait<Egld<M>> for Egld<M> {
+            #[inline]
+            fn $assign_method(&mut self, other: Egld<M>) {
+                self.wei.$assign_method(other.wei);
+            }
+        }
+
+        impl<M: ManagedTypeApi> $assign_trait<&Egld<M>> for Egld<M> {
+            #[inline]
+            fn $assign_method(&mut self, other: &Egld<M>) {
+                self.wei.$assign_method(&other.wei);
+            }
+        }
+    };
+}
+
+egld_binary_operator! {Add, add, AddAssign, add_assign}
+egld_binary_operator! {Sub, sub, SubAssign, sub_assign}
+
+macro_rules! egld_scale_operator {
+    ($trait:ident, $method:ident) => {
+        impl<M: ManagedTypeApi> $trait<u64> for Egld<M> {
+            type Output = Egld<M>;
+
+            #[inline]
+            fn $method(self, other: u64) -> Egld<M> {
+                Egld::from_wei(self.wei.$method(other))
+            }
+        }
+
+        impl<'a, M: ManagedTypeApi> $trait<u64> for &'a Egld<M> {
+            type Output = Egld<M>;
+
+            #[inline]
+            fn $method(self, other: u64) -> Egld<M> {
+                Egld::from_wei((&self.wei).$method(other))
+            }
+        }
+    };
+}
+
+egld_scale_operator! {Mul, mul}
+egld_scale_operator! {Div, div}
+
+impl<M: ManagedTypeApi> TopEncode for Egld<M> {
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.wei.top_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedEncode for Egld<M> {
+    #[inline]
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.wei.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi> TopDecode for Egld<M> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Egld::from_wei(BigUint::top_decode_or_handle_err(input, h)?))
+    }
+}
+
+impl<M: ManagedTypeApi> NestedDecode for Egld<M> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Egld::from_wei(BigUint::dep_decode_or_handle_err(input, h)?))
+    }
+}
+
+impl<M> CodecFromSelf for Egld<M> where M: ManagedTypeApi {}
+
+impl<M> CodecFrom<BigUint<M>> for Egld<M> where M: ManagedTypeApi {}
+
+impl<M> CodecFrom<Egld<M>> for BigUint<M> where M: ManagedTypeApi {}
+
+impl<M: ManagedTypeApi> TypeAbi for Egld<M> {
+    fn type_name() -> TypeName {
+        BigUint::<M>::type_name()
+    }
+}
+
+/// An amount of a fungible ESDT, in the token's smallest unit, together with the token it is denominated in.
+///
+/// Amounts can only be added to or subtracted from amounts of the same token,
+/// mixing tokens signals an error.
+/// Conversions from whole units require the number of decimals of the token to be given explicitly.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TokenAmount<M: ManagedTypeApi> {
+    token_identifier: TokenIdentifier<M>,
+    amount: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> TokenAmount<M> {
+    /// Amount given in the smallest unit of the token, as handled by the VM.
+    #[inline]
+    pub fn new(token_identifier: TokenIdentifier<M>, amount: BigUint<M>) -> Self {
+        TokenAmount {
+            token_identifier,
+            amount,
+        }
+    }
+
+    #[inline]
+    pub fn zero(token_identifier: TokenIdentifier<M>) -> Self {
+        Self::new(token_identifier, BigUint::zero())
+    }
+
+    /// Amount given in whole tokens, for a token with `num_decimals` decimals.
+    pub fn from_whole(token_identifier: TokenIdentifier<M>, whole: u64, num_decimals: u32) -> Self {
+        Self::new(
+            token_identifier,
+            BigUint::from(whole) * BigUint::from(10u32).pow(num_decimals),
+        )
+    }
+
+    #[inline]
+    pub fn token_identifier(&self) -> &TokenIdentifier<M> {
+        &self.token_identifier
+    }
+
+    /// The amount in the smallest unit of the token.
+    #[inline]
+    pub fn amount(&self) -> &BigUint<M> {
+        &self.amount
+    }
+
+    #[inline]
+    pub fn into_amount(self) -> BigUint<M> {
+        self.amount
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0u32
+    }
+
+    /// The amount in whole tokens, rounded down, for a token with `num_decimals` decimals.
+    pub fn to_whole(&self, num_decimals: u32) -> BigUint<M> {
+        &self.amount / &BigUint::from(10u32).pow(num_decimals)
+    }
+
+    /// Signals an error if the other amount is of a different token.
+    fn require_same_token(&self, other: &TokenAmount<M>) {
+        if self.token_identifier != other.token_identifier {
+            M::error_api_impl().signal_error(err_msg::TOKEN_AMOUNT_MISMATCH);
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> From<TokenAmount<M>> for EsdtTokenPayment<M> {
+    #[inline]
+    fn from(value: TokenAmount<M>) -> Self {
+        EsdtTokenPayment::new(value.token_identifier, 0, value.amount)
+    }
+}
+
+macro_rules! token_amount_binary_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<M: ManagedTypeApi> $trait<TokenAmount<M>> for TokenAmount<M> {
+            type Output = TokenAmount<M>;
+
+            fn $method(self, other: TokenAmount<M>) -> TokenAmount<M> {
+                self.require_same_token(&other);
+                TokenAmount::new(self.token_identifier, self.amount.$method(other.amount))
+            }
+        }
+
+        impl<'b, M: ManagedTypeApi> $trait<&'b TokenAmount<M>> for TokenAmount<M> {
+            type Output = TokenAmount<M>;
+
+            fn $method(self, other: &TokenAmount<M>) -> TokenAmount<M> {
+                self.require_same_token(other);
+                TokenAmount::new(self.token_identifier, self.amount.$method(&other.amount))
+            }
+        }
+
+        impl<'a, 'b, M: ManagedTypeApi> $trait<&'b TokenAmount<M>> for &'a TokenAmount<M> {
+            type Output = TokenAmount<M>;
+
+            fn $method(self, other: &TokenAmount<M>) -> TokenAmount<M> {
+                self.require_same_token(other);
+                TokenAmount::new(
+                    self.token_identifier.clone(),
+                    (&self.amount).$method(&other.amount),
+                )
+            }
+        }
+
+        impl<M: ManagedTypeApi> $assign_trait<TokenAmount<M>> for TokenAmount<M> {
+            fn $assign_method(&mut self, other: TokenAmount<M>) {
+                self.require_same_token(&other);
+                self.amount.$assign_method(other.amount);
+            }
+        }
+
+        impl<M: ManagedTypeApi> $assign_trait<&TokenAmount<M>> for TokenAmount<M> {
+            fn $assign_method(&mut self, other: &TokenAmount<M>) {
+                self.require_same_token(other);
+                self.amount.$assign_method(&other.amount);
+            }
+        }
+    };
+}
+
+token_amount_binary_operator! {Add, add, AddAssign, add_assign}
+token_amount_binary_operator! {Sub, sub, SubAssign, sub_assign}
+
+macro_rules! token_amount_scale_operator {
+    ($trait:ident, $method:ident) => {
+        impl<M: ManagedTypeApi> $trait<u64> for TokenAmount<M> {
+            type Output = TokenAmount<M>;
+
+            #[inline]
+            fn $method(self, other: u64) -> TokenAmount<M> {
+                TokenAmount::new(self.token_identifier, self.amount.$method(other))
+            }
+        }
+
+        impl<'a, M: ManagedTypeApi> $trait<u64> for &'a TokenAmount<M> {
+            type Output = TokenAmount<M>;
+
+            #[inline]
+            fn $method(self, other: u64) -> TokenAmount<M> {
+                TokenAmount::new(self.token_identifier.clone(), (&self.amount).$method(other))
+            }
+        }
+    };
+}
+
+token_amount_scale_operator! {Mul, mul}
+token_amount_scale_operator! {Div, div}
@@ -0,0 +1,19 @@
+use multiversx_sc::contract_base::split_error_code;
+
+#[test]
+fn test_split_error_code() {
+    assert_eq!(
+        split_error_code(b"[12] Static error"),
+        Some((12, &b"Static error"[..]))
+    );
+    assert_eq!(split_error_code(b"[0] "), Some((0, &b""[..])));
+}
+
+#[test]
+fn test_split_error_code_missing() {
+    assert_eq!(split_error_code(b"Static error"), None);
+    assert_eq!(split_error_code(b"[] Static error"), None);
+    assert_eq!(split_error_code(b"[12]Static error"), None);
+    assert_eq!(split_error_code(b"[1x] Static error"), None);
+    assert_eq!(split_error_code(b"[99999999999] too large"), None);
+}
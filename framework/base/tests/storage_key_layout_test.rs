@@ -0,0 +1,177 @@
+use multiversx_sc::abi::{ContractAbi, StorageKeyAbi, StorageKeyInputAbi};
+
+fn storage_key_abi(
+    name: &'static str,
+    key: &'static str,
+    mapper: Option<&'static str>,
+    value_type: Option<&'static str>,
+) -> StorageKeyAbi {
+    StorageKeyAbi {
+        name,
+        key,
+        mapper,
+        inputs: &[],
+        value_type,
+    }
+}
+
+const ADDRESS_INPUT: &[StorageKeyInputAbi] = &[StorageKeyInputAbi {
+    arg_name: "address",
+    type_name: "Address",
+}];
+
+#[test]
+fn storage_key_shared_by_getter_and_mapper_test() {
+    let mut abi = ContractAbi::default();
+    abi.add_storage_key(storage_key_abi(
+        "sum",
+        "sum",
+        Some("SingleValueMapper"),
+        Some("BigUint"),
+    ));
+    abi.add_storage_key(storage_key_abi("get_sum", "sum", None, Some("BigUint")));
+    abi.add_storage_key(storage_key_abi("is_empty_sum", "sum", None, None));
+    assert_eq!(abi.storage_keys.len(), 1);
+}
+
+#[test]
+fn storage_key_mapper_recorded_over_getter_test() {
+    let mut abi = ContractAbi::default();
+    abi.add_storage_key(storage_key_abi("get_sum", "sum", None, Some("BigUint")));
+    abi.add_storage_key(storage_key_abi(
+        "sum",
+        "sum",
+        Some("SingleValueMapper"),
+        Some("BigUint"),
+    ));
+    assert_eq!(abi.storage_keys.len(), 1);
+    assert_eq!(abi.storage_keys[0].name, "sum");
+    assert_eq!(abi.storage_keys[0].mapper, Some("SingleValueMapper"));
+}
+
+#[test]
+fn storage_key_same_declaration_added_twice_test() {
+    let mut abi = ContractAbi::default();
+    abi.add_storage_key(storage_key_abi(
+        "users",
+        "users",
+        Some("VecMapper"),
+        Some("u32"),
+    ));
+    abi.add_storage_key(storage_key_abi(
+        "users",
+        "users",
+        Some("VecMapper"),
+        Some("u32"),
+    ));
+    assert_eq!(abi.storage_keys.len(), 1);
+}
+
+#[test]
+fn storage_key_checked_by_is_empty_test() {
+    let mut abi = ContractAbi::default();
+    abi.add_storage_key(storage_key_abi(
+        "users",
+        "users",
+        Some("VecMapper"),
+        Some("u32"),
+    ));
+    abi.add_storage_key(storage_key_abi("clear_users", "users", None, None));
+    assert_eq!(abi.storage_keys.len(), 1);
+}
+
+#[test]
+#[should_panic(
+    expected = "Storage key `users` is used by both method `users` and method `user_set`: only one storage mapper can be declared per key."
+)]
+fn storage_key_layout_clash_test() {
+    let mut abi = ContractAbi::default();
+    abi.add_storage_key(storage_key_abi(
+        "users",
+        "users",
+        Some("VecMapper"),
+        Some("u32"),
+    ));
+    abi.add_storage_key(storage_key_abi(
+        "user_set",
+        "users",
+        Some("SetMapper"),
+        Some("u32"),
+    ));
+}
+
+#[test]
+#[should_panic(
+    expected = "Storage key `users` is used by both method `users` and method `get_users`: layouts `VecMapper` and `SingleValueMapper` differ."
+)]
+fn storage_key_layout_clash_across_modules_test() {
+    let mut module_abi = ContractAbi::default();
+    module_abi.add_storage_key(storage_key_abi(
+        "users",
+        "users",
+        Some("VecMapper"),
+        Some("u32"),
+    ));
+
+    let mut contract_abi = ContractAbi::default();
+    contract_abi.add_storage_key(storage_key_abi("get_users", "users", None, Some("u32")));
+    module_abi.coalesce(contract_abi);
+}
+
+#[test]
+#[should_panic(
+    expected = "Storage key `users` is used by both method `users` and method `other_users`: only one storage mapper can be declared per key."
+)]
+fn storage_key_same_mapper_clash_test() {
+    let mut abi = ContractAbi::default();
+    abi.add_storage_key(storage_key_abi(
+        "users",
+        "users",
+        Some("VecMapper"),
+        Some("u32"),
+    ));
+    abi.add_storage_key(storage_key_abi(
+        "other_users",
+        "users",
+        Some("VecMapper"),
+        Some("u32"),
+    ));
+}
+
+#[test]
+#[should_panic(
+    expected = "Storage key `sum` is used by both method `sum` and method `get_sum`: value types `BigUint` and `u64` differ."
+)]
+fn storage_key_value_type_clash_test() {
+    let mut abi = ContractAbi::default();
+    abi.add_storage_key(storage_key_abi(
+        "sum",
+        "sum",
+        Some("SingleValueMapper"),
+        Some("BigUint"),
+    ));
+    abi.add_storage_key(storage_key_abi("get_sum", "sum", None, Some("u64")));
+}
+
+#[test]
+#[should_panic(
+    expected = "Storage key `deposit` is used by both method `deposit` and method `get_deposit`: 1 and 0 key arguments."
+)]
+fn storage_key_arity_clash_test() {
+    let mut abi = ContractAbi::default();
+    abi.add_storage_key(StorageKeyAbi {
+        inputs: ADDRESS_INPUT,
+        ..storage_key_abi(
+            "deposit",
+            "deposit",
+            Some("SingleValueMapper"),
+            Some("BigUint"),
+        )
+    });
+    abi.add_storage_key(storage_key_abi(
+        "get_deposit",
+        "deposit",
+        None,
+        Some("BigUint"),
+    ));
+}
@@ -62,6 +62,27 @@ pub fn format_receiver_args_macro(input: proc_macro::TokenStream) -> proc_macro:
                     multiversx_sc::formatter::FormatBuffer::append_binary(&mut $accumulator_expr, &$arg_expr);
                 )
             },
+            FormatPartType::DisplayZeroPadded(width) => {
+                let arg_expr = tokens_iter.next().unwrap();
+                let width_literal = proc_macro::Literal::usize_unsuffixed(width);
+                quote! (
+                    multiversx_sc::formatter::FormatBuffer::append_display_zero_padded(&mut $accumulator_expr, &$arg_expr, $width_literal);
+                )
+            },
+            FormatPartType::LowerHexZeroPadded(width) => {
+                let arg_expr = tokens_iter.next().unwrap();
+                let width_literal = proc_macro::Literal::usize_unsuffixed(width);
+                quote! (
+                    multiversx_sc::formatter::FormatBuffer::append_lower_hex_zero_padded(&mut $accumulator_expr, &$arg_expr, $width_literal);
+                )
+            },
+            FormatPartType::DisplayDecimals(num_decimals) => {
+                let arg_expr = tokens_iter.next().unwrap();
+                let num_decimals_literal = proc_macro::Literal::usize_unsuffixed(num_decimals);
+                quote! (
+                    multiversx_sc::formatter::FormatBuffer::append_display_decimals(&mut $accumulator_expr, &$arg_expr, $num_decimals_literal);
+                )
+            },
         }
     }).collect()
 }
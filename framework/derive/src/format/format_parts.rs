@@ -4,6 +4,8 @@ const TWO_DOTS: u8 = b':';
 const X_LETTER: u8 = b'x';
 const B_LETTER: u8 = b'b';
 const C_LETTER: u8 = b'c';
+const ZERO: u8 = b'0';
+const DOT: u8 = b'.';
 
 const UNMATCHED_BRACE_ERR_MSG: &str = "Unmatched `{` in the format string";
 
@@ -14,6 +16,32 @@ pub enum FormatPartType {
     Display,
     Codec,
     Bytes,
+    /// `{:0N}`: display, left-padded with zeroes up to N characters.
+    DisplayZeroPadded(usize),
+    /// `{:0Nx}`: lower hex, left-padded with zeroes up to N characters.
+    LowerHexZeroPadded(usize),
+    /// `{:.N}`: integer display, scaled down by 10^N, with exactly N decimals.
+    DisplayDecimals(usize),
+}
+
+fn parse_spec_width(digits: &[u8]) -> usize {
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        panic!("{}", UNMATCHED_BRACE_ERR_MSG);
+    }
+    core::str::from_utf8(digits).unwrap().parse().unwrap()
+}
+
+/// Parses what comes between `{:` and `}`.
+fn parse_format_spec(spec: &[u8]) -> FormatPartType {
+    match spec {
+        [X_LETTER] => FormatPartType::LowerHex,
+        [B_LETTER] => FormatPartType::Bytes,
+        [C_LETTER] => FormatPartType::Codec,
+        [ZERO, width @ .., X_LETTER] => FormatPartType::LowerHexZeroPadded(parse_spec_width(width)),
+        [ZERO, width @ ..] => FormatPartType::DisplayZeroPadded(parse_spec_width(width)),
+        [DOT, num_decimals @ ..] => FormatPartType::DisplayDecimals(parse_spec_width(num_decimals)),
+        _ => panic!("{}", UNMATCHED_BRACE_ERR_MSG),
+    }
 }
 
 pub fn parse_format_string(raw_string: &str) -> Vec<FormatPartType> {
@@ -24,7 +52,6 @@ pub fn parse_format_string(raw_string: &str) -> Vec<FormatPartType> {
     let ascii_bytes = raw_string.as_bytes();
     let mut parts = Vec::new();
     let mut start_index = 1;
-    let mut format_byte;
 
     // starting from 1 and up to len - 1 to skip the ""
     let str_len = ascii_bytes.len() - 1;
@@ -50,41 +77,25 @@ pub fn parse_format_string(raw_string: &str) -> Vec<FormatPartType> {
                     start_index = i + 2;
                 },
                 TWO_DOTS => {
-                    match ascii_bytes.get(i + 2) {
-                        Some(letter) => {
-                            if *letter != X_LETTER && *letter != B_LETTER && *letter != C_LETTER {
-                                panic!("{}", UNMATCHED_BRACE_ERR_MSG);
-                            }
-                            format_byte = letter;
-                        },
-                        None => panic!("{}", UNMATCHED_BRACE_ERR_MSG),
-                    }
-                    match ascii_bytes.get(i + 3) {
-                        Some(closed_brace) => {
-                            if *closed_brace != CLOSED_BRACE {
-                                panic!("{}", UNMATCHED_BRACE_ERR_MSG);
-                            }
-                        },
-                        None => panic!("{}", UNMATCHED_BRACE_ERR_MSG),
-                    }
+                    let spec_start = i + 2;
+                    let spec_len = ascii_bytes[spec_start..str_len]
+                        .iter()
+                        .position(|byte| *byte == CLOSED_BRACE)
+                        .unwrap_or_else(|| panic!("{}", UNMATCHED_BRACE_ERR_MSG));
+                    let spec_end = spec_start + spec_len;
 
                     if i > 1 {
                         let end_index = i - 1;
-                        if start_index != end_index {
+                        if start_index <= end_index {
                             let static_part = &ascii_bytes[start_index..=end_index];
                             let as_str = String::from_utf8(static_part.to_vec()).unwrap();
                             parts.push(FormatPartType::StaticAscii(as_str));
                         }
                     }
-                    if *format_byte == X_LETTER {
-                        parts.push(FormatPartType::LowerHex);
-                    } else if *format_byte == B_LETTER {
-                        parts.push(FormatPartType::Bytes);
-                    } else if *format_byte == C_LETTER {
-                        parts.push(FormatPartType::Codec);
-                    }
 
-                    start_index = i + 4;
+                    parts.push(parse_format_spec(&ascii_bytes[spec_start..spec_end]));
+
+                    start_index = spec_end + 1;
                 },
                 _ => panic!("{}", UNMATCHED_BRACE_ERR_MSG),
             },
@@ -109,6 +120,9 @@ pub(crate) fn count_args(format_types: &[FormatPartType]) -> usize {
             FormatPartType::LowerHex => nr_args += 1,
             FormatPartType::Codec => nr_args += 1,
             FormatPartType::Bytes => nr_args += 1,
+            FormatPartType::DisplayZeroPadded(_) => nr_args += 1,
+            FormatPartType::LowerHexZeroPadded(_) => nr_args += 1,
+            FormatPartType::DisplayDecimals(_) => nr_args += 1,
             FormatPartType::StaticAscii(_) => {},
         }
     }
@@ -153,3 +167,20 @@ fn test_format_parts_multiple_chars_between_displays() {
         ]
     );
 }
+
+#[test]
+fn test_format_parts_with_spec() {
+    assert_eq!(
+        parse_format_string("\"a{:x}b{:08}c{:04x}d{:.6}\""),
+        vec![
+            FormatPartType::StaticAscii("a".to_string()),
+            FormatPartType::LowerHex,
+            FormatPartType::StaticAscii("b".to_string()),
+            FormatPartType::DisplayZeroPadded(8),
+            FormatPartType::StaticAscii("c".to_string()),
+            FormatPartType::LowerHexZeroPadded(4),
+            FormatPartType::StaticAscii("d".to_string()),
+            FormatPartType::DisplayDecimals(6),
+        ]
+    );
+}
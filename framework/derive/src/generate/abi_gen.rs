@@ -1,9 +1,32 @@
-use super::util::*;
+use super::{payable_gen::accepted_tokens_snippet, util::*};
 use crate::model::{
-    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method, MethodImpl,
-    PublicRole,
+    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method,
+    MethodArgument, MethodImpl, MethodPayableMetadata, PublicRole,
 };
 
+/// Recognizes `Result<T, E>` return types, and retrieves `E`.
+fn result_error_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let type_path = match ty {
+        syn::Type::Path(type_path) => type_path,
+        _ => return None,
+    };
+    let last_segment = type_path.path.segments.last()?;
+    if last_segment.ident != "Result" {
+        return None;
+    }
+    let generic_args = match &last_segment.arguments {
+        syn::PathArguments::AngleBracketed(generic_args) => generic_args,
+        _ => return None,
+    };
+    if generic_args.args.len() != 2 {
+        return None;
+    }
+    match generic_args.args.last()? {
+        syn::GenericArgument::Type(err_type) => Some(err_type),
+        _ => None,
+    }
+}
+
 fn generate_endpoint_snippet(
     m: &Method,
     endpoint_name: &str,
@@ -14,7 +37,13 @@ fn generate_endpoint_snippet(
 ) -> proc_macro2::TokenStream {
     let endpoint_docs = &m.docs;
     let rust_method_name = m.name.to_string();
-    let payable_in_tokens = m.payable_metadata().abi_strings();
+    let payable_in_tokens = match m.payable_metadata() {
+        MethodPayableMetadata::TokenList(token_list) => accepted_tokens_snippet(&token_list),
+        payable_metadata => {
+            let abi_strings = payable_metadata.abi_strings();
+            quote! { &[ #(#abi_strings),* ] }
+        },
+    };
 
     let input_snippets: Vec<proc_macro2::TokenStream> = m
         .method_args
@@ -41,9 +70,16 @@ fn generate_endpoint_snippet(
         syn::ReturnType::Type(_, ty) => {
             let mut res_type = ty.clone();
             clear_all_type_lifetimes(&mut res_type);
+            let errors_snippet = match result_error_type(&res_type) {
+                Some(err_type) => quote! {
+                    endpoint_abi.add_errors::<#err_type>();
+                },
+                None => quote! {},
+            };
             quote! {
                 endpoint_abi.add_output::<#res_type>(&[ #(#output_names),* ]);
                 contract_abi.add_type_descriptions::<#res_type>();
+                #errors_snippet
             }
         },
     };
@@ -61,10 +97,14 @@ fn generate_endpoint_snippet(
             only_admin: #only_admin,
             mutability: #mutability_tokens,
             endpoint_type: #endpoint_type_tokens,
-            payable_in_tokens: &[ #(#payable_in_tokens),* ],
+            payable_in_tokens: #payable_in_tokens,
             inputs: multiversx_sc::types::heap::Vec::new(),
             outputs: multiversx_sc::types::heap::Vec::new(),
+            errors: multiversx_sc::types::heap::Vec::new(),
             labels: &[ #(#label_names),* ],
+            aliases: &[],
+            deprecated: None,
+            test_only: false,
         };
         #(#input_snippets)*
         #output_snippet
@@ -90,6 +130,20 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     contract_abi.constructors.push(endpoint_abi);
                 })
             },
+            PublicRole::Upgrade(_) => {
+                let endpoint_def = generate_endpoint_snippet(
+                    m,
+                    "upgrade",
+                    false,
+                    false,
+                    EndpointMutabilityMetadata::Mutable,
+                    EndpointTypeMetadata::Upgrade,
+                );
+                Some(quote! {
+                    #endpoint_def
+                    contract_abi.upgrade_constructors.push(endpoint_abi);
+                })
+            },
             PublicRole::Endpoint(endpoint_metadata) => {
                 let endpoint_def = generate_endpoint_snippet(
                     m,
@@ -99,8 +153,32 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     endpoint_metadata.mutability.clone(),
                     EndpointTypeMetadata::Endpoint,
                 );
+                let aliases = &endpoint_metadata.aliases;
+                let deprecation_snippet = match &endpoint_metadata.deprecation {
+                    Some(deprecation) => {
+                        let since = &deprecation.since;
+                        let note = deprecation.note.clone().unwrap_or_default();
+                        quote! {
+                            endpoint_abi.deprecated = Some(multiversx_sc::abi::EndpointDeprecationAbi {
+                                since: #since,
+                                note: #note,
+                            });
+                        }
+                    },
+                    None => quote! {},
+                };
+                let test_only_snippet = if endpoint_metadata.test_only {
+                    quote! {
+                        endpoint_abi.test_only = true;
+                    }
+                } else {
+                    quote! {}
+                };
                 Some(quote! {
                     #endpoint_def
+                    endpoint_abi.aliases = &[ #(#aliases),* ];
+                    #deprecation_snippet
+                    #test_only_snippet
                     contract_abi.endpoints.push(endpoint_abi);
                 })
             },
@@ -123,8 +201,19 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
         .collect()
 }
 
-fn generate_event_snippet(m: &Method, event_name: &str) -> proc_macro2::TokenStream {
+fn generate_event_snippet(
+    m: &Method,
+    event_name: &str,
+    standard_topics: bool,
+) -> proc_macro2::TokenStream {
     let event_docs = &m.docs;
+    let standard_topics_snippet = if standard_topics {
+        quote! {
+            event_abi.add_standard_topics::<Self::Api>();
+        }
+    } else {
+        quote! {}
+    };
     let input_snippets: Vec<proc_macro2::TokenStream> = m
         .method_args
         .iter()
@@ -147,6 +236,7 @@ fn generate_event_snippet(m: &Method, event_name: &str) -> proc_macro2::TokenStr
             identifier: #event_name,
             inputs: multiversx_sc::types::heap::Vec::new(),
         };
+        #standard_topics_snippet
         #(#input_snippets)*
     }
 }
@@ -156,8 +246,12 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .methods
         .iter()
         .filter_map(|m| {
-            if let MethodImpl::Generated(AutoImpl::Event { identifier }) = &m.implementation {
-                let event_def = generate_event_snippet(m, identifier);
+            if let MethodImpl::Generated(AutoImpl::Event {
+                identifier,
+                standard_topics,
+            }) = &m.implementation
+            {
+                let event_def = generate_event_snippet(m, identifier, *standard_topics);
                 Some(quote! {
                     #event_def
                     contract_abi.events.push(event_abi);
@@ -169,6 +263,149 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+fn generate_storage_key_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .methods
+        .iter()
+        .filter_map(|m| match &m.implementation {
+            MethodImpl::Generated(
+                AutoImpl::StorageGetter { identifier }
+                | AutoImpl::StorageSetter { identifier }
+                | AutoImpl::StorageMapper { identifier }
+                | AutoImpl::StorageIsEmpty { identifier }
+                | AutoImpl::StorageClear { identifier },
+            ) => {
+                let method_name = m.name.to_string();
+                let key_args = storage_key_args(m);
+                let input_snippets = key_args.iter().map(|arg| {
+                    let arg_pat = &arg.pat;
+                    let arg_name_str = quote! { #arg_pat }.to_string();
+                    let type_name = storage_type_name(&arg.ty);
+                    quote! {
+                        multiversx_sc::abi::StorageKeyInputAbi {
+                            arg_name: #arg_name_str,
+                            type_name: #type_name,
+                        }
+                    }
+                });
+                let mapper_snippet = match (&m.implementation, &m.return_type) {
+                    (
+                        MethodImpl::Generated(AutoImpl::StorageMapper { .. }),
+                        syn::ReturnType::Type(_, ty),
+                    ) => match last_path_segment_name(ty) {
+                        Some(mapper_name) => quote! { Some(#mapper_name) },
+                        None => quote! { None },
+                    },
+                    _ => quote! { None },
+                };
+                let value_type = storage_value_type(m);
+                let value_type_snippet = match value_type {
+                    Some(value_type) => quote! { Some(#value_type) },
+                    None => quote! { None },
+                };
+                Some(quote! {
+                    contract_abi.add_storage_key(multiversx_sc::abi::StorageKeyAbi {
+                        name: #method_name,
+                        key: #identifier,
+                        mapper: #mapper_snippet,
+                        inputs: &[ #(#input_snippets),* ],
+                        value_type: #value_type_snippet,
+                    });
+                })
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// The arguments of a storage method that are appended to the base key.
+pub fn storage_key_args(m: &Method) -> &[MethodArgument] {
+    match &m.implementation {
+        // the last argument of a setter is the value
+        MethodImpl::Generated(AutoImpl::StorageSetter { .. }) => {
+            &m.method_args[..m.method_args.len().saturating_sub(1)]
+        },
+        _ => m.method_args.as_slice(),
+    }
+}
+
+/// The type of the value stored by a storage method, or of the items, for mappers that hold several.
+pub fn storage_value_type(m: &Method) -> Option<String> {
+    match (&m.implementation, &m.return_type) {
+        (MethodImpl::Generated(AutoImpl::StorageMapper { .. }), syn::ReturnType::Type(_, ty)) => {
+            first_type_arg(ty).map(storage_type_name)
+        },
+        (MethodImpl::Generated(AutoImpl::StorageGetter { .. }), syn::ReturnType::Type(_, ty)) => {
+            Some(storage_type_name(ty))
+        },
+        (MethodImpl::Generated(AutoImpl::StorageSetter { .. }), _) => {
+            m.method_args.last().map(|arg| storage_type_name(&arg.ty))
+        },
+        _ => None,
+    }
+}
+
+/// The first type argument of a generic type, other than the API, e.g. `T` in `VecMapper<Self::Api, T>`.
+fn first_type_arg(ty: &syn::Type) -> Option<&syn::Type> {
+    let type_path = match ty {
+        syn::Type::Reference(type_reference) => return first_type_arg(&type_reference.elem),
+        syn::Type::Path(type_path) => type_path,
+        _ => return None,
+    };
+    let generic_args = match &type_path.path.segments.last()?.arguments {
+        syn::PathArguments::AngleBracketed(generic_args) => generic_args,
+        _ => return None,
+    };
+    generic_args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(syn::Type::Path(arg_path))
+            if arg_path
+                .path
+                .segments
+                .first()
+                .map_or(false, |segment| segment.ident == "Self") =>
+        {
+            None
+        },
+        syn::GenericArgument::Type(arg_type) => Some(arg_type),
+        _ => None,
+    })
+}
+
+/// Storage arguments and values are not required to implement `TypeAbi`,
+/// so their ABI type name is inferred from the Rust type name.
+fn storage_type_name(ty: &syn::Type) -> String {
+    match ty {
+        syn::Type::Reference(type_reference) => return storage_type_name(&type_reference.elem),
+        syn::Type::Slice(type_slice) => {
+            return format!("List<{}>", storage_type_name(&type_slice.elem))
+        },
+        _ => {},
+    }
+    if let syn::Type::Tuple(type_tuple) = ty {
+        let item_names: Vec<String> = type_tuple.elems.iter().map(storage_type_name).collect();
+        return format!("tuple<{}>", item_names.join(","));
+    }
+    match last_path_segment_name(ty) {
+        Some(type_name) => match type_name.as_str() {
+            "ManagedAddress" => "Address".to_string(),
+            "ManagedBuffer" | "BoxedBytes" => "bytes".to_string(),
+            "ManagedVec" | "Vec" | "Option" => match first_type_arg(ty) {
+                Some(item_type) => {
+                    let generic_name = if type_name == "Option" {
+                        "Option"
+                    } else {
+                        "List"
+                    };
+                    format!("{generic_name}<{}>", storage_type_name(item_type))
+                },
+                None => type_name,
+            },
+            _ => type_name,
+        },
+        None => quote! { #ty }.to_string(),
+    }
+}
+
 fn has_callback(contract: &ContractTrait) -> bool {
     contract.methods.iter().any(|m| {
         matches!(
@@ -178,17 +415,22 @@ fn has_callback(contract: &ContractTrait) -> bool {
     })
 }
 
+/// The endpoints of each module are checked against those already merged,
+/// so that name clashes are reported together with the traits that declared them.
 fn generate_supertrait_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
-    contract
-			.supertraits
-			.iter()
-			.map(|supertrait| {
-				let module_path = &supertrait.module_path;
-				quote! {
-					contract_abi.coalesce(<#module_path AbiProvider as multiversx_sc::contract_base::ContractAbiProvider>::abi());
-				}
-			})
-			.collect()
+    let mut snippets = vec![quote! {
+        let mut endpoint_origins = multiversx_sc::abi::EndpointOrigins::default();
+        endpoint_origins.add_all(&contract_abi);
+    }];
+    snippets.extend(contract.supertraits.iter().map(|supertrait| {
+        let module_path = &supertrait.module_path;
+        quote! {
+            let module_abi = <#module_path AbiProvider as multiversx_sc::contract_base::ContractAbiProvider>::abi();
+            endpoint_origins.add_all(&module_abi);
+            contract_abi.coalesce(module_abi);
+        }
+    }));
+    snippets
 }
 
 fn generate_abi_method_body(
@@ -199,6 +441,7 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_key_snippets = generate_storage_key_snippets(contract);
     let has_callbacks = has_callback(contract);
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
@@ -219,14 +462,17 @@ fn generate_abi_method_body(
             docs: &[ #(#contract_docs),* ],
             name: #contract_name,
             constructors: multiversx_sc::types::heap::Vec::new(),
+            upgrade_constructors: multiversx_sc::types::heap::Vec::new(),
             endpoints: multiversx_sc::types::heap::Vec::new(),
             promise_callbacks: multiversx_sc::types::heap::Vec::new(),
             events: multiversx_sc::types::heap::Vec::new(),
+            storage_keys: multiversx_sc::types::heap::Vec::new(),
             has_callback: #has_callbacks,
             type_descriptions: <multiversx_sc::abi::TypeDescriptionContainerImpl as multiversx_sc::abi::TypeDescriptionContainer>::new(),
         };
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        #(#storage_key_snippets)*
         #(#supertrait_snippets)*
         contract_abi
     }
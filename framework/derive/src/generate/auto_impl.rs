@@ -29,7 +29,10 @@ pub fn generate_auto_impls(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
 
 fn generate_auto_impl(m: &Method, auto_impl: &AutoImpl) -> proc_macro2::TokenStream {
     match auto_impl {
-        AutoImpl::Event { identifier } => generate_event_impl(m, identifier),
+        AutoImpl::Event {
+            identifier,
+            standard_topics,
+        } => generate_event_impl(m, identifier, *standard_topics),
         AutoImpl::StorageGetter { identifier } => generate_getter_impl(m, identifier),
         AutoImpl::StorageSetter { identifier } => generate_setter_impl(m, identifier),
         AutoImpl::StorageMapper { identifier } => generate_mapper_impl(m, identifier),
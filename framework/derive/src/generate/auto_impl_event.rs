@@ -1,7 +1,11 @@
 use super::{method_gen, util::*};
 use crate::model::{Method, MethodArgument};
 
-pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::TokenStream {
+pub fn generate_event_impl(
+    m: &Method,
+    event_identifier: &str,
+    standard_topics: bool,
+) -> proc_macro2::TokenStream {
     let mut data_arg: Option<&MethodArgument> = None;
     let mut topic_args = Vec::<&MethodArgument>::new();
     for arg in &m.method_args {
@@ -23,6 +27,18 @@ pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::T
             }
         })
         .collect();
+    let standard_topics_snippet = if standard_topics {
+        quote! {
+            multiversx_sc::log_util::serialize_standard_event_topics(
+                &mut ___topic_accumulator___,
+                &self.blockchain().get_caller(),
+                &self.call_value().egld_value(),
+                &self.call_value().all_esdt_transfers(),
+            );
+        }
+    } else {
+        quote! {}
+    };
     let data_buffer_snippet = if let Some(data_arg) = data_arg {
         let data_pat = &data_arg.pat;
         quote! {
@@ -41,6 +57,7 @@ pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::T
             let mut ___topic_accumulator___ = multiversx_sc::log_util::event_topic_accumulator::<Self::Api>(
                 #event_identifier_literal,
             );
+            #standard_topics_snippet
             #(#topic_push_snippets)*
             #data_buffer_snippet
             multiversx_sc::log_util::write_log(&___topic_accumulator___, &___data_buffer___);
@@ -1,49 +1,70 @@
 use super::{supertrait_gen, util::*};
 use crate::model::{ContractTrait, Method, PublicRole};
 
-fn endpoint_match_arm(
-    m: &Method,
-    endpoint_name: &str,
-    match_guard: proc_macro2::TokenStream,
-) -> proc_macro2::TokenStream {
-    let fn_ident = &m.name;
-    let call_method_ident = generate_call_method_name(fn_ident);
-    quote! {
-        #endpoint_name #match_guard =>
-        {
-            self.#call_method_ident();
-            true
+/// The public names under which each method can be called: its name and its aliases, if any.
+fn endpoint_public_names(m: &Method) -> Vec<String> {
+    match &m.public_role {
+        PublicRole::Init(_) => vec!["init".to_string()],
+        PublicRole::Upgrade(_) => vec!["upgrade".to_string()],
+        PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.all_public_names(),
+        PublicRole::CallbackPromise(callback_metadata) => {
+            vec![callback_metadata.callback_name.to_string()]
         },
+        _ => Vec::new(),
     }
 }
 
-pub fn generate_function_selector_body(contract: &ContractTrait) -> proc_macro2::TokenStream {
-    let match_arms: Vec<proc_macro2::TokenStream> = contract
+/// The endpoints of the contract, sorted by name, so they can be looked up by binary search.
+/// Aliases are listed separately, pointing to the same method.
+///
+/// If several methods have the same name, the first one is kept, as with a `match` on the names.
+fn sorted_endpoints(contract: &ContractTrait) -> Vec<(String, &Method)> {
+    let mut endpoints: Vec<(String, &Method)> = contract
         .methods
         .iter()
-        .filter_map(|m| match &m.public_role {
-            PublicRole::Init(_) => Some(endpoint_match_arm(
-                m,
-                "init",
-                quote! { if !<Self::Api as multiversx_sc::api::VMApi>::external_view_init_override() },
-            )),
-            PublicRole::Endpoint(endpoint_metadata) => Some(endpoint_match_arm(
-                m,
-                endpoint_metadata.public_name.to_string().as_str(),
-                quote! {},
-            )),
-            PublicRole::CallbackPromise(callback_metadata) => Some(endpoint_match_arm(
-                m,
-                callback_metadata.callback_name.to_string().as_str(),
-                quote! {},
-            )),
-            _ => None,
+        .flat_map(|m| {
+            endpoint_public_names(m)
+                .into_iter()
+                .map(move |name| (name, m))
         })
         .collect();
+    // stable sort, to keep the first method in case of duplicates
+    endpoints.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
+    endpoints.dedup_by(|(name1, _), (name2, _)| name1 == name2);
+    endpoints
+}
+
+/// The endpoint names are sorted at macro time, so the selector only performs a binary search over them,
+/// instead of comparing the function name with each of them in turn.
+pub fn generate_function_selector_body(contract: &ContractTrait) -> proc_macro2::TokenStream {
+    let endpoints = sorted_endpoints(contract);
+    let endpoint_dispatch = if endpoints.is_empty() {
+        quote! {}
+    } else {
+        let endpoint_names = endpoints.iter().map(|(name, _)| name.as_str());
+        let dispatch_arms = endpoints.iter().enumerate().map(|(index, (_, m))| {
+            let call_method_ident = generate_call_method_name(&m.name);
+            quote! {
+                #index => {
+                    self.#call_method_ident();
+                    return true;
+                },
+            }
+        });
+        quote! {
+            const ___ENDPOINT_NAMES___: &[&str] = &[#(#endpoint_names),*];
+            if let core::result::Result::Ok(___endpoint_index___) = ___ENDPOINT_NAMES___.binary_search(&fn_name) {
+                match ___endpoint_index___ {
+                    #(#dispatch_arms)*
+                    _ => {},
+                }
+            }
+        }
+    };
     let module_calls =
         supertrait_gen::function_selector_module_calls(contract.supertraits.as_slice());
     quote! {
-        if match fn_name {
+        match fn_name {
             "callBack" => {
                 self::EndpointWrappers::callback(self);
                 return true;
@@ -52,11 +73,9 @@ pub fn generate_function_selector_body(contract: &ContractTrait) -> proc_macro2:
                 multiversx_sc::external_view_contract::external_view_contract_constructor::<Self::Api>();
                 return true;
             },
-            #(#match_arms)*
-            other => false
-        } {
-            return true;
+            _ => {},
         }
+        #endpoint_dispatch
         #(#module_calls)*
         false
     }
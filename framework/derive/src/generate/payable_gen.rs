@@ -1,4 +1,4 @@
-use crate::model::{Method, MethodArgument, MethodPayableMetadata};
+use crate::model::{Method, MethodArgument, MethodPayableMetadata, PayableTokenList};
 
 pub fn generate_payable_snippet(m: &Method) -> proc_macro2::TokenStream {
     let call_value_init = call_value_init_snippet(m.payable_metadata());
@@ -39,6 +39,29 @@ fn call_value_init_snippet(mpm: MethodPayableMetadata) -> proc_macro2::TokenStre
                 multiversx_sc::io::call_value_init::payable_any::<Self::Api>();
             }
         },
+        MethodPayableMetadata::TokenList(token_list) => {
+            let accepted_tokens = accepted_tokens_snippet(token_list);
+            quote! {
+                multiversx_sc::io::call_value_init::payable_token_list::<Self::Api>(#accepted_tokens);
+            }
+        },
+    }
+}
+
+/// Expression of type `&'static [&'static str]`, with the constant groups flattened at compile time.
+pub fn accepted_tokens_snippet(token_list: &PayableTokenList) -> proc_macro2::TokenStream {
+    let tokens = &token_list.tokens;
+    let groups = &token_list.groups;
+    quote! {
+        {
+            const ACCEPTED_TOKENS_LEN: usize = multiversx_sc::io::call_value_init::accepted_tokens_len(
+                &[&[ #(#tokens),* ], #(#groups),* ],
+            );
+            const ACCEPTED_TOKENS: [&str; ACCEPTED_TOKENS_LEN] = multiversx_sc::io::call_value_init::accepted_tokens(
+                &[&[ #(#tokens),* ], #(#groups),* ],
+            );
+            &ACCEPTED_TOKENS
+        }
     }
 }
 
@@ -263,7 +263,7 @@ pub fn generate_method_impl(contract_trait: &ContractTrait) -> Vec<proc_macro2::
         .methods
         .iter()
         .filter_map(|m| match &m.public_role {
-            PublicRole::Init(_) => Some(generate_proxy_deploy(m)),
+            PublicRole::Init(_) | PublicRole::Upgrade(_) => Some(generate_proxy_deploy(m)),
             PublicRole::Endpoint(endpoint_metadata) => Some(generate_proxy_endpoint(
                 m,
                 endpoint_metadata.public_name.to_string(),
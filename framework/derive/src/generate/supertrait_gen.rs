@@ -1,4 +1,5 @@
-use syn::punctuated::Punctuated;
+use proc_macro2::Span;
+use syn::{punctuated::Punctuated, spanned::Spanned};
 
 use crate::model::{ModulePath, Supertrait};
 
@@ -7,6 +8,12 @@ pub fn self_module_path() -> ModulePath {
     Punctuated::new()
 }
 
+/// Code generated for a module is spanned to where the contract lists it among its supertraits,
+/// so that unsatisfied module bounds, e.g. a missing module dependency, are reported for that exact module.
+fn supertrait_span(supertrait: &Supertrait) -> Span {
+    supertrait.full_path.span()
+}
+
 pub fn main_supertrait_decl(supertraits: &[Supertrait]) -> Vec<proc_macro2::TokenStream> {
     supertraits
         .iter()
@@ -26,7 +33,7 @@ pub fn endpoint_wrapper_supertrait_decl(
         .iter()
         .map(|supertrait| {
             let module_path = &supertrait.module_path;
-            quote! {
+            quote_spanned! {supertrait_span(supertrait)=>
                 + #module_path EndpointWrappers
             }
         })
@@ -38,15 +45,15 @@ pub fn proxy_supertrait_decl(supertraits: &[Supertrait]) -> Vec<proc_macro2::Tok
         .iter()
         .map(|supertrait| {
             let module_path = &supertrait.module_path;
-            quote! {
+            quote_spanned! {supertrait_span(supertrait)=>
                 + #module_path ProxyTrait
             }
         })
         .collect()
 }
 
-fn impl_auto_impl(module_path: &ModulePath) -> proc_macro2::TokenStream {
-    quote! {
+fn impl_auto_impl(module_path: &ModulePath, span: Span) -> proc_macro2::TokenStream {
+    quote_spanned! {span=>
         impl<A> #module_path AutoImpl for ContractObj<A>
         where
             A: multiversx_sc::api::VMApi,
@@ -58,10 +65,10 @@ fn impl_auto_impl(module_path: &ModulePath) -> proc_macro2::TokenStream {
 pub fn impl_all_auto_impl(supertraits: &[Supertrait]) -> Vec<proc_macro2::TokenStream> {
     let mut implementations: Vec<proc_macro2::TokenStream> = supertraits
         .iter()
-        .map(|supertrait| impl_auto_impl(&supertrait.module_path))
+        .map(|supertrait| impl_auto_impl(&supertrait.module_path, supertrait_span(supertrait)))
         .collect();
 
-    implementations.push(impl_auto_impl(&self_module_path()));
+    implementations.push(impl_auto_impl(&self_module_path(), Span::call_site()));
 
     implementations
 }
@@ -80,8 +87,8 @@ pub fn auto_impl_inheritance(supertraits: &[Supertrait]) -> Vec<proc_macro2::Tok
         .collect()
 }
 
-fn impl_endpoint_wrappers(module_path: &ModulePath) -> proc_macro2::TokenStream {
-    quote! {
+fn impl_endpoint_wrappers(module_path: &ModulePath, span: Span) -> proc_macro2::TokenStream {
+    quote_spanned! {span=>
         impl<A> #module_path EndpointWrappers for ContractObj<A>
         where
             A: multiversx_sc::api::VMApi,
@@ -93,10 +100,15 @@ fn impl_endpoint_wrappers(module_path: &ModulePath) -> proc_macro2::TokenStream
 pub fn impl_all_endpoint_wrappers(supertraits: &[Supertrait]) -> Vec<proc_macro2::TokenStream> {
     let mut implementations: Vec<proc_macro2::TokenStream> = supertraits
         .iter()
-        .map(|supertrait| impl_endpoint_wrappers(&supertrait.module_path))
+        .map(|supertrait| {
+            impl_endpoint_wrappers(&supertrait.module_path, supertrait_span(supertrait))
+        })
         .collect();
 
-    implementations.push(impl_endpoint_wrappers(&self_module_path()));
+    implementations.push(impl_endpoint_wrappers(
+        &self_module_path(),
+        Span::call_site(),
+    ));
 
     implementations
 }
@@ -123,7 +135,7 @@ pub fn function_selector_module_calls(supertraits: &[Supertrait]) -> Vec<proc_ma
         .iter()
         .map(|supertrait| {
             let module_path = &supertrait.module_path;
-            quote! {
+            quote_spanned! {supertrait_span(supertrait)=>
                 if #module_path EndpointWrappers::call(self, fn_name) {
                     return true;
                 }
@@ -132,8 +144,8 @@ pub fn function_selector_module_calls(supertraits: &[Supertrait]) -> Vec<proc_ma
         .collect()
 }
 
-fn impl_proxy_trait(module_path: &ModulePath) -> proc_macro2::TokenStream {
-    quote! {
+fn impl_proxy_trait(module_path: &ModulePath, span: Span) -> proc_macro2::TokenStream {
+    quote_spanned! {span=>
         impl<A> #module_path ProxyTrait for Proxy<A> where A: multiversx_sc::api::VMApi {}
     }
 }
@@ -141,10 +153,10 @@ fn impl_proxy_trait(module_path: &ModulePath) -> proc_macro2::TokenStream {
 pub fn impl_all_proxy_traits(supertraits: &[Supertrait]) -> Vec<proc_macro2::TokenStream> {
     let mut implementations: Vec<proc_macro2::TokenStream> = supertraits
         .iter()
-        .map(|supertrait| impl_proxy_trait(&supertrait.module_path))
+        .map(|supertrait| impl_proxy_trait(&supertrait.module_path, supertrait_span(supertrait)))
         .collect();
 
-    implementations.push(impl_proxy_trait(&self_module_path()));
+    implementations.push(impl_proxy_trait(&self_module_path(), Span::call_site()));
 
     implementations
 }
@@ -81,3 +81,16 @@ pub fn clear_all_type_lifetimes(ty: &mut syn::Type) {
         _ => {},
     }
 }
+
+/// The name of the type, without its path and generics, e.g. `VecMapper` for `VecMapper<Self::Api, u32>`.
+pub fn last_path_segment_name(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Reference(type_reference) => last_path_segment_name(&type_reference.elem),
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
@@ -19,6 +19,7 @@ mod managed_vec_item_derive;
 mod model;
 mod parse;
 mod preprocessing;
+mod sc_error_derive;
 mod type_abi_derive;
 mod validate;
 
@@ -60,6 +61,13 @@ pub fn managed_vec_item_derive(input: proc_macro::TokenStream) -> proc_macro::To
     managed_vec_item_derive::managed_vec_item_derive(&ast)
 }
 
+#[proc_macro_derive(SCError, attributes(sc_error))]
+pub fn sc_error_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+
+    sc_error_derive::sc_error_derive(&ast)
+}
+
 #[proc_macro]
 pub fn format_receiver_args(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     format::format_receiver_args_macro(input)
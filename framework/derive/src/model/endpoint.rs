@@ -13,6 +13,25 @@ pub struct EndpointMetadata {
     pub only_admin: bool,
     pub only_user_account: bool,
     pub mutability: EndpointMutabilityMetadata,
+    pub aliases: Vec<String>,
+    pub deprecation: Option<EndpointDeprecation>,
+    /// Only included in output contracts that enable `test-endpoints`, never in the main contract.
+    pub test_only: bool,
+}
+
+impl EndpointMetadata {
+    /// The public name, followed by the aliases.
+    pub fn all_public_names(&self) -> Vec<String> {
+        let mut names = vec![self.public_name.to_string()];
+        names.extend(self.aliases.iter().cloned());
+        names
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EndpointDeprecation {
+    pub since: String,
+    pub note: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -23,9 +42,12 @@ pub struct CallbackMetadata {
 /// Method visibility from the point of view of the smart contract
 #[derive(Clone, Debug)]
 pub enum PublicRole {
-    /// The smart contract constructor. There can be only one.
+    /// The smart contract constructor. There can be at most one.
     Init(InitMetadata),
 
+    /// Gets called instead of the constructor when the contract is upgraded. There can be at most one.
+    Upgrade(InitMetadata),
+
     /// Means it gets a smart contract function generated for it
     Endpoint(EndpointMetadata),
 
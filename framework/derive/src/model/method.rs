@@ -3,12 +3,25 @@ use super::*;
 /// Indicates how smart contract method implementations will be auto-generated based on their annotations.
 #[derive(Clone, Debug)]
 pub enum AutoImpl {
-    Event { identifier: String },
-    StorageGetter { identifier: String },
-    StorageSetter { identifier: String },
-    StorageMapper { identifier: String },
-    StorageIsEmpty { identifier: String },
-    StorageClear { identifier: String },
+    Event {
+        identifier: String,
+        standard_topics: bool,
+    },
+    StorageGetter {
+        identifier: String,
+    },
+    StorageSetter {
+        identifier: String,
+    },
+    StorageMapper {
+        identifier: String,
+    },
+    StorageIsEmpty {
+        identifier: String,
+    },
+    StorageClear {
+        identifier: String,
+    },
     ProxyGetter,
 }
 #[derive(Clone, Debug)]
@@ -76,7 +89,9 @@ impl Method {
 
     pub fn is_payable(&self) -> bool {
         match &self.public_role {
-            PublicRole::Init(init_metadata) => init_metadata.payable.is_payable(),
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payable.is_payable()
+            },
             PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.payable.is_payable(),
             PublicRole::Callback(_) | PublicRole::CallbackRaw | PublicRole::CallbackPromise(_) => {
                 true
@@ -87,7 +102,9 @@ impl Method {
 
     pub fn payable_metadata(&self) -> MethodPayableMetadata {
         match &self.public_role {
-            PublicRole::Init(init_metadata) => init_metadata.payable.clone(),
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payable.clone()
+            },
             PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.payable.clone(),
             PublicRole::Callback(_) | PublicRole::CallbackRaw | PublicRole::CallbackPromise(_) => {
                 MethodPayableMetadata::AnyToken
@@ -6,6 +6,15 @@ pub enum MethodPayableMetadata {
     Egld,
     SingleEsdtToken(String),
     AnyToken,
+    /// Several token identifiers (possibly including "EGLD"),
+    /// and/or named constant groups of token identifiers, of type `&[&str]`.
+    TokenList(PayableTokenList),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PayableTokenList {
+    pub tokens: Vec<String>,
+    pub groups: Vec<syn::Path>,
 }
 
 impl MethodPayableMetadata {
@@ -26,6 +35,7 @@ impl MethodPayableMetadata {
             MethodPayableMetadata::Egld => vec!["EGLD".to_string()],
             MethodPayableMetadata::SingleEsdtToken(s) => vec![s.clone()],
             MethodPayableMetadata::AnyToken => vec!["*".to_string()],
+            MethodPayableMetadata::TokenList(token_list) => token_list.tokens.clone(),
         }
     }
 }
@@ -0,0 +1,96 @@
+use super::{
+    attributes::AggregatedViewAttribute,
+    paged_view_parse::{mapper_view_args, storage_mapper_type},
+};
+
+/// The `#[aggregated_view]` annotation is handled at trait level, by `generate_aggregated_view_methods`.
+/// Here it only needs to be marked as processed.
+pub fn process_aggregated_view_attribute(attr: &syn::Attribute) -> bool {
+    AggregatedViewAttribute::parse(attr).is_some()
+}
+
+/// For each `#[aggregated_view]` annotation on a storage mapper,
+/// generates a view that returns the values of the mapper for several values of its last storage key argument.
+///
+/// E.g. for a mapper keyed by user and token, the view receives a user and a list of tokens,
+/// and returns the (token, value) pairs of that user's positions.
+pub fn generate_aggregated_view_methods(m: &syn::TraitItemMethod) -> Vec<syn::TraitItemMethod> {
+    let aggregated_view_attributes: Vec<AggregatedViewAttribute> = m
+        .attrs
+        .iter()
+        .filter_map(AggregatedViewAttribute::parse)
+        .collect();
+    if aggregated_view_attributes.is_empty() {
+        return Vec::new();
+    }
+
+    let mapper_ident = &m.sig.ident;
+    assert!(
+        aggregated_view_attributes.len() == 1,
+        "Only one `#[aggregated_view]` allowed per storage mapper. Method '{mapper_ident}' has several."
+    );
+    let mapper_type = storage_mapper_type(m, "aggregated_view");
+
+    let mut key_inputs: Vec<&syn::FnArg> = m
+        .sig
+        .inputs
+        .iter()
+        .filter(|arg| matches!(arg, syn::FnArg::Typed(_)))
+        .collect();
+    let aggregated_arg = match key_inputs.pop() {
+        Some(syn::FnArg::Typed(pat_type)) => pat_type,
+        _ => panic!(
+            "Storage mapper '{mapper_ident}' is annotated with `#[aggregated_view]`, but has no storage key arguments to aggregate over."
+        ),
+    };
+    let (view_args, mapper_call_args) = mapper_view_args(key_inputs.into_iter());
+
+    let aggregated_pat = &aggregated_arg.pat;
+    let (aggregated_type, aggregated_call_arg) = match &*aggregated_arg.ty {
+        syn::Type::Reference(type_ref) => {
+            let elem = &type_ref.elem;
+            (quote! { #elem }, quote! { &#aggregated_pat })
+        },
+        ty => (quote! { #ty }, quote! { #aggregated_pat.clone() }),
+    };
+
+    let aggregated_ident =
+        syn::Ident::new(&format!("{mapper_ident}_aggregated"), mapper_ident.span());
+    let view_name = aggregated_view_attributes[0]
+        .view_name
+        .clone()
+        .unwrap_or_else(|| aggregated_ident.clone());
+    let doc = format!(
+        " Returns the values of `{mapper_ident}` for each of the given `{}`, paired with it.",
+        quote! { #aggregated_pat }
+    );
+
+    let aggregated_method: syn::TraitItemMethod = syn::parse_quote! {
+        #[doc = #doc]
+        #[view(#view_name)]
+        fn #aggregated_ident(
+            &self,
+            #(#view_args,)*
+            #aggregated_pat: multiversx_sc::types::MultiValueEncoded<Self::Api, #aggregated_type>,
+        ) -> multiversx_sc::types::MultiValueEncoded<
+            Self::Api,
+            multiversx_sc::codec::multi_types::MultiValue2<
+                #aggregated_type,
+                <#mapper_type as multiversx_sc::storage::mappers::AggregatedMapper<Self::Api>>::Value,
+            >,
+        > {
+            let mut result = multiversx_sc::types::MultiValueEncoded::new();
+            for #aggregated_pat in #aggregated_pat {
+                let value = multiversx_sc::storage::mappers::AggregatedMapper::aggregated_value(
+                    &self.#mapper_ident(#(#mapper_call_args,)* #aggregated_call_arg),
+                );
+                result.push(multiversx_sc::codec::multi_types::MultiValue2::from((
+                    #aggregated_pat,
+                    value,
+                )));
+            }
+            result
+        }
+    };
+    vec![aggregated_method]
+}
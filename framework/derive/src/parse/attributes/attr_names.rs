@@ -9,11 +9,20 @@ pub(super) static ATTR_PAYMENT_TOKEN: &str = "payment_token";
 pub(super) static ATTR_PAYMENT_NONCE: &str = "payment_nonce";
 pub(super) static ATTR_PAYMENT_MULTI: &str = "payment_multi";
 pub(super) static ATTR_INIT: &str = "init";
+pub(super) static ATTR_UPGRADE: &str = "upgrade";
 pub(super) static ATTR_VIEW: &str = "view";
 pub(super) static ATTR_EXTERNAL_VIEW: &str = "external_view";
+pub(super) static ATTR_PAGED_VIEW: &str = "paged_view";
+pub(super) static ATTR_AGGREGATED_VIEW: &str = "aggregated_view";
 pub(super) static ATTR_EVENT: &str = "event";
 pub(super) static ATTR_EVENT_INDEXED: &str = "indexed";
+pub(super) static ATTR_EVENT_STANDARD_TOPICS: &str = "standard_topics";
 pub(super) static ATTR_ENDPOINT: &str = "endpoint";
+pub(super) static ATTR_ENDPOINT_ALIAS: &str = "alias";
+pub(super) static ATTR_DEPRECATED_ENDPOINT: &str = "deprecated_endpoint";
+pub(super) static ATTR_DEPRECATED_SINCE: &str = "since";
+pub(super) static ATTR_DEPRECATED_NOTE: &str = "note";
+pub(super) static ATTR_TEST_ENDPOINT: &str = "test_endpoint";
 pub(super) static ATTR_CALLBACK_DECL: &str = "callback"; // will be renamed to "legacy-callback" at some point
 pub(super) static ATTR_CALLBACK_PROMISES_DECL: &str = "promises_callback"; // will be renamed to just "callback" at some point
 pub(super) static ATTR_CALLBACK_RAW_DECL: &str = "callback_raw";
@@ -16,37 +16,24 @@ pub fn extract_doc(attrs: &[syn::Attribute]) -> Vec<String> {
                 false
             }
         })
-        .map(|attr| {
-            let mut tokens_iter = attr.clone().tokens.into_iter();
-
-            // checking punctuation, the first token is '='
-            if let Some(proc_macro2::TokenTree::Punct(punct)) = tokens_iter.next() {
-                assert_eq!(punct.as_char(), '=');
-            } else {
-                panic!("malformed doc attribute");
-            }
-
-            if let Some(proc_macro2::TokenTree::Literal(lit)) = tokens_iter.next() {
-                let lit_str = lit.to_string();
-                let mut message_slice = lit_str.as_str();
-
-                // the useful part of the message is between quotes
-                assert!(
-                    message_slice.starts_with('\"') && message_slice.ends_with('\"'),
-                    "malformed doc attribute: string literal expected"
-                );
-                message_slice = &message_slice[1..message_slice.len() - 1];
-
-                // most doc comments start with a space, so remove that too
-                if message_slice.starts_with(' ') {
-                    message_slice = &message_slice[1..];
-                }
-
-                // also unescape escaped single and double quotes
-                message_slice.replace("\\\"", "\"").replace("\\'", "'")
-            } else {
-                panic!("malformed doc attribute");
-            }
+        .flat_map(|attr| {
+            let doc_str = match attr.parse_meta() {
+                Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                    lit: syn::Lit::Str(lit_str),
+                    ..
+                })) => lit_str.value(),
+                _ => panic!("malformed doc attribute: string literal expected"),
+            };
+
+            // block doc comments (`/** ... */`) can span several lines,
+            // each of them becomes a separate entry
+            doc_str
+                .split('\n')
+                .map(|line| {
+                    // most doc comments start with a space, so remove that too
+                    line.strip_prefix(' ').unwrap_or(line).to_string()
+                })
+                .collect::<Vec<String>>()
         })
         .collect()
 }
@@ -1,9 +1,15 @@
+use syn::punctuated::Punctuated;
+
 use super::{attr_names::*, util::*};
 
 pub fn is_init(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_INIT)
 }
 
+pub fn is_upgrade(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_UPGRADE)
+}
+
 pub fn is_only_owner(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_OWNER)
 }
@@ -16,6 +22,10 @@ pub fn is_only_user_account(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_USER_ACCOUNT)
 }
 
+pub fn is_test_endpoint(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_TEST_ENDPOINT)
+}
+
 pub fn is_callback_raw(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_RAW_DECL)
 }
@@ -27,38 +37,137 @@ pub fn is_proxy(attr: &syn::Attribute) -> bool {
 #[derive(Clone, Debug)]
 pub struct EndpointAttribute {
     pub endpoint_name: Option<syn::Ident>,
+    pub aliases: Vec<String>,
 }
 
 impl EndpointAttribute {
     pub fn parse(attr: &syn::Attribute) -> Option<EndpointAttribute> {
-        match is_attr_with_one_opt_token_tree_arg(attr, ATTR_ENDPOINT) {
-            None => None,
-            Some(Some(proc_macro2::TokenTree::Ident(ident))) => Some(EndpointAttribute {
-                endpoint_name: Some(ident),
-            }),
-            Some(None) => Some(EndpointAttribute {
-                endpoint_name: None,
-            }),
-            _ => panic!("unexpected endpoint argument tokens"),
-        }
+        is_attr_with_name(attr, ATTR_ENDPOINT).then(|| {
+            let args = extract_endpoint_name_args(attr, ATTR_ENDPOINT);
+            EndpointAttribute {
+                endpoint_name: args.name,
+                aliases: args.aliases,
+            }
+        })
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct ViewAttribute {
     pub view_name: Option<syn::Ident>,
+    pub aliases: Vec<String>,
 }
 
 impl ViewAttribute {
     pub fn parse(attr: &syn::Attribute) -> Option<ViewAttribute> {
-        match is_attr_with_one_opt_token_tree_arg(attr, ATTR_VIEW) {
-            None => None,
-            Some(Some(proc_macro2::TokenTree::Ident(ident))) => Some(ViewAttribute {
-                view_name: Some(ident),
-            }),
-            Some(None) => Some(ViewAttribute { view_name: None }),
-            _ => panic!("unexpected view argument tokens"),
+        is_attr_with_name(attr, ATTR_VIEW).then(|| {
+            let args = extract_endpoint_name_args(attr, ATTR_VIEW);
+            ViewAttribute {
+                view_name: args.name,
+                aliases: args.aliases,
+            }
+        })
+    }
+}
+
+fn is_attr_with_name(attr: &syn::Attribute, attr_name: &str) -> bool {
+    attr.path
+        .segments
+        .first()
+        .map(|first_seg| first_seg.ident == attr_name)
+        .unwrap_or(false)
+}
+
+/// Arguments of `#[endpoint(...)]` and `#[view(...)]`: an optional public name,
+/// followed by any number of aliases, e.g. `#[endpoint(newName, alias = "oldName")]`.
+#[derive(Default)]
+struct EndpointNameArgs {
+    name: Option<syn::Ident>,
+    aliases: Vec<String>,
+}
+
+fn extract_endpoint_name_args(attr: &syn::Attribute, attr_name: &str) -> EndpointNameArgs {
+    let mut result = EndpointNameArgs::default();
+    if attr.tokens.is_empty() {
+        return result;
+    }
+
+    let exprs = attr
+        .parse_args_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+        .unwrap_or_else(|_| panic!("unexpected {attr_name} argument tokens"));
+    for (index, expr) in exprs.into_iter().enumerate() {
+        if let Some(alias) = assign_str_arg(&expr, ATTR_ENDPOINT_ALIAS) {
+            result.aliases.push(alias);
+            continue;
+        }
+        match expr {
+            syn::Expr::Path(expr_path) if index == 0 && expr_path.path.get_ident().is_some() => {
+                result.name = expr_path.path.get_ident().cloned();
+            },
+            _ => panic!(
+                "unexpected {attr_name} argument tokens, expected an optional name, followed by `alias = \"...\"` arguments"
+            ),
+        }
+    }
+    result
+}
+
+/// Recognizes arguments of the form `key = "value"`.
+fn assign_str_arg(expr: &syn::Expr, key: &str) -> Option<String> {
+    let assign = match expr {
+        syn::Expr::Assign(assign) => assign,
+        _ => return None,
+    };
+    let left = match &*assign.left {
+        syn::Expr::Path(left) => left,
+        _ => return None,
+    };
+    if !left.path.is_ident(key) {
+        return None;
+    }
+    match &*assign.right {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit_str),
+            ..
+        }) => Some(lit_str.value()),
+        _ => panic!("`{key}` expects a string literal"),
+    }
+}
+
+/// `#[deprecated_endpoint(since = "...", note = "...")]`, the note is optional.
+#[derive(Clone, Debug)]
+pub struct DeprecatedEndpointAttribute {
+    pub since: String,
+    pub note: Option<String>,
+}
+
+impl DeprecatedEndpointAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<DeprecatedEndpointAttribute> {
+        if !is_attr_with_name(attr, ATTR_DEPRECATED_ENDPOINT) {
+            return None;
+        }
+
+        let exprs = attr
+            .parse_args_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+            .expect("deprecated_endpoint attribute expects arguments `since = \"...\"` and optionally `note = \"...\"`");
+        let mut since = None;
+        let mut note = None;
+        for expr in exprs {
+            if let Some(value) = assign_str_arg(&expr, ATTR_DEPRECATED_SINCE) {
+                since = Some(value);
+            } else if let Some(value) = assign_str_arg(&expr, ATTR_DEPRECATED_NOTE) {
+                note = Some(value);
+            } else {
+                panic!(
+                    "unexpected deprecated_endpoint argument, only `since` and `note` are allowed"
+                );
+            }
         }
+        Some(DeprecatedEndpointAttribute {
+            since: since
+                .expect("deprecated_endpoint attribute requires a `since = \"...\"` argument"),
+            note,
+        })
     }
 }
 
@@ -80,6 +189,42 @@ impl ExternalViewAttribute {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct PagedViewAttribute {
+    pub view_name: Option<syn::Ident>,
+}
+
+impl PagedViewAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<PagedViewAttribute> {
+        match is_attr_with_one_opt_token_tree_arg(attr, ATTR_PAGED_VIEW) {
+            None => None,
+            Some(Some(proc_macro2::TokenTree::Ident(ident))) => Some(PagedViewAttribute {
+                view_name: Some(ident),
+            }),
+            Some(None) => Some(PagedViewAttribute { view_name: None }),
+            _ => panic!("unexpected paged view argument tokens"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AggregatedViewAttribute {
+    pub view_name: Option<syn::Ident>,
+}
+
+impl AggregatedViewAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<AggregatedViewAttribute> {
+        match is_attr_with_one_opt_token_tree_arg(attr, ATTR_AGGREGATED_VIEW) {
+            None => None,
+            Some(Some(proc_macro2::TokenTree::Ident(ident))) => Some(AggregatedViewAttribute {
+                view_name: Some(ident),
+            }),
+            Some(None) => Some(AggregatedViewAttribute { view_name: None }),
+            _ => panic!("unexpected aggregated view argument tokens"),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct CallbackAttribute {
     pub callback_name: Option<syn::Ident>,
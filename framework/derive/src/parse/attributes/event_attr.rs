@@ -1,13 +1,59 @@
-use super::{attr_names::*, util::*};
+use super::attr_names::*;
 
 pub struct EventAttribute {
     pub identifier: String,
+    pub standard_topics: bool,
 }
 
 impl EventAttribute {
+    /// Accepts `#[event("identifier")]` and `#[event("identifier", standard_topics)]`.
     pub fn parse(attr: &syn::Attribute) -> Option<Self> {
-        is_attr_one_string_arg(attr, ATTR_EVENT).map(|arg_str| EventAttribute {
-            identifier: arg_str,
+        let first_seg = attr.path.segments.first()?;
+        if first_seg.ident != ATTR_EVENT {
+            return None;
+        }
+
+        let mut iter = attr.clone().tokens.into_iter();
+        let group = match iter.next() {
+            Some(proc_macro2::TokenTree::Group(group)) => group,
+            _ => panic!("missing event identifier"),
+        };
+        assert!(iter.next().is_none(), "too many tokens in event attribute");
+
+        let mut args = group.stream().into_iter();
+        let identifier = match args.next() {
+            Some(proc_macro2::TokenTree::Literal(lit)) => {
+                let str_val = lit.to_string();
+                assert!(
+                    str_val.starts_with('\"') && str_val.ends_with('\"'),
+                    "string literal expected as event identifier"
+                );
+                str_val[1..str_val.len() - 1].to_string()
+            },
+            _ => panic!("string literal expected as event identifier"),
+        };
+
+        let standard_topics = match args.next() {
+            None => false,
+            Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == ',' => {
+                match args.next() {
+                    Some(proc_macro2::TokenTree::Ident(ident))
+                        if ident == ATTR_EVENT_STANDARD_TOPICS =>
+                    {
+                        true
+                    },
+                    _ => panic!(
+                        "unknown event option, only `{ATTR_EVENT_STANDARD_TOPICS}` is allowed"
+                    ),
+                }
+            },
+            _ => panic!("unexpected tokens in event attribute"),
+        };
+        assert!(args.next().is_none(), "too many tokens in event attribute");
+
+        Some(EventAttribute {
+            identifier,
+            standard_topics,
         })
     }
 }
@@ -1,7 +1,18 @@
+use syn::punctuated::Punctuated;
+
 use super::attr_names::*;
 
 pub struct PayableAttribute {
-    pub identifier: Option<String>,
+    pub args: Vec<PayableAttributeArg>,
+}
+
+/// One entry in the `#[payable(...)]` argument list.
+pub enum PayableAttributeArg {
+    /// A string literal: `"EGLD"`, `"*"`, or a token identifier.
+    Token(String),
+
+    /// A path to a constant of type `&[&str]`, containing token identifiers.
+    Group(syn::Path),
 }
 
 impl PayableAttribute {
@@ -9,7 +20,7 @@ impl PayableAttribute {
         if let Some(first_seg) = attr.path.segments.first() {
             if first_seg.ident == ATTR_PAYABLE {
                 Some(PayableAttribute {
-                    identifier: extract_token_identifier(attr),
+                    args: extract_payable_args(attr),
                 })
             } else {
                 None
@@ -20,38 +31,24 @@ impl PayableAttribute {
     }
 }
 
-/// Current implementation only works with 1 token name.
-/// Might be extended in the future.
-fn extract_token_identifier(attr: &syn::Attribute) -> Option<String> {
-    let mut iter = attr.clone().tokens.into_iter();
-    let result_str = match iter.next() {
-        Some(proc_macro2::TokenTree::Group(group)) => {
-            assert!(
-                group.delimiter() == proc_macro2::Delimiter::Parenthesis,
-                "payable token name must be specified in parantheses"
-            );
-            let mut iter2 = group.stream().into_iter();
-            match iter2.next() {
-                Some(proc_macro2::TokenTree::Literal(lit)) => {
-                    let str_val = lit.to_string();
-                    assert!(
-                        str_val.starts_with('\"') && str_val.ends_with('\"'),
-                        "string literal expected as attribute argument"
-                    );
-                    let substr = &str_val[1..str_val.len() - 1];
-                    Some(substr.to_string())
-                },
-                _ => panic!("literal expected as event identifier"),
-            }
-        },
-        None => None,
-        _ => panic!("unexpected payable attribute format"),
-    };
+fn extract_payable_args(attr: &syn::Attribute) -> Vec<PayableAttributeArg> {
+    if attr.tokens.is_empty() {
+        return Vec::new();
+    }
 
-    assert!(
-        iter.next().is_none(),
-        "event too many tokens in event attribute"
-    );
+    let exprs = attr
+        .parse_args_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+        .expect("payable attribute arguments must be string literals or constant paths, separated by commas, in parantheses");
 
-    result_str
+    exprs
+        .into_iter()
+        .map(|expr| match expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => PayableAttributeArg::Token(lit_str.value()),
+            syn::Expr::Path(expr_path) => PayableAttributeArg::Group(expr_path.path),
+            _ => panic!("payable attribute arguments must be string literals or constant paths"),
+        })
+        .collect()
 }
@@ -21,9 +21,9 @@ pub fn process_event_attribute(attr: &syn::Attribute, method: &mut Method) -> bo
     EventAttribute::parse(attr)
         .map(|event_attr| {
             assert_no_other_auto_impl(&*method);
-            let event_identifier = event_attr.identifier;
             method.implementation = MethodImpl::Generated(AutoImpl::Event {
-                identifier: event_identifier,
+                identifier: event_attr.identifier,
+                standard_topics: event_attr.standard_topics,
             });
         })
         .is_some()
@@ -1,6 +1,7 @@
 use super::{
-    attributes::extract_doc, method_parse::process_method, parse_util::validate_attribute_args,
-    supertrait_parse::parse_supertrait,
+    aggregated_view_parse::generate_aggregated_view_methods, attributes::extract_doc,
+    method_parse::process_method, paged_view_parse::generate_paged_view_methods,
+    parse_util::validate_attribute_args, supertrait_parse::parse_supertrait,
 };
 use crate::{
     model::{ContractTrait, Method, Supertrait, TraitProperties},
@@ -33,8 +34,21 @@ pub fn parse_contract_trait(
     let methods: Vec<Method> = contract_trait
         .items
         .iter()
-        .map(|itm| match itm {
-            syn::TraitItem::Method(m) => process_method(m, &trait_attributes),
+        .flat_map(|itm| match itm {
+            syn::TraitItem::Method(m) => {
+                let mut methods = vec![process_method(m, &trait_attributes)];
+                methods.extend(
+                    generate_paged_view_methods(m)
+                        .iter()
+                        .map(|paged_view| process_method(paged_view, &trait_attributes)),
+                );
+                methods.extend(
+                    generate_aggregated_view_methods(m)
+                        .iter()
+                        .map(|aggregated_view| process_method(aggregated_view, &trait_attributes)),
+                );
+                methods
+            },
             _ => panic!("Only methods allowed in contract traits"),
         })
         .collect();
@@ -1,20 +1,21 @@
 use crate::model::{
-    CallbackMetadata, EndpointMetadata, EndpointMutabilityMetadata, InitMetadata, Method,
-    PublicRole,
+    CallbackMetadata, EndpointDeprecation, EndpointMetadata, EndpointMutabilityMetadata,
+    InitMetadata, Method, PublicRole,
 };
 
 use super::{
     attributes::{
         is_callback_raw, is_init, is_only_admin, is_only_owner, is_only_user_account,
-        CallbackAttribute, EndpointAttribute, ExternalViewAttribute, LabelAttribute,
-        OutputNameAttribute, PromisesCallbackAttribute, ViewAttribute,
+        is_test_endpoint, is_upgrade, CallbackAttribute, DeprecatedEndpointAttribute,
+        EndpointAttribute, ExternalViewAttribute, LabelAttribute, OutputNameAttribute,
+        PromisesCallbackAttribute, ViewAttribute,
     },
     MethodAttributesPass1,
 };
 
 fn check_single_role(method: &Method) {
     assert!(matches!(method.public_role, PublicRole::Private),
-		"Can only annotate with one of the following arguments: `#[init]`, `#[endpoint]`, `#[view]`, `#[callback]`, `#[callback_raw]`."
+		"Can only annotate with one of the following arguments: `#[init]`, `#[upgrade]`, `#[endpoint]`, `#[view]`, `#[callback]`, `#[callback_raw]`."
 	);
 }
 
@@ -34,6 +35,22 @@ pub fn process_init_attribute(
     }
 }
 
+pub fn process_upgrade_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &MethodAttributesPass1,
+    method: &mut Method,
+) -> bool {
+    if is_upgrade(attr) {
+        check_single_role(&*method);
+        method.public_role = PublicRole::Upgrade(InitMetadata {
+            payable: pass_1_data.payable.clone(),
+        });
+        true
+    } else {
+        false
+    }
+}
+
 pub fn process_only_owner_attribute(
     attr: &syn::Attribute,
     pass_1_data: &mut MethodAttributesPass1,
@@ -67,6 +84,31 @@ pub fn process_only_user_account_attribute(
     is_only_user_account
 }
 
+pub fn process_test_endpoint_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_test_endpoint = is_test_endpoint(attr);
+    if is_test_endpoint {
+        pass_1_data.test_endpoint = true;
+    }
+    is_test_endpoint
+}
+
+pub fn process_deprecated_endpoint_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    DeprecatedEndpointAttribute::parse(attr)
+        .map(|deprecated_attr| {
+            pass_1_data.deprecation = Some(EndpointDeprecation {
+                since: deprecated_attr.since,
+                note: deprecated_attr.note,
+            });
+        })
+        .is_some()
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -86,6 +128,9 @@ pub fn process_endpoint_attribute(
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
                 mutability: EndpointMutabilityMetadata::Mutable,
+                aliases: endpoint_attr.aliases,
+                deprecation: pass_1_data.deprecation.clone(),
+                test_only: pass_1_data.test_endpoint,
             });
         })
         .is_some()
@@ -110,6 +155,9 @@ pub fn process_view_attribute(
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
                 mutability: EndpointMutabilityMetadata::Readonly,
+                aliases: view_attribute.aliases,
+                deprecation: pass_1_data.deprecation.clone(),
+                test_only: pass_1_data.test_endpoint,
             });
         })
         .is_some()
@@ -134,6 +182,9 @@ pub fn process_external_view_attribute(
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
                 mutability: EndpointMutabilityMetadata::Readonly,
+                aliases: Vec::new(),
+                deprecation: pass_1_data.deprecation.clone(),
+                test_only: pass_1_data.test_endpoint,
             });
         })
         .is_some()
@@ -1,17 +1,24 @@
-use crate::model::{Method, MethodImpl, MethodPayableMetadata, PublicRole, TraitProperties};
+use crate::model::{
+    EndpointDeprecation, Method, MethodImpl, MethodPayableMetadata, PublicRole, TraitProperties,
+};
 
 use super::{
+    aggregated_view_parse::process_aggregated_view_attribute,
     attributes::extract_doc,
     auto_impl_parse::{
         process_event_attribute, process_proxy_attribute, process_storage_clear_attribute,
         process_storage_get_attribute, process_storage_is_empty_attribute,
         process_storage_mapper_attribute, process_storage_set_attribute,
     },
-    extract_method_args, process_callback_attribute, process_callback_raw_attribute,
-    process_endpoint_attribute, process_external_view_attribute, process_init_attribute,
-    process_label_names_attribute, process_only_admin_attribute, process_only_owner_attribute,
+    extract_method_args,
+    paged_view_parse::process_paged_view_attribute,
+    process_callback_attribute, process_callback_raw_attribute,
+    process_deprecated_endpoint_attribute, process_endpoint_attribute,
+    process_external_view_attribute, process_init_attribute, process_label_names_attribute,
+    process_only_admin_attribute, process_only_owner_attribute,
     process_only_user_account_attribute, process_output_names_attribute, process_payable_attribute,
-    process_promises_callback_attribute, process_view_attribute,
+    process_promises_callback_attribute, process_test_endpoint_attribute,
+    process_upgrade_attribute, process_view_attribute,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
@@ -19,6 +26,8 @@ pub struct MethodAttributesPass1 {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    pub deprecation: Option<EndpointDeprecation>,
+    pub test_endpoint: bool,
 }
 
 pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperties) -> Method {
@@ -36,6 +45,8 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         only_owner: trait_attributes.only_owner,
         only_admin: trait_attributes.only_admin,
         only_user_account: trait_attributes.only_user_account,
+        deprecation: None,
+        test_endpoint: false,
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
 
@@ -65,6 +76,16 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
     );
 
     validate_method(&method);
+    assert!(
+        first_pass_data.deprecation.is_none() || matches!(method.public_role, PublicRole::Endpoint(_)),
+        "`#[deprecated_endpoint]` can only be placed on endpoints and views. Method '{}' is neither.",
+        &method.name.to_string()
+    );
+    assert!(
+        !first_pass_data.test_endpoint || matches!(method.public_role, PublicRole::Endpoint(_)),
+        "`#[test_endpoint]` can only be placed on endpoints and views. Method '{}' is neither.",
+        &method.name.to_string()
+    );
 
     method
 }
@@ -90,6 +111,8 @@ fn process_attribute_first_pass(
         || process_only_owner_attribute(attr, first_pass_data)
         || process_only_admin_attribute(attr, first_pass_data)
         || process_only_user_account_attribute(attr, first_pass_data)
+        || process_deprecated_endpoint_attribute(attr, first_pass_data)
+        || process_test_endpoint_attribute(attr, first_pass_data)
 }
 
 fn process_attributes_second_pass(
@@ -111,9 +134,12 @@ fn process_attribute_second_pass(
     method: &mut Method,
 ) -> bool {
     process_init_attribute(attr, first_pass_data, method)
+        || process_upgrade_attribute(attr, first_pass_data, method)
         || process_endpoint_attribute(attr, first_pass_data, method)
         || process_view_attribute(attr, first_pass_data, method)
         || process_external_view_attribute(attr, first_pass_data, method)
+        || process_paged_view_attribute(attr)
+        || process_aggregated_view_attribute(attr)
         || process_callback_raw_attribute(attr, method)
         || process_callback_attribute(attr, method)
         || process_promises_callback_attribute(attr, method)
@@ -132,7 +158,10 @@ fn validate_method(method: &Method) {
     assert!(
         matches!(
             method.public_role,
-            PublicRole::Init(_) | PublicRole::Endpoint(_) | PublicRole::CallbackPromise(_)
+            PublicRole::Init(_)
+                | PublicRole::Upgrade(_)
+                | PublicRole::Endpoint(_)
+                | PublicRole::CallbackPromise(_)
         ) || method.label_names.is_empty(),
         "Labels can only be placed on endpoints, constructors, and promises callbacks. Method '{}' is neither.",
         &method.name.to_string()
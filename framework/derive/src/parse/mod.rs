@@ -1,10 +1,12 @@
 pub mod attributes;
 
+mod aggregated_view_parse;
 mod argument_parse;
 mod auto_impl_parse;
 mod contract_trait_parse;
 mod endpoint_parse;
 mod method_parse;
+mod paged_view_parse;
 mod parse_util;
 mod payable_parse;
 mod split_path;
@@ -0,0 +1,107 @@
+use super::attributes::{PagedViewAttribute, StorageMapperAttribute};
+
+/// The `#[paged_view]` annotation is handled at trait level, by `generate_paged_view_methods`.
+/// Here it only needs to be marked as processed.
+pub fn process_paged_view_attribute(attr: &syn::Attribute) -> bool {
+    PagedViewAttribute::parse(attr).is_some()
+}
+
+/// For each `#[paged_view]` annotation on a storage mapper,
+/// generates a view that returns the mapper contents page by page.
+///
+/// The view receives the same arguments as the storage mapper (the storage key arguments),
+/// followed by `from` (how many items to skip) and `size` (maximum number of items to return).
+pub fn generate_paged_view_methods(m: &syn::TraitItemMethod) -> Vec<syn::TraitItemMethod> {
+    let paged_view_attributes: Vec<PagedViewAttribute> = m
+        .attrs
+        .iter()
+        .filter_map(PagedViewAttribute::parse)
+        .collect();
+    if paged_view_attributes.is_empty() {
+        return Vec::new();
+    }
+
+    let mapper_ident = &m.sig.ident;
+    assert!(
+        paged_view_attributes.len() == 1,
+        "Only one `#[paged_view]` allowed per storage mapper. Method '{mapper_ident}' has several."
+    );
+    let mapper_type = storage_mapper_type(m, "paged_view");
+    let (view_args, mapper_call_args) = mapper_view_args(m.sig.inputs.iter());
+
+    let paged_ident = syn::Ident::new(&format!("{mapper_ident}_paged"), mapper_ident.span());
+    let view_name = paged_view_attributes[0]
+        .view_name
+        .clone()
+        .unwrap_or_else(|| paged_ident.clone());
+    let doc =
+        format!(" Returns at most `size` items from `{mapper_ident}`, skipping the first `from`.");
+
+    let paged_method: syn::TraitItemMethod = syn::parse_quote! {
+        #[doc = #doc]
+        #[view(#view_name)]
+        fn #paged_ident(
+            &self,
+            #(#view_args,)*
+            from: usize,
+            size: usize,
+        ) -> multiversx_sc::types::MultiValueEncoded<
+            Self::Api,
+            <#mapper_type as multiversx_sc::storage::mappers::PagedMapper<Self::Api>>::Item,
+        > {
+            multiversx_sc::storage::mappers::PagedMapper::paged(
+                &self.#mapper_ident(#(#mapper_call_args),*),
+                from,
+                size,
+            )
+        }
+    };
+    vec![paged_method]
+}
+
+/// The type of the storage mapper that a generated view reads from.
+pub(super) fn storage_mapper_type(m: &syn::TraitItemMethod, annotation: &str) -> Box<syn::Type> {
+    let mapper_ident = &m.sig.ident;
+    assert!(
+        m.attrs
+            .iter()
+            .any(|attr| StorageMapperAttribute::parse(attr).is_some()),
+        "Method '{mapper_ident}' is annotated with `#[{annotation}]`, but is not a storage mapper."
+    );
+    match &m.sig.output {
+        syn::ReturnType::Type(_, ty) => ty.clone(),
+        syn::ReturnType::Default => {
+            panic!("Storage mapper '{mapper_ident}' must have a return type")
+        },
+    }
+}
+
+/// The storage key arguments of a storage mapper, as view arguments, and as they are passed on to the mapper.
+///
+/// References become owned view arguments.
+pub(super) fn mapper_view_args<'a, I>(
+    inputs: I,
+) -> (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>)
+where
+    I: Iterator<Item = &'a syn::FnArg>,
+{
+    let mut view_args = Vec::new();
+    let mut mapper_call_args = Vec::new();
+    for arg in inputs {
+        if let syn::FnArg::Typed(pat_type) = arg {
+            let pat = &pat_type.pat;
+            match &*pat_type.ty {
+                syn::Type::Reference(type_ref) => {
+                    let elem = &type_ref.elem;
+                    view_args.push(quote! { #pat: #elem });
+                    mapper_call_args.push(quote! { &#pat });
+                },
+                ty => {
+                    view_args.push(quote! { #pat: #ty });
+                    mapper_call_args.push(quote! { #pat });
+                },
+            }
+        }
+    }
+    (view_args, mapper_call_args)
+}
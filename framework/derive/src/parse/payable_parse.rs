@@ -1,21 +1,53 @@
-use super::{attributes::PayableAttribute, MethodAttributesPass1};
-use crate::model::MethodPayableMetadata;
+use super::{
+    attributes::{PayableAttribute, PayableAttributeArg},
+    MethodAttributesPass1,
+};
+use crate::model::{MethodPayableMetadata, PayableTokenList};
 
 pub fn process_payable_attribute(
     attr: &syn::Attribute,
     pass_1_data: &mut MethodAttributesPass1,
 ) -> bool {
     PayableAttribute::parse(attr).map(|payable_attr| {
-		if let Some(identifier) = payable_attr.identifier {
-			pass_1_data.payable = parse_payable_identifier(identifier.as_str());
-		} else {
+		if payable_attr.args.is_empty() {
 			panic!(
-				"Endpoint `payable` attribute requires one argument. Replace with `#[payable(\"*\")]` or `#[payable(\"EGLD\")]`. Method name: {}",
+				"Endpoint `payable` attribute requires at least one argument. Replace with `#[payable(\"*\")]` or `#[payable(\"EGLD\")]`. Method name: {}",
 				&pass_1_data.method_name);
 		}
+		pass_1_data.payable = parse_payable_args(payable_attr.args);
 	}).is_some()
 }
 
+fn parse_payable_args(args: Vec<PayableAttributeArg>) -> MethodPayableMetadata {
+    if args.len() == 1 {
+        if let PayableAttributeArg::Token(identifier) = &args[0] {
+            return parse_payable_identifier(identifier.as_str());
+        }
+    }
+
+    let mut token_list = PayableTokenList::default();
+    for arg in args {
+        match arg {
+            PayableAttributeArg::Token(identifier) => {
+                match identifier.as_str() {
+                    "*" => {
+                        panic!("\"*\" cannot be combined with other tokens in #[payable] attribute")
+                    },
+                    "" => panic!("empty token name not allowed in #[payable] attribute"),
+                    _ => {},
+                }
+                assert!(
+                    !token_list.tokens.contains(&identifier),
+                    "token {identifier} appears more than once in #[payable] attribute"
+                );
+                token_list.tokens.push(identifier);
+            },
+            PayableAttributeArg::Group(path) => token_list.groups.push(path),
+        }
+    }
+    MethodPayableMetadata::TokenList(token_list)
+}
+
 fn parse_payable_identifier(identifier: &str) -> MethodPayableMetadata {
     match identifier {
         "EGLD" => MethodPayableMetadata::Egld,
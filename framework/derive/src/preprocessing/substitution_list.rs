@@ -54,6 +54,8 @@ fn add_managed_types(substitutions: &mut SubstitutionsMap) {
     // types::managed::multi-value
     add_managed_type(substitutions, &quote!(ManagedAsyncCallError));
     add_managed_type_with_generics(substitutions, &quote!(ManagedAsyncCallResult));
+    add_managed_type(substitutions, &quote!(CallbackError));
+    add_managed_type_with_generics(substitutions, &quote!(CallbackResult));
     add_managed_type(substitutions, &quote!(EsdtTokenPaymentMultiArg));
     add_managed_type(substitutions, &quote!(EsdtTokenPaymentMultiValue));
     add_managed_type_with_generics(substitutions, &quote!(MultiValueEncodedIterator));
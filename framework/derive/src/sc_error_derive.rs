@@ -0,0 +1,204 @@
+use super::parse::attributes::extract_doc;
+use proc_macro::TokenStream;
+use quote::quote;
+use std::collections::BTreeSet;
+
+static ATTR_SC_ERROR: &str = "sc_error";
+static ATTR_SC_ERROR_CODE: &str = "code";
+static ATTR_SC_ERROR_MESSAGE: &str = "message";
+
+struct ErrorVariant {
+    ident: syn::Ident,
+    docs: Vec<String>,
+    code: u32,
+    message: String,
+}
+
+/// Parses `#[sc_error(code = 1, message = "...")]`.
+fn parse_sc_error_attr(variant: &syn::Variant) -> (u32, String) {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident(ATTR_SC_ERROR))
+        .unwrap_or_else(|| {
+            panic!(
+                "SCError variant `{}` requires a #[sc_error(code = ..., message = \"...\")] attribute",
+                variant.ident
+            )
+        });
+
+    let meta_list = match attr.parse_meta() {
+        Ok(syn::Meta::List(meta_list)) => meta_list,
+        _ => panic!(
+            "malformed sc_error attribute on variant `{}`",
+            variant.ident
+        ),
+    };
+
+    let mut code = None;
+    let mut message = None;
+    for nested in meta_list.nested.iter() {
+        match nested {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident(ATTR_SC_ERROR_CODE) =>
+            {
+                if let syn::Lit::Int(lit_int) = &name_value.lit {
+                    code = Some(lit_int.base10_parse::<u32>().unwrap_or_else(|_| {
+                        panic!(
+                            "sc_error code must be a u32, on variant `{}`",
+                            variant.ident
+                        )
+                    }));
+                } else {
+                    panic!(
+                        "sc_error code must be a u32, on variant `{}`",
+                        variant.ident
+                    );
+                }
+            },
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident(ATTR_SC_ERROR_MESSAGE) =>
+            {
+                if let syn::Lit::Str(lit_str) = &name_value.lit {
+                    message = Some(lit_str.value());
+                } else {
+                    panic!(
+                        "sc_error message must be a string literal, on variant `{}`",
+                        variant.ident
+                    );
+                }
+            },
+            _ => panic!("unknown sc_error argument on variant `{}`", variant.ident),
+        }
+    }
+
+    (
+        code.unwrap_or_else(|| panic!("missing sc_error code on variant `{}`", variant.ident)),
+        message
+            .unwrap_or_else(|| panic!("missing sc_error message on variant `{}`", variant.ident)),
+    )
+}
+
+fn error_variants(data_enum: &syn::DataEnum) -> Vec<ErrorVariant> {
+    let mut codes = BTreeSet::new();
+    data_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            assert!(
+                matches!(variant.fields, syn::Fields::Unit),
+                "SCError variant `{}` cannot have fields",
+                variant.ident
+            );
+            let (code, message) = parse_sc_error_attr(variant);
+            assert!(
+                codes.insert(code),
+                "duplicate SCError code {code}, on variant `{}`",
+                variant.ident
+            );
+            ErrorVariant {
+                ident: variant.ident.clone(),
+                docs: extract_doc(variant.attrs.as_slice()),
+                code,
+                message,
+            }
+        })
+        .collect()
+}
+
+pub fn sc_error_derive(ast: &syn::DeriveInput) -> TokenStream {
+    let data_enum = match &ast.data {
+        syn::Data::Enum(data_enum) => data_enum,
+        _ => panic!("SCError can only be derived for enums"),
+    };
+    assert!(
+        ast.generics.params.is_empty(),
+        "SCError cannot be derived for generic enums"
+    );
+
+    let name = &ast.ident;
+    let variants = error_variants(data_enum);
+
+    let code_arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let code = variant.code;
+        quote! { #name::#ident => #code, }
+    });
+    let message_arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let message = &variant.message;
+        quote! { #name::#ident => #message, }
+    });
+    let signalled_message_arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let signalled_message = format!("[{}] {}", variant.code, variant.message);
+        quote! { #name::#ident => #signalled_message, }
+    });
+    let error_abi_snippets = variants.iter().map(|variant| {
+        let docs = &variant.docs;
+        let name_str = variant.ident.to_string();
+        let code = variant.code;
+        let message = &variant.message;
+        quote! {
+            errors.push(multiversx_sc::abi::ErrorAbi {
+                docs: &[ #(#docs),* ],
+                name: #name_str,
+                code: #code,
+                message: #message,
+            });
+        }
+    });
+
+    let sc_error_impl = quote! {
+        impl multiversx_sc::types::SCError for #name {
+            fn finish_err<FA: multiversx_sc::api::EndpointFinishApi>(&self) -> ! {
+                multiversx_sc::api::ErrorApiImpl::signal_error(
+                    &FA::error_api_impl(),
+                    multiversx_sc::types::CodedSCError::signalled_message(self).as_bytes(),
+                )
+            }
+        }
+
+        impl multiversx_sc::types::CodedSCError for #name {
+            fn error_code(&self) -> u32 {
+                match self {
+                    #(#code_arms)*
+                }
+            }
+
+            fn error_message(&self) -> &'static str {
+                match self {
+                    #(#message_arms)*
+                }
+            }
+
+            fn signalled_message(&self) -> &'static str {
+                match self {
+                    #(#signalled_message_arms)*
+                }
+            }
+        }
+
+        impl multiversx_sc::abi::SCErrorAbi for #name {
+            fn error_abis() -> multiversx_sc::types::heap::Vec<multiversx_sc::abi::ErrorAbi> {
+                let mut errors = multiversx_sc::types::heap::Vec::new();
+                #(#error_abi_snippets)*
+                errors
+            }
+        }
+
+        impl multiversx_sc::codec::SignalError for #name {
+            fn signal_error_or_handle_err<O, H>(&self, output: &mut O, h: H) -> core::result::Result<(), H::HandledErr>
+            where
+                O: multiversx_sc::codec::TopEncodeMultiOutput,
+                H: multiversx_sc::codec::EncodeErrorHandler,
+            {
+                let static_err = multiversx_sc::types::StaticSCError::from(
+                    multiversx_sc::types::CodedSCError::signalled_message(self),
+                );
+                output.push_multi_specialized(&static_err, h)
+            }
+        }
+    };
+    sc_error_impl.into()
+}
@@ -38,6 +38,47 @@ fn fields_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
     }
 }
 
+/// The type parameters that end up in the type name, and need to implement `TypeAbi` themselves.
+///
+/// Parameters bounded by `ManagedTypeApi` (e.g. `M: ManagedTypeApi`) are not part of the ABI,
+/// since they do not change the encoding.
+fn expanded_type_params(generics: &syn::Generics) -> Vec<syn::Ident> {
+    generics
+        .type_params()
+        .filter(|type_param| !is_api_type_param(generics, type_param))
+        .map(|type_param| type_param.ident.clone())
+        .collect()
+}
+
+fn is_api_type_param(generics: &syn::Generics, type_param: &syn::TypeParam) -> bool {
+    if type_param.bounds.iter().any(is_api_bound) {
+        return true;
+    }
+
+    let where_clause = match &generics.where_clause {
+        Some(where_clause) => where_clause,
+        None => return false,
+    };
+    where_clause.predicates.iter().any(|predicate| {
+        if let syn::WherePredicate::Type(predicate_type) = predicate {
+            if let syn::Type::Path(type_path) = &predicate_type.bounded_ty {
+                return type_path.path.is_ident(&type_param.ident)
+                    && predicate_type.bounds.iter().any(is_api_bound);
+            }
+        }
+        false
+    })
+}
+
+fn is_api_bound(bound: &syn::TypeParamBound) -> bool {
+    if let syn::TypeParamBound::Trait(trait_bound) = bound {
+        if let Some(last_segment) = trait_bound.path.segments.last() {
+            return last_segment.ident == "ManagedTypeApi";
+        }
+    }
+    false
+}
+
 pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
     let type_docs = extract_doc(ast.attrs.as_slice());
     let type_description_impl = match &ast.data {
@@ -107,11 +148,30 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
 
     let name = &ast.ident;
     let name_str = name.to_string();
-    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let expanded_type_params = expanded_type_params(&ast.generics);
+    let type_name_impl = if expanded_type_params.is_empty() {
+        quote! { #name_str.into() }
+    } else {
+        quote! {
+            multiversx_sc::abi::type_name_monomorphized(
+                #name_str,
+                &[ #( <#expanded_type_params as multiversx_sc::abi::TypeAbi>::type_name() ),* ],
+            )
+        }
+    };
+
+    let mut generics = ast.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for type_param in &expanded_type_params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote! { #type_param: multiversx_sc::abi::TypeAbi });
+    }
+    let (impl_generics, ty_generics, where_clause) = &generics.split_for_impl();
     let type_abi_impl = quote! {
         impl #impl_generics multiversx_sc::abi::TypeAbi for #name #ty_generics #where_clause {
             fn type_name() -> multiversx_sc::abi::TypeName {
-                #name_str.into()
+                #type_name_impl
             }
 
             #type_description_impl
@@ -1,5 +1,11 @@
 use super::reserved;
-use crate::model::{ArgPaymentMetadata, ContractTrait, Method, PublicRole};
+use crate::{
+    generate::{
+        abi_gen::{storage_key_args, storage_value_type},
+        util::last_path_segment_name,
+    },
+    model::{ArgPaymentMetadata, AutoImpl, ContractTrait, Method, MethodImpl, PublicRole},
+};
 
 const INIT_ENDPOINT_NAME: &str = "init";
 
@@ -8,6 +14,141 @@ pub fn validate_contract(contract_trait: &ContractTrait) {
     for m in &contract_trait.methods {
         validate_method(m);
     }
+    validate_unique_endpoint_names(contract_trait);
+    validate_storage_key_layouts(contract_trait);
+}
+
+/// Methods with different labels can share a name, since they end up in different output contracts.
+///
+/// Clashes with endpoints of other modules are only detected when the contract ABI is assembled.
+fn validate_unique_endpoint_names(contract_trait: &ContractTrait) {
+    let mut declared: Vec<(String, &Method)> = Vec::new();
+    for m in &contract_trait.methods {
+        for endpoint_name in endpoint_public_names(m) {
+            if let Some((_, existing)) = declared.iter().find(|(name, existing)| {
+                *name == endpoint_name && same_labels(&existing.label_names, &m.label_names)
+            }) {
+                panic!(
+                    "Endpoint `{endpoint_name}` is declared twice in `{}`: by method `{}` and by method `{}`.",
+                    contract_trait.trait_name, existing.name, m.name
+                );
+            }
+            declared.push((endpoint_name, m));
+        }
+    }
+}
+
+fn same_labels(labels1: &[String], labels2: &[String]) -> bool {
+    labels1.len() == labels2.len() && labels1.iter().all(|label| labels2.contains(label))
+}
+
+/// Endpoint names and aliases.
+fn endpoint_public_names(m: &Method) -> Vec<String> {
+    match &m.public_role {
+        PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.all_public_names(),
+        PublicRole::CallbackPromise(callback_metadata) => {
+            vec![callback_metadata.callback_name.to_string()]
+        },
+        _ => Vec::new(),
+    }
+}
+
+/// Storage methods can share a base key, as long as they agree on how the data is laid out under it.
+///
+/// Only one storage mapper can be declared per key, getters, setters and the methods that check or clear the key
+/// can accompany it, provided they use the same key arguments and value type.
+///
+/// Clashes with storage of other modules are only detected when the contract ABI is assembled.
+fn validate_storage_key_layouts(contract_trait: &ContractTrait) {
+    let mut declared: Vec<StorageDeclaration> = Vec::new();
+    for m in &contract_trait.methods {
+        if let Some(declaration) = StorageDeclaration::from_method(m) {
+            for existing in declared.iter().filter(|d| d.key == declaration.key) {
+                if let Some(clash) = existing.clash(&declaration) {
+                    panic!(
+                        "Storage key `{}` is used in `{}` by both method `{}` and method `{}`: {clash}.",
+                        declaration.key, contract_trait.trait_name, existing.method.name, m.name
+                    );
+                }
+            }
+            declared.push(declaration);
+        }
+    }
+}
+
+struct StorageDeclaration<'a> {
+    method: &'a Method,
+    key: &'a str,
+    is_mapper: bool,
+    layout: Option<String>,
+    value_type: Option<String>,
+    num_key_args: usize,
+}
+
+impl<'a> StorageDeclaration<'a> {
+    fn from_method(m: &'a Method) -> Option<Self> {
+        let auto_impl = match &m.implementation {
+            MethodImpl::Generated(auto_impl) => auto_impl,
+            _ => return None,
+        };
+        let (key, is_mapper) = match auto_impl {
+            AutoImpl::StorageMapper { identifier } => (identifier, true),
+            AutoImpl::StorageGetter { identifier }
+            | AutoImpl::StorageSetter { identifier }
+            | AutoImpl::StorageIsEmpty { identifier }
+            | AutoImpl::StorageClear { identifier } => (identifier, false),
+            _ => return None,
+        };
+        Some(StorageDeclaration {
+            method: m,
+            key: key.as_str(),
+            is_mapper,
+            layout: storage_key_layout(m),
+            value_type: storage_value_type(m),
+            num_key_args: storage_key_args(m).len(),
+        })
+    }
+
+    /// Mirrors the checks of `ContractAbi::add_storage_key`.
+    fn clash(&self, other: &Self) -> Option<String> {
+        if self.is_mapper && other.is_mapper {
+            return Some("only one storage mapper can be declared per key".to_string());
+        }
+        if let (Some(layout), Some(other_layout)) = (&self.layout, &other.layout) {
+            if layout != other_layout {
+                return Some(format!("layouts `{layout}` and `{other_layout}` differ"));
+            }
+        }
+        if let (Some(value_type), Some(other_value_type)) = (&self.value_type, &other.value_type) {
+            if value_type != other_value_type {
+                return Some(format!(
+                    "value types `{value_type}` and `{other_value_type}` differ"
+                ));
+            }
+        }
+        if self.num_key_args != other.num_key_args {
+            return Some(format!(
+                "{} and {} key arguments",
+                self.num_key_args, other.num_key_args
+            ));
+        }
+        None
+    }
+}
+
+/// Getters and setters store the value directly under the key, like a `SingleValueMapper`.
+/// The methods that only check or clear the key work with any layout, so they have none.
+fn storage_key_layout(m: &Method) -> Option<String> {
+    match &m.implementation {
+        MethodImpl::Generated(AutoImpl::StorageMapper { .. }) => match &m.return_type {
+            syn::ReturnType::Type(_, ty) => last_path_segment_name(ty),
+            syn::ReturnType::Default => None,
+        },
+        MethodImpl::Generated(AutoImpl::StorageGetter { .. } | AutoImpl::StorageSetter { .. }) => {
+            Some("SingleValueMapper".to_string())
+        },
+        _ => None,
+    }
 }
 
 pub fn validate_method(m: &Method) {
@@ -24,6 +165,17 @@ fn validate_method_name(m: &Method) {
             "Cannot declare endpoint with name 'init'. Use #[init] instead."
         );
         assert!(!reserved::is_reserved(endpoint_name_str.as_str()), "Cannot declare endpoint with name '{endpoint_name_str}', because that name is reserved by the Arwen API.");
+        for alias in &endpoint_metadata.aliases {
+            // aliases become wasm exports, declared as functions in the generated wasm crate
+            assert!(
+                syn::parse_str::<syn::Ident>(alias).is_ok(),
+                "Invalid endpoint alias '{alias}': aliases need to be valid Rust identifiers."
+            );
+            assert!(
+                alias != INIT_ENDPOINT_NAME && !reserved::is_reserved(alias.as_str()),
+                "Cannot declare endpoint alias '{alias}', because that name is reserved."
+            );
+        }
     }
 }
 
@@ -73,7 +225,7 @@ fn validate_payment_args(m: &Method) {
 
         assert!(num_payment_token == 0, "`#[payment_token]` only allowed in payable endpoints, payable init or callbacks (method: `{}`)", m.name);
     }
-    if let PublicRole::Init(init_metadata) = &m.public_role {
+    if let PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) = &m.public_role {
         assert!(
             init_metadata.payable.no_esdt(),
             "only EGLD payments currently allowed in constructors"
@@ -0,0 +1,7 @@
+mod abi_value;
+mod abi_value_decoder;
+mod storage_decoder;
+
+pub use abi_value::{fmt_fields, AbiValue};
+pub use abi_value_decoder::{generic_arg, split_type_args, AbiValueDecoder};
+pub use storage_decoder::{DecodedStorageEntry, StorageDecoder};
@@ -0,0 +1,108 @@
+use std::fmt;
+
+use bech32::{ToBase32, Variant};
+use multiversx_sc::{
+    codec::num_bigint::{BigInt, BigUint},
+    types::heap::Address,
+};
+
+/// A value decoded based on its ABI type, e.g. from an event topic, a storage entry or call data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiValue {
+    Bool(bool),
+    Unsigned(BigUint),
+    Signed(BigInt),
+    Address(Address),
+    Str(String),
+    Bytes(Vec<u8>),
+    List(Vec<AbiValue>),
+    Option(Option<Box<AbiValue>>),
+    Tuple(Vec<AbiValue>),
+    Struct {
+        name: String,
+        fields: Vec<(String, AbiValue)>,
+    },
+    Enum {
+        name: String,
+        variant: String,
+        fields: Vec<(String, AbiValue)>,
+    },
+    /// Shown as hex: hashes, as well as the bytes of unknown types or that do not match their type.
+    Raw(Vec<u8>),
+}
+
+/// Prints the fields as ` { name: value, ... }`, nothing if there are none.
+pub fn fmt_fields(f: &mut fmt::Formatter<'_>, fields: &[(String, AbiValue)]) -> fmt::Result {
+    if fields.is_empty() {
+        return Ok(());
+    }
+
+    write!(f, " {{ ")?;
+    for (i, (name, value)) in fields.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{name}: {value}")?;
+    }
+    write!(f, " }}")
+}
+
+fn fmt_items(f: &mut fmt::Formatter<'_>, items: &[AbiValue]) -> fmt::Result {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    Ok(())
+}
+
+/// Addresses are shown in bech32, with the human-readable part configured in the SDK (`erd` by default).
+fn address_to_bech32(address: &Address) -> String {
+    bech32::encode(
+        &multiversx_sdk::data::address::hrp(),
+        address.as_bytes().to_base32(),
+        Variant::Bech32,
+    )
+    .expect("bech32 encode error")
+}
+
+/// Enums are shown by variant name, followed by the fields, if any.
+impl fmt::Display for AbiValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiValue::Bool(b) => write!(f, "{b}"),
+            AbiValue::Unsigned(n) => write!(f, "{n}"),
+            AbiValue::Signed(n) => write!(f, "{n}"),
+            AbiValue::Address(address) => write!(f, "{}", address_to_bech32(address)),
+            AbiValue::Str(s) => write!(f, "{s:?}"),
+            AbiValue::Bytes(bytes) => match std::str::from_utf8(bytes) {
+                Ok(s) if !s.chars().any(char::is_control) => write!(f, "{s:?}"),
+                _ => write!(f, "0x{}", hex::encode(bytes)),
+            },
+            AbiValue::List(items) => {
+                write!(f, "[")?;
+                fmt_items(f, items)?;
+                write!(f, "]")
+            },
+            AbiValue::Option(None) => write!(f, "None"),
+            AbiValue::Option(Some(inner)) => write!(f, "Some({inner})"),
+            AbiValue::Tuple(items) => {
+                write!(f, "(")?;
+                fmt_items(f, items)?;
+                write!(f, ")")
+            },
+            AbiValue::Struct { name, fields } => {
+                write!(f, "{name}")?;
+                fmt_fields(f, fields)
+            },
+            AbiValue::Enum {
+                variant, fields, ..
+            } => {
+                write!(f, "{variant}")?;
+                fmt_fields(f, fields)
+            },
+            AbiValue::Raw(bytes) => write!(f, "0x{}", hex::encode(bytes)),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+
+use multiversx_sc::{
+    codec::num_bigint::{BigInt, BigUint},
+    types::heap::Address,
+};
+
+use super::AbiValue;
+use crate::abi_json::TypeDescriptionJson;
+
+const ADDRESS_LEN: usize = 32;
+
+/// Decodes encoded values, based on their ABI type names.
+///
+/// The custom types are taken from the `types` section of the ABI JSON.
+/// Values that do not match their type are kept raw.
+pub struct AbiValueDecoder<'a> {
+    pub types: &'a BTreeMap<String, TypeDescriptionJson>,
+}
+
+impl<'a> AbiValueDecoder<'a> {
+    pub fn new(types: &'a BTreeMap<String, TypeDescriptionJson>) -> Self {
+        AbiValueDecoder { types }
+    }
+
+    /// Decodes a top-encoded value, e.g. an event topic, a storage value or an argument.
+    pub fn top_decode(&self, type_name: &str, bytes: &[u8]) -> AbiValue {
+        self.try_top_decode(type_name, bytes)
+            .unwrap_or_else(|| AbiValue::Raw(bytes.to_vec()))
+    }
+
+    /// Decodes a nested-encoded value from the start of the input, advancing it.
+    ///
+    /// Yields `None` if the input does not match the type.
+    pub fn nested_decode(&self, type_name: &str, input: &mut &[u8]) -> Option<AbiValue> {
+        match type_name {
+            "Address" => Some(AbiValue::Address(Address::from_slice(take(
+                input,
+                ADDRESS_LEN,
+            )?))),
+            "H256" => Some(AbiValue::Raw(take(input, 32)?.to_vec())),
+            "H160" => Some(AbiValue::Raw(take(input, 20)?.to_vec())),
+            "bool" => match take(input, 1)? {
+                [0] => Some(AbiValue::Bool(false)),
+                [1] => Some(AbiValue::Bool(true)),
+                _ => None,
+            },
+            "BigUint" => Some(AbiValue::Unsigned(BigUint::from_bytes_be(
+                take_length_prefixed(input)?,
+            ))),
+            "BigInt" => Some(AbiValue::Signed(BigInt::from_signed_bytes_be(
+                take_length_prefixed(input)?,
+            ))),
+            "bytes" => Some(AbiValue::Bytes(take_length_prefixed(input)?.to_vec())),
+            "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+                string_value(take_length_prefixed(input)?)
+            },
+            _ => {
+                if let Some(size) = unsigned_size(type_name) {
+                    Some(AbiValue::Unsigned(BigUint::from_bytes_be(take(
+                        input, size,
+                    )?)))
+                } else if let Some(size) = signed_size(type_name) {
+                    Some(AbiValue::Signed(BigInt::from_signed_bytes_be(take(
+                        input, size,
+                    )?)))
+                } else if let Some(item_type) = generic_arg(type_name, "List") {
+                    let len = take_u32(input)?;
+                    let items = (0..len)
+                        .map(|_| self.nested_decode(item_type, input))
+                        .collect::<Option<Vec<_>>>()?;
+                    Some(AbiValue::List(items))
+                } else if let Some(inner_type) = generic_arg(type_name, "Option") {
+                    match take(input, 1)? {
+                        [0] => Some(AbiValue::Option(None)),
+                        [1] => {
+                            let inner = self.nested_decode(inner_type, input)?;
+                            Some(AbiValue::Option(Some(Box::new(inner))))
+                        },
+                        _ => None,
+                    }
+                } else if let Some(item_types) = generic_arg(type_name, "tuple") {
+                    let items = split_type_args(item_types)
+                        .into_iter()
+                        .map(|item_type| self.nested_decode(item_type, input))
+                        .collect::<Option<Vec<_>>>()?;
+                    Some(AbiValue::Tuple(items))
+                } else if let Some((len, item_type)) = array_args(type_name) {
+                    let items = (0..len)
+                        .map(|_| self.nested_decode(item_type, input))
+                        .collect::<Option<Vec<_>>>()?;
+                    Some(AbiValue::List(items))
+                } else {
+                    self.nested_decode_custom(type_name, input)
+                }
+            },
+        }
+    }
+
+    fn try_top_decode(&self, type_name: &str, bytes: &[u8]) -> Option<AbiValue> {
+        match type_name {
+            "bool" => match bytes {
+                [] => Some(AbiValue::Bool(false)),
+                [1] => Some(AbiValue::Bool(true)),
+                _ => None,
+            },
+            "BigUint" => Some(AbiValue::Unsigned(BigUint::from_bytes_be(bytes))),
+            "BigInt" => Some(AbiValue::Signed(BigInt::from_signed_bytes_be(bytes))),
+            "bytes" => Some(AbiValue::Bytes(bytes.to_vec())),
+            "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => string_value(bytes),
+            _ => {
+                if let Some(size) = unsigned_size(type_name) {
+                    (bytes.len() <= size).then(|| AbiValue::Unsigned(BigUint::from_bytes_be(bytes)))
+                } else if let Some(size) = signed_size(type_name) {
+                    (bytes.len() <= size)
+                        .then(|| AbiValue::Signed(BigInt::from_signed_bytes_be(bytes)))
+                } else if let Some(item_type) = generic_arg(type_name, "List") {
+                    let mut input = bytes;
+                    let mut items = Vec::new();
+                    while !input.is_empty() {
+                        items.push(self.nested_decode(item_type, &mut input)?);
+                    }
+                    Some(AbiValue::List(items))
+                } else if let Some(inner_type) = generic_arg(type_name, "Option") {
+                    match bytes.split_first() {
+                        None => Some(AbiValue::Option(None)),
+                        Some((1, mut input)) => {
+                            let inner = self.nested_decode(inner_type, &mut input)?;
+                            input
+                                .is_empty()
+                                .then(|| AbiValue::Option(Some(Box::new(inner))))
+                        },
+                        _ => None,
+                    }
+                } else if bytes.is_empty() && self.is_enum(type_name) {
+                    // the first variant is top-encoded as empty bytes
+                    self.nested_decode_custom(type_name, &mut &[0u8][..])
+                } else {
+                    let mut input = bytes;
+                    let value = self.nested_decode(type_name, &mut input)?;
+                    input.is_empty().then_some(value)
+                }
+            },
+        }
+    }
+
+    fn is_enum(&self, type_name: &str) -> bool {
+        matches!(
+            self.types.get(type_name),
+            Some(type_description) if type_description.content_type == "enum"
+        )
+    }
+
+    fn nested_decode_custom(&self, type_name: &str, input: &mut &[u8]) -> Option<AbiValue> {
+        let type_description = self.types.get(type_name)?;
+        match type_description.content_type.as_str() {
+            "struct" => {
+                let fields = type_description
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let value = self.nested_decode(&field.field_type, input)?;
+                        Some((field.name.clone(), value))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(AbiValue::Struct {
+                    name: type_name.to_string(),
+                    fields,
+                })
+            },
+            "enum" => {
+                let discriminant = take(input, 1)?[0] as usize;
+                let variant = type_description
+                    .variants
+                    .iter()
+                    .find(|variant| variant.discriminant == Some(discriminant))?;
+                let fields = variant
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let value = self.nested_decode(&field.field_type, input)?;
+                        Some((field.name.clone(), value))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(AbiValue::Enum {
+                    name: type_name.to_string(),
+                    variant: variant.name.clone(),
+                    fields,
+                })
+            },
+            "explicit-enum" => {
+                let name = String::from_utf8(take_length_prefixed(input)?.to_vec()).ok()?;
+                type_description
+                    .variants
+                    .iter()
+                    .any(|variant| variant.name == name)
+                    .then(|| AbiValue::Enum {
+                        name: type_name.to_string(),
+                        variant: name,
+                        fields: Vec::new(),
+                    })
+            },
+            _ => None,
+        }
+    }
+}
+
+fn string_value(bytes: &[u8]) -> Option<AbiValue> {
+    String::from_utf8(bytes.to_vec()).ok().map(AbiValue::Str)
+}
+
+fn unsigned_size(type_name: &str) -> Option<usize> {
+    match type_name {
+        "u8" => Some(1),
+        "u16" => Some(2),
+        "u32" | "usize" => Some(4),
+        "u64" => Some(8),
+        _ => None,
+    }
+}
+
+fn signed_size(type_name: &str) -> Option<usize> {
+    match type_name {
+        "i8" => Some(1),
+        "i16" => Some(2),
+        "i32" | "isize" => Some(4),
+        "i64" => Some(8),
+        _ => None,
+    }
+}
+
+/// `generic_arg("List<u32>", "List")` -> `Some("u32")`.
+pub fn generic_arg<'t>(type_name: &'t str, generic_name: &str) -> Option<&'t str> {
+    type_name
+        .strip_prefix(generic_name)?
+        .strip_prefix('<')?
+        .strip_suffix('>')
+}
+
+/// `"array32<u8>"` -> `(32, "u8")`.
+fn array_args(type_name: &str) -> Option<(usize, &str)> {
+    let rest = type_name.strip_prefix("array")?;
+    let (len_str, item_type) = rest.split_once('<')?;
+    let len = len_str.parse().ok()?;
+    Some((len, item_type.strip_suffix('>')?))
+}
+
+/// Splits `"u32,List<u8>,BigUint"` at the top-level commas.
+pub fn split_type_args(type_args: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in type_args.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                result.push(type_args[start..i].trim());
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    result.push(type_args[start..].trim());
+    result
+}
+
+fn take<'b>(input: &mut &'b [u8], len: usize) -> Option<&'b [u8]> {
+    if input.len() < len {
+        return None;
+    }
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Some(taken)
+}
+
+fn take_u32(input: &mut &[u8]) -> Option<usize> {
+    let bytes: [u8; 4] = take(input, 4)?.try_into().ok()?;
+    Some(u32::from_be_bytes(bytes) as usize)
+}
+
+fn take_length_prefixed<'b>(input: &mut &'b [u8]) -> Option<&'b [u8]> {
+    let len = take_u32(input)?;
+    take(input, len)
+}
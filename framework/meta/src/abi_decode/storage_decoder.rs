@@ -0,0 +1,219 @@
+use std::{collections::BTreeMap, fmt};
+
+use multiversx_sc::abi::ContractAbi;
+
+use super::AbiValueDecoder;
+use crate::abi_json::{ContractAbiJson, StorageKeyAbiJson, TypeDescriptionJson};
+
+const LEN_SUFFIX: &[u8] = b".len";
+const ITEM_SUFFIX: &[u8] = b".item";
+
+/// Decodes raw storage entries, based on the storage section of a contract ABI.
+///
+/// Each key is attributed to the storage mapper, getter or setter with the longest matching base key.
+/// The key arguments are decoded based on their types, and so is the value,
+/// for single values and for the items of vec-like mappers.
+/// The other mapper entries are shown raw.
+pub struct StorageDecoder {
+    pub storage: Vec<StorageKeyAbiJson>,
+    pub types: BTreeMap<String, TypeDescriptionJson>,
+}
+
+/// A storage entry, decoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedStorageEntry {
+    pub key: Vec<u8>,
+
+    /// The storage mapper, getter or setter, `None` if the key matches none in the ABI.
+    pub name: Option<String>,
+
+    /// The key arguments, by name.
+    pub key_args: Vec<(String, String)>,
+
+    /// What follows the key arguments, added by the mapper, e.g. `.len`.
+    pub key_suffix: String,
+
+    pub value: String,
+}
+
+impl StorageDecoder {
+    pub fn from_abi_json(abi_json: ContractAbiJson) -> Self {
+        StorageDecoder {
+            storage: abi_json.storage,
+            types: abi_json.types,
+        }
+    }
+
+    pub fn from_abi(abi: &ContractAbi) -> Self {
+        StorageDecoder {
+            storage: abi
+                .storage_keys
+                .iter()
+                .map(StorageKeyAbiJson::from)
+                .collect(),
+            types: abi
+                .type_descriptions
+                .0
+                .iter()
+                .filter(|(_, type_description)| type_description.contents.is_specified())
+                .map(|(type_name, type_description)| {
+                    (
+                        type_name.clone(),
+                        TypeDescriptionJson::from(type_description),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Decodes all entries, sorted by key.
+    pub fn decode_storage<I, K, V>(&self, storage: I) -> Vec<DecodedStorageEntry>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        let mut entries: Vec<DecodedStorageEntry> = storage
+            .into_iter()
+            .map(|(key, value)| self.decode_entry(key.as_ref(), value.as_ref()))
+            .collect();
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+        entries
+    }
+
+    pub fn decode_entry(&self, key: &[u8], value: &[u8]) -> DecodedStorageEntry {
+        let mut candidates: Vec<&StorageKeyAbiJson> = self
+            .storage
+            .iter()
+            .filter(|storage_key_abi| key.starts_with(storage_key_abi.key.as_bytes()))
+            .collect();
+        candidates.sort_by_key(|storage_key_abi| std::cmp::Reverse(storage_key_abi.key.len()));
+
+        candidates
+            .into_iter()
+            .find_map(|storage_key_abi| self.try_decode_entry(storage_key_abi, key, value))
+            .unwrap_or_else(|| DecodedStorageEntry {
+                key: key.to_vec(),
+                name: None,
+                key_args: Vec::new(),
+                key_suffix: String::new(),
+                value: format!("0x{}", hex::encode(value)),
+            })
+    }
+
+    fn try_decode_entry(
+        &self,
+        storage_key_abi: &StorageKeyAbiJson,
+        key: &[u8],
+        value: &[u8],
+    ) -> Option<DecodedStorageEntry> {
+        let value_decoder = AbiValueDecoder::new(&self.types);
+        let mut input = &key[storage_key_abi.key.len()..];
+        let key_args = storage_key_abi
+            .inputs
+            .iter()
+            .map(|key_input| {
+                let arg_value = value_decoder.nested_decode(&key_input.type_name, &mut input)?;
+                Some((key_input.arg_name.clone(), arg_value.to_string()))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let value_type = storage_key_abi.value_type.as_deref();
+        let (key_suffix, value) = if input.is_empty() {
+            (
+                String::new(),
+                decode_value(&value_decoder, value_type, value),
+            )
+        } else if input == LEN_SUFFIX {
+            (
+                String::from_utf8_lossy(LEN_SUFFIX).to_string(),
+                value_decoder.top_decode("u32", value).to_string(),
+            )
+        } else if let Some(index) = item_index(input) {
+            (
+                format!(".item[{index}]"),
+                decode_value(&value_decoder, value_type, value),
+            )
+        } else {
+            (
+                pretty_key_suffix(input),
+                format!("0x{}", hex::encode(value)),
+            )
+        };
+
+        Some(DecodedStorageEntry {
+            key: key.to_vec(),
+            name: Some(storage_key_abi.name.clone()),
+            key_args,
+            key_suffix,
+            value,
+        })
+    }
+}
+
+fn decode_value(value_decoder: &AbiValueDecoder, value_type: Option<&str>, value: &[u8]) -> String {
+    match value_type {
+        Some(value_type) => value_decoder.top_decode(value_type, value).to_string(),
+        None => format!("0x{}", hex::encode(value)),
+    }
+}
+
+/// Vec-like mappers store their items under `.item`, followed by the 1-based index.
+fn item_index(key_suffix: &[u8]) -> Option<u32> {
+    let index_bytes: [u8; 4] = key_suffix.strip_prefix(ITEM_SUFFIX)?.try_into().ok()?;
+    Some(u32::from_be_bytes(index_bytes))
+}
+
+/// The readable part of the suffix as text, the rest as hex.
+fn pretty_key_suffix(key_suffix: &[u8]) -> String {
+    let readable_len = key_suffix
+        .iter()
+        .take_while(|b| b.is_ascii_graphic())
+        .count();
+    let (readable, rest) = key_suffix.split_at(readable_len);
+    if rest.is_empty() {
+        String::from_utf8_lossy(readable).to_string()
+    } else {
+        format!(
+            "{}|0x{}",
+            String::from_utf8_lossy(readable),
+            hex::encode(rest)
+        )
+    }
+}
+
+impl DecodedStorageEntry {
+    /// E.g. `cooldown(action: "claim")`, or the raw key, as hex, if it was not recognized.
+    pub fn key_description(&self) -> String {
+        let name = match &self.name {
+            Some(name) => name,
+            None => return format!("0x{}", hex::encode(&self.key)),
+        };
+
+        let key_args: Vec<String> = self
+            .key_args
+            .iter()
+            .map(|(arg_name, arg_value)| format!("{arg_name}: {arg_value}"))
+            .collect();
+        format!("{name}({}){}", key_args.join(", "), self.key_suffix)
+    }
+}
+
+impl fmt::Display for DecodedStorageEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.key_description(), self.value)
+    }
+}
+
+impl fmt::Debug for StorageDecoder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let storage_names: Vec<&str> = self
+            .storage
+            .iter()
+            .map(|storage_key_abi| storage_key_abi.name.as_str())
+            .collect();
+        f.debug_struct("StorageDecoder")
+            .field("storage", &storage_names)
+            .finish_non_exhaustive()
+    }
+}
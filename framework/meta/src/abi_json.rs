@@ -1,14 +1,20 @@
+mod abi_snapshot;
 mod build_info_abi_json;
 mod contract_abi_json;
 mod endpoint_abi_json;
 mod event_abi_json;
+mod json_schema;
+mod storage_abi_json;
 mod type_abi_json;
 
+pub use abi_snapshot::*;
 pub use build_info_abi_json::{BuildInfoAbiJson, RustcAbiJson};
 pub use contract_abi_json::*;
 pub use endpoint_abi_json::*;
 pub use event_abi_json::*;
+pub use json_schema::{contract_json_schema, serialize_json_schema};
 use multiversx_sc::{abi::ContractAbi, contract_base::ContractAbiProvider};
+pub use storage_abi_json::*;
 pub use type_abi_json::*;
 
 /// Function provided for convenience.
@@ -0,0 +1,158 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use multiversx_sc::abi::ContractAbi;
+use serde_json::Value;
+
+use super::{serialize_abi_to_json, ContractAbiJson};
+
+/// Setting this environment variable (to any value) overwrites the snapshots with the current values,
+/// instead of comparing against them.
+///
+/// Shared by all snapshot tests: the ABI snapshots here, as well as the transaction result snapshots in the scenario crate.
+pub const BLESS_SNAPSHOTS_ENV_VAR: &str = "SC_BLESS_SNAPSHOTS";
+
+/// The ABI JSON, without the build info, so that it only changes together with the contract interface.
+pub fn abi_snapshot_json(abi: &ContractAbi) -> String {
+    let mut abi_json = ContractAbiJson::from(abi);
+    abi_json.build_info = None;
+    serialize_abi_to_json(&abi_json)
+}
+
+/// Checks the contract ABI against the snapshot committed in the repository,
+/// panicking with the list of changed endpoints, events and types if they differ.
+///
+/// Meant to be called from a test, so that interface changes are reviewed explicitly.
+/// The snapshots are generated with `sc-meta all abi-snapshot`,
+/// or by running the test with the `SC_BLESS_SNAPSHOTS` environment variable set.
+pub fn check_abi_snapshot<P: AsRef<Path>>(abi: &ContractAbi, snapshot_path: P) {
+    let snapshot_path = snapshot_path.as_ref();
+    let current_json = abi_snapshot_json(abi);
+    if std::env::var_os(BLESS_SNAPSHOTS_ENV_VAR).is_some() {
+        fs::write(snapshot_path, current_json).unwrap_or_else(|err| {
+            panic!(
+                "failed to write ABI snapshot {}: {err}",
+                snapshot_path.display()
+            )
+        });
+        return;
+    }
+
+    let snapshot_json = fs::read_to_string(snapshot_path).unwrap_or_else(|_| {
+        panic!(
+            "ABI snapshot {} not found, generate it with `sc-meta all abi-snapshot`",
+            snapshot_path.display()
+        )
+    });
+    if snapshot_json == current_json {
+        return;
+    }
+
+    let changes: Vec<String> = abi_snapshot_diff(&snapshot_json, &current_json)
+        .iter()
+        .map(|change| format!("\n  - {change}"))
+        .collect();
+    panic!(
+        "ABI of `{}` differs from the snapshot {}:{}\nReview the interface changes, then update the snapshot with `sc-meta all abi-snapshot`, or by running the test with {BLESS_SNAPSHOTS_ENV_VAR}=1.",
+        abi.name,
+        snapshot_path.display(),
+        changes.concat(),
+    );
+}
+
+/// Lists the differences between two ABI JSONs, e.g. ``endpoint `add` changed``.
+pub fn abi_snapshot_diff(snapshot_json: &str, current_json: &str) -> Vec<String> {
+    let snapshot: Value = serde_json::from_str(snapshot_json)
+        .unwrap_or_else(|err| panic!("failed to parse ABI snapshot: {err}"));
+    let current: Value = serde_json::from_str(current_json)
+        .unwrap_or_else(|err| panic!("failed to parse ABI: {err}"));
+
+    let mut changes = Vec::new();
+    for (key, label) in [
+        ("name", "contract name"),
+        ("docs", "contract docs"),
+        ("constructor", "constructor"),
+        ("upgradeConstructor", "upgrade constructor"),
+        ("promisesCallbackNames", "promise callbacks"),
+        ("hasCallback", "callback"),
+    ] {
+        if snapshot.get(key) != current.get(key) {
+            changes.push(format!("{label} changed"));
+        }
+    }
+    diff_items(
+        &mut changes,
+        "endpoint",
+        array_items(&snapshot, "endpoints", "name"),
+        array_items(&current, "endpoints", "name"),
+    );
+    diff_items(
+        &mut changes,
+        "event",
+        array_items(&snapshot, "events", "identifier"),
+        array_items(&current, "events", "identifier"),
+    );
+    diff_items(
+        &mut changes,
+        "type",
+        object_items(&snapshot, "types"),
+        object_items(&current, "types"),
+    );
+    diff_items(
+        &mut changes,
+        "storage",
+        array_items(&snapshot, "storage", "name"),
+        array_items(&current, "storage", "name"),
+    );
+
+    if changes.is_empty() {
+        changes.push("the order or the formatting of the items changed".to_string());
+    }
+    changes
+}
+
+fn array_items<'a>(abi: &'a Value, key: &str, name_key: &str) -> BTreeMap<&'a str, &'a Value> {
+    abi.get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|item| {
+            (
+                item.get(name_key)
+                    .and_then(Value::as_str)
+                    .unwrap_or_default(),
+                item,
+            )
+        })
+        .collect()
+}
+
+fn object_items<'a>(abi: &'a Value, key: &str) -> BTreeMap<&'a str, &'a Value> {
+    abi.get(key)
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .map(|(name, item)| (name.as_str(), item))
+        .collect()
+}
+
+fn diff_items(
+    changes: &mut Vec<String>,
+    kind: &str,
+    snapshot_items: BTreeMap<&str, &Value>,
+    current_items: BTreeMap<&str, &Value>,
+) {
+    for (name, snapshot_item) in &snapshot_items {
+        match current_items.get(name) {
+            None => changes.push(format!("{kind} `{name}` removed")),
+            Some(current_item) if current_item != snapshot_item => {
+                changes.push(format!("{kind} `{name}` changed"))
+            },
+            _ => {},
+        }
+    }
+    for name in current_items.keys() {
+        if !snapshot_items.contains_key(name) {
+            changes.push(format!("{kind} `{name}` added"));
+        }
+    }
+}
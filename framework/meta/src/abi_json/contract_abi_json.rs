@@ -9,26 +9,39 @@ pub struct ContractAbiJson {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub build_info: Option<BuildInfoAbiJson>,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constructor: Option<ConstructorAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upgrade_constructor: Option<ConstructorAbiJson>,
     pub endpoints: Vec<EndpointAbiJson>,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub promises_callback_names: Vec<String>,
     pub events: Vec<EventAbiJson>,
     pub has_callback: bool,
     pub types: BTreeMap<String, TypeDescriptionJson>,
+    /// Only included on request, see `ContractAbiJson::with_storage`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<StorageKeyAbiJson>,
 }
 
 impl From<&ContractAbi> for ContractAbiJson {
     fn from(abi: &ContractAbi) -> Self {
-        let mut contract_json = ContractAbiJson {
+        ContractAbiJson {
             build_info: Some(BuildInfoAbiJson::from(&abi.build_info)),
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             constructor: abi.constructors.get(0).map(ConstructorAbiJson::from),
+            upgrade_constructor: abi
+                .upgrade_constructors
+                .get(0)
+                .map(ConstructorAbiJson::from),
             endpoints: abi.endpoints.iter().map(EndpointAbiJson::from).collect(),
             promises_callback_names: abi
                 .promise_callbacks
@@ -37,16 +50,21 @@ impl From<&ContractAbi> for ContractAbiJson {
                 .collect(),
             events: abi.events.iter().map(EventAbiJson::from).collect(),
             has_callback: abi.has_callback,
-            types: BTreeMap::new(),
-        };
-        for (type_name, type_description) in abi.type_descriptions.0.iter() {
-            if type_description.contents.is_specified() {
-                contract_json.types.insert(
-                    type_name.clone(),
-                    TypeDescriptionJson::from(type_description),
-                );
-            }
+            types: type_descriptions_json(&abi.type_descriptions),
+            storage: Vec::new(),
         }
+    }
+}
+
+impl ContractAbiJson {
+    /// Also includes the storage section: the storage mappers, getters and setters, with their keys.
+    pub fn with_storage(abi: &ContractAbi) -> Self {
+        let mut contract_json = ContractAbiJson::from(abi);
+        contract_json.storage = abi
+            .storage_keys
+            .iter()
+            .map(StorageKeyAbiJson::from)
+            .collect();
         contract_json
     }
 }
@@ -25,6 +25,7 @@ impl From<&InputAbi> for InputAbiJson {
 #[derive(Serialize, Deserialize)]
 pub struct OutputAbiJson {
     #[serde(rename = "name")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "String::is_empty")]
     pub output_name: String,
     #[serde(rename = "type")]
@@ -44,6 +45,27 @@ impl From<&OutputAbi> for OutputAbiJson {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct ErrorAbiJson {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    pub name: String,
+    pub code: u32,
+    pub message: String,
+}
+
+impl From<&ErrorAbi> for ErrorAbiJson {
+    fn from(abi: &ErrorAbi) -> Self {
+        ErrorAbiJson {
+            docs: abi.docs.iter().map(|d| d.to_string()).collect(),
+            name: abi.name.to_string(),
+            code: abi.code,
+            message: abi.message.to_string(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum EndpointMutabilityAbiJson {
@@ -52,12 +74,31 @@ pub enum EndpointMutabilityAbiJson {
     Pure,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct EndpointDeprecationAbiJson {
+    pub since: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub note: String,
+}
+
+impl From<&EndpointDeprecationAbi> for EndpointDeprecationAbiJson {
+    fn from(abi: &EndpointDeprecationAbi) -> Self {
+        EndpointDeprecationAbiJson {
+            since: abi.since.to_string(),
+            note: abi.note.to_string(),
+        }
+    }
+}
+
 /// Same as EndpointAbiJson but ignores the name
 #[derive(Serialize, Deserialize)]
 pub struct ConstructorAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     #[serde(rename = "payableInTokens")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
@@ -81,6 +122,7 @@ impl From<&EndpointAbi> for ConstructorAbiJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct EndpointAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
@@ -90,14 +132,27 @@ pub struct EndpointAbiJson {
     #[serde(rename = "onlyAdmin")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub only_admin: Option<bool>,
+    #[serde(rename = "testOnly")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_only: Option<bool>,
     pub mutability: EndpointMutabilityAbiJson,
     #[serde(rename = "payableInTokens")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
     pub outputs: Vec<OutputAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ErrorAbiJson>,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub labels: Vec<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<EndpointDeprecationAbiJson>,
 }
 
 impl From<&EndpointAbi> for EndpointAbiJson {
@@ -107,6 +162,7 @@ impl From<&EndpointAbi> for EndpointAbiJson {
             name: abi.name.to_string(),
             only_owner: if abi.only_owner { Some(true) } else { None },
             only_admin: if abi.only_admin { Some(true) } else { None },
+            test_only: if abi.test_only { Some(true) } else { None },
             mutability: match abi.mutability {
                 EndpointMutabilityAbi::Mutable => EndpointMutabilityAbiJson::Mutable,
                 EndpointMutabilityAbi::Readonly => EndpointMutabilityAbiJson::Readonly,
@@ -119,7 +175,13 @@ impl From<&EndpointAbi> for EndpointAbiJson {
                 .collect(),
             inputs: abi.inputs.iter().map(InputAbiJson::from).collect(),
             outputs: abi.outputs.iter().map(OutputAbiJson::from).collect(),
+            errors: abi.errors.iter().map(ErrorAbiJson::from).collect(),
             labels: abi.labels.iter().map(|&label| label.to_owned()).collect(),
+            aliases: abi.aliases.iter().map(|&alias| alias.to_owned()).collect(),
+            deprecated: abi
+                .deprecated
+                .as_ref()
+                .map(EndpointDeprecationAbiJson::from),
         }
     }
 }
@@ -0,0 +1,309 @@
+use multiversx_sc::abi::*;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+const JSON_SCHEMA_DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+
+/// Generates a JSON Schema (draft 7) describing the inputs and outputs of all endpoints of a contract.
+///
+/// Each endpoint is a property of the root object, with:
+/// - `inputs`: an object with one property per argument, optional and variadic arguments are not required;
+/// - `outputs`: an array with one item per result.
+///
+/// All the types in the ABI type section end up in `definitions`.
+/// Numbers that do not fit in 32 bits are represented as decimal strings,
+/// addresses as bech32 strings with the configured human-readable part, raw bytes as hex strings. Enums follow the usual JSON conventions:
+/// the name of the variant for simple variants, an object keyed by the name of the variant otherwise.
+pub fn contract_json_schema(abi: &ContractAbi) -> Value {
+    let mut endpoints = Map::new();
+    for endpoint in abi.iter_all_exports() {
+        endpoints.insert(endpoint.name.to_string(), endpoint_json_schema(endpoint));
+    }
+
+    let mut definitions = Map::new();
+    for (type_name, type_description) in abi.type_descriptions.0.iter() {
+        if type_description.contents.is_specified() {
+            definitions.insert(
+                type_name.clone(),
+                type_description_json_schema(type_description),
+            );
+        }
+    }
+
+    let mut schema = json!({
+        "$schema": JSON_SCHEMA_DRAFT,
+        "title": abi.name,
+        "type": "object",
+        "properties": endpoints,
+        "additionalProperties": false,
+        "definitions": definitions,
+    });
+    add_docs(&mut schema, abi.docs);
+    schema
+}
+
+pub fn serialize_json_schema(schema: &Value) -> String {
+    let buf = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut ser = serde_json::Serializer::with_formatter(buf, formatter);
+    schema.serialize(&mut ser).unwrap();
+    let mut serialized = String::from_utf8(ser.into_inner()).unwrap();
+    serialized.push('\n');
+    serialized
+}
+
+fn endpoint_json_schema(endpoint: &EndpointAbi) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for input in &endpoint.inputs {
+        properties.insert(
+            input.arg_name.to_string(),
+            type_name_json_schema(&input.type_name),
+        );
+        if !input.multi_arg {
+            required.push(input.arg_name);
+        }
+    }
+
+    let outputs: Vec<Value> = endpoint
+        .outputs
+        .iter()
+        .map(|output| type_name_json_schema(&output.type_name))
+        .collect();
+
+    let mut schema = json!({
+        "type": "object",
+        "properties": {
+            "inputs": {
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            },
+            "outputs": tuple_json_schema(outputs),
+        },
+    });
+    add_docs(&mut schema, endpoint.docs);
+    schema
+}
+
+fn type_description_json_schema(type_description: &TypeDescription) -> Value {
+    let mut schema = match &type_description.contents {
+        TypeContents::Struct(fields) => fields_json_schema(fields),
+        TypeContents::Enum(variants) => {
+            let variant_schemas: Vec<Value> = variants
+                .iter()
+                .map(|variant| {
+                    let mut variant_schema = if variant.fields.is_empty() {
+                        json!({ "const": variant.name })
+                    } else {
+                        json!({
+                            "type": "object",
+                            "properties": { variant.name: fields_json_schema(&variant.fields) },
+                            "required": [variant.name],
+                            "additionalProperties": false,
+                        })
+                    };
+                    add_docs(&mut variant_schema, variant.docs);
+                    variant_schema
+                })
+                .collect();
+            json!({ "oneOf": variant_schemas })
+        },
+        TypeContents::ExplicitEnum(variants) => {
+            let names: Vec<&str> = variants.iter().map(|variant| variant.name).collect();
+            json!({ "type": "string", "enum": names })
+        },
+        TypeContents::NotSpecified => json!({}),
+    };
+    add_docs(&mut schema, type_description.docs);
+    schema
+}
+
+fn fields_json_schema(fields: &[StructFieldDescription]) -> Value {
+    let mut properties = Map::new();
+    for field in fields {
+        let mut field_schema = type_name_json_schema(&field.field_type);
+        add_docs(&mut field_schema, field.docs);
+        properties.insert(field.name.to_string(), field_schema);
+    }
+    let required: Vec<&str> = fields.iter().map(|field| field.name).collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Schema of a type, as named in the ABI.
+fn type_name_json_schema(type_name: &str) -> Value {
+    if let Some((outer, args)) = split_generic_type_name(type_name) {
+        return generic_type_json_schema(outer, &args);
+    }
+
+    match type_name {
+        "bool" => json!({ "type": "boolean" }),
+        "u8" | "u16" | "u32" => unsigned_json_schema(type_name),
+        "i8" | "i16" | "i32" => signed_json_schema(type_name),
+        "u64" | "usize" | "BigUint" => {
+            json!({ "type": "string", "pattern": "^[0-9]+$" })
+        },
+        "i64" | "isize" | "BigInt" => {
+            json!({ "type": "string", "pattern": "^-?[0-9]+$" })
+        },
+        "Address" => address_json_schema(),
+        "H256" => hex_json_schema(Some(32)),
+        "H160" => hex_json_schema(Some(20)),
+        "bytes" | "CodeMetadata" => hex_json_schema(None),
+        "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+            json!({ "type": "string" })
+        },
+        "ignore" => json!({}),
+        _ => json!({ "$ref": format!("#/definitions/{type_name}") }),
+    }
+}
+
+fn generic_type_json_schema(outer: &str, args: &[&str]) -> Value {
+    match (outer, args) {
+        ("Option" | "optional", [item]) => {
+            json!({ "anyOf": [type_name_json_schema(item), { "type": "null" }] })
+        },
+        ("List" | "Queue" | "variadic" | "counted-variadic", [item]) => {
+            json!({ "type": "array", "items": type_name_json_schema(item) })
+        },
+        ("multi" | "tuple", items) => tuple_json_schema(
+            items
+                .iter()
+                .map(|item| type_name_json_schema(item))
+                .collect(),
+        ),
+        (array, [item]) if array.starts_with("array") => {
+            let length = array["array".len()..].parse::<usize>().ok();
+            if *item == "u8" {
+                hex_json_schema(length)
+            } else {
+                let mut schema = json!({ "type": "array", "items": type_name_json_schema(item) });
+                if let Some(length) = length {
+                    schema["minItems"] = json!(length);
+                    schema["maxItems"] = json!(length);
+                }
+                schema
+            }
+        },
+        _ => json!({}),
+    }
+}
+
+/// 32 bytes are encoded as 52 bech32 characters, followed by the 6 character checksum.
+fn address_json_schema() -> Value {
+    let hrp = multiversx_sdk::data::address::hrp();
+    json!({ "type": "string", "pattern": format!("^{hrp}1[02-9ac-hj-np-z]{{58}}$") })
+}
+
+fn unsigned_json_schema(type_name: &str) -> Value {
+    let max = match type_name {
+        "u8" => u8::MAX as u64,
+        "u16" => u16::MAX as u64,
+        _ => u32::MAX as u64,
+    };
+    json!({ "type": "integer", "minimum": 0, "maximum": max })
+}
+
+fn signed_json_schema(type_name: &str) -> Value {
+    let (min, max) = match type_name {
+        "i8" => (i8::MIN as i64, i8::MAX as i64),
+        "i16" => (i16::MIN as i64, i16::MAX as i64),
+        _ => (i32::MIN as i64, i32::MAX as i64),
+    };
+    json!({ "type": "integer", "minimum": min, "maximum": max })
+}
+
+fn hex_json_schema(num_bytes: Option<usize>) -> Value {
+    let pattern = match num_bytes {
+        Some(num_bytes) => format!("^([0-9a-fA-F]{{2}}){{{num_bytes}}}$"),
+        None => "^([0-9a-fA-F]{2})*$".to_string(),
+    };
+    json!({ "type": "string", "pattern": pattern })
+}
+
+fn tuple_json_schema(items: Vec<Value>) -> Value {
+    let num_items = items.len();
+    json!({
+        "type": "array",
+        "items": items,
+        "minItems": num_items,
+        "maxItems": num_items,
+    })
+}
+
+fn add_docs(schema: &mut Value, docs: &[&str]) {
+    if !docs.is_empty() {
+        schema["description"] = json!(docs.join("\n"));
+    }
+}
+
+/// Splits `List<multi<u32,BigUint>>` into `List` and `["multi<u32,BigUint>"]`.
+fn split_generic_type_name(type_name: &str) -> Option<(&str, Vec<&str>)> {
+    let open = type_name.find('<')?;
+    if !type_name.ends_with('>') {
+        return None;
+    }
+
+    let inner = &type_name[open + 1..type_name.len() - 1];
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut arg_start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(inner[arg_start..i].trim());
+                arg_start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    args.push(inner[arg_start..].trim());
+
+    Some((&type_name[..open], args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_generic_type_name_test() {
+        assert_eq!(split_generic_type_name("u32"), None);
+        assert_eq!(
+            split_generic_type_name("List<u32>"),
+            Some(("List", vec!["u32"]))
+        );
+        assert_eq!(
+            split_generic_type_name("variadic<multi<Address,List<u8>>>"),
+            Some(("variadic", vec!["multi<Address,List<u8>>"]))
+        );
+        assert_eq!(
+            split_generic_type_name("multi<u32,Option<BigUint>,bytes>"),
+            Some(("multi", vec!["u32", "Option<BigUint>", "bytes"]))
+        );
+    }
+
+    #[test]
+    fn type_name_json_schema_test() {
+        assert_eq!(
+            type_name_json_schema("Option<u8>"),
+            json!({ "anyOf": [{ "type": "integer", "minimum": 0, "maximum": 255 }, { "type": "null" }] })
+        );
+        assert_eq!(
+            type_name_json_schema("array2<u8>"),
+            json!({ "type": "string", "pattern": "^([0-9a-fA-F]{2}){2}$" })
+        );
+        assert_eq!(
+            type_name_json_schema("EsdtTokenPayment"),
+            json!({ "$ref": "#/definitions/EsdtTokenPayment" })
+        );
+    }
+}
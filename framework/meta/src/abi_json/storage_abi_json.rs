@@ -0,0 +1,51 @@
+use multiversx_sc::abi::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct StorageKeyInputAbiJson {
+    #[serde(rename = "name")]
+    pub arg_name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+}
+
+impl From<&StorageKeyInputAbi> for StorageKeyInputAbiJson {
+    fn from(abi: &StorageKeyInputAbi) -> Self {
+        StorageKeyInputAbiJson {
+            arg_name: abi.arg_name.to_string(),
+            type_name: abi.type_name.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StorageKeyAbiJson {
+    pub name: String,
+    pub key: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mapper: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<StorageKeyInputAbiJson>,
+    #[serde(rename = "valueType")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_type: Option<String>,
+}
+
+impl From<&StorageKeyAbi> for StorageKeyAbiJson {
+    fn from(abi: &StorageKeyAbi) -> Self {
+        StorageKeyAbiJson {
+            name: abi.name.to_string(),
+            key: abi.key.to_string(),
+            mapper: abi.mapper.map(|mapper| mapper.to_string()),
+            inputs: abi
+                .inputs
+                .iter()
+                .map(StorageKeyInputAbiJson::from)
+                .collect(),
+            value_type: abi.value_type.map(|value_type| value_type.to_string()),
+        }
+    }
+}
@@ -1,21 +1,42 @@
 use multiversx_sc::abi::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Serialize, Deserialize)]
 pub struct TypeDescriptionJson {
     #[serde(rename = "type")]
     pub content_type: String,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub variants: Vec<EnumVariantDescriptionJson>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
 
+/// The `types` section of the ABI JSON. Only the types with specified contents are included.
+pub fn type_descriptions_json(
+    type_descriptions: &TypeDescriptionContainerImpl,
+) -> BTreeMap<String, TypeDescriptionJson> {
+    type_descriptions
+        .0
+        .iter()
+        .filter(|(_, type_description)| type_description.contents.is_specified())
+        .map(|(type_name, type_description)| {
+            (
+                type_name.clone(),
+                TypeDescriptionJson::from(type_description),
+            )
+        })
+        .collect()
+}
+
 impl From<&TypeDescription> for TypeDescriptionJson {
     fn from(abi: &TypeDescription) -> Self {
         let content_type = match &abi.contents {
@@ -61,6 +82,7 @@ impl From<&TypeDescription> for TypeDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct StructFieldDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
@@ -80,11 +102,13 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct EnumVariantDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discriminant: Option<usize>,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
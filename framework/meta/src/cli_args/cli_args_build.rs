@@ -1,6 +1,7 @@
 use clap::{ArgAction, Args};
 
 use super::CliArgsToRaw;
+use crate::ei::EIVersion;
 
 #[derive(Clone, PartialEq, Eq, Debug, Args)]
 pub struct BuildArgs {
@@ -53,6 +54,11 @@ pub struct BuildArgs {
     #[arg(long = "target-dir-wasm", alias = "target-dir", verbatim_doc_comment)]
     pub target_dir_wasm: Option<String>,
 
+    /// Generate a size report after building, with the sizes of the code and of the static data.
+    /// If a report from a previous build exists, the differences are printed, as a size benchmark.
+    #[arg(long = "size-report", verbatim_doc_comment)]
+    pub size_report: bool,
+
     /// Generate a twiggy top report after building.
     #[arg(long = "twiggy-top", verbatim_doc_comment)]
     pub twiggy_top: bool,
@@ -69,6 +75,12 @@ pub struct BuildArgs {
     #[arg(long = "twiggy-dominators", verbatim_doc_comment)]
     pub twiggy_dominators: bool,
 
+    /// The VM version the contracts will be deployed on, e.g. "1.4".
+    /// Framework APIs not available on this VM become compile errors,
+    /// and the VM hooks are checked against its EI version, regardless of the `ei` setting.
+    #[arg(long = "target-vm", value_parser = parse_target_vm, verbatim_doc_comment)]
+    pub target_vm: Option<String>,
+
     /// Backwards compatibility with mxpy, delete when github actions are fixed.
     #[deprecated]
     #[arg(long = "target", verbatim_doc_comment)]
@@ -99,10 +111,12 @@ impl Default for BuildArgs {
             emit_llvm_ir: false,
             extract_imports: true,
             target_dir_wasm: None,
+            size_report: false,
             twiggy_top: false,
             twiggy_paths: false,
             twiggy_monos: false,
             twiggy_dominators: false,
+            target_vm: None,
             target: None,
             release: false,
             out_dir: None,
@@ -116,6 +130,12 @@ impl BuildArgs {
     }
 }
 
+/// Rejects unknown VM versions when parsing the arguments, instead of failing mid-build.
+fn parse_target_vm(target_vm: &str) -> Result<String, String> {
+    EIVersion::from_vm_version(target_vm)?;
+    Ok(target_vm.to_string())
+}
+
 impl CliArgsToRaw for BuildArgs {
     fn to_raw(&self) -> Vec<String> {
         let mut raw = Vec::new();
@@ -150,6 +170,9 @@ impl CliArgsToRaw for BuildArgs {
             raw.push("--target-dir".to_string());
             raw.push(target_dir_wasm.clone());
         }
+        if self.size_report {
+            raw.push("--size-report".to_string());
+        }
         if self.twiggy_top {
             raw.push("--twiggy-top".to_string());
         }
@@ -162,6 +185,10 @@ impl CliArgsToRaw for BuildArgs {
         if self.twiggy_dominators {
             raw.push("--twiggy-dominators".to_string());
         }
+        if let Some(target_vm) = &self.target_vm {
+            raw.push("--target-vm".to_string());
+            raw.push(target_vm.clone());
+        }
         raw
     }
 }
@@ -34,11 +34,16 @@ pub struct ContractCliArgs {
     pub load_abi_git_version: bool,
 }
 
-#[derive(Default, Clone, PartialEq, Eq, Debug, Subcommand)]
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
 pub enum ContractCliAction {
-    #[default]
     #[command(name = "abi", about = "Generates the contract ABI and nothing else.")]
-    Abi,
+    Abi(AbiArgs),
+
+    #[command(
+        name = "abi-snapshot",
+        about = "Saves the contract ABI next to the contract crate, as reference for the ABI snapshot tests."
+    )]
+    AbiSnapshot,
 
     #[command(
         name = "build",
@@ -55,6 +60,12 @@ pub enum ContractCliAction {
     )]
     Twiggy(TwiggyArgs),
 
+    #[command(
+        name = "check",
+        about = "Checks the contract(s) and warns about issues that do not prevent the build, e.g. deprecated endpoints."
+    )]
+    Check,
+
     #[command(about = "Clean the Rust project and the output folder.")]
     Clean,
 
@@ -68,12 +79,22 @@ pub enum ContractCliAction {
     GenerateSnippets(GenerateSnippetsArgs),
 }
 
+impl Default for ContractCliAction {
+    fn default() -> Self {
+        ContractCliAction::Abi(AbiArgs::default())
+    }
+}
+
 impl CliArgsToRaw for ContractCliAction {
     fn to_raw(&self) -> Vec<String> {
         let mut raw = Vec::new();
         match self {
-            ContractCliAction::Abi => {
+            ContractCliAction::Abi(args) => {
                 raw.push("abi".to_string());
+                raw.append(&mut args.to_raw());
+            },
+            ContractCliAction::AbiSnapshot => {
+                raw.push("abi-snapshot".to_string());
             },
             ContractCliAction::Build(args) => {
                 raw.push("build".to_string());
@@ -87,6 +108,9 @@ impl CliArgsToRaw for ContractCliAction {
                 raw.push("twiggy".to_string());
                 raw.append(&mut args.to_raw());
             },
+            ContractCliAction::Check => {
+                raw.push("check".to_string());
+            },
             ContractCliAction::Clean => {
                 raw.push("clean".to_string());
             },
@@ -102,6 +126,30 @@ impl CliArgsToRaw for ContractCliAction {
     }
 }
 
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AbiArgs {
+    /// Also generates a JSON Schema of the endpoint inputs and outputs, next to the ABI.
+    #[arg(long = "json-schema", verbatim_doc_comment)]
+    pub json_schema: bool,
+
+    /// Also includes the storage section in the ABI: the storage mappers, getters and setters, with their keys.
+    #[arg(long = "storage", verbatim_doc_comment)]
+    pub storage: bool,
+}
+
+impl CliArgsToRaw for AbiArgs {
+    fn to_raw(&self) -> Vec<String> {
+        let mut raw = Vec::new();
+        if self.json_schema {
+            raw.push("--json-schema".to_string());
+        }
+        if self.storage {
+            raw.push("--storage".to_string());
+        }
+        raw
+    }
+}
+
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
 pub struct GenerateSnippetsArgs {
     /// Override snippets project if it already exists.
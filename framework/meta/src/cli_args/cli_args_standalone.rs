@@ -64,6 +64,12 @@ pub enum StandaloneCliAction {
         about = "Generates Rust integration tests based on scenarios provided in the scenarios folder of each contract."
     )]
     TestGen(TestGenArgs),
+
+    #[command(
+        name = "convert",
+        about = "Converts values between the formats used by the blockchain: bech32, hex, base64, token amounts, event topics, storage keys."
+    )]
+    Convert(ConvertArgs),
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -232,3 +238,125 @@ pub struct TestGenArgs {
     #[arg(long, verbatim_doc_comment)]
     pub create: bool,
 }
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct ConvertArgs {
+    #[command(subcommand)]
+    pub command: ConvertCliAction,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum ConvertCliAction {
+    #[command(name = "bech32-to-hex", about = "Converts a bech32 address to hex.")]
+    Bech32ToHex(ConvertValueArgs),
+
+    #[command(name = "hex-to-bech32", about = "Converts a hex address to bech32.")]
+    HexToBech32(HexToBech32Args),
+
+    #[command(name = "base64-to-hex", about = "Converts base64 to hex.")]
+    Base64ToHex(ConvertValueArgs),
+
+    #[command(name = "hex-to-base64", about = "Converts hex to base64.")]
+    HexToBase64(ConvertValueArgs),
+
+    #[command(
+        name = "to-units",
+        about = "Scales a token amount, e.g. 1.5, to its integer value in the smallest units."
+    )]
+    ToUnits(ConvertAmountArgs),
+
+    #[command(
+        name = "from-units",
+        about = "Scales an integer value in the smallest units to a token amount, e.g. 1.5."
+    )]
+    FromUnits(ConvertAmountArgs),
+
+    #[command(
+        name = "topics",
+        about = "Decodes the topics of an event log, based on the events in the contract ABI."
+    )]
+    Topics(ConvertTopicsArgs),
+
+    #[command(
+        name = "storage-key",
+        about = "Computes the raw storage key of a storage mapper, based on the storage section of the contract ABI."
+    )]
+    StorageKey(ConvertStorageKeyArgs),
+
+    #[command(
+        name = "storage",
+        about = "Decodes a storage dump of a contract account, based on the storage section of the contract ABI."
+    )]
+    Storage(ConvertStorageArgs),
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ConvertValueArgs {
+    /// The value to convert.
+    pub value: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct HexToBech32Args {
+    /// The address, as hex, with or without the 0x prefix.
+    pub value: String,
+
+    /// The human-readable part of the bech32 address.
+    #[arg(long, default_value = "erd", verbatim_doc_comment)]
+    pub hrp: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ConvertAmountArgs {
+    /// The amount to convert.
+    pub value: String,
+
+    /// The number of decimals of the token.
+    /// EGLD has 18 decimals.
+    #[arg(long, default_value = "18", verbatim_doc_comment)]
+    pub decimals: usize,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ConvertTopicsArgs {
+    /// The path to the contract ABI JSON file.
+    #[arg(long, verbatim_doc_comment)]
+    pub abi: String,
+
+    /// The topics of the event log, as returned by the API, in base64.
+    /// The first topic is the event identifier.
+    #[arg(required = true, verbatim_doc_comment)]
+    pub topics: Vec<String>,
+
+    /// Interprets the topics as hex, instead of base64.
+    #[arg(long, verbatim_doc_comment)]
+    pub hex: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ConvertStorageKeyArgs {
+    /// The path to the contract ABI JSON file, generated with `sc-meta abi --storage`.
+    #[arg(long, verbatim_doc_comment)]
+    pub abi: String,
+
+    /// The name of the storage mapper, getter or setter method.
+    #[arg(long, verbatim_doc_comment)]
+    pub name: String,
+
+    /// The key arguments: numbers in decimal, addresses in bech32, strings as text.
+    /// Any of them can also be given as 0x-prefixed hex, already nested-encoded.
+    #[arg(verbatim_doc_comment)]
+    pub args: Vec<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ConvertStorageArgs {
+    /// The path to the contract ABI JSON file, generated with `sc-meta abi --storage`.
+    #[arg(long, verbatim_doc_comment)]
+    pub abi: String,
+
+    /// The path to the storage dump, as returned by the gateway `/address/<address>/keys` route.
+    /// A plain JSON object of hex keys to hex values is also accepted.
+    #[arg(long, verbatim_doc_comment)]
+    pub keys: String,
+}
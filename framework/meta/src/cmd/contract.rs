@@ -14,7 +14,15 @@ pub fn cli_main<AbiObj: ContractAbiProvider>() {
     let cli_args = ContractCliArgs::parse();
     let mut meta_config_opt = process_original_abi::<AbiObj>(&cli_args);
     match cli_args.command {
-        ContractCliAction::Abi => {},
+        ContractCliAction::Abi(abi_args) => {
+            if abi_args.storage {
+                meta_config_opt.write_abi_with_storage();
+            }
+            if abi_args.json_schema {
+                meta_config_opt.write_json_schema();
+            }
+        },
+        ContractCliAction::AbiSnapshot => meta_config_opt.write_abi_snapshots(),
         ContractCliAction::Build(build_args) => meta_config_opt.build(build_args),
         ContractCliAction::BuildDbg(build_args) => {
             meta_config_opt.build(build_args.into_build_args())
@@ -22,6 +30,7 @@ pub fn cli_main<AbiObj: ContractAbiProvider>() {
         ContractCliAction::Twiggy(build_args) => {
             meta_config_opt.build(build_args.into_build_args())
         },
+        ContractCliAction::Check => meta_config_opt.check(),
         ContractCliAction::Clean => meta_config_opt.clean(),
         ContractCliAction::Update => meta_config_opt.update(),
         ContractCliAction::GenerateSnippets(gs_args) => {
@@ -1,4 +1,5 @@
 pub mod snippet_crate_gen;
+pub mod snippet_event_decoder_gen;
 pub mod snippet_gen_common;
 pub mod snippet_gen_main;
 pub mod snippet_sc_functions_gen;
@@ -0,0 +1,182 @@
+use std::{fs::File, io::Write};
+
+use multiversx_sc::abi::{ContractAbi, EventAbi};
+
+use super::{snippet_gen_common::write_newline, snippet_type_map::map_abi_type_to_rust_type};
+
+/// Multi-values can span several topics, so they cannot be decoded one topic at a time.
+const MULTI_VALUE_TYPE_PREFIXES: &[&str] = &["variadic<", "optional<", "multi<"];
+
+pub(crate) fn write_event_decoders(file: &mut File, abi: &ContractAbi) {
+    if abi.events.is_empty() {
+        return;
+    }
+
+    write_decode_event_value_fn(file);
+    for event_abi in &abi.events {
+        write_event_decoder(file, event_abi);
+    }
+}
+
+fn write_decode_event_value_fn(file: &mut File) {
+    writeln!(
+        file,
+        "fn decode_event_value<T: multiversx_sc_snippets::multiversx_sc::codec::TopDecode>(
+    bytes: &[u8],
+) -> Option<T> {{
+    T::top_decode(bytes).ok()
+}}"
+    )
+    .unwrap();
+
+    write_newline(file);
+}
+
+fn write_event_decoder(file: &mut File, event_abi: &EventAbi) {
+    if let Some(input) = event_abi.inputs.iter().find(|input| {
+        MULTI_VALUE_TYPE_PREFIXES
+            .iter()
+            .any(|prefix| input.type_name.starts_with(prefix))
+    }) {
+        writeln!(
+            file,
+            "// no decoder for event \"{}\", its argument `{}` is a multi-value",
+            event_abi.identifier, input.arg_name
+        )
+        .unwrap();
+        write_newline(file);
+        return;
+    }
+
+    let struct_name = event_struct_name(event_abi.identifier);
+    writeln!(file, "#[derive(Debug)]\npub struct {struct_name} {{").unwrap();
+    for input in &event_abi.inputs {
+        let rust_type = map_abi_type_to_rust_type(input.type_name.clone());
+        writeln!(
+            file,
+            "    pub {}: {},",
+            input.arg_name,
+            rust_type.get_type_name()
+        )
+        .unwrap();
+    }
+    writeln!(file, "}}").unwrap();
+    write_newline(file);
+
+    writeln!(
+        file,
+        r#"impl {struct_name} {{
+    pub const IDENTIFIER: &'static [u8] = b"{}";
+
+    pub fn decode(log: &Log) -> Option<Self> {{
+        let mut topics = log.topics.iter().map(|topic| topic.value.as_slice());
+        if topics.next()? != Self::IDENTIFIER {{
+            return None;
+        }}
+
+        Some({struct_name} {{"#,
+        event_abi.identifier,
+    )
+    .unwrap();
+
+    // topics come first, in declaration order, the data field is last
+    for input in event_abi.inputs.iter().filter(|input| input.indexed) {
+        writeln!(
+            file,
+            "            {}: decode_event_value(topics.next()?)?,",
+            input.arg_name
+        )
+        .unwrap();
+    }
+    for input in event_abi.inputs.iter().filter(|input| !input.indexed) {
+        writeln!(
+            file,
+            "            {}: decode_event_value(log.data.value.as_slice())?,",
+            input.arg_name
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        file,
+        "        }})
+    }}
+}}"
+    )
+    .unwrap();
+
+    write_newline(file);
+}
+
+/// `"event_a"` -> `EventAEvent`, `"proposalCreated"` -> `ProposalCreatedEvent`.
+fn event_struct_name(identifier: &str) -> String {
+    let mut result = String::new();
+    let mut capitalize_next = true;
+    for c in identifier.chars() {
+        if !c.is_ascii_alphanumeric() {
+            capitalize_next = true;
+            continue;
+        }
+
+        if capitalize_next {
+            result.push(c.to_ascii_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result.push_str("Event");
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Seek};
+
+    use multiversx_sc::abi::{ContractAbi, EventAbi, EventInputAbi};
+
+    use super::{event_struct_name, write_event_decoders};
+
+    /// Compiled and run against a real emitted event by the basic-features interactor tests.
+    const EVENT_C_DECODER: &str = include_str!(
+        "../../../../../../contracts/feature-tests/basic-features/interact/tests/event_decoders/event_c.rs"
+    );
+
+    fn event_input(arg_name: &'static str, type_name: &str, indexed: bool) -> EventInputAbi {
+        EventInputAbi {
+            arg_name,
+            type_name: type_name.into(),
+            indexed,
+        }
+    }
+
+    #[test]
+    fn test_event_decoder_output() {
+        let mut abi = ContractAbi::default();
+        abi.events.push(EventAbi {
+            docs: &[],
+            identifier: "event_c",
+            inputs: vec![
+                event_input("caller", "Address", true),
+                event_input("egld_value", "BigUint", true),
+                event_input("esdt_transfers", "List<EsdtTokenPayment>", true),
+                event_input("id", "u32", true),
+                event_input("data", "bytes", false),
+            ],
+        });
+
+        let mut file = tempfile::tempfile().unwrap();
+        write_event_decoders(&mut file, &abi);
+        let mut output = String::new();
+        file.rewind().unwrap();
+        file.read_to_string(&mut output).unwrap();
+        assert_eq!(output, EVENT_C_DECODER);
+    }
+
+    #[test]
+    fn test_event_struct_name() {
+        assert_eq!(event_struct_name("event_a"), "EventAEvent");
+        assert_eq!(event_struct_name("proposalCreated"), "ProposalCreatedEvent");
+        assert_eq!(event_struct_name("token-issued"), "TokenIssuedEvent");
+    }
+}
@@ -10,6 +10,7 @@ use super::{
         create_and_get_lib_file, create_snippets_cargo_toml, create_snippets_folder,
         create_snippets_gitignore, create_src_folder,
     },
+    snippet_event_decoder_gen::write_event_decoders,
     snippet_sc_functions_gen::write_state_struct_impl,
     snippet_template_gen::{
         write_contract_type_alias, write_snippet_constants, write_snippet_imports,
@@ -59,4 +60,5 @@ fn write_snippets_to_file(
     write_snippet_main_function(&mut file, abi);
     write_state_struct_declaration(&mut file);
     write_state_struct_impl(&mut file, abi, wasm_output_file_path_expr);
+    write_event_decoders(&mut file, abi);
 }
@@ -38,7 +38,9 @@ pub(crate) fn write_state_struct_impl(
     )
     .unwrap();
 
-    write_deploy_method_impl(file, &abi.constructors[0]);
+    if let Some(init_abi) = abi.constructors.first() {
+        write_deploy_method_impl(file, init_abi);
+    }
 
     for endpoint_abi in &abi.endpoints {
         write_endpoint_impl(file, endpoint_abi);
@@ -98,15 +100,19 @@ fn write_method_declaration(file: &mut File, endpoint_name: &str) {
     writeln!(file, "    async fn {endpoint_name}(&mut self) {{").unwrap();
 }
 
+/// Endpoints accepting several tokens, EGLD among them, get called with EGLD,
+/// the others with the first accepted token, if it is a specific one.
+fn is_egld_payment(accepted_tokens: &[&str]) -> bool {
+    accepted_tokens.contains(&"EGLD")
+}
+
 fn write_payments_declaration(file: &mut File, accepted_tokens: &[&str]) {
     if accepted_tokens.is_empty() {
         return;
     }
 
-    // only handle EGLD and "any" case, as they're the most common
     let biguint_default = map_abi_type_to_rust_type("BigUint".to_string());
-    let first_accepted = accepted_tokens[0];
-    if first_accepted == "EGLD" {
+    if is_egld_payment(accepted_tokens) {
         writeln!(
             file,
             "        let egld_amount = {};",
@@ -114,9 +120,13 @@ fn write_payments_declaration(file: &mut File, accepted_tokens: &[&str]) {
         )
         .unwrap();
     } else {
+        let token_id = match accepted_tokens[0] {
+            "*" => "",
+            token_id => token_id,
+        };
         writeln!(
             file,
-            "        let token_id = b\"\";
+            "        let token_id = b\"{token_id}\";
         let token_nonce = 0u64;
         let token_amount = {};",
             biguint_default.get_default_value_expr()
@@ -160,7 +170,7 @@ fn endpoint_args_when_called(inputs: &[InputAbi]) -> String {
 fn write_contract_call(file: &mut File, endpoint_abi: &EndpointAbi) {
     let payment_snippet = if endpoint_abi.payable_in_tokens.is_empty() {
         ""
-    } else if endpoint_abi.payable_in_tokens[0] == "EGLD" {
+    } else if is_egld_payment(endpoint_abi.payable_in_tokens) {
         "\n                    .egld_value(egld_amount)"
     } else {
         "\n                    .esdt_transfer(token_id.to_vec(), token_nonce, token_amount)"
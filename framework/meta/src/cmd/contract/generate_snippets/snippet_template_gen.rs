@@ -15,7 +15,7 @@ use {contract_crate_name}::*;
 use multiversx_sc_snippets::{{
     env_logger,
     erdrs::wallet::Wallet,
-    multiversx_sc::{{codec::multi_types::*, types::*}},
+    multiversx_sc::{{codec::multi_types::*, esdt::EsdtIssueCost, types::*}},
     multiversx_sc_scenario::{{
         api::StaticApi,
         bech32,
@@ -42,7 +42,7 @@ const SC_ADDRESS: &str = \"\";
 const SYSTEM_SC_BECH32: &str = \"erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u\";
 const DEFAULT_ADDRESS_EXPR: &str =
     \"0x0000000000000000000000000000000000000000000000000000000000000000\";
-const TOKEN_ISSUE_COST: u64 = 50_000_000_000_000_000;"
+const TOKEN_ISSUE_COST: u64 = EsdtIssueCost::Devnet.egld_amount();"
     )
     .unwrap();
 
@@ -74,8 +74,10 @@ async fn main() {{
     )
     .unwrap();
 
-    // all contracts have a deploy snippet
-    writeln!(file, r#"        "deploy" => state.deploy().await,"#).unwrap();
+    // the constructor is optional, so only contracts that have one get a deploy snippet
+    if !abi.constructors.is_empty() {
+        writeln!(file, r#"        "deploy" => state.deploy().await,"#).unwrap();
+    }
 
     for endpoint in &abi.endpoints {
         writeln!(
@@ -3,12 +3,27 @@ use std::{
     io::Write,
 };
 
-use crate::abi_json::{serialize_abi_to_json, ContractAbiJson};
+use crate::abi_json::{
+    abi_snapshot_json, contract_json_schema, serialize_abi_to_json, serialize_json_schema,
+    ContractAbiJson,
+};
 
 use super::{meta_config::MetaConfig, output_contract::OutputContract};
 
-fn write_contract_abi(output_contract: &OutputContract, git_version: &str, output_path: &str) {
-    let mut abi_json = ContractAbiJson::from(&output_contract.abi);
+/// The snapshots are saved in the contract crate, to be committed, unlike the output folder.
+const ABI_SNAPSHOT_RELATIVE_PATH: &str = "..";
+
+fn write_contract_abi(
+    output_contract: &OutputContract,
+    git_version: &str,
+    output_path: &str,
+    with_storage: bool,
+) {
+    let mut abi_json = if with_storage {
+        ContractAbiJson::with_storage(&output_contract.abi)
+    } else {
+        ContractAbiJson::from(&output_contract.abi)
+    };
     if let Some(build_info) = &mut abi_json.build_info {
         build_info.contract_crate.git_version = git_version.to_string();
     }
@@ -19,8 +34,28 @@ fn write_contract_abi(output_contract: &OutputContract, git_version: &str, outpu
     write!(abi_file, "{abi_string}").unwrap();
 }
 
+fn write_contract_json_schema(output_contract: &OutputContract, output_path: &str) {
+    let schema_string = serialize_json_schema(&contract_json_schema(&output_contract.abi));
+
+    let schema_file_path = format!(
+        "{output_path}/{}",
+        output_contract.json_schema_output_name()
+    );
+    let mut schema_file = File::create(schema_file_path).unwrap();
+    write!(schema_file, "{schema_string}").unwrap();
+}
+
 impl MetaConfig {
     pub fn write_abi(&self) {
+        self.write_abi_files(false);
+    }
+
+    /// Rewrites the ABI files, with the storage section included.
+    pub fn write_abi_with_storage(&self) {
+        self.write_abi_files(true);
+    }
+
+    fn write_abi_files(&self, with_storage: bool) {
         create_dir_all(&self.output_dir).unwrap();
         let git_version = self.git_describe();
         for output_contract in &self.output_contracts.contracts {
@@ -28,7 +63,27 @@ impl MetaConfig {
                 output_contract,
                 git_version.as_str(),
                 self.output_dir.as_str(),
+                with_storage,
+            );
+        }
+    }
+
+    pub fn write_json_schema(&self) {
+        create_dir_all(&self.output_dir).unwrap();
+        for output_contract in &self.output_contracts.contracts {
+            write_contract_json_schema(output_contract, self.output_dir.as_str());
+        }
+    }
+
+    /// Saves the ABI of each output contract, as reference for `abi_json::check_abi_snapshot`.
+    pub fn write_abi_snapshots(&self) {
+        for output_contract in &self.output_contracts.contracts {
+            let snapshot_path = format!(
+                "{ABI_SNAPSHOT_RELATIVE_PATH}/{}",
+                output_contract.abi_snapshot_name()
             );
+            let mut snapshot_file = File::create(snapshot_path).unwrap();
+            write!(snapshot_file, "{}", abi_snapshot_json(&output_contract.abi)).unwrap();
         }
     }
 
@@ -2,7 +2,10 @@ use std::fs;
 
 use multiversx_sc::abi::ContractAbi;
 
-use crate::{cli_args::BuildArgs, tools::post_build::check_tools_installed, CargoTomlContents};
+use crate::{
+    cli_args::BuildArgs, print_util::print_check_warnings,
+    tools::post_build::check_tools_installed, CargoTomlContents,
+};
 
 use super::output_contract::{OutputContract, OutputContractGlobalConfig};
 
@@ -96,6 +99,16 @@ impl MetaConfig {
         }
     }
 
+    /// Prints the warnings of all output contracts. They do not stop the build.
+    pub fn check(&self) {
+        for output_contract in &self.output_contracts.contracts {
+            print_check_warnings(
+                &output_contract.contract_name,
+                &output_contract.check_warnings(),
+            );
+        }
+    }
+
     /// Cleans the wasm crates and all other outputs.
     pub fn clean(&self) {
         self.clean_contract_crates();
@@ -13,5 +13,5 @@ pub use multi_contract_serde::*;
 pub use oc_builder::*;
 pub use oc_config::OutputContract;
 pub use oc_global_config::OutputContractGlobalConfig;
-pub use oc_settings::OutputContractSettings;
+pub use oc_settings::{ContractAllocator, OutputContractSettings};
 pub use wasm_build::*;
@@ -36,6 +36,18 @@ pub struct OutputContractSerde {
     #[serde(rename = "panic-message")]
     pub panic_message: Option<bool>,
 
+    #[serde(default)]
+    #[serde(rename = "debug-variant")]
+    pub debug_variant: Option<bool>,
+
+    #[serde(default)]
+    #[serde(rename = "debug-variant-symbols")]
+    pub debug_variant_symbols: Option<bool>,
+
+    #[serde(default)]
+    #[serde(rename = "test-endpoints")]
+    pub test_endpoints: Option<bool>,
+
     #[serde(default)]
     pub ei: Option<String>,
 
@@ -12,7 +12,7 @@ use super::{
 };
 
 /// Temporary structure, to help create instances of `OutputContract`. Not publicly exposed.
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct OutputContractBuilder {
     pub contract_id: String,
     pub explicit_name: String,
@@ -22,6 +22,8 @@ struct OutputContractBuilder {
     pub collected_endpoints: Vec<EndpointAbi>,
     endpoint_names: HashSet<String>, // help keep endpoints unique
     pub settings: OutputContractSettings,
+    pub debug_variant: bool,
+    pub debug_variant_symbols: bool,
 }
 
 impl OutputContractBuilder {
@@ -54,12 +56,16 @@ impl OutputContractBuilder {
                 settings: OutputContractSettings {
                     external_view: cms.external_view.unwrap_or_default(),
                     panic_message: cms.panic_message.unwrap_or_default(),
+                    wasm_symbols: false,
+                    test_endpoints: cms.test_endpoints.unwrap_or_default(),
                     check_ei: parse_check_ei(&cms.ei),
                     allocator: parse_allocator(&cms.allocator),
                     stack_size: parse_stack_size(&cms.stack_size),
                     features: cms.features.clone(),
                     kill_legacy_callback: cms.kill_legacy_callback,
                 },
+                debug_variant: cms.debug_variant.unwrap_or_default(),
+                debug_variant_symbols: cms.debug_variant_symbols.unwrap_or_default(),
                 ..Default::default()
             },
         )
@@ -73,7 +79,22 @@ impl OutputContractBuilder {
         }
     }
 
+    /// Same endpoints and settings, but with panic messages, and optionally with function names.
+    fn debug_variant(&self) -> Self {
+        let mut variant = self.clone();
+        variant.contract_id = format!("{}-debug", self.contract_id);
+        variant.explicit_name = format!("{}-debug", self.wasm_name());
+        variant.settings.panic_message = true;
+        variant.settings.wasm_symbols = self.debug_variant_symbols;
+        variant.debug_variant = false;
+        variant
+    }
+
+    /// Test-only endpoints are skipped, unless the contract explicitly enables them.
     fn collect_endpoint(&mut self, endpoint_abi: &EndpointAbi) {
+        if endpoint_abi.test_only && !self.settings.test_endpoints {
+            return;
+        }
         if !self.endpoint_names.contains(endpoint_abi.name) {
             self.endpoint_names.insert(endpoint_abi.name.to_string());
             self.collected_endpoints.push(endpoint_abi.clone());
@@ -148,13 +169,33 @@ fn collect_add_endpoints(
     }
 }
 
+/// Debug variants are built alongside the contracts that request them, for use on devnet,
+/// where diagnosability matters more than size.
+fn add_debug_variants(contract_builders: &mut HashMap<String, OutputContractBuilder>) {
+    let debug_variants: Vec<OutputContractBuilder> = contract_builders
+        .values()
+        .filter(|builder| builder.debug_variant)
+        .map(OutputContractBuilder::debug_variant)
+        .collect();
+    for debug_variant in debug_variants {
+        assert!(
+            !contract_builders.contains_key(&debug_variant.contract_id),
+            "debug variant {} conflicts with an existing output contract",
+            debug_variant.contract_id
+        );
+        contract_builders.insert(debug_variant.contract_id.clone(), debug_variant);
+    }
+}
+
 fn build_contract_abi(builder: OutputContractBuilder, original_abi: &ContractAbi) -> ContractAbi {
     let mut constructors = Vec::new();
+    let mut upgrade_constructors = Vec::new();
     let mut endpoints = Vec::new();
     let mut promise_callbacks = Vec::new();
     for endpoint_abi in builder.collected_endpoints {
         match endpoint_abi.endpoint_type {
             multiversx_sc::abi::EndpointTypeAbi::Init => constructors.push(endpoint_abi),
+            multiversx_sc::abi::EndpointTypeAbi::Upgrade => upgrade_constructors.push(endpoint_abi),
             multiversx_sc::abi::EndpointTypeAbi::Endpoint => endpoints.push(endpoint_abi),
             multiversx_sc::abi::EndpointTypeAbi::PromisesCallback => {
                 promise_callbacks.push(endpoint_abi)
@@ -169,9 +210,11 @@ fn build_contract_abi(builder: OutputContractBuilder, original_abi: &ContractAbi
         docs: original_abi.docs,
         name: original_abi.name,
         constructors,
+        upgrade_constructors,
         endpoints,
         promise_callbacks,
         events: original_abi.events.clone(),
+        storage_keys: original_abi.storage_keys.clone(),
         has_callback,
         type_descriptions: original_abi.type_descriptions.clone(),
     }
@@ -226,10 +269,26 @@ fn validate_output_contracts(contracts: &[OutputContract]) {
                 contract.settings.features.is_empty(),
                 "features not supported for main contract"
             );
+            assert!(
+                !contract.settings.test_endpoints,
+                "test endpoints not supported for main contract"
+            );
         }
     }
 }
 
+fn test_endpoint_names(original_abi: &ContractAbi) -> Vec<String> {
+    original_abi
+        .endpoints
+        .iter()
+        .filter(|endpoint_abi| endpoint_abi.test_only)
+        .flat_map(|endpoint_abi| {
+            core::iter::once(endpoint_abi.name).chain(endpoint_abi.aliases.iter().copied())
+        })
+        .map(str::to_string)
+        .collect()
+}
+
 impl OutputContractGlobalConfig {
     /// Assembles an `OutputContractConfig` from a raw config object that was loaded via Serde.
     ///
@@ -244,6 +303,7 @@ impl OutputContractGlobalConfig {
         collect_labelled_endpoints(&mut contract_builders, original_abi);
         collect_add_endpoints(&mut contract_builders, original_abi);
         process_labels_for_contracts(&mut contract_builders, &config.labels_for_contracts);
+        add_debug_variants(&mut contract_builders);
         let mut contracts: Vec<OutputContract> = contract_builders
             .into_values()
             .map(|builder| build_contract(builder, original_abi))
@@ -253,15 +313,18 @@ impl OutputContractGlobalConfig {
         OutputContractGlobalConfig {
             default_contract_config_name: config.settings.main.clone().unwrap_or_default(),
             contracts,
+            test_endpoint_names: test_endpoint_names(original_abi),
         }
     }
 
     /// Provides the config for the cases where no `multicontract.toml` file is available.
     ///
-    /// The default configuration contains a single main contract, with all endpoints.
+    /// The default configuration contains a single main contract, with all endpoints, except the test-only ones.
     pub fn default_config(original_abi: &ContractAbi) -> Self {
         let default_contract_config_name = original_abi.build_info.contract_crate.name.to_string();
         let wasm_crate_name = default_wasm_crate_name(&default_contract_config_name);
+        let mut abi = original_abi.clone();
+        abi.endpoints.retain(|endpoint_abi| !endpoint_abi.test_only);
         OutputContractGlobalConfig {
             default_contract_config_name: default_contract_config_name.clone(),
             contracts: vec![OutputContract {
@@ -270,8 +333,9 @@ impl OutputContractGlobalConfig {
                 contract_id: default_contract_config_name.clone(),
                 contract_name: default_contract_config_name,
                 wasm_crate_name,
-                abi: original_abi.clone(),
+                abi,
             }],
+            test_endpoint_names: test_endpoint_names(original_abi),
         }
     }
 
@@ -92,6 +92,14 @@ impl OutputContract {
         format!("{}.abi.json", &self.contract_name)
     }
 
+    pub fn abi_snapshot_name(&self) -> String {
+        format!("{}.abi-snapshot.json", &self.contract_name)
+    }
+
+    pub fn json_schema_output_name(&self) -> String {
+        format!("{}.schema.json", &self.contract_name)
+    }
+
     fn output_name_base(&self, build_args: &BuildArgs) -> String {
         if let Some(wasm_name_override) = &build_args.wasm_name_override {
             wasm_name_override.clone()
@@ -118,6 +126,10 @@ impl OutputContract {
         format!("{}.imports.json", self.output_name_base(build_args))
     }
 
+    pub fn size_report_output_name(&self, build_args: &BuildArgs) -> String {
+        format!("{}.size-report.json", self.output_name_base(build_args))
+    }
+
     pub fn twiggy_top_name(&self, build_args: &BuildArgs) -> String {
         format!("twiggy-top-{}.txt", self.output_name_base(build_args))
     }
@@ -145,11 +157,17 @@ impl OutputContract {
             .collect()
     }
 
-    /// Yields "init" + all endpoint names + "callBack" (if it exists).
+    /// Yields "init" and "upgrade" (if they exist) + all endpoint names + "callBack" (if it exists).
     ///
     /// Should correspond to all wasm exported functions.
     pub fn all_exported_function_names(&self) -> Vec<String> {
-        let mut result = vec!["init".to_string()];
+        let mut result = Vec::new();
+        if !self.abi.constructors.is_empty() {
+            result.push("init".to_string());
+        }
+        if !self.abi.upgrade_constructors.is_empty() {
+            result.push("upgrade".to_string());
+        }
         result.append(&mut self.endpoint_names());
         if self.abi.has_callback {
             result.push("callBack".to_string());
@@ -7,6 +7,9 @@ use super::{oc_validate::validate_output_contract, OutputContract};
 pub struct OutputContractGlobalConfig {
     pub default_contract_config_name: String,
     pub contracts: Vec<OutputContract>,
+
+    /// The names and aliases of the `#[test_endpoint]`s in the original ABI.
+    pub test_endpoint_names: Vec<String>,
 }
 
 impl OutputContractGlobalConfig {
@@ -66,7 +69,7 @@ impl OutputContractGlobalConfig {
 
     pub fn validate_output_contracts(&self) {
         for contract in &self.contracts {
-            validate_output_contract(contract).unwrap_or_else(|err| {
+            validate_output_contract(contract, &self.test_endpoint_names).unwrap_or_else(|err| {
                 panic!("Invalid output contract {}: {err}", contract.contract_name)
             });
         }
@@ -18,6 +18,15 @@ pub struct OutputContractSettings {
     /// so they should only be used for debugging purposes.
     pub panic_message: bool,
 
+    /// Keeps the function names in the resulting WASM binary, even when not building with `--wasm-symbols`.
+    ///
+    /// Only set for debug variants that request it, since it also adds bloat.
+    pub wasm_symbols: bool,
+
+    /// Includes the endpoints marked with `#[test_endpoint]`. Not allowed for the main contract,
+    /// so cheat endpoints can only end up in contracts built for tests.
+    pub test_endpoints: bool,
+
     /// Post-processing check of the VM hooks is based on this.
     pub check_ei: Option<EIVersion>,
 
@@ -38,6 +47,8 @@ impl Default for OutputContractSettings {
         OutputContractSettings {
             external_view: Default::default(),
             panic_message: Default::default(),
+            wasm_symbols: false,
+            test_endpoints: false,
             check_ei: Some(EIVersion::default()),
             allocator: Default::default(),
             stack_size: DEFAULT_STACK_SIZE,
@@ -2,23 +2,88 @@ use multiversx_sc::abi::{ContractAbi, EndpointAbi};
 
 use super::OutputContract;
 
-pub fn validate_output_contract(output_contract: &OutputContract) -> Result<(), String> {
-    check_single_constructor(output_contract)?;
+impl OutputContract {
+    /// Issues that do not prevent building the contract, reported by `sc-meta check`.
+    pub fn check_warnings(&self) -> Vec<String> {
+        self.abi
+            .endpoints
+            .iter()
+            .filter_map(deprecated_endpoint_warning)
+            .collect()
+    }
+}
+
+fn deprecated_endpoint_warning(endpoint_abi: &EndpointAbi) -> Option<String> {
+    let deprecation = endpoint_abi.deprecated.as_ref()?;
+    let mut warning = format!(
+        "Endpoint `{}` is deprecated since {}.",
+        endpoint_abi.name, deprecation.since
+    );
+    if !deprecation.note.is_empty() {
+        warning.push(' ');
+        warning.push_str(deprecation.note);
+    }
+    Some(warning)
+}
+
+pub fn validate_output_contract(
+    output_contract: &OutputContract,
+    test_endpoint_names: &[String],
+) -> Result<(), String> {
+    check_at_most_one_constructor(output_contract)?;
+    check_test_endpoints(output_contract, test_endpoint_names)?;
     validate_contract_var_args(&output_contract.abi)?;
     Ok(())
 }
 
-fn check_single_constructor(output_contract: &OutputContract) -> Result<(), String> {
-    match output_contract.abi.constructors.len() {
-            0 => Err("Missing constructor. Add a method annotated with `#[init]`.".to_string()),
-            1 => Ok(()),
-            _ => Err("More than one contrctructor present. Exactly one method annotated with `#[init]` is required.".to_string()),
+/// The constructor and the upgrade function are both optional,
+/// but each output contract can have at most one of each.
+fn check_at_most_one_constructor(output_contract: &OutputContract) -> Result<(), String> {
+    if output_contract.abi.constructors.len() > 1 {
+        return Err(format!(
+            "More than one constructor present in contract {}. At most one method annotated with `#[init]` is allowed.",
+            output_contract.contract_id
+        ));
+    }
+    if output_contract.abi.upgrade_constructors.len() > 1 {
+        return Err(format!(
+            "More than one upgrade function present in contract {}. At most one method annotated with `#[upgrade]` is allowed.",
+            output_contract.contract_id
+        ));
+    }
+    Ok(())
+}
+
+/// Test-only endpoints must never end up in contracts that do not explicitly enable them, e.g. in the mainnet contract.
+///
+/// Checked against the functions listed in the generated `endpoints!` macro, i.e. what the wasm will actually export,
+/// including aliases.
+fn check_test_endpoints(
+    output_contract: &OutputContract,
+    test_endpoint_names: &[String],
+) -> Result<(), String> {
+    if output_contract.settings.test_endpoints {
+        return Ok(());
+    }
+    for (exported_name, _) in output_contract.endpoints_macro_entries() {
+        if test_endpoint_names.iter().any(|name| name == exported_name) {
+            return Err(format!(
+                "Test endpoint `{exported_name}` exported by contract {}, which does not enable `test-endpoints`.",
+                output_contract.contract_id
+            ));
         }
+    }
+    Ok(())
 }
 
 /// Note: promise callbacks not included, since they have `#[call_value]` arguments, that are currently not modelled.
 fn validate_contract_var_args(abi: &ContractAbi) -> Result<(), String> {
-    for endpoint_abi in abi.constructors.iter().chain(abi.endpoints.iter()) {
+    for endpoint_abi in abi
+        .constructors
+        .iter()
+        .chain(abi.upgrade_constructors.iter())
+        .chain(abi.endpoints.iter())
+    {
         validate_endpoint_var_args(endpoint_abi)?;
     }
     Ok(())
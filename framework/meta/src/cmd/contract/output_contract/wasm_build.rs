@@ -1,12 +1,16 @@
 use std::{ffi::OsStr, fs, process::Command};
 
+use multiversx_sc::api::{TARGET_EI_CFG, TARGET_EI_VERSION_ENV_VAR};
+
 use super::OutputContract;
+
 use crate::{
     abi_json::ContractAbiJson,
     cli_args::BuildArgs,
     ei::EIVersion,
     mxsc_file_json::{save_mxsc_file_json, MxscFileJson},
     print_util::*,
+    size_report::{load_size_report, save_size_report, WasmSizeReport},
     tools::post_build,
 };
 
@@ -38,6 +42,9 @@ impl OutputContract {
         if let Some(target_dir_wasm) = &build_args.target_dir_wasm {
             command.args(["--target-dir", target_dir_wasm]);
         }
+        if let Some(ei) = self.target_ei(build_args) {
+            command.env(TARGET_EI_VERSION_ENV_VAR, ei.name());
+        }
         let rustflags = self.compose_rustflags(build_args);
         if !rustflags.is_empty() {
             command.env("RUSTFLAGS", rustflags);
@@ -48,7 +55,7 @@ impl OutputContract {
     fn compose_rustflags(&self, build_args: &BuildArgs) -> Rustflags {
         let mut rustflags = Rustflags::default();
 
-        if !build_args.wasm_symbols {
+        if !build_args.wasm_symbols && !self.settings.wasm_symbols {
             rustflags.push_flag("-C link-arg=-s");
         }
 
@@ -57,6 +64,12 @@ impl OutputContract {
             self.settings.stack_size
         ));
 
+        if build_args.target_vm.is_some() {
+            if let Some(ei) = self.target_ei(build_args) {
+                rustflags.push_flag(&format!("--cfg {TARGET_EI_CFG}=\"{}\"", ei.name()));
+            }
+        }
+
         if build_args.emit_mir {
             rustflags.push_flag("--emit=mir");
         }
@@ -67,12 +80,23 @@ impl OutputContract {
         rustflags
     }
 
+    /// The EI version to build for: the one of the target VM, if given, otherwise the one in the contract settings.
+    fn target_ei(&self, build_args: &BuildArgs) -> Option<EIVersion> {
+        if let Some(target_vm) = &build_args.target_vm {
+            let ei = EIVersion::from_vm_version(target_vm).unwrap_or_else(|err| panic!("{err}"));
+            Some(ei)
+        } else {
+            self.settings.check_ei
+        }
+    }
+
     fn finalize_build(&self, build_args: &BuildArgs, output_path: &str) {
         self.copy_contracts_to_output(build_args, output_path);
         self.run_wasm_opt(build_args, output_path);
         self.run_wasm2wat(build_args, output_path);
         self.extract_imports(build_args, output_path);
         self.run_twiggy(build_args, output_path);
+        self.write_size_report(build_args, output_path);
         self.pack_mxsc_file(build_args, output_path);
     }
 
@@ -108,7 +132,24 @@ impl OutputContract {
 
         let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
         print_call_wasm_opt(&output_wasm_path);
-        post_build::run_wasm_opt(output_wasm_path.as_str());
+        post_build::run_wasm_opt(output_wasm_path.as_str(), self.settings.wasm_symbols);
+    }
+
+    fn write_size_report(&self, build_args: &BuildArgs, output_path: &str) {
+        if !build_args.size_report {
+            return;
+        }
+
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+        let output_size_report_path =
+            format!("{output_path}/{}", self.size_report_output_name(build_args));
+        print_size_report(&output_size_report_path);
+        let compiled_bytes = fs::read(output_wasm_path).expect("failed to open compiled contract");
+        let report = WasmSizeReport::from_wasm_bytes(&compiled_bytes);
+        if let Some(previous_report) = load_size_report(&output_size_report_path) {
+            print_size_comparison(&report.comparison_lines(&previous_report));
+        }
+        save_size_report(&report, &output_size_report_path);
     }
 
     fn run_wasm2wat(&self, build_args: &BuildArgs, output_path: &str) {
@@ -137,7 +178,7 @@ impl OutputContract {
         let result = post_build::run_wasm_objdump(output_wasm_path.as_str());
         let import_names = post_build::parse_imports(result.as_str());
         write_imports_output(output_imports_json_path.as_str(), import_names.as_slice());
-        validate_ei(&import_names, &self.settings.check_ei);
+        validate_ei(&import_names, &self.target_ei(build_args));
     }
 }
 
@@ -149,16 +190,20 @@ fn write_imports_output(dest_path: &str, import_names: &[String]) {
 fn validate_ei(import_names: &[String], check_ei: &Option<EIVersion>) {
     if let Some(ei) = check_ei {
         print_check_ei(ei.name());
-        let mut num_errors = 0;
-        for import_name in import_names {
-            if !ei.contains_vm_hook(import_name) {
-                print_invalid_vm_hook(import_name, ei.name());
-                num_errors += 1;
-            }
-        }
-        if num_errors == 0 {
-            print_check_ei_ok();
+        let unsupported = ei.unsupported_vm_hooks(import_names);
+        for import_name in &unsupported {
+            print_invalid_vm_hook(
+                import_name,
+                ei.name(),
+                EIVersion::min_version_for_vm_hook(import_name).map(|min_ei| min_ei.name()),
+            );
         }
+        assert!(
+            unsupported.is_empty(),
+            "contract uses VM hooks not available on EI version {}",
+            ei.name()
+        );
+        print_check_ei_ok();
     } else {
         print_ignore_ei_check();
     }
@@ -1,4 +1,4 @@
-use multiversx_sc::{abi::EndpointAbi, external_view_contract::EXTERNAL_VIEW_CONSTRUCTOR_FLAG};
+use multiversx_sc::external_view_contract::EXTERNAL_VIEW_CONSTRUCTOR_FLAG;
 use rustc_version::Version;
 use std::{
     fs::{self, File},
@@ -17,7 +17,6 @@ const PREFIX_AUTO_GENERATED: &str =
 ////////////////////////////////////////////////////
 ";
 
-const NUM_INIT: usize = 1;
 const NUM_ASYNC_CB: usize = 1;
 
 const VER_1_71: &str = "1.71.0-nightly";
@@ -96,7 +95,7 @@ impl OutputContract {
             self.endpoint_macro_name(),
             wasm_lib_file,
             &contract_module_name,
-            self.abi.iter_all_exports(),
+            &self.endpoints_macro_entries(),
         );
 
         write_async_callback_macro(wasm_lib_file, self.abi.has_callback, &contract_module_name);
@@ -110,8 +109,19 @@ fn write_stat_comment(wasm_lib_file: &mut File, label: &str, number: usize) {
 impl OutputContract {
     /// Writing some nicely formatted comments breaking down all exported functions.
     fn write_stat_comments(&self, wasm_lib_file: &mut File) {
-        write_stat_comment(wasm_lib_file, "Init:", NUM_INIT);
+        write_stat_comment(wasm_lib_file, "Init:", self.abi.constructors.len());
+        if !self.abi.upgrade_constructors.is_empty() {
+            write_stat_comment(
+                wasm_lib_file,
+                "Upgrade:",
+                self.abi.upgrade_constructors.len(),
+            );
+        }
         write_stat_comment(wasm_lib_file, "Endpoints:", self.abi.endpoints.len());
+        let num_aliases = self.num_endpoint_aliases();
+        if num_aliases > 0 {
+            write_stat_comment(wasm_lib_file, "Endpoint aliases:", num_aliases);
+        }
         if self.abi.has_callback {
             write_stat_comment(wasm_lib_file, "Async Callback:", NUM_ASYNC_CB);
         } else {
@@ -124,11 +134,24 @@ impl OutputContract {
                 self.abi.promise_callbacks.len(),
             );
         }
-        let total =
-            self.abi.endpoints.len() + NUM_INIT + NUM_ASYNC_CB + self.abi.promise_callbacks.len();
+        let total = self.abi.constructors.len()
+            + self.abi.upgrade_constructors.len()
+            + self.abi.endpoints.len()
+            + num_aliases
+            + NUM_ASYNC_CB
+            + self.abi.promise_callbacks.len();
 
         write_stat_comment(wasm_lib_file, "Total number of exported functions:", total);
     }
+
+    /// Aliases are exported as separate functions, calling the same endpoint.
+    fn num_endpoint_aliases(&self) -> usize {
+        self.abi
+            .endpoints
+            .iter()
+            .map(|endpoint| endpoint.aliases.len())
+            .sum()
+    }
 }
 
 fn select_features() -> &'static str {
@@ -146,26 +169,37 @@ fn write_prefix(wasm_lib_file: &mut File) {
     writeln!(wasm_lib_file, "{}", select_features()).unwrap();
 }
 
-fn write_endpoints_macro<'a, I>(
+impl OutputContract {
+    /// The exported function names, paired with the contract methods they call, as listed in the `endpoints!` macro.
+    pub(super) fn endpoints_macro_entries(&self) -> Vec<(&str, &str)> {
+        let mut entries = Vec::new();
+        for endpoint in self.abi.iter_all_exports() {
+            if endpoint.rust_method_name == EXTERNAL_VIEW_CONSTRUCTOR_FLAG {
+                continue;
+            }
+            entries.push((endpoint.name, endpoint.rust_method_name));
+            for alias in endpoint.aliases {
+                entries.push((*alias, endpoint.rust_method_name));
+            }
+        }
+        entries
+    }
+}
+
+fn write_endpoints_macro(
     full_macro_name: &str,
     wasm_lib_file: &mut File,
     contract_module_name: &str,
-    endpoint_iter: I,
-) where
-    I: Iterator<Item = &'a EndpointAbi>,
-{
+    entries: &[(&str, &str)],
+) {
     writeln!(wasm_lib_file).unwrap();
     writeln!(wasm_lib_file, "{full_macro_name} {{").unwrap();
     writeln!(wasm_lib_file, "    {contract_module_name}").unwrap();
     writeln!(wasm_lib_file, "    (").unwrap();
-    for endpoint in endpoint_iter {
-        if endpoint.rust_method_name == EXTERNAL_VIEW_CONSTRUCTOR_FLAG {
-            continue;
-        }
+    for (exported_name, rust_method_name) in entries {
         writeln!(
             wasm_lib_file,
-            "        {} => {}",
-            endpoint.name, endpoint.rust_method_name
+            "        {exported_name} => {rust_method_name}"
         )
         .unwrap();
     }
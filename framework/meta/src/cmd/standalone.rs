@@ -1,4 +1,5 @@
 mod all;
+pub mod convert;
 mod info;
 mod local_deps;
 pub mod scen_test_gen;
@@ -10,6 +11,7 @@ use crate::{
 };
 use all::call_all_meta;
 use clap::Parser;
+use convert::convert_tool;
 use info::call_info;
 use local_deps::local_deps;
 use scen_test_gen::test_gen_tool;
@@ -36,6 +38,9 @@ pub async fn cli_main_standalone() {
         Some(StandaloneCliAction::TestGen(args)) => {
             test_gen_tool(args);
         },
+        Some(StandaloneCliAction::Convert(args)) => {
+            convert_tool(args);
+        },
         None => {},
     }
 }
@@ -0,0 +1,41 @@
+mod convert_amount;
+mod convert_encoding;
+mod convert_storage;
+mod convert_storage_key;
+mod convert_topics;
+
+use std::fs;
+
+use crate::{
+    abi_json::ContractAbiJson,
+    cli_args::{ConvertArgs, ConvertCliAction},
+};
+
+pub fn convert_tool(args: &ConvertArgs) {
+    let result = match &args.command {
+        ConvertCliAction::Bech32ToHex(args) => bech32_to_hex(&args.value),
+        ConvertCliAction::HexToBech32(args) => hex_to_bech32(&args.value, &args.hrp),
+        ConvertCliAction::Base64ToHex(args) => base64_to_hex(&args.value),
+        ConvertCliAction::HexToBase64(args) => hex_to_base64(&args.value),
+        ConvertCliAction::ToUnits(args) => to_units(&args.value, args.decimals),
+        ConvertCliAction::FromUnits(args) => from_units(&args.value, args.decimals),
+        ConvertCliAction::Topics(args) => convert_topics::decode_topics_tool(args),
+        ConvertCliAction::StorageKey(args) => convert_storage_key::storage_key_tool(args),
+        ConvertCliAction::Storage(args) => convert_storage::decode_storage_tool(args),
+    };
+    println!("{result}");
+}
+
+fn load_abi_json(abi_path: &str) -> ContractAbiJson {
+    let abi_json_string = fs::read_to_string(abi_path)
+        .unwrap_or_else(|err| panic!("failed to read ABI file {abi_path}: {err}"));
+    serde_json::from_str(&abi_json_string)
+        .unwrap_or_else(|err| panic!("failed to parse ABI file {abi_path}: {err}"))
+}
+
+// Good for testing.
+pub use convert_amount::{from_units, to_units};
+pub use convert_encoding::{base64_to_hex, bech32_to_hex, hex_to_base64, hex_to_bech32};
+pub use convert_storage::decode_storage_dump;
+pub use convert_storage_key::storage_key;
+pub use convert_topics::decode_topics;
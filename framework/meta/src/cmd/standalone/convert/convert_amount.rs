@@ -0,0 +1,53 @@
+/// Scales a token amount, e.g. `"1.5"`, to its value in the smallest units,
+/// e.g. `"1500000000000000000"` for 18 decimals.
+pub fn to_units(amount: &str, decimals: usize) -> String {
+    let (integer_part, fractional_part) = amount.split_once('.').unwrap_or((amount, ""));
+    assert!(
+        is_digits(integer_part)
+            && is_digits(fractional_part)
+            && !(integer_part.is_empty() && fractional_part.is_empty()),
+        "invalid token amount `{amount}`"
+    );
+    assert!(
+        fractional_part.len() <= decimals,
+        "token amount `{amount}` has more than {decimals} decimals"
+    );
+
+    let units = format!("{integer_part}{fractional_part:0<decimals$}");
+    trim_leading_zeros(&units).to_string()
+}
+
+/// Scales a value in the smallest units, e.g. `"1500000000000000000"`,
+/// to the token amount, e.g. `"1.5"` for 18 decimals.
+pub fn from_units(units: &str, decimals: usize) -> String {
+    assert!(
+        !units.is_empty() && is_digits(units),
+        "invalid integer value `{units}`"
+    );
+
+    let padded = format!(
+        "{:0>width$}",
+        trim_leading_zeros(units),
+        width = decimals + 1
+    );
+    let (integer_part, fractional_part) = padded.split_at(padded.len() - decimals);
+    let fractional_part = fractional_part.trim_end_matches('0');
+    if fractional_part.is_empty() {
+        integer_part.to_string()
+    } else {
+        format!("{integer_part}.{fractional_part}")
+    }
+}
+
+fn is_digits(s: &str) -> bool {
+    s.chars().all(|c| c.is_ascii_digit())
+}
+
+fn trim_leading_zeros(digits: &str) -> &str {
+    let trimmed = digits.trim_start_matches('0');
+    if trimmed.is_empty() {
+        "0"
+    } else {
+        trimmed
+    }
+}
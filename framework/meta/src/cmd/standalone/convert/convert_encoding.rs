@@ -0,0 +1,55 @@
+use bech32::{FromBase32, ToBase32, Variant};
+
+const ADDRESS_LEN: usize = 32;
+
+pub fn bech32_to_hex(bech32_address: &str) -> String {
+    hex::encode(bech32_to_address(bech32_address))
+}
+
+pub(super) fn bech32_to_address(bech32_address: &str) -> Vec<u8> {
+    let (_, data, _) = bech32::decode(bech32_address)
+        .unwrap_or_else(|err| panic!("invalid bech32 address `{bech32_address}`: {err}"));
+    Vec::<u8>::from_base32(&data)
+        .unwrap_or_else(|err| panic!("invalid bech32 address `{bech32_address}`: {err}"))
+}
+
+/// Accepts the hex with or without the `0x` prefix.
+pub fn hex_to_bech32(hex_address: &str, hrp: &str) -> String {
+    address_to_bech32(&decode_hex(hex_address), hrp)
+}
+
+fn address_to_bech32(address_bytes: &[u8], hrp: &str) -> String {
+    assert!(
+        address_bytes.len() == ADDRESS_LEN,
+        "addresses are {ADDRESS_LEN} bytes long, got {} bytes",
+        address_bytes.len()
+    );
+    bech32::encode(hrp, address_bytes.to_base32(), Variant::Bech32).expect("bech32 encode error")
+}
+
+pub fn base64_to_hex(value: &str) -> String {
+    hex::encode(decode_base64(value))
+}
+
+pub fn hex_to_base64(value: &str) -> String {
+    base64::encode(decode_hex(value))
+}
+
+pub(super) fn decode_hex(value: &str) -> Vec<u8> {
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+    hex::decode(digits).unwrap_or_else(|err| panic!("invalid hex `{value}`: {err}"))
+}
+
+pub(super) fn decode_base64(value: &str) -> Vec<u8> {
+    base64::decode(value).unwrap_or_else(|err| panic!("invalid base64 `{value}`: {err}"))
+}
+
+/// The size of the fixed-width integer types, in bytes.
+pub(super) fn int_size(type_name: &str) -> usize {
+    match type_name {
+        "u8" | "i8" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" | "usize" | "isize" => 4,
+        _ => 8,
+    }
+}
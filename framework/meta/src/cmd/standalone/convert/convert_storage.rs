@@ -0,0 +1,46 @@
+use std::fs;
+
+use super::{convert_encoding::decode_hex, load_abi_json};
+use crate::{abi_decode::StorageDecoder, abi_json::ContractAbiJson, cli_args::ConvertStorageArgs};
+
+pub(super) fn decode_storage_tool(args: &ConvertStorageArgs) -> String {
+    let abi_json = load_abi_json(&args.abi);
+    assert!(
+        !abi_json.storage.is_empty(),
+        "no storage section in ABI file {}, generate it with `sc-meta abi --storage`",
+        args.abi
+    );
+
+    let dump_json = fs::read_to_string(&args.keys)
+        .unwrap_or_else(|err| panic!("failed to read storage dump {}: {err}", args.keys));
+    decode_storage_dump(abi_json, &dump_json)
+}
+
+/// Decodes a storage dump, one line per entry, sorted by key,
+/// e.g. `cooldown(action: "claim") = 100`.
+///
+/// The dump is the response of the gateway `/address/<address>/keys` route,
+/// or just the object of hex keys to hex values in it.
+pub fn decode_storage_dump(abi_json: ContractAbiJson, dump_json: &str) -> String {
+    let dump: serde_json::Value = serde_json::from_str(dump_json)
+        .unwrap_or_else(|err| panic!("failed to parse storage dump: {err}"));
+    let pairs = dump
+        .pointer("/data/pairs")
+        .or_else(|| dump.get("pairs"))
+        .unwrap_or(&dump)
+        .as_object()
+        .expect("storage dump should contain an object of hex keys to hex values");
+
+    let storage = pairs.iter().map(|(key, value)| {
+        let value = value
+            .as_str()
+            .unwrap_or_else(|| panic!("storage value of key {key} should be a hex string"));
+        (decode_hex(key), decode_hex(value))
+    });
+    StorageDecoder::from_abi_json(abi_json)
+        .decode_storage(storage)
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,125 @@
+use multiversx_sc::codec::num_bigint::{BigInt, BigUint, Sign};
+
+use super::{
+    convert_encoding::{bech32_to_address, decode_hex, int_size},
+    load_abi_json,
+};
+use crate::{abi_json::StorageKeyAbiJson, cli_args::ConvertStorageKeyArgs};
+
+pub(super) fn storage_key_tool(args: &ConvertStorageKeyArgs) -> String {
+    let abi_json = load_abi_json(&args.abi);
+    assert!(
+        !abi_json.storage.is_empty(),
+        "no storage section in ABI file {}, generate it with `sc-meta abi --storage`",
+        args.abi
+    );
+
+    let storage_key_abi = abi_json
+        .storage
+        .iter()
+        .find(|storage_key_abi| storage_key_abi.name == args.name)
+        .unwrap_or_else(|| {
+            panic!(
+                "storage `{}` not found in the ABI of `{}`",
+                args.name, abi_json.name
+            )
+        });
+    format!(
+        "0x{}",
+        hex::encode(storage_key(storage_key_abi, &args.args))
+    )
+}
+
+/// Computes the raw storage key of a storage mapper, getter or setter, given its key arguments.
+///
+/// The arguments are nested-encoded, based on their types: numbers in decimal, addresses in bech32,
+/// strings, token identifiers and bytes as text. Any of them can also be given as `0x`-prefixed hex,
+/// which is taken as already encoded.
+///
+/// For mappers that store several entries, e.g. `VecMapper`, this is the base key,
+/// that the mapper appends its own suffixes to.
+pub fn storage_key(storage_key_abi: &StorageKeyAbiJson, key_args: &[String]) -> Vec<u8> {
+    assert!(
+        key_args.len() == storage_key_abi.inputs.len(),
+        "storage `{}` expects {} key arguments, got {}",
+        storage_key_abi.name,
+        storage_key_abi.inputs.len(),
+        key_args.len()
+    );
+
+    let mut key = storage_key_abi.key.as_bytes().to_vec();
+    for (input, key_arg) in storage_key_abi.inputs.iter().zip(key_args) {
+        key.extend(encode_key_arg(&input.type_name, key_arg));
+    }
+    key
+}
+
+fn encode_key_arg(type_name: &str, key_arg: &str) -> Vec<u8> {
+    if key_arg.starts_with("0x") {
+        return decode_hex(key_arg);
+    }
+
+    match type_name {
+        "Address" => bech32_to_address(key_arg),
+        "bool" => match key_arg {
+            "false" => vec![0],
+            "true" => vec![1],
+            _ => panic!("invalid bool `{key_arg}`"),
+        },
+        "BigUint" => {
+            let value = BigUint::parse_bytes(key_arg.as_bytes(), 10)
+                .unwrap_or_else(|| panic!("invalid BigUint `{key_arg}`"));
+            let bytes = if value == BigUint::default() {
+                Vec::new()
+            } else {
+                value.to_bytes_be()
+            };
+            length_prefixed(&bytes)
+        },
+        "BigInt" => {
+            let value = BigInt::parse_bytes(key_arg.as_bytes(), 10)
+                .unwrap_or_else(|| panic!("invalid BigInt `{key_arg}`"));
+            let bytes = if value.sign() == Sign::NoSign {
+                Vec::new()
+            } else {
+                value.to_signed_bytes_be()
+            };
+            length_prefixed(&bytes)
+        },
+        "u8" | "u16" | "u32" | "usize" | "u64" => {
+            let size = int_size(type_name);
+            let value: u64 = key_arg
+                .parse()
+                .unwrap_or_else(|err| panic!("invalid {type_name} `{key_arg}`: {err}"));
+            assert!(
+                size == 8 || value >> (size * 8) == 0,
+                "value `{key_arg}` does not fit in {type_name}"
+            );
+            value.to_be_bytes()[8 - size..].to_vec()
+        },
+        "i8" | "i16" | "i32" | "isize" | "i64" => {
+            let size = int_size(type_name);
+            let value: i64 = key_arg
+                .parse()
+                .unwrap_or_else(|err| panic!("invalid {type_name} `{key_arg}`: {err}"));
+            let bits = size as u32 * 8;
+            assert!(
+                size == 8 || (value >> (bits - 1) == 0 || value >> (bits - 1) == -1),
+                "value `{key_arg}` does not fit in {type_name}"
+            );
+            value.to_be_bytes()[8 - size..].to_vec()
+        },
+        "bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+            length_prefixed(key_arg.as_bytes())
+        },
+        _ => panic!(
+            "cannot encode `{key_arg}` as {type_name}, provide it as 0x-prefixed hex, nested-encoded"
+        ),
+    }
+}
+
+fn length_prefixed(bytes: &[u8]) -> Vec<u8> {
+    let mut result = (bytes.len() as u32).to_be_bytes().to_vec();
+    result.extend_from_slice(bytes);
+    result
+}
@@ -0,0 +1,62 @@
+use super::{
+    convert_encoding::{decode_base64, decode_hex},
+    load_abi_json,
+};
+use crate::{abi_decode::AbiValueDecoder, abi_json::ContractAbiJson, cli_args::ConvertTopicsArgs};
+
+pub(super) fn decode_topics_tool(args: &ConvertTopicsArgs) -> String {
+    let abi_json = load_abi_json(&args.abi);
+
+    let topics: Vec<Vec<u8>> = args
+        .topics
+        .iter()
+        .map(|topic| {
+            if args.hex {
+                decode_hex(topic)
+            } else {
+                decode_base64(topic)
+            }
+        })
+        .collect();
+    decode_topics(&abi_json, &topics)
+}
+
+/// Decodes the topics of an event log, one line per topic.
+///
+/// The first topic is the event identifier,
+/// the others are matched to the indexed inputs of the event, in order.
+pub fn decode_topics(abi_json: &ContractAbiJson, topics: &[Vec<u8>]) -> String {
+    let (identifier, topics) = topics.split_first().expect("no topics provided");
+    let identifier = String::from_utf8_lossy(identifier);
+    let event = abi_json
+        .events
+        .iter()
+        .find(|event| event.identifier == identifier)
+        .unwrap_or_else(|| {
+            panic!(
+                "event `{identifier}` not found in the ABI of `{}`",
+                abi_json.name
+            )
+        });
+
+    let mut indexed_inputs = event
+        .inputs
+        .iter()
+        .filter(|input| input.indexed == Some(true));
+    // topics are top-encoded, values that do not match their type are shown as hex
+    let value_decoder = AbiValueDecoder::new(&abi_json.types);
+    let mut lines = vec![format!("event: {identifier}")];
+    for topic in topics {
+        let line = match indexed_inputs.next() {
+            Some(input) => format!(
+                "{} ({}): {}",
+                input.arg_name,
+                input.type_name,
+                value_decoder.top_decode(&input.type_name, topic)
+            ),
+            None => format!("(not in ABI): 0x{}", hex::encode(topic)),
+        };
+        lines.push(line);
+    }
+    lines.join("\n")
+}
@@ -1,7 +1,7 @@
 /// The version of the SC environment interface (EI), it deals with the VM hooks available at a certain point in time.
 ///
 /// It is not tied to the version of the VM, hence the different numbering.
-#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum EIVersion {
     /// This is not necessarily the first version of the EI,
     /// but rather the oldest version when we started keeping track of the EI.
@@ -31,6 +31,14 @@ pub enum EIVersion {
 }
 
 impl EIVersion {
+    /// All known EI versions, oldest first.
+    pub const ALL: &'static [EIVersion] = &[
+        EIVersion::V1_0,
+        EIVersion::V1_1,
+        EIVersion::V1_2,
+        EIVersion::V1_3,
+    ];
+
     pub fn from_name(name: &str) -> Option<Self> {
         match name {
             "1.0" => Some(EIVersion::V1_0),
@@ -41,6 +49,19 @@ impl EIVersion {
         }
     }
 
+    /// The EI version of a VM version, e.g. "1.4" for VM 1.4.
+    ///
+    /// Only the VM versions that introduced new EI versions are known.
+    pub fn from_vm_version(vm_version: &str) -> Result<Self, String> {
+        match vm_version {
+            "1.4" => Ok(EIVersion::V1_2),
+            "1.5" => Ok(EIVersion::V1_3),
+            _ => Err(format!(
+                "unknown target VM version {vm_version}, expected one of: 1.4, 1.5"
+            )),
+        }
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             EIVersion::V1_0 => "1.0",
@@ -62,4 +83,21 @@ impl EIVersion {
     pub fn contains_vm_hook(&self, vm_hook_names: &str) -> bool {
         self.vm_hook_names().contains(&vm_hook_names)
     }
+
+    /// The oldest EI version that provides the given VM hook, `None` if no known version does.
+    pub fn min_version_for_vm_hook(vm_hook_name: &str) -> Option<EIVersion> {
+        EIVersion::ALL
+            .iter()
+            .copied()
+            .find(|ei| ei.contains_vm_hook(vm_hook_name))
+    }
+
+    /// The imports that are not VM hooks of this EI version.
+    pub fn unsupported_vm_hooks<'a>(&self, import_names: &'a [String]) -> Vec<&'a str> {
+        import_names
+            .iter()
+            .map(String::as_str)
+            .filter(|import_name| !self.contains_vm_hook(import_name))
+            .collect()
+    }
 }
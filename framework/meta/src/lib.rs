@@ -1,3 +1,4 @@
+pub mod abi_decode;
 pub mod abi_json;
 mod cargo_toml_contents;
 pub mod cli_args;
@@ -6,6 +7,7 @@ pub mod ei;
 mod folder_structure;
 mod mxsc_file_json;
 mod print_util;
+pub mod size_report;
 pub mod template;
 mod tools;
 pub mod version_history;
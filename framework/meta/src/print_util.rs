@@ -83,6 +83,20 @@ pub fn print_contract_size(size: usize) {
     println!("{}", format!("Contract size: {size} bytes.").blue(),);
 }
 
+pub fn print_size_report(size_report_path: &str) {
+    println!(
+        "{}",
+        format!("Writing size report to {size_report_path} ...").green(),
+    );
+}
+
+pub fn print_size_comparison(comparison_lines: &[String]) {
+    println!("{}", "Compared to the previous build:".blue());
+    for line in comparison_lines {
+        println!("{}", format!("  {line}").blue());
+    }
+}
+
 pub fn print_extract_imports(imports_path: &str) {
     println!(
         "{}",
@@ -97,12 +111,15 @@ pub fn print_check_ei(ei_version: &str) {
     );
 }
 
-pub fn print_invalid_vm_hook(import_name: &str, ei_version: &str) {
+pub fn print_invalid_vm_hook(import_name: &str, ei_version: &str, min_ei_version: Option<&str>) {
+    let hint = match min_ei_version {
+        Some(min_ei_version) => format!(" It requires EI version {min_ei_version}."),
+        None => " It is not a known VM hook.".to_string(),
+    };
     print!(
         "\n{}",
-        format!(
-            "WARNING! Import '{import_name}' is not available on EI version {ei_version}! This will become a hard error in the next release."
-        ).yellow(),
+        format!("ERROR! Import '{import_name}' is not available on EI version {ei_version}!{hint}")
+            .red(),
     );
 }
 
@@ -113,3 +130,20 @@ pub fn print_check_ei_ok() {
 pub fn print_ignore_ei_check() {
     println!("{}", "EI version check explicitly ignored".yellow(),);
 }
+
+pub fn print_check_warnings(contract_name: &str, warnings: &[String]) {
+    if warnings.is_empty() {
+        println!(
+            "{}",
+            format!("Checking {contract_name}: no warnings").green()
+        );
+        return;
+    }
+    println!(
+        "{}",
+        format!("Checking {contract_name}: {} warning(s)", warnings.len()).yellow()
+    );
+    for warning in warnings {
+        println!("{}", format!("  warning: {warning}").yellow());
+    }
+}
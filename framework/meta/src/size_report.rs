@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+const WASM_HEADER_LEN: usize = 8;
+const WASM_MAGIC: &[u8] = b"\0asm";
+const CODE_SECTION_ID: u8 = 10;
+const DATA_SECTION_ID: u8 = 11;
+
+/// Breaks down the size of a compiled contract, to see where the bytes go.
+///
+/// The data section holds the static data, most of it being error messages and other string literals.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmSizeReport {
+    pub total_size: usize,
+    pub code_size: usize,
+    pub num_functions: usize,
+    pub data_size: usize,
+    pub num_data_segments: usize,
+}
+
+impl WasmSizeReport {
+    pub fn from_wasm_bytes(wasm_bytes: &[u8]) -> Self {
+        assert!(
+            wasm_bytes.len() >= WASM_HEADER_LEN && wasm_bytes.starts_with(WASM_MAGIC),
+            "invalid wasm file"
+        );
+
+        let mut report = WasmSizeReport {
+            total_size: wasm_bytes.len(),
+            ..Default::default()
+        };
+        let mut offset = WASM_HEADER_LEN;
+        while offset < wasm_bytes.len() {
+            let section_id = wasm_bytes[offset];
+            offset += 1;
+            let section_size = read_leb128(wasm_bytes, &mut offset) as usize;
+            let section_end = offset + section_size;
+            assert!(section_end <= wasm_bytes.len(), "invalid wasm section size");
+            let mut content_offset = offset;
+            match section_id {
+                CODE_SECTION_ID => {
+                    report.code_size = section_size;
+                    report.num_functions = read_leb128(wasm_bytes, &mut content_offset) as usize;
+                },
+                DATA_SECTION_ID => {
+                    report.data_size = section_size;
+                    report.num_data_segments =
+                        read_leb128(wasm_bytes, &mut content_offset) as usize;
+                },
+                _ => {},
+            }
+            offset = section_end;
+        }
+        report
+    }
+
+    /// Compares the report with the one of a previous build, e.g. `total size: 12000 bytes (-350)`.
+    ///
+    /// Serves as a size benchmark when trying out optimizations, such as `error_messages!`.
+    pub fn comparison_lines(&self, previous: &WasmSizeReport) -> Vec<String> {
+        vec![
+            comparison_line("total size", self.total_size, previous.total_size, "bytes"),
+            comparison_line("code size", self.code_size, previous.code_size, "bytes"),
+            comparison_line("functions", self.num_functions, previous.num_functions, ""),
+            comparison_line("data size", self.data_size, previous.data_size, "bytes"),
+            comparison_line(
+                "data segments",
+                self.num_data_segments,
+                previous.num_data_segments,
+                "",
+            ),
+        ]
+    }
+}
+
+fn comparison_line(label: &str, current: usize, previous: usize, unit: &str) -> String {
+    let unit = if unit.is_empty() {
+        String::new()
+    } else {
+        format!(" {unit}")
+    };
+    let delta = current as i64 - previous as i64;
+    if delta == 0 {
+        format!("{label}: {current}{unit} (unchanged)")
+    } else {
+        format!("{label}: {current}{unit} ({delta:+})")
+    }
+}
+
+fn read_leb128(bytes: &[u8], offset: &mut usize) -> u64 {
+    let mut result = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes
+            .get(*offset)
+            .expect("invalid wasm file: unexpected end");
+        *offset += 1;
+        result |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return result;
+        }
+        shift += 7;
+        assert!(shift < 64, "invalid wasm file: LEB128 number too large");
+    }
+}
+
+pub fn save_size_report(report: &WasmSizeReport, path: impl AsRef<Path>) {
+    let mut json = serde_json::to_string_pretty(report).unwrap();
+    json.push('\n');
+    fs::write(path, json).expect("failed to write size report file");
+}
+
+/// Loads the report of a previous build, if any.
+pub fn load_size_report(path: impl AsRef<Path>) -> Option<WasmSizeReport> {
+    let json = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&json).ok()
+}
@@ -57,9 +57,14 @@ fn is_twiggy_installed() -> bool {
         .is_ok()
 }
 
-pub(crate) fn run_wasm_opt(output_wasm_path: &str) {
-    let exit_status = Command::new(WASM_OPT_NAME)
-        .args([output_wasm_path, "-Oz", "--output", output_wasm_path])
+pub(crate) fn run_wasm_opt(output_wasm_path: &str, keep_names: bool) {
+    let mut command = Command::new(WASM_OPT_NAME);
+    command.args([output_wasm_path, "-Oz", "--output", output_wasm_path]);
+    if keep_names {
+        // otherwise the name section gets stripped
+        command.arg("--debuginfo");
+    }
+    let exit_status = command
         .spawn()
         .expect("failed to spawn wasm-opt process")
         .wait()
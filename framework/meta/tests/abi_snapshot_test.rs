@@ -0,0 +1,44 @@
+use multiversx_sc_meta::abi_json::abi_snapshot_diff;
+
+const SNAPSHOT_JSON: &str = r#"{
+    "name": "Adder",
+    "constructor": { "inputs": [], "outputs": [] },
+    "endpoints": [
+        { "name": "getSum", "mutability": "readonly", "inputs": [], "outputs": [{ "type": "BigUint" }] },
+        { "name": "add", "mutability": "mutable", "inputs": [{ "name": "value", "type": "BigUint" }], "outputs": [] }
+    ],
+    "events": [],
+    "hasCallback": false,
+    "types": {
+        "Status": { "type": "enum", "variants": [{ "name": "Active", "discriminant": 0 }] }
+    }
+}"#;
+
+const CURRENT_JSON: &str = r#"{
+    "name": "Adder",
+    "constructor": { "inputs": [], "outputs": [] },
+    "endpoints": [
+        { "name": "getSum", "mutability": "readonly", "inputs": [], "outputs": [{ "type": "BigUint" }] },
+        { "name": "add", "mutability": "mutable", "inputs": [{ "name": "value", "type": "u64" }], "outputs": [] },
+        { "name": "reset", "mutability": "mutable", "inputs": [], "outputs": [] }
+    ],
+    "events": [],
+    "hasCallback": false,
+    "types": {}
+}"#;
+
+#[test]
+fn test_abi_snapshot_diff() {
+    assert_eq!(
+        abi_snapshot_diff(SNAPSHOT_JSON, CURRENT_JSON),
+        vec![
+            "endpoint `add` changed",
+            "endpoint `reset` added",
+            "type `Status` removed",
+        ]
+    );
+    assert_eq!(
+        abi_snapshot_diff(SNAPSHOT_JSON, &SNAPSHOT_JSON.replace("    ", "  ")),
+        vec!["the order or the formatting of the items changed"]
+    );
+}
@@ -0,0 +1,13 @@
+use multiversx_sc::types::heap::Address;
+use multiversx_sc_meta::abi_decode::AbiValue;
+use multiversx_sdk::data::address::set_hrp;
+
+/// In its own test binary, since the human-readable part is set for the whole process.
+#[test]
+fn test_abi_value_address_configured_hrp() {
+    let address = AbiValue::Address(Address::from([1u8; 32]));
+    assert!(address.to_string().starts_with("erd1"));
+
+    set_hrp("test");
+    assert!(address.to_string().starts_with("test1"));
+}
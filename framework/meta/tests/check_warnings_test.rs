@@ -0,0 +1,67 @@
+use multiversx_sc::abi::{ContractAbi, EndpointAbi, EndpointDeprecationAbi};
+use multiversx_sc_meta::{
+    abi_json::EndpointAbiJson, cmd::contract::output_contract::OutputContractGlobalConfig,
+};
+
+fn get_contract_abi() -> ContractAbi {
+    let endpoints = vec![
+        EndpointAbi {
+            aliases: &["getSumOld"],
+            ..EndpointAbi::endpoint_with_name_and_labels("getSum", &[])
+        },
+        EndpointAbi {
+            deprecated: Some(EndpointDeprecationAbi {
+                since: "0.2.0",
+                note: "Use `getSum` instead.",
+            }),
+            ..EndpointAbi::endpoint_with_name_and_labels("sum", &[])
+        },
+        EndpointAbi {
+            deprecated: Some(EndpointDeprecationAbi {
+                since: "0.3.0",
+                note: "",
+            }),
+            ..EndpointAbi::endpoint_with_name_and_labels("reset", &[])
+        },
+    ];
+    ContractAbi::generate_with_endpoints(endpoints)
+}
+
+#[test]
+fn test_deprecated_endpoint_warnings() {
+    let abi = get_contract_abi();
+    let output_contracts = OutputContractGlobalConfig::default_config(&abi);
+    let warnings = output_contracts.main_contract().check_warnings();
+    assert_eq!(
+        warnings,
+        vec![
+            "Endpoint `sum` is deprecated since 0.2.0. Use `getSum` instead.".to_string(),
+            "Endpoint `reset` is deprecated since 0.3.0.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_alias_and_deprecation_abi_json() {
+    let abi = get_contract_abi();
+    let json = |endpoint_abi: &EndpointAbi| {
+        serde_json::to_value(EndpointAbiJson::from(endpoint_abi)).unwrap()
+    };
+
+    let get_sum_json = json(&abi.endpoints[0]);
+    assert_eq!(get_sum_json["aliases"], serde_json::json!(["getSumOld"]));
+    assert!(get_sum_json.get("deprecated").is_none());
+
+    let sum_json = json(&abi.endpoints[1]);
+    assert!(sum_json.get("aliases").is_none());
+    assert_eq!(
+        sum_json["deprecated"],
+        serde_json::json!({ "since": "0.2.0", "note": "Use `getSum` instead." })
+    );
+
+    let reset_json = json(&abi.endpoints[2]);
+    assert_eq!(
+        reset_json["deprecated"],
+        serde_json::json!({ "since": "0.3.0" })
+    );
+}
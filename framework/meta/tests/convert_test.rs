@@ -0,0 +1,254 @@
+use multiversx_sc_meta::{
+    abi_json::{ContractAbiJson, StorageKeyAbiJson, StorageKeyInputAbiJson},
+    cmd::standalone::convert::{
+        base64_to_hex, bech32_to_hex, decode_storage_dump, decode_topics, from_units,
+        hex_to_base64, hex_to_bech32, storage_key, to_units,
+    },
+};
+
+const ALICE_BECH32: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+const ALICE_HEX: &str = "0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1";
+
+const ABI_JSON: &str = r#"{
+    "name": "Adder",
+    "endpoints": [],
+    "events": [
+        {
+            "identifier": "deposit",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "amount", "type": "BigUint", "indexed": true },
+                { "name": "status", "type": "Status", "indexed": true },
+                { "name": "data", "type": "bytes" }
+            ]
+        }
+    ],
+    "hasCallback": false,
+    "types": {
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Pending", "discriminant": 0 },
+                { "name": "Done", "discriminant": 1 }
+            ]
+        }
+    }
+}"#;
+
+#[test]
+fn test_convert_address() {
+    assert_eq!(bech32_to_hex(ALICE_BECH32), ALICE_HEX);
+    assert_eq!(hex_to_bech32(ALICE_HEX, "erd"), ALICE_BECH32);
+    assert_eq!(
+        hex_to_bech32(&format!("0x{ALICE_HEX}"), "erd"),
+        ALICE_BECH32
+    );
+}
+
+#[test]
+#[should_panic = "addresses are 32 bytes long, got 2 bytes"]
+fn test_convert_address_invalid_length() {
+    hex_to_bech32("0102", "erd");
+}
+
+#[test]
+fn test_convert_base64() {
+    assert_eq!(base64_to_hex("AQID"), "010203");
+    assert_eq!(hex_to_base64("010203"), "AQID");
+    assert_eq!(hex_to_base64(""), "");
+}
+
+#[test]
+fn test_convert_amount() {
+    assert_eq!(to_units("1.5", 18), "1500000000000000000");
+    assert_eq!(to_units("0.000001", 6), "1");
+    assert_eq!(to_units("12", 0), "12");
+    assert_eq!(to_units("0", 18), "0");
+    assert_eq!(from_units("1500000000000000000", 18), "1.5");
+    assert_eq!(from_units("1", 6), "0.000001");
+    assert_eq!(from_units("12000000", 6), "12");
+    assert_eq!(from_units("0", 18), "0");
+}
+
+#[test]
+#[should_panic = "token amount `1.0000001` has more than 6 decimals"]
+fn test_convert_amount_too_many_decimals() {
+    to_units("1.0000001", 6);
+}
+
+#[test]
+fn test_decode_topics() {
+    let abi_json: ContractAbiJson = serde_json::from_str(ABI_JSON).unwrap();
+    let topics = vec![
+        b"deposit".to_vec(),
+        hex::decode(ALICE_HEX).unwrap(),
+        vec![0x03, 0xe8],
+        vec![1],
+        vec![0xff],
+    ];
+    assert_eq!(
+        decode_topics(&abi_json, &topics),
+        format!(
+            "event: deposit
+caller (Address): {ALICE_BECH32}
+amount (BigUint): 1000
+status (Status): Done
+(not in ABI): 0xff"
+        )
+    );
+}
+
+const STORAGE_ABI_JSON: &str = r#"{
+    "name": "Adder",
+    "endpoints": [],
+    "events": [],
+    "hasCallback": false,
+    "types": {
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Pending", "discriminant": 0 },
+                { "name": "Done", "discriminant": 1 }
+            ]
+        }
+    },
+    "storage": [
+        {
+            "name": "balance",
+            "key": "balance",
+            "mapper": "SingleValueMapper",
+            "inputs": [{ "name": "user", "type": "Address" }],
+            "valueType": "BigUint"
+        },
+        {
+            "name": "status",
+            "key": "status",
+            "mapper": "SingleValueMapper",
+            "valueType": "Status"
+        },
+        {
+            "name": "users",
+            "key": "users",
+            "mapper": "VecMapper",
+            "valueType": "Address"
+        }
+    ]
+}"#;
+
+#[test]
+fn test_decode_storage_dump() {
+    let abi_json: ContractAbiJson = serde_json::from_str(STORAGE_ABI_JSON).unwrap();
+    let balance_key = format!("{}{ALICE_HEX}", hex::encode("balance"));
+    let status_key = hex::encode("status");
+    let users_len_key = hex::encode("users.len");
+    let users_item_key = format!("{}00000001", hex::encode("users.item"));
+    let unknown_key = hex::encode("abc");
+    let dump_json = format!(
+        r#"{{
+            "data": {{
+                "blockInfo": {{ "nonce": 1 }},
+                "pairs": {{
+                    "{balance_key}": "03e8",
+                    "{status_key}": "",
+                    "{users_len_key}": "00000001",
+                    "{users_item_key}": "{ALICE_HEX}",
+                    "{unknown_key}": "01"
+                }}
+            }},
+            "error": "",
+            "code": "successful"
+        }}"#
+    );
+    assert_eq!(
+        decode_storage_dump(abi_json, &dump_json),
+        format!(
+            "0x616263 = 0x01
+balance(user: {ALICE_BECH32}) = 1000
+status() = Pending
+users().item[1] = {ALICE_BECH32}
+users().len = 1"
+        )
+    );
+}
+
+fn storage_key_input(name: &str, type_name: &str) -> StorageKeyInputAbiJson {
+    StorageKeyInputAbiJson {
+        arg_name: name.to_string(),
+        type_name: type_name.to_string(),
+    }
+}
+
+#[test]
+fn test_storage_key() {
+    let storage_key_abi = StorageKeyAbiJson {
+        name: "balance".to_string(),
+        key: "balance".to_string(),
+        mapper: Some("SingleValueMapper".to_string()),
+        inputs: vec![
+            storage_key_input("user", "Address"),
+            storage_key_input("token", "TokenIdentifier"),
+            storage_key_input("nonce", "u64"),
+        ],
+        value_type: Some("BigUint".to_string()),
+    };
+    let key = storage_key(
+        &storage_key_abi,
+        &[
+            ALICE_BECH32.to_string(),
+            "TKN-123456".to_string(),
+            "5".to_string(),
+        ],
+    );
+
+    let mut expected = b"balance".to_vec();
+    expected.extend(hex::decode(ALICE_HEX).unwrap());
+    expected.extend([0, 0, 0, 10]);
+    expected.extend(b"TKN-123456");
+    expected.extend([0, 0, 0, 0, 0, 0, 0, 5]);
+    assert_eq!(key, expected);
+}
+
+#[test]
+fn test_storage_key_numbers() {
+    let storage_key_abi = StorageKeyAbiJson {
+        name: "amounts".to_string(),
+        key: "amounts".to_string(),
+        mapper: None,
+        inputs: vec![
+            storage_key_input("a", "BigUint"),
+            storage_key_input("b", "BigUint"),
+            storage_key_input("c", "i16"),
+            storage_key_input("d", "Custom"),
+        ],
+        value_type: None,
+    };
+    let key = storage_key(
+        &storage_key_abi,
+        &[
+            "1000".to_string(),
+            "0".to_string(),
+            "-2".to_string(),
+            "0x0102".to_string(),
+        ],
+    );
+
+    let mut expected = b"amounts".to_vec();
+    expected.extend([0, 0, 0, 2, 0x03, 0xe8]);
+    expected.extend([0, 0, 0, 0]);
+    expected.extend([0xff, 0xfe]);
+    expected.extend([1, 2]);
+    assert_eq!(key, expected);
+}
+
+#[test]
+#[should_panic = "storage `amounts` expects 1 key arguments, got 0"]
+fn test_storage_key_missing_args() {
+    let storage_key_abi = StorageKeyAbiJson {
+        name: "amounts".to_string(),
+        key: "amounts".to_string(),
+        mapper: None,
+        inputs: vec![storage_key_input("a", "BigUint")],
+        value_type: None,
+    };
+    storage_key(&storage_key_abi, &[]);
+}
@@ -103,3 +103,65 @@ fn test_added_names_ei_1_2() {
 fn test_added_names_ei_1_3() {
     test_added_names(ei::EI_1_2_NAMES, EI_1_3_ADDED_NAMES, ei::EI_1_3_NAMES);
 }
+
+#[test]
+fn test_min_version_for_vm_hook() {
+    use ei::EIVersion;
+
+    assert_eq!(
+        EIVersion::min_version_for_vm_hook("getGasLeft"),
+        Some(EIVersion::V1_0)
+    );
+    assert_eq!(
+        EIVersion::min_version_for_vm_hook("managedSha256"),
+        Some(EIVersion::V1_1)
+    );
+    assert_eq!(
+        EIVersion::min_version_for_vm_hook("bigFloatAdd"),
+        Some(EIVersion::V1_2)
+    );
+    assert_eq!(
+        EIVersion::min_version_for_vm_hook(EI_1_3_ADDED_NAMES[0]),
+        Some(EIVersion::V1_3)
+    );
+    assert_eq!(EIVersion::min_version_for_vm_hook("noSuchHook"), None);
+}
+
+#[test]
+fn test_unsupported_vm_hooks() {
+    use ei::EIVersion;
+
+    let import_names = vec![
+        "getGasLeft".to_string(),
+        EI_1_3_ADDED_NAMES[0].to_string(),
+        "noSuchHook".to_string(),
+    ];
+    assert_eq!(
+        EIVersion::V1_2.unsupported_vm_hooks(&import_names),
+        vec![EI_1_3_ADDED_NAMES[0], "noSuchHook"]
+    );
+    assert_eq!(
+        EIVersion::V1_3.unsupported_vm_hooks(&import_names),
+        vec!["noSuchHook"]
+    );
+}
+
+#[test]
+fn test_target_ei_supports() {
+    use multiversx_sc::api::{parse_ei_version, target_ei_supports};
+
+    assert_eq!(parse_ei_version("1.3"), Some((1, 3)));
+    assert_eq!(parse_ei_version("ignore"), None);
+
+    // tests are not built via sc-meta, so no target EI version is configured
+    assert!(target_ei_supports(1, 3));
+}
+
+#[test]
+fn test_from_vm_version() {
+    use ei::EIVersion;
+
+    assert_eq!(EIVersion::from_vm_version("1.4"), Ok(EIVersion::V1_2));
+    assert_eq!(EIVersion::from_vm_version("1.5"), Ok(EIVersion::V1_3));
+    assert!(EIVersion::from_vm_version("0.9").is_err());
+}
@@ -0,0 +1,19 @@
+use multiversx_sc::abi::{ContractAbi, EndpointAbi};
+use multiversx_sc::types::heap::Address;
+use multiversx_sc_meta::abi_json::contract_json_schema;
+use multiversx_sdk::data::address::set_hrp;
+
+/// In its own test binary, since the human-readable part is set for the whole process.
+#[test]
+fn test_contract_json_schema_configured_hrp() {
+    let mut endpoint = EndpointAbi::endpoint_with_name_and_labels("setOwner", &[]);
+    endpoint.add_input::<Address>("owner");
+    let abi = ContractAbi::generate_with_endpoints(vec![endpoint]);
+
+    set_hrp("test");
+    let schema = contract_json_schema(&abi);
+    assert_eq!(
+        schema["properties"]["setOwner"]["properties"]["inputs"]["properties"]["owner"]["pattern"],
+        "^test1[02-9ac-hj-np-z]{58}$"
+    );
+}
@@ -0,0 +1,110 @@
+use multiversx_sc::{
+    abi::{ContractAbi, EndpointAbi},
+    codec::multi_types::MultiValueVec,
+    types::heap::Address,
+};
+use multiversx_sc_meta::abi_json::contract_json_schema;
+use serde_json::json;
+
+multiversx_sc::derive_imports!();
+
+/// A deposit.
+#[derive(TypeAbi)]
+pub struct Deposit {
+    pub owner: Address,
+    pub amount: u64,
+    pub status: DepositStatus,
+}
+
+#[derive(TypeAbi)]
+pub enum DepositStatus {
+    Pending,
+    Locked { until: u32 },
+}
+
+fn contract_abi() -> ContractAbi {
+    let mut deposit_endpoint = EndpointAbi::endpoint_with_name_and_labels("deposit", &[]);
+    deposit_endpoint.add_input::<Deposit>("deposit");
+    deposit_endpoint.add_input::<MultiValueVec<u8>>("tags");
+    deposit_endpoint.add_output::<Option<u64>>(&[]);
+
+    let mut abi = ContractAbi::generate_with_endpoints(vec![deposit_endpoint]);
+    abi.name = "vault";
+    abi.add_type_descriptions::<Deposit>();
+    abi
+}
+
+#[test]
+fn test_contract_json_schema() {
+    let address_pattern = "^erd1[02-9ac-hj-np-z]{58}$";
+    assert_eq!(
+        contract_json_schema(&contract_abi()),
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "vault",
+            "type": "object",
+            "properties": {
+                "deposit": {
+                    "type": "object",
+                    "properties": {
+                        "inputs": {
+                            "type": "object",
+                            "properties": {
+                                "deposit": { "$ref": "#/definitions/Deposit" },
+                                "tags": {
+                                    "type": "array",
+                                    "items": { "type": "integer", "minimum": 0, "maximum": 255 },
+                                },
+                            },
+                            "required": ["deposit"],
+                            "additionalProperties": false,
+                        },
+                        "outputs": {
+                            "type": "array",
+                            "items": [{ "anyOf": [
+                                { "type": "string", "pattern": "^[0-9]+$" },
+                                { "type": "null" },
+                            ] }],
+                            "minItems": 1,
+                            "maxItems": 1,
+                        },
+                    },
+                },
+            },
+            "additionalProperties": false,
+            "definitions": {
+                "Deposit": {
+                    "description": "A deposit.",
+                    "type": "object",
+                    "properties": {
+                        "owner": { "type": "string", "pattern": address_pattern },
+                        "amount": { "type": "string", "pattern": "^[0-9]+$" },
+                        "status": { "$ref": "#/definitions/DepositStatus" },
+                    },
+                    "required": ["owner", "amount", "status"],
+                    "additionalProperties": false,
+                },
+                "DepositStatus": {
+                    "oneOf": [
+                        { "const": "Pending" },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "Locked": {
+                                    "type": "object",
+                                    "properties": {
+                                        "until": { "type": "integer", "minimum": 0, "maximum": u32::MAX },
+                                    },
+                                    "required": ["until"],
+                                    "additionalProperties": false,
+                                },
+                            },
+                            "required": ["Locked"],
+                            "additionalProperties": false,
+                        },
+                    ],
+                },
+            },
+        })
+    );
+}
@@ -124,3 +124,164 @@ fn test_output_contract_config() {
         ["endpoint1", "endpoint2", "endpoint3", "endpoint4"]
     );
 }
+
+#[test]
+fn test_debug_variant_config() {
+    let serde: MultiContractConfigSerde = toml::from_str(
+        r#"
+        [settings]
+        main = "main-contract"
+
+        [contracts.main-contract]
+        add-unlabelled = true
+        debug-variant = true
+
+        [contracts.secondary-contract]
+        name = "contract2-name"
+        add-labels = ["label2"]
+        debug-variant = true
+        debug-variant-symbols = true
+    "#,
+    )
+    .unwrap();
+    let abi = get_contract_abi();
+
+    let contract_config = OutputContractGlobalConfig::load_from_config(&serde, &abi);
+    assert_eq!(contract_config.contracts.len(), 4);
+
+    let main_contract = contract_config.main_contract();
+    assert!(!main_contract.settings.panic_message);
+
+    let main_debug = contract_config
+        .get_contract_by_id("main-contract-debug".to_string())
+        .unwrap();
+    assert!(!main_debug.main);
+    assert_eq!(main_debug.contract_name, "main-contract-debug");
+    assert_eq!(main_debug.endpoint_names(), main_contract.endpoint_names());
+    assert!(main_debug.settings.panic_message);
+    assert!(!main_debug.settings.wasm_symbols);
+
+    let secondary_debug = contract_config
+        .get_contract_by_name("contract2-name-debug".to_string())
+        .unwrap();
+    assert_eq!(secondary_debug.contract_id, "secondary-contract-debug");
+    assert_eq!(
+        secondary_debug.endpoint_names(),
+        ["endpoint1", "endpoint2", "endpoint3", "endpoint4"]
+    );
+    assert!(secondary_debug.settings.panic_message);
+    assert!(secondary_debug.settings.wasm_symbols);
+}
+
+fn get_contract_abi_with_test_endpoints() -> ContractAbi {
+    let endpoints = vec![
+        EndpointAbi::endpoint_with_name_and_labels("endpoint1", &[]),
+        EndpointAbi {
+            test_only: true,
+            ..EndpointAbi::endpoint_with_name_and_labels("setBalanceCheat", &[])
+        },
+        EndpointAbi {
+            test_only: true,
+            ..EndpointAbi::endpoint_with_name_and_labels("setRoundCheat", &["label1"])
+        },
+    ];
+    ContractAbi::generate_with_endpoints(endpoints)
+}
+
+#[test]
+fn test_test_endpoints_config() {
+    let serde: MultiContractConfigSerde = toml::from_str(
+        r#"
+        [settings]
+        main = "main-contract"
+
+        [contracts.main-contract]
+        add-unlabelled = true
+        add-labels = ["label1"]
+
+        [contracts.test-contract]
+        add-unlabelled = true
+        add-labels = ["label1"]
+        test-endpoints = true
+    "#,
+    )
+    .unwrap();
+    let abi = get_contract_abi_with_test_endpoints();
+
+    let contract_config = OutputContractGlobalConfig::load_from_config(&serde, &abi);
+
+    let main_contract = contract_config.main_contract();
+    assert!(!main_contract.settings.test_endpoints);
+    assert_eq!(main_contract.endpoint_names(), ["endpoint1"]);
+
+    let test_contract = contract_config
+        .get_contract_by_id("test-contract".to_string())
+        .unwrap();
+    assert!(test_contract.settings.test_endpoints);
+    assert_eq!(
+        test_contract.endpoint_names(),
+        ["endpoint1", "setBalanceCheat", "setRoundCheat"]
+    );
+
+    contract_config.validate_output_contracts();
+}
+
+#[test]
+fn test_test_endpoints_default_config() {
+    let abi = get_contract_abi_with_test_endpoints();
+    let contract_config = OutputContractGlobalConfig::default_config(&abi);
+    assert_eq!(
+        contract_config.main_contract().endpoint_names(),
+        ["endpoint1"]
+    );
+}
+
+#[test]
+#[should_panic(expected = "test endpoints not supported for main contract")]
+fn test_test_endpoints_main_contract() {
+    let serde: MultiContractConfigSerde = toml::from_str(
+        r#"
+        [settings]
+        main = "main-contract"
+
+        [contracts.main-contract]
+        add-unlabelled = true
+        test-endpoints = true
+    "#,
+    )
+    .unwrap();
+    let abi = get_contract_abi_with_test_endpoints();
+    let _ = OutputContractGlobalConfig::load_from_config(&serde, &abi);
+}
+
+#[test]
+#[should_panic(
+    expected = "Test endpoint `setBalanceCheat` exported by contract main-contract, which does not enable `test-endpoints`."
+)]
+fn test_test_endpoints_exported() {
+    let serde: MultiContractConfigSerde = toml::from_str(
+        r#"
+        [settings]
+        main = "main-contract"
+
+        [contracts.main-contract]
+        add-unlabelled = true
+    "#,
+    )
+    .unwrap();
+    let abi = get_contract_abi_with_test_endpoints();
+    let mut contract_config = OutputContractGlobalConfig::load_from_config(&serde, &abi);
+    contract_config.validate_output_contracts();
+
+    // the test endpoint ends up in the generated code, even though it was filtered out at config level
+    let leaked_endpoint = abi.endpoints[1].clone();
+    contract_config
+        .main_contract_mut()
+        .abi
+        .endpoints
+        .push(EndpointAbi {
+            test_only: false,
+            ..leaked_endpoint
+        });
+    contract_config.validate_output_contracts();
+}
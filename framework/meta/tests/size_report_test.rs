@@ -0,0 +1,52 @@
+use multiversx_sc_meta::size_report::WasmSizeReport;
+
+/// A minimal module, with an empty type section, 2 empty functions and a data segment with 3 bytes.
+const WASM_BYTES: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+    0x01, 0x01, 0x00, // type section
+    0x0a, 0x07, 0x02, 0x02, 0x00, 0x0b, 0x02, 0x00, 0x0b, // code section
+    0x0b, 0x09, 0x01, 0x00, 0x41, 0x00, 0x0b, 0x03, b'a', b'b', b'c', // data section
+];
+
+#[test]
+fn size_report_from_wasm_test() {
+    let report = WasmSizeReport::from_wasm_bytes(WASM_BYTES);
+    assert_eq!(
+        report,
+        WasmSizeReport {
+            total_size: 31,
+            code_size: 7,
+            num_functions: 2,
+            data_size: 9,
+            num_data_segments: 1,
+        }
+    );
+}
+
+#[test]
+fn size_report_comparison_test() {
+    let report = WasmSizeReport::from_wasm_bytes(WASM_BYTES);
+    let previous_report = WasmSizeReport {
+        total_size: 40,
+        code_size: 7,
+        num_functions: 2,
+        data_size: 18,
+        num_data_segments: 3,
+    };
+    assert_eq!(
+        report.comparison_lines(&previous_report),
+        vec![
+            "total size: 31 bytes (-9)",
+            "code size: 7 bytes (unchanged)",
+            "functions: 2 (unchanged)",
+            "data size: 9 bytes (-9)",
+            "data segments: 1 (-2)",
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid wasm file")]
+fn size_report_invalid_wasm_test() {
+    WasmSizeReport::from_wasm_bytes(b"not wasm");
+}
@@ -5,4 +5,6 @@ mod managed_type_api_vh;
 mod vm_api_vh;
 
 pub(crate) use impl_vh::i32_to_bool;
-pub use impl_vh::{DebugApi, DebugHandle, SingleTxApi, StaticApi, VMHooksApi, VMHooksApiBackend};
+pub use impl_vh::{
+    DebugApi, DebugApiContext, DebugHandle, SingleTxApi, StaticApi, VMHooksApi, VMHooksApiBackend,
+};
@@ -5,7 +5,7 @@ mod static_api;
 mod vm_hooks_api;
 mod vm_hooks_backend;
 
-pub use debug_api::DebugApi;
+pub use debug_api::{DebugApi, DebugApiContext};
 pub use debug_handle_vh::DebugHandle;
 pub use single_tx_api::SingleTxApi;
 pub use static_api::StaticApi;
@@ -7,7 +7,7 @@ use multiversx_chain_vm::{
 };
 use multiversx_sc::err_msg;
 
-use crate::debug_executor::{StaticVarData, StaticVarStack};
+use crate::debug_executor::{with_allocation_allowed, StaticVarData, StaticVarStack};
 
 use super::{DebugHandle, VMHooksApi, VMHooksApiBackend};
 
@@ -21,19 +21,23 @@ impl VMHooksApiBackend for DebugApiBackend {
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        let top_context = TxContextStack::static_peek();
-        let wrapper = DebugApiVMHooksHandler::new(top_context);
-        let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
-        f(&dispatcher)
+        with_allocation_allowed(|| {
+            let top_context = TxContextStack::static_peek();
+            let wrapper = DebugApiVMHooksHandler::new(top_context);
+            let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
+            f(&dispatcher)
+        })
     }
 
     fn with_vm_hooks_ctx_1<R, F>(handle: Self::HandleType, f: F) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        let wrapper = DebugApiVMHooksHandler::new(handle.context);
-        let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
-        f(&dispatcher)
+        with_allocation_allowed(|| {
+            let wrapper = DebugApiVMHooksHandler::new(handle.context);
+            let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
+            f(&dispatcher)
+        })
     }
 
     fn with_vm_hooks_ctx_2<R, F>(handle1: Self::HandleType, handle2: Self::HandleType, f: F) -> R
@@ -70,8 +74,10 @@ impl VMHooksApiBackend for DebugApiBackend {
     where
         F: FnOnce(&StaticVarData) -> R,
     {
-        let top_context = StaticVarStack::static_peek();
-        f(&top_context)
+        with_allocation_allowed(|| {
+            let top_context = StaticVarStack::static_peek();
+            f(&top_context)
+        })
     }
 }
 
@@ -87,6 +93,64 @@ impl DebugApi {
     }
 }
 
+/// Snapshot of the debugger context that is active on the current thread.
+///
+/// The debugger keeps its context stacks thread-local. Async runtimes such as tokio
+/// can move tasks between threads, so managed types created on one thread would
+/// otherwise become unusable after an `.await`. The captured context is `Send`,
+/// and can be re-installed on whatever thread the code is currently running on.
+#[derive(Clone)]
+pub struct DebugApiContext {
+    tx_context_arc: Arc<TxContext>,
+    static_var_data: Arc<StaticVarData>,
+}
+
+impl DebugApiContext {
+    /// Captures the context currently at the top of the stacks of this thread.
+    pub fn capture() -> Self {
+        DebugApiContext {
+            tx_context_arc: TxContextStack::static_peek(),
+            static_var_data: StaticVarStack::static_peek(),
+        }
+    }
+
+    /// Pushes the captured context on the current thread's stacks, executes closure, pops after.
+    ///
+    /// The context is popped even if the closure panics.
+    pub fn with<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        TxContextStack::static_push(self.tx_context_arc.clone());
+        StaticVarStack::static_push_existing(self.static_var_data.clone());
+        let _guard = DebugApiContextGuard;
+
+        f()
+    }
+}
+
+/// Pops the context pushed by `DebugApiContext::with` when dropped, including during unwinding.
+struct DebugApiContextGuard;
+
+impl Drop for DebugApiContextGuard {
+    fn drop(&mut self) {
+        let _ = StaticVarStack::static_pop();
+        let _ = TxContextStack::static_pop();
+    }
+}
+
+impl DebugApi {
+    /// Creates a fresh dummy context, without installing it on any thread.
+    ///
+    /// Use `DebugApiContext::with` to run code that needs managed types, from any thread.
+    pub fn dummy_context() -> DebugApiContext {
+        DebugApiContext {
+            tx_context_arc: Arc::new(TxContext::dummy()),
+            static_var_data: Arc::default(),
+        }
+    }
+}
+
 impl std::fmt::Debug for DebugApi {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("DebugApi")
@@ -15,7 +15,7 @@ impl<VHB: VMHooksApiBackend> StaticVarApi for VMHooksApi<VHB> {
 impl<VHB: VMHooksApiBackend> StaticVarApiImpl for VMHooksApi<VHB> {
     fn with_lockable_static_buffer<R, F: FnOnce(&mut LockableStaticBuffer) -> R>(&self, f: F) -> R {
         self.with_static_data(|data| {
-            let mut lockable_static_buffer = data.lockable_static_buffer_cell.borrow_mut();
+            let mut lockable_static_buffer = data.lockable_static_buffer_cell.lock().unwrap();
             f(&mut lockable_static_buffer)
         })
     }
@@ -23,7 +23,8 @@ impl<VHB: VMHooksApiBackend> StaticVarApiImpl for VMHooksApi<VHB> {
     fn set_external_view_target_address_handle(&self, handle: RawHandle) {
         self.with_static_data(|data| {
             data.static_vars_cell
-                .borrow_mut()
+                .lock()
+                .unwrap()
                 .external_view_target_address_handle = handle;
         });
     }
@@ -31,14 +32,15 @@ impl<VHB: VMHooksApiBackend> StaticVarApiImpl for VMHooksApi<VHB> {
     fn get_external_view_target_address_handle(&self) -> RawHandle {
         self.with_static_data(|data| {
             data.static_vars_cell
-                .borrow()
+                .lock()
+                .unwrap()
                 .external_view_target_address_handle
         })
     }
 
     fn next_handle(&self) -> RawHandle {
         self.with_static_data(|data| {
-            let mut ref_tx_static_vars = data.static_vars_cell.borrow_mut();
+            let mut ref_tx_static_vars = data.static_vars_cell.lock().unwrap();
             let new_handle = ref_tx_static_vars.next_handle;
             ref_tx_static_vars.next_handle -= 1;
             new_handle
@@ -47,37 +49,43 @@ impl<VHB: VMHooksApiBackend> StaticVarApiImpl for VMHooksApi<VHB> {
 
     fn set_num_arguments(&self, num_arguments: i32) {
         self.with_static_data(|data| {
-            data.static_vars_cell.borrow_mut().num_arguments = num_arguments;
+            data.static_vars_cell.lock().unwrap().num_arguments = num_arguments;
         })
     }
 
     fn get_num_arguments(&self) -> i32 {
-        self.with_static_data(|data| data.static_vars_cell.borrow().num_arguments)
+        self.with_static_data(|data| data.static_vars_cell.lock().unwrap().num_arguments)
     }
 
     fn set_call_value_egld_handle(&self, handle: RawHandle) {
         self.with_static_data(|data| {
-            data.static_vars_cell.borrow_mut().call_value_egld_handle = handle;
+            data.static_vars_cell.lock().unwrap().call_value_egld_handle = handle;
         })
     }
 
     fn get_call_value_egld_handle(&self) -> RawHandle {
         self.with_static_data(|data| {
-            use_raw_handle(data.static_vars_cell.borrow().call_value_egld_handle)
+            use_raw_handle(data.static_vars_cell.lock().unwrap().call_value_egld_handle)
         })
     }
 
     fn set_call_value_multi_esdt_handle(&self, handle: RawHandle) {
         self.with_static_data(|data| {
             data.static_vars_cell
-                .borrow_mut()
+                .lock()
+                .unwrap()
                 .call_value_multi_esdt_handle = handle;
         })
     }
 
     fn get_call_value_multi_esdt_handle(&self) -> RawHandle {
         self.with_static_data(|data| {
-            use_raw_handle(data.static_vars_cell.borrow().call_value_multi_esdt_handle)
+            use_raw_handle(
+                data.static_vars_cell
+                    .lock()
+                    .unwrap()
+                    .call_value_multi_esdt_handle,
+            )
         })
     }
 }
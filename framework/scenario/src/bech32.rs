@@ -1,6 +1,9 @@
 use bech32::{FromBase32, ToBase32, Variant};
 use multiversx_sc::types::heap::Address;
 
+/// The human-readable part is shared with the SDK, so `set_hrp` affects both the scenario and the SDK addresses.
+pub use multiversx_sdk::data::address::{hrp, set_hrp, DEFAULT_HRP};
+
 pub fn decode(bech32_address: &str) -> Address {
     let (_, dest_address_bytes_u5, _) = bech32::decode(bech32_address).unwrap();
     let dest_address_bytes = Vec::<u8>::from_base32(&dest_address_bytes_u5).unwrap();
@@ -11,7 +14,12 @@ pub fn decode(bech32_address: &str) -> Address {
     Address::from_slice(&dest_address_bytes)
 }
 
+/// Formats the address as bech32, with the configured human-readable part (`erd` by default).
 pub fn encode(address: &Address) -> String {
-    bech32::encode("erd", address.as_bytes().to_base32(), Variant::Bech32)
+    encode_with_hrp(address, &hrp())
+}
+
+pub fn encode_with_hrp(address: &Address, hrp: &str) -> String {
+    bech32::encode(hrp, address.as_bytes().to_base32(), Variant::Bech32)
         .expect("bech32 encode error")
 }
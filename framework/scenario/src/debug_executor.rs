@@ -1,6 +1,7 @@
 mod catch_tx_panic;
 mod contract_container;
 mod contract_map;
+mod debug_fail_allocator;
 mod static_var_stack;
 mod tx_static_vars;
 
@@ -9,5 +10,9 @@ pub use contract_container::{
     contract_instance_wrapped_execution, ContractContainer, ContractContainerRef,
 };
 pub use contract_map::{ContractMap, ContractMapRef};
+pub use debug_fail_allocator::{
+    with_allocation_allowed, with_allocation_forbidden, AllocationKind, DebugFailAllocator,
+    ForbiddenAllocation,
+};
 pub use static_var_stack::{StaticVarData, StaticVarStack};
 pub use tx_static_vars::TxStaticVars;
@@ -3,7 +3,7 @@ use multiversx_chain_vm_executor::{BreakpointValue, ExecutorError, Instance, Mem
 use multiversx_sc::contract_base::CallableContract;
 use std::sync::Arc;
 
-use super::{catch_tx_panic, StaticVarStack};
+use super::{catch_tx_panic, with_allocation_forbidden, StaticVarStack};
 
 /// Contains a reference to a contract implementation.
 ///
@@ -12,6 +12,9 @@ pub struct ContractContainer {
     callable: Box<dyn CallableContract>,
     function_whitelist: Option<Vec<String>>,
     pub panic_message: bool,
+
+    /// Set for contracts built with the fail allocator, to report their allocations.
+    pub allocation_forbidden: bool,
 }
 
 impl ContractContainer {
@@ -24,6 +27,7 @@ impl ContractContainer {
             callable,
             function_whitelist,
             panic_message,
+            allocation_forbidden: false,
         }
     }
 
@@ -39,10 +43,19 @@ impl ContractContainer {
 
     pub fn call(&self, function_name: &TxFunctionName) -> bool {
         if self.validate_function_name(function_name) {
-            self.callable.call(function_name.as_str())
-        } else {
-            false
+            if self.callable.call(function_name.as_str()) {
+                return true;
+            }
+
+            // the constructor is optional, if the contract has none there is nothing to call
+            if *function_name == TxFunctionName::INIT {
+                return true;
+            }
         }
+
+        // the upgrade function is optional too,
+        // contracts without an upgrade function get their constructor called instead
+        *function_name == TxFunctionName::UPGRADE && self.call(&TxFunctionName::INIT)
     }
 }
 
@@ -77,7 +90,9 @@ impl Instance for ContractContainerRef {
         let tx_func_name = TxFunctionName::from(func_name);
 
         contract_instance_wrapped_execution(self.0.panic_message, || {
-            let call_successful = self.0.call(&tx_func_name);
+            let call_successful = with_allocation_forbidden(self.0.allocation_forbidden, || {
+                self.0.call(&tx_func_name)
+            })?;
             if call_successful {
                 Ok(())
             } else {
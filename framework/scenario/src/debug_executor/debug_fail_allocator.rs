@@ -0,0 +1,155 @@
+use multiversx_chain_vm::tx_mock::TxPanic;
+use multiversx_sc::err_msg;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+};
+
+/// The allocation operations that the wasm `FailAllocator` rejects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllocationKind {
+    Alloc,
+    AllocZeroed,
+    Realloc,
+    Dealloc,
+}
+
+impl AllocationKind {
+    /// Same message as the one signalled by the wasm `FailAllocator`.
+    pub fn error_message(&self) -> &'static str {
+        match self {
+            AllocationKind::Alloc => err_msg::MEM_ALLOC_FORBIDDEN,
+            AllocationKind::AllocZeroed => err_msg::MEM_ALLOC_ZEROED_FORBIDDEN,
+            AllocationKind::Realloc => err_msg::MEM_REALLOC_FORBIDDEN,
+            AllocationKind::Dealloc => err_msg::MEM_DEALLOC_FORBIDDEN,
+        }
+    }
+}
+
+/// The first allocation attempted by a contract that is configured with the fail allocator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ForbiddenAllocation {
+    pub kind: AllocationKind,
+    pub size: usize,
+    pub align: usize,
+}
+
+impl fmt::Display for ForbiddenAllocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} bytes, align {} (contract is configured with the fail allocator, choose another allocator in multicontract.toml)",
+            self.kind.error_message(),
+            self.size,
+            self.align
+        )
+    }
+}
+
+thread_local! {
+    static ALLOCATION_FORBIDDEN: Cell<bool> = const { Cell::new(false) };
+    static FORBIDDEN_ALLOCATION: Cell<Option<ForbiddenAllocation>> = const { Cell::new(None) };
+}
+
+fn record_allocation(kind: AllocationKind, layout: Layout) {
+    // `try_with`, since allocations can also happen while thread locals are being destroyed
+    let _ = ALLOCATION_FORBIDDEN.try_with(|forbidden| {
+        if forbidden.get() {
+            let _ = FORBIDDEN_ALLOCATION.try_with(|first| {
+                if first.get().is_none() {
+                    first.set(Some(ForbiddenAllocation {
+                        kind,
+                        size: layout.size(),
+                        align: layout.align(),
+                    }));
+                }
+            });
+        }
+    });
+}
+
+/// Global allocator for test binaries, that reports allocations performed by contracts
+/// configured with the fail allocator, instead of silently allowing them.
+///
+/// The allocations themselves are always delegated to the system allocator.
+/// Install it in the test file:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: multiversx_sc_scenario::debug_executor::DebugFailAllocator =
+///     multiversx_sc_scenario::debug_executor::DebugFailAllocator;
+/// ```
+pub struct DebugFailAllocator;
+
+unsafe impl GlobalAlloc for DebugFailAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation(AllocationKind::Alloc, layout);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocation(AllocationKind::AllocZeroed, layout);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation(AllocationKind::Realloc, layout);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record_allocation(AllocationKind::Dealloc, layout);
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// Restores the previous state on drop, so that panics do not leave allocations forbidden.
+struct AllocationForbiddenGuard {
+    previous_forbidden: bool,
+}
+
+impl AllocationForbiddenGuard {
+    fn new(forbidden: bool) -> Self {
+        AllocationForbiddenGuard {
+            previous_forbidden: ALLOCATION_FORBIDDEN.with(|flag| flag.replace(forbidden)),
+        }
+    }
+}
+
+impl Drop for AllocationForbiddenGuard {
+    fn drop(&mut self) {
+        ALLOCATION_FORBIDDEN.with(|flag| flag.set(self.previous_forbidden));
+    }
+}
+
+/// Executes a contract call, reporting the first allocation it performs, if forbidden.
+///
+/// Only has an effect if the `DebugFailAllocator` is installed as global allocator.
+pub fn with_allocation_forbidden<R, F>(forbidden: bool, f: F) -> Result<R, TxPanic>
+where
+    F: FnOnce() -> R,
+{
+    // nested contract calls have their own report
+    let previous_allocation = FORBIDDEN_ALLOCATION.with(|first| first.take());
+    let guard = AllocationForbiddenGuard::new(forbidden);
+    let result = f();
+    drop(guard);
+    let forbidden_allocation =
+        FORBIDDEN_ALLOCATION.with(|first| first.replace(previous_allocation));
+
+    if let Some(forbidden_allocation) = forbidden_allocation {
+        Err(TxPanic::user_error(&forbidden_allocation.to_string()))
+    } else {
+        Ok(result)
+    }
+}
+
+/// Allocations performed by the VM itself, while serving a VM hook, are never reported.
+pub fn with_allocation_allowed<R, F>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let _guard = AllocationForbiddenGuard::new(false);
+    f()
+}
@@ -1,4 +1,4 @@
-use std::{cell::RefCell, rc::Rc};
+use std::sync::{Arc, Mutex};
 
 use multiversx_sc::types::LockableStaticBuffer;
 
@@ -6,35 +6,40 @@ use super::TxStaticVars;
 
 #[derive(Debug, Default)]
 pub struct StaticVarData {
-    pub lockable_static_buffer_cell: RefCell<LockableStaticBuffer>,
-    pub static_vars_cell: RefCell<TxStaticVars>,
+    pub lockable_static_buffer_cell: Mutex<LockableStaticBuffer>,
+    pub static_vars_cell: Mutex<TxStaticVars>,
 }
 
 #[derive(Debug, Default)]
-pub struct StaticVarStack(Vec<Rc<StaticVarData>>);
+pub struct StaticVarStack(Vec<Arc<StaticVarData>>);
 
 thread_local!(
-    static STATIC_STACK: RefCell<StaticVarStack> = RefCell::new(StaticVarStack::default())
+    static STATIC_STACK: Mutex<StaticVarStack> = Mutex::new(StaticVarStack::default())
 );
 
 impl StaticVarStack {
-    pub fn static_peek() -> Rc<StaticVarData> {
+    pub fn static_peek() -> Arc<StaticVarData> {
         STATIC_STACK.with(|cell| {
-            let stack = cell.borrow();
+            let stack = cell.lock().unwrap();
             stack.0.last().unwrap().clone()
         })
     }
 
     pub fn static_push() {
+        Self::static_push_existing(Arc::default());
+    }
+
+    /// Pushes static data that was created elsewhere, potentially on another thread.
+    pub fn static_push_existing(static_var_data: Arc<StaticVarData>) {
         STATIC_STACK.with(|cell| {
-            let mut stack = cell.borrow_mut();
-            stack.0.push(Rc::default());
+            let mut stack = cell.lock().unwrap();
+            stack.0.push(static_var_data);
         })
     }
 
-    pub fn static_pop() -> Rc<StaticVarData> {
+    pub fn static_pop() -> Arc<StaticVarData> {
         STATIC_STACK.with(|cell| {
-            let mut stack = cell.borrow_mut();
+            let mut stack = cell.lock().unwrap();
             stack.0.pop().unwrap()
         })
     }
@@ -1,12 +1,32 @@
 mod contract_info;
 mod debugger_backend;
+mod scenario_benchmark;
+mod scenario_profile;
 mod scenario_world;
+mod scenario_world_account;
 mod scenario_world_runner;
+mod scenario_world_service;
 mod scenario_world_steps;
 mod scenario_world_steps_deprecated;
+mod scenario_world_tx;
 mod scenario_world_whitebox;
+mod storage_usage;
+mod tx_result_snapshot;
 mod whitebox_contract;
 
 pub use contract_info::ContractInfo;
+pub use scenario_benchmark::{BenchmarkAllocator, BenchmarkReport};
+pub use scenario_profile::{
+    FileProfile, ScenarioProfile, StepProfile, PROFILE_REPORT_DEFAULT_LENGTH,
+    PROFILE_SCENARIOS_ENV_VAR,
+};
 pub use scenario_world::ScenarioWorld;
+pub use scenario_world_account::WorldAccount;
+pub use scenario_world_service::ScenarioWorldService;
+pub use scenario_world_tx::{WorldTx, WorldTxStep};
+pub use storage_usage::{MapperStorageUsage, StorageUsage, StorageUsageReport};
+pub use tx_result_snapshot::{
+    assert_tx_response_snapshot, assert_tx_result_snapshot, TxLogSnapshot, TxResultSnapshot,
+    BLESS_SNAPSHOTS_ENV_VAR,
+};
 pub use whitebox_contract::WhiteboxContract;
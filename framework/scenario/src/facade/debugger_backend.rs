@@ -1,13 +1,19 @@
-use crate::scenario::{
-    self, model::*, run_trace::ScenarioTrace, run_vm::ScenarioVMRunner, ScenarioRunner,
+use crate::{
+    scenario::{
+        self, model::*, run_trace::ScenarioTrace, run_vm::ScenarioVMRunner, ScenarioRunner,
+    },
+    scenario_format::value_interpreter::FileRegistry,
 };
-use std::path::Path;
+use std::{path::Path, time::Instant};
+
+use super::scenario_profile::{ScenarioProfile, StepProfile};
 
 /// Coordinates the execution of scenario tests
 /// using the Rust implementation of the VM and direct contract execution.
 pub(crate) struct DebuggerBackend {
     pub vm_runner: ScenarioVMRunner,
     pub trace: Option<ScenarioTrace>,
+    pub profile: Option<ScenarioProfile>,
 }
 
 impl DebuggerBackend {
@@ -90,15 +96,17 @@ impl ScenarioRunner for DebuggerBackend {
 }
 
 impl DebuggerBackend {
-    pub(super) fn run_scenario_file(&mut self, steps_path: &Path) {
-        let mut scenario = scenario::parse_scenario(steps_path);
+    pub(super) fn run_scenario_file(&mut self, steps_path: &Path, file_registry: &FileRegistry) {
+        let mut scenario = scenario::parse_scenario_with_file_registry(steps_path, file_registry);
 
-        for step in &mut scenario.steps {
+        for (step_index, step) in scenario.steps.iter_mut().enumerate() {
+            self.vm_runner.last_gas_usage = None;
+            let start = Instant::now();
             match step {
                 Step::ExternalSteps(external_steps_step) => {
                     let parent_path = steps_path.parent().unwrap();
                     let new_path = parent_path.join(external_steps_step.path.as_str());
-                    self.run_scenario_file(new_path.as_path());
+                    self.run_scenario_file(new_path.as_path(), file_registry);
                 },
                 Step::SetState(set_state_step) => {
                     self.run_set_state_step(set_state_step);
@@ -125,6 +133,21 @@ impl DebuggerBackend {
                     self.run_dump_state_step();
                 },
             }
+
+            // external steps are profiled individually, in their own file
+            if let Some(profile) = &mut self.profile {
+                if !matches!(step, Step::ExternalSteps(_)) {
+                    profile.push(StepProfile::new(
+                        steps_path.to_path_buf(),
+                        step_index,
+                        step,
+                        start.elapsed(),
+                        self.vm_runner
+                            .last_gas_usage
+                            .map(|gas_usage| gas_usage.gas_used),
+                    ));
+                }
+            }
         }
     }
 }
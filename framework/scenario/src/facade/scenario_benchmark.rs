@@ -0,0 +1,219 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    scenario_model::{ScCallStep, ScQueryStep},
+    ScenarioWorld,
+};
+
+use super::scenario_world::Backend;
+
+static ALLOCATOR_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static THREAD_ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static THREAD_ALLOCATED_BYTES: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Global allocator that counts the allocations of each thread, on top of the system allocator.
+///
+/// Benchmarks only report allocations if the test crate installs it:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: BenchmarkAllocator = BenchmarkAllocator;
+/// ```
+///
+/// The counters are kept per thread, so tests running in parallel do not affect each other.
+pub struct BenchmarkAllocator;
+
+unsafe impl GlobalAlloc for BenchmarkAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+fn count_allocation(size: usize) {
+    ALLOCATOR_INSTALLED.store(true, Ordering::Relaxed);
+    // the counters are unavailable while the thread is shutting down
+    let _ = THREAD_ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+    let _ = THREAD_ALLOCATED_BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+}
+
+fn thread_allocation_counters() -> (u64, u64) {
+    (
+        THREAD_ALLOCATIONS.with(Cell::get),
+        THREAD_ALLOCATED_BYTES.with(Cell::get),
+    )
+}
+
+/// Measurements of a step executed repeatedly, see `ScenarioWorld::benchmark_sc_call`.
+///
+/// Only the execution is measured, not building the steps.
+/// Timings are those of the mock VM, meant for comparing implementations, not for estimating gas.
+#[derive(Clone, Debug)]
+pub struct BenchmarkReport {
+    pub name: String,
+    pub num_executions: usize,
+    pub duration: Duration,
+    /// `None` if the `BenchmarkAllocator` is not installed.
+    pub allocations: Option<u64>,
+    /// `None` if the `BenchmarkAllocator` is not installed.
+    pub allocated_bytes: Option<u64>,
+}
+
+impl BenchmarkReport {
+    pub fn executions_per_second(&self) -> f64 {
+        self.num_executions as f64 / self.duration.as_secs_f64()
+    }
+
+    pub fn allocations_per_execution(&self) -> Option<f64> {
+        self.allocations
+            .map(|allocations| allocations as f64 / self.num_executions as f64)
+    }
+
+    /// How many times faster than the baseline, e.g. 2.0 for twice the executions per second.
+    pub fn speedup_over(&self, baseline: &BenchmarkReport) -> f64 {
+        self.executions_per_second() / baseline.executions_per_second()
+    }
+}
+
+/// Prints e.g. `add: 1000 executions in 12.3ms, 81300 executions/s, 25.0 allocations (1240 bytes) per execution`.
+impl fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} executions in {:?}, {:.0} executions/s",
+            self.name,
+            self.num_executions,
+            self.duration,
+            self.executions_per_second()
+        )?;
+        match (self.allocations, self.allocated_bytes) {
+            (Some(allocations), Some(allocated_bytes)) => write!(
+                f,
+                ", {:.1} allocations ({:.0} bytes) per execution",
+                allocations as f64 / self.num_executions as f64,
+                allocated_bytes as f64 / self.num_executions as f64
+            ),
+            _ => write!(f, ", allocations not measured"),
+        }
+    }
+}
+
+impl ScenarioWorld {
+    /// Executes a SC call `num_executions` times and measures the throughput,
+    /// to compare the performance of alternative contract implementations under the mock VM.
+    ///
+    /// The step is built anew for each execution, from its index, so that the arguments can vary.
+    /// The expectations of the steps are checked as usual.
+    ///
+    /// Only available for the contract debugger backend.
+    pub fn benchmark_sc_call<S, F>(
+        &mut self,
+        name: &str,
+        num_executions: usize,
+        mut step_builder: F,
+    ) -> BenchmarkReport
+    where
+        S: AsMut<ScCallStep>,
+        F: FnMut(usize) -> S,
+    {
+        self.benchmark(name, num_executions, |world, index| {
+            let mut step = step_builder(index);
+            measure(|| {
+                world.sc_call(step.as_mut());
+            })
+        })
+    }
+
+    /// Same as `benchmark_sc_call`, for queries.
+    pub fn benchmark_sc_query<S, F>(
+        &mut self,
+        name: &str,
+        num_executions: usize,
+        mut step_builder: F,
+    ) -> BenchmarkReport
+    where
+        S: AsMut<ScQueryStep>,
+        F: FnMut(usize) -> S,
+    {
+        self.benchmark(name, num_executions, |world, index| {
+            let mut step = step_builder(index);
+            measure(|| {
+                world.sc_query(step.as_mut());
+            })
+        })
+    }
+
+    fn benchmark<F>(&mut self, name: &str, num_executions: usize, mut execute: F) -> BenchmarkReport
+    where
+        F: FnMut(&mut ScenarioWorld, usize) -> Measurement,
+    {
+        assert!(
+            num_executions > 0,
+            "benchmark `{name}` needs at least one execution"
+        );
+        assert!(
+            matches!(self.backend, Backend::Debugger(_)),
+            "benchmarks are only available for the contract debugger backend"
+        );
+
+        let mut total = Measurement::default();
+        for index in 0..num_executions {
+            let measurement = execute(self, index);
+            total.duration += measurement.duration;
+            total.allocations += measurement.allocations;
+            total.allocated_bytes += measurement.allocated_bytes;
+        }
+
+        let allocator_installed = ALLOCATOR_INSTALLED.load(Ordering::Relaxed);
+        BenchmarkReport {
+            name: name.to_string(),
+            num_executions,
+            duration: total.duration,
+            allocations: allocator_installed.then_some(total.allocations),
+            allocated_bytes: allocator_installed.then_some(total.allocated_bytes),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Measurement {
+    duration: Duration,
+    allocations: u64,
+    allocated_bytes: u64,
+}
+
+fn measure<F: FnOnce()>(f: F) -> Measurement {
+    let (allocations_before, allocated_bytes_before) = thread_allocation_counters();
+    let start = Instant::now();
+    f();
+    let duration = start.elapsed();
+    let (allocations_after, allocated_bytes_after) = thread_allocation_counters();
+    Measurement {
+        duration,
+        allocations: allocations_after - allocations_before,
+        allocated_bytes: allocated_bytes_after - allocated_bytes_before,
+    }
+}
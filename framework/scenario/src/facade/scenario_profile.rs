@@ -0,0 +1,200 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::scenario_model::Step;
+
+/// Setting this environment variable (to any value) makes `ScenarioWorld::run_scenarios`
+/// print a profiling report of the slowest steps and files, after all scenarios ran.
+pub const PROFILE_SCENARIOS_ENV_VAR: &str = "SC_PROFILE_SCENARIOS";
+
+/// Number of entries in each section of the report printed by `run_scenarios`.
+pub const PROFILE_REPORT_DEFAULT_LENGTH: usize = 20;
+
+/// Timing of a single scenario step.
+#[derive(Clone, Debug)]
+pub struct StepProfile {
+    /// The file the step belongs to. Steps from external files are attributed to the external file.
+    pub file: PathBuf,
+    /// Index of the step within its file.
+    pub step_index: usize,
+    /// The step type, as it appears in the scenario JSON, e.g. `scCall`.
+    pub step_type: &'static str,
+    /// The step id, empty for steps that have none.
+    pub id: String,
+    /// Wall-clock time of the step.
+    pub duration: Duration,
+    /// The gas limit of the transaction, for steps that have one.
+    pub gas_limit: Option<u64>,
+    /// The gas actually consumed by the transaction.
+    ///
+    /// Only available if the world has a gas schedule, otherwise the mock VM does not meter gas.
+    pub gas_used: Option<u64>,
+}
+
+impl StepProfile {
+    pub(crate) fn new(
+        file: PathBuf,
+        step_index: usize,
+        step: &Step,
+        duration: Duration,
+        gas_used: Option<u64>,
+    ) -> Self {
+        let (step_type, id, gas_limit) = match step {
+            Step::ExternalSteps(_) => ("externalSteps", String::new(), None),
+            Step::SetState(_) => ("setState", String::new(), None),
+            Step::ScCall(step) => ("scCall", step.id.clone(), Some(step.tx.gas_limit.value)),
+            Step::ScQuery(step) => ("scQuery", step.id.clone(), None),
+            Step::ScDeploy(step) => ("scDeploy", step.id.clone(), Some(step.tx.gas_limit.value)),
+            Step::Transfer(step) => ("transfer", step.id.clone(), Some(step.tx.gas_limit.value)),
+            Step::ValidatorReward(step) => ("validatorReward", step.id.clone(), None),
+            Step::CheckState(_) => ("checkState", String::new(), None),
+            Step::DumpState(_) => ("dumpState", String::new(), None),
+        };
+        StepProfile {
+            file,
+            step_index,
+            step_type,
+            id,
+            duration,
+            gas_limit,
+            gas_used,
+        }
+    }
+}
+
+/// Totals of all the steps in a scenario file.
+#[derive(Clone, Debug)]
+pub struct FileProfile {
+    pub file: PathBuf,
+    pub num_steps: usize,
+    pub duration: Duration,
+    pub gas_limit: u64,
+    /// Only counts the steps where gas was metered.
+    pub gas_used: u64,
+}
+
+/// Step timings collected while running scenarios, used to find the hotspots of large scenario suites.
+#[derive(Clone, Debug, Default)]
+pub struct ScenarioProfile {
+    steps: Vec<StepProfile>,
+}
+
+impl ScenarioProfile {
+    pub fn new() -> Self {
+        ScenarioProfile::default()
+    }
+
+    pub fn push(&mut self, step_profile: StepProfile) {
+        self.steps.push(step_profile);
+    }
+
+    /// Adds all the steps of another profile, e.g. of another scenario run.
+    pub fn merge(&mut self, other: ScenarioProfile) {
+        self.steps.extend(other.steps);
+    }
+
+    /// All profiled steps, in the order they ran.
+    pub fn steps(&self) -> &[StepProfile] {
+        &self.steps
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.steps.iter().map(|step| step.duration).sum()
+    }
+
+    /// The slowest steps, slowest first.
+    pub fn slowest_steps(&self, limit: usize) -> Vec<&StepProfile> {
+        let mut steps: Vec<&StepProfile> = self.steps.iter().collect();
+        steps.sort_by(|a, b| b.duration.cmp(&a.duration));
+        steps.truncate(limit);
+        steps
+    }
+
+    /// Per-file totals, slowest file first.
+    pub fn slowest_files(&self, limit: usize) -> Vec<FileProfile> {
+        let mut files: HashMap<&PathBuf, FileProfile> = HashMap::new();
+        for step in &self.steps {
+            let file_profile = files.entry(&step.file).or_insert_with(|| FileProfile {
+                file: step.file.clone(),
+                num_steps: 0,
+                duration: Duration::ZERO,
+                gas_limit: 0,
+                gas_used: 0,
+            });
+            file_profile.num_steps += 1;
+            file_profile.duration += step.duration;
+            file_profile.gas_limit = file_profile
+                .gas_limit
+                .saturating_add(step.gas_limit.unwrap_or_default());
+            file_profile.gas_used = file_profile
+                .gas_used
+                .saturating_add(step.gas_used.unwrap_or_default());
+        }
+
+        let mut files: Vec<FileProfile> = files.into_values().collect();
+        files.sort_by(|a, b| b.duration.cmp(&a.duration));
+        files.truncate(limit);
+        files
+    }
+
+    /// Human-readable report of the slowest steps and files.
+    pub fn report(&self, limit: usize) -> String {
+        let mut report = String::new();
+        writeln!(
+            report,
+            "Scenario profile: {} steps, {:?} in total.",
+            self.steps.len(),
+            self.total_duration()
+        )
+        .unwrap();
+
+        writeln!(report, "\nSlowest files:").unwrap();
+        for file in self.slowest_files(limit) {
+            writeln!(
+                report,
+                "{:>12?}  {:>4} steps  gas limit {:>14}  gas used {:>14}  {}",
+                file.duration,
+                file.num_steps,
+                file.gas_limit,
+                file.gas_used,
+                file.file.display()
+            )
+            .unwrap();
+        }
+
+        writeln!(report, "\nSlowest steps:").unwrap();
+        for step in self.slowest_steps(limit) {
+            let gas_limit = step
+                .gas_limit
+                .map(|gas_limit| gas_limit.to_string())
+                .unwrap_or_default();
+            let gas_used = step
+                .gas_used
+                .map(|gas_used| gas_used.to_string())
+                .unwrap_or_default();
+            writeln!(
+                report,
+                "{:>12?}  {:<15}  gas limit {:>14}  gas used {:>14}  {}#{} {}",
+                step.duration,
+                step.step_type,
+                gas_limit,
+                gas_used,
+                step.file.display(),
+                step.step_index,
+                step.id
+            )
+            .unwrap();
+        }
+        report
+    }
+}
+
+impl fmt::Display for ScenarioProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.report(PROFILE_REPORT_DEFAULT_LENGTH))
+    }
+}
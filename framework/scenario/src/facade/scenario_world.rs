@@ -1,5 +1,11 @@
 use multiversx_chain_scenario_format::interpret_trait::InterpretableFrom;
-use multiversx_chain_vm::world_mock::BlockchainState;
+use multiversx_chain_vm::world_mock::{
+    BlockchainState, CommitObserver, GasSchedule, NewAddressGenerator, ProtocolConfig, ShardModel,
+};
+use multiversx_sc_meta::{
+    abi_decode::StorageDecoder, cmd::contract::output_contract::ContractAllocator,
+};
+use multiversx_sdk::data::network_config::NetworkConfig;
 
 use crate::{
     api::DebugApi,
@@ -8,14 +14,27 @@ use crate::{
         api,
         contract_base::{CallableContractBuilder, ContractAbiProvider},
     },
-    scenario::{run_trace::ScenarioTrace, run_vm::ScenarioVMRunner},
-    scenario_format::{interpret_trait::InterpreterContext, value_interpreter::interpret_string},
-    scenario_model::BytesValue,
+    scenario::{
+        check_scenarios_parse,
+        run_trace::ScenarioTrace,
+        run_vm::{GasLimits, ScenarioVMRunner},
+    },
+    scenario_format::{
+        interpret_trait::InterpreterContext,
+        value_interpreter::{interpret_string, FileRegistry},
+    },
+    scenario_model::{AddressKey, BytesKey, BytesValue, TxResponse},
     vm_go_tool::run_vm_go_tool,
 };
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use super::debugger_backend::DebuggerBackend;
+use super::{
+    debugger_backend::DebuggerBackend,
+    scenario_profile::{ScenarioProfile, PROFILE_SCENARIOS_ENV_VAR},
+};
 
 /// A facade for contracts tests.
 ///
@@ -25,7 +44,9 @@ use super::debugger_backend::DebuggerBackend;
 /// but that one will be refactored and broken up into smaller pieces.
 pub struct ScenarioWorld {
     pub(crate) current_dir: PathBuf,
+    pub(crate) file_registry: FileRegistry,
     pub(crate) backend: Backend,
+    pub(crate) bech32_hrp: Option<String>,
 }
 
 pub(crate) enum Backend {
@@ -43,10 +64,13 @@ impl ScenarioWorld {
     pub fn debugger() -> Self {
         ScenarioWorld {
             current_dir: std::env::current_dir().unwrap(),
+            file_registry: FileRegistry::new(),
             backend: Backend::Debugger(DebuggerBackend {
                 vm_runner: ScenarioVMRunner::new(),
                 trace: None,
+                profile: None,
             }),
+            bech32_hrp: None,
         }
     }
 
@@ -58,7 +82,9 @@ impl ScenarioWorld {
     pub fn vm_go() -> Self {
         ScenarioWorld {
             current_dir: std::env::current_dir().unwrap(),
+            file_registry: FileRegistry::new(),
             backend: Backend::VmGoBackend,
+            bech32_hrp: None,
         }
     }
 
@@ -70,14 +96,86 @@ impl ScenarioWorld {
         absolute_path.push(relative_path);
         match self.backend {
             Backend::Debugger(mut debugger) => {
-                debugger.run_scenario_file(&absolute_path);
+                debugger.run_scenario_file(&absolute_path, &self.file_registry);
+            },
+            Backend::VmGoBackend => {
+                run_vm_go_tool(&absolute_path);
+            },
+        }
+    }
+
+    /// Runs a scenario file, like `run`, and also times each of its steps.
+    ///
+    /// Only the debugger backend can be profiled, the profile is empty for the other backends.
+    pub fn run_profiled<P: AsRef<Path>>(self, relative_path: P) -> ScenarioProfile {
+        let mut absolute_path = self.current_dir.clone();
+        absolute_path.push(relative_path);
+        match self.backend {
+            Backend::Debugger(mut debugger) => {
+                debugger.profile = Some(ScenarioProfile::new());
+                debugger.run_scenario_file(&absolute_path, &self.file_registry);
+                debugger.profile.take().unwrap_or_default()
             },
             Backend::VmGoBackend => {
                 run_vm_go_tool(&absolute_path);
+                ScenarioProfile::new()
             },
         }
     }
 
+    /// Runs several scenario files, each of them in a fresh world produced by `world_builder`.
+    ///
+    /// All files are parsed upfront. If any of them is malformed,
+    /// nothing is run and the test fails with a report listing all failing files.
+    ///
+    /// If the `SC_PROFILE_SCENARIOS` environment variable is set,
+    /// a report of the slowest steps and files is printed at the end.
+    pub fn run_scenarios<P, F>(world_builder: F, relative_paths: &[P])
+    where
+        P: AsRef<Path>,
+        F: Fn() -> ScenarioWorld,
+    {
+        if std::env::var(PROFILE_SCENARIOS_ENV_VAR).is_ok() {
+            let profile = Self::run_scenarios_profiled(world_builder, relative_paths);
+            println!("{profile}");
+            return;
+        }
+
+        for absolute_path in Self::scenario_paths_checked(&world_builder, relative_paths) {
+            world_builder().run(absolute_path);
+        }
+    }
+
+    /// Runs several scenario files, like `run_scenarios`,
+    /// and returns the timings of all their steps, to help find the hotspots of large scenario suites.
+    pub fn run_scenarios_profiled<P, F>(world_builder: F, relative_paths: &[P]) -> ScenarioProfile
+    where
+        P: AsRef<Path>,
+        F: Fn() -> ScenarioWorld,
+    {
+        let mut profile = ScenarioProfile::new();
+        for absolute_path in Self::scenario_paths_checked(&world_builder, relative_paths) {
+            profile.merge(world_builder().run_profiled(absolute_path));
+        }
+        profile
+    }
+
+    fn scenario_paths_checked<P, F>(world_builder: &F, relative_paths: &[P]) -> Vec<PathBuf>
+    where
+        P: AsRef<Path>,
+        F: Fn() -> ScenarioWorld,
+    {
+        let current_dir = world_builder().current_dir;
+        let absolute_paths: Vec<PathBuf> = relative_paths
+            .iter()
+            .map(|relative_path| current_dir.join(relative_path))
+            .collect();
+        if let Err(report) = check_scenarios_parse(&absolute_paths) {
+            panic!("{report}");
+        }
+        absolute_paths
+    }
+
     pub(crate) fn get_debugger_backend(&self) -> &DebuggerBackend {
         if let Backend::Debugger(debugger) = &self.backend {
             debugger
@@ -106,20 +204,225 @@ impl ScenarioWorld {
             .state
     }
 
+    /// Configures how the addresses of newly deployed contracts are derived,
+    /// whenever no explicit new address was set for the creator and nonce.
+    ///
+    /// Explicit new addresses (from `setState`) always take precedence.
+    pub fn new_address_generator<G>(&mut self, generator: G) -> &mut Self
+    where
+        G: NewAddressGenerator + 'static,
+    {
+        self.get_mut_state().new_address_generator = Some(Arc::new(generator));
+        self
+    }
+
+    /// Registers an observer, to be notified each time the updates of a transaction are committed.
+    ///
+    /// It receives the addresses of the touched accounts and the resulting state,
+    /// so it can keep custom indexes or check invariants along the way.
+    pub fn add_commit_observer<O>(&mut self, observer: O) -> &mut Self
+    where
+        O: CommitObserver + 'static,
+    {
+        self.get_mut_state().add_commit_observer(Arc::new(observer));
+        self
+    }
+
+    /// Number of decimals of a token, as kept by the mock token registry.
+    ///
+    /// Synchronous shortcut for tests, on-chain the decimals are only available
+    /// via an asynchronous `getTokenProperties` call to the ESDT system SC.
+    pub fn get_token_decimals(&self, token_identifier_expr: &str) -> Option<u32> {
+        let token_identifier = BytesKey::from(token_identifier_expr);
+        self.get_state().get_token_decimals(&token_identifier.value)
+    }
+
+    /// Minted minus burnt amount of a token, as tracked by the mock token registry.
+    ///
+    /// Takes into account the initial supply at issue and the local mint/burn builtin functions.
+    pub fn get_token_supply(&self, token_identifier_expr: &str) -> Option<num_bigint::BigUint> {
+        let token_identifier = BytesKey::from(token_identifier_expr);
+        self.get_state().get_token_supply(&token_identifier.value)
+    }
+
+    /// Checks whether an address is the current owner of a token, as kept by the mock token registry.
+    ///
+    /// Useful for checking the outcome of a `transferOwnership` call.
+    pub fn is_token_owner(&self, token_identifier_expr: &str, address_expr: &str) -> bool {
+        let token_identifier = BytesKey::from(token_identifier_expr);
+        let address = AddressKey::from(address_expr).to_vm_address();
+        self.get_state().get_token_owner(&token_identifier.value) == Some(address)
+    }
+
+    /// Sets the number of shards that addresses are split into, 3 by default.
+    ///
+    /// Affects the shard ids reported to contracts.
+    pub fn num_shards(&mut self, num_shards: u32) -> &mut Self {
+        self.get_mut_state().shard_model = ShardModel::new(num_shards);
+        self
+    }
+
+    /// Simulates the latency of async calls between shards, as given by `num_shards`.
+    ///
+    /// Async calls and promises to contracts in other shards are no longer executed right away,
+    /// but queued and executed in the next block, while their callbacks are executed in the block after.
+    /// Use `next_block` to advance through the blocks, and check the intermediate state in between.
+    ///
+    /// The EGLD and ESDT value of such a call leaves the sender as soon as the call is sent,
+    /// and only reaches the destination once the call is executed there, in the next block.
+    /// If the call fails, the value is refunded to the sender together with the callback.
+    pub fn simulate_cross_shard_async(&mut self) -> &mut Self {
+        self.get_mut_state().cross_shard_queue.enabled = true;
+        self
+    }
+
+    /// Starts a new block, by incrementing the block nonce and round,
+    /// then executes the cross-shard async calls and callbacks that are due, in the order in which they were sent.
+    ///
+    /// Returns their responses, in the same order.
+    pub fn next_block(&mut self) -> Vec<TxResponse> {
+        let blockchain_mock = &mut self.get_mut_debugger_backend().vm_runner.blockchain_mock;
+        let state = &mut blockchain_mock.state;
+        state.previous_block_info = state.current_block_info.clone();
+        state.current_block_info.block_nonce += 1;
+        state.current_block_info.block_round += 1;
+        blockchain_mock
+            .vm
+            .execute_due_cross_shard_steps(state)
+            .into_iter()
+            .map(TxResponse::from_tx_result)
+            .collect()
+    }
+
+    /// Number of cross-shard async calls and callbacks waiting for a later block.
+    pub fn num_pending_cross_shard_calls(&self) -> usize {
+        self.get_state().cross_shard_queue.len()
+    }
+
+    /// Configures the epochs at which protocol features become active.
+    ///
+    /// Combined with changing the block epoch in `setState`, allows testing behavior across a protocol upgrade boundary.
+    pub fn protocol_config(&mut self, protocol_config: ProtocolConfig) -> &mut Self {
+        self.get_mut_state().protocol_config = protocol_config;
+        self
+    }
+
+    /// Enables gas metering in the contract debugger: storage access, managed type operations,
+    /// builtin functions and calls to other contracts consume gas from the gas limit of the transaction,
+    /// which fails with status 5 if it runs out of gas.
+    ///
+    /// Once enabled, the `gas` field of the expected transaction results, i.e. the gas remaining, is checked too.
+    pub fn gas_schedule(&mut self, gas_schedule: GasSchedule) -> &mut Self {
+        self.get_mut_state().gas_schedule = Some(gas_schedule);
+        self
+    }
+
+    /// Restricts the addresses that transactions can be sent from, to keep tests honest about who can call what.
+    ///
+    /// Calls, deploys and transfers from any other address fail. Can be called several times, the lists are merged.
+    pub fn allowed_senders(&mut self, sender_exprs: &[&str]) -> &mut Self {
+        let allowed_senders = self
+            .get_mut_debugger_backend()
+            .vm_runner
+            .allowed_senders
+            .get_or_insert_with(Default::default);
+        for sender_expr in sender_exprs {
+            allowed_senders.insert(AddressKey::from(*sender_expr).to_vm_address());
+        }
+        self
+    }
+
+    /// Removes the sender restrictions set with `allowed_senders`.
+    pub fn allow_any_sender(&mut self) -> &mut Self {
+        self.get_mut_debugger_backend().vm_runner.allowed_senders = None;
+        self
+    }
+
+    /// Rejects transactions whose gas limit is above the given protocol maximums,
+    /// per transaction and summed up over the current block.
+    ///
+    /// Keeps scenarios representative of mainnet constraints. A new block starts whenever the block nonce changes.
+    pub fn enforce_gas_limits(&mut self, gas_limits: GasLimits) -> &mut Self {
+        self.get_mut_debugger_backend().vm_runner.gas_limits = Some(gas_limits);
+        self
+    }
+
+    /// Removes the gas limit restrictions set with `enforce_gas_limits`.
+    pub fn ignore_gas_limits(&mut self) -> &mut Self {
+        self.get_mut_debugger_backend().vm_runner.gas_limits = None;
+        self
+    }
+
+    /// Runs the transactions under the rules of a custom network, e.g. a sovereign chain.
+    ///
+    /// Transactions then need to pay at least the minimum gas price,
+    /// and their gas limit needs to cover the minimum gas limit plus the cost of their data.
+    /// The same config can be given to an interactor, via `Interactor::with_network_config`.
+    pub fn network_config(&mut self, network_config: NetworkConfig) -> &mut Self {
+        self.get_mut_debugger_backend().vm_runner.network_config = Some(network_config);
+        self
+    }
+
+    /// Fails tests that rely on storage keys holding an empty value.
+    ///
+    /// Just like the protocol, the VM never keeps empty storage values: writing one deletes the key.
+    /// By default, empty values in `setState` steps are dropped silently,
+    /// with this option on, they fail the step instead.
+    pub fn forbid_empty_storage_values(&mut self) -> &mut Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .forbid_empty_storage_values = true;
+        self
+    }
+
     pub fn start_trace(&mut self) -> &mut Self {
         self.get_mut_debugger_backend().trace = Some(ScenarioTrace::default());
         self
     }
 
+    /// Overrides the workspace root, which is otherwise deduced from the location of the test executable.
+    ///
+    /// Affects `set_current_dir_from_workspace` and `temp_dir`,
+    /// as well as files referenced from relative context paths.
+    pub fn set_workspace_root<P: AsRef<Path>>(&mut self, workspace_root: P) -> &mut Self {
+        self.file_registry = self
+            .file_registry
+            .clone()
+            .with_workspace_root(workspace_root.as_ref().to_path_buf());
+        self
+    }
+
+    /// The configured workspace root, or the one deduced from the location of the test executable.
+    pub fn workspace_root(&self) -> PathBuf {
+        self.file_registry
+            .workspace_root()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(find_workspace)
+    }
+
     /// Tells the tests where the crate lies relative to the workspace.
     /// This ensures that the paths are set correctly, including in debug mode.
     pub fn set_current_dir_from_workspace(&mut self, relative_path: &str) -> &mut Self {
-        let mut path = find_workspace();
+        let mut path = self.workspace_root();
         path.push(relative_path);
         self.current_dir = path;
         self
     }
 
+    /// Creates (if needed) and returns a temporary directory dedicated to one test,
+    /// under `target/tmp` in the workspace.
+    ///
+    /// Useful for tests that write files, e.g. traces, so that they do not interfere with each other.
+    pub fn temp_dir(&self, test_name: &str) -> PathBuf {
+        let mut path = self.workspace_root();
+        path.push("target");
+        path.push("tmp");
+        path.push(test_name);
+        std::fs::create_dir_all(&path)
+            .unwrap_or_else(|err| panic!("could not create temporary directory {path:?}: {err}"));
+        path
+    }
+
     pub fn current_dir(&self) -> &PathBuf {
         &self.current_dir
     }
@@ -128,6 +431,12 @@ impl ScenarioWorld {
         InterpreterContext::default()
             .with_dir(self.current_dir.clone())
             .with_allowed_missing_files()
+            .with_file_registry(self.file_registry.clone())
+    }
+
+    /// Holds the contract code files loaded so far, and resolves new ones.
+    pub fn file_registry(&self) -> &FileRegistry {
+        &self.file_registry
     }
 
     /// Convenient way of creating a code expression based on the current context
@@ -142,11 +451,36 @@ impl ScenarioWorld {
         contract_container: ContractContainer,
     ) {
         let contract_bytes = interpret_string(expression, &self.interpreter_context());
+        self.register_contract_code_container(contract_bytes, contract_container);
+    }
+
+    /// Same as `register_contract_container`, but the contract is identified directly by its code.
+    pub fn register_contract_code_container<C: Into<Vec<u8>>>(
+        &mut self,
+        code: C,
+        contract_container: ContractContainer,
+    ) {
         self.get_mut_debugger_backend()
             .vm_runner
             .contract_map_ref
             .lock()
-            .register_contract(contract_bytes, contract_container);
+            .register_contract(code.into(), contract_container);
+    }
+
+    /// Links raw contract code to a contract implementation, instead of a file.
+    ///
+    /// Useful for code generated in the test itself, e.g. code that a factory contract
+    /// keeps in storage and deploys child contracts from.
+    /// The same bytes can then be used as code in `setState` or in deploys.
+    pub fn register_contract_code<C, B>(&mut self, code: C, contract_builder: B)
+    where
+        C: Into<Vec<u8>>,
+        B: CallableContractBuilder,
+    {
+        self.register_contract_code_container(
+            code,
+            ContractContainer::new(contract_builder.new_contract_obj::<DebugApi>(), None, false),
+        )
     }
 
     /// Links a contract path in a test to a contract implementation.
@@ -198,14 +532,26 @@ impl ScenarioWorld {
             contract_builder.new_contract_obj::<DebugApi>()
         };
 
-        self.register_contract_container(
-            expression,
-            ContractContainer::new(
-                contract_obj,
-                Some(sub_contract.all_exported_function_names()),
-                sub_contract.settings.panic_message,
-            ),
+        let mut contract_container = ContractContainer::new(
+            contract_obj,
+            Some(sub_contract.all_exported_function_names()),
+            sub_contract.settings.panic_message,
         );
+        contract_container.allocation_forbidden =
+            sub_contract.settings.allocator == ContractAllocator::AllocationForbidden;
+
+        self.register_contract_container(expression, contract_container);
+    }
+
+    /// Links a contract path in a test to the ABI of the contract,
+    /// so that storage mismatches in `checkState` steps get shown decoded:
+    /// storage mapper name, key arguments and value.
+    pub fn register_contract_abi<Abi: ContractAbiProvider>(&mut self, expression: &str) {
+        let contract_bytes = interpret_string(expression, &self.interpreter_context());
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .storage_decoders
+            .insert(contract_bytes, StorageDecoder::from_abi(&Abi::abi()));
     }
 
     /// Exports current scenario to a JSON file, as created.
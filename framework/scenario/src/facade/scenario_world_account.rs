@@ -0,0 +1,268 @@
+use crate::{
+    bech32,
+    facade::ScenarioWorld,
+    multiversx_sc::{
+        codec::{top_encode_to_vec_u8_or_panic, TopEncode},
+        types::heap::Address,
+    },
+    scenario::model::{
+        Account, AddressKey, AddressValue, BigUintValue, BytesKey, BytesValue, SetStateStep,
+        U64Value,
+    },
+    scenario_format::interpret_trait::{InterpretableFrom, InterpreterContext},
+    whitebox_legacy::account_as_raw,
+};
+
+const ADDRESS_PREFIX: &str = "address:";
+
+impl ScenarioWorld {
+    /// Named test wallet, e.g. `world.account("alice")`, with the address `address:alice`.
+    ///
+    /// The account is created empty the first time it is retrieved.
+    /// Its balances can then be set fluently, each change being recorded as a `setState` step.
+    ///
+    /// Full scenario address expressions (e.g. `"address:owner"`) are also accepted.
+    pub fn account(&mut self, name: &str) -> WorldAccount<'_> {
+        let address_key = if name.contains(':') {
+            AddressKey::from(name)
+        } else {
+            AddressKey::from(format!("{ADDRESS_PREFIX}{name}"))
+        };
+
+        if !self
+            .get_state()
+            .account_exists(&address_key.to_vm_address())
+        {
+            self.set_state_step(
+                SetStateStep::new().put_account(address_key.clone(), Account::new()),
+            );
+        }
+
+        WorldAccount {
+            world: self,
+            address_key,
+        }
+    }
+
+    /// Uses a different bech32 human-readable part when this world displays addresses, e.g. `test` on a sovereign chain.
+    ///
+    /// Unlike `bech32::set_hrp`, only affects this world.
+    pub fn set_bech32_hrp(&mut self, hrp: &str) -> &mut Self {
+        self.bech32_hrp = Some(hrp.to_string());
+        self
+    }
+
+    /// The bech32 human-readable part of this world, or the process-wide one, if none was set.
+    pub fn bech32_hrp(&self) -> String {
+        self.bech32_hrp.clone().unwrap_or_else(bech32::hrp)
+    }
+
+    /// Formats an address as bech32, with the human-readable part of this world.
+    pub fn bech32_encode(&self, address: &Address) -> String {
+        bech32::encode_with_hrp(address, &self.bech32_hrp())
+    }
+
+    /// Gives an account an NFT/SFT balance, with the attributes serialized via the codec.
+    ///
+    /// Shorthand for `world.account(address_expr).nft_balance(...)`.
+    pub fn set_nft_balance<K, V, T>(
+        &mut self,
+        address_expr: &str,
+        token_id_expr: K,
+        nonce: u64,
+        balance_expr: V,
+        attributes: &T,
+    ) -> &mut Self
+    where
+        BytesKey: From<K>,
+        BigUintValue: From<V>,
+        T: TopEncode,
+    {
+        self.account(address_expr)
+            .nft_balance(token_id_expr, nonce, balance_expr, attributes);
+        self
+    }
+
+    /// Gives an account an NFT/SFT balance, together with all the token metadata.
+    ///
+    /// Shorthand for `world.account(address_expr).nft_balance_all_properties(...)`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_nft_balance_all_properties<K, V, T>(
+        &mut self,
+        address_expr: &str,
+        token_id_expr: K,
+        nonce: u64,
+        balance_expr: V,
+        attributes: &T,
+        royalties: u64,
+        creator: Option<&Address>,
+        hash: Option<&[u8]>,
+        uris: &[&[u8]],
+    ) -> &mut Self
+    where
+        BytesKey: From<K>,
+        BigUintValue: From<V>,
+        T: TopEncode,
+    {
+        self.account(address_expr).nft_balance_all_properties(
+            token_id_expr,
+            nonce,
+            balance_expr,
+            attributes,
+            royalties,
+            creator,
+            hash,
+            uris,
+        );
+        self
+    }
+}
+
+/// Handle to a test wallet, obtained via `ScenarioWorld::account`.
+///
+/// The setters only change the given fields, everything else in the account is kept as is.
+pub struct WorldAccount<'w> {
+    world: &'w mut ScenarioWorld,
+    address_key: AddressKey,
+}
+
+impl<'w> WorldAccount<'w> {
+    pub fn address(&self) -> Address {
+        self.address_key.value.clone()
+    }
+
+    pub fn address_value(&self) -> AddressValue {
+        AddressValue::from(&self.address_key)
+    }
+
+    /// The account address as bech32, with the human-readable part of the world.
+    pub fn bech32(&self) -> String {
+        self.world.bech32_encode(&self.address_key.value)
+    }
+
+    pub fn nonce<V>(self, nonce: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.update(|account| account.nonce(nonce))
+    }
+
+    pub fn balance<V>(self, balance_expr: V) -> Self
+    where
+        BigUintValue: From<V>,
+    {
+        self.update(|account| account.balance(balance_expr))
+    }
+
+    pub fn esdt_balance<K, V>(self, token_id_expr: K, balance_expr: V) -> Self
+    where
+        BytesKey: From<K>,
+        BigUintValue: From<V>,
+    {
+        self.update(|account| account.esdt_balance(token_id_expr, balance_expr))
+    }
+
+    pub fn esdt_nft_balance<K, N, V, T>(
+        self,
+        token_id_expr: K,
+        nonce_expr: N,
+        balance_expr: V,
+        opt_attributes_expr: Option<T>,
+    ) -> Self
+    where
+        N: Clone,
+        BytesKey: From<K>,
+        U64Value: From<N>,
+        BigUintValue: From<V>,
+        BytesValue: From<T>,
+    {
+        self.update(|account| {
+            account.esdt_nft_balance(token_id_expr, nonce_expr, balance_expr, opt_attributes_expr)
+        })
+    }
+
+    /// Sets an NFT/SFT balance, the attributes being any type that the codec can serialize.
+    pub fn nft_balance<K, V, T>(
+        self,
+        token_id_expr: K,
+        nonce: u64,
+        balance_expr: V,
+        attributes: &T,
+    ) -> Self
+    where
+        BytesKey: From<K>,
+        BigUintValue: From<V>,
+        T: TopEncode,
+    {
+        let attributes = top_encode_to_vec_u8_or_panic(attributes);
+        self.esdt_nft_balance(token_id_expr, nonce, balance_expr, Some(attributes))
+    }
+
+    /// Sets an NFT/SFT balance, together with the token metadata: attributes, royalties, creator, hash and uris.
+    #[allow(clippy::too_many_arguments)]
+    pub fn nft_balance_all_properties<K, V, T>(
+        self,
+        token_id_expr: K,
+        nonce: u64,
+        balance_expr: V,
+        attributes: &T,
+        royalties: u64,
+        creator: Option<&Address>,
+        hash: Option<&[u8]>,
+        uris: &[&[u8]],
+    ) -> Self
+    where
+        BytesKey: From<K>,
+        BigUintValue: From<V>,
+        T: TopEncode,
+    {
+        let attributes = BytesValue::from(top_encode_to_vec_u8_or_panic(attributes));
+        let creator = creator.map(|creator| BytesValue::from(creator.as_bytes()));
+        let hash = hash.map(BytesValue::from);
+        let uris = uris.iter().map(|uri| BytesValue::from(*uri)).collect();
+        self.update(|account| {
+            account.esdt_nft_all_properties(
+                token_id_expr,
+                nonce,
+                balance_expr,
+                Some(attributes),
+                royalties,
+                creator,
+                hash,
+                uris,
+            )
+        })
+    }
+
+    pub fn esdt_roles<K>(self, token_id_expr: K, roles: Vec<String>) -> Self
+    where
+        BytesKey: From<K>,
+    {
+        self.update(|account| account.esdt_roles(token_id_expr, roles))
+    }
+
+    /// Reads the current state of the account, applies the change and writes it back.
+    fn update<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Account) -> Account,
+    {
+        let account_data = self
+            .world
+            .get_state()
+            .accounts
+            .get(&self.address_key.to_vm_address())
+            .expect("test wallet account missing");
+        let account =
+            Account::interpret_from(account_as_raw(account_data), &InterpreterContext::default());
+
+        self.world
+            .set_state_step(SetStateStep::new().put_account(self.address_key.clone(), f(account)));
+        self
+    }
+}
+
+impl<'w> From<&WorldAccount<'w>> for AddressValue {
+    fn from(from: &WorldAccount<'w>) -> Self {
+        from.address_value()
+    }
+}
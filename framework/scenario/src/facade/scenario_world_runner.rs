@@ -21,6 +21,23 @@ impl ScenarioWorld {
     }
 }
 
+impl ScenarioWorld {
+    /// Runs a transfer step and saves its response in the step, which `run_transfer_step` cannot do.
+    pub(crate) fn run_transfer_step_update_results(&mut self, step: &mut TransferStep) {
+        match &mut self.backend {
+            Backend::Debugger(cd_debugger) => {
+                cd_debugger.vm_runner.perform_transfer_update_results(step);
+                if let Some(trace) = &mut cd_debugger.trace {
+                    trace.run_transfer_step(step);
+                }
+            },
+            Backend::VmGoBackend => {
+                panic!("the VM Go backend does not support step-by-step execution")
+            },
+        }
+    }
+}
+
 impl ScenarioRunner for ScenarioWorld {
     fn run_external_steps(&mut self, step: &ExternalStepsStep) {
         self.for_each_runner_mut(|runner| runner.run_external_steps(step));
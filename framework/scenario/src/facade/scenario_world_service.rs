@@ -0,0 +1,107 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Mutex},
+    thread,
+};
+
+use tokio::sync::oneshot;
+
+use super::ScenarioWorld;
+
+type WorldJob = Box<dyn FnOnce(&mut ScenarioWorld) + Send>;
+
+/// A `Send + Sync` handle to a [`ScenarioWorld`], for embedding the mock VM in services,
+/// e.g. a transaction simulation API.
+///
+/// The world itself is not thread-safe, so it lives on a dedicated worker thread.
+/// Work is sent to it as closures, via message passing, and executed one at a time, in the order received.
+/// Results are sent back, either to an async caller, or to a blocking one.
+///
+/// A panic in a closure, e.g. a failed check, is propagated to its caller, the worker keeps going.
+pub struct ScenarioWorldService {
+    sender: Mutex<Option<mpsc::Sender<WorldJob>>>,
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+impl ScenarioWorldService {
+    /// Starts the worker thread, which creates its world using the given function.
+    pub fn new<F>(create_world: F) -> Self
+    where
+        F: FnOnce() -> ScenarioWorld + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel::<WorldJob>();
+        let worker = thread::Builder::new()
+            .name("scenario-world".to_string())
+            .spawn(move || {
+                let mut world = create_world();
+                for job in receiver {
+                    job(&mut world);
+                }
+            })
+            .expect("failed to spawn the scenario world thread");
+
+        ScenarioWorldService {
+            sender: Mutex::new(Some(sender)),
+            worker: Some(worker),
+        }
+    }
+
+    fn send_job<R, F>(&self, f: F) -> oneshot::Receiver<thread::Result<R>>
+    where
+        F: FnOnce(&mut ScenarioWorld) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (result_sender, result_receiver) = oneshot::channel();
+        let job: WorldJob = Box::new(move |world| {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| f(world)));
+            let _ = result_sender.send(result);
+        });
+        self.sender
+            .lock()
+            .unwrap()
+            .as_ref()
+            .expect("scenario world service stopped")
+            .send(job)
+            .expect("scenario world thread stopped");
+        result_receiver
+    }
+
+    /// Runs the closure on the world and waits, asynchronously, for its result.
+    pub async fn execute<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut ScenarioWorld) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let result_receiver = self.send_job(f);
+        unwrap_job_result(result_receiver.await)
+    }
+
+    /// Runs the closure on the world and blocks the current thread until it is done.
+    ///
+    /// Must not be called from within an async runtime, use `execute` there.
+    pub fn execute_blocking<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut ScenarioWorld) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let result_receiver = self.send_job(f);
+        unwrap_job_result(result_receiver.blocking_recv())
+    }
+}
+
+fn unwrap_job_result<R>(result: Result<thread::Result<R>, oneshot::error::RecvError>) -> R {
+    match result.expect("scenario world thread stopped") {
+        Ok(value) => value,
+        Err(panic_payload) => panic::resume_unwind(panic_payload),
+    }
+}
+
+impl Drop for ScenarioWorldService {
+    /// Lets the worker finish the work already sent, then stops it.
+    fn drop(&mut self) {
+        self.sender.lock().unwrap().take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     api::StaticApi,
     facade::ScenarioWorld,
     multiversx_sc::codec::{CodecFrom, TopEncodeMulti},
-    scenario::{model::*, ScenarioRunner},
+    scenario::{model::*, ScenarioRunner, StepExecutor, StepFuture},
 };
 
 impl ScenarioWorld {
@@ -260,6 +260,24 @@ impl TypedScQueryExecutor for ScenarioWorld {
     }
 }
 
+impl StepExecutor for ScenarioWorld {
+    fn execute_sc_call<'a>(&'a mut self, step: &'a mut ScCallStep) -> StepFuture<'a> {
+        Box::pin(async move { self.run_sc_call_step(step) })
+    }
+
+    fn execute_sc_deploy<'a>(&'a mut self, step: &'a mut ScDeployStep) -> StepFuture<'a> {
+        Box::pin(async move { self.run_sc_deploy_step(step) })
+    }
+
+    fn execute_sc_query<'a>(&'a mut self, step: &'a mut ScQueryStep) -> StepFuture<'a> {
+        Box::pin(async move { self.run_sc_query_step(step) })
+    }
+
+    fn execute_transfer<'a>(&'a mut self, step: &'a mut TransferStep) -> StepFuture<'a> {
+        Box::pin(async move { self.run_transfer_step_update_results(step) })
+    }
+}
+
 fn unwrap_response(opt_response: &Option<TxResponse>) -> &TxResponse {
     opt_response.as_ref().expect("response not processed")
 }
@@ -0,0 +1,161 @@
+use multiversx_sc::types::{heap::Address, CodeMetadata};
+
+use crate::{
+    facade::ScenarioWorld,
+    multiversx_sc::codec::{CodecFrom, TopEncodeMulti},
+    scenario::{
+        model::{AddressValue, TxResultHandler, TypedScCall, TypedScDeploy, TypedScQuery},
+        ExecutorTx, ExecutorTxStep, TxUpgrade,
+    },
+};
+
+impl ScenarioWorld {
+    /// Starts a transaction, in the chained syntax.
+    ///
+    /// ```ignore
+    /// let result: u64 = world
+    ///     .tx()
+    ///     .from("address:owner")
+    ///     .esdt(payment)
+    ///     .call(contract.endpoint(arg))
+    ///     .returns();
+    /// ```
+    pub fn tx(&mut self) -> WorldTx<'_> {
+        ExecutorTx::new(self)
+    }
+
+    fn current_code_metadata(&self, address: &AddressValue) -> CodeMetadata {
+        let account = self
+            .get_state()
+            .accounts
+            .get(&address.to_vm_address())
+            .unwrap_or_else(|| panic!("upgraded contract not found: {}", address.original));
+        CodeMetadata::from(account.code_metadata.to_byte_array())
+    }
+}
+
+/// A `ScenarioTx` bound to a `ScenarioWorld`, so that it can be executed directly.
+pub type WorldTx<'w> = ExecutorTx<'w, ScenarioWorld>;
+
+/// A typed step bound to a `ScenarioWorld`, ready to be executed.
+pub type WorldTxStep<'w, S> = ExecutorTxStep<'w, ScenarioWorld, S>;
+
+impl<'w, OriginalResult> WorldTxStep<'w, TypedScCall<OriginalResult>>
+where
+    OriginalResult: TopEncodeMulti,
+{
+    /// Executes the call and decodes its result.
+    pub fn returns<RequestedResult>(self) -> RequestedResult
+    where
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        self.executor.sc_call_get_result(self.step)
+    }
+
+    /// Executes the call, ignoring its result.
+    pub fn run(self) -> &'w mut ScenarioWorld {
+        self.executor.sc_call(self.step)
+    }
+
+    /// Executes the call, then extracts what is needed from the response using the given handler(s).
+    pub fn run_with<H>(mut self, handler: H) -> H::Returns
+    where
+        H: TxResultHandler<OriginalResult>,
+    {
+        self.executor.sc_call(&mut self.step);
+        handler.handle_response(self.step.response())
+    }
+}
+
+impl<'w, OriginalResult> WorldTxStep<'w, TxUpgrade<OriginalResult>>
+where
+    OriginalResult: TopEncodeMulti,
+{
+    /// Keeps the current code metadata of the contract, unless given explicitly.
+    fn into_sc_call_step(self) -> WorldTxStep<'w, TypedScCall<OriginalResult>> {
+        let current_code_metadata = if self.step.has_code_metadata() {
+            CodeMetadata::DEFAULT
+        } else {
+            self.executor.current_code_metadata(&self.step.recipient())
+        };
+        ExecutorTxStep {
+            executor: self.executor,
+            step: self.step.into_sc_call(current_code_metadata),
+        }
+    }
+
+    /// Executes the upgrade and decodes its result.
+    pub fn returns<RequestedResult>(self) -> RequestedResult
+    where
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        self.into_sc_call_step().returns()
+    }
+
+    /// Executes the upgrade, ignoring its result.
+    pub fn run(self) -> &'w mut ScenarioWorld {
+        self.into_sc_call_step().run()
+    }
+
+    /// Executes the upgrade, then extracts what is needed from the response using the given handler(s).
+    pub fn run_with<H>(self, handler: H) -> H::Returns
+    where
+        H: TxResultHandler<OriginalResult>,
+    {
+        self.into_sc_call_step().run_with(handler)
+    }
+}
+
+impl<'w, OriginalResult> WorldTxStep<'w, TypedScDeploy<OriginalResult>>
+where
+    OriginalResult: TopEncodeMulti,
+{
+    /// Executes the deploy, returns the new contract address and the decoded result.
+    pub fn returns<RequestedResult>(self) -> (Address, RequestedResult)
+    where
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        self.executor.sc_deploy_get_result(self.step)
+    }
+
+    /// Executes the deploy, ignoring its result.
+    pub fn run(self) -> &'w mut ScenarioWorld {
+        self.executor.sc_deploy(self.step)
+    }
+
+    /// Executes the deploy, then extracts what is needed from the response using the given handler(s).
+    pub fn run_with<H>(mut self, handler: H) -> H::Returns
+    where
+        H: TxResultHandler<OriginalResult>,
+    {
+        self.executor.sc_deploy(&mut self.step);
+        handler.handle_response(self.step.response())
+    }
+}
+
+impl<'w, OriginalResult> WorldTxStep<'w, TypedScQuery<OriginalResult>>
+where
+    OriginalResult: TopEncodeMulti,
+{
+    /// Executes the query and decodes its result.
+    pub fn returns<RequestedResult>(self) -> RequestedResult
+    where
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        self.executor.sc_query_get_result(self.step)
+    }
+
+    /// Executes the query, ignoring its result.
+    pub fn run(self) -> &'w mut ScenarioWorld {
+        self.executor.sc_query(self.step)
+    }
+
+    /// Executes the query, then extracts what is needed from the response using the given handler(s).
+    pub fn run_with<H>(mut self, handler: H) -> H::Returns
+    where
+        H: TxResultHandler<OriginalResult>,
+    {
+        self.executor.sc_query(&mut self.step);
+        handler.handle_response(self.step.response())
+    }
+}
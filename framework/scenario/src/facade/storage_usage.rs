@@ -0,0 +1,156 @@
+use std::fmt;
+
+use multiversx_chain_vm::world_mock::AccountStorage;
+
+use crate::{
+    multiversx_sc::{abi::StorageKeyAbi, contract_base::ContractAbiProvider},
+    scenario_model::AddressKey,
+    ScenarioWorld,
+};
+
+/// Number and size of storage entries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StorageUsage {
+    pub num_entries: usize,
+    pub key_bytes: usize,
+    pub value_bytes: usize,
+}
+
+impl StorageUsage {
+    fn add_entry(&mut self, key: &[u8], value: &[u8]) {
+        self.num_entries += 1;
+        self.key_bytes += key.len();
+        self.value_bytes += value.len();
+    }
+
+    fn add_usage(&mut self, other: &StorageUsage) {
+        self.num_entries += other.num_entries;
+        self.key_bytes += other.key_bytes;
+        self.value_bytes += other.value_bytes;
+    }
+
+    pub fn total_bytes(&self) -> usize {
+        self.key_bytes + self.value_bytes
+    }
+}
+
+impl fmt::Display for StorageUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} entries, {} bytes ({} key + {} value)",
+            self.num_entries,
+            self.total_bytes(),
+            self.key_bytes,
+            self.value_bytes
+        )
+    }
+}
+
+/// Storage used by one storage mapper (or getter/setter).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MapperStorageUsage {
+    pub name: &'static str,
+    pub base_key: &'static str,
+    pub usage: StorageUsage,
+}
+
+/// Storage entries of an account, attributed to the storage mappers declared in the contract ABI.
+///
+/// Each entry is attributed to the mapper with the longest base key that prefixes the entry key,
+/// since mappers, as well as storage arguments, append to the base key.
+/// Entries that do not belong to any mapper are counted separately.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageUsageReport {
+    /// Sorted by total bytes, largest first.
+    pub mappers: Vec<MapperStorageUsage>,
+    pub unattributed: StorageUsage,
+}
+
+impl StorageUsageReport {
+    pub fn new(storage_keys: &[StorageKeyAbi], storage: &AccountStorage) -> Self {
+        let mut mappers: Vec<MapperStorageUsage> = storage_keys
+            .iter()
+            .map(|storage_key| MapperStorageUsage {
+                name: storage_key.name,
+                base_key: storage_key.key,
+                usage: StorageUsage::default(),
+            })
+            .collect();
+        let mut unattributed = StorageUsage::default();
+
+        for (key, value) in storage {
+            let owner = mappers
+                .iter_mut()
+                .filter(|mapper| key.starts_with(mapper.base_key.as_bytes()))
+                .max_by_key(|mapper| mapper.base_key.len());
+            match owner {
+                Some(mapper) => mapper.usage.add_entry(key, value),
+                None => unattributed.add_entry(key, value),
+            }
+        }
+
+        mappers.sort_by(|a, b| {
+            b.usage
+                .total_bytes()
+                .cmp(&a.usage.total_bytes())
+                .then_with(|| a.name.cmp(b.name))
+        });
+
+        StorageUsageReport {
+            mappers,
+            unattributed,
+        }
+    }
+
+    /// Usage of the mapper declared by the method with the given name.
+    pub fn get(&self, name: &str) -> Option<&StorageUsage> {
+        self.mappers
+            .iter()
+            .find(|mapper| mapper.name == name)
+            .map(|mapper| &mapper.usage)
+    }
+
+    pub fn total(&self) -> StorageUsage {
+        let mut total = self.unattributed;
+        for mapper in &self.mappers {
+            total.add_usage(&mapper.usage);
+        }
+        total
+    }
+}
+
+impl fmt::Display for StorageUsageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "total: {}", self.total())?;
+        for mapper in &self.mappers {
+            writeln!(
+                f,
+                "{} (\"{}\"): {}",
+                mapper.name, mapper.base_key, mapper.usage
+            )?;
+        }
+        writeln!(f, "unattributed: {}", self.unattributed)
+    }
+}
+
+impl ScenarioWorld {
+    /// Attributes the storage of an account to the storage mappers of a contract,
+    /// based on the base keys in its ABI.
+    ///
+    /// Useful for spotting mappers that grow unbounded, before they reach mainnet.
+    ///
+    /// Only available for the contract debugger backend.
+    pub fn storage_usage<Abi: ContractAbiProvider>(
+        &self,
+        address_expr: &str,
+    ) -> StorageUsageReport {
+        let address = AddressKey::from(address_expr);
+        let account = self
+            .get_state()
+            .accounts
+            .get(&address.to_vm_address())
+            .unwrap_or_else(|| panic!("account {} not found", address.original));
+        StorageUsageReport::new(&Abi::abi().storage_keys, &account.storage)
+    }
+}
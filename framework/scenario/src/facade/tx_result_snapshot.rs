@@ -0,0 +1,146 @@
+use std::{fs, path::Path};
+
+use multiversx_chain_vm::tx_mock::TxResult;
+pub use multiversx_sc_meta::abi_json::BLESS_SNAPSHOTS_ENV_VAR;
+use serde::{Deserialize, Serialize};
+
+use crate::scenario_model::TxResponse;
+
+/// A stable, human-readable representation of a transaction result, meant to be stored in golden files.
+///
+/// All bytes are hex-encoded, in the same order as produced by the VM.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TxResultSnapshot {
+    pub status: u64,
+    pub message: String,
+    pub out: Vec<String>,
+    pub logs: Vec<TxLogSnapshot>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TxLogSnapshot {
+    pub address: String,
+    pub endpoint: String,
+    pub topics: Vec<String>,
+    pub data: String,
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+impl From<&TxResult> for TxResultSnapshot {
+    fn from(tx_result: &TxResult) -> Self {
+        TxResultSnapshot {
+            status: tx_result.result_status,
+            message: tx_result.result_message.clone(),
+            out: tx_result
+                .result_values
+                .iter()
+                .map(|v| hex_string(v))
+                .collect(),
+            logs: tx_result
+                .result_logs
+                .iter()
+                .map(|log| TxLogSnapshot {
+                    address: hex_string(log.address.as_bytes()),
+                    endpoint: log.endpoint.as_str().to_string(),
+                    topics: log.topics.iter().map(|t| hex_string(t)).collect(),
+                    data: hex_string(&log.data),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&TxResponse> for TxResultSnapshot {
+    fn from(response: &TxResponse) -> Self {
+        TxResultSnapshot {
+            status: response.tx_error.status,
+            message: response.tx_error.message.clone(),
+            out: response.out.iter().map(|v| hex_string(v)).collect(),
+            logs: response
+                .logs
+                .iter()
+                .map(|log| TxLogSnapshot {
+                    address: hex_string(&log.address.value),
+                    endpoint: String::from_utf8_lossy(&log.endpoint.value).into_owned(),
+                    topics: log.topics.iter().map(|t| hex_string(&t.value)).collect(),
+                    data: hex_string(&log.data.value),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TxResultSnapshot {
+    pub fn to_json_string(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).unwrap();
+        json.push('\n');
+        json
+    }
+
+    /// Compares against the golden file at the given path, panicking with a diff if they differ.
+    ///
+    /// If the `SC_BLESS_SNAPSHOTS` environment variable is set, the golden file is (re)written instead.
+    pub fn assert_golden_file<P: AsRef<Path>>(&self, path: P) {
+        let path = path.as_ref();
+        let actual = self.to_json_string();
+
+        if std::env::var_os(BLESS_SNAPSHOTS_ENV_VAR).is_some() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).unwrap();
+            }
+            fs::write(path, actual).unwrap();
+            return;
+        }
+
+        let expected = fs::read_to_string(path).unwrap_or_else(|_| {
+            panic!(
+                "snapshot file {} not found, run with {BLESS_SNAPSHOTS_ENV_VAR}=1 to create it",
+                path.display()
+            )
+        });
+
+        if expected != actual {
+            panic!(
+                "snapshot mismatch for {}, run with {BLESS_SNAPSHOTS_ENV_VAR}=1 to accept the changes:\n{}",
+                path.display(),
+                line_diff(&expected, &actual)
+            );
+        }
+    }
+}
+
+/// Minimal line-by-line diff, good enough for the small JSON files produced by snapshots.
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let mut result = String::new();
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => {
+                result.push_str(&format!("  {e}\n"));
+            },
+            (e, a) => {
+                if let Some(e) = e {
+                    result.push_str(&format!("- {e}\n"));
+                }
+                if let Some(a) = a {
+                    result.push_str(&format!("+ {a}\n"));
+                }
+            },
+        }
+    }
+    result
+}
+
+/// Snapshot-tests a mock VM transaction result against a golden file.
+pub fn assert_tx_result_snapshot<P: AsRef<Path>>(path: P, tx_result: &TxResult) {
+    TxResultSnapshot::from(tx_result).assert_golden_file(path);
+}
+
+/// Snapshot-tests a scenario transaction response against a golden file.
+pub fn assert_tx_response_snapshot<P: AsRef<Path>>(path: P, response: &TxResponse) {
+    TxResultSnapshot::from(response).assert_golden_file(path);
+}
@@ -36,13 +36,21 @@ pub use multiversx_sc;
 /// but the export will hopefully remain the same.
 pub use crate::scenario::model as scenario_model;
 
+pub use crate::scenario::run_vm::GasLimits;
+
 /// For backwards compatibility, will be removed.
 pub use crate::scenario as mandos_system;
 
 // Re-exporting the whole mandos crate for easier use in tests.
 pub use multiversx_chain_scenario_format as scenario_format;
 
-pub use facade::{ContractInfo, ScenarioWorld, WhiteboxContract};
+pub use facade::{
+    assert_tx_response_snapshot, assert_tx_result_snapshot, BenchmarkAllocator, BenchmarkReport,
+    ContractInfo, FileProfile, MapperStorageUsage, ScenarioProfile, ScenarioWorld,
+    ScenarioWorldService, StepProfile, StorageUsage, StorageUsageReport, TxLogSnapshot,
+    TxResultSnapshot, WhiteboxContract, WorldAccount, WorldTx, WorldTxStep,
+    BLESS_SNAPSHOTS_ENV_VAR, PROFILE_REPORT_DEFAULT_LENGTH, PROFILE_SCENARIOS_ENV_VAR,
+};
 
 use std::path::Path;
 
@@ -0,0 +1,380 @@
+use std::{future::Future, pin::Pin};
+
+use multiversx_sc::types::{heap::Address, CodeMetadata, ContractCall, ContractDeploy};
+
+use crate::{
+    api::StaticApi,
+    multiversx_sc::codec::{CodecFrom, TopEncodeMulti},
+    scenario::model::{
+        AddressValue, BigUintValue, BytesValue, ScenarioTx, TxESDT, TxExpect, TxResultHandler,
+        TypedScCall, TypedScDeploy, TypedScQuery, U64Value,
+    },
+};
+
+use super::StepExecutor;
+
+/// Future returned by `AsyncStepExecutor::code_metadata`.
+pub type CodeMetadataFuture<'a> = Pin<Box<dyn Future<Output = CodeMetadata> + 'a>>;
+
+/// A `StepExecutor` that sends the transactions asynchronously, e.g. to a real blockchain.
+///
+/// The transactions started via `ExecutorTx` are then run with `.await`.
+/// `ScenarioWorld` is not one of them, it runs them synchronously.
+pub trait AsyncStepExecutor: StepExecutor {
+    /// The current code metadata of a contract, kept on upgrade if no other is given.
+    fn code_metadata<'a>(&'a mut self, address: &'a Address) -> CodeMetadataFuture<'a>;
+}
+
+/// A `ScenarioTx` bound to an executor, so that it can be run directly.
+///
+/// Started via `ScenarioWorld::tx` or `Interactor::tx`.
+pub struct ExecutorTx<'e, E> {
+    executor: &'e mut E,
+    tx: ScenarioTx,
+}
+
+impl<'e, E> ExecutorTx<'e, E> {
+    pub fn new(executor: &'e mut E) -> Self {
+        ExecutorTx {
+            executor,
+            tx: ScenarioTx::new(),
+        }
+    }
+
+    pub fn from<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx = self.tx.from(address);
+        self
+    }
+
+    pub fn to<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx = self.tx.to(address);
+        self
+    }
+
+    pub fn egld_value<A>(mut self, amount: A) -> Self
+    where
+        BigUintValue: From<A>,
+    {
+        self.tx = self.tx.egld_value(amount);
+        self
+    }
+
+    pub fn esdt<P>(mut self, payment: P) -> Self
+    where
+        TxESDT: From<P>,
+    {
+        self.tx = self.tx.esdt(payment);
+        self
+    }
+
+    pub fn multi_esdt<I, P>(mut self, payments: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        TxESDT: From<P>,
+    {
+        self.tx = self.tx.multi_esdt(payments);
+        self
+    }
+
+    pub fn gas_limit<V>(mut self, value: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.tx = self.tx.gas_limit(value);
+        self
+    }
+
+    pub fn code<V>(mut self, expr: V) -> Self
+    where
+        BytesValue: From<V>,
+    {
+        self.tx = self.tx.code(expr);
+        self
+    }
+
+    pub fn code_metadata(mut self, code_metadata: CodeMetadata) -> Self {
+        self.tx = self.tx.code_metadata(code_metadata);
+        self
+    }
+
+    pub fn call<CC>(
+        self,
+        contract_call: CC,
+    ) -> ExecutorTxStep<'e, E, TypedScCall<CC::OriginalResult>>
+    where
+        CC: ContractCall<StaticApi>,
+    {
+        ExecutorTxStep {
+            executor: self.executor,
+            step: self.tx.call(contract_call),
+        }
+    }
+
+    pub fn deploy<OriginalResult>(
+        self,
+        contract_deploy: ContractDeploy<StaticApi, OriginalResult>,
+    ) -> ExecutorTxStep<'e, E, TypedScDeploy<OriginalResult>> {
+        ExecutorTxStep {
+            executor: self.executor,
+            step: self.tx.deploy(contract_deploy),
+        }
+    }
+
+    /// The upgrade call is only built when run,
+    /// since without an explicit `code_metadata` it needs the current one from the executor.
+    pub fn upgrade<OriginalResult>(
+        self,
+        contract_deploy: ContractDeploy<StaticApi, OriginalResult>,
+    ) -> ExecutorTxStep<'e, E, TxUpgrade<OriginalResult>> {
+        ExecutorTxStep {
+            executor: self.executor,
+            step: TxUpgrade {
+                tx: self.tx,
+                contract_deploy,
+                expect: None,
+            },
+        }
+    }
+
+    pub fn query<CC>(
+        self,
+        contract_call: CC,
+    ) -> ExecutorTxStep<'e, E, TypedScQuery<CC::OriginalResult>>
+    where
+        CC: ContractCall<StaticApi>,
+    {
+        ExecutorTxStep {
+            executor: self.executor,
+            step: self.tx.query(contract_call),
+        }
+    }
+}
+
+/// An upgrade started via `ExecutorTx`, waiting for the current code metadata, if needed.
+pub struct TxUpgrade<OriginalResult> {
+    tx: ScenarioTx,
+    contract_deploy: ContractDeploy<StaticApi, OriginalResult>,
+    expect: Option<TxExpect>,
+}
+
+impl<OriginalResult> TxUpgrade<OriginalResult> {
+    /// The contract being upgraded.
+    pub fn recipient(&self) -> AddressValue {
+        self.tx.upgrade_recipient(&self.contract_deploy)
+    }
+
+    pub fn has_code_metadata(&self) -> bool {
+        self.tx.code_metadata.is_some()
+    }
+
+    /// Builds the upgrade call, with the given code metadata, unless one was set explicitly.
+    pub fn into_sc_call(self, current_code_metadata: CodeMetadata) -> TypedScCall<OriginalResult>
+    where
+        OriginalResult: TopEncodeMulti,
+    {
+        let code_metadata = self.tx.code_metadata.unwrap_or(current_code_metadata);
+        let step = self
+            .tx
+            .code_metadata(code_metadata)
+            .upgrade(self.contract_deploy);
+        match self.expect {
+            Some(expect) => step.expect(expect),
+            None => step,
+        }
+    }
+}
+
+/// A typed step bound to an executor, ready to be run.
+pub struct ExecutorTxStep<'e, E, S> {
+    pub(crate) executor: &'e mut E,
+    pub(crate) step: S,
+}
+
+impl<'e, E, OriginalResult> ExecutorTxStep<'e, E, TypedScCall<OriginalResult>>
+where
+    OriginalResult: TopEncodeMulti,
+{
+    pub fn expect(mut self, expect: TxExpect) -> Self {
+        self.step = self.step.expect(expect);
+        self
+    }
+}
+
+impl<'e, E, OriginalResult> ExecutorTxStep<'e, E, TypedScDeploy<OriginalResult>>
+where
+    OriginalResult: TopEncodeMulti,
+{
+    pub fn expect(mut self, expect: TxExpect) -> Self {
+        self.step = self.step.expect(expect);
+        self
+    }
+}
+
+impl<'e, E, OriginalResult> ExecutorTxStep<'e, E, TxUpgrade<OriginalResult>> {
+    pub fn expect(mut self, expect: TxExpect) -> Self {
+        self.step.expect = Some(expect);
+        self
+    }
+}
+
+impl<'e, E, OriginalResult> ExecutorTxStep<'e, E, TypedScQuery<OriginalResult>>
+where
+    OriginalResult: TopEncodeMulti,
+{
+    pub fn expect(mut self, expect: TxExpect) -> Self {
+        self.step = self.step.expect(expect);
+        self
+    }
+}
+
+impl<'e, E, OriginalResult> ExecutorTxStep<'e, E, TypedScCall<OriginalResult>>
+where
+    E: AsyncStepExecutor,
+    OriginalResult: TopEncodeMulti,
+{
+    /// Sends the call and decodes its result. Panics if the transaction failed.
+    pub async fn returns<RequestedResult>(self) -> RequestedResult
+    where
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        self.step
+            .run_on(self.executor)
+            .await
+            .result
+            .expect("SC call failed")
+    }
+
+    /// Sends the call, ignoring its result.
+    pub async fn run(mut self) {
+        self.executor
+            .execute_sc_call(&mut self.step.sc_call_step)
+            .await;
+    }
+
+    /// Sends the call, then extracts what is needed from the response using the given handler(s).
+    pub async fn run_with<H>(mut self, handler: H) -> H::Returns
+    where
+        H: TxResultHandler<OriginalResult>,
+    {
+        self.executor
+            .execute_sc_call(&mut self.step.sc_call_step)
+            .await;
+        handler.handle_response(self.step.response())
+    }
+}
+
+impl<'e, E, OriginalResult> ExecutorTxStep<'e, E, TxUpgrade<OriginalResult>>
+where
+    E: AsyncStepExecutor,
+    OriginalResult: TopEncodeMulti,
+{
+    async fn into_sc_call_step(self) -> ExecutorTxStep<'e, E, TypedScCall<OriginalResult>> {
+        let current_code_metadata = if self.step.has_code_metadata() {
+            CodeMetadata::DEFAULT
+        } else {
+            let address = self.step.recipient().to_address();
+            self.executor.code_metadata(&address).await
+        };
+        ExecutorTxStep {
+            executor: self.executor,
+            step: self.step.into_sc_call(current_code_metadata),
+        }
+    }
+
+    /// Sends the upgrade and decodes its result. Panics if the transaction failed.
+    pub async fn returns<RequestedResult>(self) -> RequestedResult
+    where
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        self.into_sc_call_step().await.returns().await
+    }
+
+    /// Sends the upgrade, ignoring its result.
+    pub async fn run(self) {
+        self.into_sc_call_step().await.run().await
+    }
+
+    /// Sends the upgrade, then extracts what is needed from the response using the given handler(s).
+    pub async fn run_with<H>(self, handler: H) -> H::Returns
+    where
+        H: TxResultHandler<OriginalResult>,
+    {
+        self.into_sc_call_step().await.run_with(handler).await
+    }
+}
+
+impl<'e, E, OriginalResult> ExecutorTxStep<'e, E, TypedScDeploy<OriginalResult>>
+where
+    E: AsyncStepExecutor,
+    OriginalResult: TopEncodeMulti,
+{
+    /// Sends the deploy, returns the new contract address and the decoded result.
+    /// Panics if the transaction failed.
+    pub async fn returns<RequestedResult>(self) -> (Address, RequestedResult)
+    where
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        let (new_address, response) = self.step.run_on(self.executor).await;
+        (new_address, response.result.expect("SC deploy failed"))
+    }
+
+    /// Sends the deploy, ignoring its result.
+    pub async fn run(mut self) {
+        self.executor
+            .execute_sc_deploy(&mut self.step.sc_deploy_step)
+            .await;
+    }
+
+    /// Sends the deploy, then extracts what is needed from the response using the given handler(s).
+    pub async fn run_with<H>(mut self, handler: H) -> H::Returns
+    where
+        H: TxResultHandler<OriginalResult>,
+    {
+        self.executor
+            .execute_sc_deploy(&mut self.step.sc_deploy_step)
+            .await;
+        handler.handle_response(self.step.response())
+    }
+}
+
+impl<'e, E, OriginalResult> ExecutorTxStep<'e, E, TypedScQuery<OriginalResult>>
+where
+    E: AsyncStepExecutor,
+    OriginalResult: TopEncodeMulti,
+{
+    /// Performs the query and decodes its result. Panics if the query failed.
+    pub async fn returns<RequestedResult>(self) -> RequestedResult
+    where
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        self.step
+            .run_on(self.executor)
+            .await
+            .result
+            .expect("SC query failed")
+    }
+
+    /// Performs the query, ignoring its result.
+    pub async fn run(mut self) {
+        self.executor
+            .execute_sc_query(&mut self.step.sc_query_step)
+            .await;
+    }
+
+    /// Performs the query, then extracts what is needed from the response using the given handler(s).
+    pub async fn run_with<H>(mut self, handler: H) -> H::Returns
+    where
+        H: TxResultHandler<OriginalResult>,
+    {
+        self.executor
+            .execute_sc_query(&mut self.step.sc_query_step)
+            .await;
+        handler.handle_response(self.step.response())
+    }
+}
@@ -1,9 +1,20 @@
+mod executor_tx;
 pub mod model;
+mod parse_error;
 mod parse_util;
 pub mod run_list;
 pub mod run_trace;
 pub mod run_vm;
 mod scenario_runner;
+mod step_executor;
 
-pub use parse_util::{parse_scenario, parse_scenario_raw};
+pub use executor_tx::{
+    AsyncStepExecutor, CodeMetadataFuture, ExecutorTx, ExecutorTxStep, TxUpgrade,
+};
+pub use parse_error::{ScenarioParseError, ScenarioParseReport};
+pub use parse_util::{
+    check_scenarios_parse, parse_scenario, parse_scenario_raw, parse_scenario_with_file_registry,
+    try_parse_scenario, try_parse_scenario_raw, try_parse_scenario_with_file_registry,
+};
 pub use scenario_runner::ScenarioRunner;
+pub use step_executor::{StepExecutor, StepFuture};
@@ -19,6 +19,7 @@ pub struct Account {
     pub username: Option<BytesValue>,
     pub storage: BTreeMap<BytesKey, BytesValue>,
     pub code: Option<BytesValue>,
+    pub code_metadata: Option<BytesValue>,
     pub owner: Option<AddressValue>,
     pub developer_rewards: Option<BigUintValue>,
 }
@@ -84,6 +85,51 @@ impl Account {
         self
     }
 
+    /// Sets an NFT/SFT instance, together with all its metadata.
+    ///
+    /// The attributes, creator and hash are only changed if given.
+    #[allow(clippy::too_many_arguments)]
+    pub fn esdt_nft_all_properties<K, N, V, R, T>(
+        mut self,
+        token_id_expr: K,
+        nonce_expr: N,
+        balance_expr: V,
+        opt_attributes_expr: Option<T>,
+        royalties_expr: R,
+        opt_creator_expr: Option<T>,
+        opt_hash_expr: Option<T>,
+        uris_expr: Vec<T>,
+    ) -> Self
+    where
+        N: Clone,
+        BytesKey: From<K>,
+        U64Value: From<N>,
+        BigUintValue: From<V>,
+        U64Value: From<R>,
+        BytesValue: From<T>,
+    {
+        let token_id = BytesKey::from(token_id_expr);
+
+        let esdt_obj_ref = self
+            .get_esdt_data_or_create(&token_id)
+            .get_mut_esdt_object();
+        esdt_obj_ref.set_balance(nonce_expr.clone(), balance_expr);
+        esdt_obj_ref.set_token_royalties(nonce_expr.clone(), royalties_expr);
+        esdt_obj_ref.set_token_uris(nonce_expr.clone(), uris_expr);
+
+        if let Some(attributes_expr) = opt_attributes_expr {
+            esdt_obj_ref.set_token_attributes(nonce_expr.clone(), attributes_expr);
+        }
+        if let Some(creator_expr) = opt_creator_expr {
+            esdt_obj_ref.set_token_creator(nonce_expr.clone(), creator_expr);
+        }
+        if let Some(hash_expr) = opt_hash_expr {
+            esdt_obj_ref.set_token_hash(nonce_expr, hash_expr);
+        }
+
+        self
+    }
+
     pub fn esdt_nft_last_nonce<K, N>(mut self, token_id_expr: K, last_nonce_expr: N) -> Self
     where
         BytesKey: From<K>,
@@ -132,6 +178,14 @@ impl Account {
         self
     }
 
+    pub fn code_metadata<V>(mut self, code_metadata_expr: V) -> Self
+    where
+        BytesValue: From<V>,
+    {
+        self.code_metadata = Some(BytesValue::from(code_metadata_expr));
+        self
+    }
+
     pub fn owner<V>(mut self, owner_expr: V) -> Self
     where
         AddressValue: From<V>,
@@ -173,6 +227,9 @@ impl InterpretableFrom<AccountRaw> for Account {
                 })
                 .collect(),
             code: from.code.map(|c| BytesValue::interpret_from(c, context)),
+            code_metadata: from
+                .code_metadata
+                .map(|c| BytesValue::interpret_from(c, context)),
             owner: from.owner.map(|v| AddressValue::interpret_from(v, context)),
             developer_rewards: from
                 .developer_rewards
@@ -199,6 +256,7 @@ impl IntoRaw<AccountRaw> for Account {
                 .map(|(key, value)| (key.original, value.original))
                 .collect(),
             code: self.code.map(|n| n.original),
+            code_metadata: self.code_metadata.map(|n| n.original),
             owner: self.owner.map(|n| n.original),
             developer_rewards: self.developer_rewards.map(|n| n.original),
         }
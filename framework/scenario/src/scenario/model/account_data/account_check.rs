@@ -1,7 +1,8 @@
 use crate::{
     scenario::model::{
-        BigUintValue, BytesKey, BytesValue, CheckEsdt, CheckEsdtInstances, CheckEsdtMap,
-        CheckEsdtMapContents, CheckStorage, CheckStorageDetails, CheckValue, U64Value,
+        BigUintCheckValue, BigUintValue, BytesKey, BytesValue, CheckEsdt, CheckEsdtData,
+        CheckEsdtInstances, CheckEsdtMap, CheckEsdtMapContents, CheckStorage, CheckStorageDetails,
+        CheckValue, U64Value,
     },
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
@@ -19,6 +20,7 @@ pub struct CheckAccount {
     pub username: CheckValue<BytesValue>,
     pub storage: CheckStorage,
     pub code: CheckValue<BytesValue>,
+    pub code_hash: CheckValue<BytesValue>,
     pub owner: CheckValue<BytesValue>, // WARNING! Not currently checked. TODO: implement check
     pub developer_rewards: CheckValue<BigUintValue>,
     pub async_call_data: CheckValue<BytesValue>,
@@ -62,13 +64,25 @@ impl CheckAccount {
         self
     }
 
+    /// Checks the blake2b-256 hash of the contract code, as the protocol computes it.
+    pub fn code_hash<V>(mut self, code_hash_expr: V) -> Self
+    where
+        BytesValue: InterpretableFrom<V>,
+    {
+        self.code_hash = CheckValue::Equal(BytesValue::interpret_from(
+            code_hash_expr,
+            &InterpreterContext::default(),
+        ));
+        self
+    }
+
     pub fn esdt_balance<K, V>(mut self, token_id_expr: K, balance_expr: V) -> Self
     where
         BytesKey: From<K>,
-        BigUintValue: From<V>,
+        BigUintCheckValue: From<V>,
     {
         let token_id = BytesKey::from(token_id_expr);
-        let balance = BigUintValue::from(balance_expr);
+        let balance = BigUintCheckValue::from(balance_expr);
 
         match &mut self.esdt {
             CheckEsdtMap::Unspecified | CheckEsdtMap::Star => {
@@ -99,6 +113,36 @@ impl CheckAccount {
         self
     }
 
+    /// Checks that the account has exactly the given roles for the token.
+    pub fn esdt_roles<K>(mut self, token_id_expr: K, roles: &[&str]) -> Self
+    where
+        BytesKey: From<K>,
+    {
+        let token_id = BytesKey::from(token_id_expr);
+
+        if let CheckEsdtMap::Unspecified | CheckEsdtMap::Star = &self.esdt {
+            self.esdt = CheckEsdtMap::Equal(CheckEsdtMapContents {
+                contents: BTreeMap::new(),
+                other_esdts_allowed: true,
+            });
+        }
+
+        if let CheckEsdtMap::Equal(check_esdt_map) = &mut self.esdt {
+            check_esdt_map
+                .contents
+                .entry(token_id)
+                .or_insert_with(|| {
+                    CheckEsdt::Full(CheckEsdtData {
+                        instances: CheckEsdtInstances::Star,
+                        ..Default::default()
+                    })
+                })
+                .set_roles_check(roles);
+        }
+
+        self
+    }
+
     pub fn check_storage(mut self, key: &str, value: &str) -> Self {
         let mut details = match self.storage {
             CheckStorage::Star => CheckStorageDetails::default(),
@@ -126,6 +170,7 @@ impl InterpretableFrom<Box<CheckAccountRaw>> for CheckAccount {
             username: CheckValue::<BytesValue>::interpret_from(from.username, context),
             storage: CheckStorage::interpret_from(from.storage, context),
             code: CheckValue::<BytesValue>::interpret_from(from.code, context),
+            code_hash: CheckValue::<BytesValue>::interpret_from(from.code_hash, context),
             owner: CheckValue::<BytesValue>::interpret_from(from.owner, context),
             developer_rewards: CheckValue::<BigUintValue>::interpret_from(
                 from.developer_rewards,
@@ -149,6 +194,7 @@ impl IntoRaw<CheckAccountRaw> for CheckAccount {
             username: self.username.into_raw(),
             storage: self.storage.into_raw(),
             code: self.code.into_raw_explicit(), // TODO: convert back to into_raw after VM CI upgrade
+            code_hash: self.code_hash.into_raw(),
             owner: self.owner.into_raw_explicit(), // TODO: convert back to into_raw after VM CI upgrade
             developer_rewards: self.developer_rewards.into_raw(),
             async_call_data: self.async_call_data.into_raw(),
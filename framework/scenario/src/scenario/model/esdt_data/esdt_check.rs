@@ -1,6 +1,6 @@
 use super::{CheckEsdtData, CheckEsdtInstance, CheckEsdtInstances};
 use crate::{
-    scenario::model::{BigUintValue, CheckValue, U64Value},
+    scenario::model::{BigUintCheckValue, CheckValue, U64Value},
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
         serde_raw::{CheckEsdtRaw, ValueSubTree},
@@ -10,7 +10,7 @@ use num_bigint::BigUint;
 
 #[derive(Debug, Clone)]
 pub enum CheckEsdt {
-    Short(BigUintValue),
+    Short(BigUintCheckValue),
     Full(CheckEsdtData),
 }
 
@@ -27,13 +27,15 @@ impl CheckEsdt {
             if has_single_fungible_instance
                 && esdt_check.frozen.is_star()
                 && esdt_check.last_nonce.is_star()
+                && esdt_check.roles.is_none()
             {
                 let balance =
                     if let CheckEsdtInstances::Equal(check_instances) = &esdt_check.instances {
                         match &check_instances[0].balance {
-                            CheckValue::Star => BigUintValue {
+                            CheckValue::Star => BigUintCheckValue {
                                 original: ValueSubTree::Str("*".to_string()),
                                 value: BigUint::from(0u32),
+                                tolerance: BigUint::from(0u32),
                             },
                             CheckValue::Equal(val) => val.clone(),
                         }
@@ -61,14 +63,23 @@ impl CheckEsdt {
         }
     }
 
+    /// Only these roles are expected, in any order.
+    pub fn set_roles_check(&mut self, roles: &[&str]) {
+        self.convert_to_full();
+
+        if let CheckEsdt::Full(prev_esdt_check) = self {
+            prev_esdt_check.roles = Some(roles.iter().map(|role| role.to_string()).collect());
+        }
+    }
+
     pub fn add_balance_check<N, V>(&mut self, nonce_expr: N, balance_expr: V)
     where
         U64Value: InterpretableFrom<N>,
-        BigUintValue: InterpretableFrom<V>,
+        BigUintCheckValue: InterpretableFrom<V>,
     {
         let ctx = InterpreterContext::default();
         let nonce = U64Value::interpret_from(nonce_expr, &ctx);
-        let balance = BigUintValue::interpret_from(balance_expr, &ctx);
+        let balance = BigUintCheckValue::interpret_from(balance_expr, &ctx);
 
         self.convert_to_full();
 
@@ -106,7 +117,9 @@ impl InterpretableFrom<CheckEsdtRaw> for CheckEsdt {
     fn interpret_from(from: CheckEsdtRaw, context: &InterpreterContext) -> Self {
         match from {
             CheckEsdtRaw::Full(m) => CheckEsdt::Full(CheckEsdtData::interpret_from(m, context)),
-            CheckEsdtRaw::Short(v) => CheckEsdt::Short(BigUintValue::interpret_from(v, context)),
+            CheckEsdtRaw::Short(v) => {
+                CheckEsdt::Short(BigUintCheckValue::interpret_from(v, context))
+            },
         }
     }
 }
@@ -13,6 +13,9 @@ pub struct CheckEsdtData {
     pub instances: CheckEsdtInstances,
     pub last_nonce: CheckValue<U64Value>,
     pub frozen: CheckValue<U64Value>,
+
+    /// The exact set of roles expected, in any order. Not checked if missing.
+    pub roles: Option<Vec<String>>,
 }
 
 impl InterpretableFrom<CheckEsdtDataRaw> for CheckEsdtData {
@@ -21,6 +24,7 @@ impl InterpretableFrom<CheckEsdtDataRaw> for CheckEsdtData {
             instances: CheckEsdtInstances::interpret_from(from.instances, context),
             last_nonce: CheckValue::<U64Value>::interpret_from(from.last_nonce, context),
             frozen: CheckValue::<U64Value>::interpret_from(from.frozen, context),
+            roles: from.roles,
         }
     }
 }
@@ -30,7 +34,7 @@ impl IntoRaw<CheckEsdtDataRaw> for CheckEsdtData {
         CheckEsdtDataRaw {
             instances: self.instances.into_raw(),
             last_nonce: self.last_nonce.into_raw(),
-            roles: Vec::new(),
+            roles: self.roles,
             frozen: self.frozen.into_raw(),
         }
     }
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    scenario::model::{AddressKey, U64Value},
+    scenario_format::{
+        interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
+        serde_raw::EsdtGlobalSettingsRaw,
+    },
+};
+
+/// Token-level settings, configured once per token identifier in `setState`.
+#[derive(Debug, Default, Clone)]
+pub struct EsdtGlobalSettings {
+    pub paused: Option<U64Value>,
+    pub limited_transfer: Option<U64Value>,
+    pub last_nonce: Option<U64Value>,
+    pub num_decimals: Option<U64Value>,
+    pub roles: BTreeMap<AddressKey, Vec<String>>,
+}
+
+impl EsdtGlobalSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = Some(U64Value::from(paused as u64));
+        self
+    }
+
+    pub fn limited_transfer(mut self, limited_transfer: bool) -> Self {
+        self.limited_transfer = Some(U64Value::from(limited_transfer as u64));
+        self
+    }
+
+    pub fn last_nonce<N>(mut self, last_nonce_expr: N) -> Self
+    where
+        U64Value: From<N>,
+    {
+        self.last_nonce = Some(U64Value::from(last_nonce_expr));
+        self
+    }
+
+    pub fn num_decimals(mut self, num_decimals: u32) -> Self {
+        self.num_decimals = Some(U64Value::from(num_decimals as u64));
+        self
+    }
+
+    pub fn roles<A>(mut self, address_expr: A, roles: &[&str]) -> Self
+    where
+        AddressKey: From<A>,
+    {
+        self.roles.insert(
+            AddressKey::from(address_expr),
+            roles.iter().map(|role| role.to_string()).collect(),
+        );
+        self
+    }
+}
+
+impl InterpretableFrom<EsdtGlobalSettingsRaw> for EsdtGlobalSettings {
+    fn interpret_from(from: EsdtGlobalSettingsRaw, context: &InterpreterContext) -> Self {
+        EsdtGlobalSettings {
+            paused: from.paused.map(|b| U64Value::interpret_from(b, context)),
+            limited_transfer: from
+                .limited_transfer
+                .map(|b| U64Value::interpret_from(b, context)),
+            last_nonce: from
+                .last_nonce
+                .map(|b| U64Value::interpret_from(b, context)),
+            num_decimals: from
+                .num_decimals
+                .map(|b| U64Value::interpret_from(b, context)),
+            roles: from
+                .roles
+                .into_iter()
+                .map(|(k, v)| (AddressKey::interpret_from(k, context), v))
+                .collect(),
+        }
+    }
+}
+
+impl IntoRaw<EsdtGlobalSettingsRaw> for EsdtGlobalSettings {
+    fn into_raw(self) -> EsdtGlobalSettingsRaw {
+        EsdtGlobalSettingsRaw {
+            paused: self.paused.map(|v| v.original),
+            limited_transfer: self.limited_transfer.map(|v| v.original),
+            last_nonce: self.last_nonce.map(|v| v.original),
+            num_decimals: self.num_decimals.map(|v| v.original),
+            roles: self
+                .roles
+                .into_iter()
+                .map(|(k, v)| (k.into_raw(), v))
+                .collect(),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    scenario::model::{BigUintValue, BytesValue, CheckValue, CheckValueList, U64Value},
+    scenario::model::{BigUintCheckValue, BytesValue, CheckValue, CheckValueList, U64Value},
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
         serde_raw::CheckEsdtInstanceRaw,
@@ -9,7 +9,7 @@ use crate::{
 #[derive(Debug, Default, Clone)]
 pub struct CheckEsdtInstance {
     pub nonce: U64Value,
-    pub balance: CheckValue<BigUintValue>,
+    pub balance: CheckValue<BigUintCheckValue>,
     pub creator: CheckValue<BytesValue>,
     pub royalties: CheckValue<U64Value>,
     pub hash: CheckValue<BytesValue>,
@@ -34,7 +34,7 @@ impl InterpretableFrom<CheckEsdtInstanceRaw> for CheckEsdtInstance {
     fn interpret_from(from: CheckEsdtInstanceRaw, context: &InterpreterContext) -> Self {
         CheckEsdtInstance {
             nonce: U64Value::interpret_from(from.nonce, context),
-            balance: CheckValue::<BigUintValue>::interpret_from(from.balance, context),
+            balance: CheckValue::<BigUintCheckValue>::interpret_from(from.balance, context),
             creator: CheckValue::<BytesValue>::interpret_from(from.creator, context),
             royalties: CheckValue::<U64Value>::interpret_from(from.royalties, context),
             hash: CheckValue::<BytesValue>::interpret_from(from.hash, context),
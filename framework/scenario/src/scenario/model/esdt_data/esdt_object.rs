@@ -52,6 +52,48 @@ impl EsdtObject {
         }
     }
 
+    pub fn set_token_royalties<N, R>(&mut self, token_nonce_expr: N, royalties_expr: R)
+    where
+        U64Value: From<N>,
+        U64Value: From<R>,
+    {
+        let royalties = U64Value::from(royalties_expr);
+        let inst_for_nonce = self.get_or_insert_instance_for_nonce(token_nonce_expr);
+
+        if royalties.value > 0 {
+            inst_for_nonce.royalties = Some(royalties);
+        } else {
+            inst_for_nonce.royalties = None;
+        }
+    }
+
+    pub fn set_token_creator<N, C>(&mut self, token_nonce_expr: N, creator_expr: C)
+    where
+        U64Value: From<N>,
+        BytesValue: From<C>,
+    {
+        let inst_for_nonce = self.get_or_insert_instance_for_nonce(token_nonce_expr);
+        inst_for_nonce.creator = Some(BytesValue::from(creator_expr));
+    }
+
+    pub fn set_token_hash<N, H>(&mut self, token_nonce_expr: N, hash_expr: H)
+    where
+        U64Value: From<N>,
+        BytesValue: From<H>,
+    {
+        let inst_for_nonce = self.get_or_insert_instance_for_nonce(token_nonce_expr);
+        inst_for_nonce.hash = Some(BytesValue::from(hash_expr));
+    }
+
+    pub fn set_token_uris<N, U>(&mut self, token_nonce_expr: N, uris_expr: Vec<U>)
+    where
+        U64Value: From<N>,
+        BytesValue: From<U>,
+    {
+        let inst_for_nonce = self.get_or_insert_instance_for_nonce(token_nonce_expr);
+        inst_for_nonce.uri = uris_expr.into_iter().map(BytesValue::from).collect();
+    }
+
     pub fn set_last_nonce<N>(&mut self, last_nonce_expr: N)
     where
         U64Value: From<N>,
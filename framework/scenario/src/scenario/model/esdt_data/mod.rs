@@ -1,6 +1,7 @@
 mod esdt;
 mod esdt_check;
 mod esdt_data_check;
+mod esdt_global_settings;
 mod esdt_instance;
 mod esdt_instance_check;
 mod esdt_instances_check;
@@ -11,6 +12,7 @@ mod esdt_object;
 pub use esdt::*;
 pub use esdt_check::*;
 pub use esdt_data_check::*;
+pub use esdt_global_settings::*;
 pub use esdt_instance::*;
 pub use esdt_instance_check::*;
 pub use esdt_instances_check::*;
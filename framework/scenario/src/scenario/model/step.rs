@@ -4,6 +4,7 @@ mod into_blockchain_call;
 mod sc_call_step;
 mod sc_deploy_step;
 mod sc_query_step;
+mod scenario_tx;
 mod set_state_step;
 mod step_enum;
 mod transfer_step;
@@ -17,6 +18,7 @@ pub use into_blockchain_call::*;
 pub use sc_call_step::*;
 pub use sc_deploy_step::*;
 pub use sc_query_step::*;
+pub use scenario_tx::*;
 pub use set_state_step::*;
 pub use step_enum::*;
 pub use transfer_step::*;
@@ -7,7 +7,7 @@ use crate::{
 };
 
 use crate::multiversx_sc::{
-    codec::{CodecFrom, PanicErrorHandler, TopEncodeMulti},
+    codec::{CodecFrom, TopEncodeMulti},
     types::{ContractCall, ManagedArgBuffer},
 };
 
@@ -71,21 +71,38 @@ impl ScCallStep {
         self
     }
 
-    pub fn esdt_transfer<T, N, A>(mut self, token_id: T, token_nonce: N, amount: A) -> Self
+    pub fn esdt_transfer<T, N, A>(self, token_id: T, token_nonce: N, amount: A) -> Self
     where
         BytesValue: From<T>,
         U64Value: From<N>,
         BigUintValue: From<A>,
+    {
+        self.esdt_payment(TxESDT::new(token_id, token_nonce, amount))
+    }
+
+    /// Adds an ESDT/NFT transfer, typically from an `EsdtTokenPayment`.
+    pub fn esdt_payment<P>(mut self, payment: P) -> Self
+    where
+        TxESDT: From<P>,
     {
         if self.tx.egld_value.value > 0u32.into() {
             panic!("Cannot transfer both EGLD and ESDT");
         }
 
-        self.tx.esdt_value.push(TxESDT {
-            esdt_token_identifier: BytesValue::from(token_id),
-            nonce: U64Value::from(token_nonce),
-            esdt_value: BigUintValue::from(amount),
-        });
+        self.tx.esdt_value.push(TxESDT::from(payment));
+
+        self
+    }
+
+    /// Adds several ESDT/NFT transfers at once, they are sent as a single multi-transfer.
+    pub fn multi_esdt_transfer<I, P>(mut self, payments: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        TxESDT: From<P>,
+    {
+        for payment in payments {
+            self = self.esdt_payment(payment);
+        }
 
         self
     }
@@ -111,6 +128,14 @@ impl ScCallStep {
         self
     }
 
+    pub fn gas_price<V>(mut self, value: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.tx.gas_price = U64Value::from(value);
+        self
+    }
+
     /// Sets following fields based on the smart contract proxy:
     /// - "to"
     /// - "function"
@@ -232,12 +257,5 @@ pub fn convert_call_args(arg_buffer: &ManagedArgBuffer<StaticApi>) -> Vec<String
 }
 
 pub(super) fn format_expect<T: TopEncodeMulti>(t: T) -> TxExpect {
-    let mut encoded = Vec::<Vec<u8>>::new();
-    let Ok(()) = t.multi_encode_or_handle_err(&mut encoded, PanicErrorHandler);
-    let mut expect = TxExpect::ok().no_result();
-    for encoded_res in encoded {
-        let encoded_hex_string = format!("0x{}", hex::encode(encoded_res.as_slice()));
-        expect = expect.result(encoded_hex_string.as_str());
-    }
-    expect
+    TxExpect::ok().typed_result(t)
 }
@@ -82,7 +82,10 @@ impl ScDeployStep {
         self
     }
 
-    pub fn argument(mut self, expr: &str) -> Self {
+    pub fn argument<A>(mut self, expr: A) -> Self
+    where
+        BytesValue: From<A>,
+    {
         self.tx.arguments.push(BytesValue::from(expr));
         self
     }
@@ -95,6 +98,14 @@ impl ScDeployStep {
         self
     }
 
+    pub fn gas_price<V>(mut self, value: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.tx.gas_price = U64Value::from(value);
+        self
+    }
+
     /// Sets following fields based on the smart contract proxy:
     /// - "function"
     /// - "arguments"
@@ -0,0 +1,221 @@
+use multiversx_chain_vm::tx_execution::builtin_function_names::UPGRADE_CONTRACT_FUNC_NAME;
+
+use crate::{
+    api::StaticApi,
+    multiversx_sc::types::{CodeMetadata, ContractCall, ContractDeploy},
+    scenario::model::{AddressValue, BigUintValue, BytesValue, TxESDT, U64Value},
+};
+
+use super::{
+    sc_deploy_step::process_contract_deploy, ScCallStep, ScDeployStep, ScQueryStep, TypedScCall,
+    TypedScDeploy, TypedScQuery,
+};
+
+/// Collects the fields common to all transaction kinds: sender, recipient, payments and gas.
+///
+/// The transaction kind is only decided at the end, by calling one of
+/// `call`, `deploy`, `upgrade` or `query`, which produce the corresponding typed step.
+/// The typed steps can then be executed on any executor: a `ScenarioWorld` or an interactor.
+#[derive(Debug, Default, Clone)]
+pub struct ScenarioTx {
+    pub from: Option<AddressValue>,
+    pub to: Option<AddressValue>,
+    pub egld_value: Option<BigUintValue>,
+    pub esdt_value: Vec<TxESDT>,
+    pub gas_limit: Option<U64Value>,
+    pub code: Option<BytesValue>,
+    pub code_metadata: Option<CodeMetadata>,
+}
+
+impl ScenarioTx {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.from = Some(AddressValue::from(address));
+        self
+    }
+
+    /// Explicit recipient. Overrides the recipient of the contract proxy, if any.
+    pub fn to<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.to = Some(AddressValue::from(address));
+        self
+    }
+
+    pub fn egld_value<A>(mut self, amount: A) -> Self
+    where
+        BigUintValue: From<A>,
+    {
+        self.egld_value = Some(BigUintValue::from(amount));
+        self
+    }
+
+    pub fn esdt<P>(mut self, payment: P) -> Self
+    where
+        TxESDT: From<P>,
+    {
+        self.esdt_value.push(TxESDT::from(payment));
+        self
+    }
+
+    pub fn multi_esdt<I, P>(mut self, payments: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        TxESDT: From<P>,
+    {
+        self.esdt_value
+            .extend(payments.into_iter().map(TxESDT::from));
+        self
+    }
+
+    pub fn gas_limit<V>(mut self, value: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.gas_limit = Some(U64Value::from(value));
+        self
+    }
+
+    /// Contract code, only used for deploys and upgrades.
+    pub fn code<V>(mut self, expr: V) -> Self
+    where
+        BytesValue: From<V>,
+    {
+        self.code = Some(BytesValue::from(expr));
+        self
+    }
+
+    /// Code metadata, only used for deploys and upgrades.
+    pub fn code_metadata(mut self, code_metadata: CodeMetadata) -> Self {
+        self.code_metadata = Some(code_metadata);
+        self
+    }
+
+    /// Builds a SC call step, based on the contract proxy.
+    pub fn call<CC>(self, contract_call: CC) -> TypedScCall<CC::OriginalResult>
+    where
+        CC: ContractCall<StaticApi>,
+    {
+        let mut step = self.call_step_with_payments().call(contract_call);
+        if let Some(to) = self.to {
+            step = step.to(to);
+        }
+        step
+    }
+
+    /// Builds a SC deploy step, based on the contract proxy.
+    pub fn deploy<OriginalResult>(
+        self,
+        contract_deploy: ContractDeploy<StaticApi, OriginalResult>,
+    ) -> TypedScDeploy<OriginalResult> {
+        assert!(
+            self.esdt_value.is_empty(),
+            "cannot transfer ESDT when deploying a contract"
+        );
+
+        let mut step = ScDeployStep::new();
+        if let Some(from) = self.from {
+            step = step.from(from);
+        }
+        if let Some(egld_value) = self.egld_value {
+            step = step.egld_value(egld_value);
+        }
+        if let Some(gas_limit) = self.gas_limit {
+            step = step.gas_limit(gas_limit);
+        }
+        if let Some(code) = self.code {
+            step = step.code(code);
+        }
+        if let Some(code_metadata) = self.code_metadata {
+            step = step.code_metadata(code_metadata);
+        }
+        step.call(contract_deploy)
+    }
+
+    /// Builds a SC call step to the `upgradeContract` builtin function.
+    ///
+    /// The contract to upgrade is either given explicitly via `to`, or taken from the upgrade proxy.
+    ///
+    /// The code metadata is required, since the upgrade replaces it.
+    /// `ScenarioWorld::tx` and `Interactor::tx` fill it in with the current one of the contract, if missing.
+    pub fn upgrade<OriginalResult>(
+        self,
+        contract_deploy: ContractDeploy<StaticApi, OriginalResult>,
+    ) -> TypedScCall<OriginalResult> {
+        let code = self
+            .code
+            .clone()
+            .expect("contract code required for upgrade");
+        let code_metadata = self
+            .code_metadata
+            .expect("code metadata required for upgrade");
+        let to = self.upgrade_recipient(&contract_deploy);
+        let (_, args) = process_contract_deploy(contract_deploy);
+
+        let mut step = self
+            .call_step_with_payments()
+            .to(to)
+            .function(UPGRADE_CONTRACT_FUNC_NAME)
+            .argument(code)
+            .argument(code_metadata.to_byte_array().as_slice());
+        for arg in args {
+            step = step.argument(arg.as_str());
+        }
+        step.into()
+    }
+
+    /// The contract to upgrade: either given explicitly via `to`, or taken from the upgrade proxy.
+    pub fn upgrade_recipient<OriginalResult>(
+        &self,
+        contract_deploy: &ContractDeploy<StaticApi, OriginalResult>,
+    ) -> AddressValue {
+        self.to
+            .clone()
+            .or_else(|| {
+                contract_deploy
+                    .to
+                    .as_option()
+                    .map(|to| AddressValue::from(&*to))
+            })
+            .expect("upgrade recipient not specified")
+    }
+
+    /// Builds a SC query step, based on the contract proxy.
+    pub fn query<CC>(self, contract_call: CC) -> TypedScQuery<CC::OriginalResult>
+    where
+        CC: ContractCall<StaticApi>,
+    {
+        assert!(
+            self.egld_value.is_none() && self.esdt_value.is_empty(),
+            "cannot send payments in queries"
+        );
+
+        let mut step = ScQueryStep::new().call(contract_call);
+        if let Some(to) = self.to {
+            step = step.to(to);
+        }
+        step
+    }
+
+    fn call_step_with_payments(&self) -> ScCallStep {
+        let mut step = ScCallStep::new();
+        if let Some(from) = &self.from {
+            step = step.from(from);
+        }
+        if let Some(egld_value) = &self.egld_value {
+            step = step.egld_value(egld_value.clone());
+        }
+        step = step.multi_esdt_transfer(self.esdt_value.iter().cloned());
+        if let Some(gas_limit) = &self.gas_limit {
+            step = step.gas_limit(gas_limit.clone());
+        }
+        step
+    }
+}
@@ -1,7 +1,8 @@
 use std::collections::BTreeMap;
 
 use crate::scenario::model::{
-    Account, AddressKey, AddressValue, BlockInfo, BytesValue, NewAddress, U64Value,
+    Account, AddressKey, AddressValue, BlockInfo, BytesKey, BytesValue, EsdtGlobalSettings,
+    NewAddress, U64Value,
 };
 
 #[derive(Debug, Default, Clone)]
@@ -10,6 +11,7 @@ pub struct SetStateStep {
     pub accounts: BTreeMap<AddressKey, Account>,
     pub new_addresses: Vec<NewAddress>,
     pub new_token_identifiers: Vec<String>,
+    pub esdt_global_settings: BTreeMap<BytesKey, EsdtGlobalSettings>,
     pub block_hashes: Vec<BytesValue>,
     pub previous_block_info: Box<Option<BlockInfo>>,
     pub current_block_info: Box<Option<BlockInfo>>,
@@ -56,6 +58,15 @@ impl SetStateStep {
         self
     }
 
+    pub fn esdt_global_settings<K>(mut self, token_id_expr: K, settings: EsdtGlobalSettings) -> Self
+    where
+        BytesKey: From<K>,
+    {
+        self.esdt_global_settings
+            .insert(BytesKey::from(token_id_expr), settings);
+        self
+    }
+
     pub fn block_epoch<N>(mut self, block_epoch_expr: N) -> Self
     where
         U64Value: From<N>,
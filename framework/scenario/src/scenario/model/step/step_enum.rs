@@ -4,8 +4,8 @@ use crate::scenario_format::{
 };
 
 use crate::scenario::model::{
-    Account, AddressKey, BlockInfo, BytesValue, CheckAccounts, NewAddress, TxCall, TxDeploy,
-    TxExpect, TxQuery, TxTransfer, TxValidatorReward,
+    Account, AddressKey, BlockInfo, BytesKey, BytesValue, CheckAccounts, EsdtGlobalSettings,
+    NewAddress, TxCall, TxDeploy, TxExpect, TxQuery, TxTransfer, TxValidatorReward,
 };
 
 use super::{
@@ -43,6 +43,7 @@ impl InterpretableFrom<StepRaw> for Step {
                 accounts,
                 new_addresses,
                 new_token_identifiers,
+                esdt_global_settings,
                 block_hashes,
                 previous_block_info,
                 current_block_info,
@@ -62,6 +63,15 @@ impl InterpretableFrom<StepRaw> for Step {
                     .map(|t| NewAddress::interpret_from(t, context))
                     .collect(),
                 new_token_identifiers,
+                esdt_global_settings: esdt_global_settings
+                    .into_iter()
+                    .map(|(k, v)| {
+                        (
+                            BytesKey::interpret_from(k, context),
+                            EsdtGlobalSettings::interpret_from(v, context),
+                        )
+                    })
+                    .collect(),
                 block_hashes: block_hashes
                     .into_iter()
                     .map(|t| BytesValue::interpret_from(t, context))
@@ -128,6 +138,7 @@ impl InterpretableFrom<StepRaw> for Step {
                 tx_id,
                 comment,
                 tx: Box::new(TxTransfer::interpret_from(tx, context)),
+                response: None,
             }),
             StepRaw::ValidatorReward {
                 id,
@@ -169,6 +180,11 @@ impl IntoRaw<StepRaw> for Step {
                     .map(|na| na.into_raw())
                     .collect(),
                 new_token_identifiers: s.new_token_identifiers,
+                esdt_global_settings: s
+                    .esdt_global_settings
+                    .into_iter()
+                    .map(|(token_id, settings)| (token_id.original, settings.into_raw()))
+                    .collect(),
                 block_hashes: s.block_hashes.into_iter().map(|bh| bh.original).collect(),
                 previous_block_info: s.previous_block_info.map(|bi| bi.into_raw()),
                 current_block_info: s.current_block_info.map(|bi| bi.into_raw()),
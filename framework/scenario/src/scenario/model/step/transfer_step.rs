@@ -1,5 +1,6 @@
 use crate::scenario::model::{
-    AddressValue, BigUintValue, BytesValue, TxESDT, TxTransfer, TxValidatorReward, U64Value,
+    AddressValue, BigUintValue, BytesValue, TxESDT, TxResponse, TxTransfer, TxValidatorReward,
+    U64Value,
 };
 
 #[derive(Debug, Default, Clone)]
@@ -8,6 +9,7 @@ pub struct TransferStep {
     pub tx_id: Option<String>,
     pub comment: Option<String>,
     pub tx: Box<TxTransfer>,
+    pub response: Option<TxResponse>,
 }
 
 #[derive(Debug, Clone)]
@@ -63,11 +65,26 @@ impl TransferStep {
             panic!("Cannot transfer both EGLD and ESDT");
         }
 
-        self.tx.esdt_value.push(TxESDT {
-            esdt_token_identifier: BytesValue::from(token_id),
-            nonce: U64Value::from(token_nonce),
-            esdt_value: BigUintValue::from(amount),
-        });
+        self.tx
+            .esdt_value
+            .push(TxESDT::new(token_id, token_nonce, amount));
+
+        self
+    }
+
+    /// Adds several ESDT/NFT transfers at once, they are sent as a single multi-transfer.
+    pub fn multi_esdt_transfer<I, P>(mut self, payments: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        TxESDT: From<P>,
+    {
+        if self.tx.egld_value.value > 0u32.into() {
+            panic!("Cannot transfer both EGLD and ESDT");
+        }
+
+        self.tx
+            .esdt_value
+            .extend(payments.into_iter().map(TxESDT::from));
 
         self
     }
@@ -79,4 +96,23 @@ impl TransferStep {
         self.tx.gas_limit = U64Value::from(value);
         self
     }
+
+    pub fn gas_price<V>(mut self, value: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.tx.gas_price = U64Value::from(value);
+        self
+    }
+
+    /// Unwraps the response, if available.
+    pub fn response(&self) -> &TxResponse {
+        self.response
+            .as_ref()
+            .expect("transfer response not yet available")
+    }
+
+    pub fn save_response(&mut self, tx_response: TxResponse) {
+        self.response = Some(tx_response);
+    }
 }
@@ -5,8 +5,13 @@ use multiversx_sc::codec::PanicErrorHandler;
 use crate::multiversx_sc::codec::{CodecFrom, TopEncodeMulti};
 
 use crate::{
-    scenario::model::{AddressValue, U64Value},
-    scenario_model::{BigUintValue, BytesValue, TxExpect, TxResponse, TxResponseStatus},
+    scenario::{
+        model::{AddressValue, U64Value},
+        StepExecutor,
+    },
+    scenario_model::{
+        BigUintValue, BytesValue, TxESDT, TxExpect, TxResponse, TxResponseStatus, TypedResponse,
+    },
 };
 
 use super::{format_expect, ScCallStep};
@@ -68,6 +73,23 @@ impl<OriginalResult> TypedScCall<OriginalResult> {
         self
     }
 
+    pub fn esdt_payment<P>(mut self, payment: P) -> Self
+    where
+        TxESDT: From<P>,
+    {
+        self.sc_call_step = self.sc_call_step.esdt_payment(payment);
+        self
+    }
+
+    pub fn multi_esdt_transfer<I, P>(mut self, payments: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        TxESDT: From<P>,
+    {
+        self.sc_call_step = self.sc_call_step.multi_esdt_transfer(payments);
+        self
+    }
+
     pub fn function(mut self, expr: &str) -> Self {
         self.sc_call_step = self.sc_call_step.function(expr);
         self
@@ -114,6 +136,26 @@ impl<OriginalResult> TypedScCall<OriginalResult> {
         self.expect(format_expect(expected_value))
     }
 
+    /// Expects the given result, encoded with the same codec as the contract,
+    /// e.g. `.expect_result(5usize)`.
+    ///
+    /// Unlike `expect_value`, it keeps the other expectations already set, e.g. the logs.
+    pub fn expect_result<ExpectedResult>(mut self, expected_result: ExpectedResult) -> Self
+    where
+        OriginalResult: TopEncodeMulti,
+        ExpectedResult: CodecFrom<OriginalResult> + TopEncodeMulti,
+    {
+        let expect = self.sc_call_step.expect.take().unwrap_or_else(TxExpect::ok);
+        self.expect(expect.typed_result(expected_result))
+    }
+
+    /// Expects the tx to fail with the given status and message,
+    /// e.g. `.expect_error(4, "quorum not reached")`.
+    pub fn expect_error(mut self, status: u64, message: &str) -> Self {
+        let expect = self.sc_call_step.expect.take().unwrap_or_else(TxExpect::ok);
+        self.expect(expect.typed_error(status, message))
+    }
+
     /// Unwraps the response, if available.
     pub fn response(&self) -> &TxResponse {
         self.sc_call_step.response()
@@ -168,4 +210,17 @@ where
     {
         executor.execute_typed_sc_call(self)
     }
+
+    /// Executes the operation on any `StepExecutor`, i.e. either on the mock or on a real blockchain.
+    pub async fn run_on<E, RequestedResult>(
+        mut self,
+        executor: &mut E,
+    ) -> TypedResponse<RequestedResult>
+    where
+        E: StepExecutor + ?Sized,
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        executor.execute_sc_call(&mut self.sc_call_step).await;
+        TypedResponse::from_raw(self.response())
+    }
 }
@@ -8,8 +8,9 @@ use crate::{
         codec::{CodecFrom, TopEncodeMulti},
         types::{Address, CodeMetadata},
     },
+    scenario::StepExecutor,
     scenario_format::interpret_trait::InterpreterContext,
-    scenario_model::{BytesValue, TxResponse, TxResponseStatus},
+    scenario_model::{BytesValue, TxResponse, TxResponseStatus, TypedResponse},
 };
 
 use crate::scenario::model::{AddressValue, BigUintValue, TxExpect, U64Value};
@@ -97,6 +98,17 @@ impl<OriginalResult> TypedScDeploy<OriginalResult> {
         self
     }
 
+    /// Expects the deploy to fail with the given status and message,
+    /// e.g. `.expect_error(4, "invalid quorum")`.
+    pub fn expect_error(mut self, status: u64, message: &str) -> Self {
+        let expect = self
+            .sc_deploy_step
+            .expect
+            .take()
+            .unwrap_or_else(TxExpect::ok);
+        self.expect(expect.typed_error(status, message))
+    }
+
     /// Sets following fields based on the smart contract proxy:
     /// - "function"
     /// - "arguments"
@@ -162,4 +174,24 @@ where
     {
         executor.execute_typed_sc_deploy(self)
     }
+
+    /// Executes the operation on any `StepExecutor`, i.e. either on the mock or on a real blockchain.
+    ///
+    /// Returns the new contract address and the typed response.
+    pub async fn run_on<E, RequestedResult>(
+        mut self,
+        executor: &mut E,
+    ) -> (Address, TypedResponse<RequestedResult>)
+    where
+        E: StepExecutor + ?Sized,
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        executor.execute_sc_deploy(&mut self.sc_deploy_step).await;
+        let response = self.response();
+        let new_address = response
+            .new_deployed_address
+            .clone()
+            .expect("missing new address after deploy");
+        (new_address, TypedResponse::from_raw(response))
+    }
 }
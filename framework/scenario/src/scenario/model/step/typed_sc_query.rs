@@ -3,8 +3,11 @@ use std::marker::PhantomData;
 use crate::multiversx_sc::codec::{CodecFrom, TopEncodeMulti};
 
 use crate::{
-    scenario::model::{AddressValue, BytesValue, TxExpect},
-    scenario_model::TxResponse,
+    scenario::{
+        model::{AddressValue, BytesValue, TxExpect},
+        StepExecutor,
+    },
+    scenario_model::{TxResponse, TypedResponse},
 };
 
 use super::{format_expect, ScQueryStep};
@@ -77,6 +80,34 @@ impl<OriginalResult> TypedScQuery<OriginalResult> {
         self.expect(format_expect(expected_value))
     }
 
+    /// Expects the given result, encoded with the same codec as the contract,
+    /// e.g. `.expect_result(5usize)`.
+    ///
+    /// Unlike `expect_value`, it keeps the other expectations already set, e.g. the logs.
+    pub fn expect_result<ExpectedResult>(mut self, expected_result: ExpectedResult) -> Self
+    where
+        OriginalResult: TopEncodeMulti,
+        ExpectedResult: CodecFrom<OriginalResult> + TopEncodeMulti,
+    {
+        let expect = self
+            .sc_query_step
+            .expect
+            .take()
+            .unwrap_or_else(TxExpect::ok);
+        self.expect(expect.typed_result(expected_result))
+    }
+
+    /// Expects the tx to fail with the given status and message,
+    /// e.g. `.expect_error(4, "quorum not reached")`.
+    pub fn expect_error(mut self, status: u64, message: &str) -> Self {
+        let expect = self
+            .sc_query_step
+            .expect
+            .take()
+            .unwrap_or_else(TxExpect::ok);
+        self.expect(expect.typed_error(status, message))
+    }
+
     /// Unwraps the response, if available.
     pub fn response(&self) -> &TxResponse {
         self.sc_query_step.response()
@@ -122,4 +153,17 @@ where
     {
         executor.execute_typed_sc_query(self)
     }
+
+    /// Executes the operation on any `StepExecutor`, i.e. either on the mock or on a real blockchain.
+    pub async fn run_on<E, RequestedResult>(
+        mut self,
+        executor: &mut E,
+    ) -> TypedResponse<RequestedResult>
+    where
+        E: StepExecutor + ?Sized,
+        RequestedResult: CodecFrom<OriginalResult>,
+    {
+        executor.execute_sc_query(&mut self.sc_query_step).await;
+        TypedResponse::from_raw(self.response())
+    }
 }
@@ -10,6 +10,7 @@ mod tx_query;
 mod tx_response;
 mod tx_response_status;
 mod tx_response_utils;
+mod tx_result_handler;
 mod tx_transfer;
 mod tx_validator_reward;
 mod typed_response;
@@ -25,6 +26,7 @@ pub use tx_query::*;
 pub use tx_response::TxResponse;
 pub use tx_response_status::TxResponseStatus;
 pub use tx_response_utils::*;
+pub use tx_result_handler::*;
 pub use tx_transfer::*;
 pub use tx_validator_reward::*;
 pub use typed_response::TypedResponse;
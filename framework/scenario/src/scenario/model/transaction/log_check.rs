@@ -1,4 +1,5 @@
 use crate::{
+    multiversx_sc::codec::{top_encode_to_vec_u8_or_panic, TopEncode},
     scenario::model::{BytesValue, CheckValue, CheckValueList},
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
@@ -14,6 +15,75 @@ pub struct CheckLog {
     pub data: CheckValue<BytesValue>,
 }
 
+impl Default for CheckLog {
+    fn default() -> Self {
+        CheckLog {
+            address: CheckValue::Star,
+            endpoint: CheckValue::Star,
+            topics: CheckValue::Star,
+            data: CheckValue::Star,
+        }
+    }
+}
+
+impl CheckLog {
+    /// Matches any log, until restricted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn address<V>(mut self, address_expr: V) -> Self
+    where
+        BytesValue: From<V>,
+    {
+        self.address = CheckValue::Equal(BytesValue::from(address_expr));
+        self
+    }
+
+    pub fn endpoint<V>(mut self, endpoint_expr: V) -> Self
+    where
+        BytesValue: From<V>,
+    {
+        self.endpoint = CheckValue::Equal(BytesValue::from(endpoint_expr));
+        self
+    }
+
+    /// Adds an expected topic, given as a scenario value expression, e.g. `"str:..."`, `"*"`.
+    pub fn topic(mut self, topic_expr: &str) -> Self {
+        let topic = if topic_expr == "*" {
+            CheckValue::Star
+        } else {
+            CheckValue::Equal(BytesValue::from(topic_expr))
+        };
+        self.push_topic(topic);
+        self
+    }
+
+    /// Adds an expected topic, given as a typed value, encoded the same way the contract encodes event topics.
+    pub fn typed_topic<T: TopEncode>(mut self, topic: T) -> Self {
+        let encoded = top_encode_to_vec_u8_or_panic(&topic);
+        self.push_topic(CheckValue::Equal(BytesValue::from(encoded)));
+        self
+    }
+
+    pub fn data<V>(mut self, data_expr: V) -> Self
+    where
+        BytesValue: From<V>,
+    {
+        self.data = CheckValue::Equal(BytesValue::from(data_expr));
+        self
+    }
+
+    fn push_topic(&mut self, topic: CheckValue<BytesValue>) {
+        if let CheckValue::Star = self.topics {
+            self.topics = CheckValue::Equal(Vec::new());
+        }
+        if let CheckValue::Equal(topics) = &mut self.topics {
+            topics.push(topic);
+        }
+    }
+}
+
 impl InterpretableFrom<CheckLogRaw> for CheckLog {
     fn interpret_from(from: CheckLogRaw, context: &InterpreterContext) -> Self {
         CheckLog {
@@ -5,10 +5,13 @@ use crate::scenario_format::{
 
 use super::CheckLog;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct CheckLogList {
     pub list: Vec<CheckLog>,
     pub more_allowed_at_end: bool,
+
+    /// If set, the expected logs can appear in any order.
+    pub unordered: bool,
 }
 
 impl InterpretableFrom<CheckLogListRaw> for CheckLogList {
@@ -20,6 +23,7 @@ impl InterpretableFrom<CheckLogListRaw> for CheckLogList {
                 .map(|c| CheckLog::interpret_from(c, context))
                 .collect(),
             more_allowed_at_end: from.more_allowed_at_end,
+            unordered: from.unordered,
         }
     }
 }
@@ -29,6 +33,7 @@ impl IntoRaw<CheckLogListRaw> for CheckLogList {
         CheckLogListRaw {
             list: self.list.into_iter().map(|c| c.into_raw()).collect(),
             more_allowed_at_end: self.more_allowed_at_end,
+            unordered: self.unordered,
         }
     }
 }
@@ -43,6 +48,17 @@ impl CheckLogs {
     pub fn is_star(&self) -> bool {
         matches!(self, CheckLogs::Star)
     }
+
+    /// Switches to an explicit list, if not already, so that expectations can be added to it.
+    pub fn list_mut(&mut self) -> &mut CheckLogList {
+        if let CheckLogs::Star = self {
+            *self = CheckLogs::List(CheckLogList::default());
+        }
+        match self {
+            CheckLogs::List(list) => list,
+            CheckLogs::Star => unreachable!(),
+        }
+    }
 }
 
 impl InterpretableFrom<CheckLogsRaw> for CheckLogs {
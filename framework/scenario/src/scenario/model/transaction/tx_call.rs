@@ -122,4 +122,22 @@ impl TxCall {
         }
         contract_call
     }
+
+    /// The data field of the equivalent blockchain transaction, ESDT payments included.
+    pub fn to_tx_data(&self) -> String {
+        let contract_call = self.to_contract_call();
+        let mut result = String::from_utf8(
+            contract_call
+                .basic
+                .endpoint_name
+                .to_boxed_bytes()
+                .into_vec(),
+        )
+        .unwrap();
+        for argument in contract_call.basic.arg_buffer.raw_arg_iter() {
+            result.push('@');
+            result.push_str(hex::encode(argument.to_boxed_bytes().as_slice()).as_str());
+        }
+        result
+    }
 }
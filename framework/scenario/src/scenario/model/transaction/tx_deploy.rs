@@ -39,7 +39,6 @@ impl InterpretableFrom<TxDeployRaw> for TxDeploy {
         TxDeploy {
             from: AddressValue::interpret_from(from.from, context),
             egld_value: interpret_egld_value(from.value, from.egld_value, context),
-            code_metadata: CodeMetadata::empty(), // not yet modelled in scenarios
             contract_code: BytesValue::interpret_from(from.contract_code, context),
             arguments: from
                 .arguments
@@ -48,6 +47,7 @@ impl InterpretableFrom<TxDeployRaw> for TxDeploy {
                 .collect(),
             gas_limit: U64Value::interpret_from(from.gas_limit, context),
             gas_price: U64Value::interpret_from(from.gas_price, context),
+            ..Default::default()
         }
     }
 }
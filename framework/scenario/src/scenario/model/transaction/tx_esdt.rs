@@ -1,4 +1,5 @@
 use crate::{
+    multiversx_sc::{api::ManagedTypeApi, types::EsdtTokenPayment},
     scenario::model::{BigUintValue, BytesValue, U64Value},
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
@@ -13,6 +14,37 @@ pub struct TxESDT {
     pub esdt_value: BigUintValue,
 }
 
+impl TxESDT {
+    pub fn new<T, N, A>(token_id: T, token_nonce: N, amount: A) -> Self
+    where
+        BytesValue: From<T>,
+        U64Value: From<N>,
+        BigUintValue: From<A>,
+    {
+        TxESDT {
+            esdt_token_identifier: BytesValue::from(token_id),
+            nonce: U64Value::from(token_nonce),
+            esdt_value: BigUintValue::from(amount),
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> From<&EsdtTokenPayment<M>> for TxESDT {
+    fn from(from: &EsdtTokenPayment<M>) -> Self {
+        TxESDT::new(
+            &from.token_identifier,
+            from.token_nonce,
+            from.amount.clone(),
+        )
+    }
+}
+
+impl<M: ManagedTypeApi> From<EsdtTokenPayment<M>> for TxESDT {
+    fn from(from: EsdtTokenPayment<M>) -> Self {
+        TxESDT::new(from.token_identifier, from.token_nonce, from.amount)
+    }
+}
+
 impl InterpretableFrom<TxESDTRaw> for TxESDT {
     fn interpret_from(from: TxESDTRaw, context: &InterpreterContext) -> Self {
         TxESDT {
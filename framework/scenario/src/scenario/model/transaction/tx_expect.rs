@@ -1,6 +1,6 @@
 use super::TxResponse;
 use crate::{
-    scenario::model::{BytesValue, CheckLogs, CheckValue, CheckValueList, U64Value},
+    scenario::model::{BytesValue, CheckLog, CheckLogs, CheckValue, CheckValueList, U64Value},
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
         serde_raw::TxExpectRaw,
@@ -8,6 +8,10 @@ use crate::{
     scenario_model::Checkable,
 };
 use multiversx_chain_vm::tx_mock::result_values_to_string;
+use multiversx_sc::{
+    codec::{PanicErrorHandler, TopEncodeMulti},
+    types::CodedSCError,
+};
 
 const USER_ERROR_CODE: u64 = 4;
 
@@ -64,6 +68,25 @@ impl TxExpect {
         Self::err(USER_ERROR_CODE, err_msg_expr)
     }
 
+    /// Expects the error signalled by `require!` or `sc_panic!` with `code = ...`.
+    pub fn user_error_with_code(code: u32, err_msg: &str) -> Self {
+        Self::user_error(format!("str:[{code}] {err_msg}").as_str())
+    }
+
+    /// Expects the error signalled when an endpoint returns a `#[derive(SCError)]` variant.
+    pub fn coded_error<E: CodedSCError>(err: &E) -> Self {
+        Self::user_error_with_code(err.error_code(), err.error_message())
+    }
+
+    /// The gas remaining after the transaction. Only checked by the contract debugger if gas metering is enabled.
+    pub fn gas<G>(mut self, gas_remaining_expr: G) -> Self
+    where
+        U64Value: From<G>,
+    {
+        self.gas = CheckValue::Equal(U64Value::from(gas_remaining_expr));
+        self
+    }
+
     pub fn no_result(mut self) -> Self {
         self.out = CheckValue::Equal(Vec::new());
         self.build_from_response = false;
@@ -84,6 +107,44 @@ impl TxExpect {
         self
     }
 
+    /// Expects the given value as result, encoded with the same codec as the contract.
+    ///
+    /// Multi-values are expected as several results.
+    pub fn typed_result<T: TopEncodeMulti>(mut self, value: T) -> Self {
+        let mut encoded = Vec::<Vec<u8>>::new();
+        let Ok(()) = value.multi_encode_or_handle_err(&mut encoded, PanicErrorHandler);
+        self = self.no_result();
+        for encoded_res in encoded {
+            self = self.result(format!("0x{}", hex::encode(encoded_res)).as_str());
+        }
+        self
+    }
+
+    /// Expects the given status and error message, given as plain text.
+    pub fn typed_error(mut self, status: u64, message: &str) -> Self {
+        self.status = CheckValue::Equal(U64Value::from(status));
+        self.message = CheckValue::Equal(BytesValue::from(format!("str:{message}").as_str()));
+        self
+    }
+
+    /// Adds an expected log. By default logs are matched in order and no other logs are allowed.
+    pub fn log(mut self, check_log: CheckLog) -> Self {
+        self.logs.list_mut().list.push(check_log);
+        self
+    }
+
+    /// Allows additional logs after the expected ones, or anywhere in between, if the logs are unordered.
+    pub fn more_logs_allowed(mut self) -> Self {
+        self.logs.list_mut().more_allowed_at_end = true;
+        self
+    }
+
+    /// The expected logs can appear in any order.
+    pub fn logs_unordered(mut self) -> Self {
+        self.logs.list_mut().unordered = true;
+        self
+    }
+
     pub fn additional_error_message<A>(mut self, message: A) -> Self
     where
         A: AsRef<str>,
@@ -1,5 +1,7 @@
-use crate::multiversx_sc::types::Address;
-use multiversx_chain_vm::tx_mock::TxResult;
+use crate::{multiversx_sc::types::Address, scenario_model::BytesValue};
+use multiversx_chain_vm::tx_mock::{
+    assert_tx_ok, assert_tx_out_values, assert_tx_user_error, TxResult,
+};
 use multiversx_sdk::data::transaction::{
     ApiLogs, ApiSmartContractResult, Events, TransactionOnNetwork,
 };
@@ -45,15 +47,38 @@ impl TxResponse {
                 status: tx_result.result_status,
                 message: tx_result.result_message,
             },
+            logs: tx_result
+                .result_logs
+                .into_iter()
+                .map(|tx_log| Log {
+                    address: tx_log.address.as_bytes().into(),
+                    endpoint: tx_log.endpoint.into_bytes().into(),
+                    topics: tx_log.topics.into_iter().map(BytesValue::from).collect(),
+                    data: tx_log.data.into(),
+                })
+                .collect(),
+            gas: tx_result
+                .gas_usage
+                .map(|gas_usage| gas_usage.gas_used)
+                .unwrap_or_default(),
             ..Default::default()
         }
     }
 
     /// Creates a [`TxResponse`] from a [`TransactionOnNetwork`].
     pub fn from_network_tx(tx: TransactionOnNetwork) -> Self {
+        Self::from_api_results(tx.smart_contract_results.unwrap_or_default(), tx.logs)
+    }
+
+    /// Creates a [`TxResponse`] from the smart contract results and logs reported by the API,
+    /// e.g. when simulating a transaction.
+    pub fn from_api_results(
+        api_scrs: Vec<ApiSmartContractResult>,
+        api_logs: Option<ApiLogs>,
+    ) -> Self {
         let mut response = Self {
-            api_scrs: tx.smart_contract_results.unwrap_or_default(),
-            api_logs: tx.logs,
+            api_scrs,
+            api_logs,
             ..Default::default()
         };
 
@@ -101,6 +126,26 @@ impl TxResponse {
         self.tx_error.is_success()
     }
 
+    pub fn assert_ok(&self) {
+        assert_tx_ok(self.tx_error.status, &self.tx_error.message);
+    }
+
+    pub fn assert_user_error(&self, expected_message: &str) {
+        assert_tx_user_error(
+            self.tx_error.status,
+            &self.tx_error.message,
+            expected_message,
+        );
+    }
+
+    /// Checks the returned values, printing a value-by-value diff on mismatch.
+    pub fn assert_out_values<V>(&self, expected: &[V])
+    where
+        V: AsRef<[u8]>,
+    {
+        assert_tx_out_values(&self.out, expected);
+    }
+
     fn process_signal_error(&self) -> TxResponseStatus {
         if let Some(event) = self.find_log(LOG_IDENTIFIER_SIGNAL_ERROR) {
             let topics = event.topics.as_ref();
@@ -118,6 +163,7 @@ impl TxResponse {
 
     fn process(self) -> Self {
         self.process_out()
+            .process_logs()
             .process_new_deployed_address()
             .process_new_issued_token_identifier()
     }
@@ -132,6 +178,36 @@ impl TxResponse {
         self
     }
 
+    /// Converts the api logs to the same format as the logs coming from the mock VM,
+    /// with the event identifier as first topic.
+    fn process_logs(mut self) -> Self {
+        if let Some(api_logs) = &self.api_logs {
+            self.logs = api_logs
+                .events
+                .iter()
+                .map(|event| {
+                    let mut topics = vec![BytesValue::from(event.identifier.as_bytes())];
+                    for topic in event.topics.iter().flatten() {
+                        topics.push(base64::decode(topic).unwrap_or_default().into());
+                    }
+                    let data = event
+                        .data
+                        .as_ref()
+                        .map(|data| base64::decode(data).unwrap_or_default())
+                        .unwrap_or_default();
+                    Log {
+                        address: event.address.to_bytes().as_slice().into(),
+                        endpoint: BytesValue::empty(),
+                        topics,
+                        data: data.into(),
+                    }
+                })
+                .collect();
+        }
+
+        self
+    }
+
     fn process_new_deployed_address(mut self) -> Self {
         if let Some(event) = self.find_log(LOG_IDENTIFIER_SC_DEPLOY).cloned() {
             let topics = event.topics.as_ref();
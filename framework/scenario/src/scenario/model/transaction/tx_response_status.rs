@@ -1,3 +1,5 @@
+use multiversx_sc::contract_base::split_error_code;
+
 #[derive(Debug, Default, Clone)]
 /// The status of a transaction.
 pub struct TxResponseStatus {
@@ -25,6 +27,14 @@ impl TxResponseStatus {
     pub fn is_success(&self) -> bool {
         self.status == 0
     }
+
+    /// The error code given to `require!` or `sc_panic!`, if any.
+    pub fn error_code(&self) -> Option<u32> {
+        if self.is_success() {
+            return None;
+        }
+        split_error_code(self.message.as_bytes()).map(|(code, _)| code)
+    }
 }
 
 impl std::fmt::Display for TxResponseStatus {
@@ -0,0 +1,177 @@
+use std::marker::PhantomData;
+
+use multiversx_sdk::data::transaction::ApiSmartContractResult;
+
+use crate::multiversx_sc::{
+    codec::{CodecFrom, PanicErrorHandler, TopDecodeMulti, TopEncodeMulti},
+    types::Address,
+};
+
+use super::{Log, TxResponse, TxResponseStatus};
+
+/// Extracts something from a transaction response, once the transaction has been executed.
+///
+/// Handlers can be combined in tuples, in which case the results also come as a tuple.
+///
+/// `OriginalResult` is the return type of the endpoint, as declared in the contract proxy.
+pub trait TxResultHandler<OriginalResult> {
+    type Returns;
+
+    fn handle_response(self, response: &TxResponse) -> Self::Returns;
+}
+
+/// Returns the address of the newly deployed contract. Panics if there is none.
+pub struct ReturnsNewAddress;
+
+impl<OriginalResult> TxResultHandler<OriginalResult> for ReturnsNewAddress {
+    type Returns = Address;
+
+    fn handle_response(self, response: &TxResponse) -> Self::Returns {
+        response
+            .new_deployed_address
+            .clone()
+            .expect("missing new address after deploy")
+    }
+}
+
+/// Decodes the result as the type declared in the proxy. Panics if the transaction failed.
+pub struct ReturnsResult;
+
+impl<OriginalResult> TxResultHandler<OriginalResult> for ReturnsResult
+where
+    OriginalResult: TopDecodeMulti,
+{
+    type Returns = OriginalResult;
+
+    fn handle_response(self, response: &TxResponse) -> Self::Returns {
+        decode_result(response)
+    }
+}
+
+/// Decodes the result as any type compatible with the one declared in the proxy.
+/// Panics if the transaction failed.
+pub struct ReturnsResultAs<T>(PhantomData<T>);
+
+impl<T> ReturnsResultAs<T> {
+    pub fn new() -> Self {
+        ReturnsResultAs(PhantomData)
+    }
+}
+
+impl<T> Default for ReturnsResultAs<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<OriginalResult, T> TxResultHandler<OriginalResult> for ReturnsResultAs<T>
+where
+    OriginalResult: TopEncodeMulti,
+    T: CodecFrom<OriginalResult>,
+{
+    type Returns = T;
+
+    fn handle_response(self, response: &TxResponse) -> Self::Returns {
+        decode_result(response)
+    }
+}
+
+/// Returns the raw, undecoded results.
+pub struct ReturnsRawResult;
+
+impl<OriginalResult> TxResultHandler<OriginalResult> for ReturnsRawResult {
+    type Returns = Vec<Vec<u8>>;
+
+    fn handle_response(self, response: &TxResponse) -> Self::Returns {
+        response.out.clone()
+    }
+}
+
+/// Returns the status of the transaction, without checking it.
+pub struct ReturnsStatus;
+
+impl<OriginalResult> TxResultHandler<OriginalResult> for ReturnsStatus {
+    type Returns = TxResponseStatus;
+
+    fn handle_response(self, response: &TxResponse) -> Self::Returns {
+        response.tx_error.clone()
+    }
+}
+
+/// Returns all logs produced by the transaction.
+pub struct ReturnsLogs;
+
+impl<OriginalResult> TxResultHandler<OriginalResult> for ReturnsLogs {
+    type Returns = Vec<Log>;
+
+    fn handle_response(self, response: &TxResponse) -> Self::Returns {
+        response.logs.clone()
+    }
+}
+
+/// Returns the logs produced by the transaction with the given endpoint (identifier).
+pub struct ReturnsLogsWithEndpoint {
+    endpoint: Vec<u8>,
+}
+
+impl ReturnsLogsWithEndpoint {
+    pub fn new(endpoint: &str) -> Self {
+        ReturnsLogsWithEndpoint {
+            endpoint: endpoint.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl<OriginalResult> TxResultHandler<OriginalResult> for ReturnsLogsWithEndpoint {
+    type Returns = Vec<Log>;
+
+    fn handle_response(self, response: &TxResponse) -> Self::Returns {
+        response
+            .logs
+            .iter()
+            .filter(|log| log.endpoint.value == self.endpoint)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Returns the smart contract results, as received from the blockchain.
+///
+/// Only available for transactions sent to a real blockchain, always empty on the mock.
+pub struct ReturnsScrs;
+
+impl<OriginalResult> TxResultHandler<OriginalResult> for ReturnsScrs {
+    type Returns = Vec<ApiSmartContractResult>;
+
+    fn handle_response(self, response: &TxResponse) -> Self::Returns {
+        response.api_scrs.clone()
+    }
+}
+
+fn decode_result<T: TopDecodeMulti>(response: &TxResponse) -> T {
+    if !response.tx_error.is_success() {
+        panic!("transaction failed: {}", response.tx_error);
+    }
+    let mut raw_result = response.out.clone();
+    let Ok(decoded) = T::multi_decode_or_handle_err(&mut raw_result, PanicErrorHandler);
+    decoded
+}
+
+macro_rules! tuple_result_handler_impl {
+    ($($h:ident $i:tt)+) => {
+        impl<OriginalResult, $($h),+> TxResultHandler<OriginalResult> for ($($h,)+)
+        where
+            $($h: TxResultHandler<OriginalResult>,)+
+        {
+            type Returns = ($($h::Returns,)+);
+
+            fn handle_response(self, response: &TxResponse) -> Self::Returns {
+                ($(self.$i.handle_response(response),)+)
+            }
+        }
+    };
+}
+
+tuple_result_handler_impl! {H0 0 H1 1}
+tuple_result_handler_impl! {H0 0 H1 1 H2 2}
+tuple_result_handler_impl! {H0 0 H1 1 H2 2 H3 3}
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use crate::multiversx_sc::types::Address;
+use crate::multiversx_sc::{
+    api::ManagedTypeApi,
+    types::{Address, ManagedAddress},
+};
 
 use crate::scenario_format::{
     interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
@@ -107,6 +110,24 @@ impl From<&Address> for AddressValue {
     }
 }
 
+impl From<Address> for AddressValue {
+    fn from(from: Address) -> Self {
+        AddressValue::from(&from)
+    }
+}
+
+impl<M: ManagedTypeApi> From<&ManagedAddress<M>> for AddressValue {
+    fn from(from: &ManagedAddress<M>) -> Self {
+        AddressValue::from(&from.to_address())
+    }
+}
+
+impl<M: ManagedTypeApi> From<ManagedAddress<M>> for AddressValue {
+    fn from(from: ManagedAddress<M>) -> Self {
+        AddressValue::from(&from)
+    }
+}
+
 impl From<&str> for AddressValue {
     fn from(from: &str) -> Self {
         AddressValue::interpret_from(from, &InterpreterContext::default())
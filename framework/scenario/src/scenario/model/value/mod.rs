@@ -1,6 +1,7 @@
 mod address_key;
 mod address_value;
 mod value_check;
+mod value_check_big_uint;
 mod value_checkable;
 mod value_key_bytes;
 mod value_key_u64;
@@ -11,6 +12,7 @@ mod value_set_u64;
 pub use address_key::*;
 pub use address_value::*;
 pub use value_check::*;
+pub use value_check_big_uint::*;
 pub use value_checkable::*;
 pub use value_key_bytes::*;
 pub use value_key_u64::*;
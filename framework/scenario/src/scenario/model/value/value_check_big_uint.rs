@@ -0,0 +1,185 @@
+use crate::scenario_format::{
+    interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
+    serde_raw::ValueSubTree,
+    value_interpreter::{interpret_string, interpret_subtree},
+};
+
+use crate::multiversx_sc::api::ManagedTypeApi;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use std::fmt;
+
+use super::BigUintValue;
+
+const APPROX_PREFIX: &str = "~";
+const TOLERANCE_SEPARATOR: &str = "+-";
+const PERCENT_SUFFIX: &str = "%";
+
+/// Expected big unsigned value in checks, currently used for ESDT balances.
+///
+/// On top of the regular value expressions, it accepts:
+/// - decimal-scaled literals, e.g. `"1.5e18"`, which must resolve to an integer;
+/// - approximate expectations, e.g. `"~1000+-5"` or `"~1.5e18+-0.01%"`,
+///   where the tolerance is either absolute or relative to the expected value.
+#[derive(Debug, Clone)]
+pub struct BigUintCheckValue {
+    pub value: BigUint,
+    pub tolerance: BigUint,
+    pub original: ValueSubTree,
+}
+
+impl BigUintCheckValue {
+    pub fn is_exact(&self) -> bool {
+        self.tolerance.is_zero()
+    }
+}
+
+impl InterpretableFrom<ValueSubTree> for BigUintCheckValue {
+    fn interpret_from(from: ValueSubTree, context: &InterpreterContext) -> Self {
+        if let ValueSubTree::Str(s) = &from {
+            let (value, tolerance) = interpret_check_str(s, context);
+            BigUintCheckValue {
+                value,
+                tolerance,
+                original: from,
+            }
+        } else {
+            let bytes = interpret_subtree(&from, context);
+            BigUintCheckValue {
+                value: BigUint::from_bytes_be(&bytes),
+                tolerance: BigUint::zero(),
+                original: from,
+            }
+        }
+    }
+}
+
+impl InterpretableFrom<&str> for BigUintCheckValue {
+    fn interpret_from(from: &str, context: &InterpreterContext) -> Self {
+        let (value, tolerance) = interpret_check_str(from, context);
+        BigUintCheckValue {
+            value,
+            tolerance,
+            original: ValueSubTree::Str(from.to_string()),
+        }
+    }
+}
+
+impl IntoRaw<ValueSubTree> for BigUintCheckValue {
+    fn into_raw(self) -> ValueSubTree {
+        self.original
+    }
+}
+
+fn interpret_check_str(s: &str, context: &InterpreterContext) -> (BigUint, BigUint) {
+    if let Some(approx) = s.strip_prefix(APPROX_PREFIX) {
+        let (expected, tolerance) = approx.rsplit_once(TOLERANCE_SEPARATOR).unwrap_or_else(|| {
+            panic!("approximate value {s} is missing a tolerance, e.g. \"~1000+-5\"")
+        });
+        let value = interpret_exact(expected.trim(), context);
+        let tolerance = tolerance.trim();
+        let tolerance = if let Some(percent) = tolerance.strip_suffix(PERCENT_SUFFIX) {
+            let (digits, scale) = parse_decimal(percent.trim())
+                .unwrap_or_else(|| panic!("invalid percentage tolerance in {s}"));
+            &value * digits / (BigUint::from(100u32) * BigUint::from(10u32).pow(scale))
+        } else {
+            interpret_exact(tolerance, context)
+        };
+        (value, tolerance)
+    } else {
+        (interpret_exact(s, context), BigUint::zero())
+    }
+}
+
+fn interpret_exact(s: &str, context: &InterpreterContext) -> BigUint {
+    try_parse_decimal_scaled(s)
+        .unwrap_or_else(|| BigUint::from_bytes_be(&interpret_string(s, context)))
+}
+
+/// Parses `"<int>[.<frac>]"` into its digits and the number of fractional digits.
+fn parse_decimal(s: &str) -> Option<(BigUint, u32)> {
+    let clean = s.replace(&['_', ','][..], "");
+    let (int_part, frac_part) = clean.split_once('.').unwrap_or((clean.as_str(), ""));
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if int_part.is_empty() || !is_digits(int_part) || !is_digits(frac_part) {
+        return None;
+    }
+    let digits = BigUint::parse_bytes(format!("{int_part}{frac_part}").as_bytes(), 10)?;
+    Some((digits, frac_part.len() as u32))
+}
+
+/// Parses `"<int>[.<frac>]e<exponent>"`, e.g. `"1.5e18"`.
+fn try_parse_decimal_scaled(s: &str) -> Option<BigUint> {
+    let (mantissa, exponent) = s.split_once(|c| c == 'e' || c == 'E')?;
+    let exponent: u32 = exponent.parse().ok()?;
+    let (digits, scale) = parse_decimal(mantissa)?;
+    assert!(
+        scale <= exponent,
+        "decimal-scaled value {s} does not resolve to an integer"
+    );
+    Some(digits * BigUint::from(10u32).pow(exponent - scale))
+}
+
+impl From<BigUintValue> for BigUintCheckValue {
+    fn from(from: BigUintValue) -> Self {
+        BigUintCheckValue {
+            value: from.value,
+            tolerance: BigUint::zero(),
+            original: from.original,
+        }
+    }
+}
+
+impl From<u32> for BigUintCheckValue {
+    fn from(from: u32) -> Self {
+        BigUintValue::from(from).into()
+    }
+}
+
+impl From<u64> for BigUintCheckValue {
+    fn from(from: u64) -> Self {
+        BigUintValue::from(from).into()
+    }
+}
+
+impl From<u128> for BigUintCheckValue {
+    fn from(from: u128) -> Self {
+        BigUintValue::from(from).into()
+    }
+}
+
+impl From<BigUint> for BigUintCheckValue {
+    fn from(from: BigUint) -> Self {
+        BigUintValue::from(from).into()
+    }
+}
+
+impl From<&BigUint> for BigUintCheckValue {
+    fn from(from: &BigUint) -> Self {
+        BigUintValue::from(from).into()
+    }
+}
+
+impl<M: ManagedTypeApi> From<crate::multiversx_sc::types::BigUint<M>> for BigUintCheckValue {
+    fn from(from: crate::multiversx_sc::types::BigUint<M>) -> Self {
+        BigUintValue::from(from).into()
+    }
+}
+
+impl From<&str> for BigUintCheckValue {
+    fn from(from: &str) -> Self {
+        BigUintCheckValue::interpret_from(from, &InterpreterContext::default())
+    }
+}
+
+impl fmt::Display for BigUintCheckValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.original.fmt(f)
+    }
+}
+
+impl Default for BigUintCheckValue {
+    fn default() -> Self {
+        BigUintValue::default().into()
+    }
+}
@@ -1,6 +1,6 @@
 use multiversx_chain_vm::tx_mock::TxFunctionName;
 
-use super::{value_set_big_uint::*, BytesValue, CheckValue, U64Value};
+use super::{value_set_big_uint::*, BigUintCheckValue, BytesValue, CheckValue, U64Value};
 use num_bigint::BigUint;
 
 pub trait Checkable<V> {
@@ -37,6 +37,17 @@ impl Checkable<&BigUint> for BigUintValue {
     }
 }
 
+impl Checkable<&BigUint> for BigUintCheckValue {
+    fn check(&self, value: &BigUint) -> bool {
+        let diff = if value > &self.value {
+            value - &self.value
+        } else {
+            &self.value - value
+        };
+        diff <= self.tolerance
+    }
+}
+
 impl Checkable<u64> for U64Value {
     fn check(&self, value: u64) -> bool {
         self.value == value
@@ -72,9 +83,10 @@ impl Checkable<&[Vec<u8>]> for Vec<CheckValue<BytesValue>> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        scenario::model::{BytesValue, CheckValue, Checkable, U64Value},
+        scenario::model::{BigUintCheckValue, BytesValue, CheckValue, Checkable, U64Value},
         scenario_format::serde_raw::ValueSubTree,
     };
+    use num_bigint::BigUint;
 
     #[test]
     fn check_bytes() {
@@ -106,4 +118,35 @@ mod tests {
         let cb_star: CheckValue<U64Value> = CheckValue::Star;
         assert!(cb_star.check(1234567890));
     }
+
+    #[test]
+    fn check_big_uint_decimal_scaled() {
+        let bcv = BigUintCheckValue::from("1.25e3");
+        assert!(bcv.is_exact());
+        assert!(bcv.check(&BigUint::from(1250u32)));
+        assert!(!bcv.check(&BigUint::from(1251u32)));
+
+        let bcv = BigUintCheckValue::from("2e18");
+        assert!(bcv.check(&BigUint::from(2_000_000_000_000_000_000u64)));
+    }
+
+    #[test]
+    fn check_big_uint_approx() {
+        let bcv = BigUintCheckValue::from("~1000+-5");
+        assert!(bcv.check(&BigUint::from(995u32)));
+        assert!(bcv.check(&BigUint::from(1005u32)));
+        assert!(!bcv.check(&BigUint::from(994u32)));
+        assert!(!bcv.check(&BigUint::from(1006u32)));
+
+        let bcv = BigUintCheckValue::from("~1.5e18+-0.1%");
+        assert_eq!(bcv.tolerance, BigUint::from(1_500_000_000_000_000u64));
+        assert!(bcv.check(&BigUint::from(1_501_000_000_000_000_000u64)));
+        assert!(!bcv.check(&BigUint::from(1_502_000_000_000_000_000u64)));
+    }
+
+    #[test]
+    #[should_panic = "missing a tolerance"]
+    fn check_big_uint_approx_no_tolerance() {
+        let _ = BigUintCheckValue::from("~1000");
+    }
 }
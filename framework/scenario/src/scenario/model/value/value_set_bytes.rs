@@ -4,6 +4,7 @@ use crate::scenario_format::{
     value_interpreter::{interpret_string, interpret_subtree},
 };
 
+use crate::multiversx_sc::{api::ManagedTypeApi, types::TokenIdentifier};
 use std::fmt;
 
 use super::BytesKey;
@@ -84,6 +85,23 @@ impl From<Vec<u8>> for BytesValue {
     }
 }
 
+/// Token identifiers are written as `str:` expressions, to keep them readable in the generated JSON.
+impl<M: ManagedTypeApi> From<&TokenIdentifier<M>> for BytesValue {
+    fn from(from: &TokenIdentifier<M>) -> Self {
+        let value = from.to_boxed_bytes().into_vec();
+        BytesValue {
+            original: ValueSubTree::Str(format!("str:{}", String::from_utf8_lossy(&value))),
+            value,
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> From<TokenIdentifier<M>> for BytesValue {
+    fn from(from: TokenIdentifier<M>) -> Self {
+        BytesValue::from(&from)
+    }
+}
+
 impl From<BytesKey> for BytesValue {
     fn from(from: BytesKey) -> Self {
         BytesValue {
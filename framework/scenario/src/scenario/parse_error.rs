@@ -0,0 +1,102 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// Describes why a scenario file could not be loaded.
+///
+/// Carries enough context to locate the problem in the JSON file:
+/// the file path, the index of the offending step (if the error is inside a step),
+/// the name of the offending field (if serde reported one) and the line/column in the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioParseError {
+    pub path: PathBuf,
+    pub step_index: Option<usize>,
+    pub field: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ScenarioParseError {
+    pub(crate) fn io(path: &Path, err: std::io::Error) -> Self {
+        ScenarioParseError {
+            path: path.to_path_buf(),
+            step_index: None,
+            field: None,
+            line: 0,
+            column: 0,
+            message: err.to_string(),
+        }
+    }
+
+    pub(crate) fn serde(path: &Path, step_index: Option<usize>, err: &serde_json::Error) -> Self {
+        ScenarioParseError {
+            path: path.to_path_buf(),
+            step_index,
+            field: field_from_serde_message(&err.to_string()),
+            line: err.line(),
+            column: err.column(),
+            message: err.to_string(),
+        }
+    }
+
+    /// The JSON is well-formed, but one of the values in the step could not be interpreted.
+    pub(crate) fn interpret(path: &Path, step_index: usize, message: String) -> Self {
+        ScenarioParseError {
+            path: path.to_path_buf(),
+            step_index: Some(step_index),
+            field: None,
+            line: 0,
+            column: 0,
+            message,
+        }
+    }
+}
+
+/// Serde reports field problems as "missing field `x`", "unknown field `x`", etc.
+fn field_from_serde_message(message: &str) -> Option<String> {
+    let start = message.find('`')? + 1;
+    let len = message[start..].find('`')?;
+    Some(message[start..start + len].to_string())
+}
+
+impl fmt::Display for ScenarioParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path.display())?;
+        if self.line > 0 {
+            write!(f, ":{}:{}", self.line, self.column)?;
+        }
+        if let Some(step_index) = self.step_index {
+            write!(f, ", step #{step_index}")?;
+        }
+        if let Some(field) = &self.field {
+            write!(f, ", field `{field}`")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for ScenarioParseError {}
+
+/// Aggregates the parse errors of multiple scenario files.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ScenarioParseReport {
+    pub errors: Vec<ScenarioParseError>,
+}
+
+impl ScenarioParseReport {
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl fmt::Display for ScenarioParseReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} scenario file(s) failed to parse:", self.errors.len())?;
+        for error in &self.errors {
+            writeln!(f, "  - {error}")?;
+        }
+        Ok(())
+    }
+}
@@ -1,24 +1,128 @@
 use crate::{
-    scenario::model::Scenario,
+    scenario::model::{Scenario, Step},
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext},
-        serde_raw::ScenarioRaw,
+        serde_raw::{ScenarioRaw, StepRaw},
+        value_interpreter::FileRegistry,
     },
 };
 
-use std::{fs, path::Path};
+use std::{fs, panic::AssertUnwindSafe, path::Path};
+
+use super::{ScenarioParseError, ScenarioParseReport};
 
 pub fn parse_scenario_raw<P: AsRef<Path>>(path: P) -> ScenarioRaw {
-    let contents = fs::read_to_string(path.as_ref())
-        .unwrap_or_else(|e| panic!("not found: {} {:?}", e, path.as_ref()));
-    serde_json::from_str(contents.as_str()).unwrap()
+    try_parse_scenario_raw(path).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Same as `parse_scenario_raw`, but returns an error with file/step/field context instead of panicking.
+pub fn try_parse_scenario_raw<P: AsRef<Path>>(path: P) -> Result<ScenarioRaw, ScenarioParseError> {
+    let path = path.as_ref();
+    let contents = fs::read_to_string(path).map_err(|err| ScenarioParseError::io(path, err))?;
+    serde_json::from_str(contents.as_str())
+        .map_err(|err| locate_step_error(path, contents.as_str(), err))
+}
+
+/// Serde only reports line and column. To also point to the step,
+/// the steps are deserialized one by one, until the failing one is found.
+fn locate_step_error(path: &Path, contents: &str, err: serde_json::Error) -> ScenarioParseError {
+    let root = match serde_json::from_str::<serde_json::Value>(contents) {
+        Ok(serde_json::Value::Object(root)) => root,
+        _ => return ScenarioParseError::serde(path, None, &err),
+    };
+    if let Some(serde_json::Value::Array(steps)) = root.get("steps") {
+        for (step_index, step) in steps.iter().enumerate() {
+            if serde_json::from_value::<StepRaw>(step.clone()).is_err() {
+                let mut error = ScenarioParseError::serde(path, Some(step_index), &err);
+                if error.field.is_none() {
+                    error.field = step
+                        .get("step")
+                        .and_then(serde_json::Value::as_str)
+                        .map(|step_type| format!("step: {step_type}"));
+                }
+                return error;
+            }
+        }
+    }
+    ScenarioParseError::serde(path, None, &err)
 }
 
 pub fn parse_scenario<P: AsRef<Path>>(path: P) -> Scenario {
-    let scenario_parent = path.as_ref().parent().unwrap();
+    try_parse_scenario(path).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Same as `parse_scenario`, but returns an error with file/step/field context instead of panicking.
+pub fn try_parse_scenario<P: AsRef<Path>>(path: P) -> Result<Scenario, ScenarioParseError> {
+    try_parse_scenario_with_file_registry(path, &FileRegistry::new())
+}
+
+/// Same as `parse_scenario`, but the referenced files are resolved and cached via the given registry.
+pub fn parse_scenario_with_file_registry<P: AsRef<Path>>(
+    path: P,
+    file_registry: &FileRegistry,
+) -> Scenario {
+    try_parse_scenario_with_file_registry(path, file_registry).unwrap_or_else(|err| panic!("{err}"))
+}
+
+pub fn try_parse_scenario_with_file_registry<P: AsRef<Path>>(
+    path: P,
+    file_registry: &FileRegistry,
+) -> Result<Scenario, ScenarioParseError> {
+    let path = path.as_ref();
+    let scenario_parent = path.parent().unwrap_or_else(|| Path::new(""));
     let interpreter_context = InterpreterContext::new()
         .with_dir(scenario_parent.into())
-        .with_allowed_missing_files();
-    let raw = parse_scenario_raw(path);
-    Scenario::interpret_from(raw, &interpreter_context)
+        .with_allowed_missing_files()
+        .with_file_registry(file_registry.clone());
+    let raw = try_parse_scenario_raw(path)?;
+    let steps = raw
+        .steps
+        .into_iter()
+        .enumerate()
+        .map(|(step_index, step)| try_interpret_step(path, step_index, step, &interpreter_context))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Scenario {
+        name: raw.name,
+        comment: raw.comment,
+        check_gas: raw.check_gas,
+        steps,
+    })
+}
+
+/// The value interpreter panics on malformed values (e.g. an invalid number or address expression),
+/// the panic is turned into an error pointing to the step.
+fn try_interpret_step(
+    path: &Path,
+    step_index: usize,
+    step: StepRaw,
+    interpreter_context: &InterpreterContext,
+) -> Result<Step, ScenarioParseError> {
+    std::panic::catch_unwind(AssertUnwindSafe(|| {
+        Step::interpret_from(step, interpreter_context)
+    }))
+    .map_err(|payload| {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "could not interpret step".to_string()
+        };
+        ScenarioParseError::interpret(path, step_index, message)
+    })
+}
+
+/// Parses and interprets all given files, collecting all errors in a single report.
+pub fn check_scenarios_parse<P: AsRef<Path>>(paths: &[P]) -> Result<(), ScenarioParseReport> {
+    let report = ScenarioParseReport {
+        errors: paths
+            .iter()
+            .filter_map(|path| try_parse_scenario(path).err())
+            .collect(),
+    };
+    if report.is_empty() {
+        Ok(())
+    } else {
+        Err(report)
+    }
 }
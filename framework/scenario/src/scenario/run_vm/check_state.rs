@@ -2,18 +2,28 @@ use crate::scenario::model::{
     AddressKey, BytesValue, CheckAccounts, CheckEsdt, CheckEsdtData, CheckEsdtInstance,
     CheckEsdtInstances, CheckEsdtMap, CheckStateStep, CheckStorage, CheckValue, Checkable,
 };
+use multiversx_sc_meta::abi_decode::StorageDecoder;
 use num_traits::Zero;
+use std::collections::HashMap;
 
 use multiversx_chain_vm::{
+    crypto_functions::blake2b_256,
     display_util::{bytes_to_string, verbose_hex, verbose_hex_list},
     world_mock::{AccountEsdt, BlockchainState, EsdtData, EsdtInstance, EsdtInstances},
 };
 
-use super::ScenarioVMRunner;
+use super::{
+    check_state_diff::{pretty_storage_key, AccountDiff, CheckStateDiff},
+    ScenarioVMRunner,
+};
 
 impl ScenarioVMRunner {
     pub fn perform_check_state(&mut self, check_state_step: &CheckStateStep) {
-        execute(&self.blockchain_mock.state, &check_state_step.accounts);
+        execute(
+            &self.blockchain_mock.state,
+            &check_state_step.accounts,
+            &self.storage_decoders,
+        );
     }
 
     pub fn perform_dump_state(&mut self) {
@@ -21,142 +31,207 @@ impl ScenarioVMRunner {
     }
 }
 
-fn execute(state: &BlockchainState, accounts: &CheckAccounts) {
+fn execute(
+    state: &BlockchainState,
+    accounts: &CheckAccounts,
+    storage_decoders: &HashMap<Vec<u8>, StorageDecoder>,
+) {
+    let diff = compute_diff(state, accounts, storage_decoders);
+    assert!(diff.is_empty(), "{diff}");
+}
+
+fn compute_diff(
+    state: &BlockchainState,
+    accounts: &CheckAccounts,
+    storage_decoders: &HashMap<Vec<u8>, StorageDecoder>,
+) -> CheckStateDiff {
+    let mut diff = CheckStateDiff::default();
     for (expected_address, expected_account) in accounts.accounts.iter() {
+        let mut account_diff = AccountDiff::new(expected_address.to_string());
         if let Some(account) = state.accounts.get(&expected_address.to_vm_address()) {
-            assert!(
-                expected_account.nonce.check(account.nonce),
-                "bad account nonce. Address: {}. Want: {}. Have: {}",
-                expected_address,
-                expected_account.nonce,
-                account.nonce
-            );
+            if !expected_account.nonce.check(account.nonce) {
+                account_diff.push("nonce", &expected_account.nonce, account.nonce);
+            }
 
-            assert!(
-                expected_account.balance.check(&account.egld_balance),
-                "bad account balance. Address: {}. Want: {}. Have: {}",
-                expected_address,
-                expected_account.balance,
-                account.egld_balance
-            );
+            if !expected_account.balance.check(&account.egld_balance) {
+                account_diff.push("balance", &expected_account.balance, &account.egld_balance);
+            }
+
+            if !expected_account.username.check(&account.username) {
+                account_diff.push(
+                    "username",
+                    &expected_account.username,
+                    bytes_to_string(account.username.as_slice()),
+                );
+            }
 
-            assert!(
-                expected_account.username.check(&account.username),
-                "bad account username. Address: {}. Want: {}. Have: {}",
-                expected_address,
-                expected_account.username,
-                std::str::from_utf8(account.username.as_slice()).unwrap()
-            );
             let default_value = &Vec::new();
             let actual_code = account.contract_path.as_ref().unwrap_or(default_value);
-            assert!(
-                expected_account.code.check(actual_code),
-                "bad account code. Address: {}. Want: {}. Have: {}",
-                expected_address,
-                expected_account.code,
-                std::str::from_utf8(actual_code.as_slice()).unwrap()
-            );
+            if !expected_account.code.check(actual_code) {
+                account_diff.push(
+                    "code",
+                    &expected_account.code,
+                    bytes_to_string(actual_code.as_slice()),
+                );
+            }
 
-            assert!(
-                expected_account
-                    .developer_rewards
-                    .check(&account.developer_rewards),
-                "bad account developerRewards. Address: {}. Want: {}. Have: {}",
-                expected_address,
-                expected_account.developer_rewards,
-                account.developer_rewards
-            );
+            let actual_code_hash = account
+                .contract_path
+                .as_ref()
+                .map(|code| blake2b_256(code).to_vec())
+                .unwrap_or_default();
+            if !expected_account.code_hash.check(&actual_code_hash) {
+                account_diff.push(
+                    "codeHash",
+                    &expected_account.code_hash,
+                    verbose_hex(&actual_code_hash),
+                );
+            }
+
+            if !expected_account
+                .developer_rewards
+                .check(&account.developer_rewards)
+            {
+                account_diff.push(
+                    "developerRewards",
+                    &expected_account.developer_rewards,
+                    &account.developer_rewards,
+                );
+            }
 
             if let CheckStorage::Equal(eq) = &expected_account.storage {
+                let storage_decoder = account
+                    .contract_path
+                    .as_ref()
+                    .and_then(|code| storage_decoders.get(code));
                 let default_value = &Vec::new();
                 for (expected_key, expected_value) in eq.storages.iter() {
                     let actual_value = account
                         .storage
                         .get(&expected_key.value)
                         .unwrap_or(default_value);
-                    assert!(
-                        expected_value.check(actual_value),
-                        "bad storage value. Address: {}. Key: {}. Want: {}. Have: {}",
-                        expected_address,
-                        expected_key,
-                        expected_value,
-                        verbose_hex(actual_value)
-                    );
-                }
-
-                let default_check_value = CheckValue::Equal(BytesValue::empty());
-                for (actual_key, actual_value) in account.storage.iter() {
-                    let expected_value = eq
-                        .storages
-                        .get(&actual_key.clone().into())
-                        .unwrap_or(&default_check_value);
-                    if expected_value.to_string() == default_check_value.to_string()
-                        && !eq.other_storages_allowed
-                    {
-                        assert!(
-                            expected_value.check(actual_value),
-                            "bad storage value. Address: {}. Key: {}. Want: {}. Have: {}",
-                            expected_address,
-                            verbose_hex(actual_key),
+                    if !expected_value.check(actual_value) {
+                        push_storage_diff(
+                            &mut account_diff,
+                            storage_decoder,
+                            expected_key.to_string(),
+                            &expected_key.value,
                             expected_value,
-                            verbose_hex(actual_value)
+                            actual_value,
                         );
                     }
                 }
+
+                let default_check_value = CheckValue::Equal(BytesValue::empty());
+                let mut unexpected_keys: Vec<&Vec<u8>> = account
+                    .storage
+                    .iter()
+                    .filter(|(actual_key, actual_value)| {
+                        !eq.other_storages_allowed
+                            && !eq.storages.contains_key(&(*actual_key).clone().into())
+                            && !default_check_value.check(*actual_value)
+                    })
+                    .map(|(actual_key, _)| actual_key)
+                    .collect();
+                unexpected_keys.sort();
+                for actual_key in unexpected_keys {
+                    push_storage_diff(
+                        &mut account_diff,
+                        storage_decoder,
+                        pretty_storage_key(actual_key),
+                        actual_key,
+                        &default_check_value,
+                        &account.storage[actual_key],
+                    );
+                }
             }
-            check_account_esdt(expected_address, &expected_account.esdt, &account.esdt);
-        } else {
-            assert!(
-                accounts.other_accounts_allowed,
-                "Expected account not found"
-            );
+            push_account_esdt_diff(&expected_account.esdt, &account.esdt, &mut account_diff);
+        } else if !accounts.other_accounts_allowed {
+            account_diff.push("account", "present", "missing");
         }
+        diff.push_account(account_diff);
+    }
+    diff
+}
+
+/// When the ABI of the contract is registered, the key and the actual value are also shown decoded,
+/// e.g. `storage str:rate_limit:cooldown|0x00000005636c61696d (cooldown(action: "claim"))`.
+fn push_storage_diff(
+    account_diff: &mut AccountDiff,
+    storage_decoder: Option<&StorageDecoder>,
+    key_expr: String,
+    key: &[u8],
+    expected_value: &CheckValue<BytesValue>,
+    actual_value: &[u8],
+) {
+    match storage_decoder.map(|decoder| decoder.decode_entry(key, actual_value)) {
+        Some(decoded) if decoded.name.is_some() => account_diff.push(
+            format!("storage {key_expr} ({})", decoded.key_description()),
+            expected_value,
+            format!("{} ({})", verbose_hex(actual_value), decoded.value),
+        ),
+        _ => account_diff.push(
+            format!("storage {key_expr}"),
+            expected_value,
+            verbose_hex(actual_value),
+        ),
     }
 }
 
+/// Panics with the ESDT differences of the account, if there are any.
 pub fn check_account_esdt(address: &AddressKey, expected: &CheckEsdtMap, actual: &AccountEsdt) {
+    let mut account_diff = AccountDiff::new(address.to_string());
+    push_account_esdt_diff(expected, actual, &mut account_diff);
+    assert!(account_diff.is_empty(), "{account_diff}");
+}
+
+pub(crate) fn push_account_esdt_diff(
+    expected: &CheckEsdtMap,
+    actual: &AccountEsdt,
+    account_diff: &mut AccountDiff,
+) {
     match expected {
         CheckEsdtMap::Star => {},
         CheckEsdtMap::Equal(contents) => {
             for (key, expected_value) in contents.contents.iter() {
                 let actual_value = actual.get_by_identifier_or_default(key.value.as_slice());
+                let token = bytes_to_string(key.value.as_slice());
                 match expected_value {
                     CheckEsdt::Short(expected_balance) => {
-                        if expected_balance.value.is_zero() {
-                            assert!(
-                                actual_value.is_empty(),
-                                "No balance expected for ESDT token address: {}. token name: {}. nonce: {}.",
-                                address,
-                                bytes_to_string(key.value.as_slice()),
-                                0
-                            );
-                        } else {
-                            assert!(
-                                actual_value.instances.len() == 1,
-                                "One ESDT instance expected, with nonce 0 for address: {}. token name: {}.",
-                                address,
-                                bytes_to_string(key.value.as_slice()),
-                            );
-                            let single_instance = actual_value
-                                .instances
-                                .get_by_nonce(0)
-                                .unwrap_or_else(|| panic!("Expected fungible ESDT with none 0"));
-                            assert_eq!(
-                                single_instance.balance,
-                                expected_balance.value,
-                                "Unexpected fungible token balance for address: {}. token name: {}.",
-                                address,
-                                bytes_to_string(key.value.as_slice()),
+                        if expected_balance.value.is_zero() && expected_balance.is_exact() {
+                            if !actual_value.is_empty() {
+                                account_diff.push(
+                                    format!("esdt {token}"),
+                                    "no balance",
+                                    format!("{} instance(s)", actual_value.instances.len()),
+                                );
+                            }
+                        } else if let Some(single_instance) = actual_value.instances.get_by_nonce(0)
+                        {
+                            if actual_value.instances.len() != 1 {
+                                account_diff.push(
+                                    format!("esdt {token}"),
+                                    "one instance, with nonce 0",
+                                    format!("{} instance(s)", actual_value.instances.len()),
+                                );
+                            } else if !expected_balance.check(&single_instance.balance) {
+                                account_diff.push(
+                                    format!("esdt {token} balance"),
+                                    expected_balance,
+                                    &single_instance.balance,
+                                );
+                            }
+                        } else if !expected_balance.check(&num_bigint::BigUint::zero()) {
+                            // an absent balance is 0, as far as approximate checks are concerned
+                            account_diff.push(
+                                format!("esdt {token} balance"),
+                                expected_balance,
+                                "no fungible instance",
                             );
                         }
                     },
                     CheckEsdt::Full(expected_esdt) => {
-                        check_esdt_data(
-                            address,
-                            bytes_to_string(key.value.as_slice()),
-                            expected_esdt,
-                            &actual_value,
-                        );
+                        check_esdt_data(token, expected_esdt, &actual_value, account_diff);
                     },
                 }
             }
@@ -167,10 +242,10 @@ pub fn check_account_esdt(address: &AddressKey, expected: &CheckEsdtMap, actual:
                         continue;
                     }
                     check_esdt_data(
-                        address,
                         bytes_to_string(token_identifier),
                         &CheckEsdtData::default(),
                         actual_value,
+                        account_diff,
                     );
                 }
             }
@@ -178,10 +253,10 @@ pub fn check_account_esdt(address: &AddressKey, expected: &CheckEsdtMap, actual:
         CheckEsdtMap::Unspecified => {
             for (token_identifier, actual_value) in actual.iter() {
                 check_esdt_data(
-                    address,
                     bytes_to_string(token_identifier),
                     &CheckEsdtData::default(),
                     actual_value,
+                    account_diff,
                 );
             }
         },
@@ -189,61 +264,72 @@ pub fn check_account_esdt(address: &AddressKey, expected: &CheckEsdtMap, actual:
 }
 
 pub fn check_esdt_data(
-    address: &AddressKey,
     token: String,
     expected: &CheckEsdtData,
     actual: &EsdtData,
+    account_diff: &mut AccountDiff,
 ) {
-    let mut errors: Vec<String> = vec!["".to_string()];
     check_token_instances(
-        address,
         token.clone(),
         &expected.instances,
         &actual.instances,
-        &mut errors,
+        account_diff,
     );
     if !expected.last_nonce.check(actual.last_nonce) {
-        errors.push(format!(
-            "bad last nonce. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
-            address, token, expected.last_nonce, &actual.last_nonce
-        ));
+        account_diff.push(
+            format!("esdt {token} lastNonce"),
+            &expected.last_nonce,
+            actual.last_nonce,
+        );
     }
 
     if !expected.frozen.check(u64::from(actual.frozen)) {
-        errors.push(format!(
-            "bad last nonce. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
-            address, token, expected.frozen, &actual.frozen
-        ));
+        account_diff.push(
+            format!("esdt {token} frozen"),
+            &expected.frozen,
+            actual.frozen,
+        );
     }
 
-    errors.push("".to_string());
-    assert!(errors.len() == 2, "{}", errors.join("\n"));
+    if let Some(expected_roles) = &expected.roles {
+        let mut expected_roles = expected_roles.clone();
+        expected_roles.sort();
+        let mut actual_roles: Vec<String> = actual
+            .roles
+            .get()
+            .iter()
+            .map(|role| bytes_to_string(role))
+            .collect();
+        actual_roles.sort();
+        if expected_roles != actual_roles {
+            account_diff.push(
+                format!("esdt {token} roles"),
+                format!("{expected_roles:?}"),
+                format!("{actual_roles:?}"),
+            );
+        }
+    }
 }
 
 pub fn check_token_instances(
-    address: &AddressKey,
     token: String,
     expected: &CheckEsdtInstances,
     actual: &EsdtInstances,
-    errors: &mut Vec<String>,
+    account_diff: &mut AccountDiff,
 ) {
     match expected {
         CheckEsdtInstances::Equal(eq) => {
             for expected_value in eq.iter() {
                 let actual_value = actual.get_by_nonce_or_default(expected_value.nonce.value);
-                check_token_instance(address, &token, expected_value, &actual_value, errors);
+                check_token_instance(&token, expected_value, &actual_value, account_diff);
             }
 
             let default_expected_value = CheckEsdtInstance::default();
             for (actual_key, actual_value) in actual.get_instances().iter() {
                 if !expected.contains_nonce(*actual_key) {
-                    check_token_instance(
-                        address,
-                        &token,
-                        &default_expected_value,
-                        actual_value,
-                        errors,
-                    );
+                    let mut unexpected_value = default_expected_value.clone();
+                    unexpected_value.nonce = (*actual_key).into();
+                    check_token_instance(&token, &unexpected_value, actual_value, account_diff);
                 }
             }
         },
@@ -254,92 +340,69 @@ pub fn check_token_instances(
 }
 
 pub fn check_token_instance(
-    address: &AddressKey,
     token: &str,
     expected_value: &CheckEsdtInstance,
     actual_value: &EsdtInstance,
-    errors: &mut Vec<String>,
+    account_diff: &mut AccountDiff,
 ) {
-    if !expected_value.balance.check(&actual_value.balance) {
-        errors.push(format!(
-            "bad esdt balance. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.balance,
-            &actual_value.balance,
-        ))
-    }
+    let nonce = expected_value.nonce.value;
 
     if !expected_value.balance.check(&actual_value.balance) {
-        errors.push(format!(
-            "bad esdt balance. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.balance,
+        account_diff.push(
+            format!("esdt {token} nonce {nonce} balance"),
+            &expected_value.balance,
             &actual_value.balance,
-        ))
+        );
     }
+
     let actual_creator = if let Some(creator) = &actual_value.metadata.creator {
         creator.as_ref()
     } else {
         &[]
     };
     if !expected_value.creator.check(actual_creator) {
-        errors.push(format!(
-            "bad esdt creator. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.creator,
+        account_diff.push(
+            format!("esdt {token} nonce {nonce} creator"),
+            &expected_value.creator,
             verbose_hex(actual_creator),
-        ))
+        );
     }
 
     let actual_royalties = actual_value.metadata.royalties;
     if !expected_value.royalties.check(actual_royalties) {
-        errors.push(format!(
-            "bad esdt royalties. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address, token, expected_value.nonce.value, expected_value.royalties, actual_royalties
-        ))
+        account_diff.push(
+            format!("esdt {token} nonce {nonce} royalties"),
+            &expected_value.royalties,
+            actual_royalties,
+        );
     }
 
     let actual_hash = actual_value.metadata.hash.clone().unwrap_or_default();
     if !expected_value.hash.check(&actual_hash) {
-        errors.push(format!(
-            "bad esdt hash. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.hash,
+        account_diff.push(
+            format!("esdt {token} nonce {nonce} hash"),
+            &expected_value.hash,
             verbose_hex(&actual_hash),
-        ))
+        );
     }
 
     let actual_uri = actual_value.metadata.uri.as_slice();
     if !expected_value.uri.check(actual_uri) {
-        errors.push(format!(
-            "bad esdt uri. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
+        account_diff.push(
+            format!("esdt {token} nonce {nonce} uri"),
             expected_value.uri.pretty_str(),
             verbose_hex_list(actual_uri),
-        ))
+        );
     }
 
     if !expected_value
         .attributes
         .check(&actual_value.metadata.attributes)
     {
-        errors.push(format!(
-            "bad esdt attributes. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.attributes,
+        account_diff.push(
+            format!("esdt {token} nonce {nonce} attributes"),
+            &expected_value.attributes,
             verbose_hex(&actual_value.metadata.attributes),
-        ))
+        );
     }
 }
@@ -0,0 +1,110 @@
+use std::fmt;
+
+use multiversx_chain_vm::display_util::verbose_hex;
+
+/// Collects all mismatches found during a `checkState` step, so they can be reported together,
+/// instead of stopping at the first one.
+#[derive(Debug, Default)]
+pub struct CheckStateDiff {
+    pub accounts: Vec<AccountDiff>,
+}
+
+/// All mismatches of a single account.
+#[derive(Debug, Default)]
+pub struct AccountDiff {
+    pub address: String,
+    pub entries: Vec<DiffEntry>,
+}
+
+/// A single mismatched field, with expected and actual values pretty-printed.
+#[derive(Debug)]
+pub struct DiffEntry {
+    pub field: String,
+    pub want: String,
+    pub have: String,
+}
+
+impl CheckStateDiff {
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    pub fn push_account(&mut self, account_diff: AccountDiff) {
+        if !account_diff.is_empty() {
+            self.accounts.push(account_diff);
+        }
+    }
+}
+
+impl AccountDiff {
+    pub fn new(address: String) -> Self {
+        AccountDiff {
+            address,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn push<F, W, H>(&mut self, field: F, want: W, have: H)
+    where
+        F: fmt::Display,
+        W: fmt::Display,
+        H: fmt::Display,
+    {
+        self.entries.push(DiffEntry {
+            field: field.to_string(),
+            want: want.to_string(),
+            have: have.to_string(),
+        });
+    }
+}
+
+impl fmt::Display for CheckStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "checkState failed, {} account(s) do not match:",
+            self.accounts.len()
+        )?;
+        for account_diff in &self.accounts {
+            write!(f, "{account_diff}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AccountDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  account {}:", self.address)?;
+        for entry in &self.entries {
+            writeln!(f, "    {}", entry.field)?;
+            writeln!(f, "      want: {}", entry.want)?;
+            writeln!(f, "      have: {}", entry.have)?;
+        }
+        Ok(())
+    }
+}
+
+/// Storage keys are usually composed of a readable mapper base key, followed by encoded arguments.
+///
+/// The readable prefix is displayed as a string, the rest as hex, using the scenario concatenation syntax.
+pub fn pretty_storage_key(key: &[u8]) -> String {
+    let readable_len = key
+        .iter()
+        .take_while(|b| b.is_ascii_graphic() || **b == b' ')
+        .count();
+    if readable_len == 0 {
+        verbose_hex(key)
+    } else if readable_len == key.len() {
+        format!("str:{}", String::from_utf8_lossy(key))
+    } else {
+        format!(
+            "str:{}|{}",
+            String::from_utf8_lossy(&key[..readable_len]),
+            verbose_hex(&key[readable_len..])
+        )
+    }
+}
@@ -0,0 +1,49 @@
+/// Maximum gas limit of a single transaction, on mainnet.
+pub const MAX_GAS_LIMIT_PER_TX: u64 = 600_000_000;
+
+/// Maximum sum of the gas limits of the transactions in a shard block, on mainnet.
+pub const MAX_GAS_LIMIT_PER_BLOCK: u64 = 3_000_000_000;
+
+/// Protocol gas limits, that the scenario runner can optionally enforce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasLimits {
+    pub max_gas_limit_per_tx: u64,
+    pub max_gas_limit_per_block: u64,
+}
+
+impl GasLimits {
+    pub const fn mainnet() -> Self {
+        GasLimits {
+            max_gas_limit_per_tx: MAX_GAS_LIMIT_PER_TX,
+            max_gas_limit_per_block: MAX_GAS_LIMIT_PER_BLOCK,
+        }
+    }
+}
+
+impl Default for GasLimits {
+    fn default() -> Self {
+        Self::mainnet()
+    }
+}
+
+/// Keeps track of the gas limits of the transactions already included in the current block.
+#[derive(Default, Debug)]
+pub(crate) struct BlockGasUsage {
+    block_nonce: u64,
+    gas_limit_sum: u64,
+}
+
+impl BlockGasUsage {
+    /// Adds a transaction to the block with the given nonce,
+    /// starting from scratch whenever the block changes.
+    ///
+    /// Returns the new sum of gas limits in the block.
+    pub(crate) fn add_tx(&mut self, block_nonce: u64, gas_limit: u64) -> u64 {
+        if block_nonce != self.block_nonce {
+            self.block_nonce = block_nonce;
+            self.gas_limit_sum = 0;
+        }
+        self.gas_limit_sum = self.gas_limit_sum.saturating_add(gas_limit);
+        self.gas_limit_sum
+    }
+}
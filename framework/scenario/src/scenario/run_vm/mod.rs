@@ -1,4 +1,6 @@
 mod check_state;
+mod check_state_diff;
+mod gas_limits;
 pub mod sc_call;
 pub mod sc_deploy;
 pub mod sc_query;
@@ -8,5 +10,8 @@ mod tx_input_util;
 mod tx_output_check;
 mod vm_runner;
 
+pub use check_state::check_account_esdt;
+pub use check_state_diff::{pretty_storage_key, AccountDiff, CheckStateDiff, DiffEntry};
+pub use gas_limits::{GasLimits, MAX_GAS_LIMIT_PER_BLOCK, MAX_GAS_LIMIT_PER_TX};
 use tx_output_check::*;
 pub use vm_runner::ScenarioVMRunner;
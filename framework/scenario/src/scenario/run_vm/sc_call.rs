@@ -47,6 +47,16 @@ impl ScenarioVMRunner {
     where
         F: FnOnce(),
     {
+        self.check_sender_allowed(&sc_call_step.id, &sc_call_step.tx.from);
+        self.check_gas_limit(&sc_call_step.id, &sc_call_step.tx.gas_limit);
+        if self.network_config.is_some() {
+            self.check_network_config(
+                &sc_call_step.id,
+                &sc_call_step.tx.gas_price,
+                &sc_call_step.tx.gas_limit,
+                &sc_call_step.tx.to_tx_data(),
+            );
+        }
         let tx_input = tx_input_from_call(sc_call_step);
 
         // nonce gets increased irrespective of whether the tx fails or not
@@ -54,11 +64,13 @@ impl ScenarioVMRunner {
             .state
             .increase_account_nonce(&tx_input.from);
 
-        self.blockchain_mock.vm.sc_call_with_async_and_callback(
+        let tx_result = self.blockchain_mock.vm.sc_call_with_async_and_callback(
             tx_input,
             &mut self.blockchain_mock.state,
             f,
-        )
+        );
+        self.last_gas_usage = tx_result.gas_usage;
+        tx_result
     }
 
     pub fn perform_sc_call_lambda_and_check<F>(
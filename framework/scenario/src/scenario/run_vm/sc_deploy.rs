@@ -5,6 +5,7 @@ use crate::{
 use multiversx_chain_vm::{
     tx_execution::execute_current_tx_context_input,
     tx_mock::{TxFunctionName, TxInput, TxResult},
+    types::VMCodeMetadata,
 };
 
 use super::{check_tx_output, tx_input_util::generate_tx_hash, ScenarioVMRunner};
@@ -29,11 +30,22 @@ impl ScenarioVMRunner {
     where
         F: FnOnce(),
     {
+        self.check_sender_allowed(&sc_deploy_step.id, &sc_deploy_step.tx.from);
+        self.check_gas_limit(&sc_deploy_step.id, &sc_deploy_step.tx.gas_limit);
+        if self.network_config.is_some() {
+            self.check_network_config(
+                &sc_deploy_step.id,
+                &sc_deploy_step.tx.gas_price,
+                &sc_deploy_step.tx.gas_limit,
+                &sc_deploy_step.tx.to_tx_data(),
+            );
+        }
         let tx_input = tx_input_from_deploy(sc_deploy_step);
         let contract_code = &sc_deploy_step.tx.contract_code.value;
         let (new_address, tx_result) = self.blockchain_mock.vm.sc_create(
             tx_input,
             contract_code,
+            VMCodeMetadata::from(sc_deploy_step.tx.code_metadata.bits()),
             &mut self.blockchain_mock.state,
             f,
         );
@@ -41,6 +53,7 @@ impl ScenarioVMRunner {
             tx_result.pending_calls.no_calls(),
             "Async calls from constructors are currently not supported"
         );
+        self.last_gas_usage = tx_result.gas_usage;
         (new_address.as_array().into(), tx_result)
     }
 
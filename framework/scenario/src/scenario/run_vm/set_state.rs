@@ -1,7 +1,7 @@
 use crate::scenario::model::SetStateStep;
 
 use multiversx_chain_vm::{
-    types::VMAddress,
+    types::{VMAddress, VMCodeMetadata},
     world_mock::{
         AccountData, AccountEsdt, BlockInfo as CrateBlockInfo, BlockchainState, EsdtData,
         EsdtInstance, EsdtInstanceMetadata, EsdtInstances, EsdtRoles,
@@ -12,15 +12,32 @@ use super::ScenarioVMRunner;
 
 impl ScenarioVMRunner {
     pub fn perform_set_state(&mut self, set_state_step: &SetStateStep) {
+        if self.forbid_empty_storage_values {
+            check_no_empty_storage_values(set_state_step);
+        }
         execute(&mut self.blockchain_mock.state, set_state_step);
     }
 }
 
+/// Empty storage values are deletions on the protocol, a test setting them most likely expects the key to exist.
+fn check_no_empty_storage_values(set_state_step: &SetStateStep) {
+    for (address, account) in set_state_step.accounts.iter() {
+        for (key, value) in account.storage.iter() {
+            assert!(
+                !value.value.is_empty(),
+                "setState: storage key {key} of account {address} is set to an empty value, which is the same as not setting it at all"
+            );
+        }
+    }
+}
+
 fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
     for (address, account) in set_state_step.accounts.iter() {
+        // same as the protocol: empty values are not stored
         let storage = account
             .storage
             .iter()
+            .filter(|(_, v)| !v.value.is_empty())
             .map(|(k, v)| (k.value.clone(), v.value.clone()))
             .collect();
         let esdt = AccountEsdt::new_from_raw_map(
@@ -58,6 +75,7 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
                 .owner
                 .as_ref()
                 .map(|address_value| address_value.to_vm_address()),
+            code_metadata: convert_code_metadata(account),
             developer_rewards: account
                 .developer_rewards
                 .as_ref()
@@ -65,6 +83,9 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
                 .unwrap_or_default(),
         });
     }
+    for (token_id, settings) in set_state_step.esdt_global_settings.iter() {
+        set_esdt_global_settings(state, &token_id.value, settings);
+    }
     for new_address in set_state_step.new_addresses.iter() {
         assert!(
             new_address.new_address.value.is_smart_contract_address(),
@@ -87,6 +108,69 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
     }
 }
 
+/// Saves the settings in the token registry,
+/// and mirrors the roles and last nonce onto the existing accounts that hold roles.
+fn set_esdt_global_settings(
+    state: &mut BlockchainState,
+    token_identifier: &[u8],
+    scenario_settings: &crate::scenario::model::EsdtGlobalSettings,
+) {
+    let mut settings = state
+        .get_esdt_global_settings(token_identifier)
+        .cloned()
+        .unwrap_or_default();
+    if let Some(u64_value) = &scenario_settings.paused {
+        settings.paused = u64_value.value > 0;
+    }
+    if let Some(u64_value) = &scenario_settings.limited_transfer {
+        settings.limited_transfer = u64_value.value > 0;
+    }
+    if let Some(u64_value) = &scenario_settings.last_nonce {
+        settings.last_nonce = u64_value.value;
+    }
+    if let Some(u64_value) = &scenario_settings.num_decimals {
+        settings.num_decimals = u64_value.value as u32;
+    }
+    for (address, roles) in scenario_settings.roles.iter() {
+        settings.roles.insert(
+            address.to_vm_address(),
+            roles.iter().map(|role| role.as_bytes().to_vec()).collect(),
+        );
+    }
+
+    for (address, roles) in settings.roles.iter() {
+        // roles of addresses without an account only stay in the global settings
+        if let Some(account) = state.accounts.get_mut(address) {
+            account
+                .esdt
+                .set_roles(token_identifier.to_vec(), roles.clone());
+            let esdt_data = account
+                .esdt
+                .get_mut_by_identifier(token_identifier)
+                .unwrap();
+            esdt_data.last_nonce = esdt_data.last_nonce.max(settings.last_nonce);
+        }
+    }
+
+    state.put_esdt_global_settings(token_identifier.to_vec(), settings);
+}
+
+/// Contracts without explicit code metadata get all flags, same as in the Go scenario runner.
+fn convert_code_metadata(account: &crate::scenario::model::Account) -> VMCodeMetadata {
+    if let Some(bytes_value) = &account.code_metadata {
+        let code_metadata_bytes: [u8; 2] = bytes_value
+            .value
+            .as_slice()
+            .try_into()
+            .expect("code metadata must be exactly 2 bytes long");
+        VMCodeMetadata::from(code_metadata_bytes)
+    } else if account.code.is_some() {
+        VMCodeMetadata::all()
+    } else {
+        VMCodeMetadata::DEFAULT
+    }
+}
+
 fn convert_mandos_esdt_to_world_mock(mandos_esdt: &crate::scenario::model::Esdt) -> EsdtData {
     match mandos_esdt {
         crate::scenario::model::Esdt::Short(short_esdt) => {
@@ -1,19 +1,37 @@
 use super::{sc_call::tx_esdt_transfers_from_scenario, ScenarioVMRunner};
-use crate::scenario::model::{TransferStep, TxTransfer, ValidatorRewardStep};
+use crate::scenario::model::{TransferStep, TxResponse, TxTransfer, ValidatorRewardStep};
 use multiversx_chain_vm::{
     tx_execution::{execute_current_tx_context_input, BlockchainVMRef},
-    tx_mock::{TxFunctionName, TxInput},
+    tx_mock::{TxFunctionName, TxInput, TxResult},
     with_shared::Shareable,
     world_mock::BlockchainState,
 };
 
 impl ScenarioVMRunner {
-    pub fn perform_transfer(&mut self, transfer_step: &TransferStep) {
-        execute(
+    /// Executes a transfer step, then saves the result in the step's response field.
+    pub fn perform_transfer_update_results(&mut self, transfer_step: &mut TransferStep) {
+        let tx_result = self.perform_transfer(transfer_step);
+        transfer_step.save_response(TxResponse::from_tx_result(tx_result));
+    }
+
+    pub fn perform_transfer(&mut self, transfer_step: &TransferStep) -> TxResult {
+        self.check_sender_allowed(&transfer_step.id, &transfer_step.tx.from);
+        self.check_gas_limit(&transfer_step.id, &transfer_step.tx.gas_limit);
+        if self.network_config.is_some() {
+            self.check_network_config(
+                &transfer_step.id,
+                &transfer_step.tx.gas_price,
+                &transfer_step.tx.gas_limit,
+                &transfer_step.tx.to_tx_call().to_tx_data(),
+            );
+        }
+        let tx_result = execute(
             self.blockchain_mock.vm.clone(),
             &mut self.blockchain_mock.state,
             &transfer_step.tx,
         );
+        self.last_gas_usage = tx_result.gas_usage;
+        tx_result
     }
 
     pub fn perform_validator_reward(&mut self, validator_rewards_step: &ValidatorRewardStep) {
@@ -38,7 +56,11 @@ fn tx_input_from_transfer(tx_transfer: &TxTransfer) -> TxInput {
     }
 }
 
-fn execute(vm: BlockchainVMRef, state: &mut Shareable<BlockchainState>, tx_transfer: &TxTransfer) {
+fn execute(
+    vm: BlockchainVMRef,
+    state: &mut Shareable<BlockchainState>,
+    tx_transfer: &TxTransfer,
+) -> TxResult {
     let tx_input = tx_input_from_transfer(tx_transfer);
 
     // nonce gets increased irrespective of whether the tx fails or not
@@ -46,4 +68,5 @@ fn execute(vm: BlockchainVMRef, state: &mut Shareable<BlockchainState>, tx_trans
 
     let tx_result = vm.execute_sc_call_lambda(tx_input, state, execute_current_tx_context_input);
     tx_result.assert_ok();
+    tx_result
 }
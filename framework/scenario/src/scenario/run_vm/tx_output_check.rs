@@ -1,4 +1,4 @@
-use crate::scenario::model::{CheckLogs, Checkable, TxExpect};
+use crate::scenario::model::{CheckLog, CheckLogList, CheckLogs, Checkable, TxExpect};
 
 use multiversx_chain_vm::{
     display_util::{address_hex, verbose_hex, verbose_hex_list},
@@ -32,8 +32,22 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
         have_str,
     );
 
+    // gas is only checked if the debugger meters it
+    if let Some(gas_usage) = &tx_result.gas_usage {
+        assert!(
+            tx_expect.gas.check(gas_usage.gas_remaining()),
+            "result gas mismatch. Tx id: '{}'. Want: {}. Have: {}",
+            tx_id,
+            tx_expect.gas,
+            gas_usage.gas_remaining(),
+        );
+    }
+
     match &tx_expect.logs {
         CheckLogs::Star => {},
+        CheckLogs::List(expected_logs) if expected_logs.unordered => {
+            check_logs_unordered(tx_id, expected_logs, &tx_result.result_logs);
+        },
         CheckLogs::List(expected_logs) => {
             assert!(
                 tx_result.result_logs.len() >= expected_logs.list.len(),
@@ -76,7 +90,74 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
     }
 }
 
-fn scenario_check(tx_log: &TxLog, check_log: &crate::scenario::model::CheckLog) -> bool {
+/// Every expected log needs to be matched by a different actual log, irrespective of their order.
+fn check_logs_unordered(tx_id: &str, expected_logs: &CheckLogList, actual_logs: &[TxLog]) {
+    // for each actual log, the index of the expected log it matches
+    let mut assigned = vec![None; actual_logs.len()];
+    for (i, expected_log) in expected_logs.list.iter().enumerate() {
+        let mut visited = vec![false; actual_logs.len()];
+        assert!(
+            assign_log(i, &expected_logs.list, actual_logs, &mut assigned, &mut visited),
+            "Expected log not found. Tx id: '{}'. Index: {}.\nWant: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}",
+            tx_id,
+            i,
+            &expected_log.address,
+            &expected_log.endpoint,
+            &expected_log.topics.pretty_str(),
+            &expected_log.data,
+        );
+    }
+
+    if expected_logs.more_allowed_at_end {
+        return;
+    }
+    if let Some(i) = assigned.iter().position(Option::is_none) {
+        let actual_log = &actual_logs[i];
+        panic!(
+            "Unexpected log. Tx id: '{}'. Index: {}.\nAddress: {}, Endpoint: {}, Topics: {:?}, Data: {}",
+            tx_id,
+            i,
+            address_hex(&actual_log.address),
+            &actual_log.endpoint,
+            verbose_hex_list(actual_log.topics.as_slice()),
+            verbose_hex(&actual_log.data),
+        )
+    }
+}
+
+/// Tries to match an expected log, reassigning previously matched logs if needed (augmenting path).
+fn assign_log(
+    expected_index: usize,
+    expected_logs: &[CheckLog],
+    actual_logs: &[TxLog],
+    assigned: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for (actual_index, actual_log) in actual_logs.iter().enumerate() {
+        if visited[actual_index] || !scenario_check(actual_log, &expected_logs[expected_index]) {
+            continue;
+        }
+        visited[actual_index] = true;
+
+        let available = match assigned[actual_index] {
+            None => true,
+            Some(other_expected_index) => assign_log(
+                other_expected_index,
+                expected_logs,
+                actual_logs,
+                assigned,
+                visited,
+            ),
+        };
+        if available {
+            assigned[actual_index] = Some(expected_index);
+            return true;
+        }
+    }
+    false
+}
+
+fn scenario_check(tx_log: &TxLog, check_log: &CheckLog) -> bool {
     check_log.address.check(tx_log.address.as_bytes())
         && check_log.endpoint.check(&tx_log.endpoint)
         && check_log.topics.check(tx_log.topics.as_slice())
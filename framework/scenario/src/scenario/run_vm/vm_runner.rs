@@ -1,15 +1,44 @@
+use std::collections::{HashMap, HashSet};
+
+use multiversx_sc_meta::abi_decode::StorageDecoder;
+use multiversx_sdk::data::network_config::NetworkConfig;
+
 use crate::{
     debug_executor::ContractMapRef,
-    multiversx_chain_vm::BlockchainMock,
+    multiversx_chain_vm::{tx_mock::TxGasUsage, types::VMAddress, BlockchainMock},
     scenario::{model::*, ScenarioRunner},
 };
 
+use super::gas_limits::{BlockGasUsage, GasLimits};
+
 /// Wraps calls to the blockchain mock,
 /// while implementing the StepRunner interface.
 #[derive(Default, Debug)]
 pub struct ScenarioVMRunner {
     pub contract_map_ref: ContractMapRef,
     pub blockchain_mock: BlockchainMock,
+
+    /// If set, transactions can only be sent from these addresses.
+    pub allowed_senders: Option<HashSet<VMAddress>>,
+
+    /// If set, transactions with gas limits above the protocol maximums are rejected.
+    pub gas_limits: Option<GasLimits>,
+
+    /// If set, `setState` steps writing empty storage values fail,
+    /// instead of having the empty values silently dropped.
+    pub forbid_empty_storage_values: bool,
+
+    /// If set, transactions need to pay at least the minimum gas price,
+    /// and their gas limit needs to cover the minimum gas limit and their data.
+    pub network_config: Option<NetworkConfig>,
+
+    /// Storage decoders, by contract code, used to show the storage mismatches of `checkState` steps decoded.
+    pub storage_decoders: HashMap<Vec<u8>, StorageDecoder>,
+
+    pub(crate) block_gas_usage: BlockGasUsage,
+
+    /// The gas metered during the last transaction, if a gas schedule is configured. Used when profiling.
+    pub(crate) last_gas_usage: Option<TxGasUsage>,
 }
 
 impl ScenarioVMRunner {
@@ -19,6 +48,75 @@ impl ScenarioVMRunner {
         ScenarioVMRunner {
             contract_map_ref,
             blockchain_mock,
+            allowed_senders: None,
+            gas_limits: None,
+            forbid_empty_storage_values: false,
+            network_config: None,
+            storage_decoders: HashMap::new(),
+            block_gas_usage: BlockGasUsage::default(),
+            last_gas_usage: None,
+        }
+    }
+
+    /// Fails the step if its sender is not in the allowlist, when one is configured.
+    pub(crate) fn check_sender_allowed(&self, step_id: &str, from: &AddressValue) {
+        if let Some(allowed_senders) = &self.allowed_senders {
+            assert!(
+                allowed_senders.contains(&from.to_vm_address()),
+                "step \"{step_id}\": sender {from} is not an allowed sender"
+            );
+        }
+    }
+
+    /// Fails the step if its gas limit, or the total gas limit of the current block, is too high,
+    /// when gas limits are enforced.
+    pub(crate) fn check_gas_limit(&mut self, step_id: &str, gas_limit: &U64Value) {
+        if let Some(gas_limits) = self.gas_limits {
+            assert!(
+                gas_limit.value <= gas_limits.max_gas_limit_per_tx,
+                "step \"{step_id}\": gas limit {} exceeds the maximum gas limit per transaction ({})",
+                gas_limit.value,
+                gas_limits.max_gas_limit_per_tx,
+            );
+
+            let block_nonce = self.blockchain_mock.state.current_block_info.block_nonce;
+            let block_gas_limit_sum = self.block_gas_usage.add_tx(block_nonce, gas_limit.value);
+            assert!(
+                block_gas_limit_sum <= gas_limits.max_gas_limit_per_block,
+                "step \"{step_id}\": total gas limit in block {block_nonce} ({block_gas_limit_sum}) exceeds the maximum gas limit per block ({})",
+                gas_limits.max_gas_limit_per_block,
+            );
+        }
+    }
+}
+
+impl ScenarioVMRunner {
+    /// Fails the step if it does not respect the minimum gas price and gas limit of the network,
+    /// when a network config is set.
+    pub(crate) fn check_network_config(
+        &self,
+        step_id: &str,
+        gas_price: &U64Value,
+        gas_limit: &U64Value,
+        tx_data: &str,
+    ) {
+        if let Some(network_config) = &self.network_config {
+            assert!(
+                gas_price.value >= network_config.min_gas_price,
+                "step \"{step_id}\": gas price {} is below the minimum gas price of chain {} ({})",
+                gas_price.value,
+                network_config.chain_id,
+                network_config.min_gas_price,
+            );
+
+            let min_gas_limit = network_config.min_gas_limit_for_data(tx_data.len());
+            assert!(
+                gas_limit.value >= min_gas_limit,
+                "step \"{step_id}\": gas limit {} does not cover the {} bytes of data, at least {min_gas_limit} needed on chain {}",
+                gas_limit.value,
+                tx_data.len(),
+                network_config.chain_id,
+            );
         }
     }
 }
@@ -57,7 +155,7 @@ impl ScenarioRunner for ScenarioVMRunner {
     }
 
     fn run_transfer_step(&mut self, step: &TransferStep) {
-        self.perform_transfer(step);
+        let _ = self.perform_transfer(step);
     }
 
     fn run_validator_reward_step(&mut self, step: &ValidatorRewardStep) {
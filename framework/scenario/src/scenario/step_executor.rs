@@ -0,0 +1,25 @@
+use std::{future::Future, pin::Pin};
+
+use super::model::{ScCallStep, ScDeployStep, ScQueryStep, TransferStep};
+
+/// Future returned by the `StepExecutor` methods.
+pub type StepFuture<'a> = Pin<Box<dyn Future<Output = ()> + 'a>>;
+
+/// Executes transaction steps, regardless of where they end up.
+///
+/// Implemented by both `ScenarioWorld` (mock VM) and `Interactor` (real blockchain),
+/// so a test flow written once as an `async fn` generic over the executor can run against either.
+///
+/// The methods are async because sending transactions to a real blockchain is.
+/// On the mock they complete immediately.
+///
+/// The results are saved in the steps, as with any other way of running them.
+pub trait StepExecutor {
+    fn execute_sc_call<'a>(&'a mut self, step: &'a mut ScCallStep) -> StepFuture<'a>;
+
+    fn execute_sc_deploy<'a>(&'a mut self, step: &'a mut ScDeployStep) -> StepFuture<'a>;
+
+    fn execute_sc_query<'a>(&'a mut self, step: &'a mut ScQueryStep) -> StepFuture<'a>;
+
+    fn execute_transfer<'a>(&'a mut self, step: &'a mut TransferStep) -> StepFuture<'a>;
+}
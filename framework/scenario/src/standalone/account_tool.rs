@@ -57,6 +57,7 @@ pub async fn retrieve_account_as_scenario_set_state(
             storage: convert_storage(account_storage),
             comment: None,
             code: retrieve_code(account.code),
+            code_metadata: None,
             owner: None,
             developer_rewards: None,
         },
@@ -72,6 +73,7 @@ pub async fn retrieve_account_as_scenario_set_state(
             block_hashes: Vec::new(),
             new_addresses: Vec::new(),
             new_token_identifiers: Vec::new(),
+            esdt_global_settings: BTreeMap::new(),
             comment: None,
             current_block_info: None,
             previous_block_info: None,
@@ -99,9 +101,18 @@ fn convert_esdt(
     sdk_esdt: HashMap<String, EsdtBalance>,
     sdk_esdt_roles: HashMap<String, Vec<String>>,
 ) -> BTreeMap<String, EsdtRaw> {
+    // sorted by token identifier and nonce, so that retrieving the same account always yields the same output
+    let mut balances: Vec<(String, u64, EsdtBalance)> = sdk_esdt
+        .into_iter()
+        .map(|(key, value)| {
+            let (token_identifier, nonce) = split_token_identifer_nonce(key);
+            (token_identifier, nonce, value)
+        })
+        .collect();
+    balances.sort_by(|(a, a_nonce, _), (b, b_nonce, _)| (a, a_nonce).cmp(&(b, b_nonce)));
+
     let mut result = BTreeMap::new();
-    for (key, value) in sdk_esdt.into_iter() {
-        let (token_identifier, nonce) = split_token_identifer_nonce(key);
+    for (token_identifier, nonce, value) in balances {
         let esdt_raw = result
             .entry(format!("str:{}", token_identifier.clone()))
             .or_insert(EsdtRaw::Full(EsdtFullRaw::default()));
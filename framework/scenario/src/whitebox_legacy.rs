@@ -9,6 +9,7 @@ mod tx_mandos;
 use address_factory::*;
 pub use contract_obj_wrapper::*;
 use mandos_generator::*;
+pub(crate) use raw_converter::account_as_raw;
 pub use tx_mandos::*;
 
 pub use multiversx_chain_vm::tx_mock::{TxContextStack, TxResult, TxTokenTransfer};
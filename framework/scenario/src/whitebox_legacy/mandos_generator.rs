@@ -44,6 +44,7 @@ impl<'a> MandosGenerator<'a> {
             block_hashes: Vec::new(),
             new_addresses: Vec::new(),
             new_token_identifiers: Vec::new(),
+            esdt_global_settings: BTreeMap::new(),
             comment: None,
             current_block_info: None,
             previous_block_info: None,
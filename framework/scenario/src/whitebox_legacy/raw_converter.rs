@@ -47,6 +47,7 @@ pub(crate) fn account_as_raw(acc: &AccountData) -> AccountRaw {
     AccountRaw {
         balance: balance_raw,
         code: code_raw,
+        code_metadata: None,
         comment: None,
         esdt: all_esdt_raw,
         nonce: Some(u64_as_raw(acc.nonce)),
@@ -213,7 +214,7 @@ pub(crate) fn account_as_check_state_raw(acc: &AccountData) -> CheckAccountsRaw
             frozen: CheckBytesValueRaw::Unspecified,
             last_nonce: last_nonce_check,
             instances: CheckEsdtInstancesRaw::Equal(esdt_instances_check_raw),
-            roles: roles_as_str,
+            roles: Some(roles_as_str),
         };
 
         let token_id_str = bytes_to_scenario_string_or_hex(token_id);
@@ -243,6 +244,7 @@ pub(crate) fn account_as_check_state_raw(acc: &AccountData) -> CheckAccountsRaw
         developer_rewards: CheckBytesValueRaw::Equal(rust_biguint_as_raw(&acc.developer_rewards)),
         storage: CheckStorageRaw::Equal(check_storage_raw),
         code: CheckBytesValueRaw::Star,
+        code_hash: CheckBytesValueRaw::Unspecified,
         async_call_data: CheckBytesValueRaw::Unspecified,
         comment: None,
         username: CheckBytesValueRaw::Unspecified,
@@ -0,0 +1,51 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:alice", Account::new().balance("1000"))
+            .put_account("address:mallory", Account::new().balance("1000"))
+            .put_account("address:bob", Account::new()),
+    );
+    world
+}
+
+fn transfer_from(from: &str) -> TransferStep {
+    TransferStep::new()
+        .from(from)
+        .to("address:bob")
+        .egld_value("100")
+}
+
+#[test]
+fn allowed_senders_ok_test() {
+    let mut world = world();
+    world
+        .allowed_senders(&["address:alice"])
+        .transfer_step(transfer_from("address:alice"))
+        .check_state_step(
+            CheckStateStep::new().put_account("address:bob", CheckAccount::new().balance("100")),
+        );
+}
+
+#[test]
+#[should_panic(expected = "sender \"address:mallory\" is not an allowed sender")]
+fn allowed_senders_fail_test() {
+    let mut world = world();
+    world
+        .allowed_senders(&["address:alice"])
+        .transfer_step(transfer_from("address:mallory"));
+}
+
+#[test]
+fn allow_any_sender_test() {
+    let mut world = world();
+    world
+        .allowed_senders(&["address:alice"])
+        .allow_any_sender()
+        .transfer_step(transfer_from("address:mallory"))
+        .check_state_step(
+            CheckStateStep::new().put_account("address:bob", CheckAccount::new().balance("100")),
+        );
+}
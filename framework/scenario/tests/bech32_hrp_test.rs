@@ -0,0 +1,37 @@
+use multiversx_sc_scenario::{bech32, ScenarioWorld};
+
+const ALICE_ERD: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+
+#[test]
+fn bech32_encode_with_hrp_test() {
+    let alice = bech32::decode(ALICE_ERD);
+    assert_eq!(
+        bech32::encode_with_hrp(&alice, bech32::DEFAULT_HRP),
+        ALICE_ERD
+    );
+
+    // decoding accepts any human-readable part
+    let alice_test = bech32::encode_with_hrp(&alice, "test");
+    assert!(alice_test.starts_with("test1"));
+    assert_eq!(bech32::decode(&alice_test), alice);
+}
+
+#[test]
+fn world_bech32_hrp_test() {
+    let mut world = ScenarioWorld::new();
+    let alice_address = world.account("alice").address();
+    let default_bech32 = world.account("alice").bech32();
+    assert!(default_bech32.starts_with("erd1"));
+
+    world.set_bech32_hrp("sov");
+    assert_eq!(world.bech32_hrp(), "sov");
+    let sov_bech32 = world.account("alice").bech32();
+    assert!(sov_bech32.starts_with("sov1"));
+    assert_eq!(sov_bech32, world.bech32_encode(&alice_address));
+    assert_eq!(bech32::decode(&sov_bech32), alice_address);
+
+    // other worlds are not affected
+    assert!(ScenarioWorld::new()
+        .bech32_encode(&alice_address)
+        .starts_with("erd1"));
+}
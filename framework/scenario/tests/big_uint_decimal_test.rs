@@ -0,0 +1,52 @@
+use multiversx_sc::types::{BigUint, ManagedBuffer};
+use multiversx_sc_scenario::api::StaticApi;
+
+fn from_decimal_str(decimal_str: &str, num_decimals: u32) -> Option<BigUint<StaticApi>> {
+    BigUint::from_decimal_str(&ManagedBuffer::from(decimal_str), num_decimals)
+}
+
+fn to_decimal_display(value: u64, num_decimals: u32) -> String {
+    let display = BigUint::<StaticApi>::from(value).to_decimal_display(num_decimals);
+    String::from_utf8(display.to_vec()).unwrap()
+}
+
+#[test]
+fn big_uint_from_decimal_str_test() {
+    assert_eq!(from_decimal_str("15", 2), Some(BigUint::from(1500u32)));
+    assert_eq!(from_decimal_str("1.5", 2), Some(BigUint::from(150u32)));
+    assert_eq!(from_decimal_str("0.05", 2), Some(BigUint::from(5u32)));
+    assert_eq!(from_decimal_str("1.50", 1), Some(BigUint::from(15u32)));
+    assert_eq!(from_decimal_str("1.5e3", 0), Some(BigUint::from(1500u32)));
+    assert_eq!(from_decimal_str("25E-1", 1), Some(BigUint::from(25u32)));
+    assert_eq!(from_decimal_str("1e+2", 2), Some(BigUint::from(10000u32)));
+    assert_eq!(
+        from_decimal_str("12345678901234567890.123456789012345678", 18),
+        Some(BigUint::from(
+            12_345_678_901_234_567_890_123_456_789_012_345_678u128
+        ))
+    );
+
+    // more decimals than allowed
+    assert_eq!(from_decimal_str("1.25", 1), None);
+    assert_eq!(from_decimal_str("1e-1", 0), None);
+
+    // malformed
+    for malformed in [
+        "", "-1", "1.", ".5", "1.2.3", "1e", "1e+", "1,5", " 1", "0x10", "1e256",
+    ] {
+        assert_eq!(from_decimal_str(malformed, 18), None, "{malformed}");
+    }
+}
+
+#[test]
+fn big_uint_to_decimal_display_test() {
+    assert_eq!(to_decimal_display(0, 18), "0");
+    assert_eq!(to_decimal_display(1500, 2), "15");
+    assert_eq!(to_decimal_display(150, 2), "1.5");
+    assert_eq!(to_decimal_display(5, 2), "0.05");
+    assert_eq!(to_decimal_display(1_000_500_000_000_000_000, 18), "1.0005");
+    assert_eq!(to_decimal_display(42, 0), "42");
+
+    let parsed = from_decimal_str(&to_decimal_display(1_234_000, 6), 6);
+    assert_eq!(parsed, Some(BigUint::from(1_234_000u32)));
+}
@@ -0,0 +1,89 @@
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::{
+        tx_mock::BlockchainUpdate,
+        types::VMAddress,
+        world_mock::{AccountData, EsdtGlobalSettings},
+    },
+    num_bigint::BigUint,
+};
+use serde_json::json;
+
+#[test]
+fn blockchain_update_json_test() {
+    let address = VMAddress::from([1u8; 32]);
+    let mut account = AccountData::new_empty(address.clone());
+    account.nonce = 3;
+    account.egld_balance = BigUint::from(1000u32);
+    account.esdt.set_esdt_balance(
+        b"TOK-123456".to_vec(),
+        0,
+        &BigUint::from(50u32),
+        Default::default(),
+    );
+    account.storage.insert(b"key".to_vec(), b"value".to_vec());
+
+    let mut update = BlockchainUpdate::empty();
+    update.accounts.insert(address, account);
+    update.esdt_global_settings.insert(
+        b"TOK-123456".to_vec(),
+        EsdtGlobalSettings {
+            num_decimals: 18,
+            ..Default::default()
+        },
+    );
+
+    let address_hex = format!("0x{}", "01".repeat(32));
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({
+            "accounts": {
+                address_hex: {
+                    "nonce": "3",
+                    "balance": "1000",
+                    "esdt": {
+                        "0x544f4b2d313233343536": {
+                            "instances": [
+                                {
+                                    "nonce": "0",
+                                    "balance": "50",
+                                    "name": "0x",
+                                    "creator": null,
+                                    "royalties": "0",
+                                    "hash": null,
+                                    "uris": [],
+                                    "attributes": "0x"
+                                }
+                            ],
+                            "lastNonce": "0",
+                            "roles": [],
+                            "frozen": false
+                        }
+                    },
+                    "storage": {
+                        "0x6b6579": "0x76616c7565"
+                    },
+                    "username": "0x",
+                    "contractPath": null,
+                    "owner": null,
+                    "codeMetadata": "0x0000",
+                    "developerRewards": "0"
+                }
+            },
+            "newTokenIdentifiers": null,
+            "esdtGlobalSettings": {
+                "0x544f4b2d313233343536": {
+                    "paused": false,
+                    "limitedTransfer": false,
+                    "roles": {},
+                    "lastNonce": "0",
+                    "tokenName": "0x",
+                    "tokenType": null,
+                    "owner": null,
+                    "minted": "0",
+                    "burnt": "0",
+                    "numDecimals": 18
+                }
+            }
+        })
+    );
+}
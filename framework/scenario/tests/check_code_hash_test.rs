@@ -0,0 +1,31 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+/// The blake2b-256 hash of `"contract code"`.
+const CODE_HASH: &str = "0x3cbf3c011ff4767f4039a0f60b8a6689d3695ab1e753b07046af9f635e538eb1";
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("sc:contract", Account::new().code("str:contract code"))
+            .put_account("address:user", Account::new()),
+    );
+    world
+}
+
+#[test]
+fn check_code_hash_test() {
+    world().check_state_step(
+        CheckStateStep::new()
+            .put_account("sc:contract", CheckAccount::new().code_hash(CODE_HASH))
+            .put_account("address:user", CheckAccount::new().code_hash("")),
+    );
+}
+
+#[test]
+#[should_panic(expected = "codeHash")]
+fn check_code_hash_mismatch_test() {
+    world().check_state_step(
+        CheckStateStep::new().put_account("address:user", CheckAccount::new().code_hash(CODE_HASH)),
+    );
+}
@@ -0,0 +1,45 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(SetStateStep::new().put_account(
+        "address:minter",
+        Account::new().esdt_roles(
+            "str:TOK-123456",
+            vec![
+                "ESDTRoleLocalMint".to_string(),
+                "ESDTRoleLocalBurn".to_string(),
+            ],
+        ),
+    ));
+    world
+}
+
+#[test]
+fn check_esdt_roles_test() {
+    world().check_state_step(CheckStateStep::new().put_account(
+        "address:minter",
+        CheckAccount::new().esdt_roles(
+            "str:TOK-123456",
+            &["ESDTRoleLocalBurn", "ESDTRoleLocalMint"],
+        ),
+    ));
+}
+
+#[test]
+#[should_panic(expected = "esdt TOK-123456 roles")]
+fn check_esdt_roles_missing_test() {
+    world().check_state_step(CheckStateStep::new().put_account(
+        "address:minter",
+        CheckAccount::new().esdt_roles("str:TOK-123456", &["ESDTRoleLocalMint"]),
+    ));
+}
+
+#[test]
+#[should_panic(expected = "esdt OTHER-123456 roles")]
+fn check_esdt_roles_other_token_test() {
+    world().check_state_step(CheckStateStep::new().put_account(
+        "address:minter",
+        CheckAccount::new().esdt_roles("str:OTHER-123456", &["ESDTRoleLocalMint"]),
+    ));
+}
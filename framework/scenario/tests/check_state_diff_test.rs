@@ -0,0 +1,57 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+fn world_with_alice() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new().put_account(
+            "address:alice",
+            Account::new()
+                .nonce("1")
+                .balance("100")
+                .esdt_balance("str:TOKEN-123456", "50"),
+        ),
+    );
+    world
+}
+
+#[test]
+fn check_state_diff_ok_test() {
+    let mut world = world_with_alice();
+    world.check_state_step(
+        CheckStateStep::new().put_account(
+            "address:alice",
+            CheckAccount::new()
+                .nonce("1")
+                .balance("100")
+                .esdt_balance("str:TOKEN-123456", "50"),
+        ),
+    );
+}
+
+#[test]
+fn check_state_diff_all_fields_test() {
+    let mut world = world_with_alice();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        world.check_state_step(
+            CheckStateStep::new()
+                .put_account(
+                    "address:alice",
+                    CheckAccount::new()
+                        .nonce("2")
+                        .balance("200")
+                        .esdt_balance("str:TOKEN-123456", "60"),
+                )
+                .put_account("address:bob", CheckAccount::new()),
+        );
+    }));
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+
+    // all mismatches are reported, not just the first one
+    assert!(message.contains("2 account(s) do not match"));
+    assert!(message.contains("account address:alice"));
+    assert!(message.contains("nonce\n      want: \"2\"\n      have: 1"));
+    assert!(message.contains("balance\n      want: \"200\"\n      have: 100"));
+    assert!(message.contains("esdt TOKEN-123456 balance"));
+    assert!(message.contains("account address:bob"));
+    assert!(message.contains("want: present\n      have: missing"));
+}
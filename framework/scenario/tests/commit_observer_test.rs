@@ -0,0 +1,64 @@
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::{types::VMAddress, world_mock::BlockchainState},
+    num_bigint::BigUint,
+    scenario_model::*,
+    ScenarioWorld,
+};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn commit_observer_test() {
+    let mut world = ScenarioWorld::new();
+    world.account("alice").balance(1_000u64);
+    world.account("bob");
+    world.account("carol");
+
+    let commits: Arc<Mutex<Vec<(Vec<VMAddress>, BigUint)>>> = Arc::default();
+    let commits_clone = commits.clone();
+    let alice = AddressKey::from("address:alice").to_vm_address();
+    world.add_commit_observer(
+        move |changed_accounts: &[VMAddress], state: &BlockchainState| {
+            let alice_balance = state.accounts[&alice].egld_balance.clone();
+            commits_clone
+                .lock()
+                .unwrap()
+                .push((changed_accounts.to_vec(), alice_balance));
+        },
+    );
+
+    world
+        .transfer_step(
+            TransferStep::new()
+                .from("address:alice")
+                .to("address:bob")
+                .egld_value(100u64),
+        )
+        .transfer_step(
+            TransferStep::new()
+                .from("address:alice")
+                .to("address:carol")
+                .egld_value(200u64),
+        );
+
+    let commits = commits.lock().unwrap();
+    assert_eq!(commits.len(), 2);
+
+    // sorted by address bytes
+    assert_eq!(
+        commits[0].0,
+        vec![
+            AddressKey::from("address:alice").to_vm_address(),
+            AddressKey::from("address:bob").to_vm_address(),
+        ]
+    );
+    assert_eq!(commits[0].1, BigUint::from(900u32));
+
+    assert_eq!(
+        commits[1].0,
+        vec![
+            AddressKey::from("address:alice").to_vm_address(),
+            AddressKey::from("address:carol").to_vm_address(),
+        ]
+    );
+    assert_eq!(commits[1].1, BigUint::from(700u32));
+}
@@ -0,0 +1,74 @@
+use std::sync::{Arc, Mutex};
+
+use multiversx_sc::contract_base::CallableContract;
+use multiversx_sc_scenario::{
+    debug_executor::ContractContainer, multiversx_chain_vm::tx_mock::TxFunctionName,
+};
+
+/// Only has the given functions, records the calls to them.
+struct RecordingContract {
+    functions: &'static [&'static str],
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl CallableContract for RecordingContract {
+    fn call(&self, fn_name: &str) -> bool {
+        if !self.functions.contains(&fn_name) {
+            return false;
+        }
+        self.calls.lock().unwrap().push(fn_name.to_string());
+        true
+    }
+}
+
+fn contract_container(
+    functions: &'static [&'static str],
+    function_whitelist: Option<&[&str]>,
+) -> (ContractContainer, Arc<Mutex<Vec<String>>>) {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let contract = RecordingContract {
+        functions,
+        calls: calls.clone(),
+    };
+    let function_whitelist = function_whitelist.map(|whitelist| {
+        whitelist
+            .iter()
+            .map(|function| function.to_string())
+            .collect()
+    });
+    (
+        ContractContainer::new(Box::new(contract), function_whitelist, false),
+        calls,
+    )
+}
+
+#[test]
+fn contract_container_without_init_test() {
+    let (container, calls) = contract_container(&["endpoint"], None);
+    assert!(container.call(&TxFunctionName::INIT));
+    assert!(container.call(&TxFunctionName::UPGRADE));
+    assert!(calls.lock().unwrap().is_empty());
+}
+
+#[test]
+fn contract_container_upgrade_falls_back_to_init_test() {
+    let (container, calls) = contract_container(&["init"], None);
+    assert!(container.call(&TxFunctionName::UPGRADE));
+    assert_eq!(*calls.lock().unwrap(), vec!["init".to_string()]);
+}
+
+#[test]
+fn contract_container_init_not_whitelisted_test() {
+    let (container, calls) = contract_container(&["init", "endpoint"], Some(&["endpoint"]));
+    assert!(!container.call(&TxFunctionName::INIT));
+    assert!(!container.call(&TxFunctionName::UPGRADE));
+    assert!(calls.lock().unwrap().is_empty());
+}
+
+#[test]
+fn contract_container_whitelisted_init_test() {
+    let (container, calls) = contract_container(&["init", "endpoint"], Some(&["init"]));
+    assert!(container.call(&TxFunctionName::UPGRADE));
+    assert!(!container.call(&TxFunctionName::from("endpoint")));
+    assert_eq!(*calls.lock().unwrap(), vec!["init".to_string()]);
+}
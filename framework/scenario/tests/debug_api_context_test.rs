@@ -0,0 +1,47 @@
+use std::panic::AssertUnwindSafe;
+
+use multiversx_sc::types::{BigUint, ManagedBuffer};
+use multiversx_sc_scenario::api::{DebugApi, DebugApiContext};
+
+#[test]
+fn debug_api_context_other_thread_test() {
+    let context = DebugApi::dummy_context();
+
+    let value = context.with(|| BigUint::<DebugApi>::from(5u32));
+
+    let context_clone = context.clone();
+    let handle = std::thread::spawn(move || {
+        context_clone.with(|| {
+            let sum = value + BigUint::<DebugApi>::from(7u32);
+            sum.to_u64()
+        })
+    });
+
+    assert_eq!(handle.join().unwrap(), Some(12));
+}
+
+#[test]
+fn debug_api_context_capture_test() {
+    DebugApi::dummy();
+    let buffer = ManagedBuffer::<DebugApi>::from(&b"abc"[..]);
+    let context = DebugApiContext::capture();
+
+    let handle = std::thread::spawn(move || context.with(|| buffer.to_boxed_bytes()));
+
+    assert_eq!(handle.join().unwrap().as_slice(), b"abc");
+}
+
+#[test]
+fn debug_api_context_restored_after_panic_test() {
+    DebugApi::dummy();
+    let buffer = ManagedBuffer::<DebugApi>::from(&b"abc"[..]);
+
+    let other_context = DebugApi::dummy_context();
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        other_context.with(|| panic!("inside context"))
+    }));
+    assert!(result.is_err());
+
+    // the buffer is only usable if its own context is back on top
+    assert_eq!(buffer.to_boxed_bytes().as_slice(), b"abc");
+}
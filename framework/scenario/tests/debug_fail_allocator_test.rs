@@ -0,0 +1,43 @@
+use multiversx_sc::err_msg;
+use multiversx_sc_scenario::debug_executor::{
+    with_allocation_allowed, with_allocation_forbidden, DebugFailAllocator,
+};
+
+#[global_allocator]
+static ALLOC: DebugFailAllocator = DebugFailAllocator;
+
+#[test]
+fn debug_fail_allocator_forbidden_test() {
+    let result = with_allocation_forbidden(true, || {
+        let v = Vec::from([1u64, 2, 3]);
+        v.len()
+    });
+    let tx_panic = result.unwrap_err();
+    assert_eq!(tx_panic.status, 4);
+    assert!(tx_panic.message.starts_with(&format!(
+        "{}: 24 bytes, align 8",
+        err_msg::MEM_ALLOC_FORBIDDEN
+    )));
+}
+
+#[test]
+fn debug_fail_allocator_allowed_test() {
+    let result = with_allocation_forbidden(false, || vec![1u64, 2, 3].len());
+    assert_eq!(result.unwrap(), 3);
+
+    let result =
+        with_allocation_forbidden(true, || with_allocation_allowed(|| vec![1u64, 2, 3].len()));
+    assert_eq!(result.unwrap(), 3);
+}
+
+#[test]
+fn debug_fail_allocator_nested_test() {
+    let result = with_allocation_forbidden(true, || {
+        with_allocation_allowed(|| {
+            let inner = with_allocation_forbidden(true, || Box::new(5u8));
+            assert!(inner.is_err());
+        });
+        7
+    });
+    assert_eq!(result.unwrap(), 7);
+}
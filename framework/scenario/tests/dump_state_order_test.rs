@@ -0,0 +1,45 @@
+use multiversx_sc_scenario::multiversx_chain_vm::{
+    types::VMAddress,
+    world_mock::{AccountData, BlockchainState},
+};
+
+fn account(index: u8, storage_keys: &[u8]) -> AccountData {
+    let mut account = AccountData::new_empty(VMAddress::from([index; 32]));
+    account.nonce = index as u64;
+    for key in storage_keys {
+        account.storage_write(&[*key], b"value");
+    }
+    account
+}
+
+fn state(indices: impl Iterator<Item = u8>) -> BlockchainState {
+    let mut state = BlockchainState::default();
+    for index in indices {
+        state.add_account(account(index, &[3, 1, 2, index]));
+    }
+    state
+}
+
+#[test]
+fn dump_state_order_test() {
+    // the same accounts, inserted in opposite orders, in maps with different hashers
+    let state_1 = state(1..=20);
+    let state_2 = state((1..=20).rev());
+    assert_eq!(format!("{state_1:?}"), format!("{state_2:?}"));
+
+    let debug = format!("{state_1:?}");
+    let position = |index: u8| debug.find(&format!("nonce: {index},")).unwrap();
+    assert!(position(1) < position(2));
+    assert!(position(2) < position(20));
+}
+
+#[test]
+fn dump_account_storage_order_test() {
+    let account = account(5, &[9, 3, 7, 1]);
+    let display = account.to_string();
+    let positions: Vec<usize> = ["0x01 ->", "0x03 ->", "0x07 ->", "0x09 ->"]
+        .iter()
+        .map(|key| display.find(key).unwrap())
+        .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+}
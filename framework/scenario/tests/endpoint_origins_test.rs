@@ -0,0 +1,78 @@
+use multiversx_sc::abi::{ContractAbi, EndpointAbi, EndpointOrigins, EndpointTypeAbi};
+
+fn endpoint_abi(name: &'static str, rust_method_name: &'static str) -> EndpointAbi {
+    EndpointAbi {
+        name,
+        rust_method_name,
+        endpoint_type: EndpointTypeAbi::Endpoint,
+        ..Default::default()
+    }
+}
+
+fn trait_abi(name: &'static str, endpoints: Vec<EndpointAbi>) -> ContractAbi {
+    ContractAbi {
+        name,
+        ..ContractAbi::generate_with_endpoints(endpoints)
+    }
+}
+
+#[test]
+fn endpoint_origins_distinct_test() {
+    let mut origins = EndpointOrigins::default();
+    origins.add_all(&trait_abi(
+        "Adder",
+        vec![endpoint_abi("add", "add"), endpoint_abi("getSum", "sum")],
+    ));
+    origins.add_all(&trait_abi(
+        "PauseModule",
+        vec![endpoint_abi("pause", "pause_endpoint")],
+    ));
+}
+
+#[test]
+#[should_panic(
+    expected = "Endpoint `pause` is declared twice: by method `pause` in `Adder` and by method `pause_endpoint` in `PauseModule`."
+)]
+fn endpoint_origins_clash_test() {
+    let mut origins = EndpointOrigins::default();
+    origins.add_all(&trait_abi(
+        "Adder",
+        vec![endpoint_abi("add", "add"), endpoint_abi("pause", "pause")],
+    ));
+    origins.add_all(&trait_abi(
+        "PauseModule",
+        vec![endpoint_abi("pause", "pause_endpoint")],
+    ));
+}
+
+#[test]
+#[should_panic(
+    expected = "Endpoint `pauseContract` is declared twice: by method `pause` in `Adder` and by method `pause_endpoint` in `PauseModule`."
+)]
+fn endpoint_origins_alias_clash_test() {
+    let mut origins = EndpointOrigins::default();
+    origins.add_all(&trait_abi(
+        "Adder",
+        vec![EndpointAbi {
+            aliases: &["pauseContract"],
+            ..endpoint_abi("pause", "pause")
+        }],
+    ));
+    origins.add_all(&trait_abi(
+        "PauseModule",
+        vec![endpoint_abi("pauseContract", "pause_endpoint")],
+    ));
+}
+
+#[test]
+fn endpoint_origins_different_labels_test() {
+    let mut origins = EndpointOrigins::default();
+    origins.add_all(&trait_abi("Adder", vec![endpoint_abi("getSum", "sum")]));
+    origins.add_all(&trait_abi(
+        "AltModule",
+        vec![EndpointAbi {
+            labels: &["alt-impl"],
+            ..endpoint_abi("getSum", "alternative_sum")
+        }],
+    ));
+}
@@ -0,0 +1,275 @@
+use multiversx_sc::contract_base::ErrorMessageTable;
+
+// More entries than the default macro recursion limit allows, were they numbered recursively.
+multiversx_sc::error_messages! {
+    pub LargeErrors {
+        ERR_000 = "error message 000",
+        ERR_001 = "error message 001",
+        ERR_002 = "error message 002",
+        ERR_003 = "error message 003",
+        ERR_004 = "error message 004",
+        ERR_005 = "error message 005",
+        ERR_006 = "error message 006",
+        ERR_007 = "error message 007",
+        ERR_008 = "error message 008",
+        ERR_009 = "error message 009",
+        ERR_010 = "error message 010",
+        ERR_011 = "error message 011",
+        ERR_012 = "error message 012",
+        ERR_013 = "error message 013",
+        ERR_014 = "error message 014",
+        ERR_015 = "error message 015",
+        ERR_016 = "error message 016",
+        ERR_017 = "error message 017",
+        ERR_018 = "error message 018",
+        ERR_019 = "error message 019",
+        ERR_020 = "error message 020",
+        ERR_021 = "error message 021",
+        ERR_022 = "error message 022",
+        ERR_023 = "error message 023",
+        ERR_024 = "error message 024",
+        ERR_025 = "error message 025",
+        ERR_026 = "error message 026",
+        ERR_027 = "error message 027",
+        ERR_028 = "error message 028",
+        ERR_029 = "error message 029",
+        ERR_030 = "error message 030",
+        ERR_031 = "error message 031",
+        ERR_032 = "error message 032",
+        ERR_033 = "error message 033",
+        ERR_034 = "error message 034",
+        ERR_035 = "error message 035",
+        ERR_036 = "error message 036",
+        ERR_037 = "error message 037",
+        ERR_038 = "error message 038",
+        ERR_039 = "error message 039",
+        ERR_040 = "error message 040",
+        ERR_041 = "error message 041",
+        ERR_042 = "error message 042",
+        ERR_043 = "error message 043",
+        ERR_044 = "error message 044",
+        ERR_045 = "error message 045",
+        ERR_046 = "error message 046",
+        ERR_047 = "error message 047",
+        ERR_048 = "error message 048",
+        ERR_049 = "error message 049",
+        ERR_050 = "error message 050",
+        ERR_051 = "error message 051",
+        ERR_052 = "error message 052",
+        ERR_053 = "error message 053",
+        ERR_054 = "error message 054",
+        ERR_055 = "error message 055",
+        ERR_056 = "error message 056",
+        ERR_057 = "error message 057",
+        ERR_058 = "error message 058",
+        ERR_059 = "error message 059",
+        ERR_060 = "error message 060",
+        ERR_061 = "error message 061",
+        ERR_062 = "error message 062",
+        ERR_063 = "error message 063",
+        ERR_064 = "error message 064",
+        ERR_065 = "error message 065",
+        ERR_066 = "error message 066",
+        ERR_067 = "error message 067",
+        ERR_068 = "error message 068",
+        ERR_069 = "error message 069",
+        ERR_070 = "error message 070",
+        ERR_071 = "error message 071",
+        ERR_072 = "error message 072",
+        ERR_073 = "error message 073",
+        ERR_074 = "error message 074",
+        ERR_075 = "error message 075",
+        ERR_076 = "error message 076",
+        ERR_077 = "error message 077",
+        ERR_078 = "error message 078",
+        ERR_079 = "error message 079",
+        ERR_080 = "error message 080",
+        ERR_081 = "error message 081",
+        ERR_082 = "error message 082",
+        ERR_083 = "error message 083",
+        ERR_084 = "error message 084",
+        ERR_085 = "error message 085",
+        ERR_086 = "error message 086",
+        ERR_087 = "error message 087",
+        ERR_088 = "error message 088",
+        ERR_089 = "error message 089",
+        ERR_090 = "error message 090",
+        ERR_091 = "error message 091",
+        ERR_092 = "error message 092",
+        ERR_093 = "error message 093",
+        ERR_094 = "error message 094",
+        ERR_095 = "error message 095",
+        ERR_096 = "error message 096",
+        ERR_097 = "error message 097",
+        ERR_098 = "error message 098",
+        ERR_099 = "error message 099",
+        ERR_100 = "error message 100",
+        ERR_101 = "error message 101",
+        ERR_102 = "error message 102",
+        ERR_103 = "error message 103",
+        ERR_104 = "error message 104",
+        ERR_105 = "error message 105",
+        ERR_106 = "error message 106",
+        ERR_107 = "error message 107",
+        ERR_108 = "error message 108",
+        ERR_109 = "error message 109",
+        ERR_110 = "error message 110",
+        ERR_111 = "error message 111",
+        ERR_112 = "error message 112",
+        ERR_113 = "error message 113",
+        ERR_114 = "error message 114",
+        ERR_115 = "error message 115",
+        ERR_116 = "error message 116",
+        ERR_117 = "error message 117",
+        ERR_118 = "error message 118",
+        ERR_119 = "error message 119",
+        ERR_120 = "error message 120",
+        ERR_121 = "error message 121",
+        ERR_122 = "error message 122",
+        ERR_123 = "error message 123",
+        ERR_124 = "error message 124",
+        ERR_125 = "error message 125",
+        ERR_126 = "error message 126",
+        ERR_127 = "error message 127",
+        ERR_128 = "error message 128",
+        ERR_129 = "error message 129",
+        ERR_130 = "error message 130",
+        ERR_131 = "error message 131",
+        ERR_132 = "error message 132",
+        ERR_133 = "error message 133",
+        ERR_134 = "error message 134",
+        ERR_135 = "error message 135",
+        ERR_136 = "error message 136",
+        ERR_137 = "error message 137",
+        ERR_138 = "error message 138",
+        ERR_139 = "error message 139",
+        ERR_140 = "error message 140",
+        ERR_141 = "error message 141",
+        ERR_142 = "error message 142",
+        ERR_143 = "error message 143",
+        ERR_144 = "error message 144",
+        ERR_145 = "error message 145",
+        ERR_146 = "error message 146",
+        ERR_147 = "error message 147",
+        ERR_148 = "error message 148",
+        ERR_149 = "error message 149",
+        ERR_150 = "error message 150",
+        ERR_151 = "error message 151",
+        ERR_152 = "error message 152",
+        ERR_153 = "error message 153",
+        ERR_154 = "error message 154",
+        ERR_155 = "error message 155",
+        ERR_156 = "error message 156",
+        ERR_157 = "error message 157",
+        ERR_158 = "error message 158",
+        ERR_159 = "error message 159",
+        ERR_160 = "error message 160",
+        ERR_161 = "error message 161",
+        ERR_162 = "error message 162",
+        ERR_163 = "error message 163",
+        ERR_164 = "error message 164",
+        ERR_165 = "error message 165",
+        ERR_166 = "error message 166",
+        ERR_167 = "error message 167",
+        ERR_168 = "error message 168",
+        ERR_169 = "error message 169",
+        ERR_170 = "error message 170",
+        ERR_171 = "error message 171",
+        ERR_172 = "error message 172",
+        ERR_173 = "error message 173",
+        ERR_174 = "error message 174",
+        ERR_175 = "error message 175",
+        ERR_176 = "error message 176",
+        ERR_177 = "error message 177",
+        ERR_178 = "error message 178",
+        ERR_179 = "error message 179",
+        ERR_180 = "error message 180",
+        ERR_181 = "error message 181",
+        ERR_182 = "error message 182",
+        ERR_183 = "error message 183",
+        ERR_184 = "error message 184",
+        ERR_185 = "error message 185",
+        ERR_186 = "error message 186",
+        ERR_187 = "error message 187",
+        ERR_188 = "error message 188",
+        ERR_189 = "error message 189",
+        ERR_190 = "error message 190",
+        ERR_191 = "error message 191",
+        ERR_192 = "error message 192",
+        ERR_193 = "error message 193",
+        ERR_194 = "error message 194",
+        ERR_195 = "error message 195",
+        ERR_196 = "error message 196",
+        ERR_197 = "error message 197",
+        ERR_198 = "error message 198",
+        ERR_199 = "error message 199",
+        ERR_200 = "error message 200",
+        ERR_201 = "error message 201",
+        ERR_202 = "error message 202",
+        ERR_203 = "error message 203",
+        ERR_204 = "error message 204",
+        ERR_205 = "error message 205",
+        ERR_206 = "error message 206",
+        ERR_207 = "error message 207",
+        ERR_208 = "error message 208",
+        ERR_209 = "error message 209",
+        ERR_210 = "error message 210",
+        ERR_211 = "error message 211",
+        ERR_212 = "error message 212",
+        ERR_213 = "error message 213",
+        ERR_214 = "error message 214",
+        ERR_215 = "error message 215",
+        ERR_216 = "error message 216",
+        ERR_217 = "error message 217",
+        ERR_218 = "error message 218",
+        ERR_219 = "error message 219",
+        ERR_220 = "error message 220",
+        ERR_221 = "error message 221",
+        ERR_222 = "error message 222",
+        ERR_223 = "error message 223",
+        ERR_224 = "error message 224",
+        ERR_225 = "error message 225",
+        ERR_226 = "error message 226",
+        ERR_227 = "error message 227",
+        ERR_228 = "error message 228",
+        ERR_229 = "error message 229",
+        ERR_230 = "error message 230",
+        ERR_231 = "error message 231",
+        ERR_232 = "error message 232",
+        ERR_233 = "error message 233",
+        ERR_234 = "error message 234",
+        ERR_235 = "error message 235",
+        ERR_236 = "error message 236",
+        ERR_237 = "error message 237",
+        ERR_238 = "error message 238",
+        ERR_239 = "error message 239",
+        ERR_240 = "error message 240",
+        ERR_241 = "error message 241",
+        ERR_242 = "error message 242",
+        ERR_243 = "error message 243",
+        ERR_244 = "error message 244",
+        ERR_245 = "error message 245",
+        ERR_246 = "error message 246",
+        ERR_247 = "error message 247",
+        ERR_248 = "error message 248",
+        ERR_249 = "error message 249",
+        ERR_250 = "error message 250",
+        ERR_251 = "error message 251",
+        ERR_252 = "error message 252",
+        ERR_253 = "error message 253",
+        ERR_254 = "error message 254",
+        ERR_255 = "error message 255",
+    }
+}
+
+#[test]
+fn test_large_error_message_table() {
+    assert_eq!(LargeErrors::MESSAGE_ENDS.len(), 256);
+    assert_eq!(LargeErrors::MESSAGES.len(), 256 * "error message 000".len());
+    assert_eq!(ERR_000.index(), 0);
+    assert_eq!(ERR_000.as_bytes(), b"error message 000");
+    assert_eq!(ERR_200.index(), 200);
+    assert_eq!(ERR_200.as_bytes(), b"error message 200");
+    assert_eq!(ERR_255.index(), 255);
+    assert_eq!(ERR_255.as_bytes(), b"error message 255");
+}
@@ -0,0 +1,32 @@
+use multiversx_sc::contract_base::ErrorMessageTable;
+
+multiversx_sc::error_messages! {
+    pub TestErrors {
+        ERR_NOT_OWNER = "only owner can call this",
+        ERR_EMPTY = "",
+        ERR_PAUSED = "contract paused",
+    }
+}
+
+#[test]
+fn test_error_message_lookup() {
+    assert_eq!(ERR_NOT_OWNER.as_bytes(), b"only owner can call this");
+    assert_eq!(ERR_EMPTY.as_bytes(), b"");
+    assert_eq!(ERR_PAUSED.as_bytes(), b"contract paused");
+}
+
+#[test]
+fn test_error_message_indices() {
+    assert_eq!(ERR_NOT_OWNER.index(), 0);
+    assert_eq!(ERR_EMPTY.index(), 1);
+    assert_eq!(ERR_PAUSED.index(), 2);
+}
+
+#[test]
+fn test_error_message_table_layout() {
+    assert_eq!(
+        TestErrors::MESSAGES,
+        b"only owner can call thiscontract paused"
+    );
+    assert_eq!(TestErrors::MESSAGE_ENDS, &[24, 24, 39]);
+}
@@ -0,0 +1,76 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+const TOKEN_ID_EXPR: &str = "str:TOKEN-123456";
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:alice", Account::new())
+            .esdt_global_settings(
+                TOKEN_ID_EXPR,
+                EsdtGlobalSettings::new()
+                    .roles("address:alice", &["ESDTRoleLocalMint", "ESDTRoleLocalBurn"]),
+            ),
+    );
+    world
+}
+
+fn builtin_call(function: &str, amount: &str) -> ScCallStep {
+    ScCallStep::new()
+        .from("address:alice")
+        .to("address:alice")
+        .function(function)
+        .argument(TOKEN_ID_EXPR)
+        .argument(amount)
+}
+
+#[test]
+fn esdt_local_mint_burn_supply_test() {
+    let mut world = world();
+    world
+        .sc_call(builtin_call("ESDTLocalMint", "1000"))
+        .sc_call(builtin_call("ESDTLocalBurn", "300"))
+        .check_state_step(CheckStateStep::new().put_account(
+            "address:alice",
+            CheckAccount::new().esdt_balance(TOKEN_ID_EXPR, "700"),
+        ));
+
+    assert_eq!(world.get_token_supply(TOKEN_ID_EXPR), Some(700u32.into()));
+}
+
+#[test]
+fn esdt_local_mint_unregistered_token_test() {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(SetStateStep::new().put_account(
+        "address:alice",
+        Account::new().esdt_roles(TOKEN_ID_EXPR, vec!["ESDTRoleLocalMint".to_string()]),
+    ));
+    world.sc_call(builtin_call("ESDTLocalMint", "1000"));
+
+    assert_eq!(world.get_token_supply(TOKEN_ID_EXPR), None);
+}
+
+fn find_log<'a>(response: &'a TxResponse, endpoint: &str) -> &'a Log {
+    response
+        .logs
+        .iter()
+        .find(|log| log.endpoint.value == endpoint.as_bytes())
+        .unwrap_or_else(|| panic!("{endpoint} log not found"))
+}
+
+#[test]
+fn esdt_local_mint_burn_logs_test() {
+    let mut world = world();
+    world
+        .sc_call_use_raw_response(builtin_call("ESDTLocalMint", "1000"), |response| {
+            let log = find_log(response, "ESDTLocalMint");
+            assert_eq!(log.topics[0].value, b"TOKEN-123456");
+            assert_eq!(log.topics[2].value, vec![3u8, 232]);
+        })
+        .sc_call_use_raw_response(builtin_call("ESDTLocalBurn", "300"), |response| {
+            let log = find_log(response, "ESDTLocalBurn");
+            assert_eq!(log.topics[0].value, b"TOKEN-123456");
+            assert_eq!(log.topics[2].value, vec![1u8, 44]);
+        });
+}
@@ -0,0 +1,54 @@
+use multiversx_sc::{
+    esdt::{
+        ESDTSystemSmartContractProxy, EsdtIssueCost, FungibleTokenProperties,
+        NonFungibleTokenProperties, ESDT_DEFAULT_ISSUE_COST,
+    },
+    types::{BigUint, ContractCall, EsdtTokenType, ManagedBuffer},
+};
+use multiversx_sc_scenario::api::StaticApi;
+
+fn esdt_system_sc_proxy() -> ESDTSystemSmartContractProxy<StaticApi> {
+    ESDTSystemSmartContractProxy::new_proxy_obj()
+}
+
+#[test]
+fn test_issue_fungible_cost() {
+    let cc = esdt_system_sc_proxy().issue_fungible(
+        EsdtIssueCost::Mainnet,
+        &ManagedBuffer::from("TestToken"),
+        &ManagedBuffer::from("TEST"),
+        &BigUint::from(1_000u32),
+        FungibleTokenProperties::default(),
+    );
+    assert_eq!(cc.basic.endpoint_name, ManagedBuffer::from("issue"));
+    assert_eq!(cc.egld_payment, BigUint::from(ESDT_DEFAULT_ISSUE_COST));
+}
+
+#[test]
+fn test_issue_non_fungible_custom_cost() {
+    let cc = esdt_system_sc_proxy().issue_non_fungible(
+        EsdtIssueCost::Custom(5_000_000_000_000_000),
+        &ManagedBuffer::from("TestNft"),
+        &ManagedBuffer::from("NFT"),
+        NonFungibleTokenProperties::default(),
+    );
+    assert_eq!(
+        cc.basic.endpoint_name,
+        ManagedBuffer::from("issueNonFungible")
+    );
+    assert_eq!(cc.egld_payment, BigUint::from(5_000_000_000_000_000u64));
+}
+
+#[test]
+fn test_issue_and_set_all_roles_explicit_amount() {
+    let cc = esdt_system_sc_proxy().issue_and_set_all_roles(
+        BigUint::from(ESDT_DEFAULT_ISSUE_COST),
+        ManagedBuffer::from("TestMeta"),
+        ManagedBuffer::from("META"),
+        EsdtTokenType::Meta,
+        18,
+    );
+    let call_data = cc.to_call_data_string().to_string();
+    assert!(call_data.starts_with("registerAndSetAllRoles@"));
+    assert_eq!(cc.egld_payment, BigUint::from(ESDT_DEFAULT_ISSUE_COST));
+}
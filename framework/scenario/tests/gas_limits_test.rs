@@ -0,0 +1,73 @@
+use multiversx_sc_scenario::{scenario_model::*, GasLimits, ScenarioWorld};
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:alice", Account::new().balance("1000"))
+            .put_account("address:bob", Account::new()),
+    );
+    world
+}
+
+fn transfer_with_gas(gas_limit: u64) -> TransferStep {
+    TransferStep::new()
+        .from("address:alice")
+        .to("address:bob")
+        .egld_value("100")
+        .gas_limit(gas_limit)
+}
+
+#[test]
+fn gas_limits_ok_test() {
+    let mut world = world();
+    world
+        .enforce_gas_limits(GasLimits::mainnet())
+        .transfer_step(transfer_with_gas(600_000_000))
+        .check_state_step(
+            CheckStateStep::new().put_account("address:bob", CheckAccount::new().balance("100")),
+        );
+}
+
+#[test]
+#[should_panic(expected = "gas limit 600000001 exceeds the maximum gas limit per transaction")]
+fn gas_limit_per_tx_fail_test() {
+    let mut world = world();
+    world
+        .enforce_gas_limits(GasLimits::mainnet())
+        .transfer_step(transfer_with_gas(600_000_001));
+}
+
+#[test]
+#[should_panic(
+    expected = "total gas limit in block 0 (3000000001) exceeds the maximum gas limit per block"
+)]
+fn gas_limit_per_block_fail_test() {
+    let mut world = world();
+    world.enforce_gas_limits(GasLimits::mainnet());
+    for _ in 0..5 {
+        world.transfer_step(transfer_with_gas(600_000_000));
+    }
+    world.transfer_step(transfer_with_gas(1));
+}
+
+#[test]
+fn gas_limit_per_block_reset_test() {
+    let mut world = world();
+    world.enforce_gas_limits(GasLimits::mainnet());
+    for _ in 0..5 {
+        world.transfer_step(transfer_with_gas(600_000_000));
+    }
+    world
+        .set_state_step(SetStateStep::new().block_nonce(1))
+        .transfer_step(transfer_with_gas(600_000_000));
+}
+
+#[test]
+fn ignore_gas_limits_test() {
+    let mut world = world();
+    world
+        .enforce_gas_limits(GasLimits::mainnet())
+        .ignore_gas_limits()
+        .transfer_step(transfer_with_gas(1_000_000_000_000));
+}
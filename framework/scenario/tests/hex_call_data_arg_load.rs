@@ -5,7 +5,7 @@ use multiversx_sc::{
         multi_types::{MultiValue2, MultiValueVec, OptionalValue},
         PanicErrorHandler, TopDecodeMulti, TopDecodeMultiInput,
     },
-    types::{AsyncCallResult, BigUint},
+    types::{AsyncCallResult, BigUint, CallbackError, CallbackResult},
     HexCallDataDeserializer,
 };
 use multiversx_sc_scenario::api::StaticApi;
@@ -158,3 +158,35 @@ fn test_async_call_result_err() {
         },
     }
 }
+
+#[test]
+fn test_callback_result_ok() {
+    let input: &[u8] = b"func@00@1111";
+    let mut de = HexCallDataDeserializer::new(input);
+    let Ok(cr) =
+        CallbackResult::<StaticApi, i32>::multi_decode_or_handle_err(&mut de, PanicErrorHandler);
+    assert_eq!(cr.ok(), Some(0x1111i32));
+}
+
+#[test]
+fn test_callback_result_err_categories() {
+    let check = |input: &[u8], expected_code: u32| {
+        let mut de = HexCallDataDeserializer::new(input);
+        let Ok(cr) = CallbackResult::<StaticApi, i32>::multi_decode_or_handle_err(
+            &mut de,
+            PanicErrorHandler,
+        );
+        let error = cr.err().expect("CallbackResult::Err expected");
+        assert_eq!(error.err_code(), expected_code);
+        assert_eq!(error.err_msg().to_boxed_bytes().as_slice(), b"msg");
+        error
+    };
+
+    assert!(check(b"func@04@6d7367", 4).is_user_error());
+    assert!(check(b"func@05@6d7367", 5).is_out_of_gas());
+    assert!(check(b"func@0a@6d7367", 10).is_execution_failed());
+    assert!(matches!(
+        check(b"func@0123@6d7367", 0x0123),
+        CallbackError::Other { .. }
+    ));
+}
@@ -0,0 +1,105 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(
+                "address:A",
+                Account::new()
+                    .esdt_balance("str:TOK-123456", "150")
+                    .esdt_balance("str:OTHERTOK-123456", "500"),
+            )
+            .put_account("address:B", Account::new()),
+    );
+    world
+}
+
+fn multi_transfer() -> ScCallStep {
+    ScCallStep::new()
+        .from("address:A")
+        .to("address:A")
+        .function("MultiESDTNFTTransfer")
+        .argument("address:B")
+        .argument("2")
+        .argument("str:TOK-123456")
+        .argument("0")
+        .argument("10")
+        .argument("str:OTHERTOK-123456")
+        .argument("0")
+        .argument("20")
+}
+
+fn transfer_log(token_id_expr: &str, amount: u32) -> CheckLog {
+    CheckLog::new()
+        .endpoint("str:MultiESDTNFTTransfer")
+        .topic(token_id_expr)
+        .topic("*")
+        .typed_topic(amount)
+        .topic("address:B")
+}
+
+#[test]
+fn logs_ordered_check_test() {
+    let mut world = world();
+    world.sc_call(
+        multi_transfer().expect(
+            TxExpect::ok()
+                .log(transfer_log("str:TOK-123456", 10))
+                .log(transfer_log("str:OTHERTOK-123456", 20)),
+        ),
+    );
+}
+
+#[test]
+fn logs_unordered_check_test() {
+    let mut world = world();
+    world.sc_call(
+        multi_transfer().expect(
+            TxExpect::ok()
+                .log(transfer_log("str:OTHERTOK-123456", 20))
+                .log(transfer_log("str:TOK-123456", 10))
+                .logs_unordered(),
+        ),
+    );
+}
+
+#[test]
+fn logs_unordered_subset_check_test() {
+    let mut world = world();
+    world.sc_call(
+        multi_transfer().expect(
+            TxExpect::ok()
+                .log(transfer_log("str:OTHERTOK-123456", 20))
+                .logs_unordered()
+                .more_logs_allowed(),
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Logs do not match")]
+fn logs_ordered_check_fail_test() {
+    let mut world = world();
+    world.sc_call(
+        multi_transfer().expect(
+            TxExpect::ok()
+                .log(transfer_log("str:OTHERTOK-123456", 20))
+                .log(transfer_log("str:TOK-123456", 10)),
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Expected log not found")]
+fn logs_unordered_check_fail_test() {
+    let mut world = world();
+    world.sc_call(
+        multi_transfer().expect(
+            TxExpect::ok()
+                .log(transfer_log("str:TOK-123456", 20))
+                .logs_unordered()
+                .more_logs_allowed(),
+        ),
+    );
+}
@@ -0,0 +1,38 @@
+use multiversx_sc::types::ManagedAddress;
+use multiversx_sc_scenario::api::StaticApi;
+
+fn address_from_bytes(bytes: [u8; 32]) -> ManagedAddress<StaticApi> {
+    ManagedAddress::from(bytes)
+}
+
+#[test]
+fn managed_address_is_zero_test() {
+    assert!(ManagedAddress::<StaticApi>::zero().is_zero());
+    assert!(!address_from_bytes([1u8; 32]).is_zero());
+}
+
+#[test]
+fn managed_address_is_smart_contract_test() {
+    let mut sc_address = [1u8; 32];
+    sc_address[..8].copy_from_slice(&[0u8; 8]);
+    assert!(address_from_bytes(sc_address).is_smart_contract());
+
+    let mut user_address = [1u8; 32];
+    user_address[..7].copy_from_slice(&[0u8; 7]);
+    assert!(!address_from_bytes(user_address).is_smart_contract());
+}
+
+#[test]
+fn managed_address_shard_id_test() {
+    let mut address = [1u8; 32];
+    for (last_byte, shard_id) in [(0u8, 0u32), (1, 1), (2, 2), (3, 1), (0xff, 1)] {
+        address[31] = last_byte;
+        assert_eq!(address_from_bytes(address).shard_id(), shard_id);
+    }
+
+    // system smart contracts are on the metachain
+    let mut esdt_system_sc = [0u8; 32];
+    esdt_system_sc[9] = 1;
+    esdt_system_sc[30..].copy_from_slice(&[0xff, 0xff]);
+    assert_eq!(address_from_bytes(esdt_system_sc).shard_id(), u32::MAX);
+}
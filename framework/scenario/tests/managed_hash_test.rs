@@ -0,0 +1,57 @@
+use multiversx_sc::{
+    abi::TypeAbi,
+    contract_base::CryptoWrapper,
+    types::{Hash20, Hash32, ManagedBuffer, ManagedByteArray, ManagedVec},
+};
+use multiversx_sc_scenario::{api::StaticApi, managed_test_util::check_managed_top_encode_decode};
+
+fn sha256_abc() -> [u8; 32] {
+    hex::decode("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn managed_hash_from_crypto_test() {
+    let crypto = CryptoWrapper::<StaticApi>::new();
+    let hash: Hash32<StaticApi> = crypto.sha256(ManagedBuffer::from("abc")).into();
+    assert_eq!(hash, Hash32::from(sha256_abc()));
+    assert_eq!(hash.to_byte_array(), sha256_abc());
+    assert!(!hash.is_zero());
+
+    // ripemd160 is not available in the debugger, the hash of "abc" is hardcoded instead
+    let ripemd160_abc: [u8; 20] = hex::decode("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let hash: Hash20<StaticApi> = ManagedByteArray::new_from_bytes(&ripemd160_abc).into();
+    assert_eq!(hash.as_managed_buffer().len(), 20);
+    assert_eq!(hash.to_byte_array(), ripemd160_abc);
+}
+
+#[test]
+fn managed_hash_serialization_test() {
+    check_managed_top_encode_decode(Hash32::<StaticApi>::from(sha256_abc()), &sha256_abc());
+    check_managed_top_encode_decode(Hash20::<StaticApi>::from([7u8; 20]), &[7u8; 20]);
+
+    // encodes just like a byte array
+    let hash_vec = ManagedVec::<StaticApi, Hash20<StaticApi>>::from_single_item(Hash20::zero());
+    let array_vec = ManagedVec::<StaticApi, ManagedByteArray<StaticApi, 20>>::from_single_item(
+        ManagedByteArray::new_from_bytes(&[0u8; 20]),
+    );
+    check_managed_top_encode_decode(hash_vec, &[0u8; 20]);
+    check_managed_top_encode_decode(array_vec, &[0u8; 20]);
+}
+
+#[test]
+fn managed_hash_abi_test() {
+    assert_eq!(Hash32::<StaticApi>::type_name(), "H256");
+    assert_eq!(Hash20::<StaticApi>::type_name(), "H160");
+}
+
+#[test]
+fn managed_hash_debug_test() {
+    let hash = Hash20::<StaticApi>::from([0xabu8; 20]);
+    assert!(format!("{hash:?}").contains(&"ab".repeat(20)));
+}
@@ -0,0 +1,71 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+use multiversx_sdk::data::network_config::NetworkConfig;
+
+fn sovereign_world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.network_config(
+        NetworkConfig::default()
+            .with_chain_id("sov-1")
+            .with_min_gas_price(1_000)
+            .with_min_gas_limit(10_000)
+            .with_gas_per_data_byte(100),
+    );
+    world.account("alice").balance(1_000_000_000u64);
+    world.account("bob");
+    world
+}
+
+#[test]
+fn network_config_min_gas_limit_for_data_test() {
+    let network_config = NetworkConfig::default()
+        .with_min_gas_limit(10_000)
+        .with_gas_per_data_byte(100);
+    assert_eq!(network_config.min_gas_limit_for_data(0), 10_000);
+    assert_eq!(network_config.min_gas_limit_for_data(5), 10_500);
+}
+
+#[test]
+fn network_config_transfer_ok_test() {
+    let mut world = sovereign_world();
+    world
+        .transfer_step(
+            TransferStep::new()
+                .from("address:alice")
+                .to("address:bob")
+                .egld_value(100u64)
+                .gas_limit(10_000u64)
+                .gas_price(1_000u64),
+        )
+        .check_state_step(
+            CheckStateStep::new().put_account("address:bob", CheckAccount::new().balance("100")),
+        );
+}
+
+#[test]
+#[should_panic(expected = "gas price 0 is below the minimum gas price of chain sov-1 (1000)")]
+fn network_config_gas_price_too_low_test() {
+    let mut world = sovereign_world();
+    world.transfer_step(
+        TransferStep::new()
+            .from("address:alice")
+            .to("address:bob")
+            .egld_value(100u64)
+            .gas_limit(10_000u64),
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "does not cover the 5 bytes of data, at least 10500 needed on chain sov-1"
+)]
+fn network_config_gas_limit_too_low_test() {
+    let mut world = sovereign_world();
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:alice")
+            .to("address:bob")
+            .function("hello")
+            .gas_limit(10_499u64)
+            .gas_price(1_000u64),
+    );
+}
@@ -0,0 +1,42 @@
+use multiversx_sc_scenario::multiversx_chain_vm::{
+    types::VMAddress,
+    world_mock::{BlockchainState, NewAddressGenerator, ProtocolNewAddressGenerator},
+};
+use std::sync::Arc;
+
+#[test]
+fn protocol_new_address_generator_test() {
+    let creator = VMAddress::from([7u8; 32]);
+    let new_address = ProtocolNewAddressGenerator.generate_new_address(&creator, 3);
+
+    assert!(new_address.is_smart_contract_address());
+    assert_eq!(&new_address.as_bytes()[8..10], &[5, 0]);
+    assert_eq!(&new_address.as_bytes()[30..], &creator.as_bytes()[30..]);
+
+    // deterministic, but nonce-dependent
+    assert_eq!(
+        new_address,
+        ProtocolNewAddressGenerator.generate_new_address(&creator, 3)
+    );
+    assert_ne!(
+        new_address,
+        ProtocolNewAddressGenerator.generate_new_address(&creator, 4)
+    );
+}
+
+#[test]
+fn explicit_new_address_precedence_test() {
+    let creator = VMAddress::from([7u8; 32]);
+    let pinned = VMAddress::from([9u8; 32]);
+
+    let mut state = BlockchainState::default();
+    assert_eq!(state.get_new_address(creator.clone(), 0), None);
+
+    state.new_address_generator = Some(Arc::new(ProtocolNewAddressGenerator));
+    state.put_new_address(creator.clone(), 0, pinned.clone());
+    assert_eq!(state.get_new_address(creator.clone(), 0), Some(pinned));
+    assert_eq!(
+        state.get_new_address(creator.clone(), 1),
+        Some(ProtocolNewAddressGenerator.generate_new_address(&creator, 1))
+    );
+}
@@ -13,3 +13,28 @@ fn local_path_test() {
 fn nested_path_test() {
     world().run("tests/scenarios-self/external_steps/external_path_test.scen.json");
 }
+
+#[test]
+fn workspace_root_file_registry_test() {
+    let mut world = world();
+    let workspace_root = world.workspace_root();
+    world
+        .set_workspace_root(&workspace_root)
+        .set_current_dir_from_workspace("framework/scenario");
+
+    let expected = std::fs::read("tests/scenarios-self/path_test.scen.json").unwrap();
+    let code = world.code_expression("file:tests/scenarios-self/path_test.scen.json");
+    assert_eq!(code.value, expected);
+
+    // loading again comes from the cache
+    world.code_expression("file:tests/../tests/scenarios-self/path_test.scen.json");
+    assert_eq!(world.file_registry().num_cached_files(), 1);
+}
+
+#[test]
+fn temp_dir_test() {
+    let world = world();
+    let temp_dir = world.temp_dir("temp_dir_test");
+    assert!(temp_dir.is_dir());
+    assert!(temp_dir.starts_with(world.workspace_root().join("target")));
+}
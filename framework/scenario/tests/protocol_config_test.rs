@@ -0,0 +1,46 @@
+use multiversx_chain_vm::world_mock::{ProtocolConfig, ProtocolFeature};
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:alice", Account::new().balance("1000"))
+            .put_account(
+                "sc:not-payable",
+                Account::new()
+                    .code("str:not payable code")
+                    .code_metadata("0x0000"),
+            ),
+    );
+    world
+        .protocol_config(ProtocolConfig::new().activate_at_epoch(ProtocolFeature::PayableCheck, 5));
+    world
+}
+
+fn transfer_to_non_payable() -> TransferStep {
+    TransferStep::new()
+        .from("address:alice")
+        .to("sc:not-payable")
+        .egld_value("100")
+}
+
+#[test]
+fn payable_check_before_activation_test() {
+    let mut world = world();
+    world
+        .set_state_step(SetStateStep::new().block_epoch(4))
+        .transfer_step(transfer_to_non_payable())
+        .check_state_step(
+            CheckStateStep::new().put_account("sc:not-payable", CheckAccount::new().balance("100")),
+        );
+}
+
+#[test]
+#[should_panic(expected = "sending value to non payable contract")]
+fn payable_check_after_activation_test() {
+    let mut world = world();
+    world
+        .set_state_step(SetStateStep::new().block_epoch(5))
+        .transfer_step(transfer_to_non_payable());
+}
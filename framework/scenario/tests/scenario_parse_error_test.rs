@@ -0,0 +1,59 @@
+use multiversx_sc_scenario::scenario::{
+    check_scenarios_parse, try_parse_scenario, try_parse_scenario_raw,
+};
+
+#[test]
+fn scenario_parse_error_step_test() {
+    let err = try_parse_scenario_raw("tests/scenarios-parse-error/bad_step.scen.json")
+        .err()
+        .unwrap();
+    assert_eq!(err.step_index, Some(1));
+    assert_eq!(err.field.as_deref(), Some("tx"));
+}
+
+#[test]
+fn scenario_parse_error_syntax_test() {
+    let err = try_parse_scenario_raw("tests/scenarios-parse-error/bad_syntax.scen.json")
+        .err()
+        .unwrap();
+    assert_eq!(err.step_index, None);
+    assert_eq!(err.line, 6);
+}
+
+#[test]
+fn scenario_parse_error_value_test() {
+    let err = try_parse_scenario("tests/scenarios-parse-error/bad_value.scen.json")
+        .err()
+        .unwrap();
+    assert_eq!(err.step_index, Some(1));
+    assert!(err.to_string().contains("bad_value.scen.json, step #1"));
+}
+
+#[test]
+fn scenario_parse_error_report_test() {
+    let report = check_scenarios_parse(&[
+        "tests/scenarios-parse-error/bad_step.scen.json",
+        "tests/scenarios-parse-error/bad_syntax.scen.json",
+        "tests/scenarios-parse-error/bad_value.scen.json",
+        "tests/scenarios-parse-error/bad_address.scen.json",
+        "tests/scenarios-io/example_raw.scen.json",
+    ])
+    .err()
+    .unwrap();
+    assert_eq!(report.errors.len(), 4);
+    let report_string = report.to_string();
+    assert!(report_string.contains("bad_step.scen.json"));
+    assert!(report_string.contains("bad_syntax.scen.json"));
+    // valid json, but the values cannot be interpreted
+    assert!(report_string.contains("bad_value.scen.json, step #1"));
+    assert!(report_string.contains("bad_address.scen.json, step #0"));
+}
+
+#[test]
+#[should_panic(expected = "1 scenario file(s) failed to parse")]
+fn scenario_parse_error_run_scenarios_test() {
+    multiversx_sc_scenario::ScenarioWorld::run_scenarios(
+        multiversx_sc_scenario::ScenarioWorld::new,
+        &["tests/scenarios-parse-error/bad_step.scen.json"],
+    );
+}
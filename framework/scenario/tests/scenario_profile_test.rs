@@ -0,0 +1,108 @@
+use multiversx_sc_scenario::{multiversx_chain_vm::world_mock::GasSchedule, *};
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("framework/scenario");
+    blockchain
+}
+
+#[test]
+fn scenario_profile_steps_test() {
+    let profile = ScenarioWorld::run_scenarios_profiled(
+        world,
+        &[
+            "tests/scenarios-self/external_steps/external_steps.scen.json",
+            "tests/scenarios-self/transfer-egld.scen.json",
+        ],
+    );
+
+    // external steps are attributed to the file they come from
+    let step_types: Vec<&str> = profile.steps().iter().map(|step| step.step_type).collect();
+    assert_eq!(
+        step_types,
+        [
+            "setState",
+            "checkState",
+            "setState",
+            "transfer",
+            "checkState",
+            "transfer",
+            "checkState"
+        ]
+    );
+    assert_eq!(
+        profile.steps()[0].file.file_name().unwrap(),
+        "external_step_2.step.json"
+    );
+    assert_eq!(profile.steps()[1].step_index, 1);
+
+    let transfer = &profile.steps()[3];
+    assert_eq!(transfer.id, "1");
+    assert!(transfer.gas_limit.is_some());
+    assert_eq!(transfer.gas_used, None);
+    assert_eq!(profile.steps()[2].gas_limit, None);
+}
+
+#[test]
+fn scenario_profile_gas_used_test() {
+    let profile = ScenarioWorld::run_scenarios_profiled(
+        || {
+            let mut world = world();
+            world.gas_schedule(GasSchedule::default());
+            world
+        },
+        &["tests/scenarios-self/builtin-func-esdt-transfer.scen.json"],
+    );
+
+    // with a gas schedule, the gas actually used is reported, not the gas limit
+    let esdt_transfer = &profile.steps()[1];
+    assert_eq!(esdt_transfer.step_type, "scCall");
+    assert_eq!(esdt_transfer.gas_limit, Some(50_000_000));
+    assert_eq!(
+        esdt_transfer.gas_used,
+        Some(GasSchedule::default().builtin_call)
+    );
+    assert_eq!(profile.steps()[0].gas_used, None);
+    assert_eq!(
+        profile.slowest_files(1)[0].gas_used,
+        GasSchedule::default().builtin_call
+    );
+}
+
+#[test]
+fn scenario_profile_report_test() {
+    let profile = ScenarioWorld::run_scenarios_profiled(
+        world,
+        &[
+            "tests/scenarios-self/external_steps/external_steps.scen.json",
+            "tests/scenarios-self/transfer-egld.scen.json",
+        ],
+    );
+
+    let slowest_steps = profile.slowest_steps(3);
+    assert_eq!(slowest_steps.len(), 3);
+    assert!(slowest_steps[0].duration >= slowest_steps[1].duration);
+    assert!(slowest_steps[1].duration >= slowest_steps[2].duration);
+
+    let slowest_files = profile.slowest_files(10);
+    assert_eq!(slowest_files.len(), 3);
+    assert_eq!(
+        slowest_files
+            .iter()
+            .map(|file| file.num_steps)
+            .sum::<usize>(),
+        7
+    );
+    assert_eq!(
+        slowest_files
+            .iter()
+            .map(|file| file.duration)
+            .sum::<std::time::Duration>(),
+        profile.total_duration()
+    );
+
+    let report = profile.report(5);
+    assert!(report.starts_with("Scenario profile: 7 steps"));
+    assert!(report.contains("transfer-egld.scen.json"));
+    assert!(report.contains("Slowest steps:"));
+}
@@ -0,0 +1,75 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld, ScenarioWorldService};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+};
+
+fn service() -> ScenarioWorldService {
+    ScenarioWorldService::new(|| {
+        let mut world = ScenarioWorld::new();
+        world.account("alice").balance(1_000u64);
+        world.account("bob");
+        world
+    })
+}
+
+fn transfer(world: &mut ScenarioWorld, amount: u64) {
+    world.transfer_step(
+        TransferStep::new()
+            .from("address:alice")
+            .to("address:bob")
+            .egld_value(amount),
+    );
+}
+
+#[test]
+fn scenario_world_service_send_sync_test() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ScenarioWorldService>();
+}
+
+#[tokio::test]
+async fn scenario_world_service_async_test() {
+    let service = Arc::new(service());
+
+    let mut handles = Vec::new();
+    for _ in 0..5 {
+        let service = service.clone();
+        handles.push(tokio::spawn(async move {
+            service.execute(|world| transfer(world, 10)).await
+        }));
+    }
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    let bob_address = service
+        .execute(|world| {
+            world.check_state_step(
+                CheckStateStep::new()
+                    .put_account("address:alice", CheckAccount::new().balance("950"))
+                    .put_account("address:bob", CheckAccount::new().balance("50")),
+            );
+            world.account("bob").address()
+        })
+        .await;
+    assert_eq!(bob_address, AddressKey::from("address:bob").value);
+}
+
+#[test]
+fn scenario_world_service_panic_test() {
+    let service = service();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        service.execute_blocking(|world| transfer(world, 2_000))
+    }));
+    assert!(result.is_err());
+
+    // the world is still available after a failed call
+    service.execute_blocking(|world| transfer(world, 100));
+    service.execute_blocking(|world| {
+        world.check_state_step(
+            CheckStateStep::new().put_account("address:bob", CheckAccount::new().balance("100")),
+        );
+    });
+}
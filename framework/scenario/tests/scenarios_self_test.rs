@@ -151,6 +151,45 @@ fn esdt_zero_balance_check_err_rs() {
     world().run("tests/scenarios-self/esdt-zero-balance-check-err.scen.json");
 }
 
+#[test]
+fn esdt_balance_approx_check_rs() {
+    world().run("tests/scenarios-self/esdt-balance-approx-check.scen.json");
+}
+
+#[test]
+#[should_panic]
+fn esdt_balance_approx_check_err_rs() {
+    world().run("tests/scenarios-self/esdt-balance-approx-check.err.json");
+}
+
+#[test]
+fn esdt_global_settings_rs() {
+    world().run("tests/scenarios-self/esdt-global-settings.scen.json");
+}
+
+#[test]
+#[should_panic]
+fn esdt_paused_transfer_err_rs() {
+    world().run("tests/scenarios-self/esdt-paused-transfer.err.json");
+}
+
+#[test]
+#[should_panic]
+fn esdt_limited_transfer_err_rs() {
+    world().run("tests/scenarios-self/esdt-limited-transfer.err.json");
+}
+
+#[test]
+fn esdt_roles_check_rs() {
+    world().run("tests/scenarios-self/esdt-roles-check.scen.json");
+}
+
+#[test]
+#[should_panic]
+fn esdt_roles_check_err_rs() {
+    world().run("tests/scenarios-self/esdt-roles-check.err.json");
+}
+
 #[test]
 fn multi_transfer_esdt_rs() {
     world().run("tests/scenarios-self/multi-transfer-esdt.scen.json");
@@ -170,3 +209,14 @@ fn transfer_esdt_rs() {
 fn validator_reward_rs() {
     world().run("tests/scenarios-self/validatorReward.scen.json");
 }
+
+#[test]
+fn logs_unordered_check_rs() {
+    world().run("tests/scenarios-self/logs-unordered-check.scen.json");
+}
+
+#[test]
+#[should_panic]
+fn logs_unordered_check_err_rs() {
+    world().run("tests/scenarios-self/logs-unordered-check.err.json");
+}
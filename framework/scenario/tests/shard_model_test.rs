@@ -0,0 +1,52 @@
+use multiversx_sc_scenario::multiversx_chain_vm::{
+    types::VMAddress,
+    world_mock::{ShardModel, METACHAIN_SHARD_ID},
+};
+
+fn address_with_last_byte(last_byte: u8) -> VMAddress {
+    let mut bytes = [1u8; 32];
+    bytes[31] = last_byte;
+    VMAddress::from(bytes)
+}
+
+#[test]
+fn shard_model_three_shards_test() {
+    let shard_model = ShardModel::default();
+    assert_eq!(shard_model.compute_shard_id(&address_with_last_byte(0)), 0);
+    assert_eq!(shard_model.compute_shard_id(&address_with_last_byte(1)), 1);
+    assert_eq!(shard_model.compute_shard_id(&address_with_last_byte(2)), 2);
+    // 3 is out of range, falls back to the lower mask
+    assert_eq!(shard_model.compute_shard_id(&address_with_last_byte(3)), 1);
+    assert_eq!(shard_model.compute_shard_id(&address_with_last_byte(4)), 0);
+    assert_eq!(
+        shard_model.compute_shard_id(&address_with_last_byte(0xff)),
+        1
+    );
+}
+
+#[test]
+fn shard_model_single_shard_test() {
+    let shard_model = ShardModel::new(1);
+    for last_byte in [0u8, 1, 2, 3, 0xff] {
+        assert_eq!(
+            shard_model.compute_shard_id(&address_with_last_byte(last_byte)),
+            0
+        );
+    }
+}
+
+#[test]
+fn shard_model_metachain_test() {
+    let mut esdt_system_sc = [0u8; 32];
+    esdt_system_sc[9] = 1;
+    esdt_system_sc[30] = 0xff;
+    esdt_system_sc[31] = 0xff;
+    let esdt_system_sc = VMAddress::from(esdt_system_sc);
+
+    let shard_model = ShardModel::default();
+    assert_eq!(
+        shard_model.compute_shard_id(&esdt_system_sc),
+        METACHAIN_SHARD_ID
+    );
+    assert!(!shard_model.same_shard(&esdt_system_sc, &address_with_last_byte(0xff)));
+}
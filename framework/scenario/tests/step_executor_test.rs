@@ -0,0 +1,45 @@
+use multiversx_sc_scenario::{scenario::StepExecutor, scenario_model::*, ScenarioWorld};
+
+/// Written once, could just as well run on an `Interactor`.
+async fn transfer_flow<E: StepExecutor>(executor: &mut E) {
+    let mut transfer_step = TransferStep::new()
+        .from("address:sender")
+        .to("address:receiver")
+        .egld_value(300u64);
+    executor.execute_transfer(&mut transfer_step).await;
+    assert!(transfer_step.response().is_success());
+
+    let typed_sc_call: TypedScCall<()> = ScCallStep::new()
+        .from("address:sender")
+        .to("address:receiver")
+        .esdt_transfer("str:FUNG-123456", 0u64, 400u64)
+        .into();
+    let response: TypedResponse<()> = typed_sc_call.run_on(executor).await;
+    assert!(response.result.is_ok());
+}
+
+#[tokio::test]
+async fn step_executor_world_test() {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(
+                "address:sender",
+                Account::new()
+                    .balance(1_000u64)
+                    .esdt_balance("str:FUNG-123456", "1000"),
+            )
+            .put_account("address:receiver", Account::new()),
+    );
+
+    transfer_flow(&mut world).await;
+
+    world.check_state_step(
+        CheckStateStep::new().put_account(
+            "address:receiver",
+            CheckAccount::new()
+                .balance("300")
+                .esdt_balance("str:FUNG-123456", "400"),
+        ),
+    );
+}
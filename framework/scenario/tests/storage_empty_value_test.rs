@@ -0,0 +1,69 @@
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::{
+        types::VMAddress,
+        world_mock::{AccountData, BlockchainState},
+    },
+    scenario_model::*,
+    ScenarioWorld,
+};
+use std::sync::{Arc, Mutex};
+
+fn account_with_storage(storage: &[(&str, &str)]) -> Account {
+    let mut account = Account::new().balance(1_000u64);
+    for (key, value) in storage {
+        account
+            .storage
+            .insert(BytesKey::from(*key), BytesValue::from(*value));
+    }
+    account
+}
+
+#[test]
+fn storage_write_empty_value_deletes_key_test() {
+    let mut account = AccountData::new_empty(VMAddress::from([1u8; 32]));
+    account.storage_write(b"key", b"value");
+    assert_eq!(
+        account.storage.get(b"key".as_slice()),
+        Some(&b"value".to_vec())
+    );
+
+    account.storage_write(b"key", b"");
+    assert!(account.storage.is_empty());
+}
+
+#[test]
+fn set_state_drops_empty_storage_values_test() {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(SetStateStep::new().put_account(
+        "address:alice",
+        account_with_storage(&[("str:empty", ""), ("str:non-empty", "str:value")]),
+    ));
+    world.account("bob");
+
+    let storage_keys: Arc<Mutex<Vec<Vec<u8>>>> = Arc::default();
+    let storage_keys_clone = storage_keys.clone();
+    let alice = AddressKey::from("address:alice").to_vm_address();
+    world.add_commit_observer(move |_: &[VMAddress], state: &BlockchainState| {
+        *storage_keys_clone.lock().unwrap() =
+            state.accounts[&alice].storage.keys().cloned().collect();
+    });
+
+    world.transfer_step(
+        TransferStep::new()
+            .from("address:alice")
+            .to("address:bob")
+            .egld_value(100u64),
+    );
+
+    assert_eq!(*storage_keys.lock().unwrap(), vec![b"non-empty".to_vec()]);
+}
+
+#[test]
+#[should_panic(expected = "is set to an empty value")]
+fn forbid_empty_storage_values_test() {
+    let mut world = ScenarioWorld::new();
+    world.forbid_empty_storage_values();
+    world.set_state_step(
+        SetStateStep::new().put_account("address:alice", account_with_storage(&[("str:key", "")])),
+    );
+}
@@ -102,3 +102,85 @@ fn test_list_clear() {
     assert_eq!(list.len(), 0);
     assert!(list.is_empty());
 }
+
+#[test]
+fn test_list_remove_by_id_out_of_order() {
+    let mut list = create_list();
+    let node_ids: Vec<u32> = (40..45)
+        .map(|value| list.push_back(value).get_node_id())
+        .collect();
+
+    assert_eq!(
+        list.remove_node_by_id(node_ids[3]).unwrap().into_value(),
+        43
+    );
+    check_list(&list, vec![40, 41, 42, 44]);
+    assert_eq!(
+        list.remove_node_by_id(node_ids[0]).unwrap().into_value(),
+        40
+    );
+    check_list(&list, vec![41, 42, 44]);
+    assert!(list.remove_node_by_id(node_ids[0]).is_none());
+
+    list.push_after_node_id(node_ids[1], 50);
+    check_list(&list, vec![41, 50, 42, 44]);
+}
+
+#[test]
+fn test_list_cursor_remove_current() {
+    let mut list = create_list();
+    (1..=6).for_each(|value| {
+        let _ = list.push_back(value);
+    });
+
+    let mut visited = Vec::new();
+    let mut cursor = list.cursor();
+    while let Some(node) = cursor.next(&list) {
+        visited.push(node.get_value_cloned());
+        if node.get_value_cloned() % 2 == 0 {
+            list.remove_node_by_id(cursor.current_node_id());
+        }
+    }
+    assert_eq!(visited, vec![1, 2, 3, 4, 5, 6]);
+    check_list(&list, vec![1, 3, 5]);
+}
+
+#[test]
+fn test_list_cursor_insert_and_remove_ahead() {
+    let mut list = create_list();
+    (1..=5).for_each(|value| {
+        let _ = list.push_back(value);
+    });
+
+    let mut visited = Vec::new();
+    let mut cursor = list.cursor();
+    while let Some(node) = cursor.next(&list) {
+        let value = node.get_value_cloned();
+        visited.push(value);
+        if value == 1 {
+            // the successor of the current node can be removed
+            list.remove_node_by_id(node.get_next_node_id());
+        }
+        if value == 3 {
+            list.push_after_node_id(node.get_node_id(), 30);
+        }
+    }
+    assert_eq!(visited, vec![1, 3, 30, 4, 5]);
+    check_list(&list, vec![1, 3, 30, 4, 5]);
+    assert!(cursor.next(&list).is_none());
+}
+
+#[test]
+fn test_list_retain() {
+    let mut list = create_list();
+    (1..=10).for_each(|value| {
+        let _ = list.push_back(value);
+    });
+
+    list.retain(|value| value % 3 != 0);
+    check_list(&list, vec![1, 2, 4, 5, 7, 8, 10]);
+
+    list.retain(|_| false);
+    check_list(&list, vec![]);
+    assert!(list.is_empty());
+}
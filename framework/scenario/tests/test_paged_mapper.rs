@@ -0,0 +1,90 @@
+use multiversx_sc::{
+    codec::{multi_types::MultiValue2, TopDecodeMulti, TopEncodeMulti},
+    storage::{
+        mappers::{
+            MapMapper, PagedMapper, QueueMapper, SetMapper, StorageMapper, UnorderedSetMapper,
+            VecMapper,
+        },
+        StorageKey,
+    },
+    types::MultiValueEncoded,
+};
+use multiversx_sc_scenario::api::SingleTxApi;
+
+fn paged_to_vec<T>(paged: MultiValueEncoded<SingleTxApi, T>) -> Vec<T>
+where
+    T: TopEncodeMulti + TopDecodeMulti,
+{
+    paged.into_iter().collect()
+}
+
+/// All mappers are filled with 10, 20, 30, 40, 50, in this order.
+fn check_pages<M>(mapper: &M)
+where
+    M: PagedMapper<SingleTxApi, Item = u64>,
+{
+    assert_eq!(paged_to_vec(mapper.paged(0, 2)), vec![10, 20]);
+    assert_eq!(paged_to_vec(mapper.paged(2, 2)), vec![30, 40]);
+    assert_eq!(paged_to_vec(mapper.paged(4, 2)), vec![50]);
+    assert_eq!(paged_to_vec(mapper.paged(0, 100)), vec![10, 20, 30, 40, 50]);
+    assert!(paged_to_vec(mapper.paged(5, 2)).is_empty());
+    assert!(paged_to_vec(mapper.paged(1, 0)).is_empty());
+    assert!(paged_to_vec(mapper.paged(usize::MAX, usize::MAX)).is_empty());
+    assert_eq!(paged_to_vec(mapper.paged(3, usize::MAX)), vec![40, 50]);
+}
+
+#[test]
+fn test_paged_vec_mapper() {
+    let mut mapper = VecMapper::<SingleTxApi, u64>::new(StorageKey::new(&b"paged_vec"[..]));
+    assert!(paged_to_vec(mapper.paged(0, 10)).is_empty());
+    for item in [10, 20, 30, 40, 50] {
+        mapper.push(&item);
+    }
+    check_pages(&mapper);
+}
+
+#[test]
+fn test_paged_unordered_set_mapper() {
+    let mut mapper =
+        UnorderedSetMapper::<SingleTxApi, u64>::new(StorageKey::new(&b"paged_unordered_set"[..]));
+    assert!(paged_to_vec(mapper.paged(0, 10)).is_empty());
+    for item in [10, 20, 30, 40, 50] {
+        mapper.insert(item);
+    }
+    check_pages(&mapper);
+}
+
+#[test]
+fn test_paged_set_mapper() {
+    let mut mapper = SetMapper::<SingleTxApi, u64>::new(StorageKey::new(&b"paged_set"[..]));
+    assert!(paged_to_vec(mapper.paged(0, 10)).is_empty());
+    for item in [10, 20, 30, 40, 50] {
+        mapper.insert(item);
+    }
+    check_pages(&mapper);
+}
+
+#[test]
+fn test_paged_queue_mapper() {
+    let mut mapper = QueueMapper::<SingleTxApi, u64>::new(StorageKey::new(&b"paged_queue"[..]));
+    assert!(paged_to_vec(mapper.paged(0, 10)).is_empty());
+    for item in [10, 20, 30, 40, 50] {
+        mapper.push_back(item);
+    }
+    check_pages(&mapper);
+}
+
+#[test]
+fn test_paged_map_mapper() {
+    let mut mapper = MapMapper::<SingleTxApi, u64, u64>::new(StorageKey::new(&b"paged_map"[..]));
+    assert!(paged_to_vec(mapper.paged(0, 10)).is_empty());
+    for key in [1, 2, 3] {
+        mapper.insert(key, key * 100);
+    }
+    let page: Vec<(u64, u64)> = paged_to_vec(mapper.paged(1, 5))
+        .into_iter()
+        .map(MultiValue2::into_tuple)
+        .collect();
+    assert_eq!(page, vec![(2, 200), (3, 300)]);
+    assert!(paged_to_vec(mapper.paged(3, 5)).is_empty());
+}
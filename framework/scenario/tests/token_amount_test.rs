@@ -0,0 +1,73 @@
+use multiversx_sc::{
+    codec::test_util::{check_top_decode, check_top_encode},
+    types::{BigUint, Egld, EsdtTokenPayment, TokenAmount, TokenIdentifier},
+};
+use multiversx_sc_scenario::api::StaticApi;
+
+#[test]
+fn egld_conversions_test() {
+    let one_egld = Egld::<StaticApi>::from_egld(1);
+    assert_eq!(
+        one_egld.as_wei(),
+        &BigUint::from(1_000_000_000_000_000_000u64)
+    );
+    assert_eq!(one_egld.to_whole_egld(), BigUint::from(1u32));
+
+    let half_egld = Egld::<StaticApi>::from_wei(BigUint::from(500_000_000_000_000_000u64));
+    assert_eq!(half_egld.to_whole_egld(), BigUint::zero());
+    assert!(half_egld < one_egld);
+    assert!(Egld::<StaticApi>::zero().is_zero());
+}
+
+#[test]
+fn egld_arithmetic_test() {
+    let one_egld = Egld::<StaticApi>::from_egld(1);
+    let mut total = &one_egld + &Egld::from_egld(2);
+    assert_eq!(total, Egld::from_egld(3));
+
+    total -= &one_egld;
+    assert_eq!(total, Egld::from_egld(2));
+
+    assert_eq!(&one_egld * 5, Egld::from_egld(5));
+    assert_eq!(Egld::<StaticApi>::from_egld(10) / 2, Egld::from_egld(5));
+}
+
+#[test]
+fn egld_codec_test() {
+    let amount = Egld::<StaticApi>::from_wei(BigUint::from(1_000u32));
+    let encoded = check_top_encode(&amount);
+    assert_eq!(
+        encoded,
+        check_top_encode(&BigUint::<StaticApi>::from(1_000u32))
+    );
+    assert_eq!(check_top_decode::<Egld<StaticApi>>(&encoded), amount);
+}
+
+#[test]
+fn token_amount_test() {
+    let token_identifier = TokenIdentifier::<StaticApi>::from("USDC-123456");
+    let ten = TokenAmount::from_whole(token_identifier.clone(), 10, 6);
+    assert_eq!(ten.amount(), &BigUint::from(10_000_000u64));
+    assert_eq!(ten.to_whole(6), BigUint::from(10u32));
+
+    let mut total = &ten + &TokenAmount::new(token_identifier.clone(), BigUint::from(500u32));
+    assert_eq!(total.amount(), &BigUint::from(10_000_500u64));
+
+    total -= &ten;
+    assert_eq!(total.amount(), &BigUint::from(500u32));
+    assert_eq!((&total * 2).amount(), &BigUint::from(1_000u32));
+
+    let payment: EsdtTokenPayment<StaticApi> = total.into();
+    assert_eq!(
+        payment,
+        EsdtTokenPayment::new(token_identifier, 0, BigUint::from(500u32))
+    );
+}
+
+#[test]
+#[should_panic]
+fn token_amount_mismatch_test() {
+    let usdc = TokenAmount::<StaticApi>::from_whole(TokenIdentifier::from("USDC-123456"), 1, 6);
+    let wegld = TokenAmount::<StaticApi>::from_whole(TokenIdentifier::from("WEGLD-123456"), 1, 18);
+    let _ = usdc + wegld;
+}
@@ -0,0 +1,13 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+#[test]
+fn token_decimals_from_registry_test() {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new()
+            .esdt_global_settings("str:USDC-123456", EsdtGlobalSettings::new().num_decimals(6)),
+    );
+
+    assert_eq!(world.get_token_decimals("str:USDC-123456"), Some(6));
+    assert_eq!(world.get_token_decimals("str:UNKNOWN-123456"), None);
+}
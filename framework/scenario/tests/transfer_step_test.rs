@@ -0,0 +1,41 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+#[test]
+fn transfer_step_multi_esdt_test() {
+    let mut world = ScenarioWorld::new();
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account(
+                    "address:sender",
+                    Account::new()
+                        .esdt_balance("str:FUNG-123456", "1000")
+                        .esdt_nft_balance("str:NFT-123456", 1, "1", Option::<&[u8]>::None),
+                )
+                .put_account("address:receiver", Account::new()),
+        )
+        .transfer_step(
+            TransferStep::new()
+                .from("address:sender")
+                .to("address:receiver")
+                .multi_esdt_transfer([
+                    TxESDT::new("str:FUNG-123456", 0u64, 400u64),
+                    TxESDT::new("str:NFT-123456", 1u64, 1u64),
+                ]),
+        )
+        .check_state_step(
+            CheckStateStep::new()
+                .put_account(
+                    "address:sender",
+                    CheckAccount::new()
+                        .esdt_balance("str:FUNG-123456", "600")
+                        .esdt_balance("str:NFT-123456", "0"),
+                )
+                .put_account(
+                    "address:receiver",
+                    CheckAccount::new()
+                        .esdt_balance("str:FUNG-123456", "400")
+                        .esdt_balance("str:NFT-123456", "1"),
+                ),
+        );
+}
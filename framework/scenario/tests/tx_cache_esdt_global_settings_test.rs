@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::{
+        tx_mock::TxCache,
+        types::VMAddress,
+        world_mock::{AccountData, BlockchainState, EsdtInstanceMetadata},
+    },
+    num_bigint::BigUint,
+};
+
+const TOKEN: &[u8] = b"TOK-123456";
+
+fn tx_cache(from: &VMAddress, to: &VMAddress) -> TxCache {
+    let mut state = BlockchainState::default();
+    let mut from_account = AccountData::new_empty(from.clone());
+    from_account.esdt.set_esdt_balance(
+        TOKEN.to_vec(),
+        0,
+        &BigUint::from(100u32),
+        EsdtInstanceMetadata::default(),
+    );
+    state.accounts.insert(from.clone(), from_account);
+    state
+        .accounts
+        .insert(to.clone(), AccountData::new_empty(to.clone()));
+    TxCache::new(Arc::new(state))
+}
+
+#[test]
+fn tx_cache_transfer_paused_in_same_tx_test() {
+    let from = VMAddress::from([1u8; 32]);
+    let to = VMAddress::from([2u8; 32]);
+    let tx_cache = tx_cache(&from, &to);
+
+    let value = BigUint::from(10u32);
+    assert!(tx_cache
+        .transfer_esdt_balance(&from, &to, TOKEN, 0, &value)
+        .is_ok());
+
+    // not committed yet, but the transfers in the same transaction must already see it
+    tx_cache.with_esdt_global_settings_mut(TOKEN, |settings| settings.paused = true);
+    let err = tx_cache
+        .transfer_esdt_balance(&from, &to, TOKEN, 0, &value)
+        .unwrap_err();
+    assert_eq!(err.message, "esdt token is paused");
+}
+
+#[test]
+fn tx_cache_transfer_limited_in_same_tx_test() {
+    let from = VMAddress::from([1u8; 32]);
+    let to = VMAddress::from([2u8; 32]);
+    let tx_cache = tx_cache(&from, &to);
+
+    tx_cache.with_esdt_global_settings_mut(TOKEN, |settings| settings.limited_transfer = true);
+    let err = tx_cache
+        .transfer_esdt_balance(&from, &to, TOKEN, 0, &BigUint::from(10u32))
+        .unwrap_err();
+    assert_eq!(err.message, "action is not allowed");
+}
@@ -0,0 +1,88 @@
+use multiversx_chain_vm::{
+    tx_mock::{TxLog, TxResult},
+    types::VMAddress,
+};
+
+fn ok_result() -> TxResult {
+    TxResult {
+        result_values: vec![b"abc".to_vec(), vec![1, 2]],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn tx_result_assert_ok_test() {
+    let tx_result = ok_result();
+    tx_result.assert_ok();
+    tx_result.assert_out_values(&[b"abc".to_vec(), vec![1, 2]]);
+}
+
+#[test]
+#[should_panic(expected = "Tx success expected, but failed. Status: 4, message: \"fail\"")]
+fn tx_result_assert_ok_fail_test() {
+    TxResult::from_panic_string("fail").assert_ok();
+}
+
+#[test]
+fn tx_result_assert_user_error_test() {
+    TxResult::from_panic_string("fail").assert_user_error("fail");
+}
+
+#[test]
+#[should_panic(expected = "Tx error status mismatch. Want status 4, message \"fail\"")]
+fn tx_result_assert_user_error_status_fail_test() {
+    TxResult::from_vm_error("fail").assert_user_error("fail");
+}
+
+#[test]
+#[should_panic(expected = "Tx out values mismatch. Want 1 values, have 2.")]
+fn tx_result_assert_out_values_count_fail_test() {
+    ok_result().assert_out_values(&[b"abc"]);
+}
+
+#[test]
+#[should_panic(expected = "Tx out values mismatch. Want 2 values, have 2.")]
+fn tx_result_assert_out_values_fail_test() {
+    ok_result().assert_out_values(&[b"abc".to_vec(), vec![1, 3]]);
+}
+
+fn log_result() -> TxResult {
+    TxResult {
+        result_logs: vec![
+            TxLog {
+                address: VMAddress::from([1u8; 32]),
+                endpoint: "deposit".into(),
+                topics: vec![b"deposit".to_vec(), vec![5]],
+                data: b"first".to_vec(),
+            },
+            TxLog {
+                address: VMAddress::from([1u8; 32]),
+                endpoint: "deposit".into(),
+                topics: vec![b"transfer".to_vec(), vec![5]],
+                data: b"second".to_vec(),
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn tx_result_logs_for_topic_test() {
+    let tx_result = log_result();
+    let deposit_logs = tx_result.logs_for_topic(b"deposit");
+    assert_eq!(deposit_logs.len(), 1);
+    assert_eq!(deposit_logs[0].data, b"first".to_vec());
+    assert_eq!(tx_result.logs_for_topic(&[5]).len(), 2);
+    assert!(tx_result.logs_for_topic(b"withdraw").is_empty());
+}
+
+#[test]
+fn tx_result_assert_log_emitted_test() {
+    log_result().assert_log_emitted(&[b"transfer".to_vec(), vec![5]]);
+}
+
+#[test]
+#[should_panic(expected = "Tx log not emitted.")]
+fn tx_result_assert_log_emitted_fail_test() {
+    log_result().assert_log_emitted(&[b"transfer".to_vec(), vec![6]]);
+}
@@ -0,0 +1,52 @@
+use multiversx_sc_scenario::{
+    assert_tx_result_snapshot,
+    multiversx_chain_vm::{
+        tx_mock::{TxFunctionName, TxLog, TxResult},
+        types::VMAddress,
+    },
+    TxResultSnapshot, BLESS_SNAPSHOTS_ENV_VAR,
+};
+
+fn example_tx_result() -> TxResult {
+    TxResult {
+        result_values: vec![vec![1, 2, 3], Vec::new(), b"abc".to_vec()],
+        result_logs: vec![TxLog {
+            address: VMAddress::from([b'a'; 32]),
+            endpoint: TxFunctionName::from("transfer"),
+            topics: vec![b"event".to_vec()],
+            data: vec![5],
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn tx_result_snapshot_golden_file_test() {
+    assert_tx_result_snapshot(
+        "tests/snapshots/example_tx_result.json",
+        &example_tx_result(),
+    );
+}
+
+#[test]
+fn tx_result_snapshot_mismatch_test() {
+    // works on a copy, so that blessing never overwrites the golden file with the altered result
+    let snapshot_path = std::env::temp_dir().join("tx_result_snapshot_mismatch_test.json");
+    std::fs::copy("tests/snapshots/example_tx_result.json", &snapshot_path).unwrap();
+
+    let mut tx_result = example_tx_result();
+    tx_result.result_values[0] = vec![4];
+    let result = std::panic::catch_unwind(|| {
+        TxResultSnapshot::from(&tx_result).assert_golden_file(&snapshot_path);
+    });
+
+    if std::env::var_os(BLESS_SNAPSHOTS_ENV_VAR).is_some() {
+        assert!(result.is_ok());
+        let blessed = std::fs::read_to_string(&snapshot_path).unwrap();
+        assert_eq!(blessed, TxResultSnapshot::from(&tx_result).to_json_string());
+    } else {
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("-     \"0x010203\""));
+        assert!(message.contains("+     \"0x04\""));
+    }
+}
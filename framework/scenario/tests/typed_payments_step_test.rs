@@ -0,0 +1,99 @@
+use multiversx_sc::types::{Address, BigUint, EsdtTokenPayment, ManagedAddress, TokenIdentifier};
+use multiversx_sc_scenario::{
+    api::StaticApi, scenario_format::serde_raw::ValueSubTree, scenario_model::*, ScenarioWorld,
+};
+
+fn payment(token_id: &str, nonce: u64, amount: u64) -> EsdtTokenPayment<StaticApi> {
+    EsdtTokenPayment::new(
+        TokenIdentifier::from(token_id),
+        nonce,
+        BigUint::from(amount),
+    )
+}
+
+#[test]
+fn typed_payment_conversion_test() {
+    let tx_esdt = TxESDT::from(payment("FUNG-123456", 0, 400));
+    assert_eq!(tx_esdt.esdt_token_identifier.value, b"FUNG-123456".to_vec());
+    assert_eq!(
+        tx_esdt.esdt_token_identifier.original,
+        ValueSubTree::Str("str:FUNG-123456".to_string())
+    );
+    assert_eq!(tx_esdt.nonce.value, 0);
+    assert_eq!(tx_esdt.esdt_value.value, 400u32.into());
+    assert_eq!(
+        tx_esdt.esdt_value.original,
+        ValueSubTree::Str("400".to_string())
+    );
+
+    let address = Address::from([7u8; 32]);
+    let managed_address = ManagedAddress::<StaticApi>::from(&address);
+    assert_eq!(AddressValue::from(&managed_address).value, address);
+    assert_eq!(AddressValue::from(managed_address).value, address);
+}
+
+#[test]
+fn sc_call_step_typed_payments_test() {
+    let step = ScCallStep::new()
+        .from(ManagedAddress::<StaticApi>::from(&Address::from([1u8; 32])))
+        .to("sc:contract")
+        .esdt_payment(payment("FUNG-123456", 0, 400))
+        .multi_esdt_transfer(vec![
+            payment("NFT-123456", 1, 1),
+            payment("NFT-123456", 2, 1),
+        ])
+        .function("accept")
+        .argument(&TokenIdentifier::<StaticApi>::from("FUNG-123456"));
+
+    assert_eq!(step.tx.from.value, Address::from([1u8; 32]));
+    assert_eq!(step.tx.esdt_value.len(), 3);
+    assert_eq!(step.tx.esdt_value[2].nonce.value, 2);
+    assert_eq!(
+        step.tx.arguments[0].original,
+        ValueSubTree::Str("str:FUNG-123456".to_string())
+    );
+}
+
+#[test]
+fn sc_deploy_step_typed_egld_value_test() {
+    let step = ScDeployStep::new()
+        .from("address:owner")
+        .egld_value(BigUint::<StaticApi>::from(1_000u64))
+        .argument(&TokenIdentifier::<StaticApi>::from("FUNG-123456"));
+
+    assert_eq!(step.tx.egld_value.value, 1_000u32.into());
+    assert_eq!(step.tx.arguments[0].value, b"FUNG-123456".to_vec());
+}
+
+#[test]
+fn transfer_step_typed_payments_test() {
+    let mut world = ScenarioWorld::new();
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account(
+                    "address:sender",
+                    Account::new()
+                        .esdt_balance("str:FUNG-123456", "1000")
+                        .esdt_nft_balance("str:NFT-123456", 1, "1", Option::<&[u8]>::None),
+                )
+                .put_account("address:receiver", Account::new()),
+        )
+        .transfer_step(
+            TransferStep::new()
+                .from("address:sender")
+                .to("address:receiver")
+                .multi_esdt_transfer(vec![
+                    payment("FUNG-123456", 0, 400),
+                    payment("NFT-123456", 1, 1),
+                ]),
+        )
+        .check_state_step(
+            CheckStateStep::new().put_account(
+                "address:receiver",
+                CheckAccount::new()
+                    .esdt_balance("str:FUNG-123456", "400")
+                    .esdt_balance("str:NFT-123456", "1"),
+            ),
+        );
+}
@@ -0,0 +1,65 @@
+use multiversx_sc_scenario::{scenario_model::*, ScenarioWorld};
+
+#[test]
+fn world_account_fluent_balances_test() {
+    let mut world = ScenarioWorld::new();
+
+    let alice = world
+        .account("alice")
+        .balance(1_000u64)
+        .esdt_balance("str:FUNG-123456", 500u64)
+        .esdt_nft_balance("str:NFT-123456", 1, 1u64, Option::<&[u8]>::None)
+        .address();
+    assert_eq!(alice, AddressKey::from("address:alice").value);
+
+    // changing one balance keeps the others
+    world
+        .account("alice")
+        .esdt_balance("str:FUNG-123456", 600u64);
+    world.account("bob");
+
+    world
+        .transfer_step(
+            TransferStep::new()
+                .from("address:alice")
+                .to("address:bob")
+                .egld_value(100u64),
+        )
+        .check_state_step(
+            CheckStateStep::new()
+                .put_account(
+                    "address:alice",
+                    CheckAccount::new()
+                        .balance("900")
+                        .esdt_balance("str:FUNG-123456", "600")
+                        .esdt_balance("str:NFT-123456", "1"),
+                )
+                .put_account("address:bob", CheckAccount::new().balance("100")),
+        );
+}
+
+#[test]
+fn world_account_keeps_state_after_transactions_test() {
+    let mut world = ScenarioWorld::new();
+    world.account("alice").balance(1_000u64);
+    world.account("address:bob");
+
+    world.transfer_step(
+        TransferStep::new()
+            .from("address:alice")
+            .to("address:bob")
+            .egld_value(400u64),
+    );
+
+    world.account("alice").esdt_balance("str:FUNG-123456", 5u64);
+
+    world.check_state_step(
+        CheckStateStep::new().put_account(
+            "address:alice",
+            CheckAccount::new()
+                .nonce("1")
+                .balance("600")
+                .esdt_balance("str:FUNG-123456", "5"),
+        ),
+    );
+}
@@ -0,0 +1,119 @@
+use multiversx_sc::{
+    codec,
+    codec::derive::{NestedEncode, TopEncode},
+};
+use multiversx_sc_scenario::{
+    scenario_format::{interpret_trait::InterpretableFrom, serde_raw::StepRaw},
+    scenario_model::Step,
+    ScenarioWorld,
+};
+
+#[derive(TopEncode, NestedEncode)]
+struct NftAttributes {
+    level: u32,
+    power: u64,
+}
+
+const ATTRIBUTES_HEX: &str = "0x000000030000000000000064";
+
+/// Checks the account state using a `checkState` step written in JSON,
+/// since the typed check API only covers balances.
+fn check_state_json(world: &mut ScenarioWorld, accounts_json: &str) {
+    let step_json = format!(r#"{{ "step": "checkState", "accounts": {accounts_json} }}"#);
+    let step_raw: StepRaw = serde_json::from_str(&step_json).unwrap();
+    match Step::interpret_from(step_raw, &world.interpreter_context()) {
+        Step::CheckState(check_state_step) => {
+            world.check_state_step(check_state_step);
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn world_set_nft_balance_test() {
+    let mut world = ScenarioWorld::new();
+    world.set_nft_balance(
+        "alice",
+        "str:NFT-123456",
+        1,
+        1u64,
+        &NftAttributes {
+            level: 3,
+            power: 100,
+        },
+    );
+
+    check_state_json(
+        &mut world,
+        &format!(
+            r#"{{
+                "address:alice": {{
+                    "esdt": {{
+                        "str:NFT-123456": {{
+                            "instances": [
+                                {{
+                                    "nonce": "1",
+                                    "balance": "1",
+                                    "attributes": "{ATTRIBUTES_HEX}"
+                                }}
+                            ]
+                        }}
+                    }},
+                    "storage": "*",
+                    "code": "*"
+                }}
+            }}"#
+        ),
+    );
+}
+
+#[test]
+fn world_set_nft_balance_all_properties_test() {
+    let mut world = ScenarioWorld::new();
+    let creator = world.account("creator").address();
+    world
+        .account("alice")
+        .balance(100u64)
+        .nft_balance_all_properties(
+            "str:SFT-123456",
+            2,
+            5u64,
+            &NftAttributes {
+                level: 3,
+                power: 100,
+            },
+            1_000,
+            Some(&creator),
+            Some(b"hash".as_slice()),
+            &[b"https://example.com/2.png".as_slice()],
+        );
+
+    check_state_json(
+        &mut world,
+        &format!(
+            r#"{{
+                "address:alice": {{
+                    "balance": "100",
+                    "esdt": {{
+                        "str:SFT-123456": {{
+                            "instances": [
+                                {{
+                                    "nonce": "2",
+                                    "balance": "5",
+                                    "creator": "address:creator",
+                                    "royalties": "1000",
+                                    "hash": "str:hash",
+                                    "uri": ["str:https://example.com/2.png"],
+                                    "attributes": "{ATTRIBUTES_HEX}"
+                                }}
+                            ]
+                        }}
+                    }},
+                    "storage": "*",
+                    "code": "*"
+                }},
+                "+": ""
+            }}"#
+        ),
+    );
+}
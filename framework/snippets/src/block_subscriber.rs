@@ -0,0 +1,135 @@
+use crate::{GatewayProxy, GatewayResult, Interactor};
+use futures::stream::{self, Stream};
+use multiversx_sdk::data::{
+    address::Address as ErdrsAddress, hyperblock::HyperBlock, transaction::Events,
+};
+use std::time::Duration;
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(6);
+
+/// Selects events by emitting address and/or identifier.
+///
+/// An empty filter matches all events.
+#[derive(Default, Clone, Debug)]
+pub struct EventFilter {
+    pub address: Option<ErdrsAddress>,
+    pub identifier: Option<String>,
+}
+
+impl EventFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn address(mut self, address: ErdrsAddress) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    pub fn identifier(mut self, identifier: &str) -> Self {
+        self.identifier = Some(identifier.to_string());
+        self
+    }
+
+    pub fn matches(&self, event: &Events) -> bool {
+        if let Some(address) = &self.address {
+            if address.to_bytes() != event.address.to_bytes() {
+                return false;
+            }
+        }
+        if let Some(identifier) = &self.identifier {
+            if identifier != &event.identifier {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// All events in the block that pass the filter, in order.
+    pub fn filter_block(&self, block: &HyperBlock) -> Vec<Events> {
+        block
+            .transactions
+            .iter()
+            .filter_map(|tx| tx.logs.as_ref())
+            .flat_map(|logs| logs.events.iter())
+            .filter(|event| self.matches(event))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Follows the chain by polling the gateway for new hyper blocks.
+///
+/// Hyper blocks are only produced once the metachain notarizes the shard blocks,
+/// so every block returned is final.
+pub struct BlockSubscriber<'a> {
+    proxy: &'a dyn GatewayProxy,
+    next_nonce: Option<u64>,
+    poll_interval: Duration,
+}
+
+impl<'a> BlockSubscriber<'a> {
+    /// Starts from the latest hyper block at the time of the first poll.
+    pub fn new(proxy: &'a dyn GatewayProxy) -> Self {
+        BlockSubscriber {
+            proxy,
+            next_nonce: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Starts from a given hyper block nonce, replaying older blocks if needed.
+    pub fn from_nonce(mut self, nonce: u64) -> Self {
+        self.next_nonce = Some(nonce);
+        self
+    }
+
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// The nonce of the block to be returned next, if already known.
+    pub fn next_nonce(&self) -> Option<u64> {
+        self.next_nonce
+    }
+
+    /// Waits until the next hyper block is available and returns it.
+    pub async fn next_block(&mut self) -> GatewayResult<HyperBlock> {
+        loop {
+            let latest_nonce = self.proxy.get_latest_hyper_block_nonce(true).await?;
+            let next_nonce = *self.next_nonce.get_or_insert(latest_nonce);
+            if next_nonce <= latest_nonce {
+                let block = self.proxy.get_hyper_block_by_nonce(next_nonce).await?;
+                self.next_nonce = Some(next_nonce + 1);
+                return Ok(block);
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Waits for the next block containing events that pass the filter, and returns those events.
+    pub async fn next_events(&mut self, filter: &EventFilter) -> GatewayResult<Vec<Events>> {
+        loop {
+            let events = filter.filter_block(&self.next_block().await?);
+            if !events.is_empty() {
+                return Ok(events);
+            }
+        }
+    }
+
+    /// Converts the subscriber into an endless stream of hyper blocks.
+    pub fn into_stream(self) -> impl Stream<Item = GatewayResult<HyperBlock>> + 'a {
+        stream::unfold(self, |mut subscriber| async move {
+            let block = subscriber.next_block().await;
+            Some((block, subscriber))
+        })
+    }
+}
+
+impl Interactor {
+    pub fn block_subscriber(&self) -> BlockSubscriber<'_> {
+        BlockSubscriber::new(self.proxy.as_ref())
+    }
+}
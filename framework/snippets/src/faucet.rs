@@ -0,0 +1,120 @@
+use futures::future::BoxFuture;
+use log::info;
+use multiversx_sc_scenario::{bech32, multiversx_sc::types::Address, num_bigint::BigUint};
+use std::{str::FromStr, time::Duration};
+
+use crate::{address_h256_to_erdrs, GatewayResult, Interactor};
+
+const FAUCET_ADDRESS_PLACEHOLDER: &str = "{address}";
+const FAUCET_POLL_INTERVAL: Duration = Duration::from_secs(6);
+const FAUCET_MAX_POLLS: usize = 20;
+
+/// Funds fresh wallets on public test networks (devnet, testnet),
+/// so that interactor setups do not need pre-funded PEM files.
+pub trait Faucet: Send + Sync {
+    fn request_funds<'a>(&'a self, address: &'a Address) -> BoxFuture<'a, GatewayResult<()>>;
+}
+
+enum HttpFaucetMethod {
+    Get,
+    Post { body_template: String },
+}
+
+/// A faucet service reachable over HTTP, such as the r3d4 community faucet,
+/// or a faucet deployed alongside a private test network.
+///
+/// All occurrences of `{address}` in the URL and in the body get replaced with the bech32 address to fund.
+pub struct HttpFaucet {
+    url_template: String,
+    method: HttpFaucetMethod,
+    client: reqwest::Client,
+}
+
+impl HttpFaucet {
+    /// Funds are requested with a GET, e.g. `https://my-faucet/request?address={address}`.
+    pub fn get(url_template: &str) -> Self {
+        HttpFaucet {
+            url_template: url_template.to_string(),
+            method: HttpFaucetMethod::Get,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Funds are requested with a POST of a JSON body, e.g. `{"address":"{address}"}`.
+    pub fn post(url_template: &str, body_template: &str) -> Self {
+        HttpFaucet {
+            url_template: url_template.to_string(),
+            method: HttpFaucetMethod::Post {
+                body_template: body_template.to_string(),
+            },
+            client: reqwest::Client::new(),
+        }
+    }
+
+    async fn send_request(&self, address: &Address) -> GatewayResult<()> {
+        let bech32_address = bech32::encode(address);
+        let url = self
+            .url_template
+            .replace(FAUCET_ADDRESS_PLACEHOLDER, &bech32_address);
+        let request = match &self.method {
+            HttpFaucetMethod::Get => self.client.get(url),
+            HttpFaucetMethod::Post { body_template } => self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body_template.replace(FAUCET_ADDRESS_PLACEHOLDER, &bech32_address)),
+        };
+        request.send().await?.error_for_status()?;
+        Ok(())
+    }
+}
+
+impl Faucet for HttpFaucet {
+    fn request_funds<'a>(&'a self, address: &'a Address) -> BoxFuture<'a, GatewayResult<()>> {
+        Box::pin(self.send_request(address))
+    }
+}
+
+impl Interactor {
+    pub fn with_faucet<F>(mut self, faucet: F) -> Self
+    where
+        F: Faucet + 'static,
+    {
+        self.faucet = Some(Box::new(faucet));
+        self
+    }
+
+    /// Requests funds from the faucet, then waits until the EGLD balance of the account reaches `min_balance`.
+    ///
+    /// Panics if no faucet was configured, or if the funds do not arrive in time.
+    pub async fn fund_wallet(&mut self, address: &Address, min_balance: u64) {
+        let faucet = self
+            .faucet
+            .as_ref()
+            .expect("no faucet configured, use `with_faucet`");
+        let bech32_address = bech32::encode(address);
+        faucet
+            .request_funds(address)
+            .await
+            .unwrap_or_else(|err| panic!("faucet request for {bech32_address} failed: {err}"));
+        println!("faucet funds requested for {bech32_address}");
+
+        let min_balance = BigUint::from(min_balance);
+        for _ in 0..FAUCET_MAX_POLLS {
+            let account = self
+                .proxy
+                .get_account(&address_h256_to_erdrs(address))
+                .await
+                .unwrap_or_else(|err| panic!("failed to retrieve account {bech32_address}: {err}"));
+            let balance = BigUint::from_str(&account.balance).unwrap_or_default();
+            if balance >= min_balance {
+                info!("account {bech32_address} funded, balance: {balance}");
+                return;
+            }
+
+            self.sleep(FAUCET_POLL_INTERVAL).await;
+        }
+
+        panic!("account {bech32_address} still not funded after {FAUCET_MAX_POLLS} checks");
+    }
+}
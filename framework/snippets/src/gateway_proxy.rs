@@ -0,0 +1,191 @@
+use futures::future::BoxFuture;
+use multiversx_sdk::{
+    blockchain::CommunicationProxy,
+    data::{
+        account::Account,
+        address::Address as ErdrsAddress,
+        esdt::{EsdtTokenData, EsdtTokenSupply},
+        hyperblock::HyperBlock,
+        network_config::NetworkConfig,
+        transaction::{SimulationResults, Transaction, TransactionOnNetwork, TxCostResponseData},
+        vm::{VmValueRequest, VmValuesResponseData},
+    },
+};
+use std::collections::HashMap;
+
+pub use anyhow::Result as GatewayResult;
+
+/// The gateway operations needed by the interactor.
+///
+/// The default implementation is the HTTP `CommunicationProxy`.
+/// Unit tests can provide a mock with canned responses instead,
+/// and other transports can be plugged in the same way.
+pub trait GatewayProxy: Send + Sync {
+    fn get_network_config(&self) -> BoxFuture<'_, GatewayResult<NetworkConfig>>;
+
+    fn get_account<'a>(
+        &'a self,
+        address: &'a ErdrsAddress,
+    ) -> BoxFuture<'a, GatewayResult<Account>>;
+
+    /// The value is hex-encoded.
+    fn get_account_storage_value<'a>(
+        &'a self,
+        address: &'a ErdrsAddress,
+        key: &'a [u8],
+    ) -> BoxFuture<'a, GatewayResult<String>>;
+
+    /// Nonce 0 means a fungible token.
+    fn get_account_esdt_token<'a>(
+        &'a self,
+        address: &'a ErdrsAddress,
+        token_identifier: &'a str,
+        nonce: u64,
+    ) -> BoxFuture<'a, GatewayResult<EsdtTokenData>>;
+
+    /// The role names of the account, for each token.
+    fn get_account_esdt_roles<'a>(
+        &'a self,
+        address: &'a ErdrsAddress,
+    ) -> BoxFuture<'a, GatewayResult<HashMap<String, Vec<String>>>>;
+
+    fn get_esdt_token_supply<'a>(
+        &'a self,
+        token_identifier: &'a str,
+    ) -> BoxFuture<'a, GatewayResult<EsdtTokenSupply>>;
+
+    /// The nonce of the latest hyper block, or of the latest shard block if `with_metachain` is false.
+    fn get_latest_hyper_block_nonce(
+        &self,
+        with_metachain: bool,
+    ) -> BoxFuture<'_, GatewayResult<u64>>;
+
+    fn get_hyper_block_by_nonce(&self, nonce: u64) -> BoxFuture<'_, GatewayResult<HyperBlock>>;
+
+    /// Returns the transaction hash.
+    fn send_transaction<'a>(&'a self, tx: &'a Transaction) -> BoxFuture<'a, GatewayResult<String>>;
+
+    /// Executes the transaction, without broadcasting it.
+    fn simulate_transaction<'a>(
+        &'a self,
+        tx: &'a Transaction,
+    ) -> BoxFuture<'a, GatewayResult<SimulationResults>>;
+
+    /// Estimates the gas the transaction consumes.
+    fn request_transaction_cost<'a>(
+        &'a self,
+        tx: &'a Transaction,
+    ) -> BoxFuture<'a, GatewayResult<TxCostResponseData>>;
+
+    fn get_transaction_info_with_results<'a>(
+        &'a self,
+        hash: &'a str,
+    ) -> BoxFuture<'a, GatewayResult<TransactionOnNetwork>>;
+
+    fn execute_vmquery<'a>(
+        &'a self,
+        vm_request: &'a VmValueRequest,
+    ) -> BoxFuture<'a, GatewayResult<VmValuesResponseData>>;
+}
+
+impl GatewayProxy for CommunicationProxy {
+    fn get_network_config(&self) -> BoxFuture<'_, GatewayResult<NetworkConfig>> {
+        Box::pin(CommunicationProxy::get_network_config(self))
+    }
+
+    fn get_account<'a>(
+        &'a self,
+        address: &'a ErdrsAddress,
+    ) -> BoxFuture<'a, GatewayResult<Account>> {
+        Box::pin(CommunicationProxy::get_account(self, address))
+    }
+
+    fn get_account_storage_value<'a>(
+        &'a self,
+        address: &'a ErdrsAddress,
+        key: &'a [u8],
+    ) -> BoxFuture<'a, GatewayResult<String>> {
+        Box::pin(CommunicationProxy::get_account_storage_value(
+            self, address, key,
+        ))
+    }
+
+    fn get_account_esdt_token<'a>(
+        &'a self,
+        address: &'a ErdrsAddress,
+        token_identifier: &'a str,
+        nonce: u64,
+    ) -> BoxFuture<'a, GatewayResult<EsdtTokenData>> {
+        Box::pin(CommunicationProxy::get_account_esdt_token(
+            self,
+            address,
+            token_identifier,
+            nonce,
+        ))
+    }
+
+    fn get_account_esdt_roles<'a>(
+        &'a self,
+        address: &'a ErdrsAddress,
+    ) -> BoxFuture<'a, GatewayResult<HashMap<String, Vec<String>>>> {
+        Box::pin(CommunicationProxy::get_account_esdt_roles(self, address))
+    }
+
+    fn get_esdt_token_supply<'a>(
+        &'a self,
+        token_identifier: &'a str,
+    ) -> BoxFuture<'a, GatewayResult<EsdtTokenSupply>> {
+        Box::pin(CommunicationProxy::get_esdt_token_supply(
+            self,
+            token_identifier,
+        ))
+    }
+
+    fn get_latest_hyper_block_nonce(
+        &self,
+        with_metachain: bool,
+    ) -> BoxFuture<'_, GatewayResult<u64>> {
+        Box::pin(CommunicationProxy::get_latest_hyper_block_nonce(
+            self,
+            with_metachain,
+        ))
+    }
+
+    fn get_hyper_block_by_nonce(&self, nonce: u64) -> BoxFuture<'_, GatewayResult<HyperBlock>> {
+        Box::pin(CommunicationProxy::get_hyper_block_by_nonce(self, nonce))
+    }
+
+    fn send_transaction<'a>(&'a self, tx: &'a Transaction) -> BoxFuture<'a, GatewayResult<String>> {
+        Box::pin(CommunicationProxy::send_transaction(self, tx))
+    }
+
+    fn simulate_transaction<'a>(
+        &'a self,
+        tx: &'a Transaction,
+    ) -> BoxFuture<'a, GatewayResult<SimulationResults>> {
+        Box::pin(CommunicationProxy::simulate_transaction(self, tx))
+    }
+
+    fn request_transaction_cost<'a>(
+        &'a self,
+        tx: &'a Transaction,
+    ) -> BoxFuture<'a, GatewayResult<TxCostResponseData>> {
+        Box::pin(CommunicationProxy::request_transaction_cost(self, tx))
+    }
+
+    fn get_transaction_info_with_results<'a>(
+        &'a self,
+        hash: &'a str,
+    ) -> BoxFuture<'a, GatewayResult<TransactionOnNetwork>> {
+        Box::pin(CommunicationProxy::get_transaction_info_with_results(
+            self, hash,
+        ))
+    }
+
+    fn execute_vmquery<'a>(
+        &'a self,
+        vm_request: &'a VmValueRequest,
+    ) -> BoxFuture<'a, GatewayResult<VmValuesResponseData>> {
+        Box::pin(CommunicationProxy::execute_vmquery(self, vm_request))
+    }
+}
@@ -1,4 +1,5 @@
 use multiversx_sc_scenario::{
+    bech32,
     mandos_system::{run_list::ScenarioRunnerList, run_trace::ScenarioTraceFile},
     multiversx_sc::types::Address,
     scenario_model::AddressValue,
@@ -10,14 +11,16 @@ use multiversx_sdk::{
 };
 use std::{collections::HashMap, path::Path, time::Duration};
 
-use crate::Sender;
+use crate::{EventDecoder, Faucet, GatewayProxy, Sender};
 
 pub const INTERACTOR_SCENARIO_TRACE_PATH: &str = "interactor_trace.scen.json";
 
 pub struct Interactor {
-    pub proxy: CommunicationProxy,
+    pub proxy: Box<dyn GatewayProxy>,
     pub network_config: NetworkConfig,
     pub sender_map: HashMap<Address, Sender>,
+    pub event_decoder: EventDecoder,
+    pub faucet: Option<Box<dyn Faucet>>,
 
     pub(crate) waiting_time_ms: u64,
     pub pre_runners: ScenarioRunnerList,
@@ -26,12 +29,21 @@ pub struct Interactor {
 
 impl Interactor {
     pub async fn new(gateway_url: &str) -> Self {
-        let proxy = CommunicationProxy::new(gateway_url.to_string());
+        Self::new_with_gateway(CommunicationProxy::new(gateway_url.to_string())).await
+    }
+
+    /// Creates an interactor that talks to the network through a custom gateway implementation.
+    pub async fn new_with_gateway<G>(proxy: G) -> Self
+    where
+        G: GatewayProxy + 'static,
+    {
         let network_config = proxy.get_network_config().await.unwrap();
         Self {
-            proxy,
+            proxy: Box::new(proxy),
             network_config,
             sender_map: HashMap::new(),
+            event_decoder: EventDecoder::default(),
+            faucet: None,
             waiting_time_ms: 0,
             pre_runners: ScenarioRunnerList::empty(),
             post_runners: ScenarioRunnerList::empty(),
@@ -51,11 +63,41 @@ impl Interactor {
         address
     }
 
+    /// Generates a disposable wallet, saves it as a PEM file, so it can be reused later, and registers it.
+    ///
+    /// Fresh wallets hold no EGLD, on public test networks they can be funded via `fund_wallet`.
+    pub fn register_generated_wallet(&mut self, pem_file_path: &str) -> Address {
+        let (wallet, _) = Wallet::generate();
+        wallet
+            .write_pem_file(pem_file_path)
+            .unwrap_or_else(|err| panic!("failed to write {pem_file_path}: {err}"));
+        self.register_wallet(wallet)
+    }
+
     pub async fn sleep(&mut self, duration: Duration) {
         self.waiting_time_ms += duration.as_millis() as u64;
         tokio::time::sleep(duration).await;
     }
 
+    /// Replaces the network configuration retrieved from the gateway,
+    /// e.g. to use a custom chain id or minimum gas price, on a sovereign chain.
+    ///
+    /// The same config can be given to the mock VM, via `ScenarioWorld::network_config`.
+    pub fn with_network_config(mut self, network_config: NetworkConfig) -> Self {
+        self.network_config = network_config;
+        self
+    }
+
+    /// Sets the human-readable part of the bech32 addresses of the network, for chains that do not use `erd`,
+    /// e.g. sovereign chains.
+    ///
+    /// The setting is process-wide, shared by the SDK and the scenario framework:
+    /// it applies both to the addresses sent to the gateway and to the printed ones.
+    pub fn with_bech32_hrp(self, hrp: &str) -> Self {
+        bech32::set_hrp(hrp);
+        self
+    }
+
     pub async fn with_tracer<P: AsRef<Path>>(mut self, path: P) -> Self {
         self.post_runners.push(ScenarioTraceFile::new(path));
         self
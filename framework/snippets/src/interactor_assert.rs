@@ -0,0 +1,124 @@
+use crate::{address_h256_to_erdrs, Interactor};
+use multiversx_sc_scenario::{
+    bech32,
+    multiversx_chain_vm::display_util::verbose_hex,
+    multiversx_sc::types::Address,
+    num_bigint::BigUint,
+    scenario::run_vm::{pretty_storage_key, AccountDiff, CheckStateDiff},
+    scenario_model::{BigUintCheckValue, BytesKey, BytesValue, Checkable},
+};
+use std::str::FromStr;
+
+/// Token supplies are kept by the ESDT system smart contract.
+const ESDT_SYSTEM_SC_BECH32: &str =
+    "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u";
+
+/// Assertions against the live network state.
+///
+/// They mirror the `checkState` step of scenarios, and report mismatches the same way.
+impl Interactor {
+    /// Checks the EGLD balance of an account.
+    ///
+    /// Accepts the same expressions as scenario ESDT balance checks, including approximate values.
+    pub async fn assert_balance<V>(&self, address: &Address, expected: V)
+    where
+        BigUintCheckValue: From<V>,
+    {
+        let expected = BigUintCheckValue::from(expected);
+        let account = self
+            .proxy
+            .get_account(&address_h256_to_erdrs(address))
+            .await
+            .unwrap_or_else(|err| {
+                panic!(
+                    "failed to retrieve account {}: {err}",
+                    bech32::encode(address)
+                )
+            });
+        let actual = parse_biguint(&account.balance);
+
+        let mut account_diff = AccountDiff::new(bech32::encode(address));
+        if !expected.check(&actual) {
+            account_diff.push("balance", &expected, &actual);
+        }
+        assert_no_diff(account_diff);
+    }
+
+    /// Checks a single storage value of an account.
+    pub async fn assert_storage_value<K, V>(&self, address: &Address, key: K, expected: V)
+    where
+        BytesKey: From<K>,
+        BytesValue: From<V>,
+    {
+        let key = BytesKey::from(key);
+        let expected = BytesValue::from(expected);
+        let actual_hex = self
+            .proxy
+            .get_account_storage_value(&address_h256_to_erdrs(address), &key.value)
+            .await
+            .unwrap_or_else(|err| {
+                panic!(
+                    "failed to retrieve storage of account {}: {err}",
+                    bech32::encode(address)
+                )
+            });
+        let actual = hex::decode(actual_hex).expect("storage value is not valid hex");
+
+        let mut account_diff = AccountDiff::new(bech32::encode(address));
+        if !expected.check(&actual) {
+            account_diff.push(
+                format!("storage {}", pretty_storage_key(&key.value)),
+                &expected,
+                verbose_hex(&actual),
+            );
+        }
+        assert_no_diff(account_diff);
+    }
+
+    /// Checks the hash of the code deployed at an address,
+    /// e.g. against `code_hash(&contract_code("my-contract").value)` after an upgrade.
+    pub async fn assert_code_hash<V>(&self, address: &Address, expected: V)
+    where
+        BytesValue: From<V>,
+    {
+        let expected = BytesValue::from(expected);
+        let actual = self.get_code_hash(address).await;
+
+        let mut account_diff = AccountDiff::new(bech32::encode(address));
+        if !expected.check(&actual) {
+            account_diff.push("codeHash", &expected, verbose_hex(&actual));
+        }
+        assert_no_diff(account_diff);
+    }
+
+    /// Checks the current supply of a token.
+    pub async fn assert_token_supply<V>(&self, token_identifier: &str, expected: V)
+    where
+        BigUintCheckValue: From<V>,
+    {
+        let expected = BigUintCheckValue::from(expected);
+        let token_supply = self
+            .proxy
+            .get_esdt_token_supply(token_identifier)
+            .await
+            .unwrap_or_else(|err| panic!("failed to retrieve supply of {token_identifier}: {err}"));
+        let actual = parse_biguint(&token_supply.supply);
+
+        let esdt_system_sc_address = bech32::decode(ESDT_SYSTEM_SC_BECH32);
+        let mut account_diff = AccountDiff::new(bech32::encode(&esdt_system_sc_address));
+        if !expected.check(&actual) {
+            account_diff.push(format!("supply str:{token_identifier}"), &expected, &actual);
+        }
+        assert_no_diff(account_diff);
+    }
+}
+
+fn parse_biguint(s: &str) -> BigUint {
+    BigUint::from_str(s).unwrap_or_else(|_| panic!("invalid amount received from the network: {s}"))
+}
+
+fn assert_no_diff(account_diff: AccountDiff) {
+    let mut diff = CheckStateDiff::default();
+    diff.push_account(account_diff);
+    assert!(diff.is_empty(), "{diff}");
+}
@@ -0,0 +1,360 @@
+use crate::EventValue;
+use multiversx_sc_meta::{
+    abi_decode::{fmt_fields, generic_arg, split_type_args, AbiValueDecoder},
+    abi_json::type_descriptions_json,
+};
+use multiversx_sc_scenario::multiversx_sc::{
+    abi::{ContractAbi, EndpointAbi, EndpointTypeAbi, TypeAbi},
+    api::UPGRADE_CONTRACT_FUNC_NAME,
+    codec::TopEncodeMulti,
+    types::CodeMetadata,
+};
+use std::{fmt, iter::Peekable, slice};
+
+/// VM identifier in the deploy data, `code@0500@metadata@args`.
+const VM_TYPE_WASM: &str = "0500";
+
+/// Why call data could not be built or parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CallDataError {
+    EndpointNotFound {
+        contract: String,
+        function: String,
+    },
+    NotAConstructor {
+        function: String,
+    },
+    TooManyArguments {
+        function: String,
+        num_extra_args: usize,
+    },
+    MissingArgument {
+        function: String,
+        argument: String,
+    },
+    WrongArgumentType {
+        function: String,
+        argument: String,
+        expected: String,
+        actual: String,
+    },
+    EncodeError {
+        function: String,
+        argument: String,
+        message: String,
+    },
+    InvalidHex {
+        part: String,
+    },
+    InvalidDeployData,
+}
+
+impl fmt::Display for CallDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CallDataError::EndpointNotFound { contract, function } => {
+                write!(
+                    f,
+                    "endpoint `{function}` not found in the ABI of `{contract}`"
+                )
+            },
+            CallDataError::NotAConstructor { function } => {
+                write!(f, "`{function}` is not a constructor")
+            },
+            CallDataError::TooManyArguments {
+                function,
+                num_extra_args,
+            } => write!(
+                f,
+                "too many arguments for `{function}`, {num_extra_args} not in the ABI"
+            ),
+            CallDataError::MissingArgument { function, argument } => {
+                write!(f, "missing argument `{argument}` of `{function}`")
+            },
+            CallDataError::WrongArgumentType {
+                function,
+                argument,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "argument `{argument}` of `{function}` should be {expected}, got {actual}"
+            ),
+            CallDataError::EncodeError {
+                function,
+                argument,
+                message,
+            } => write!(
+                f,
+                "failed to encode argument `{argument}` of `{function}`: {message}"
+            ),
+            CallDataError::InvalidHex { part } => write!(f, "invalid hex argument `{part}`"),
+            CallDataError::InvalidDeployData => {
+                write!(
+                    f,
+                    "deploy data should be `code@{VM_TYPE_WASM}@metadata@args`"
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error for CallDataError {}
+
+/// Builds the data field of a contract call, `function@arg1@arg2`, from typed arguments.
+///
+/// The arguments are checked against the endpoint inputs in the ABI, in order.
+/// Useful when crafting raw transactions, e.g. for a multisig proposal or a hardware wallet.
+pub struct CallDataBuilder<'a> {
+    endpoint_abi: &'a EndpointAbi,
+    num_inputs: usize,
+    raw_args: Vec<Vec<u8>>,
+}
+
+impl<'a> CallDataBuilder<'a> {
+    /// The constructors are called `init` and `upgrade`, as in the ABI.
+    pub fn new(abi: &'a ContractAbi, function: &str) -> Result<Self, CallDataError> {
+        Ok(CallDataBuilder {
+            endpoint_abi: find_endpoint_abi(abi, function)?,
+            num_inputs: 0,
+            raw_args: Vec::new(),
+        })
+    }
+
+    /// Multi-values, e.g. `MultiValueEncoded`, are expanded into several arguments.
+    pub fn argument<T: TopEncodeMulti + TypeAbi>(
+        mut self,
+        value: &T,
+    ) -> Result<Self, CallDataError> {
+        let function = self.endpoint_abi.name;
+        let input_abi = self
+            .endpoint_abi
+            .inputs
+            .get(self.num_inputs)
+            .ok_or_else(|| CallDataError::TooManyArguments {
+                function: function.to_string(),
+                num_extra_args: 1,
+            })?;
+        let type_name = T::type_name();
+        if type_name != input_abi.type_name {
+            return Err(CallDataError::WrongArgumentType {
+                function: function.to_string(),
+                argument: input_abi.arg_name.to_string(),
+                expected: input_abi.type_name.clone(),
+                actual: type_name,
+            });
+        }
+
+        value
+            .multi_encode(&mut self.raw_args)
+            .map_err(|err| CallDataError::EncodeError {
+                function: function.to_string(),
+                argument: input_abi.arg_name.to_string(),
+                message: err.message_str().to_string(),
+            })?;
+        self.num_inputs += 1;
+        Ok(self)
+    }
+
+    /// Variadic and optional inputs at the end can be left out.
+    pub fn build(&self) -> Result<String, CallDataError> {
+        let mut result = self.endpoint_abi.name.to_string();
+        self.push_args(&mut result)?;
+        Ok(result)
+    }
+
+    /// The data of a deploy, `code@0500@metadata@args`, for `init`,
+    /// or of an upgrade, `upgradeContract@code@metadata@args`, for `upgrade`.
+    pub fn build_deploy(
+        &self,
+        code: &[u8],
+        code_metadata: CodeMetadata,
+    ) -> Result<String, CallDataError> {
+        let mut result = match self.endpoint_abi.endpoint_type {
+            EndpointTypeAbi::Init => format!("{}@{VM_TYPE_WASM}", hex::encode(code)),
+            EndpointTypeAbi::Upgrade => {
+                format!("{UPGRADE_CONTRACT_FUNC_NAME}@{}", hex::encode(code))
+            },
+            _ => {
+                return Err(CallDataError::NotAConstructor {
+                    function: self.endpoint_abi.name.to_string(),
+                })
+            },
+        };
+        result.push('@');
+        result.push_str(&hex::encode(code_metadata.to_byte_array()));
+        self.push_args(&mut result)?;
+        Ok(result)
+    }
+
+    fn push_args(&self, result: &mut String) -> Result<(), CallDataError> {
+        if let Some(missing_input) = self.endpoint_abi.inputs[self.num_inputs..]
+            .iter()
+            .find(|input_abi| !input_abi.multi_arg)
+        {
+            return Err(CallDataError::MissingArgument {
+                function: self.endpoint_abi.name.to_string(),
+                argument: missing_input.arg_name.to_string(),
+            });
+        }
+
+        for raw_arg in &self.raw_args {
+            result.push('@');
+            result.push_str(&hex::encode(raw_arg));
+        }
+        Ok(())
+    }
+}
+
+/// The data field of a contract call, `function@arg1@arg2`, with the arguments decoded based on the ABI.
+///
+/// Variadic inputs are decoded as lists, optional inputs as options and multi-values as tuples.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsedCallData {
+    pub function: String,
+    pub arguments: Vec<(String, EventValue)>,
+}
+
+impl ParsedCallData {
+    /// Upgrades, `upgradeContract@code@metadata@args`, are parsed as calls to `upgrade`.
+    pub fn parse(abi: &ContractAbi, data: &str) -> Result<Self, CallDataError> {
+        let mut parts = data.split('@');
+        let function = parts.next().unwrap_or_default();
+        if function == UPGRADE_CONTRACT_FUNC_NAME {
+            // skipping the code and the code metadata
+            let raw_args = parts.skip(2).map(decode_hex).collect::<Result<_, _>>()?;
+            return Self::decode_arguments(abi, "upgrade", raw_args);
+        }
+
+        let raw_args = parts.map(decode_hex).collect::<Result<_, _>>()?;
+        Self::decode_arguments(abi, function, raw_args)
+    }
+
+    /// Parses the data of a deploy, `code@0500@metadata@args`, as a call to `init`.
+    pub fn parse_deploy(abi: &ContractAbi, data: &str) -> Result<Self, CallDataError> {
+        let mut parts = data.split('@');
+        if !matches!(
+            (parts.next(), parts.next(), parts.next()),
+            (Some(_code), Some(VM_TYPE_WASM), Some(_code_metadata))
+        ) {
+            return Err(CallDataError::InvalidDeployData);
+        }
+
+        let raw_args = parts.map(decode_hex).collect::<Result<_, _>>()?;
+        Self::decode_arguments(abi, "init", raw_args)
+    }
+
+    fn decode_arguments(
+        abi: &ContractAbi,
+        function: &str,
+        raw_args: Vec<Vec<u8>>,
+    ) -> Result<Self, CallDataError> {
+        let endpoint_abi = find_endpoint_abi(abi, function)?;
+        let types = type_descriptions_json(&abi.type_descriptions);
+        let value_decoder = AbiValueDecoder::new(&types);
+        let mut raw_args_iter = raw_args.iter().peekable();
+        let arguments = endpoint_abi
+            .inputs
+            .iter()
+            .map(|input_abi| {
+                let value = decode_input(&value_decoder, &input_abi.type_name, &mut raw_args_iter)
+                    .ok_or_else(|| CallDataError::MissingArgument {
+                        function: function.to_string(),
+                        argument: input_abi.arg_name.to_string(),
+                    })?;
+                Ok((input_abi.arg_name.to_string(), value))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let num_extra_args = raw_args_iter.count();
+        if num_extra_args > 0 {
+            return Err(CallDataError::TooManyArguments {
+                function: function.to_string(),
+                num_extra_args,
+            });
+        }
+
+        Ok(ParsedCallData {
+            function: function.to_string(),
+            arguments,
+        })
+    }
+
+    pub fn argument(&self, name: &str) -> Option<&EventValue> {
+        self.arguments
+            .iter()
+            .find(|(arg_name, _)| arg_name == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Prints e.g. `propose { title: "abc", amount: 1000 }`.
+impl fmt::Display for ParsedCallData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.function)?;
+        fmt_fields(f, &self.arguments)
+    }
+}
+
+/// Also finds the constructors, `init` and `upgrade`, and the promise callbacks.
+fn find_endpoint_abi<'a>(
+    abi: &'a ContractAbi,
+    function: &str,
+) -> Result<&'a EndpointAbi, CallDataError> {
+    abi.iter_all_exports()
+        .find(|endpoint_abi| endpoint_abi.name == function)
+        .ok_or_else(|| CallDataError::EndpointNotFound {
+            contract: abi.name.to_string(),
+            function: function.to_string(),
+        })
+}
+
+fn decode_hex(part: &str) -> Result<Vec<u8>, CallDataError> {
+    hex::decode(part).map_err(|_| CallDataError::InvalidHex {
+        part: part.to_string(),
+    })
+}
+
+/// Consumes the raw arguments of one input. Yields `None` if they are missing.
+fn decode_input(
+    value_decoder: &AbiValueDecoder,
+    type_name: &str,
+    raw_args: &mut Peekable<slice::Iter<Vec<u8>>>,
+) -> Option<EventValue> {
+    if let Some(item_type) = generic_arg(type_name, "variadic") {
+        let mut items = Vec::new();
+        while raw_args.peek().is_some() {
+            items.push(decode_input(value_decoder, item_type, raw_args)?);
+        }
+        Some(EventValue::List(items))
+    } else if let Some(item_type) = generic_arg(type_name, "counted-variadic") {
+        let count = raw_args
+            .next()?
+            .iter()
+            .fold(0usize, |count, byte| (count << 8) | *byte as usize);
+        let items = (0..count)
+            .map(|_| decode_input(value_decoder, item_type, raw_args))
+            .collect::<Option<Vec<_>>>()?;
+        Some(EventValue::List(items))
+    } else if let Some(inner_type) = generic_arg(type_name, "optional") {
+        if raw_args.peek().is_none() {
+            return Some(EventValue::Option(None));
+        }
+        let inner = decode_input(value_decoder, inner_type, raw_args)?;
+        Some(EventValue::Option(Some(Box::new(inner))))
+    } else if let Some(item_types) = generic_arg(type_name, "multi") {
+        let items = split_type_args(item_types)
+            .into_iter()
+            .map(|item_type| decode_input(value_decoder, item_type, raw_args))
+            .collect::<Option<Vec<_>>>()?;
+        Some(EventValue::Tuple(items))
+    } else if type_name == "ignore" {
+        Some(EventValue::List(
+            raw_args
+                .map(|raw_arg| EventValue::Raw(raw_arg.clone()))
+                .collect(),
+        ))
+    } else {
+        Some(value_decoder.top_decode(type_name, raw_args.next()?))
+    }
+}
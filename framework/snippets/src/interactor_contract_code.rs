@@ -0,0 +1,227 @@
+use crate::{address_h256_to_erdrs, Interactor};
+use multiversx_sc_meta::CargoTomlContents;
+use multiversx_sc_scenario::{
+    bech32,
+    multiversx_chain_vm::crypto_functions::blake2b_256,
+    multiversx_sc::types::{Address, CodeMetadata},
+    scenario_format::interpret_trait::{InterpretableFrom, InterpreterContext},
+    scenario_model::BytesValue,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::SystemTime,
+};
+
+const CARGO_TOML_FILE_NAME: &str = "Cargo.toml";
+const OUTPUT_DIR_NAME: &str = "output";
+const META_DIR_NAME: &str = "meta";
+const IGNORED_DIR_NAMES: &[&str] = &["target", "output"];
+
+/// Retrieves the compiled code of a contract, given the name of its crate, e.g. `"multisig"`.
+///
+/// The contract crate is searched for in the workspace of the current directory,
+/// and the code is read from its `output` directory.
+/// If the contract was not built yet, or any of its files changed since the last build,
+/// it is built first, with `sc-meta`.
+pub fn contract_code(contract_crate_name: &str) -> BytesValue {
+    let contract_crate_path = find_contract_crate(contract_crate_name).unwrap_or_else(|| {
+        panic!("contract crate `{contract_crate_name}` not found in the current workspace")
+    });
+
+    let wasm_path = contract_crate_path
+        .join(OUTPUT_DIR_NAME)
+        .join(format!("{contract_crate_name}.wasm"));
+    if is_build_outdated(&contract_crate_path, &wasm_path) {
+        build_contract(&contract_crate_path);
+        assert!(
+            wasm_path.is_file(),
+            "contract code not found at {} after build",
+            wasm_path.display()
+        );
+    }
+
+    BytesValue::interpret_from(
+        format!("file:{}", wasm_path.display()),
+        &InterpreterContext::default(),
+    )
+}
+
+/// The code hash, as computed by the protocol: the blake2b-256 hash of the contract code.
+pub fn code_hash(code: &[u8]) -> [u8; 32] {
+    blake2b_256(code)
+}
+
+fn find_contract_crate(contract_crate_name: &str) -> Option<PathBuf> {
+    let current_dir = std::env::current_dir().expect("failed to retrieve current directory");
+    find_crate_in_dir(&workspace_root(&current_dir), contract_crate_name)
+}
+
+/// The outermost ancestor that is a Cargo workspace, or the given path, if there is none.
+fn workspace_root(path: &Path) -> PathBuf {
+    path.ancestors()
+        .filter(|ancestor| {
+            let cargo_toml_path = ancestor.join(CARGO_TOML_FILE_NAME);
+            cargo_toml_path.is_file()
+                && CargoTomlContents::load_from_file(cargo_toml_path)
+                    .toml_value
+                    .get("workspace")
+                    .is_some()
+        })
+        .last()
+        .unwrap_or(path)
+        .to_path_buf()
+}
+
+/// Contract crates are recognized by having a `meta` crate next to their `Cargo.toml`.
+fn find_crate_in_dir(dir_path: &Path, contract_crate_name: &str) -> Option<PathBuf> {
+    let cargo_toml_path = dir_path.join(CARGO_TOML_FILE_NAME);
+    if cargo_toml_path.is_file() && dir_path.join(META_DIR_NAME).is_dir() {
+        let cargo_toml_contents = CargoTomlContents::load_from_file(cargo_toml_path);
+        if cargo_toml_contents.toml_value.get("package").is_some()
+            && cargo_toml_contents.package_name() == contract_crate_name
+        {
+            return Some(dir_path.to_path_buf());
+        }
+    }
+
+    let read_dir = fs::read_dir(dir_path).ok()?;
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        if !path.is_dir()
+            || file_name.starts_with('.')
+            || IGNORED_DIR_NAMES.contains(&file_name.as_ref())
+        {
+            continue;
+        }
+
+        if let Some(found) = find_crate_in_dir(&path, contract_crate_name) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+/// The contract needs to be (re)built if the wasm is missing,
+/// or if any file of the contract crate was modified after it.
+///
+/// Only the files of the contract crate itself are considered, not those of its path dependencies.
+fn is_build_outdated(contract_crate_path: &Path, wasm_path: &Path) -> bool {
+    let wasm_modified = match fs::metadata(wasm_path).and_then(|metadata| metadata.modified()) {
+        Ok(wasm_modified) => wasm_modified,
+        Err(_) => return true,
+    };
+    latest_modification_in_dir(contract_crate_path)
+        .map(|source_modified| source_modified > wasm_modified)
+        .unwrap_or(false)
+}
+
+fn latest_modification_in_dir(dir_path: &Path) -> Option<SystemTime> {
+    let mut latest = None;
+    for entry in fs::read_dir(dir_path).ok()?.flatten() {
+        let path = entry.path();
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        if file_name.starts_with('.') || IGNORED_DIR_NAMES.contains(&file_name.as_ref()) {
+            continue;
+        }
+
+        let modified = if path.is_dir() {
+            latest_modification_in_dir(&path)
+        } else {
+            entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        };
+        latest = latest.max(modified);
+    }
+    latest
+}
+
+fn build_contract(contract_crate_path: &Path) {
+    let meta_path = contract_crate_path.join(META_DIR_NAME);
+    println!("building contract in {} ...", meta_path.display());
+
+    let exit_status = Command::new("cargo")
+        .current_dir(&meta_path)
+        .args(["run", "build"])
+        .spawn()
+        .expect("failed to spawn cargo run process in meta crate")
+        .wait()
+        .expect("cargo run process in meta crate was not running");
+
+    assert!(exit_status.success(), "contract build failed");
+}
+
+impl Interactor {
+    /// Retrieves the hash of the code deployed at the given address.
+    ///
+    /// Empty if the account is not a contract.
+    pub async fn get_code_hash(&self, address: &Address) -> Vec<u8> {
+        let account = self
+            .proxy
+            .get_account(&address_h256_to_erdrs(address))
+            .await
+            .unwrap_or_else(|err| {
+                panic!(
+                    "failed to retrieve account {}: {err}",
+                    bech32::encode(address)
+                )
+            });
+        account
+            .code_hash
+            .map(|code_hash| base64::decode(code_hash).expect("failed to decode code hash"))
+            .unwrap_or_default()
+    }
+
+    /// Retrieves the code metadata of a contract from the blockchain.
+    pub async fn get_code_metadata(&self, address: &Address) -> CodeMetadata {
+        let account = self
+            .proxy
+            .get_account(&address_h256_to_erdrs(address))
+            .await
+            .unwrap_or_else(|err| {
+                panic!(
+                    "failed to retrieve account {}: {err}",
+                    bech32::encode(address)
+                )
+            });
+        let code_metadata = account
+            .code_metadata
+            .map(|code_metadata| {
+                base64::decode(code_metadata).expect("failed to decode code metadata")
+            })
+            .unwrap_or_default();
+        let code_metadata: [u8; 2] = code_metadata.as_slice().try_into().unwrap_or_else(|_| {
+            panic!(
+                "account {} has no valid code metadata",
+                bech32::encode(address)
+            )
+        });
+        CodeMetadata::from(code_metadata)
+    }
+
+    /// Checks that the code deployed at the given address is the expected one.
+    ///
+    /// Skipped if the gateway does not provide the code hash.
+    pub(crate) async fn verify_deployed_code(&self, address: &Address, code: &[u8]) {
+        let actual_code_hash = self.get_code_hash(address).await;
+        if actual_code_hash.is_empty() {
+            return;
+        }
+
+        let expected_code_hash = code_hash(code);
+        assert!(
+            actual_code_hash == expected_code_hash,
+            "code hash mismatch for {}: expected 0x{}, got 0x{}",
+            bech32::encode(address),
+            hex::encode(expected_code_hash),
+            hex::encode(actual_code_hash),
+        );
+    }
+}
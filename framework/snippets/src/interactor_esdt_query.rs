@@ -0,0 +1,59 @@
+use crate::{address_h256_to_erdrs, Interactor};
+use multiversx_sc_scenario::{
+    bech32,
+    multiversx_sc::types::{Address, EsdtLocalRole},
+    num_bigint::BigUint,
+};
+use std::str::FromStr;
+
+/// Queries of the ESDT state of any account, commonly needed in system tests.
+impl Interactor {
+    /// Retrieves the balance of a token of an account. Nonce 0 means a fungible token.
+    pub async fn get_esdt_balance(
+        &self,
+        address: &Address,
+        token_identifier: &str,
+        nonce: u64,
+    ) -> BigUint {
+        let token_data = self
+            .proxy
+            .get_account_esdt_token(&address_h256_to_erdrs(address), token_identifier, nonce)
+            .await
+            .unwrap_or_else(|err| {
+                panic!(
+                    "failed to retrieve {token_identifier} balance of account {}: {err}",
+                    bech32::encode(address)
+                )
+            });
+        BigUint::from_str(&token_data.balance).unwrap_or_else(|_| {
+            panic!(
+                "invalid amount received from the network: {}",
+                token_data.balance
+            )
+        })
+    }
+
+    /// Retrieves the roles an account has for a token. Empty if it has none.
+    pub async fn get_esdt_roles(
+        &self,
+        address: &Address,
+        token_identifier: &str,
+    ) -> Vec<EsdtLocalRole> {
+        let mut all_roles = self
+            .proxy
+            .get_account_esdt_roles(&address_h256_to_erdrs(address))
+            .await
+            .unwrap_or_else(|err| {
+                panic!(
+                    "failed to retrieve ESDT roles of account {}: {err}",
+                    bech32::encode(address)
+                )
+            });
+        all_roles
+            .remove(token_identifier)
+            .unwrap_or_default()
+            .iter()
+            .map(|role_name| EsdtLocalRole::from(role_name.as_bytes()))
+            .collect()
+    }
+}
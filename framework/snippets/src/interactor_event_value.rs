@@ -0,0 +1,6 @@
+pub use multiversx_sc_meta::abi_decode::AbiValue;
+
+/// A value decoded from an event topic, from the event data or from call data, based on its ABI type.
+///
+/// The decoding is shared with `sc-meta`, see `AbiValueDecoder`.
+pub type EventValue = AbiValue;
@@ -0,0 +1,118 @@
+use crate::{EventValue, Interactor};
+use multiversx_sc_meta::{
+    abi_decode::{fmt_fields, AbiValueDecoder},
+    abi_json::type_descriptions_json,
+};
+use multiversx_sc_scenario::{
+    multiversx_sc::abi::{ContractAbi, EventAbi},
+    scenario_model::{BytesValue, Log},
+};
+use std::fmt;
+
+/// An event log, decoded based on the event section of a contract ABI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedEvent {
+    pub identifier: String,
+    pub fields: Vec<(String, EventValue)>,
+}
+
+impl DecodedEvent {
+    pub fn field(&self, name: &str) -> Option<&EventValue> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Prints `"proposalCreated"` with field `id` as `ProposalCreated { id: 5 }`.
+impl fmt::Display for DecodedEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut capitalize_next = true;
+        for c in self.identifier.chars() {
+            if !c.is_ascii_alphanumeric() {
+                capitalize_next = true;
+            } else if capitalize_next {
+                write!(f, "{}", c.to_ascii_uppercase())?;
+                capitalize_next = false;
+            } else {
+                write!(f, "{c}")?;
+            }
+        }
+        fmt_fields(f, &self.fields)
+    }
+}
+
+/// Holds the events of the contracts the interactor talks to.
+#[derive(Default)]
+pub struct EventDecoder {
+    pub contract_abis: Vec<ContractAbi>,
+}
+
+impl EventDecoder {
+    pub fn add_contract_abi(&mut self, abi: ContractAbi) {
+        self.contract_abis.push(abi);
+    }
+
+    /// Decodes the logs that correspond to known events, in order. The other logs are skipped.
+    pub fn decode_logs(&self, logs: &[Log]) -> Vec<DecodedEvent> {
+        logs.iter().filter_map(|log| self.decode_log(log)).collect()
+    }
+
+    pub fn decode_log(&self, log: &Log) -> Option<DecodedEvent> {
+        let (identifier, topics) = log.topics.split_first()?;
+        self.contract_abis.iter().find_map(|abi| {
+            let event_abi = abi
+                .events
+                .iter()
+                .find(|event_abi| event_abi.identifier.as_bytes() == identifier.value)?;
+            let types = type_descriptions_json(&abi.type_descriptions);
+            decode_event(
+                &AbiValueDecoder::new(&types),
+                event_abi,
+                topics,
+                &log.data.value,
+            )
+        })
+    }
+}
+
+fn decode_event(
+    value_decoder: &AbiValueDecoder,
+    event_abi: &EventAbi,
+    topics: &[BytesValue],
+    data: &[u8],
+) -> Option<DecodedEvent> {
+    let mut topics_iter = topics.iter();
+    let mut fields = Vec::new();
+    for input in event_abi.inputs.iter().filter(|input| input.indexed) {
+        let topic = topics_iter.next()?;
+        let value = value_decoder.top_decode(&input.type_name, &topic.value);
+        fields.push((input.arg_name.to_string(), value));
+    }
+    for input in event_abi.inputs.iter().filter(|input| !input.indexed) {
+        let value = value_decoder.top_decode(&input.type_name, data);
+        fields.push((input.arg_name.to_string(), value));
+    }
+
+    Some(DecodedEvent {
+        identifier: event_abi.identifier.to_string(),
+        fields,
+    })
+}
+
+impl Interactor {
+    /// Registers the ABI of a contract, so that its events get decoded and printed after each call.
+    ///
+    /// The ABI is usually obtained via `<my_contract::AbiProvider as ContractAbiProvider>::abi()`.
+    pub fn with_contract_abi(mut self, abi: ContractAbi) -> Self {
+        self.event_decoder.add_contract_abi(abi);
+        self
+    }
+
+    pub(crate) fn print_decoded_events(&self, logs: &[Log]) {
+        for event in self.event_decoder.decode_logs(logs) {
+            println!("event: {event}");
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{multiversx_sc::types::Address, Interactor, Sender};
 use futures::future::join_all;
 use multiversx_sdk::data::transaction::{Transaction, TransactionOnNetwork};
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 pub(crate) type Txs = Vec<Transaction>;
 pub(crate) type SenderSet = HashSet<Address>;
@@ -21,17 +24,25 @@ impl Interactor {
     }
 
     pub(crate) async fn process_txs(&mut self, txs: Vec<Transaction>) -> Vec<TransactionOnNetwork> {
+        let interactor = &*self;
+        let num_txs = txs.len();
+        let num_processed = &AtomicUsize::new(0);
         let mut futures = Vec::new();
 
         for tx in &txs {
-            let tx_hash = self
+            let tx_hash = interactor
                 .proxy
                 .send_transaction(tx)
                 .await
                 .expect("failed to send transaction");
 
             println!("process tx hash: {tx_hash} with nonce: {}", tx.nonce);
-            futures.push(self.retrieve_tx_on_network(tx_hash.clone()));
+            futures.push(async move {
+                let tx_on_network = interactor.retrieve_tx_on_network(tx_hash.clone()).await;
+                let num_processed = num_processed.fetch_add(1, Ordering::Relaxed) + 1;
+                println!("processed tx hash: {tx_hash} ({num_processed}/{num_txs})");
+                tx_on_network
+            });
         }
 
         join_all(futures).await
@@ -1,8 +1,6 @@
 use crate::{address_h256_to_erdrs, mandos_to_erdrs_address, Interactor};
 use log::info;
 use multiversx_sc_scenario::{
-    api::StaticApi,
-    multiversx_sc::types::ContractCallWithEgld,
     scenario::ScenarioRunner,
     scenario_model::{ScCallStep, SetStateStep, TxCall, TxResponse},
 };
@@ -18,6 +16,7 @@ impl Interactor {
         let tx = self.retrieve_tx_on_network(tx_hash.clone()).await;
 
         sc_call_step.save_response(TxResponse::from_network_tx(tx));
+        self.print_decoded_events(&sc_call_step.response().logs);
 
         if let Some(token_identifier) = sc_call_step.response().new_issued_token_identifier.clone()
         {
@@ -47,7 +46,7 @@ impl Interactor {
 
     pub(crate) fn tx_call_to_blockchain_tx(&self, tx_call: &TxCall) -> Transaction {
         let contract_call = tx_call.to_contract_call();
-        let contract_call_tx_data = contract_call_to_tx_data(&contract_call);
+        let contract_call_tx_data = tx_call.to_tx_data();
         let data = if contract_call_tx_data.is_empty() {
             None
         } else {
@@ -69,19 +68,3 @@ impl Interactor {
         }
     }
 }
-
-fn contract_call_to_tx_data(contract_call: &ContractCallWithEgld<StaticApi, ()>) -> String {
-    let mut result = String::from_utf8(
-        contract_call
-            .basic
-            .endpoint_name
-            .to_boxed_bytes()
-            .into_vec(),
-    )
-    .unwrap();
-    for argument in contract_call.basic.arg_buffer.raw_arg_iter() {
-        result.push('@');
-        result.push_str(hex::encode(argument.to_boxed_bytes().as_slice()).as_str());
-    }
-    result
-}
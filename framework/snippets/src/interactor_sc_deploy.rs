@@ -69,6 +69,8 @@ impl Interactor {
         );
 
         println!("deploy address: {}", bech32::encode(&deploy_address));
+        self.verify_deployed_code(&deploy_address, &sc_deploy_step.tx.contract_code.value)
+            .await;
         self.pre_runners.run_set_state_step(&set_state_step);
         self.post_runners.run_set_state_step(&set_state_step);
 
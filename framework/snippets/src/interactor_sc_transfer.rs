@@ -1,10 +1,18 @@
 use crate::Interactor;
 use log::info;
-use multiversx_sc_scenario::{scenario::ScenarioRunner, scenario_model::TransferStep};
+use multiversx_sc_scenario::{
+    scenario::ScenarioRunner,
+    scenario_model::{TransferStep, TxResponse},
+};
 
 impl Interactor {
-    pub async fn transfer(&mut self, transfer_step: TransferStep) -> String {
-        self.pre_runners.run_transfer_step(&transfer_step);
+    pub async fn transfer(&mut self, mut transfer_step: TransferStep) -> String {
+        self.perform_transfer(&mut transfer_step).await
+    }
+
+    /// Sends the transfer, then saves the transaction result in the step's response field.
+    pub async fn perform_transfer(&mut self, transfer_step: &mut TransferStep) -> String {
+        self.pre_runners.run_transfer_step(transfer_step);
 
         let sender_address = &transfer_step.tx.from.value;
         let mut transaction = self.tx_call_to_blockchain_tx(&transfer_step.tx.to_tx_call());
@@ -14,9 +22,10 @@ impl Interactor {
         println!("transfer tx hash: {tx_hash}");
         info!("transfer tx hash: {}", tx_hash);
 
-        self.retrieve_tx_on_network(tx_hash.clone()).await;
+        let tx = self.retrieve_tx_on_network(tx_hash.clone()).await;
+        transfer_step.save_response(TxResponse::from_network_tx(tx));
 
-        self.post_runners.run_transfer_step(&transfer_step);
+        self.post_runners.run_transfer_step(transfer_step);
 
         tx_hash
     }
@@ -22,6 +22,17 @@ impl Interactor {
         account.nonce
     }
 
+    /// Signs a message with the wallet of a registered sender.
+    ///
+    /// Used to produce off-chain approvals, e.g. permits, that contracts verify on-chain.
+    pub fn sign_message(&self, signer_address: &Address, message: &[u8]) -> [u8; 64] {
+        self.sender_map
+            .get(signer_address)
+            .expect("the wallet that was supposed to sign is not registered")
+            .wallet
+            .sign_message(message)
+    }
+
     pub(crate) async fn set_nonce_and_sign_tx(
         &mut self,
         sender_address: &Address,
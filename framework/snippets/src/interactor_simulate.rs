@@ -0,0 +1,90 @@
+use crate::{erdrs_address_to_h256, Interactor};
+use multiversx_sc_scenario::scenario_model::{
+    ScCallStep, ScDeployStep, TxResponse, TxResponseStatus,
+};
+use multiversx_sdk::data::transaction::{SimulationResults, Transaction};
+
+/// The status reported by the API for successful simulations.
+const SIMULATION_STATUS_SUCCESS: &str = "success";
+
+impl Interactor {
+    /// Executes a call on the network, without broadcasting it.
+    ///
+    /// The response contains the status, the returned values, the logs and the gas used,
+    /// just like after a real call, but nothing gets written on the blockchain.
+    /// Useful for pre-flight checks, before sending the actual transaction.
+    pub async fn simulate<S>(&self, mut sc_call_step: S) -> TxResponse
+    where
+        S: AsMut<ScCallStep>,
+    {
+        let transaction = self.tx_call_to_blockchain_tx(&sc_call_step.as_mut().tx);
+        self.simulate_tx(transaction).await
+    }
+
+    /// Executes a deploy on the network, without broadcasting it.
+    pub async fn simulate_deploy<S>(&self, mut sc_deploy_step: S) -> TxResponse
+    where
+        S: AsMut<ScDeployStep>,
+    {
+        let transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step.as_mut());
+        self.simulate_tx(transaction).await
+    }
+
+    async fn simulate_tx(&self, mut transaction: Transaction) -> TxResponse {
+        let sender_address = erdrs_address_to_h256(transaction.sender.clone());
+        transaction.nonce = self.recall_nonce(&sender_address).await;
+        if let Some(sender) = self.sender_map.get(&sender_address) {
+            transaction.signature = Some(hex::encode(sender.wallet.sign_tx(&transaction)));
+        }
+
+        let simulation_results = self
+            .proxy
+            .simulate_transaction(&transaction)
+            .await
+            .unwrap_or_else(|err| panic!("failed to simulate transaction: {err}"));
+        let mut response = simulation_response(simulation_results);
+
+        if response.is_success() {
+            let tx_cost = self
+                .proxy
+                .request_transaction_cost(&transaction)
+                .await
+                .unwrap_or_else(|err| panic!("failed to estimate transaction cost: {err}"));
+            response.gas = tx_cost.tx_gas_units;
+        }
+
+        response
+    }
+}
+
+/// Cross-shard calls are executed on the receiver shard,
+/// so its results are the relevant ones, unless the transaction fails on the sender shard.
+fn simulation_response(simulation_results: SimulationResults) -> TxResponse {
+    let SimulationResults {
+        status,
+        fail_reason,
+        sc_results,
+        logs,
+        sender_shard,
+        receiver_shard,
+        ..
+    } = simulation_results;
+
+    if let Some(sender_shard) = sender_shard {
+        if sender_shard.status != SIMULATION_STATUS_SUCCESS {
+            return simulation_response(*sender_shard);
+        }
+    }
+    if let Some(receiver_shard) = receiver_shard {
+        return simulation_response(*receiver_shard);
+    }
+
+    let mut response = TxResponse::from_api_results(sc_results, logs);
+    if response.is_success() && status != SIMULATION_STATUS_SUCCESS {
+        response.tx_error = TxResponseStatus {
+            status: 4,
+            message: fail_reason,
+        };
+    }
+    response
+}
@@ -0,0 +1,34 @@
+use crate::Interactor;
+use multiversx_sc_scenario::{
+    multiversx_sc::types::Address,
+    scenario::{AsyncStepExecutor, CodeMetadataFuture, StepExecutor, StepFuture},
+    scenario_model::{ScCallStep, ScDeployStep, ScQueryStep, TransferStep},
+};
+
+impl StepExecutor for Interactor {
+    fn execute_sc_call<'a>(&'a mut self, step: &'a mut ScCallStep) -> StepFuture<'a> {
+        Box::pin(self.sc_call(step))
+    }
+
+    fn execute_sc_deploy<'a>(&'a mut self, step: &'a mut ScDeployStep) -> StepFuture<'a> {
+        Box::pin(self.sc_deploy(step))
+    }
+
+    fn execute_sc_query<'a>(&'a mut self, step: &'a mut ScQueryStep) -> StepFuture<'a> {
+        Box::pin(async move {
+            self.perform_sc_query(step).await;
+        })
+    }
+
+    fn execute_transfer<'a>(&'a mut self, step: &'a mut TransferStep) -> StepFuture<'a> {
+        Box::pin(async move {
+            self.perform_transfer(step).await;
+        })
+    }
+}
+
+impl AsyncStepExecutor for Interactor {
+    fn code_metadata<'a>(&'a mut self, address: &'a Address) -> CodeMetadataFuture<'a> {
+        Box::pin(self.get_code_metadata(address))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::Interactor;
+use multiversx_sc_scenario::{
+    multiversx_sc::types::Address,
+    scenario_model::{BigUintValue, BytesValue, TransferStep, TxESDT, U64Value},
+};
+
+/// Gas limit for fungible ESDT transfers between wallets.
+pub const ESDT_TRANSFER_GAS_LIMIT: u64 = 500_000;
+
+/// Gas limit for single NFT/SFT transfers between wallets.
+pub const NFT_TRANSFER_GAS_LIMIT: u64 = 1_000_000;
+
+/// Gas limit for multi-transfers, per transferred token.
+pub const MULTI_TRANSFER_GAS_LIMIT_PER_TOKEN: u64 = 1_100_000;
+
+impl Interactor {
+    /// Sends fungible ESDT tokens from a registered wallet to any address.
+    ///
+    /// Returns the transaction hash.
+    pub async fn esdt_transfer<T, A>(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        token_id: T,
+        amount: A,
+    ) -> String
+    where
+        BytesValue: From<T>,
+        BigUintValue: From<A>,
+    {
+        self.transfer(
+            TransferStep::new()
+                .from(from)
+                .to(to)
+                .esdt_transfer(token_id, 0u64, amount)
+                .gas_limit(ESDT_TRANSFER_GAS_LIMIT),
+        )
+        .await
+    }
+
+    /// Sends an NFT, SFT or MetaESDT from a registered wallet to any address.
+    ///
+    /// Returns the transaction hash.
+    pub async fn nft_transfer<T, N, A>(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        token_id: T,
+        token_nonce: N,
+        amount: A,
+    ) -> String
+    where
+        BytesValue: From<T>,
+        U64Value: From<N>,
+        BigUintValue: From<A>,
+    {
+        self.transfer(
+            TransferStep::new()
+                .from(from)
+                .to(to)
+                .esdt_transfer(token_id, token_nonce, amount)
+                .gas_limit::<u64>(NFT_TRANSFER_GAS_LIMIT),
+        )
+        .await
+    }
+
+    /// Sends several tokens in a single multi-transfer, from a registered wallet to any address.
+    ///
+    /// Returns the transaction hash.
+    pub async fn multi_esdt_transfer<I>(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        payments: I,
+    ) -> String
+    where
+        I: IntoIterator<Item = TxESDT>,
+    {
+        let payments: Vec<TxESDT> = payments.into_iter().collect();
+        let gas_limit = MULTI_TRANSFER_GAS_LIMIT_PER_TOKEN * payments.len() as u64;
+        self.transfer(
+            TransferStep::new()
+                .from(from)
+                .to(to)
+                .multi_esdt_transfer(payments)
+                .gas_limit(gas_limit),
+        )
+        .await
+    }
+}
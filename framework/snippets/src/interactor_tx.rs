@@ -0,0 +1,15 @@
+use crate::Interactor;
+use multiversx_sc_scenario::scenario::{ExecutorTx, ExecutorTxStep};
+
+impl Interactor {
+    /// Starts a transaction, in the same chained syntax as `ScenarioWorld::tx`.
+    pub fn tx(&mut self) -> InteractorTx<'_> {
+        ExecutorTx::new(self)
+    }
+}
+
+/// A `ScenarioTx` bound to an `Interactor`, so that it can be sent directly.
+pub type InteractorTx<'a> = ExecutorTx<'a, Interactor>;
+
+/// A typed step bound to an `Interactor`, ready to be sent.
+pub type InteractorTxStep<'a, S> = ExecutorTxStep<'a, Interactor, S>;
@@ -0,0 +1,51 @@
+use crate::{erdrs_address_to_h256, Interactor, StepBuffer};
+use multiversx_sc_scenario::multiversx_sc::types::Address;
+use multiversx_sdk::data::transaction::Transaction;
+use std::{collections::HashMap, fs, path::Path};
+
+impl Interactor {
+    /// Builds the transactions of the given steps, without signing or sending them.
+    ///
+    /// The senders do not need to be registered. Their nonces are recalled from the network,
+    /// and transactions from the same sender get consecutive nonces, in the order of the steps.
+    pub async fn unsigned_txs(&self, buffer: StepBuffer<'_>) -> Vec<Transaction> {
+        let mut next_nonces: HashMap<Address, u64> = HashMap::new();
+        let mut txs = Vec::new();
+
+        for step in buffer.refs.iter() {
+            let mut transaction = step.to_transaction(self);
+            let sender_address = erdrs_address_to_h256(transaction.sender.clone());
+            let nonce = match next_nonces.get(&sender_address) {
+                Some(nonce) => *nonce,
+                None => self.recall_nonce(&sender_address).await,
+            };
+            next_nonces.insert(sender_address, nonce + 1);
+
+            transaction.nonce = nonce;
+            transaction.signature = None;
+            txs.push(transaction);
+        }
+        txs
+    }
+
+    /// Writes the unsigned transactions of the given steps to a JSON file,
+    /// in the format accepted by the web wallet and xPortal for signing.
+    ///
+    /// Meant for flows prepared by a script, but signed offline, by a cold wallet.
+    pub async fn export_unsigned_txs<P: AsRef<Path>>(&self, buffer: StepBuffer<'_>, path: P) {
+        let txs = self.unsigned_txs(buffer).await;
+        let json = serde_json::to_string_pretty(&txs).expect("failed to serialize transactions");
+        fs::write(path.as_ref(), json).unwrap_or_else(|err| {
+            panic!(
+                "failed to write unsigned transactions to {}: {err}",
+                path.as_ref().display()
+            )
+        });
+
+        println!(
+            "exported {} unsigned transactions to {}",
+            txs.len(),
+            path.as_ref().display()
+        );
+    }
+}
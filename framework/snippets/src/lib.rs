@@ -1,5 +1,14 @@
+mod block_subscriber;
+mod faucet;
+mod gateway_proxy;
 mod interactor;
+mod interactor_assert;
+mod interactor_call_data;
+mod interactor_contract_code;
 mod interactor_dns;
+mod interactor_esdt_query;
+mod interactor_event_value;
+mod interactor_events;
 mod interactor_multi_sc_exec;
 mod interactor_multi_sc_process;
 mod interactor_retrieve;
@@ -8,19 +17,36 @@ mod interactor_sc_deploy;
 mod interactor_sc_extra;
 mod interactor_sc_transfer;
 mod interactor_sender;
+mod interactor_simulate;
+mod interactor_step_executor;
+mod interactor_token_transfer;
+mod interactor_tx;
 mod interactor_tx_spec;
+mod interactor_unsigned_tx;
 mod interactor_vm_query;
 mod step_buffer;
+mod system_sc_calls;
 
+pub use block_subscriber::*;
 pub use env_logger;
+pub use faucet::*;
+pub use futures;
+pub use gateway_proxy::*;
 pub use hex;
 pub use interactor::*;
+pub use interactor_call_data::*;
+pub use interactor_contract_code::{code_hash, contract_code};
 pub use interactor_dns::*;
+pub use interactor_event_value::*;
+pub use interactor_events::*;
 pub use interactor_sender::*;
+pub use interactor_token_transfer::*;
+pub use interactor_tx::*;
 pub use interactor_tx_spec::*;
 pub use log;
 pub use multiversx_sc_scenario::{self, multiversx_sc};
 pub use multiversx_sdk as erdrs; // TODO: remove
 pub use multiversx_sdk as sdk;
 pub use step_buffer::*;
+pub use system_sc_calls::*;
 pub use tokio;
@@ -0,0 +1,190 @@
+use multiversx_sc_scenario::{
+    multiversx_sc::types::Address,
+    scenario_model::{BigUintValue, BytesValue, ScCallStep},
+};
+
+/// Gas limit for creating a new delegation contract.
+pub const CREATE_DELEGATION_CONTRACT_GAS_LIMIT: u64 = 60_000_000;
+
+/// Gas limit for calls to a delegation contract that change the stake (delegate, unDelegate, withdraw, reDelegateRewards).
+pub const DELEGATION_GAS_LIMIT: u64 = 12_000_000;
+
+/// Gas limit for claiming rewards from a delegation contract.
+pub const CLAIM_REWARDS_GAS_LIMIT: u64 = 6_000_000;
+
+/// Base gas limit for calls to the staking (validator) system SC.
+pub const STAKING_BASE_GAS_LIMIT: u64 = 5_000_000;
+
+/// Additional gas limit for each node staked, unstaked or unbonded.
+pub const STAKING_GAS_LIMIT_PER_NODE: u64 = 6_000_000;
+
+/// Bech32: erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqplllst77y4l
+pub const STAKING_SC_ADDRESS_ARRAY: [u8; 32] = system_sc_address_array(0x01);
+
+/// Bech32: erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqylllslmq6y6
+pub const DELEGATION_MANAGER_SC_ADDRESS_ARRAY: [u8; 32] = system_sc_address_array(0x04);
+
+const fn system_sc_address_array(id: u8) -> [u8; 32] {
+    let mut address = [0u8; 32];
+    address[9] = 0x01;
+    address[29] = id;
+    address[30] = 0xff;
+    address[31] = 0xff;
+    address
+}
+
+/// BLS key of a validator node, together with the signature proving its ownership.
+#[derive(Clone, Debug)]
+pub struct ValidatorKey {
+    pub bls_key: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+/// Builds calls to the staking (validator) system SC.
+pub struct StakingScCall {
+    from: Address,
+}
+
+impl StakingScCall {
+    pub fn new(from: &Address) -> Self {
+        StakingScCall { from: from.clone() }
+    }
+
+    fn call(&self, function: &str, num_nodes: usize) -> ScCallStep {
+        ScCallStep::new()
+            .from(&self.from)
+            .to(&Address::from(STAKING_SC_ADDRESS_ARRAY))
+            .function(function)
+            .gas_limit(STAKING_BASE_GAS_LIMIT + STAKING_GAS_LIMIT_PER_NODE * num_nodes as u64)
+    }
+
+    /// Stakes the given nodes, the value must cover the stake of all of them.
+    pub fn stake<A>(&self, keys: &[ValidatorKey], value: A) -> ScCallStep
+    where
+        BigUintValue: From<A>,
+    {
+        let mut step = self
+            .call("stake", keys.len())
+            .egld_value(value)
+            .argument(biguint_argument(keys.len()));
+        for key in keys {
+            step = step
+                .argument(key.bls_key.as_slice())
+                .argument(key.signature.as_slice());
+        }
+        step
+    }
+
+    pub fn un_stake(&self, bls_keys: &[Vec<u8>]) -> ScCallStep {
+        with_key_arguments(self.call("unStake", bls_keys.len()), bls_keys)
+    }
+
+    pub fn un_bond(&self, bls_keys: &[Vec<u8>]) -> ScCallStep {
+        with_key_arguments(self.call("unBond", bls_keys.len()), bls_keys)
+    }
+
+    pub fn claim(&self) -> ScCallStep {
+        self.call("claim", 0)
+    }
+}
+
+/// Builds calls to the delegation manager system SC.
+pub struct DelegationManagerScCall {
+    from: Address,
+}
+
+impl DelegationManagerScCall {
+    pub fn new(from: &Address) -> Self {
+        DelegationManagerScCall { from: from.clone() }
+    }
+
+    /// Creates a new delegation contract owned by the sender.
+    ///
+    /// The service fee is expressed in hundredths of a percent (e.g. 1000 means 10%).
+    /// A total delegation cap of 0 means uncapped.
+    /// The value is the initial stake of the owner.
+    pub fn create_new_delegation_contract<C, A>(
+        &self,
+        total_delegation_cap: C,
+        service_fee: u64,
+        value: A,
+    ) -> ScCallStep
+    where
+        BigUintValue: From<C>,
+        BigUintValue: From<A>,
+    {
+        ScCallStep::new()
+            .from(&self.from)
+            .to(&Address::from(DELEGATION_MANAGER_SC_ADDRESS_ARRAY))
+            .function("createNewDelegationContract")
+            .argument(biguint_argument(
+                BigUintValue::from(total_delegation_cap).value,
+            ))
+            .argument(biguint_argument(service_fee))
+            .egld_value(value)
+            .gas_limit(CREATE_DELEGATION_CONTRACT_GAS_LIMIT)
+    }
+}
+
+/// Builds calls to a delegation contract (staking provider).
+pub struct DelegationScCall {
+    from: Address,
+    delegation_contract: Address,
+}
+
+impl DelegationScCall {
+    pub fn new(from: &Address, delegation_contract: &Address) -> Self {
+        DelegationScCall {
+            from: from.clone(),
+            delegation_contract: delegation_contract.clone(),
+        }
+    }
+
+    fn call(&self, function: &str, gas_limit: u64) -> ScCallStep {
+        ScCallStep::new()
+            .from(&self.from)
+            .to(&self.delegation_contract)
+            .function(function)
+            .gas_limit(gas_limit)
+    }
+
+    pub fn delegate<A>(&self, value: A) -> ScCallStep
+    where
+        BigUintValue: From<A>,
+    {
+        self.call("delegate", DELEGATION_GAS_LIMIT)
+            .egld_value(value)
+    }
+
+    pub fn un_delegate<A>(&self, amount: A) -> ScCallStep
+    where
+        BigUintValue: From<A>,
+    {
+        self.call("unDelegate", DELEGATION_GAS_LIMIT)
+            .argument(biguint_argument(BigUintValue::from(amount).value))
+    }
+
+    /// Withdraws the undelegated funds, once the unbonding period has passed.
+    pub fn withdraw(&self) -> ScCallStep {
+        self.call("withdraw", DELEGATION_GAS_LIMIT)
+    }
+
+    pub fn claim_rewards(&self) -> ScCallStep {
+        self.call("claimRewards", CLAIM_REWARDS_GAS_LIMIT)
+    }
+
+    pub fn re_delegate_rewards(&self) -> ScCallStep {
+        self.call("reDelegateRewards", DELEGATION_GAS_LIMIT)
+    }
+}
+
+fn biguint_argument<T: ToString>(value: T) -> BytesValue {
+    BytesValue::from(value.to_string())
+}
+
+fn with_key_arguments(mut step: ScCallStep, bls_keys: &[Vec<u8>]) -> ScCallStep {
+    for bls_key in bls_keys {
+        step = step.argument(bls_key.as_slice());
+    }
+    step
+}
@@ -0,0 +1,240 @@
+use multiversx_sc_snippets::{
+    multiversx_sc::{
+        abi::{ContractAbi, EndpointAbi, EndpointTypeAbi, InputAbi},
+        types::{Address, BigUint, CodeMetadata, ManagedAddress, ManagedBuffer, MultiValueEncoded},
+    },
+    multiversx_sc_scenario::{api::StaticApi, bech32, num_bigint},
+    CallDataBuilder, CallDataError, EventValue, ParsedCallData,
+};
+
+fn input(arg_name: &'static str, type_name: &str, multi_arg: bool) -> InputAbi {
+    InputAbi {
+        arg_name,
+        type_name: type_name.into(),
+        multi_arg,
+    }
+}
+
+fn proposal_abi() -> ContractAbi {
+    let mut abi = ContractAbi::default();
+    abi.endpoints.push(EndpointAbi {
+        name: "propose",
+        inputs: vec![
+            input("title", "bytes", false),
+            input("amount", "BigUint", false),
+            input("voters", "variadic<Address>", true),
+        ],
+        endpoint_type: EndpointTypeAbi::Endpoint,
+        ..Default::default()
+    });
+    abi
+}
+
+fn constructors_abi() -> ContractAbi {
+    let mut abi = ContractAbi::default();
+    abi.constructors.push(EndpointAbi {
+        name: "init",
+        inputs: vec![input("initial_value", "BigUint", false)],
+        endpoint_type: EndpointTypeAbi::Init,
+        ..Default::default()
+    });
+    abi.upgrade_constructors.push(EndpointAbi {
+        name: "upgrade",
+        inputs: vec![input("new_value", "BigUint", false)],
+        endpoint_type: EndpointTypeAbi::Upgrade,
+        ..Default::default()
+    });
+    abi
+}
+
+#[test]
+fn test_build_call_data() {
+    let abi = proposal_abi();
+    let title = ManagedBuffer::<StaticApi>::from("abc");
+    let amount = BigUint::<StaticApi>::from(1000u32);
+    assert_eq!(
+        CallDataBuilder::new(&abi, "propose")
+            .unwrap()
+            .argument(&title)
+            .unwrap()
+            .argument(&amount)
+            .unwrap()
+            .build()
+            .unwrap(),
+        "propose@616263@03e8"
+    );
+
+    let mut voters = MultiValueEncoded::<StaticApi, ManagedAddress<StaticApi>>::new();
+    voters.push(ManagedAddress::from_address(&Address::from([1u8; 32])));
+    voters.push(ManagedAddress::from_address(&Address::from([2u8; 32])));
+    assert_eq!(
+        CallDataBuilder::new(&abi, "propose")
+            .unwrap()
+            .argument(&title)
+            .unwrap()
+            .argument(&amount)
+            .unwrap()
+            .argument(&voters)
+            .unwrap()
+            .build()
+            .unwrap(),
+        format!(
+            "propose@616263@03e8@{}@{}",
+            hex::encode([1u8; 32]),
+            hex::encode([2u8; 32])
+        )
+    );
+}
+
+#[test]
+fn test_build_call_data_wrong_type() {
+    let abi = proposal_abi();
+    let err = CallDataBuilder::new(&abi, "propose")
+        .unwrap()
+        .argument(&ManagedBuffer::<StaticApi>::from("abc"))
+        .unwrap()
+        .argument(&1000u64)
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "argument `amount` of `propose` should be BigUint, got u64"
+    );
+}
+
+#[test]
+fn test_build_call_data_missing_argument() {
+    let abi = proposal_abi();
+    let builder = CallDataBuilder::new(&abi, "propose")
+        .unwrap()
+        .argument(&ManagedBuffer::<StaticApi>::from("abc"))
+        .unwrap();
+    assert_eq!(
+        builder.build(),
+        Err(CallDataError::MissingArgument {
+            function: "propose".to_string(),
+            argument: "amount".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_build_call_data_unknown_endpoint() {
+    let abi = proposal_abi();
+    assert!(matches!(
+        CallDataBuilder::new(&abi, "vote"),
+        Err(CallDataError::EndpointNotFound { .. })
+    ));
+}
+
+#[test]
+fn test_build_deploy_data() {
+    let abi = constructors_abi();
+    let value = BigUint::<StaticApi>::from(5u32);
+    let deploy_data = CallDataBuilder::new(&abi, "init")
+        .unwrap()
+        .argument(&value)
+        .unwrap()
+        .build_deploy(&[0xaa, 0xbb], CodeMetadata::UPGRADEABLE)
+        .unwrap();
+    assert_eq!(deploy_data, "aabb@0500@0100@05");
+    assert_eq!(
+        ParsedCallData::parse_deploy(&abi, &deploy_data)
+            .unwrap()
+            .to_string(),
+        "init { initial_value: 5 }"
+    );
+
+    let upgrade_data = CallDataBuilder::new(&abi, "upgrade")
+        .unwrap()
+        .argument(&value)
+        .unwrap()
+        .build_deploy(&[0xaa, 0xbb], CodeMetadata::UPGRADEABLE)
+        .unwrap();
+    assert_eq!(upgrade_data, "upgradeContract@aabb@0100@05");
+    assert_eq!(
+        ParsedCallData::parse(&abi, &upgrade_data)
+            .unwrap()
+            .to_string(),
+        "upgrade { new_value: 5 }"
+    );
+}
+
+#[test]
+fn test_build_deploy_data_not_a_constructor() {
+    let abi = proposal_abi();
+    let builder = CallDataBuilder::new(&abi, "propose")
+        .unwrap()
+        .argument(&ManagedBuffer::<StaticApi>::from("abc"))
+        .unwrap()
+        .argument(&BigUint::<StaticApi>::from(1000u32))
+        .unwrap();
+    assert_eq!(
+        builder.build_deploy(&[0xaa], CodeMetadata::DEFAULT),
+        Err(CallDataError::NotAConstructor {
+            function: "propose".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_parse_call_data() {
+    let abi = proposal_abi();
+    let voter = Address::from([1u8; 32]);
+    let parsed = ParsedCallData::parse(
+        &abi,
+        &format!("propose@616263@03e8@{}", hex::encode(voter.as_bytes())),
+    )
+    .unwrap();
+
+    assert_eq!(parsed.function, "propose");
+    assert_eq!(
+        parsed.argument("amount"),
+        Some(&EventValue::Unsigned(num_bigint::BigUint::from(1000u32)))
+    );
+    assert_eq!(
+        parsed.argument("voters"),
+        Some(&EventValue::List(vec![EventValue::Address(voter.clone())]))
+    );
+    assert_eq!(
+        parsed.to_string(),
+        format!(
+            "propose {{ title: \"abc\", amount: 1000, voters: [{}] }}",
+            bech32::encode(&voter)
+        )
+    );
+}
+
+#[test]
+fn test_parse_call_data_too_many_arguments() {
+    let mut abi = proposal_abi();
+    abi.endpoints[0].inputs.pop();
+    assert_eq!(
+        ParsedCallData::parse(&abi, "propose@616263@03e8@01")
+            .unwrap_err()
+            .to_string(),
+        "too many arguments for `propose`, 1 not in the ABI"
+    );
+}
+
+#[test]
+fn test_parse_call_data_invalid() {
+    let abi = proposal_abi();
+    assert_eq!(
+        ParsedCallData::parse(&abi, "propose@xyz"),
+        Err(CallDataError::InvalidHex {
+            part: "xyz".to_string()
+        })
+    );
+    assert_eq!(
+        ParsedCallData::parse(&abi, "propose@616263"),
+        Err(CallDataError::MissingArgument {
+            function: "propose".to_string(),
+            argument: "amount".to_string(),
+        })
+    );
+    assert_eq!(
+        ParsedCallData::parse_deploy(&abi, "aabb@0400@0100"),
+        Err(CallDataError::InvalidDeployData)
+    );
+}
@@ -0,0 +1,13 @@
+use multiversx_sc_snippets::code_hash;
+
+#[test]
+fn code_hash_test() {
+    assert_eq!(
+        hex::encode(code_hash(b"")),
+        "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+    );
+    assert_eq!(
+        hex::encode(code_hash(b"abc")),
+        "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+    );
+}
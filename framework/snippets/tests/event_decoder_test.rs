@@ -0,0 +1,99 @@
+use multiversx_sc_snippets::{
+    multiversx_sc::abi::{
+        ContractAbi, EventAbi, EventInputAbi, StructFieldDescription, TypeContents,
+        TypeDescription, TypeDescriptionContainer,
+    },
+    multiversx_sc_scenario::{num_bigint::BigUint, scenario_model::Log},
+    EventDecoder, EventValue,
+};
+
+fn proposal_abi() -> ContractAbi {
+    let mut abi = ContractAbi::default();
+    abi.events.push(EventAbi {
+        docs: &[],
+        identifier: "proposalCreated",
+        inputs: vec![
+            EventInputAbi {
+                arg_name: "id",
+                type_name: "u32".into(),
+                indexed: true,
+            },
+            EventInputAbi {
+                arg_name: "proposal",
+                type_name: "Proposal".into(),
+                indexed: false,
+            },
+        ],
+    });
+    abi.type_descriptions.insert(
+        "Proposal".into(),
+        TypeDescription {
+            docs: &[],
+            name: "Proposal".into(),
+            contents: TypeContents::Struct(vec![
+                StructFieldDescription {
+                    docs: &[],
+                    name: "title",
+                    field_type: "bytes".into(),
+                },
+                StructFieldDescription {
+                    docs: &[],
+                    name: "amount",
+                    field_type: "BigUint".into(),
+                },
+            ]),
+        },
+    );
+    abi
+}
+
+fn log(topics: &[&[u8]], data: &[u8]) -> Log {
+    Log {
+        address: [0u8; 32][..].into(),
+        endpoint: b"propose"[..].into(),
+        topics: topics.iter().map(|topic| (*topic).into()).collect(),
+        data: data.into(),
+    }
+}
+
+#[test]
+fn test_decode_event() {
+    let mut decoder = EventDecoder::default();
+    decoder.add_contract_abi(proposal_abi());
+
+    // "abc" and 1000, nested-encoded
+    let data = [
+        0, 0, 0, 3, b'a', b'b', b'c', //
+        0, 0, 0, 2, 0x03, 0xe8,
+    ];
+    let events = decoder.decode_logs(&[
+        log(&[b"proposalCreated", &[5]], &data),
+        log(&[b"unknownEvent"], &[]),
+    ]);
+
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(
+        event.field("id"),
+        Some(&EventValue::Unsigned(BigUint::from(5u32)))
+    );
+    assert_eq!(
+        event.to_string(),
+        "ProposalCreated { id: 5, proposal: Proposal { title: \"abc\", amount: 1000 } }"
+    );
+}
+
+#[test]
+fn test_decode_event_mismatched_data() {
+    let mut decoder = EventDecoder::default();
+    decoder.add_contract_abi(proposal_abi());
+
+    let event = decoder
+        .decode_log(&log(&[b"proposalCreated", &[5]], &[1, 2]))
+        .unwrap();
+    assert_eq!(event.field("proposal"), Some(&EventValue::Raw(vec![1, 2])));
+    assert_eq!(
+        event.to_string(),
+        "ProposalCreated { id: 5, proposal: 0x0102 }"
+    );
+}
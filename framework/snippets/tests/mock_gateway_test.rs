@@ -0,0 +1,534 @@
+use multiversx_sc_snippets::{
+    code_hash,
+    futures::future::{self, BoxFuture},
+    multiversx_sc::types::{Address, CodeMetadata, EsdtLocalRole},
+    multiversx_sc_scenario::{
+        num_bigint::BigUint,
+        scenario::StepExecutor,
+        scenario_model::{ScCallStep, ScDeployStep, TransferStep, TypedResponse, TypedScCall},
+    },
+    sdk::{
+        data::{
+            account::Account,
+            address::Address as ErdrsAddress,
+            esdt::{EsdtTokenData, EsdtTokenSupply},
+            hyperblock::{HyperBlock, HyperBlockTransaction},
+            network_config::NetworkConfig,
+            transaction::{
+                ApiLogs, ApiSmartContractResult, Events, SimulationResults, Transaction,
+                TransactionOnNetwork, TxCostResponseData,
+            },
+            vm::{CallType, VmValueRequest, VmValuesResponseData},
+        },
+        wallet::Wallet,
+    },
+    EventFilter, Faucet, GatewayProxy, GatewayResult, Interactor, StepBuffer,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+const ALICE: [u8; 32] = [1u8; 32];
+const ALICE_PRIVATE_KEY: &str = "1648ad209d6b157a289884933e3bb30f161ec7113221ec16f87c3578b05830b0";
+const LATEST_BLOCK_NONCE: u64 = 3;
+const EVENT_BLOCK_NONCE: u64 = 2;
+
+fn mock_hyper_block(nonce: u64) -> HyperBlock {
+    let mut transactions = Vec::new();
+    if nonce == EVENT_BLOCK_NONCE {
+        transactions.push(HyperBlockTransaction {
+            kind: "normal".to_string(),
+            hash: Some("abcd".to_string()),
+            sender: Some(ErdrsAddress::from_bytes(ALICE)),
+            receiver: Some(ErdrsAddress::from_bytes(ALICE)),
+            status: "success".to_string(),
+            logs: Some(ApiLogs {
+                address: ErdrsAddress::from_bytes(ALICE),
+                events: vec![
+                    Events {
+                        address: ErdrsAddress::from_bytes(ALICE),
+                        identifier: "liquidate".to_string(),
+                        topics: None,
+                        data: None,
+                    },
+                    Events {
+                        address: ErdrsAddress::from_bytes(ALICE),
+                        identifier: "completedTxEvent".to_string(),
+                        topics: None,
+                        data: None,
+                    },
+                ],
+            }),
+        });
+    }
+
+    HyperBlock {
+        nonce,
+        round: nonce,
+        hash: format!("hash-{nonce}"),
+        prev_block_hash: String::new(),
+        epoch: 0,
+        num_txs: transactions.len() as u64,
+        shard_blocks: Vec::new(),
+        timestamp: 0,
+        accumulated_fees: "0".to_string(),
+        developer_fees: "0".to_string(),
+        accumulated_fees_in_epoch: "0".to_string(),
+        developer_fees_in_epoch: "0".to_string(),
+        transactions,
+    }
+}
+
+fn mock_tx_on_network(hash: &str) -> TransactionOnNetwork {
+    TransactionOnNetwork {
+        kind: "normal".to_string(),
+        hash: Some(hash.to_string()),
+        nonce: 7,
+        round: LATEST_BLOCK_NONCE,
+        epoch: 0,
+        value: "0".to_string(),
+        receiver: ErdrsAddress::from_bytes(ALICE),
+        sender: ErdrsAddress::from_bytes(ALICE),
+        gas_price: 1_000_000_000,
+        gas_limit: 50_000,
+        signature: String::new(),
+        source_shard: 0,
+        destination_shard: 0,
+        block_nonce: LATEST_BLOCK_NONCE,
+        block_hash: format!("hash-{LATEST_BLOCK_NONCE}"),
+        notarized_at_source_in_meta_nonce: 0,
+        notarized_at_source_in_meta_hash: String::new(),
+        notarized_at_destination_in_meta_nonce: 0,
+        notarized_at_destination_in_meta_hash: String::new(),
+        miniblock_type: String::new(),
+        miniblock_hash: String::new(),
+        timestamp: 0,
+        data: None,
+        status: "success".to_string(),
+        hyperblock_nonce: LATEST_BLOCK_NONCE,
+        hyperblock_hash: format!("hash-{LATEST_BLOCK_NONCE}"),
+        smart_contract_results: Some(Vec::new()),
+        logs: None,
+    }
+}
+
+/// Serves canned responses, just enough to exercise the interactor logic.
+struct MockGateway;
+
+impl GatewayProxy for MockGateway {
+    fn get_network_config(&self) -> BoxFuture<'_, GatewayResult<NetworkConfig>> {
+        Box::pin(future::ready(Ok(NetworkConfig {
+            chain_id: "local-testnet".to_string(),
+            denomination: 18,
+            gas_per_data_byte: 1500,
+            latest_tag_software_version: String::new(),
+            meta_consensus_group_size: 1,
+            min_gas_limit: 50_000,
+            min_gas_price: 1_000_000_000,
+            min_transaction_version: 1,
+            num_metachain_nodes: 1,
+            num_nodes_in_shard: 1,
+            num_shards_without_meta: 3,
+            round_duration: 6000,
+            shard_consensus_group_size: 1,
+            start_time: 0,
+        })))
+    }
+
+    fn get_account<'a>(
+        &'a self,
+        address: &'a ErdrsAddress,
+    ) -> BoxFuture<'a, GatewayResult<Account>> {
+        Box::pin(future::ready(Ok(Account {
+            address: address.clone(),
+            nonce: 7,
+            balance: "1000000000000000000".to_string(),
+            username: String::new(),
+            code: String::new(),
+            // the hash of `"contract code"`
+            code_hash: Some("PL88AR/0dn9AOaD2C4pmidNpWrHnU7BwRq+fY15TjrE=".to_string()),
+            root_hash: None,
+            code_metadata: None,
+            developer_reward: None,
+            owner_address: None,
+        })))
+    }
+
+    fn get_account_storage_value<'a>(
+        &'a self,
+        _address: &'a ErdrsAddress,
+        key: &'a [u8],
+    ) -> BoxFuture<'a, GatewayResult<String>> {
+        let value = if key == b"counter" { "2a" } else { "" };
+        Box::pin(future::ready(Ok(value.to_string())))
+    }
+
+    fn get_account_esdt_token<'a>(
+        &'a self,
+        _address: &'a ErdrsAddress,
+        token_identifier: &'a str,
+        nonce: u64,
+    ) -> BoxFuture<'a, GatewayResult<EsdtTokenData>> {
+        Box::pin(future::ready(Ok(EsdtTokenData {
+            token_identifier: token_identifier.to_string(),
+            balance: if nonce == 0 { "300" } else { "1" }.to_string(),
+            nonce,
+            attributes: None,
+        })))
+    }
+
+    fn get_account_esdt_roles<'a>(
+        &'a self,
+        _address: &'a ErdrsAddress,
+    ) -> BoxFuture<'a, GatewayResult<HashMap<String, Vec<String>>>> {
+        let mut roles = HashMap::new();
+        roles.insert(
+            "TOKEN-123456".to_string(),
+            vec![
+                "ESDTRoleLocalMint".to_string(),
+                "ESDTRoleLocalBurn".to_string(),
+            ],
+        );
+        Box::pin(future::ready(Ok(roles)))
+    }
+
+    fn get_esdt_token_supply<'a>(
+        &'a self,
+        _token_identifier: &'a str,
+    ) -> BoxFuture<'a, GatewayResult<EsdtTokenSupply>> {
+        Box::pin(future::ready(Ok(EsdtTokenSupply {
+            supply: "5000".to_string(),
+            minted: "6000".to_string(),
+            burned: "1000".to_string(),
+            initial_minted: "0".to_string(),
+        })))
+    }
+
+    fn get_latest_hyper_block_nonce(
+        &self,
+        _with_metachain: bool,
+    ) -> BoxFuture<'_, GatewayResult<u64>> {
+        Box::pin(future::ready(Ok(LATEST_BLOCK_NONCE)))
+    }
+
+    fn get_hyper_block_by_nonce(&self, nonce: u64) -> BoxFuture<'_, GatewayResult<HyperBlock>> {
+        Box::pin(future::ready(Ok(mock_hyper_block(nonce))))
+    }
+
+    fn send_transaction<'a>(&'a self, tx: &'a Transaction) -> BoxFuture<'a, GatewayResult<String>> {
+        Box::pin(future::ready(Ok(format!("tx-hash-{}", tx.nonce))))
+    }
+
+    /// Calls to `fail` fail, all the other calls return `42`.
+    fn simulate_transaction<'a>(
+        &'a self,
+        tx: &'a Transaction,
+    ) -> BoxFuture<'a, GatewayResult<SimulationResults>> {
+        if tx.data == Some(base64::encode("fail")) {
+            return Box::pin(future::ready(Ok(SimulationResults {
+                status: "fail".to_string(),
+                fail_reason: "execution failed".to_string(),
+                ..Default::default()
+            })));
+        }
+
+        let out_scr = ApiSmartContractResult {
+            hash: "scr-hash".to_string(),
+            nonce: 8,
+            value: 0,
+            receiver: tx.sender.clone(),
+            sender: tx.receiver.clone(),
+            data: "@6f6b@2a".to_string(),
+            prev_tx_hash: String::new(),
+            original_tx_hash: String::new(),
+            gas_limit: 0,
+            gas_price: tx.gas_price,
+            call_type: CallType::DirectCall,
+            relayer_address: None,
+            relayed_value: None,
+            code: None,
+            code_metadata: None,
+            return_message: None,
+            original_sender: None,
+        };
+        Box::pin(future::ready(Ok(SimulationResults {
+            status: "success".to_string(),
+            sc_results: vec![out_scr],
+            ..Default::default()
+        })))
+    }
+
+    fn request_transaction_cost<'a>(
+        &'a self,
+        _tx: &'a Transaction,
+    ) -> BoxFuture<'a, GatewayResult<TxCostResponseData>> {
+        Box::pin(future::ready(Ok(TxCostResponseData {
+            tx_gas_units: 1_500_000,
+            return_message: String::new(),
+        })))
+    }
+
+    /// All sent transactions succeed, without any results.
+    fn get_transaction_info_with_results<'a>(
+        &'a self,
+        hash: &'a str,
+    ) -> BoxFuture<'a, GatewayResult<TransactionOnNetwork>> {
+        Box::pin(future::ready(Ok(mock_tx_on_network(hash))))
+    }
+
+    fn execute_vmquery<'a>(
+        &'a self,
+        _vm_request: &'a VmValueRequest,
+    ) -> BoxFuture<'a, GatewayResult<VmValuesResponseData>> {
+        Box::pin(future::ready(Err(anyhow::anyhow!(
+            "vm queries not supported by the mock gateway"
+        ))))
+    }
+}
+
+/// Records the funding requests.
+#[derive(Clone, Default)]
+struct MockFaucet {
+    requests: Arc<Mutex<Vec<Address>>>,
+}
+
+impl Faucet for MockFaucet {
+    fn request_funds<'a>(&'a self, address: &'a Address) -> BoxFuture<'a, GatewayResult<()>> {
+        self.requests.lock().unwrap().push(address.clone());
+        Box::pin(future::ready(Ok(())))
+    }
+}
+
+#[tokio::test]
+async fn mock_gateway_network_config_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+    assert_eq!(interactor.network_config.chain_id, "local-testnet");
+}
+
+#[tokio::test]
+async fn mock_gateway_recall_nonce_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+    assert_eq!(interactor.recall_nonce(&Address::from(ALICE)).await, 7);
+}
+
+#[tokio::test]
+async fn mock_gateway_assertions_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+    let alice = Address::from(ALICE);
+    interactor.assert_balance(&alice, "1e18").await;
+    interactor
+        .assert_storage_value(&alice, "str:counter", "42")
+        .await;
+    interactor.assert_token_supply("TOKEN-123456", "5000").await;
+}
+
+#[tokio::test]
+async fn mock_gateway_code_hash_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+    let alice = Address::from(ALICE);
+    let expected_code_hash = code_hash(b"contract code");
+    assert_eq!(
+        interactor.get_code_hash(&alice).await,
+        expected_code_hash.to_vec()
+    );
+    interactor
+        .assert_code_hash(&alice, expected_code_hash.as_slice())
+        .await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "codeHash")]
+async fn mock_gateway_assert_code_hash_mismatch_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+    interactor
+        .assert_code_hash(&Address::from(ALICE), code_hash(b"other code").as_slice())
+        .await;
+}
+
+#[tokio::test]
+async fn mock_gateway_esdt_query_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+    let alice = Address::from(ALICE);
+    assert_eq!(
+        interactor.get_esdt_balance(&alice, "TOKEN-123456", 0).await,
+        BigUint::from(300u32)
+    );
+    assert_eq!(
+        interactor.get_esdt_balance(&alice, "NFT-123456", 5).await,
+        BigUint::from(1u32)
+    );
+    assert_eq!(
+        interactor.get_esdt_roles(&alice, "TOKEN-123456").await,
+        vec![EsdtLocalRole::Mint, EsdtLocalRole::Burn]
+    );
+    assert!(interactor
+        .get_esdt_roles(&alice, "OTHER-123456")
+        .await
+        .is_empty());
+}
+
+#[tokio::test]
+async fn mock_gateway_simulate_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+    let response = interactor
+        .simulate(
+            ScCallStep::new()
+                .from(&Address::from(ALICE))
+                .to(&Address::from(ALICE))
+                .function("getAnswer")
+                .gas_limit("5,000,000"),
+        )
+        .await;
+    assert!(response.is_success());
+    assert_eq!(response.out, vec![vec![42u8]]);
+    assert_eq!(response.gas, 1_500_000);
+
+    let response = interactor
+        .simulate(
+            ScCallStep::new()
+                .from(&Address::from(ALICE))
+                .to(&Address::from(ALICE))
+                .function("fail")
+                .gas_limit("5,000,000"),
+        )
+        .await;
+    assert!(!response.is_success());
+    assert_eq!(response.tx_error.message, "execution failed");
+}
+
+#[tokio::test]
+#[should_panic(expected = "have: 1000000000000000000")]
+async fn mock_gateway_assert_balance_mismatch_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+    interactor.assert_balance(&Address::from(ALICE), "2").await;
+}
+
+#[tokio::test]
+async fn mock_gateway_block_subscriber_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+
+    let mut subscriber = interactor.block_subscriber();
+    assert_eq!(
+        subscriber.next_block().await.unwrap().nonce,
+        LATEST_BLOCK_NONCE
+    );
+
+    let mut subscriber = interactor.block_subscriber().from_nonce(1);
+    for nonce in 1..=LATEST_BLOCK_NONCE {
+        assert_eq!(subscriber.next_block().await.unwrap().nonce, nonce);
+    }
+    assert_eq!(subscriber.next_nonce(), Some(LATEST_BLOCK_NONCE + 1));
+}
+
+#[tokio::test]
+async fn mock_gateway_block_subscriber_events_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+    let mut subscriber = interactor.block_subscriber().from_nonce(1);
+
+    let filter = EventFilter::new()
+        .address(ErdrsAddress::from_bytes(ALICE))
+        .identifier("liquidate");
+    let events = subscriber.next_events(&filter).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].identifier, "liquidate");
+    assert_eq!(subscriber.next_nonce(), Some(EVENT_BLOCK_NONCE + 1));
+}
+
+#[tokio::test]
+async fn mock_gateway_faucet_test() {
+    let faucet = MockFaucet::default();
+    let mut interactor = Interactor::new_with_gateway(MockGateway)
+        .await
+        .with_faucet(faucet.clone());
+    let alice = Address::from(ALICE);
+
+    // the mock account already holds 1 EGLD
+    interactor
+        .fund_wallet(&alice, 1_000_000_000_000_000_000)
+        .await;
+    assert_eq!(*faucet.requests.lock().unwrap(), vec![alice]);
+}
+
+#[tokio::test]
+async fn mock_gateway_generated_wallet_test() {
+    let mut interactor = Interactor::new_with_gateway(MockGateway).await;
+    let pem_path = std::env::temp_dir().join("interactor_generated_wallet.pem");
+    let pem_path = pem_path.to_str().unwrap();
+
+    let address = interactor.register_generated_wallet(pem_path);
+    let reloaded = Wallet::from_pem_file(pem_path).unwrap();
+    std::fs::remove_file(pem_path).unwrap();
+
+    assert_eq!(reloaded.address().to_bytes(), *address.as_array());
+    assert!(interactor.sender_map.contains_key(&address));
+}
+
+#[tokio::test]
+async fn mock_gateway_export_unsigned_txs_test() {
+    let interactor = Interactor::new_with_gateway(MockGateway).await;
+    let alice = Address::from(ALICE);
+
+    let mut deploy_step = ScDeployStep::new()
+        .from(&alice)
+        .code("0x0061736d")
+        .code_metadata(CodeMetadata::UPGRADEABLE)
+        .argument("5")
+        .gas_limit("60,000,000");
+    let mut call_step = ScCallStep::new()
+        .from(&alice)
+        .to(&alice)
+        .function("add")
+        .argument("3")
+        .gas_limit("5,000,000");
+    let buffer = StepBuffer {
+        refs: vec![&mut deploy_step, &mut call_step],
+    };
+
+    let json_path = std::env::temp_dir().join("interactor_unsigned_txs.json");
+    interactor.export_unsigned_txs(buffer, &json_path).await;
+    let json = std::fs::read_to_string(&json_path).unwrap();
+    std::fs::remove_file(&json_path).unwrap();
+
+    let txs: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+    assert_eq!(txs.len(), 2);
+
+    // the mock account nonce is 7, the following transactions from the same sender increment it
+    assert_eq!(txs[0]["nonce"], 7);
+    assert_eq!(txs[0]["gasLimit"], 60_000_000);
+    assert_eq!(
+        base64::decode(txs[0]["data"].as_str().unwrap()).unwrap(),
+        b"0061736d@0500@0100@05"
+    );
+    assert_eq!(txs[1]["nonce"], 8);
+    assert_eq!(txs[1]["gasLimit"], 5_000_000);
+    assert_eq!(
+        base64::decode(txs[1]["data"].as_str().unwrap()).unwrap(),
+        b"add@03"
+    );
+    assert!(txs.iter().all(|tx| tx.get("signature").is_none()));
+}
+
+/// Written once, runs the same on the mock VM and on the interactor.
+async fn transfer_flow<E: StepExecutor>(executor: &mut E, from: &Address, to: &Address) {
+    let mut transfer_step = TransferStep::new().from(from).to(to).egld_value(300u64);
+    executor.execute_transfer(&mut transfer_step).await;
+    assert!(transfer_step.response().is_success());
+
+    let typed_sc_call: TypedScCall<()> = ScCallStep::new()
+        .from(from)
+        .to(to)
+        .function("add")
+        .argument("3")
+        .gas_limit("5,000,000")
+        .into();
+    let response: TypedResponse<()> = typed_sc_call.run_on(executor).await;
+    assert!(response.result.is_ok());
+}
+
+#[tokio::test(start_paused = true)]
+async fn mock_gateway_step_executor_test() {
+    let mut interactor = Interactor::new_with_gateway(MockGateway).await;
+    let alice = interactor.register_wallet(Wallet::from_private_key(ALICE_PRIVATE_KEY).unwrap());
+    transfer_flow(&mut interactor, &alice, &alice).await;
+}
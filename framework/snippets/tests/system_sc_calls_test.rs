@@ -0,0 +1,107 @@
+use multiversx_sc_snippets::{
+    multiversx_sc::types::Address, multiversx_sc_scenario::scenario_model::ScCallStep,
+    DelegationManagerScCall, DelegationScCall, StakingScCall, ValidatorKey,
+    CLAIM_REWARDS_GAS_LIMIT, DELEGATION_GAS_LIMIT, DELEGATION_MANAGER_SC_ADDRESS_ARRAY,
+    STAKING_BASE_GAS_LIMIT, STAKING_GAS_LIMIT_PER_NODE, STAKING_SC_ADDRESS_ARRAY,
+};
+
+const OWNER: [u8; 32] = [1u8; 32];
+const PROVIDER: [u8; 32] = [2u8; 32];
+
+fn arguments(step: &ScCallStep) -> Vec<Vec<u8>> {
+    step.tx
+        .arguments
+        .iter()
+        .map(|arg| arg.value.clone())
+        .collect()
+}
+
+#[test]
+fn system_sc_addresses_test() {
+    assert_eq!(
+        hex::encode(STAKING_SC_ADDRESS_ARRAY),
+        "000000000000000000010000000000000000000000000000000000000001ffff"
+    );
+    assert_eq!(
+        hex::encode(DELEGATION_MANAGER_SC_ADDRESS_ARRAY),
+        "000000000000000000010000000000000000000000000000000000000004ffff"
+    );
+}
+
+#[test]
+fn delegation_calls_test() {
+    let calls = DelegationScCall::new(&Address::from(OWNER), &Address::from(PROVIDER));
+
+    let delegate = calls.delegate("1,000000000000000000");
+    assert_eq!(delegate.tx.to.value, Address::from(PROVIDER));
+    assert_eq!(delegate.tx.function, "delegate");
+    assert_eq!(delegate.tx.egld_value.value, 10u64.pow(18).into());
+    assert!(delegate.tx.arguments.is_empty());
+    assert_eq!(delegate.tx.gas_limit.value, DELEGATION_GAS_LIMIT);
+
+    let un_delegate = calls.un_delegate(500u64);
+    assert_eq!(un_delegate.tx.function, "unDelegate");
+    assert_eq!(arguments(&un_delegate), vec![vec![0x01, 0xf4]]);
+
+    let claim_rewards = calls.claim_rewards();
+    assert_eq!(claim_rewards.tx.function, "claimRewards");
+    assert_eq!(claim_rewards.tx.gas_limit.value, CLAIM_REWARDS_GAS_LIMIT);
+
+    assert_eq!(calls.re_delegate_rewards().tx.function, "reDelegateRewards");
+    assert_eq!(calls.withdraw().tx.function, "withdraw");
+}
+
+#[test]
+fn create_delegation_contract_test() {
+    let step = DelegationManagerScCall::new(&Address::from(OWNER)).create_new_delegation_contract(
+        0u64,
+        1000,
+        "1250,000000000000000000",
+    );
+    assert_eq!(
+        step.tx.to.value,
+        Address::from(DELEGATION_MANAGER_SC_ADDRESS_ARRAY)
+    );
+    assert_eq!(step.tx.function, "createNewDelegationContract");
+    assert_eq!(arguments(&step), vec![vec![], vec![0x03, 0xe8]]);
+}
+
+#[test]
+fn staking_calls_test() {
+    let calls = StakingScCall::new(&Address::from(OWNER));
+    let keys = vec![
+        ValidatorKey {
+            bls_key: vec![0xaa; 96],
+            signature: vec![0xbb; 48],
+        },
+        ValidatorKey {
+            bls_key: vec![0xcc; 96],
+            signature: vec![0xdd; 48],
+        },
+    ];
+
+    let stake = calls.stake(&keys, "5000,000000000000000000");
+    assert_eq!(stake.tx.to.value, Address::from(STAKING_SC_ADDRESS_ARRAY));
+    assert_eq!(stake.tx.function, "stake");
+    assert_eq!(
+        arguments(&stake),
+        vec![
+            vec![2],
+            vec![0xaa; 96],
+            vec![0xbb; 48],
+            vec![0xcc; 96],
+            vec![0xdd; 48]
+        ]
+    );
+    assert_eq!(
+        stake.tx.gas_limit.value,
+        STAKING_BASE_GAS_LIMIT + 2 * STAKING_GAS_LIMIT_PER_NODE
+    );
+
+    let un_stake = calls.un_stake(&[vec![0xaa; 96]]);
+    assert_eq!(un_stake.tx.function, "unStake");
+    assert_eq!(arguments(&un_stake), vec![vec![0xaa; 96]]);
+
+    assert_eq!(calls.un_bond(&[vec![0xaa; 96]]).tx.function, "unBond");
+    assert_eq!(calls.claim().tx.function, "claim");
+}
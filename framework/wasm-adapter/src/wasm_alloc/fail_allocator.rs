@@ -1,18 +1,29 @@
 use core::alloc::{GlobalAlloc, Layout};
+use multiversx_sc::err_msg;
 
-fn signal_allocation_not_allowed() -> ! {
-    crate::error_hook::signal_error(&b"memory allocation forbidden"[..])
+fn signal_allocation_not_allowed(message: &str) -> ! {
+    crate::error_hook::signal_error(message.as_bytes())
 }
 
 /// Allocator that fails (with signal error) whenever an allocation is attempted.
+///
+/// The error message identifies the kind of operation that was attempted.
 pub struct FailAllocator;
 
 unsafe impl GlobalAlloc for FailAllocator {
     unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
-        signal_allocation_not_allowed()
+        signal_allocation_not_allowed(err_msg::MEM_ALLOC_FORBIDDEN)
+    }
+
+    unsafe fn alloc_zeroed(&self, _layout: Layout) -> *mut u8 {
+        signal_allocation_not_allowed(err_msg::MEM_ALLOC_ZEROED_FORBIDDEN)
+    }
+
+    unsafe fn realloc(&self, _ptr: *mut u8, _layout: Layout, _new_size: usize) -> *mut u8 {
+        signal_allocation_not_allowed(err_msg::MEM_REALLOC_FORBIDDEN)
     }
 
     unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {
-        signal_allocation_not_allowed()
+        signal_allocation_not_allowed(err_msg::MEM_DEALLOC_FORBIDDEN)
     }
 }
@@ -2,16 +2,20 @@ use std::collections::HashMap;
 
 use crate::data::{
     account::{Account, AccountResponse},
-    account_storage::AccountStorageResponse,
+    account_storage::{AccountStorageResponse, AccountStorageValueResponse},
     address::Address,
-    esdt::{EsdtBalance, EsdtBalanceResponse, EsdtRolesResponse},
+    esdt::{
+        EsdtBalance, EsdtBalanceResponse, EsdtRolesResponse, EsdtTokenData, EsdtTokenDataResponse,
+        EsdtTokenSupply, EsdtTokenSupplyResponse,
+    },
     hyperblock::{HyperBlock, HyperBlockResponse},
     network_config::{NetworkConfig, NetworkConfigResponse},
     network_economics::{NetworkEconomics, NetworkEconomicsResponse},
     network_status::NetworkStatusResponse,
     transaction::{
         ArgCreateTransaction, ResponseTxCost, SendTransactionResponse, SendTransactionsResponse,
-        Transaction, TransactionInfo, TransactionOnNetwork, TransactionStatus, TxCostResponseData,
+        SimulateTransactionResponse, SimulationResults, Transaction, TransactionInfo,
+        TransactionOnNetwork, TransactionStatus, TxCostResponseData,
     },
     vm::{ResponseVmValue, VmValueRequest, VmValuesResponseData},
 };
@@ -30,9 +34,15 @@ const NETWORK_CONFIG_ENDPOINT: &str = "network/config";
 const NETWORK_ECONOMICS_ENDPOINT: &str = "network/economics";
 const ACCOUNT_ENDPOINT: &str = "address/";
 const KEYS_ENDPOINT: &str = "/keys/";
+const KEY_ENDPOINT: &str = "/key/";
+const ESDT_TOKEN_ENDPOINT: &str = "/esdt/";
+const NFT_TOKEN_ENDPOINT: &str = "/nft/";
+const NFT_NONCE_ENDPOINT: &str = "/nonce/";
+const ESDT_SUPPLY_ENDPOINT: &str = "network/esdt/supply/";
 const COST_TRANSACTION_ENDPOINT: &str = "transaction/cost";
 const SEND_TRANSACTION_ENDPOINT: &str = "transaction/send";
 const SEND_MULTIPLE_TRANSACTIONS_ENDPOINT: &str = "transaction/send-multiple";
+const SIMULATE_TRANSACTION_ENDPOINT: &str = "transaction/simulate?checkSignature=false";
 const GET_TRANSACTION_INFO_ENDPOINT: &str = "transaction/";
 const GET_HYPER_BLOCK_BY_NONCE_ENDPOINT: &str = "hyperblock/by-nonce/";
 const GET_HYPER_BLOCK_BY_HASH_ENDPOINT: &str = "hyperblock/by-hash/";
@@ -234,6 +244,42 @@ impl CommunicationProxy {
         }
     }
 
+    // get_account_esdt_token retrieves the balance of a single token of an account, nonce 0 meaning fungible
+    pub async fn get_account_esdt_token(
+        &self,
+        address: &Address,
+        token_identifier: &str,
+        nonce: u64,
+    ) -> Result<EsdtTokenData> {
+        if !address.is_valid() {
+            return Err(anyhow!("invalid address"));
+        }
+
+        let mut endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str();
+        if nonce == 0 {
+            endpoint = endpoint + ESDT_TOKEN_ENDPOINT + token_identifier;
+        } else {
+            endpoint = endpoint
+                + NFT_TOKEN_ENDPOINT
+                + token_identifier
+                + NFT_NONCE_ENDPOINT
+                + nonce.to_string().as_str();
+        }
+        let endpoint = self.get_endpoint(endpoint.as_str());
+        let resp = self
+            .client
+            .get(endpoint)
+            .send()
+            .await?
+            .json::<EsdtTokenDataResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(anyhow!("{}", resp.error)),
+            Some(b) => Ok(b.token_data),
+        }
+    }
+
     // get_account_esdt_tokens retrieves an all esdt token of an account from the network
     pub async fn get_account_storage_keys(
         &self,
@@ -259,6 +305,49 @@ impl CommunicationProxy {
         }
     }
 
+    // get_account_storage_value retrieves the value stored under a key of an account, hex-encoded
+    pub async fn get_account_storage_value(&self, address: &Address, key: &[u8]) -> Result<String> {
+        if !address.is_valid() {
+            return Err(anyhow!("invalid address"));
+        }
+
+        let endpoint = ACCOUNT_ENDPOINT.to_string()
+            + address.to_string().as_str()
+            + KEY_ENDPOINT
+            + hex::encode(key).as_str();
+        let endpoint = self.get_endpoint(endpoint.as_str());
+        let resp = self
+            .client
+            .get(endpoint)
+            .send()
+            .await?
+            .json::<AccountStorageValueResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(anyhow!("{}", resp.error)),
+            Some(b) => Ok(b.value),
+        }
+    }
+
+    // get_esdt_token_supply retrieves the current supply of a token, as well as the minted and burned amounts
+    pub async fn get_esdt_token_supply(&self, token_identifier: &str) -> Result<EsdtTokenSupply> {
+        let endpoint = ESDT_SUPPLY_ENDPOINT.to_string() + token_identifier;
+        let endpoint = self.get_endpoint(endpoint.as_str());
+        let resp = self
+            .client
+            .get(endpoint)
+            .send()
+            .await?
+            .json::<EsdtTokenSupplyResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(anyhow!("{}", resp.error)),
+            Some(b) => Ok(b),
+        }
+    }
+
     async fn get_transaction_info_internal(
         &self,
         hash: &str,
@@ -358,6 +447,24 @@ impl CommunicationProxy {
         }
     }
 
+    // simulate_transaction executes a transaction on the network, without broadcasting it
+    pub async fn simulate_transaction(&self, tx: &Transaction) -> Result<SimulationResults> {
+        let endpoint = self.get_endpoint(SIMULATE_TRANSACTION_ENDPOINT);
+        let resp = self
+            .client
+            .post(endpoint)
+            .json(tx)
+            .send()
+            .await?
+            .json::<SimulateTransactionResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(anyhow!("{}", resp.error)),
+            Some(b) => Ok(b.result),
+        }
+    }
+
     pub async fn send_transactions(&self, txs: &Vec<Transaction>) -> Result<Vec<String>> {
         let endpoint = self.get_endpoint(SEND_MULTIPLE_TRANSACTIONS_ENDPOINT);
         let resp = self
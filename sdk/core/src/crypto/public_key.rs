@@ -1,4 +1,5 @@
 use super::private_key::PrivateKey;
+use crate::data::address::hrp;
 use anyhow::Result;
 use bech32::{self, ToBase32, Variant};
 use serde::{
@@ -21,7 +22,7 @@ impl PublicKey {
     }
 
     pub fn to_address(&self) -> Result<String> {
-        let address = bech32::encode("erd", self.0.to_base32(), Variant::Bech32)?;
+        let address = bech32::encode(&hrp(), self.0.to_base32(), Variant::Bech32)?;
         Ok(address)
     }
 
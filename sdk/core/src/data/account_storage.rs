@@ -14,3 +14,16 @@ pub struct AccountStorageResponse {
     pub error: String,
     pub code: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStorageValue {
+    pub value: String,
+}
+
+// AccountStorageValueResponse holds the storage value endpoint response, value is hex-encoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStorageValueResponse {
+    pub data: Option<AccountStorageValue>,
+    pub error: String,
+    pub code: String,
+}
@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::RwLock};
 
 use crate::crypto::public_key::PublicKey;
 use anyhow::Result;
@@ -8,6 +8,26 @@ use serde::{
     ser::{Serialize, Serializer},
 };
 
+/// The human-readable part of bech32 addresses on the MultiversX networks.
+pub const DEFAULT_HRP: &str = "erd";
+
+static HRP: RwLock<Option<String>> = RwLock::new(None);
+
+/// Changes the human-readable part used when formatting addresses as bech32, for the whole process.
+///
+/// Needed for chains that do not use `erd`, e.g. sovereign chains. Decoding accepts any prefix.
+pub fn set_hrp(hrp: &str) {
+    *HRP.write().unwrap() = Some(hrp.to_string());
+}
+
+/// The human-readable part currently used when formatting addresses as bech32.
+pub fn hrp() -> String {
+    HRP.read()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(|| DEFAULT_HRP.to_string())
+}
+
 #[derive(Clone)]
 pub struct Address([u8; 32]);
 
@@ -30,8 +50,13 @@ impl Address {
         Ok(Self(bits))
     }
 
+    /// Formats the address as bech32, with the configured human-readable part (`erd` by default).
     pub fn to_bech32_string(&self) -> Result<String> {
-        let address = bech32::encode("erd", self.0.to_base32(), Variant::Bech32)?;
+        self.to_bech32_string_with_hrp(&hrp())
+    }
+
+    pub fn to_bech32_string_with_hrp(&self, hrp: &str) -> Result<String> {
+        let address = bech32::encode(hrp, self.0.to_base32(), Variant::Bech32)?;
         Ok(address)
     }
 
@@ -98,4 +123,17 @@ pub mod tests {
             "00000000000000000500226529d6300bbcbcdbee58455351dd2200adff849157"
         );
     }
+
+    #[test]
+    fn test_encode_address_custom_hrp() {
+        let addr = Address::from_bech32_string(
+            "erd1qqqqqqqqqqqqqpgqyfjjn43spw7teklwtpz4x5waygq2mluyj9ts0mdwn6",
+        )
+        .unwrap();
+        let encoded = addr.to_bech32_string_with_hrp("test").unwrap();
+        assert!(encoded.starts_with("test1"));
+
+        let decoded = Address::from_bech32_string(&encoded).unwrap();
+        assert_eq!(decoded.to_bytes(), addr.to_bytes());
+    }
 }
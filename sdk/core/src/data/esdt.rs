@@ -23,6 +23,33 @@ pub struct EsdtBalanceResponse {
     pub code: String,
 }
 
+// EsdtTokenData holds the balance of a single token (fungible or not) of an account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EsdtTokenData {
+    pub token_identifier: String,
+    pub balance: String,
+    #[serde(default)]
+    pub nonce: u64,
+    #[serde(default)]
+    pub attributes: Option<String>,
+}
+
+// EsdtTokenDataWrapper wraps the token data, as returned by the gateway
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EsdtTokenDataWrapper {
+    pub token_data: EsdtTokenData,
+}
+
+// EsdtTokenDataResponse holds the esdt token data endpoint response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EsdtTokenDataResponse {
+    pub data: Option<EsdtTokenDataWrapper>,
+    pub error: String,
+    pub code: String,
+}
+
 // EsdtRolesData holds the esdt roles data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EsdtRolesData {
@@ -36,3 +63,21 @@ pub struct EsdtRolesResponse {
     pub error: String,
     pub code: String,
 }
+
+// EsdtTokenSupply holds the supply of a token, all amounts are in base 10
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EsdtTokenSupply {
+    pub supply: String,
+    pub minted: String,
+    pub burned: String,
+    pub initial_minted: String,
+}
+
+// EsdtTokenSupplyResponse holds the esdt supply endpoint response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EsdtTokenSupplyResponse {
+    pub data: Option<EsdtTokenSupply>,
+    pub error: String,
+    pub code: String,
+}
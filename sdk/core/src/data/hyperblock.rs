@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::{address::Address, transaction::ApiLogs};
+
 // HyperBlock holds a hyper block's details
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -16,6 +18,22 @@ pub struct HyperBlock {
     pub developer_fees: String,
     pub accumulated_fees_in_epoch: String,
     pub developer_fees_in_epoch: String,
+    #[serde(default)]
+    pub transactions: Vec<HyperBlockTransaction>,
+}
+
+// HyperBlockTransaction holds the details of a transaction included in a hyper block
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HyperBlockTransaction {
+    #[serde(rename = "type", default)]
+    pub kind: String,
+    pub hash: Option<String>,
+    pub sender: Option<Address>,
+    pub receiver: Option<Address>,
+    #[serde(default)]
+    pub status: String,
+    pub logs: Option<ApiLogs>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+// Keystore is the password-protected JSON wallet format, also used by the web wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    // missing from the keystores exported by older web wallet versions
+    #[serde(default = "default_keystore_kind")]
+    pub kind: String,
+    pub id: String,
+    pub address: String,
+    pub bech32: String,
+    pub crypto: KeystoreCrypto,
+}
+
+fn default_keystore_kind() -> String {
+    "secretKey".to_string()
+}
+
+// KeystoreCrypto holds the encrypted secret key, together with the encryption parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    pub ciphertext: String,
+    pub cipherparams: KeystoreCipherParams,
+    pub cipher: String,
+    pub kdf: String,
+    pub kdfparams: KeystoreKdfParams,
+    pub mac: String,
+}
+
+// KeystoreCipherParams holds the hex-encoded initialization vector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeystoreCipherParams {
+    pub iv: String,
+}
+
+// KeystoreKdfParams holds the scrypt key derivation parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeystoreKdfParams {
+    pub dklen: usize,
+    pub salt: String,
+    pub n: u32,
+    pub r: u32,
+    pub p: u32,
+}
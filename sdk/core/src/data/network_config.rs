@@ -33,6 +33,56 @@ pub struct NetworkConfig {
     pub start_time: i64,
 }
 
+/// Mainnet values, for use when the network configuration is not retrieved from a gateway.
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        NetworkConfig {
+            chain_id: "1".to_string(),
+            denomination: 18,
+            gas_per_data_byte: 1_500,
+            latest_tag_software_version: String::new(),
+            meta_consensus_group_size: 400,
+            min_gas_limit: 50_000,
+            min_gas_price: 1_000_000_000,
+            min_transaction_version: 1,
+            num_metachain_nodes: 400,
+            num_nodes_in_shard: 400,
+            num_shards_without_meta: 3,
+            round_duration: 6_000,
+            shard_consensus_group_size: 63,
+            start_time: 0,
+        }
+    }
+}
+
+impl NetworkConfig {
+    /// Custom chain id, e.g. of a sovereign chain.
+    pub fn with_chain_id(mut self, chain_id: &str) -> Self {
+        self.chain_id = chain_id.to_string();
+        self
+    }
+
+    pub fn with_min_gas_price(mut self, min_gas_price: u64) -> Self {
+        self.min_gas_price = min_gas_price;
+        self
+    }
+
+    pub fn with_min_gas_limit(mut self, min_gas_limit: u64) -> Self {
+        self.min_gas_limit = min_gas_limit;
+        self
+    }
+
+    pub fn with_gas_per_data_byte(mut self, gas_per_data_byte: u64) -> Self {
+        self.gas_per_data_byte = gas_per_data_byte;
+        self
+    }
+
+    /// The smallest gas limit accepted for a transaction with the given data length.
+    pub fn min_gas_limit_for_data(&self, data_len: usize) -> u64 {
+        self.min_gas_limit + self.gas_per_data_byte * data_len as u64
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkConfigData {
     pub config: NetworkConfig,
@@ -194,3 +194,136 @@ pub struct SendTransactionsResponse {
     pub code: String,
     pub data: Option<SendTransactionsResponseData>,
 }
+
+// SimulationResults holds the outcome of a transaction that was executed, but not broadcasted.
+// Cross-shard transactions have separate results for the sender and for the receiver shard.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationResults {
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub fail_reason: String,
+    #[serde(default)]
+    pub hash: String,
+    /// Kept in the order returned by the gateway, which sends them as a map keyed by hash.
+    #[serde(default, with = "sc_results_by_hash")]
+    pub sc_results: Vec<ApiSmartContractResult>,
+    pub logs: Option<ApiLogs>,
+    pub sender_shard: Option<Box<SimulationResults>>,
+    pub receiver_shard: Option<Box<SimulationResults>>,
+}
+
+mod sc_results_by_hash {
+    use std::fmt;
+
+    use serde::{
+        de::{MapAccess, Visitor},
+        ser::SerializeMap,
+        Deserializer, Serializer,
+    };
+
+    use super::ApiSmartContractResult;
+
+    pub fn serialize<S>(
+        sc_results: &[ApiSmartContractResult],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(sc_results.len()))?;
+        for sc_result in sc_results {
+            map.serialize_entry(&sc_result.hash, sc_result)?;
+        }
+        map.end()
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<ApiSmartContractResult>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ScResultsVisitor)
+    }
+
+    struct ScResultsVisitor;
+
+    impl<'de> Visitor<'de> for ScResultsVisitor {
+        type Value = Vec<ApiSmartContractResult>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map of smart contract results, keyed by hash")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut sc_results = Vec::new();
+            while let Some((_hash, sc_result)) =
+                map.next_entry::<String, ApiSmartContractResult>()?
+            {
+                sc_results.push(sc_result);
+            }
+            Ok(sc_results)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateTransactionData {
+    pub result: SimulationResults,
+}
+
+// SimulateTransactionResponse holds the response received from the network when simulating a transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateTransactionResponse {
+    pub error: String,
+    pub code: String,
+    pub data: Option<SimulateTransactionData>,
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn sc_result_json(hash: &str, nonce: u64) -> String {
+        format!(
+            r#""{hash}": {{
+                "hash": "{hash}",
+                "nonce": {nonce},
+                "value": 0,
+                "receiver": "erd1qqqqqqqqqqqqqpgqyfjjn43spw7teklwtpz4x5waygq2mluyj9ts0mdwn6",
+                "sender": "erd1qqqqqqqqqqqqqpgqyfjjn43spw7teklwtpz4x5waygq2mluyj9ts0mdwn6",
+                "data": "",
+                "prevTxHash": "",
+                "originalTxHash": "",
+                "gasLimit": 0,
+                "gasPrice": 0,
+                "callType": 0
+            }}"#
+        )
+    }
+
+    #[test]
+    fn test_simulation_sc_results_order() {
+        let json = format!(
+            r#"{{"status": "success", "scResults": {{ {}, {}, {} }} }}"#,
+            sc_result_json("ccc", 1),
+            sc_result_json("aaa", 2),
+            sc_result_json("bbb", 3),
+        );
+        let results: SimulationResults = serde_json::from_str(&json).unwrap();
+        let nonces: Vec<u64> = results.sc_results.iter().map(|scr| scr.nonce).collect();
+        assert_eq!(nonces, vec![1, 2, 3]);
+
+        let reserialized = serde_json::to_string(&results).unwrap();
+        let results: SimulationResults = serde_json::from_str(&reserialized).unwrap();
+        let hashes: Vec<&str> = results
+            .sc_results
+            .iter()
+            .map(|scr| scr.hash.as_str())
+            .collect();
+        assert_eq!(hashes, vec!["ccc", "aaa", "bbb"]);
+    }
+}
@@ -1,11 +1,13 @@
 extern crate rand;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use bip39::{Language, Mnemonic};
+use ctr::cipher::{KeyIvInit, StreamCipher};
 use hmac::{Hmac, Mac, NewMac};
 use pbkdf2::pbkdf2;
+use rand::RngCore;
 use serde_json::json;
-use sha2::{Digest, Sha512};
+use sha2::{Digest, Sha256, Sha512};
 use sha3::Keccak256;
 use zeroize::Zeroize;
 
@@ -14,13 +16,28 @@ use crate::{
         private_key::{PrivateKey, PRIVATE_KEY_LENGTH},
         public_key::PublicKey,
     },
-    data::{address::Address, transaction::Transaction},
+    data::{
+        address::Address,
+        keystore::{Keystore, KeystoreCipherParams, KeystoreCrypto, KeystoreKdfParams},
+        transaction::Transaction,
+    },
 };
 
 const EGLD_COIN_TYPE: u32 = 508;
 const HARDENED: u32 = 0x80000000;
 
+const KEYSTORE_VERSION: u32 = 4;
+const KEYSTORE_KIND: &str = "secretKey";
+const KEYSTORE_CIPHER: &str = "aes-128-ctr";
+const KEYSTORE_KDF: &str = "scrypt";
+const KEYSTORE_KDF_LOG_N: u8 = 12;
+const KEYSTORE_KDF_R: u32 = 8;
+const KEYSTORE_KDF_P: u32 = 1;
+const KEYSTORE_KDF_DKLEN: usize = 32;
+
 type HmacSha521 = Hmac<Sha512>;
+type HmacSha256 = Hmac<Sha256>;
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
 
 #[derive(Copy, Clone, Debug)]
 pub struct Wallet {
@@ -93,6 +110,19 @@ impl Wallet {
         PrivateKey::from_bytes(key.as_slice()).unwrap()
     }
 
+    /// Generates a fresh wallet, also returning the mnemonic it was derived from.
+    pub fn generate() -> (Self, Mnemonic) {
+        let mnemonic = Self::generate_mnemonic();
+        let wallet = Self::from_mnemonic(mnemonic.clone(), 0, 0);
+        (wallet, mnemonic)
+    }
+
+    pub fn from_mnemonic(mnemonic: Mnemonic, account: u32, address_index: u32) -> Self {
+        Self {
+            priv_key: Self::get_private_key_from_mnemonic(mnemonic, account, address_index),
+        }
+    }
+
     pub fn from_private_key(priv_key: &str) -> Result<Self> {
         let pri_key = PrivateKey::from_hex_str(priv_key)?;
         Ok(Self { priv_key: pri_key })
@@ -111,6 +141,108 @@ impl Wallet {
         Ok(Self { priv_key: pri_key })
     }
 
+    /// Same format as the PEM files produced by mxpy, readable via `from_pem_file`.
+    pub fn to_pem_file_contents(&self) -> String {
+        let address = self.address().to_bech32_string().unwrap();
+        let public_key = PublicKey::from(&self.priv_key);
+        let contents = self.priv_key.to_string() + public_key.to_string().as_str();
+        pem::encode_config(
+            &pem::Pem {
+                tag: format!("PRIVATE KEY for {address}"),
+                contents: contents.into_bytes(),
+            },
+            pem::EncodeConfig {
+                line_ending: pem::LineEnding::LF,
+            },
+        )
+    }
+
+    pub fn write_pem_file(&self, file_path: &str) -> Result<()> {
+        std::fs::write(file_path, self.to_pem_file_contents())?;
+        Ok(())
+    }
+
+    pub fn from_keystore_file(file_path: &str, password: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(file_path)?;
+        Self::from_keystore_json(&contents, password)
+    }
+
+    pub fn from_keystore_json(contents: &str, password: &str) -> Result<Self> {
+        let keystore: Keystore = serde_json::from_str(contents)?;
+        let crypto = &keystore.crypto;
+        if crypto.cipher != KEYSTORE_CIPHER || crypto.kdf != KEYSTORE_KDF {
+            return Err(anyhow!(
+                "unsupported keystore cipher or kdf: {}, {}",
+                crypto.cipher,
+                crypto.kdf
+            ));
+        }
+
+        let derived_key = keystore_derived_key(password, &crypto.kdfparams)?;
+        let mut ciphertext = hex::decode(&crypto.ciphertext)?;
+        let mut mac = HmacSha256::new_from_slice(&derived_key[16..]).unwrap();
+        mac.update(&ciphertext);
+        mac.verify(&hex::decode(&crypto.mac)?)
+            .map_err(|_| anyhow!("wrong keystore password"))?;
+
+        let iv = hex::decode(&crypto.cipherparams.iv)?;
+        let mut cipher = Aes128Ctr::new_from_slices(&derived_key[..16], &iv)
+            .map_err(|_| anyhow!("invalid keystore initialization vector"))?;
+        cipher.apply_keystream(&mut ciphertext);
+        let priv_key = PrivateKey::from_bytes(&ciphertext)?;
+        ciphertext.zeroize();
+        Ok(Self { priv_key })
+    }
+
+    /// Encrypts the secret key with the password, in the keystore format of the web wallet.
+    pub fn to_keystore_json(&self, password: &str) -> String {
+        let mut rng = rand::thread_rng();
+        let mut salt = [0u8; 32];
+        rng.fill_bytes(&mut salt);
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut iv);
+
+        let kdfparams = KeystoreKdfParams {
+            dklen: KEYSTORE_KDF_DKLEN,
+            salt: hex::encode(salt),
+            n: 1 << KEYSTORE_KDF_LOG_N,
+            r: KEYSTORE_KDF_R,
+            p: KEYSTORE_KDF_P,
+        };
+        let derived_key = keystore_derived_key(password, &kdfparams).unwrap();
+
+        let mut ciphertext = self.priv_key.to_bytes().to_vec();
+        let mut cipher = Aes128Ctr::new_from_slices(&derived_key[..16], &iv).unwrap();
+        cipher.apply_keystream(&mut ciphertext);
+        let mut mac = HmacSha256::new_from_slice(&derived_key[16..]).unwrap();
+        mac.update(&ciphertext);
+
+        let public_key = PublicKey::from(&self.priv_key);
+        let keystore = Keystore {
+            version: KEYSTORE_VERSION,
+            kind: KEYSTORE_KIND.to_string(),
+            id: uuid::Uuid::new_v4().to_string(),
+            address: public_key.to_string(),
+            bech32: self.address().to_bech32_string().unwrap(),
+            crypto: KeystoreCrypto {
+                ciphertext: hex::encode(ciphertext),
+                cipherparams: KeystoreCipherParams {
+                    iv: hex::encode(iv),
+                },
+                cipher: KEYSTORE_CIPHER.to_string(),
+                kdf: KEYSTORE_KDF.to_string(),
+                kdfparams,
+                mac: hex::encode(mac.finalize().into_bytes()),
+            },
+        };
+        serde_json::to_string_pretty(&keystore).unwrap()
+    }
+
+    pub fn write_keystore_file(&self, file_path: &str, password: &str) -> Result<()> {
+        std::fs::write(file_path, self.to_keystore_json(password))?;
+        Ok(())
+    }
+
     pub fn address(&self) -> Address {
         let public_key = PublicKey::from(&self.priv_key);
         Address::from(&public_key)
@@ -131,4 +263,25 @@ impl Wallet {
 
         self.priv_key.sign(tx_bytes)
     }
+
+    /// Signs an arbitrary message, as is.
+    ///
+    /// Useful for off-chain approvals, such as permits, that contracts verify with the ed25519 hook.
+    pub fn sign_message(&self, message: &[u8]) -> [u8; 64] {
+        self.priv_key.sign(message.to_vec())
+    }
+}
+
+fn keystore_derived_key(password: &str, kdfparams: &KeystoreKdfParams) -> Result<Vec<u8>> {
+    if !kdfparams.n.is_power_of_two() || kdfparams.dklen < 32 {
+        return Err(anyhow!("invalid keystore kdf parameters"));
+    }
+
+    let params = scrypt::Params::new(kdfparams.n.trailing_zeros() as u8, kdfparams.r, kdfparams.p)
+        .map_err(|_| anyhow!("invalid keystore kdf parameters"))?;
+    let salt = hex::decode(&kdfparams.salt)?;
+    let mut derived_key = vec![0u8; kdfparams.dklen];
+    scrypt::scrypt(password.as_bytes(), &salt, &params, &mut derived_key)
+        .map_err(|_| anyhow!("keystore key derivation failed"))?;
+    Ok(derived_key)
 }
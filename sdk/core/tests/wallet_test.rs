@@ -48,3 +48,92 @@ fn test_load_from_pem() {
         "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th"
     );
 }
+
+#[test]
+fn test_sign_message() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let signature = wallet.sign_message(b"message to sign");
+    assert_eq!(
+        hex::encode(signature),
+        "7ad6e83562c16f72cc81d5e5fdae1f8418dc89f6c367698335902f89e49e107ce973b2b18300a5984ab3a1b8d7c2faf3626b4a6beb520db40f2c81d69d79020b"
+    );
+}
+
+#[test]
+fn test_generate_and_reload_pem() {
+    let (wallet, mnemonic) = Wallet::generate();
+    assert_eq!(
+        Wallet::from_mnemonic(mnemonic, 0, 0).address().to_bytes(),
+        wallet.address().to_bytes()
+    );
+
+    let pem_path = std::env::temp_dir().join("generated_wallet_test.pem");
+    let pem_path = pem_path.to_str().unwrap();
+    wallet.write_pem_file(pem_path).unwrap();
+    let reloaded = Wallet::from_pem_file(pem_path).unwrap();
+    std::fs::remove_file(pem_path).unwrap();
+
+    assert_eq!(reloaded.address().to_bytes(), wallet.address().to_bytes());
+}
+
+#[test]
+fn test_pem_contents_same_as_mxpy() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let expected = std::fs::read_to_string("tests/alice.pem").unwrap();
+    assert_eq!(wallet.to_pem_file_contents().trim(), expected.trim());
+}
+
+#[test]
+fn test_keystore_round_trip() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let keystore_json = wallet.to_keystore_json("password");
+
+    let reloaded = Wallet::from_keystore_json(&keystore_json, "password").unwrap();
+    assert_eq!(reloaded.address().to_bytes(), wallet.address().to_bytes());
+    assert_eq!(
+        reloaded.sign_message(b"message to sign"),
+        wallet.sign_message(b"message to sign")
+    );
+
+    assert!(Wallet::from_keystore_json(&keystore_json, "wrong password").is_err());
+}
+
+/// Keystore of the alice test wallet, as exported by the web wallet.
+const ALICE_WEB_WALLET_KEYSTORE: &str = r#"{
+    "version": 4,
+    "id": "0dc10c02-b59b-4bac-9710-6b2cfa4284ba",
+    "address": "0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1",
+    "bech32": "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th",
+    "crypto": {
+        "ciphertext": "4c41ef6fdfd52c39b1585a875eb3c86d30a315642d0e35bb8205b6372c1882f135441099b11ff76345a6f3a930b5665aaf9f7325a32c8ccd60081c797aa2d538",
+        "cipherparams": {
+            "iv": "033182afaa1ebaafcde9ccc68a5eac31"
+        },
+        "cipher": "aes-128-ctr",
+        "kdf": "scrypt",
+        "kdfparams": {
+            "dklen": 32,
+            "salt": "4903bd0e7880baa04fc4f886518ac5c672cdc745a6bd13dcec2b6c12e9bffe8d",
+            "n": 4096,
+            "r": 8,
+            "p": 1
+        },
+        "mac": "5b4a6f14ab74ba7ca23db6847e28447f0e6a7724ba9664cf425df707a84f5a8b"
+    }
+}"#;
+
+#[test]
+fn test_load_from_web_wallet_keystore() {
+    let wallet = Wallet::from_keystore_json(ALICE_WEB_WALLET_KEYSTORE, "password").unwrap();
+    let pem_wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    assert_eq!(
+        wallet.address().to_bech32_string().unwrap(),
+        "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th"
+    );
+    assert_eq!(
+        wallet.sign_message(b"message to sign"),
+        pem_wallet.sign_message(b"message to sign")
+    );
+
+    assert!(Wallet::from_keystore_json(ALICE_WEB_WALLET_KEYSTORE, "wrong password").is_err());
+}
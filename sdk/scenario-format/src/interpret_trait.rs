@@ -1,12 +1,13 @@
 use std::path::PathBuf;
 
-use crate::value_interpreter::VMIdentifier;
+use crate::value_interpreter::{FileRegistry, VMIdentifier};
 
 #[derive(Default, Clone)]
 pub struct InterpreterContext {
     pub context_path: PathBuf,
     pub vm_type: VMIdentifier,
     pub allow_missing_files: bool,
+    pub file_registry: FileRegistry,
 }
 
 impl InterpreterContext {
@@ -27,6 +28,14 @@ impl InterpreterContext {
             ..self
         }
     }
+
+    /// Files will be resolved and cached via the given registry.
+    pub fn with_file_registry(self, file_registry: FileRegistry) -> Self {
+        InterpreterContext {
+            file_registry,
+            ..self
+        }
+    }
 }
 
 pub trait InterpretableFrom<T> {
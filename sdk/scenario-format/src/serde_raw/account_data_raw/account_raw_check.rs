@@ -32,6 +32,10 @@ pub struct CheckAccountRaw {
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
     pub code: CheckBytesValueRaw,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
+    pub code_hash: CheckBytesValueRaw,
+
     #[serde(default)]
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
     pub owner: CheckBytesValueRaw,
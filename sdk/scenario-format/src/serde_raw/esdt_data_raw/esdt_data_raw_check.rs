@@ -13,10 +13,10 @@ pub struct CheckEsdtDataRaw {
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
     pub last_nonce: CheckBytesValueRaw,
 
-    /// Currently not actually checked anywhere.
+    /// Missing means the roles are not checked, an empty list means no roles are expected.
     #[serde(default)]
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub roles: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<String>>,
 
     #[serde(default)]
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::serde_raw::ValueSubTree;
+
+/// Token-level properties, not tied to a single account.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EsdtGlobalSettingsRaw {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limited_transfer: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_nonce: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_decimals: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub roles: BTreeMap<String, Vec<String>>,
+}
@@ -1,5 +1,6 @@
 mod esdt_data_raw_check;
 mod esdt_full_raw;
+mod esdt_global_settings_raw;
 mod esdt_instance_raw_check;
 mod esdt_instances_raw_check;
 mod esdt_map_contents_raw_check;
@@ -10,6 +11,7 @@ mod instance_raw;
 
 pub use esdt_data_raw_check::*;
 pub use esdt_full_raw::*;
+pub use esdt_global_settings_raw::*;
 pub use esdt_instance_raw_check::*;
 pub use esdt_instances_raw_check::*;
 pub use esdt_map_contents_raw_check::*;
@@ -3,14 +3,42 @@ use std::fmt;
 
 use serde::{
     de::{self, Deserializer, SeqAccess, Visitor},
-    ser::{SerializeSeq, Serializer},
+    ser::{SerializeMap, SerializeSeq, Serializer},
     Deserialize, Serialize,
 };
 
+const UNORDERED_KEY: &str = "unordered";
+const LIST_KEY: &str = "list";
+
+/// A list of expected logs.
+///
+/// Can be written either as a plain list, which is matched in order,
+/// or as an object, `{ "unordered": true, "list": [...] }`, which is matched in any order.
+/// In both cases, a trailing `"+"` in the list allows additional logs.
 #[derive(Default)]
 pub struct CheckLogListRaw {
     pub list: Vec<CheckLogRaw>,
     pub more_allowed_at_end: bool,
+    pub unordered: bool,
+}
+
+/// Serializes just the list part, including the trailing `"+"`.
+struct CheckLogListSeq<'a>(&'a CheckLogListRaw);
+
+impl<'a> Serialize for CheckLogListSeq<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.list.len()))?;
+        for item in &self.0.list {
+            seq.serialize_element(item)?;
+        }
+        if self.0.more_allowed_at_end {
+            seq.serialize_element("+")?;
+        }
+        seq.end()
+    }
 }
 
 #[derive(Default)]
@@ -39,16 +67,13 @@ impl Serialize for CheckLogsRaw {
         match self {
             CheckLogsRaw::Unspecified => serializer.serialize_str(""),
             CheckLogsRaw::Star => serializer.serialize_str("*"),
-            CheckLogsRaw::List(l) => {
-                let mut seq = serializer.serialize_seq(Some(l.list.len()))?;
-                for item in &l.list {
-                    seq.serialize_element(item)?;
-                }
-                if l.more_allowed_at_end {
-                    seq.serialize_element("+")?;
-                }
-                seq.end()
+            CheckLogsRaw::List(l) if l.unordered => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry(UNORDERED_KEY, &true)?;
+                map.serialize_entry(LIST_KEY, &CheckLogListSeq(l))?;
+                map.end()
             },
+            CheckLogsRaw::List(l) => CheckLogListSeq(l).serialize(serializer),
         }
     }
 }
@@ -100,6 +125,38 @@ impl<'de> Deserialize<'de> for CheckLogElement {
     }
 }
 
+fn check_log_list_from_elements<E>(elements: Vec<CheckLogElement>) -> Result<CheckLogListRaw, E>
+where
+    E: de::Error,
+{
+    let mut check_list = CheckLogListRaw::default();
+    for element in elements {
+        match element {
+            CheckLogElement::Log(log) => {
+                if check_list.more_allowed_at_end {
+                    return Err(de::Error::custom(
+                        "in check log list \"+\" can only be placed last",
+                    ));
+                }
+                check_list.list.push(log);
+            },
+            CheckLogElement::Plus => {
+                check_list.more_allowed_at_end = true;
+            },
+        }
+    }
+    Ok(check_list)
+}
+
+/// Temporary value, just for loading the object form of the check log list.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CheckLogListObject {
+    #[serde(default)]
+    unordered: bool,
+    list: Vec<CheckLogElement>,
+}
+
 struct CheckLogsVisitor;
 
 impl<'de> Visitor<'de> for CheckLogsVisitor {
@@ -125,24 +182,21 @@ impl<'de> Visitor<'de> for CheckLogsVisitor {
     where
         A: SeqAccess<'de>,
     {
-        let mut check_list = CheckLogListRaw::default();
-
-        while let Some(entry) = seq.next_element::<CheckLogElement>()? {
-            match entry {
-                CheckLogElement::Log(log) => {
-                    if check_list.more_allowed_at_end {
-                        return Err(de::Error::custom(
-                            "in check log list \"+\" can only be placed last",
-                        ));
-                    }
-                    check_list.list.push(log);
-                },
-                CheckLogElement::Plus => {
-                    check_list.more_allowed_at_end = true;
-                },
-            }
+        let mut elements = Vec::new();
+        while let Some(element) = seq.next_element::<CheckLogElement>()? {
+            elements.push(element);
         }
 
+        check_log_list_from_elements(elements).map(CheckLogsRaw::List)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let object = CheckLogListObject::deserialize(de::value::MapAccessDeserializer::new(map))?;
+        let mut check_list = check_log_list_from_elements(object.list)?;
+        check_list.unordered = object.unordered;
         Ok(CheckLogsRaw::List(check_list))
     }
 }
@@ -3,8 +3,8 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 
 use crate::serde_raw::{
-    AccountRaw, BlockInfoRaw, CheckAccountsRaw, NewAddressRaw, TxCallRaw, TxDeployRaw, TxExpectRaw,
-    TxQueryRaw, TxTransferRaw, TxValidatorRewardRaw, ValueSubTree,
+    AccountRaw, BlockInfoRaw, CheckAccountsRaw, EsdtGlobalSettingsRaw, NewAddressRaw, TxCallRaw,
+    TxDeployRaw, TxExpectRaw, TxQueryRaw, TxTransferRaw, TxValidatorRewardRaw, ValueSubTree,
 };
 
 #[derive(Serialize, Deserialize)]
@@ -37,6 +37,10 @@ pub enum StepRaw {
         #[serde(skip_serializing_if = "Vec::is_empty")]
         new_token_identifiers: Vec<String>,
 
+        #[serde(default)]
+        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        esdt_global_settings: BTreeMap<String, EsdtGlobalSettingsRaw>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Vec::is_empty")]
         block_hashes: Vec<ValueSubTree>,
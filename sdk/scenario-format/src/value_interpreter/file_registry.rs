@@ -0,0 +1,96 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Component, Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+/// Resolves and loads the files referenced via the `file:` prefix.
+///
+/// Relative context paths are resolved against the workspace root, if one is configured,
+/// so that results do not depend on the working directory the tests are started from.
+///
+/// Loaded files are cached, since the same contract code is usually referenced by many steps.
+/// Clones share the same cache.
+#[derive(Default, Clone, Debug)]
+pub struct FileRegistry {
+    workspace_root: Option<PathBuf>,
+    cache: Arc<Mutex<HashMap<PathBuf, Vec<u8>>>>,
+}
+
+impl FileRegistry {
+    pub fn new() -> Self {
+        FileRegistry::default()
+    }
+
+    pub fn with_workspace_root(self, workspace_root: PathBuf) -> Self {
+        FileRegistry {
+            workspace_root: Some(workspace_root),
+            ..self
+        }
+    }
+
+    pub fn workspace_root(&self) -> Option<&Path> {
+        self.workspace_root.as_deref()
+    }
+
+    /// The full path of a file, as referenced from a given context path.
+    pub fn resolve_path(&self, context_path: &Path, file_path: &str) -> PathBuf {
+        let mut path_buf = match &self.workspace_root {
+            Some(workspace_root) if context_path.is_relative() => workspace_root.join(context_path),
+            _ => context_path.to_path_buf(),
+        };
+        path_buf.push(file_path);
+        normalize_path(path_buf)
+    }
+
+    /// Loads a file, from the cache if it was loaded before.
+    ///
+    /// Missing files are not cached, they might get created later on.
+    pub fn load(&self, path: &Path) -> Option<Vec<u8>> {
+        let mut cache = self.cache.lock().unwrap();
+        if let Some(contents) = cache.get(path) {
+            return Some(contents.clone());
+        }
+
+        let contents = fs::read(path).ok()?;
+        cache.insert(path.to_path_buf(), contents.clone());
+        Some(contents)
+    }
+
+    /// Number of files currently held in the cache.
+    pub fn num_cached_files(&self) -> usize {
+        self.cache.lock().unwrap().len()
+    }
+}
+
+/// Improve the path to try remove and solve .. token.
+///
+/// This assumes that `a/b/../c` is `a/c` which might be different from
+/// what the OS would have chosen when b is a link. This is OK
+/// for broot verb arguments but can't be generally used elsewhere
+///
+/// This function ensures a given path ending with '/' still
+/// ends with '/' after normalization.
+///
+/// Source: https://stackoverflow.com/questions/68231306/stdfscanonicalize-for-files-that-dont-exist
+fn normalize_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    let ends_with_slash = path.as_ref().to_str().map_or(false, |s| s.ends_with('/'));
+    let mut normalized = PathBuf::new();
+    for component in path.as_ref().components() {
+        match &component {
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            },
+            _ => {
+                normalized.push(component);
+            },
+        }
+    }
+    if ends_with_slash {
+        normalized.push("");
+    }
+    normalized
+}
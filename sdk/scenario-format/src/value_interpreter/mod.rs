@@ -1,4 +1,5 @@
 mod file_loader;
+mod file_registry;
 pub(crate) mod functions;
 mod interpreter;
 mod parse_num;
@@ -6,6 +7,7 @@ mod prefixes;
 mod reconstructor;
 mod vm_identifier;
 
+pub use file_registry::FileRegistry;
 pub use functions::keccak256;
 pub use interpreter::{interpret_string, interpret_subtree};
 pub use reconstructor::{
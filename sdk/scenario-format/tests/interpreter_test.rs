@@ -1,5 +1,6 @@
 use multiversx_chain_scenario_format::{
-    interpret_trait::InterpreterContext, value_interpreter::interpret_string,
+    interpret_trait::InterpreterContext,
+    value_interpreter::{interpret_string, FileRegistry},
 };
 
 const EMPTY: Vec<u8> = Vec::<u8>::new();
@@ -292,3 +293,16 @@ fn test_bech32() {
         )
     );
 }
+
+#[test]
+fn test_file_relative_to_workspace_root() {
+    let file_registry = FileRegistry::new().with_workspace_root(env!("CARGO_MANIFEST_DIR").into());
+    let context = InterpreterContext::default()
+        .with_dir("tests".into())
+        .with_file_registry(file_registry.clone());
+    assert_eq!(
+        include_bytes!("interpreter_test.rs").to_vec(),
+        interpret_string("file:interpreter_test.rs", &context)
+    );
+    assert_eq!(file_registry.num_cached_files(), 1);
+}
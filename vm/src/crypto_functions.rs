@@ -1,9 +1,11 @@
+use blake2::{digest::consts::U32, Blake2b};
 use ed25519_dalek::*;
 use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
 pub const SHA256_RESULT_LEN: usize = 32;
 pub const KECCAK256_RESULT_LEN: usize = 32;
+pub const BLAKE2B_256_RESULT_LEN: usize = 32;
 
 pub fn sha256(data: &[u8]) -> [u8; SHA256_RESULT_LEN] {
     let mut hasher = Sha256::new();
@@ -17,6 +19,13 @@ pub fn keccak256(data: &[u8]) -> [u8; KECCAK256_RESULT_LEN] {
     hasher.finalize().into()
 }
 
+/// Also used by the protocol for computing contract code hashes.
+pub fn blake2b_256(data: &[u8]) -> [u8; BLAKE2B_256_RESULT_LEN] {
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
 pub fn verify_ed25519(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
     let key_32: [u8; 32] = if let Ok(key_32) = key.try_into() {
         key_32
@@ -1,3 +1,5 @@
+use std::{collections::HashMap, fmt};
+
 use crate::types::VMAddress;
 
 pub fn address_hex(address: &VMAddress) -> String {
@@ -29,3 +31,26 @@ pub fn verbose_hex_list(values: &[Vec<u8>]) -> String {
 pub fn bytes_to_string(bytes: &[u8]) -> String {
     String::from_utf8(bytes.to_vec()).unwrap_or_else(|_| verbose_hex(bytes))
 }
+
+/// Entries of a map keyed by bytes, sorted by key, so that dumps of the same state are always identical.
+pub fn sorted_by_bytes_key<V>(map: &HashMap<Vec<u8>, V>) -> Vec<(&Vec<u8>, &V)> {
+    let mut entries: Vec<(&Vec<u8>, &V)> = map.iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    entries
+}
+
+/// Entries of a map keyed by address, sorted by address bytes, so that dumps of the same state are always identical.
+pub fn sorted_by_address<V>(map: &HashMap<VMAddress, V>) -> Vec<(&VMAddress, &V)> {
+    let mut entries: Vec<(&VMAddress, &V)> = map.iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+    entries
+}
+
+/// Prints already sorted map entries as a map, in `Debug` output.
+pub struct DebugMap<'a, K, V>(pub Vec<(&'a K, &'a V)>);
+
+impl<'a, K: fmt::Debug, V: fmt::Debug> fmt::Debug for DebugMap<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.0.iter().copied()).finish()
+    }
+}
@@ -11,8 +11,9 @@ use super::{
 };
 use crate::{
     tx_execution::BlockchainVMRef,
-    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    tx_mock::{BlockchainUpdate, GasCost, TxCache, TxGasUsage, TxInput, TxPanic, TxResult},
     types::EsdtLocalRole,
+    world_mock::{GasSchedule, ProtocolFeature},
 };
 
 /// Container for builtin function logic.
@@ -95,14 +96,20 @@ impl<'a> BuiltinFunctionCall<'a> {
             ESDT_NFT_ADD_QUANTITY_FUNC_NAME => {
                 self.check_role_and_execute(EsdtLocalRole::NftAddQuantity, ESDTNftAddQuantity, f)
             },
-            ESDT_NFT_ADD_URI_FUNC_NAME => {
+            ESDT_NFT_ADD_URI_FUNC_NAME
+                if self.is_feature_active(ProtocolFeature::EsdtNftAddUri) =>
+            {
                 self.check_role_and_execute(EsdtLocalRole::NftAddUri, ESDTNftAddUri, f)
             },
-            ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME => self.check_role_and_execute(
-                EsdtLocalRole::NftUpdateAttributes,
-                ESDTNftUpdateAttributes,
-                f,
-            ),
+            ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME
+                if self.is_feature_active(ProtocolFeature::EsdtNftUpdateAttributes) =>
+            {
+                self.check_role_and_execute(
+                    EsdtLocalRole::NftUpdateAttributes,
+                    ESDTNftUpdateAttributes,
+                    f,
+                )
+            },
 
             ESDT_MULTI_TRANSFER_FUNC_NAME => self.execute_bf(ESDTMultiTransfer, f),
             ESDT_NFT_TRANSFER_FUNC_NAME => self.execute_bf(ESDTNftTransfer, f),
@@ -119,12 +126,25 @@ impl<'a> BuiltinFunctionCall<'a> {
         }
     }
 
+    /// Builtin functions that are not yet active are treated as regular function calls.
+    fn is_feature_active(&self, feature: ProtocolFeature) -> bool {
+        self.tx_cache.blockchain_ref().is_feature_active(feature)
+    }
+
     fn execute_bf<B, F>(self, builtin_func: B, f: F) -> (TxResult, BlockchainUpdate)
     where
         B: BuiltinFunction,
         F: FnOnce(),
     {
-        builtin_func.execute(self.tx_input, self.tx_cache, self.vm, f)
+        let gas_schedule = self.tx_cache.blockchain_ref().gas_schedule;
+        let gas_limit = self.tx_input.gas_limit;
+        let (tx_result, blockchain_updates) =
+            builtin_func.execute(self.tx_input, self.tx_cache, self.vm, f);
+        if let Some(gas_schedule) = gas_schedule {
+            use_builtin_call_gas(tx_result, blockchain_updates, &gas_schedule, gas_limit)
+        } else {
+            (tx_result, blockchain_updates)
+        }
     }
 
     fn check_role_and_execute<B, F>(
@@ -148,10 +168,36 @@ impl<'a> BuiltinFunctionCall<'a> {
     }
 }
 
+/// The builtin function cost comes on top of the gas used by the execution it triggers, if any.
+fn use_builtin_call_gas(
+    mut tx_result: TxResult,
+    blockchain_updates: BlockchainUpdate,
+    gas_schedule: &GasSchedule,
+    gas_limit: u64,
+) -> (TxResult, BlockchainUpdate) {
+    let mut gas_usage = tx_result.gas_usage.unwrap_or(TxGasUsage {
+        gas_limit,
+        gas_used: 0,
+    });
+    if gas_usage.use_gas(gas_schedule.cost(GasCost::BuiltinCall)) {
+        tx_result.gas_usage = Some(gas_usage);
+        (tx_result, blockchain_updates)
+    } else {
+        let mut out_of_gas_result =
+            TxResult::from_panic_obj(&TxPanic::out_of_gas("not enough gas"));
+        out_of_gas_result.gas_usage = Some(gas_usage);
+        (out_of_gas_result, BlockchainUpdate::empty())
+    }
+}
+
+/// Calls without a token identifier are let through, so the builtin function reports the missing arguments.
 fn check_allowed_to_execute(role: EsdtLocalRole, tx_input: &TxInput, tx_cache: &TxCache) -> bool {
-    let token_identifier = tx_input.args[0].clone();
+    let token_identifier = match tx_input.args.first() {
+        Some(token_identifier) => token_identifier,
+        None => return true,
+    };
     let available_roles = tx_cache.with_account_mut(&tx_input.to, |account| {
-        account.esdt.get_roles(&token_identifier)
+        account.esdt.get_roles(token_identifier)
     });
     available_roles
         .iter()
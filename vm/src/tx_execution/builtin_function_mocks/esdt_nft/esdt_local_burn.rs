@@ -37,8 +37,17 @@ impl BuiltinFunction for ESDTLocalBurn {
         if let Err(err) = subtract_result {
             return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
         }
+        // the supply is only tracked for tokens in the registry, no entry is created for the others
+        if tx_cache
+            .get_esdt_global_settings(&token_identifier)
+            .is_some()
+        {
+            tx_cache.with_esdt_global_settings_mut(&token_identifier, |settings| {
+                settings.burnt_value += &value;
+            });
+        }
 
-        let esdt_nft_create_log = TxLog {
+        let esdt_local_burn_log = TxLog {
             address: tx_input.from,
             endpoint: ESDT_LOCAL_BURN_FUNC_NAME.into(),
             topics: vec![token_identifier.to_vec(), Vec::new(), value.to_bytes_be()],
@@ -47,7 +56,7 @@ impl BuiltinFunction for ESDTLocalBurn {
 
         let tx_result = TxResult {
             result_status: 0,
-            result_logs: vec![esdt_nft_create_log],
+            result_logs: vec![esdt_local_burn_log],
             ..Default::default()
         };
 
@@ -41,8 +41,17 @@ impl BuiltinFunction for ESDTLocalMint {
             &value,
             EsdtInstanceMetadata::default(),
         );
+        // the supply is only tracked for tokens in the registry, no entry is created for the others
+        if tx_cache
+            .get_esdt_global_settings(&token_identifier)
+            .is_some()
+        {
+            tx_cache.with_esdt_global_settings_mut(&token_identifier, |settings| {
+                settings.minted_value += &value;
+            });
+        }
 
-        let esdt_nft_create_log = TxLog {
+        let esdt_local_mint_log = TxLog {
             address: tx_input.from,
             endpoint: ESDT_LOCAL_MINT_FUNC_NAME.into(),
             topics: vec![token_identifier.to_vec(), Vec::new(), value.to_bytes_be()],
@@ -51,7 +60,7 @@ impl BuiltinFunction for ESDTLocalMint {
 
         let tx_result = TxResult {
             result_status: 0,
-            result_logs: vec![esdt_nft_create_log],
+            result_logs: vec![esdt_local_mint_log],
             ..Default::default()
         };
 
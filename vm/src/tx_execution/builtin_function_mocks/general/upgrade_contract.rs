@@ -1,6 +1,9 @@
 use crate::tx_execution::{builtin_function_names::UPGRADE_CONTRACT_FUNC_NAME, BlockchainVMRef};
 
-use crate::tx_mock::{BlockchainUpdate, TxCache, TxFunctionName, TxInput, TxResult};
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxFunctionName, TxInput, TxResult},
+    types::VMCodeMetadata,
+};
 
 use super::super::builtin_func_trait::BuiltinFunction;
 
@@ -29,9 +32,15 @@ impl BuiltinFunction for UpgradeContract {
         }
 
         let new_code = tx_input.args[0].clone();
-
-        // tx_input.args[1] is the code metadata, we ignore for now
-        // TODO: model code metadata in Mandos
+        let code_metadata = match <[u8; 2]>::try_from(tx_input.args[1].as_slice()) {
+            Ok(code_metadata_bytes) => VMCodeMetadata::from(code_metadata_bytes),
+            Err(_) => {
+                return (
+                    TxResult::from_vm_error("upgradeContract code metadata must be 2 bytes long"),
+                    BlockchainUpdate::empty(),
+                )
+            },
+        };
 
         let args = if tx_input.args.len() > 2 {
             tx_input.args[2..].to_vec()
@@ -41,6 +50,7 @@ impl BuiltinFunction for UpgradeContract {
 
         tx_cache.with_account_mut(&tx_input.to, |account| {
             account.contract_path = Some(new_code);
+            account.code_metadata = code_metadata;
         });
 
         let exec_input = TxInput {
@@ -48,7 +58,7 @@ impl BuiltinFunction for UpgradeContract {
             to: tx_input.to,
             egld_value: tx_input.egld_value,
             esdt_values: Vec::new(),
-            func_name: TxFunctionName::INIT,
+            func_name: TxFunctionName::UPGRADE,
             args,
             gas_limit: tx_input.gas_limit,
             gas_price: tx_input.gas_price,
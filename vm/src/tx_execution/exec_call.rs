@@ -2,11 +2,11 @@ use crate::{
     tx_mock::{
         async_call_tx_input, async_callback_tx_input, async_promise_tx_input, merge_results,
         AsyncCallTxData, BlockchainUpdate, Promise, TxCache, TxContext, TxContextStack, TxInput,
-        TxPanic, TxResult, TxResultCalls,
+        TxPanic, TxResult, TxResultCalls, TxTokenTransfer,
     },
-    types::VMAddress,
+    types::{VMAddress, VMCodeMetadata},
     with_shared::Shareable,
-    world_mock::{AccountData, AccountEsdt, BlockchainState},
+    world_mock::{AccountData, AccountEsdt, BlockchainState, CrossShardStep, CrossShardValue},
 };
 use num_bigint::BigUint;
 use num_traits::Zero;
@@ -126,14 +126,10 @@ impl BlockchainVMRef {
             );
             (async_result, callback_result)
         } else {
-            let result = self.insert_ghost_account(&async_data, state);
-            match result {
-                Ok(blockchain_updates) => {
-                    state.commit_updates(blockchain_updates);
-                    (TxResult::empty(), TxResult::empty())
-                },
-                Err(err) => (TxResult::from_panic_obj(&err), TxResult::empty()),
-            }
+            (
+                self.call_ghost_account(&async_data, state),
+                TxResult::empty(),
+            )
         }
     }
 
@@ -158,6 +154,25 @@ impl BlockchainVMRef {
         // the async call also gets reset
         if tx_result.result_status == 0 {
             if let Some(async_data) = pending_calls.async_call {
+                if state.is_cross_shard(&async_data.from, &async_data.to) {
+                    let transfers = self.cross_shard_token_transfers(&async_data);
+                    match self.debit_cross_shard_value(
+                        &async_data.from,
+                        &async_data.call_value,
+                        transfers,
+                        state,
+                    ) {
+                        Ok(value) => state.queue_cross_shard_step(CrossShardStep::AsyncCall {
+                            async_data,
+                            value,
+                        }),
+                        Err(err) => {
+                            tx_result = merge_results(tx_result, TxResult::from_panic_obj(&err))
+                        },
+                    }
+                    return tx_result;
+                }
+
                 let (async_result, callback_result) =
                     self.execute_async_call_and_callback(async_data, state);
 
@@ -171,6 +186,26 @@ impl BlockchainVMRef {
         // calling all promises
         // the promises are also reset
         for promise in pending_calls.promises {
+            if state.is_cross_shard(&contract_address, &promise.call.to) {
+                let transfers = self.cross_shard_token_transfers(&promise.call);
+                match self.debit_cross_shard_value(
+                    &promise.call.from,
+                    &promise.call.call_value,
+                    transfers,
+                    state,
+                ) {
+                    Ok(value) => state.queue_cross_shard_step(CrossShardStep::PromiseCall {
+                        caller: contract_address.clone(),
+                        promise,
+                        value,
+                    }),
+                    Err(err) => {
+                        tx_result = merge_results(tx_result, TxResult::from_panic_obj(&err))
+                    },
+                }
+                continue;
+            }
+
             let (async_result, callback_result) =
                 self.execute_promise_call_and_callback(&contract_address, &promise, state);
 
@@ -196,6 +231,10 @@ impl BlockchainVMRef {
             );
 
             let callback_input = async_promise_tx_input(address, promise, &async_result);
+            if callback_input.func_name.is_empty() {
+                // no callback registered for this outcome
+                return (async_result, TxResult::empty());
+            }
             let callback_result = self.execute_sc_call_lambda(
                 callback_input,
                 state,
@@ -207,14 +246,212 @@ impl BlockchainVMRef {
             );
             (async_result, callback_result)
         } else {
-            let result = self.insert_ghost_account(&promise.call, state);
-            match result {
-                Ok(blockchain_updates) => {
-                    state.commit_updates(blockchain_updates);
-                    (TxResult::empty(), TxResult::empty())
-                },
-                Err(err) => (TxResult::from_panic_obj(&err), TxResult::empty()),
+            (
+                self.call_ghost_account(&promise.call, state),
+                TxResult::empty(),
+            )
+        }
+    }
+
+    /// Executes the cross-shard async calls and callbacks that are due in the current block,
+    /// in the order in which they were sent.
+    ///
+    /// Callbacks, as well as any cross-shard calls that these produce, are queued for the next block.
+    pub fn execute_due_cross_shard_steps(
+        &self,
+        state: &mut Shareable<BlockchainState>,
+    ) -> Vec<TxResult> {
+        let block_nonce = state.current_block_info.block_nonce;
+        let due_steps = state.cross_shard_queue.take_due(block_nonce);
+        due_steps
+            .into_iter()
+            .map(|step| self.execute_cross_shard_step(step, state))
+            .collect()
+    }
+
+    fn execute_cross_shard_step(
+        &self,
+        step: CrossShardStep,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        match step {
+            CrossShardStep::AsyncCall { async_data, value } => {
+                // the call performs the transfer itself, so the value in flight goes back to the sender first
+                self.credit_cross_shard_value(&async_data.from, &value, state);
+                if !state.accounts.contains_key(&async_data.to) {
+                    return self.call_ghost_account(&async_data, state);
+                }
+                let async_result = self.sc_call_with_async_and_callback(
+                    async_call_tx_input(&async_data),
+                    state,
+                    execute_current_tx_context_input,
+                );
+                let mut callback_input =
+                    async_callback_tx_input(&async_data, &async_result, &self.builtin_functions);
+                let refund = self.cross_shard_refund(&async_data.from, &async_result, value, state);
+                if async_result.result_status != 0 {
+                    callback_input.callback_payments.egld_value = refund.egld_value.clone();
+                    callback_input.callback_payments.esdt_values = refund
+                        .esdt_values
+                        .iter()
+                        .map(|(transfer, _)| transfer.clone())
+                        .collect();
+                }
+                state.queue_cross_shard_step(CrossShardStep::Callback {
+                    callback_input,
+                    refund,
+                });
+                async_result
+            },
+            CrossShardStep::PromiseCall {
+                caller,
+                promise,
+                value,
+            } => {
+                self.credit_cross_shard_value(&promise.call.from, &value, state);
+                if !state.accounts.contains_key(&promise.call.to) {
+                    return self.call_ghost_account(&promise.call, state);
+                }
+                let async_result = self.sc_call_with_async_and_callback(
+                    async_call_tx_input(&promise.call),
+                    state,
+                    execute_current_tx_context_input,
+                );
+                let callback_input = async_promise_tx_input(&caller, &promise, &async_result);
+                let refund =
+                    self.cross_shard_refund(&promise.call.from, &async_result, value, state);
+                if !callback_input.func_name.is_empty() || !refund.is_empty() {
+                    state.queue_cross_shard_step(CrossShardStep::Callback {
+                        callback_input,
+                        refund,
+                    });
+                }
+                async_result
+            },
+            CrossShardStep::Callback {
+                callback_input,
+                refund,
+            } => {
+                self.credit_cross_shard_value(&callback_input.to, &refund, state);
+                if callback_input.func_name.is_empty() {
+                    // only the refund of a promise without an error callback
+                    return TxResult::empty();
+                }
+                let callback_result = self.execute_sc_call_lambda(
+                    callback_input,
+                    state,
+                    execute_current_tx_context_input,
+                );
+                // same limitations as for the callbacks executed in the same shard
+                assert!(
+                    callback_result.pending_calls.async_call.is_none(),
+                    "successive asyncs currently not supported"
+                );
+                assert!(
+                    callback_result.pending_calls.promises.is_empty(),
+                    "successive promises currently not supported"
+                );
+                callback_result
+            },
+        }
+    }
+
+    /// The ESDT transfers performed by a cross-shard call, if it is a transfer builtin function.
+    fn cross_shard_token_transfers(&self, async_data: &AsyncCallTxData) -> Vec<TxTokenTransfer> {
+        self.builtin_functions
+            .extract_token_transfers(&async_call_tx_input(async_data))
+            .transfers
+    }
+
+    /// The value of a cross-shard call leaves the sender right away, it is only credited once the call arrives.
+    fn debit_cross_shard_value(
+        &self,
+        address: &VMAddress,
+        egld_value: &BigUint,
+        transfers: Vec<TxTokenTransfer>,
+        state: &mut Shareable<BlockchainState>,
+    ) -> Result<CrossShardValue, TxPanic> {
+        let (value, blockchain_updates) = state.with_shared(|state_arc| {
+            let tx_cache = TxCache::new(state_arc);
+            tx_cache.subtract_egld_balance(address, egld_value)?;
+            let mut esdt_values = Vec::new();
+            for transfer in transfers {
+                let metadata = tx_cache.subtract_esdt_balance(
+                    address,
+                    &transfer.token_identifier,
+                    transfer.nonce,
+                    &transfer.value,
+                )?;
+                esdt_values.push((transfer, metadata));
             }
+            let value = CrossShardValue {
+                egld_value: egld_value.clone(),
+                esdt_values,
+            };
+            Ok::<_, TxPanic>((value, tx_cache.into_blockchain_updates()))
+        })?;
+        state.commit_updates(blockchain_updates);
+        Ok(value)
+    }
+
+    fn credit_cross_shard_value(
+        &self,
+        address: &VMAddress,
+        value: &CrossShardValue,
+        state: &mut Shareable<BlockchainState>,
+    ) {
+        if value.is_empty() {
+            return;
+        }
+        let blockchain_updates = state.with_shared(|state_arc| {
+            let tx_cache = TxCache::new(state_arc);
+            tx_cache.increase_egld_balance(address, &value.egld_value);
+            for (transfer, metadata) in &value.esdt_values {
+                tx_cache.increase_esdt_balance(
+                    address,
+                    &transfer.token_identifier,
+                    transfer.nonce,
+                    &transfer.value,
+                    metadata.clone(),
+                );
+            }
+            tx_cache.into_blockchain_updates()
+        });
+        state.commit_updates(blockchain_updates);
+    }
+
+    /// A failed call gets reverted, leaving its value on the sender.
+    /// The value is taken out again, to travel back with the callback.
+    fn cross_shard_refund(
+        &self,
+        sender: &VMAddress,
+        async_result: &TxResult,
+        value: CrossShardValue,
+        state: &mut Shareable<BlockchainState>,
+    ) -> CrossShardValue {
+        if async_result.result_status == 0 || value.is_empty() {
+            return CrossShardValue::default();
+        }
+        let transfers = value
+            .esdt_values
+            .into_iter()
+            .map(|(transfer, _)| transfer)
+            .collect();
+        self.debit_cross_shard_value(sender, &value.egld_value, transfers, state)
+            .expect("value of the failed call should have been reverted to the sender")
+    }
+
+    fn call_ghost_account(
+        &self,
+        async_data: &AsyncCallTxData,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        match self.insert_ghost_account(async_data, state) {
+            Ok(blockchain_updates) => {
+                state.commit_updates(blockchain_updates);
+                TxResult::empty()
+            },
+            Err(err) => TxResult::from_panic_obj(&err),
         }
     }
 
@@ -236,6 +473,7 @@ impl BlockchainVMRef {
                 storage: HashMap::new(),
                 contract_path: None,
                 contract_owner: None,
+                code_metadata: VMCodeMetadata::DEFAULT,
                 developer_rewards: BigUint::zero(),
             });
             Ok(tx_cache.into_blockchain_updates())
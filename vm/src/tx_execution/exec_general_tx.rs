@@ -6,8 +6,10 @@ use crate::{
         BlockchainUpdate, TxCache, TxContext, TxContextStack, TxFunctionName, TxInput, TxLog,
         TxResult,
     },
-    types::VMAddress,
+    types::{VMAddress, VMCodeMetadata},
+    vm_err_msg,
     with_shared::Shareable,
+    world_mock::ProtocolFeature,
 };
 
 use super::{is_system_sc_address, BlockchainVMRef};
@@ -27,6 +29,27 @@ fn should_execute_sc_call(tx_input: &TxInput) -> bool {
     !tx_input.func_name.is_empty()
 }
 
+/// Plain value transfers to contracts need the payable flag,
+/// or the payable by SC flag, if they come from another contract.
+fn is_rejected_as_non_payable(tx_input: &TxInput, tx_cache: &TxCache) -> bool {
+    let has_value = !tx_input.egld_value.is_zero() || !tx_input.esdt_values.is_empty();
+    if !has_value || !tx_input.func_name.is_empty() || !tx_input.to.is_smart_contract_address() {
+        return false;
+    }
+
+    if !tx_cache
+        .blockchain_ref()
+        .is_feature_active(ProtocolFeature::PayableCheck)
+    {
+        return false;
+    }
+
+    let code_metadata = tx_cache.with_account(&tx_input.to, |account| account.code_metadata);
+    let payable_by_sender = code_metadata.is_payable()
+        || (tx_input.from.is_smart_contract_address() && code_metadata.is_payable_by_sc());
+    !payable_by_sender
+}
+
 impl BlockchainVMRef {
     /// Executes without builtin functions, directly on the contract or the given lambda closure.
     pub fn default_execution<F>(
@@ -38,6 +61,13 @@ impl BlockchainVMRef {
     where
         F: FnOnce(),
     {
+        if is_rejected_as_non_payable(&tx_input, &tx_cache) {
+            return (
+                TxResult::from_vm_error(vm_err_msg::NON_PAYABLE_CONTRACT),
+                BlockchainUpdate::empty(),
+            );
+        }
+
         if let Err(err) =
             tx_cache.transfer_egld_balance(&tx_input.from, &tx_input.to, &tx_input.egld_value)
         {
@@ -102,6 +132,7 @@ impl BlockchainVMRef {
         &self,
         mut tx_input: TxInput,
         contract_path: Vec<u8>,
+        code_metadata: VMCodeMetadata,
         tx_cache: TxCache,
         f: F,
     ) -> (TxResult, VMAddress, BlockchainUpdate)
@@ -125,7 +156,12 @@ impl BlockchainVMRef {
                 BlockchainUpdate::empty(),
             );
         }
-        tx_context_sh.create_new_contract(&new_address, contract_path, tx_input_ref.from.clone());
+        tx_context_sh.create_new_contract(
+            &new_address,
+            contract_path,
+            code_metadata,
+            tx_input_ref.from.clone(),
+        );
         tx_context_sh
             .tx_cache
             .increase_egld_balance(&new_address, &tx_input_ref.egld_value);
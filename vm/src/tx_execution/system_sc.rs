@@ -1,5 +1,7 @@
 mod system_sc_issue;
+mod system_sc_ownership;
 mod system_sc_special_roles;
+mod system_sc_token_properties;
 mod system_sc_unimplemented;
 
 use crate::{
@@ -8,7 +10,9 @@ use crate::{
 };
 use hex_literal::hex;
 use system_sc_issue::*;
+use system_sc_ownership::*;
 use system_sc_special_roles::*;
+use system_sc_token_properties::*;
 use system_sc_unimplemented::*;
 
 /// Address of the system smart contract that manages ESDT.
@@ -1,4 +1,5 @@
 use num_bigint::BigUint;
+use num_traits::Zero;
 
 use crate::{
     crypto_functions::keccak256,
@@ -7,7 +8,6 @@ use crate::{
 };
 
 /// Issues a new fungible token.
-#[allow(unused_variables)]
 pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 4 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -18,11 +18,16 @@ pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdat
     let total_supply = BigUint::from_bytes_be(tx_input.args[2].clone().as_ref());
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::Fungible)
+    let token_properties = IssuedTokenProperties {
+        name,
+        token_type: VMTokenType::Fungible,
+        initial_supply: total_supply,
+        num_decimals: decimals,
+    };
+    register_and_set_roles(tx_input, tx_cache, ticker, token_properties)
 }
 
 /// Issues a new semi-fungible token.
-#[allow(unused_variables)]
 pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -31,11 +36,11 @@ pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, B
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::SemiFungible)
+    let token_properties = IssuedTokenProperties::non_fungible(name, VMTokenType::SemiFungible);
+    register_and_set_roles(tx_input, tx_cache, ticker, token_properties)
 }
 
 /// Issues a new non-fungible token.
-#[allow(unused_variables)]
 pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -44,11 +49,11 @@ pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bl
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::NonFungible)
+    let token_properties = IssuedTokenProperties::non_fungible(name, VMTokenType::NonFungible);
+    register_and_set_roles(tx_input, tx_cache, ticker, token_properties)
 }
 
 // Issues a new token and sets all roles for its type.
-#[allow(unused_variables)]
 pub fn register_and_set_all_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
@@ -63,14 +68,39 @@ pub fn register_and_set_all_roles(
     let token_type = VMTokenType::from_system_sc_arg(&tx_input.args[2]);
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, token_type)
+    let token_properties = IssuedTokenProperties {
+        name,
+        token_type,
+        initial_supply: BigUint::zero(),
+        num_decimals: decimals,
+    };
+    register_and_set_roles(tx_input, tx_cache, ticker, token_properties)
+}
+
+/// What the token registry records about a newly issued token.
+struct IssuedTokenProperties {
+    name: Vec<u8>,
+    token_type: VMTokenType,
+    initial_supply: BigUint,
+    num_decimals: u32,
+}
+
+impl IssuedTokenProperties {
+    fn non_fungible(name: Vec<u8>, token_type: VMTokenType) -> Self {
+        IssuedTokenProperties {
+            name,
+            token_type,
+            initial_supply: BigUint::zero(),
+            num_decimals: 0,
+        }
+    }
 }
 
 fn register_and_set_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
     ticker: Vec<u8>,
-    token_type: VMTokenType,
+    token_properties: IssuedTokenProperties,
 ) -> (TxResult, BlockchainUpdate) {
     let mut new_token_identifiers = tx_cache.get_new_token_identifiers();
 
@@ -86,7 +116,14 @@ fn register_and_set_roles(
     tx_cache.with_account_mut(&tx_input.from, |account| {
         account
             .esdt
-            .register_and_set_roles(&token_identifier, token_type);
+            .register_and_set_roles(&token_identifier, token_properties.token_type);
+    });
+    tx_cache.with_esdt_global_settings_mut(&token_identifier, |settings| {
+        settings.token_name = token_properties.name;
+        settings.token_type = Some(token_properties.token_type);
+        settings.owner = Some(tx_input.from.clone());
+        settings.minted_value = token_properties.initial_supply;
+        settings.num_decimals = token_properties.num_decimals;
     });
     tx_cache.set_new_token_identifiers(new_token_identifiers);
 
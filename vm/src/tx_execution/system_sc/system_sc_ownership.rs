@@ -0,0 +1,49 @@
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::VMAddress,
+};
+
+/// Hands over the management of a token to a new address.
+///
+/// Only the current token owner is allowed to call it.
+pub fn transfer_ownership(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 2 {
+        return (
+            TxResult::from_user_error("invalid number of arguments"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].as_slice();
+    let new_owner = match <[u8; 32]>::try_from(tx_input.args[1].as_slice()) {
+        Ok(address_bytes) => VMAddress::from(address_bytes),
+        Err(_) => {
+            return (
+                TxResult::from_user_error("invalid new owner address"),
+                BlockchainUpdate::empty(),
+            )
+        },
+    };
+
+    let settings = match tx_cache.get_esdt_global_settings(token_identifier) {
+        Some(settings) => settings,
+        None => {
+            return (
+                TxResult::from_vm_error("no ticker with given name"),
+                BlockchainUpdate::empty(),
+            )
+        },
+    };
+    if settings.owner.as_ref() != Some(&tx_input.from) {
+        return (
+            TxResult::from_vm_error("can be called by owner only"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    tx_cache.with_esdt_global_settings_mut(token_identifier, |settings| {
+        settings.owner = Some(new_owner);
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
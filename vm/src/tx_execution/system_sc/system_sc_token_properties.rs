@@ -0,0 +1,29 @@
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult};
+
+/// Returns the token properties, as kept by the token registry.
+pub fn get_token_properties(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 1 {
+        return (
+            TxResult::from_vm_error("invalid number of arguments"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].as_slice();
+    let settings = match tx_cache.get_esdt_global_settings(token_identifier) {
+        Some(settings) => settings,
+        None => {
+            return (
+                TxResult::from_vm_error("no ticker with given name"),
+                BlockchainUpdate::empty(),
+            )
+        },
+    };
+
+    let tx_result = TxResult {
+        result_values: settings.token_properties(),
+        ..Default::default()
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
@@ -68,14 +68,6 @@ pub fn control_changes(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Block
     unimplemented!()
 }
 
-pub fn transfer_ownership(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
-pub fn get_token_properties(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    unimplemented!()
-}
-
 pub fn get_special_roles(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     unimplemented!()
 }
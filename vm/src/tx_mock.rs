@@ -2,6 +2,7 @@
 
 mod blockchain_rng;
 mod blockchain_update;
+mod blockchain_update_json;
 mod tx_async_call_data;
 mod tx_async_promise;
 mod tx_cache;
@@ -10,13 +11,16 @@ mod tx_cache_source;
 mod tx_context;
 mod tx_context_ref;
 mod tx_context_stack;
+mod tx_gas;
 mod tx_input;
 mod tx_input_function;
 mod tx_log;
 mod tx_managed_types;
 mod tx_panic;
 mod tx_result;
+mod tx_result_assert;
 mod tx_result_calls;
+mod tx_storage_access;
 
 pub use blockchain_rng::*;
 pub use blockchain_update::BlockchainUpdate;
@@ -27,10 +31,13 @@ pub use tx_cache_source::*;
 pub use tx_context::*;
 pub use tx_context_ref::*;
 pub use tx_context_stack::*;
+pub use tx_gas::*;
 pub use tx_input::*;
 pub use tx_input_function::*;
 pub use tx_log::*;
 pub use tx_managed_types::*;
 pub use tx_panic::*;
 pub use tx_result::*;
+pub use tx_result_assert::*;
 pub use tx_result_calls::*;
+pub use tx_storage_access::TxStorageAccess;
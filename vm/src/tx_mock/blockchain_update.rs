@@ -2,13 +2,14 @@ use std::collections::HashMap;
 
 use crate::{
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, EsdtGlobalSettings},
 };
 
 #[derive(Default)]
 pub struct BlockchainUpdate {
     pub accounts: HashMap<VMAddress, AccountData>,
     pub new_token_identifiers: Option<Vec<String>>,
+    pub esdt_global_settings: HashMap<Vec<u8>, EsdtGlobalSettings>,
 }
 
 impl BlockchainUpdate {
@@ -16,11 +17,29 @@ impl BlockchainUpdate {
         BlockchainUpdate::default()
     }
 
+    /// Addresses of all the accounts touched by the update, sorted by address bytes.
+    pub fn changed_addresses(&self) -> Vec<VMAddress> {
+        let mut addresses: Vec<VMAddress> = self.accounts.keys().cloned().collect();
+        addresses.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+        addresses
+    }
+
+    /// Commits the update to the blockchain state, then notifies the commit observers.
     pub fn apply(self, blockchain: &mut BlockchainState) {
+        let changed_addresses = self.changed_addresses();
         blockchain.update_accounts(self.accounts);
 
         if let Some(token_identifiers) = self.new_token_identifiers {
             blockchain.update_new_token_identifiers(token_identifiers);
         }
+
+        blockchain
+            .esdt_global_settings
+            .extend(self.esdt_global_settings);
+
+        let state: &BlockchainState = blockchain;
+        for observer in &state.commit_observers {
+            observer.on_commit(&changed_addresses, state);
+        }
     }
 }
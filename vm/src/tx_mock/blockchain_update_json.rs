@@ -0,0 +1,264 @@
+//! JSON layout of the blockchain updates, for external analysis tools.
+//!
+//! Byte arrays (addresses, token identifiers, storage keys and values, etc.) are hex-encoded, with a `0x` prefix.
+//! Big numbers and nonces are decimal strings, so they do not lose precision in JSON parsers.
+//! Maps are sorted by key, so the output is deterministic.
+//!
+//! ```json
+//! {
+//!     "accounts": {
+//!         "0x<address>": {
+//!             "nonce": "1",
+//!             "balance": "1000",
+//!             "esdt": {
+//!                 "0x<token identifier>": {
+//!                     "instances": [
+//!                         {
+//!                             "nonce": "0",
+//!                             "balance": "100",
+//!                             "name": "0x",
+//!                             "creator": null,
+//!                             "royalties": "0",
+//!                             "hash": null,
+//!                             "uris": [],
+//!                             "attributes": "0x"
+//!                         }
+//!                     ],
+//!                     "lastNonce": "0",
+//!                     "roles": ["0x<role name>"],
+//!                     "frozen": false
+//!                 }
+//!             },
+//!             "storage": {
+//!                 "0x<key>": "0x<value>"
+//!             },
+//!             "username": "0x",
+//!             "contractPath": null,
+//!             "owner": null,
+//!             "codeMetadata": "0x0000",
+//!             "developerRewards": "0"
+//!         }
+//!     },
+//!     "newTokenIdentifiers": null,
+//!     "esdtGlobalSettings": {
+//!         "0x<token identifier>": {
+//!             "paused": false,
+//!             "limitedTransfer": false,
+//!             "roles": {
+//!                 "0x<address>": ["0x<role name>"]
+//!             },
+//!             "lastNonce": "0",
+//!             "tokenName": "0x",
+//!             "tokenType": "FungibleESDT",
+//!             "owner": null,
+//!             "minted": "0",
+//!             "burnt": "0",
+//!             "numDecimals": 0
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Serialize, Serializer};
+
+use crate::world_mock::{AccountData, EsdtData, EsdtGlobalSettings, EsdtInstance};
+
+use super::BlockchainUpdate;
+
+fn hex_json(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BlockchainUpdateJson {
+    accounts: BTreeMap<String, AccountJson>,
+    new_token_identifiers: Option<Vec<String>>,
+    esdt_global_settings: BTreeMap<String, EsdtGlobalSettingsJson>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountJson {
+    nonce: String,
+    balance: String,
+    esdt: BTreeMap<String, EsdtJson>,
+    storage: BTreeMap<String, String>,
+    username: String,
+    contract_path: Option<String>,
+    owner: Option<String>,
+    code_metadata: String,
+    developer_rewards: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EsdtJson {
+    instances: Vec<EsdtInstanceJson>,
+    last_nonce: String,
+    roles: Vec<String>,
+    frozen: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EsdtInstanceJson {
+    nonce: String,
+    balance: String,
+    name: String,
+    creator: Option<String>,
+    royalties: String,
+    hash: Option<String>,
+    uris: Vec<String>,
+    attributes: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EsdtGlobalSettingsJson {
+    paused: bool,
+    limited_transfer: bool,
+    roles: BTreeMap<String, Vec<String>>,
+    last_nonce: String,
+    token_name: String,
+    token_type: Option<&'static str>,
+    owner: Option<String>,
+    minted: String,
+    burnt: String,
+    num_decimals: u32,
+}
+
+impl From<&AccountData> for AccountJson {
+    fn from(account: &AccountData) -> Self {
+        AccountJson {
+            nonce: account.nonce.to_string(),
+            balance: account.egld_balance.to_string(),
+            esdt: account
+                .esdt
+                .iter()
+                .map(|(token_identifier, esdt_data)| {
+                    (hex_json(token_identifier), EsdtJson::from(esdt_data))
+                })
+                .collect(),
+            storage: account
+                .storage
+                .iter()
+                .map(|(key, value)| (hex_json(key), hex_json(value)))
+                .collect(),
+            username: hex_json(&account.username),
+            contract_path: account.contract_path.as_deref().map(hex_json),
+            owner: account
+                .contract_owner
+                .as_ref()
+                .map(|owner| hex_json(owner.as_bytes())),
+            code_metadata: hex_json(&account.code_metadata.to_byte_array()),
+            developer_rewards: account.developer_rewards.to_string(),
+        }
+    }
+}
+
+impl From<&EsdtData> for EsdtJson {
+    fn from(esdt_data: &EsdtData) -> Self {
+        EsdtJson {
+            instances: esdt_data
+                .instances
+                .get_instances()
+                .values()
+                .map(EsdtInstanceJson::from)
+                .collect(),
+            last_nonce: esdt_data.last_nonce.to_string(),
+            roles: esdt_data
+                .get_roles()
+                .iter()
+                .map(|role| hex_json(role))
+                .collect(),
+            frozen: esdt_data.frozen,
+        }
+    }
+}
+
+impl From<&EsdtInstance> for EsdtInstanceJson {
+    fn from(instance: &EsdtInstance) -> Self {
+        let metadata = &instance.metadata;
+        EsdtInstanceJson {
+            nonce: instance.nonce.to_string(),
+            balance: instance.balance.to_string(),
+            name: hex_json(&metadata.name),
+            creator: metadata
+                .creator
+                .as_ref()
+                .map(|creator| hex_json(creator.as_bytes())),
+            royalties: metadata.royalties.to_string(),
+            hash: metadata.hash.as_deref().map(hex_json),
+            uris: metadata.uri.iter().map(|uri| hex_json(uri)).collect(),
+            attributes: hex_json(&metadata.attributes),
+        }
+    }
+}
+
+impl From<&EsdtGlobalSettings> for EsdtGlobalSettingsJson {
+    fn from(settings: &EsdtGlobalSettings) -> Self {
+        EsdtGlobalSettingsJson {
+            paused: settings.paused,
+            limited_transfer: settings.limited_transfer,
+            roles: settings
+                .roles
+                .iter()
+                .map(|(address, roles)| {
+                    (
+                        hex_json(address.as_bytes()),
+                        roles.iter().map(|role| hex_json(role)).collect(),
+                    )
+                })
+                .collect(),
+            last_nonce: settings.last_nonce.to_string(),
+            token_name: hex_json(&settings.token_name),
+            token_type: settings
+                .token_type
+                .map(|token_type| token_type.properties_name()),
+            owner: settings
+                .owner
+                .as_ref()
+                .map(|owner| hex_json(owner.as_bytes())),
+            minted: settings.minted_value.to_string(),
+            burnt: settings.burnt_value.to_string(),
+            num_decimals: settings.num_decimals,
+        }
+    }
+}
+
+impl From<&BlockchainUpdate> for BlockchainUpdateJson {
+    fn from(update: &BlockchainUpdate) -> Self {
+        BlockchainUpdateJson {
+            accounts: update
+                .accounts
+                .iter()
+                .map(|(address, account)| {
+                    (hex_json(address.as_bytes()), AccountJson::from(account))
+                })
+                .collect(),
+            new_token_identifiers: update.new_token_identifiers.clone(),
+            esdt_global_settings: update
+                .esdt_global_settings
+                .iter()
+                .map(|(token_identifier, settings)| {
+                    (
+                        hex_json(token_identifier),
+                        EsdtGlobalSettingsJson::from(settings),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Serialize for BlockchainUpdate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        BlockchainUpdateJson::from(self).serialize(serializer)
+    }
+}
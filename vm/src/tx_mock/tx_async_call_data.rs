@@ -16,6 +16,8 @@ pub struct AsyncCallTxData {
     pub endpoint_name: TxFunctionName,
     pub arguments: Vec<Vec<u8>>,
     pub tx_hash: H256,
+    /// Callbacks get the same gas limit as the call.
+    pub gas_limit: u64,
 }
 
 pub fn async_call_tx_input(async_call: &AsyncCallTxData) -> TxInput {
@@ -26,7 +28,7 @@ pub fn async_call_tx_input(async_call: &AsyncCallTxData) -> TxInput {
         esdt_values: Vec::new(),
         func_name: async_call.endpoint_name.clone(),
         args: async_call.arguments.clone(),
-        gas_limit: 1000,
+        gas_limit: async_call.gas_limit,
         gas_price: 0,
         tx_hash: async_call.tx_hash.clone(),
         ..Default::default()
@@ -61,7 +63,7 @@ pub fn async_callback_tx_input(
         esdt_values: Vec::new(),
         func_name: TxFunctionName::CALLBACK,
         args,
-        gas_limit: 1000,
+        gas_limit: async_data.gas_limit,
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
         callback_payments,
@@ -105,6 +107,12 @@ pub fn async_promise_tx_input(
         args.push(async_result.result_message.clone().into_bytes());
         promise.error_callback.clone()
     };
+    let gas_limit = match &async_result.gas_usage {
+        Some(gas_usage) => gas_usage
+            .gas_remaining()
+            .saturating_add(promise.extra_gas_for_callback),
+        None => promise.call.gas_limit,
+    };
 
     TxInput {
         from: promise.call.from.clone(),
@@ -113,7 +121,7 @@ pub fn async_promise_tx_input(
         esdt_values: Vec::new(),
         func_name: callback_name,
         args,
-        gas_limit: 1000,
+        gas_limit,
         gas_price: 0,
         tx_hash: promise.call.tx_hash.clone(),
         promise_callback_closure_data: promise.callback_closure_data.clone(),
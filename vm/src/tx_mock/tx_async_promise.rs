@@ -5,5 +5,7 @@ pub struct Promise {
     pub call: AsyncCallTxData,
     pub success_callback: TxFunctionName,
     pub error_callback: TxFunctionName,
+    /// Reserved for the callback, on top of the gas left by the call.
+    pub extra_gas_for_callback: u64,
     pub callback_closure_data: Vec<u8>,
 }
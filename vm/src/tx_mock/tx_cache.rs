@@ -5,9 +5,9 @@ use std::{
 };
 
 use crate::{
-    display_util::address_hex,
+    display_util::{address_hex, sorted_by_address, DebugMap},
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, EsdtGlobalSettings},
 };
 
 use super::{BlockchainUpdate, TxCacheSource};
@@ -16,13 +16,18 @@ pub struct TxCache {
     source_ref: Arc<dyn TxCacheSource>,
     pub(super) accounts: Mutex<HashMap<VMAddress, AccountData>>,
     pub(super) new_token_identifiers: Mutex<Option<Vec<String>>>,
+    pub(super) esdt_global_settings: Mutex<HashMap<Vec<u8>, EsdtGlobalSettings>>,
 }
 
 impl fmt::Debug for TxCache {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("TxCache")
-            .field("accounts", &self.accounts)
-            .finish()
+        let mut debug_struct = f.debug_struct("TxCache");
+        // try_lock, so that printing never deadlocks, even while the cache is being updated
+        match self.accounts.try_lock() {
+            Ok(accounts) => debug_struct.field("accounts", &DebugMap(sorted_by_address(&accounts))),
+            Err(_) => debug_struct.field("accounts", &"<locked>"),
+        };
+        debug_struct.finish()
     }
 }
 
@@ -32,6 +37,7 @@ impl TxCache {
             source_ref,
             accounts: Mutex::new(HashMap::new()),
             new_token_identifiers: Mutex::new(None),
+            esdt_global_settings: Mutex::new(HashMap::new()),
         }
     }
 
@@ -48,6 +54,11 @@ impl TxCache {
         }
     }
 
+    pub fn account_exists(&self, address: &VMAddress) -> bool {
+        self.load_account_if_necessary(address);
+        self.accounts.lock().unwrap().contains_key(address)
+    }
+
     pub fn with_account<R, F>(&self, address: &VMAddress, f: F) -> R
     where
         F: FnOnce(&AccountData) -> R,
@@ -91,7 +102,7 @@ impl TxCache {
         self.blockchain_ref()
             .get_new_address(creator_address.clone(), current_nonce - 1)
             .unwrap_or_else(|| {
-                panic!("Missing new address. Only explicit new deploy addresses supported")
+                panic!("Missing new address. Either configure an explicit new deploy address, or a new address generator")
             })
     }
 
@@ -103,14 +114,48 @@ impl TxCache {
         *self.new_token_identifiers.lock().unwrap() = Some(token_identifiers);
     }
 
+    /// Token registry entry, including changes not yet committed.
+    pub fn get_esdt_global_settings(&self, token_identifier: &[u8]) -> Option<EsdtGlobalSettings> {
+        if let Some(settings) = self
+            .esdt_global_settings
+            .lock()
+            .unwrap()
+            .get(token_identifier)
+        {
+            return Some(settings.clone());
+        }
+        self.source_ref.load_esdt_global_settings(token_identifier)
+    }
+
+    /// Edits the token registry entry, creating it if necessary.
+    pub fn with_esdt_global_settings_mut<R, F>(&self, token_identifier: &[u8], f: F) -> R
+    where
+        F: FnOnce(&mut EsdtGlobalSettings) -> R,
+    {
+        let mut settings = self
+            .get_esdt_global_settings(token_identifier)
+            .unwrap_or_default();
+        let result = f(&mut settings);
+        self.esdt_global_settings
+            .lock()
+            .unwrap()
+            .insert(token_identifier.to_vec(), settings);
+        result
+    }
+
     pub fn into_blockchain_updates(self) -> BlockchainUpdate {
         BlockchainUpdate {
             accounts: self.accounts.into_inner().unwrap(),
             new_token_identifiers: self.new_token_identifiers.into_inner().unwrap(),
+            esdt_global_settings: self.esdt_global_settings.into_inner().unwrap(),
         }
     }
 
     pub fn commit_updates(&self, updates: BlockchainUpdate) {
         self.accounts.lock().unwrap().extend(updates.accounts);
+        self.esdt_global_settings
+            .lock()
+            .unwrap()
+            .extend(updates.esdt_global_settings);
     }
 }
@@ -1,7 +1,9 @@
 use num_bigint::BigUint;
 
 use crate::{
-    tx_execution::is_system_sc_address, tx_mock::TxPanic, types::VMAddress,
+    tx_execution::is_system_sc_address,
+    tx_mock::TxPanic,
+    types::{EsdtLocalRole, VMAddress},
     world_mock::EsdtInstanceMetadata,
 };
 
@@ -110,11 +112,34 @@ impl TxCache {
         value: &BigUint,
     ) -> Result<(), TxPanic> {
         if !is_system_sc_address(from) && !is_system_sc_address(to) {
+            self.check_esdt_transfer_allowed(from, to, esdt_token_identifier)?;
             let metadata = self.subtract_esdt_balance(from, esdt_token_identifier, nonce, value)?;
             self.increase_esdt_balance(to, esdt_token_identifier, nonce, value, metadata);
         }
         Ok(())
     }
+
+    fn check_esdt_transfer_allowed(
+        &self,
+        from: &VMAddress,
+        to: &VMAddress,
+        esdt_token_identifier: &[u8],
+    ) -> Result<(), TxPanic> {
+        if let Some(settings) = self.get_esdt_global_settings(esdt_token_identifier) {
+            if settings.paused {
+                return Err(TxPanic::vm_error("esdt token is paused"));
+            }
+
+            let transfer_role = EsdtLocalRole::Transfer.name().as_bytes();
+            if settings.limited_transfer
+                && !settings.has_role(from, transfer_role)
+                && !settings.has_role(to, transfer_role)
+            {
+                return Err(TxPanic::vm_error("action is not allowed"));
+            }
+        }
+        Ok(())
+    }
 }
 
 fn err_insufficient_funds() -> TxPanic {
@@ -1,6 +1,6 @@
 use crate::{
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, EsdtGlobalSettings},
 };
 
 use super::TxCache;
@@ -8,6 +8,8 @@ use super::TxCache;
 pub trait TxCacheSource: Send + Sync {
     fn load_account(&self, address: &VMAddress) -> Option<AccountData>;
 
+    fn load_esdt_global_settings(&self, token_identifier: &[u8]) -> Option<EsdtGlobalSettings>;
+
     fn blockchain_ref(&self) -> &BlockchainState;
 }
 
@@ -16,6 +18,10 @@ impl TxCacheSource for TxCache {
         Some(self.with_account(address, AccountData::clone))
     }
 
+    fn load_esdt_global_settings(&self, token_identifier: &[u8]) -> Option<EsdtGlobalSettings> {
+        self.get_esdt_global_settings(token_identifier)
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState {
         self.blockchain_ref()
     }
@@ -26,6 +32,10 @@ impl TxCacheSource for BlockchainState {
         self.accounts.get(address).map(AccountData::clone)
     }
 
+    fn load_esdt_global_settings(&self, token_identifier: &[u8]) -> Option<EsdtGlobalSettings> {
+        self.get_esdt_global_settings(token_identifier).cloned()
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState {
         self
     }
@@ -1,6 +1,6 @@
 use crate::{
     tx_execution::BlockchainVMRef,
-    types::VMAddress,
+    types::{VMAddress, VMCodeMetadata},
     world_mock::{AccountData, AccountEsdt, BlockchainState, FailingExecutor},
 };
 use num_bigint::BigUint;
@@ -10,7 +10,9 @@ use std::{
     sync::{Arc, Mutex, MutexGuard},
 };
 
-use super::{BlockchainRng, BlockchainUpdate, TxCache, TxInput, TxManagedTypes, TxResult};
+use super::{
+    BlockchainRng, BlockchainUpdate, TxCache, TxGasMeter, TxInput, TxManagedTypes, TxResult,
+};
 
 pub struct TxContext {
     pub vm_ref: BlockchainVMRef,
@@ -19,11 +21,16 @@ pub struct TxContext {
     pub managed_types: Mutex<TxManagedTypes>,
     pub tx_result_cell: Mutex<TxResult>,
     pub b_rng: Mutex<BlockchainRng>,
+    pub gas_meter: Mutex<TxGasMeter>,
 }
 
 impl TxContext {
     pub fn new(vm_ref: BlockchainVMRef, tx_input: TxInput, tx_cache: TxCache) -> Self {
         let b_rng = Mutex::new(BlockchainRng::new(&tx_input, &tx_cache));
+        let gas_meter = Mutex::new(TxGasMeter::new(
+            tx_cache.blockchain_ref().gas_schedule,
+            tx_input.gas_limit,
+        ));
         TxContext {
             vm_ref,
             tx_input_box: Box::new(tx_input),
@@ -31,6 +38,7 @@ impl TxContext {
             managed_types: Mutex::new(TxManagedTypes::new()),
             tx_result_cell: Mutex::new(TxResult::empty()),
             b_rng,
+            gas_meter,
         }
     }
 
@@ -46,6 +54,7 @@ impl TxContext {
             username: Vec::new(),
             contract_path: None,
             contract_owner: None,
+            code_metadata: VMCodeMetadata::DEFAULT,
             developer_rewards: BigUint::zero(),
         });
 
@@ -64,6 +73,7 @@ impl TxContext {
             managed_types: Mutex::new(TxManagedTypes::new()),
             tx_result_cell: Mutex::new(TxResult::empty()),
             b_rng,
+            gas_meter: Mutex::new(TxGasMeter::new(None, 0)),
         }
     }
 
@@ -127,10 +137,15 @@ impl TxContext {
         self.b_rng.lock().unwrap()
     }
 
+    pub fn gas_meter_lock(&self) -> MutexGuard<TxGasMeter> {
+        self.gas_meter.lock().unwrap()
+    }
+
     pub fn create_new_contract(
         &self,
         new_address: &VMAddress,
         contract_path: Vec<u8>,
+        code_metadata: VMCodeMetadata,
         contract_owner: VMAddress,
     ) {
         assert!(
@@ -147,6 +162,7 @@ impl TxContext {
             username: Vec::new(),
             contract_path: Some(contract_path),
             contract_owner: Some(contract_owner),
+            code_metadata,
             developer_rewards: BigUint::zero(),
         });
     }
@@ -158,7 +174,8 @@ impl TxContext {
 
     pub fn into_results(self) -> (TxResult, BlockchainUpdate) {
         let tx_cache = Arc::try_unwrap(self.tx_cache).unwrap();
-        let tx_result = Mutex::into_inner(self.tx_result_cell).unwrap();
+        let mut tx_result = Mutex::into_inner(self.tx_result_cell).unwrap();
+        tx_result.gas_usage = Mutex::into_inner(self.gas_meter).unwrap().usage();
         let blockchain_updates = tx_cache.into_blockchain_updates();
         (tx_result, blockchain_updates)
     }
@@ -172,6 +189,7 @@ impl std::fmt::Debug for TxContext {
             .field("managed_types", &self.managed_types)
             .field("tx_result_cell", &self.tx_result_cell)
             .field("b_rng", &self.b_rng)
+            .field("gas_meter", &self.gas_meter)
             .finish()
     }
 }
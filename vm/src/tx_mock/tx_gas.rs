@@ -0,0 +1,105 @@
+use crate::world_mock::GasSchedule;
+
+/// The gas limit under which calls to other contracts are performed, when gas is not metered.
+pub const UNMETERED_CALL_GAS_LIMIT: u64 = 1000;
+
+/// The operations that consume gas. Their cost is given by the `GasSchedule`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GasCost {
+    StorageLoad {
+        value_len: usize,
+    },
+    StorageStore {
+        value_len: usize,
+    },
+    ManagedTypeOp,
+    BuiltinCall,
+    ContractCall,
+    /// The gas used by a synchronous call, which is paid by the caller.
+    NestedExecution {
+        gas_used: u64,
+    },
+}
+
+/// How much of its gas limit a transaction consumed.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct TxGasUsage {
+    pub gas_limit: u64,
+    pub gas_used: u64,
+}
+
+impl TxGasUsage {
+    pub fn gas_remaining(&self) -> u64 {
+        self.gas_limit.saturating_sub(self.gas_used)
+    }
+
+    /// Adds the gas, returns `false` if it exceeds the gas limit.
+    ///
+    /// Running out of gas consumes the entire gas limit, as on mainnet.
+    pub fn use_gas(&mut self, gas: u64) -> bool {
+        match self.gas_used.checked_add(gas) {
+            Some(gas_used) if gas_used <= self.gas_limit => {
+                self.gas_used = gas_used;
+                true
+            },
+            _ => {
+                self.gas_used = self.gas_limit;
+                false
+            },
+        }
+    }
+}
+
+/// Keeps track of the gas consumed during the execution of a transaction.
+///
+/// Does nothing if there is no gas schedule configured, gas is then not metered at all.
+#[derive(Debug)]
+pub struct TxGasMeter {
+    gas_schedule: Option<GasSchedule>,
+    usage: TxGasUsage,
+}
+
+impl TxGasMeter {
+    pub fn new(gas_schedule: Option<GasSchedule>, gas_limit: u64) -> Self {
+        TxGasMeter {
+            gas_schedule,
+            usage: TxGasUsage {
+                gas_limit,
+                gas_used: 0,
+            },
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.gas_schedule.is_some()
+    }
+
+    /// Without metering, the gas left is always the gas limit.
+    pub fn gas_left(&self) -> u64 {
+        self.usage.gas_remaining()
+    }
+
+    /// Returns `false` if the transaction ran out of gas.
+    pub fn use_gas(&mut self, gas_cost: GasCost) -> bool {
+        if let Some(gas_schedule) = &self.gas_schedule {
+            let gas = gas_schedule.cost(gas_cost);
+            self.usage.use_gas(gas)
+        } else {
+            true
+        }
+    }
+
+    /// The gas limit of calls to other contracts, they can use at most the gas left.
+    pub fn nested_call_gas_limit(&self, requested_gas: u64) -> u64 {
+        if self.is_enabled() {
+            requested_gas.min(self.gas_left())
+        } else {
+            UNMETERED_CALL_GAS_LIMIT
+        }
+    }
+
+    /// `None` if gas is not metered.
+    pub fn usage(&self) -> Option<TxGasUsage> {
+        self.gas_schedule.map(|_| self.usage)
+    }
+}
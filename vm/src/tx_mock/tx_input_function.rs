@@ -53,6 +53,9 @@ impl TxFunctionName {
     /// The constructor name of any smart contract.
     pub const INIT: TxFunctionName = TxFunctionName::from_static("init");
 
+    /// The function called when upgrading a smart contract, if present. Otherwise the constructor is called.
+    pub const UPGRADE: TxFunctionName = TxFunctionName::from_static("upgrade");
+
     /// The the legacy async central callback name of any smart contract.
     pub const CALLBACK: TxFunctionName = TxFunctionName::from_static("callBack");
 
@@ -16,6 +16,10 @@ impl TxPanic {
         TxPanic::new(4, message)
     }
 
+    pub fn out_of_gas(message: &str) -> TxPanic {
+        TxPanic::new(5, message)
+    }
+
     pub fn vm_error(message: &str) -> TxPanic {
         TxPanic::new(10, message)
     }
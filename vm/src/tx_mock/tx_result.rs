@@ -1,6 +1,15 @@
 use std::fmt;
 
-use super::{AsyncCallTxData, TxLog, TxPanic, TxResultCalls};
+use crate::{
+    display_util::{address_hex, bytes_to_string},
+    types::VMAddress,
+};
+
+use super::{
+    assert_tx_error, assert_tx_log_emitted, assert_tx_ok, assert_tx_out_values,
+    assert_tx_user_error, AsyncCallTxData, TxGasUsage, TxLog, TxPanic, TxResultCalls,
+    TxStorageAccess,
+};
 
 #[derive(Clone, Debug)]
 #[must_use]
@@ -19,6 +28,12 @@ pub struct TxResult {
     ///
     /// Is never cleared of its contents.
     pub all_calls: Vec<AsyncCallTxData>,
+
+    /// Storage keys read and written by the contracts, including in synchronous calls.
+    pub storage_access: TxStorageAccess,
+
+    /// `None` if gas is not metered, i.e. if there is no gas schedule configured.
+    pub gas_usage: Option<TxGasUsage>,
 }
 
 impl Default for TxResult {
@@ -30,6 +45,8 @@ impl Default for TxResult {
             result_logs: Vec::new(),
             pending_calls: TxResultCalls::empty(),
             all_calls: Vec::new(),
+            storage_access: TxStorageAccess::default(),
+            gas_usage: None,
         }
     }
 }
@@ -67,6 +84,18 @@ impl TxResult {
         Self::from_panic_string("")
     }
 
+    /// Status 4, for calls rejected because of their arguments, as opposed to VM failures.
+    pub fn from_user_error<S>(result_message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        TxResult {
+            result_status: 4,
+            result_message: result_message.into(),
+            ..Default::default()
+        }
+    }
+
     pub fn from_vm_error<S>(result_message: S) -> Self
     where
         S: Into<String>,
@@ -83,6 +112,7 @@ impl TxResult {
             .extend_from_slice(sync_call_result.result_values.as_slice());
         self.result_logs
             .extend_from_slice(sync_call_result.result_logs.as_slice());
+        self.storage_access.merge(&sync_call_result.storage_access);
         if let Some(sync_result_async) = &sync_call_result.pending_calls.async_call {
             assert!(
                 self.pending_calls.async_call.is_none(),
@@ -93,35 +123,77 @@ impl TxResult {
     }
 
     pub fn assert_ok(&self) {
-        assert!(
-            self.result_status == 0,
-            "Tx success expected, but failed. Status: {}, message: \"{}\"",
-            self.result_status,
-            self.result_message.as_str()
-        );
+        assert_tx_ok(self.result_status, &self.result_message);
     }
 
     pub fn assert_error(&self, expected_status: u64, expected_message: &str) {
-        assert!(
-            self.result_message.as_str() == expected_message,
-            "Tx error message mismatch. Want status {}, message \"{}\". Have status {}, message \"{}\"",
-            expected_status,
-            expected_message,
+        assert_tx_error(
             self.result_status,
-            self.result_message.as_str()
-        );
-        assert!(
-            self.result_status == expected_status,
-            "Tx error status mismatch. Want status {}, message \"{}\". Have status {}, message \"{}\"",
+            &self.result_message,
             expected_status,
             expected_message,
-            self.result_status,
-            self.result_message.as_str()
         );
     }
 
     pub fn assert_user_error(&self, expected_message: &str) {
-        self.assert_error(4, expected_message);
+        assert_tx_user_error(self.result_status, &self.result_message, expected_message);
+    }
+
+    /// Checks the returned values, printing a value-by-value diff on mismatch.
+    pub fn assert_out_values<V>(&self, expected: &[V])
+    where
+        V: AsRef<[u8]>,
+    {
+        assert_tx_out_values(&self.result_values, expected);
+    }
+
+    /// The logs that have the given topic among their topics, e.g. the event identifier, which comes first.
+    pub fn logs_for_topic(&self, topic: &[u8]) -> Vec<&TxLog> {
+        self.result_logs
+            .iter()
+            .filter(|log| {
+                log.topics
+                    .iter()
+                    .any(|log_topic| log_topic.as_slice() == topic)
+            })
+            .collect()
+    }
+
+    /// Checks that a log with exactly the given topics was emitted, listing the emitted logs otherwise.
+    ///
+    /// The log data is not compared, use `logs_for_topic` to check it.
+    pub fn assert_log_emitted<V>(&self, expected_topics: &[V])
+    where
+        V: AsRef<[u8]>,
+    {
+        assert_tx_log_emitted(&self.result_logs, expected_topics);
+    }
+
+    /// Useful for checking that views and other read-only endpoints really are read-only.
+    pub fn assert_no_storage_writes(&self) {
+        for (address, keys) in &self.storage_access.writes {
+            if let Some(key) = keys.iter().next() {
+                panic!(
+                    "No storage writes expected, but key \"{}\" was written in account {}",
+                    bytes_to_string(key),
+                    address_hex(address)
+                );
+            }
+        }
+    }
+
+    /// Checks that all keys written in the storage of the given account start with one of the given prefixes.
+    pub fn assert_storage_writes_with_prefixes(&self, address: &VMAddress, prefixes: &[&[u8]]) {
+        if let Some(key) = self
+            .storage_access
+            .find_write_outside_prefixes(address, prefixes)
+        {
+            panic!(
+                "Unexpected storage write of key \"{}\" in account {}",
+                bytes_to_string(key),
+                address_hex(address)
+            );
+        }
     }
 }
 
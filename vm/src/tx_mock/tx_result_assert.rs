@@ -0,0 +1,141 @@
+use colored::Colorize;
+
+use crate::display_util::bytes_to_string;
+
+use super::TxLog;
+
+const USER_ERROR_STATUS: u64 = 4;
+
+/// Panics with a readable message if the transaction status is not success.
+pub fn assert_tx_ok(status: u64, message: &str) {
+    if status != 0 {
+        panic!(
+            "Tx success expected, but failed. Status: {status}, message: \"{message}\"\n{}",
+            want_have_lines("status", "0", &status.to_string()),
+        );
+    }
+}
+
+/// Panics with a readable message if the transaction did not fail with the expected status and message.
+pub fn assert_tx_error(status: u64, message: &str, expected_status: u64, expected_message: &str) {
+    let kind = if message != expected_message {
+        "message"
+    } else if status != expected_status {
+        "status"
+    } else {
+        return;
+    };
+
+    let mut diff = String::new();
+    if status != expected_status {
+        diff.push_str(&want_have_lines(
+            "status",
+            &expected_status.to_string(),
+            &status.to_string(),
+        ));
+    }
+    if message != expected_message {
+        diff.push_str(&want_have_lines(
+            "message",
+            &format!("\"{expected_message}\""),
+            &format!("\"{message}\""),
+        ));
+    }
+    panic!(
+        "Tx error {kind} mismatch. Want status {expected_status}, message \"{expected_message}\". Have status {status}, message \"{message}\"\n{diff}"
+    );
+}
+
+pub fn assert_tx_user_error(status: u64, message: &str, expected_message: &str) {
+    assert_tx_error(status, message, USER_ERROR_STATUS, expected_message);
+}
+
+/// Panics with a value-by-value diff if the transaction results differ from the expected ones.
+pub fn assert_tx_out_values<V>(out: &[Vec<u8>], expected: &[V])
+where
+    V: AsRef<[u8]>,
+{
+    let matches = out.len() == expected.len()
+        && out
+            .iter()
+            .zip(expected.iter())
+            .all(|(have, want)| have.as_slice() == want.as_ref());
+    if matches {
+        return;
+    }
+
+    let mut diff = String::new();
+    for i in 0..out.len().max(expected.len()) {
+        let want = expected.get(i).map(|value| value.as_ref());
+        let have = out.get(i).map(Vec::as_slice);
+        if want == have {
+            diff.push_str(&format!("  [{i}] {}\n", display_value(have)));
+        } else {
+            diff.push_str(&want_have_lines(
+                &format!("[{i}]"),
+                &display_value(want),
+                &display_value(have),
+            ));
+        }
+    }
+    panic!(
+        "Tx out values mismatch. Want {} values, have {}.\n{diff}",
+        expected.len(),
+        out.len(),
+    );
+}
+
+/// Panics with the list of emitted logs if none of them has exactly the expected topics.
+pub fn assert_tx_log_emitted<V>(logs: &[TxLog], expected_topics: &[V])
+where
+    V: AsRef<[u8]>,
+{
+    let emitted = logs.iter().any(|log| {
+        log.topics.len() == expected_topics.len()
+            && log
+                .topics
+                .iter()
+                .zip(expected_topics.iter())
+                .all(|(have, want)| have.as_slice() == want.as_ref())
+    });
+    if emitted {
+        return;
+    }
+
+    let want = display_topics(expected_topics.iter().map(|topic| topic.as_ref()));
+    let mut have = String::new();
+    for (i, log) in logs.iter().enumerate() {
+        have.push_str(&format!(
+            "  [{i}] {}\n",
+            display_topics(log.topics.iter().map(Vec::as_slice))
+        ));
+    }
+    panic!(
+        "Tx log not emitted. Want topics {}. Have {} logs:\n{have}",
+        want.green(),
+        logs.len(),
+    );
+}
+
+fn display_topics<'a, I>(topics: I) -> String
+where
+    I: Iterator<Item = &'a [u8]>,
+{
+    let topics: Vec<String> = topics.map(|topic| display_value(Some(topic))).collect();
+    format!("[{}]", topics.join(", "))
+}
+
+fn display_value(value: Option<&[u8]>) -> String {
+    match value {
+        Some(bytes) => format!("0x{} ({})", hex::encode(bytes), bytes_to_string(bytes)),
+        None => "<missing>".to_string(),
+    }
+}
+
+fn want_have_lines(label: &str, want: &str, have: &str) -> String {
+    format!(
+        "  {label}\n    want: {}\n    have: {}\n",
+        want.green(),
+        have.red()
+    )
+}
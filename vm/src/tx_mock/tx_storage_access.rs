@@ -0,0 +1,118 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt,
+};
+
+use crate::{
+    display_util::{sorted_by_address, DebugMap},
+    types::VMAddress,
+};
+
+/// Storage keys read and written during a transaction, per account.
+///
+/// Includes the accesses of all synchronous calls made by the transaction.
+/// Only accesses performed by contract code are recorded, not those of builtin functions.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct TxStorageAccess {
+    pub reads: HashMap<VMAddress, BTreeSet<Vec<u8>>>,
+    pub writes: HashMap<VMAddress, BTreeSet<Vec<u8>>>,
+}
+
+impl TxStorageAccess {
+    pub fn record_read(&mut self, address: &VMAddress, key: &[u8]) {
+        record_key(&mut self.reads, address, key);
+    }
+
+    pub fn record_write(&mut self, address: &VMAddress, key: &[u8]) {
+        record_key(&mut self.writes, address, key);
+    }
+
+    pub fn merge(&mut self, other: &TxStorageAccess) {
+        for (address, keys) in &other.reads {
+            self.reads
+                .entry(address.clone())
+                .or_default()
+                .extend(keys.iter().cloned());
+        }
+        for (address, keys) in &other.writes {
+            self.writes
+                .entry(address.clone())
+                .or_default()
+                .extend(keys.iter().cloned());
+        }
+    }
+
+    /// Keys read from the storage of the given account, sorted.
+    pub fn read_keys(&self, address: &VMAddress) -> Vec<&[u8]> {
+        keys_of(&self.reads, address)
+    }
+
+    /// Keys written to the storage of the given account, sorted.
+    pub fn written_keys(&self, address: &VMAddress) -> Vec<&[u8]> {
+        keys_of(&self.writes, address)
+    }
+
+    pub fn is_read(&self, address: &VMAddress, key: &[u8]) -> bool {
+        contains_key(&self.reads, address, key)
+    }
+
+    pub fn is_written(&self, address: &VMAddress, key: &[u8]) -> bool {
+        contains_key(&self.writes, address, key)
+    }
+
+    pub fn has_writes(&self) -> bool {
+        self.writes.values().any(|keys| !keys.is_empty())
+    }
+
+    /// The first written key of the given account that starts with none of the given prefixes.
+    pub fn find_write_outside_prefixes(
+        &self,
+        address: &VMAddress,
+        prefixes: &[&[u8]],
+    ) -> Option<&[u8]> {
+        self.written_keys(address)
+            .into_iter()
+            .find(|key| !prefixes.iter().any(|prefix| key.starts_with(prefix)))
+    }
+}
+
+fn record_key(
+    accesses: &mut HashMap<VMAddress, BTreeSet<Vec<u8>>>,
+    address: &VMAddress,
+    key: &[u8],
+) {
+    let keys = accesses.entry(address.clone()).or_default();
+    if !keys.contains(key) {
+        keys.insert(key.to_vec());
+    }
+}
+
+fn keys_of<'a>(
+    accesses: &'a HashMap<VMAddress, BTreeSet<Vec<u8>>>,
+    address: &VMAddress,
+) -> Vec<&'a [u8]> {
+    accesses
+        .get(address)
+        .map(|keys| keys.iter().map(Vec::as_slice).collect())
+        .unwrap_or_default()
+}
+
+fn contains_key(
+    accesses: &HashMap<VMAddress, BTreeSet<Vec<u8>>>,
+    address: &VMAddress,
+    key: &[u8],
+) -> bool {
+    accesses
+        .get(address)
+        .map(|keys| keys.contains(key))
+        .unwrap_or(false)
+}
+
+impl fmt::Debug for TxStorageAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TxStorageAccess")
+            .field("reads", &DebugMap(sorted_by_address(&self.reads)))
+            .field("writes", &DebugMap(sorted_by_address(&self.writes)))
+            .finish()
+    }
+}
@@ -16,4 +16,14 @@ impl VMTokenType {
             _ => panic!("invalid token type"),
         }
     }
+
+    /// The token type name, as it appears in the ESDT system SC token properties.
+    pub fn properties_name(self) -> &'static str {
+        match self {
+            VMTokenType::Fungible => "FungibleESDT",
+            VMTokenType::SemiFungible => "SemiFungibleESDT",
+            VMTokenType::Meta => "MetaESDT",
+            VMTokenType::NonFungible => "NonFungibleESDT",
+        }
+    }
 }
@@ -1,5 +1,8 @@
 pub const NON_PAYABLE_FUNC_EGLD: &str = "function does not accept EGLD payment";
 pub const NON_PAYABLE_FUNC_ESDT: &str = "function does not accept ESDT payment";
+pub const NON_PAYABLE_CONTRACT: &str = "sending value to non payable contract";
+
+pub const CONTRACT_NOT_FOUND: &str = "contract not found";
 
 pub const BIG_INT_BITWISE_OPERATION_NEGATIVE: &str =
     "bitwise operations only allowed on positive integers";
@@ -2,7 +2,7 @@ use std::ffi::c_void;
 
 use multiversx_chain_vm_executor::{MemLength, MemPtr, VMHooks};
 
-use crate::mem_conv;
+use crate::{mem_conv, tx_mock::GasCost};
 
 use super::VMHooksHandler;
 
@@ -16,6 +16,12 @@ impl VMHooksDispatcher {
     pub fn new(handler: Box<dyn VMHooksHandler>) -> Self {
         VMHooksDispatcher { handler }
     }
+
+    /// Managed type hooks are charged once per call, however many operations they perform.
+    fn managed_types_handler(&self) -> &dyn VMHooksHandler {
+        self.handler.use_gas(GasCost::ManagedTypeOp);
+        self.handler.as_ref()
+    }
 }
 
 fn bool_to_i32(b: bool) -> i32 {
@@ -947,96 +953,110 @@ impl VMHooks for VMHooksDispatcher {
         fractional_part: i32,
         exponent: i32,
     ) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .bf_from_parts(integral_part, fractional_part, exponent)
     }
 
     fn big_float_new_from_frac(&self, numerator: i64, denominator: i64) -> i32 {
-        self.handler.bf_from_frac(numerator, denominator)
+        self.managed_types_handler()
+            .bf_from_frac(numerator, denominator)
     }
 
     fn big_float_new_from_sci(&self, significand: i64, exponent: i64) -> i32 {
-        self.handler.bf_from_sci(significand, exponent)
+        self.managed_types_handler()
+            .bf_from_sci(significand, exponent)
     }
 
     fn big_float_add(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bf_add(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_sub(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bf_sub(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_mul(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bf_mul(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_div(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bf_div(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_float_neg(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bf_neg(destination_handle, op_handle);
+        self.managed_types_handler()
+            .bf_neg(destination_handle, op_handle);
     }
 
     fn big_float_clone(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bf_clone(destination_handle, op_handle);
+        self.managed_types_handler()
+            .bf_clone(destination_handle, op_handle);
     }
 
     fn big_float_cmp(&self, op1_handle: i32, op2_handle: i32) -> i32 {
-        self.handler.bf_cmp(op1_handle, op2_handle)
+        self.managed_types_handler().bf_cmp(op1_handle, op2_handle)
     }
 
     fn big_float_abs(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bf_abs(destination_handle, op_handle);
+        self.managed_types_handler()
+            .bf_abs(destination_handle, op_handle);
     }
 
     fn big_float_sign(&self, op_handle: i32) -> i32 {
-        self.handler.bf_sign(op_handle)
+        self.managed_types_handler().bf_sign(op_handle)
     }
 
     fn big_float_sqrt(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bf_sqrt(destination_handle, op_handle);
+        self.managed_types_handler()
+            .bf_sqrt(destination_handle, op_handle);
     }
 
     fn big_float_pow(&self, destination_handle: i32, op_handle: i32, exponent: i32) {
-        self.handler.bf_pow(destination_handle, op_handle, exponent);
+        self.managed_types_handler()
+            .bf_pow(destination_handle, op_handle, exponent);
     }
 
     fn big_float_floor(&self, dest_big_int_handle: i32, op_handle: i32) {
-        self.handler.bf_floor(dest_big_int_handle, op_handle);
+        self.managed_types_handler()
+            .bf_floor(dest_big_int_handle, op_handle);
     }
 
     fn big_float_ceil(&self, dest_big_int_handle: i32, op_handle: i32) {
-        self.handler.bf_ceil(dest_big_int_handle, op_handle);
+        self.managed_types_handler()
+            .bf_ceil(dest_big_int_handle, op_handle);
     }
 
     fn big_float_truncate(&self, dest_big_int_handle: i32, op_handle: i32) {
-        self.handler.bf_trunc(dest_big_int_handle, op_handle);
+        self.managed_types_handler()
+            .bf_trunc(dest_big_int_handle, op_handle);
     }
 
     fn big_float_set_int64(&self, destination_handle: i32, value: i64) {
-        self.handler.bf_set_i64(destination_handle, value);
+        self.managed_types_handler()
+            .bf_set_i64(destination_handle, value);
     }
 
     fn big_float_is_int(&self, op_handle: i32) -> i32 {
-        bool_to_i32(self.handler.bf_is_bi(op_handle))
+        bool_to_i32(self.managed_types_handler().bf_is_bi(op_handle))
     }
 
     fn big_float_set_big_int(&self, destination_handle: i32, big_int_handle: i32) {
-        self.handler.bf_set_bi(destination_handle, big_int_handle);
+        self.managed_types_handler()
+            .bf_set_bi(destination_handle, big_int_handle);
     }
 
     fn big_float_get_const_pi(&self, destination_handle: i32) {
-        self.handler.bf_get_const_pi(destination_handle);
+        self.managed_types_handler()
+            .bf_get_const_pi(destination_handle);
     }
 
     fn big_float_get_const_e(&self, destination_handle: i32) {
-        self.handler.bf_get_const_e(destination_handle);
+        self.managed_types_handler()
+            .bf_get_const_e(destination_handle);
     }
 
     fn big_int_get_unsigned_argument(&self, id: i32, destination_handle: i32) {
@@ -1066,7 +1086,8 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_get_call_value(&self, destination_handle: i32) {
-        self.handler.load_egld_value(destination_handle);
+        self.managed_types_handler()
+            .load_egld_value(destination_handle);
     }
 
     fn big_int_get_esdt_call_value(&self, destination: i32) {
@@ -1080,7 +1101,8 @@ impl VMHooks for VMHooksDispatcher {
     fn big_int_get_external_balance(&self, address_offset: MemPtr, result: i32) {
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
-                self.handler.load_balance(address_bytes, result);
+                self.managed_types_handler()
+                    .load_balance(address_bytes, result);
             })
         }
     }
@@ -1096,19 +1118,20 @@ impl VMHooks for VMHooksDispatcher {
         unsafe {
             mem_conv::with_bytes(address_offset, 32, |address_bytes| {
                 mem_conv::with_bytes(token_id_offset, token_id_len, |token_id_bytes| {
-                    self.handler.big_int_get_esdt_external_balance(
-                        address_bytes,
-                        token_id_bytes,
-                        nonce as u64,
-                        result_handle,
-                    );
+                    self.managed_types_handler()
+                        .big_int_get_esdt_external_balance(
+                            address_bytes,
+                            token_id_bytes,
+                            nonce as u64,
+                            result_handle,
+                        );
                 })
             })
         }
     }
 
     fn big_int_new(&self, small_value: i64) -> i32 {
-        self.handler.bi_new(small_value)
+        self.managed_types_handler().bi_new(small_value)
     }
 
     fn big_int_unsigned_byte_length(&self, reference_handle: i32) -> i32 {
@@ -1135,7 +1158,7 @@ impl VMHooks for VMHooksDispatcher {
     ) {
         unsafe {
             mem_conv::with_bytes_mut(byte_offset, byte_length, |bytes| {
-                self.handler
+                self.managed_types_handler()
                     .bi_set_unsigned_bytes(destination_handle, bytes);
             })
         }
@@ -1149,45 +1172,48 @@ impl VMHooks for VMHooksDispatcher {
     ) {
         unsafe {
             mem_conv::with_bytes_mut(byte_offset, byte_length, |bytes| {
-                self.handler.bi_set_signed_bytes(destination_handle, bytes);
+                self.managed_types_handler()
+                    .bi_set_signed_bytes(destination_handle, bytes);
             })
         }
     }
 
     fn big_int_is_int64(&self, destination_handle: i32) -> i32 {
-        self.handler.bi_is_int64(destination_handle)
+        self.managed_types_handler().bi_is_int64(destination_handle)
     }
 
     fn big_int_get_int64(&self, destination_handle: i32) -> i64 {
-        self.handler.bi_get_int64(destination_handle)
+        self.managed_types_handler()
+            .bi_get_int64(destination_handle)
     }
 
     fn big_int_set_int64(&self, destination_handle: i32, value: i64) {
-        self.handler.bi_set_int64(destination_handle, value);
+        self.managed_types_handler()
+            .bi_set_int64(destination_handle, value);
     }
 
     fn big_int_add(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_add(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_sub(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_sub(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_mul(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_mul(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_tdiv(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_t_div(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_tmod(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_t_mod(destination_handle, op1_handle, op2_handle);
     }
 
@@ -1200,32 +1226,35 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_sqrt(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bi_sqrt(destination_handle, op_handle);
+        self.managed_types_handler()
+            .bi_sqrt(destination_handle, op_handle);
     }
 
     fn big_int_pow(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_pow(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_log2(&self, op_handle: i32) -> i32 {
-        self.handler.bi_log2(op_handle)
+        self.managed_types_handler().bi_log2(op_handle)
     }
 
     fn big_int_abs(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bi_abs(destination_handle, op_handle);
+        self.managed_types_handler()
+            .bi_abs(destination_handle, op_handle);
     }
 
     fn big_int_neg(&self, destination_handle: i32, op_handle: i32) {
-        self.handler.bi_neg(destination_handle, op_handle);
+        self.managed_types_handler()
+            .bi_neg(destination_handle, op_handle);
     }
 
     fn big_int_sign(&self, op_handle: i32) -> i32 {
-        self.handler.bi_sign(op_handle)
+        self.managed_types_handler().bi_sign(op_handle)
     }
 
     fn big_int_cmp(&self, op1_handle: i32, op2_handle: i32) -> i32 {
-        self.handler.bi_cmp(op1_handle, op2_handle)
+        self.managed_types_handler().bi_cmp(op1_handle, op2_handle)
     }
 
     fn big_int_not(&self, destination_handle: i32, op_handle: i32) {
@@ -1233,62 +1262,64 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn big_int_and(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_and(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_or(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_or(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_xor(&self, destination_handle: i32, op1_handle: i32, op2_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_xor(destination_handle, op1_handle, op2_handle);
     }
 
     fn big_int_shr(&self, destination_handle: i32, op_handle: i32, bits: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_shr(destination_handle, op_handle, bits as usize);
     }
 
     fn big_int_shl(&self, destination_handle: i32, op_handle: i32, bits: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_shl(destination_handle, op_handle, bits as usize);
     }
 
     fn big_int_finish_unsigned(&self, reference_handle: i32) {
-        self.handler.finish_big_uint_raw(reference_handle);
+        self.managed_types_handler()
+            .finish_big_uint_raw(reference_handle);
     }
 
     fn big_int_finish_signed(&self, reference_handle: i32) {
-        self.handler.finish_big_int_raw(reference_handle);
+        self.managed_types_handler()
+            .finish_big_int_raw(reference_handle);
     }
 
     fn big_int_to_string(&self, big_int_handle: i32, destination_handle: i32) {
-        self.handler
+        self.managed_types_handler()
             .bi_to_string(big_int_handle, destination_handle);
     }
 
     fn mbuffer_new(&self) -> i32 {
-        self.handler.mb_new_empty()
+        self.managed_types_handler().mb_new_empty()
     }
 
     fn mbuffer_new_from_bytes(&self, data_offset: MemPtr, data_length: MemLength) -> i32 {
         unsafe {
             mem_conv::with_bytes_mut(data_offset, data_length, |bytes| {
-                self.handler.mb_new_from_bytes(bytes)
+                self.managed_types_handler().mb_new_from_bytes(bytes)
             })
         }
     }
 
     fn mbuffer_get_length(&self, m_buffer_handle: i32) -> i32 {
-        self.handler.mb_len(m_buffer_handle) as i32
+        self.managed_types_handler().mb_len(m_buffer_handle) as i32
     }
 
     fn mbuffer_get_bytes(&self, m_buffer_handle: i32, result_offset: MemPtr) -> i32 {
         unsafe {
-            self.handler
+            self.managed_types_handler()
                 .mb_copy_bytes(m_buffer_handle, result_offset as *mut u8) as i32
         }
     }
@@ -1302,8 +1333,11 @@ impl VMHooks for VMHooksDispatcher {
     ) -> i32 {
         unsafe {
             mem_conv::with_bytes_mut(result_offset, slice_length as isize, |bytes| {
-                self.handler
-                    .mb_load_slice(source_handle, starting_position as usize, bytes)
+                self.managed_types_handler().mb_load_slice(
+                    source_handle,
+                    starting_position as usize,
+                    bytes,
+                )
             })
         }
     }
@@ -1315,7 +1349,7 @@ impl VMHooks for VMHooksDispatcher {
         slice_length: i32,
         destination_handle: i32,
     ) -> i32 {
-        self.handler.mb_copy_slice(
+        self.managed_types_handler().mb_copy_slice(
             source_handle,
             starting_position as usize,
             slice_length as usize,
@@ -1324,7 +1358,8 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_eq(&self, m_buffer_handle1: i32, m_buffer_handle2: i32) -> i32 {
-        self.handler.mb_eq(m_buffer_handle1, m_buffer_handle2)
+        self.managed_types_handler()
+            .mb_eq(m_buffer_handle1, m_buffer_handle2)
     }
 
     fn mbuffer_set_bytes(
@@ -1335,7 +1370,7 @@ impl VMHooks for VMHooksDispatcher {
     ) -> i32 {
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
-                self.handler.mb_set(m_buffer_handle, bytes);
+                self.managed_types_handler().mb_set(m_buffer_handle, bytes);
             });
         }
         0
@@ -1350,14 +1385,18 @@ impl VMHooks for VMHooksDispatcher {
     ) -> i32 {
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
-                self.handler
-                    .mb_set_slice(m_buffer_handle, starting_position as usize, bytes)
+                self.managed_types_handler().mb_set_slice(
+                    m_buffer_handle,
+                    starting_position as usize,
+                    bytes,
+                )
             })
         }
     }
 
     fn mbuffer_append(&self, accumulator_handle: i32, data_handle: i32) -> i32 {
-        self.handler.mb_append(accumulator_handle, data_handle);
+        self.managed_types_handler()
+            .mb_append(accumulator_handle, data_handle);
         0
     }
 
@@ -1369,32 +1408,33 @@ impl VMHooks for VMHooksDispatcher {
     ) -> i32 {
         unsafe {
             mem_conv::with_bytes(data_offset, data_length, |bytes| {
-                self.handler.mb_append_bytes(accumulator_handle, bytes);
+                self.managed_types_handler()
+                    .mb_append_bytes(accumulator_handle, bytes);
             });
         }
         0
     }
 
     fn mbuffer_to_big_int_unsigned(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .mb_to_big_int_unsigned(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_to_big_int_signed(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .mb_to_big_int_signed(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_from_big_int_unsigned(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .mb_from_big_int_unsigned(m_buffer_handle, big_int_handle);
         0
     }
 
     fn mbuffer_from_big_int_signed(&self, m_buffer_handle: i32, big_int_handle: i32) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .mb_from_big_int_signed(m_buffer_handle, big_int_handle);
         0
     }
@@ -1408,13 +1448,13 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn mbuffer_storage_store(&self, key_handle: i32, source_handle: i32) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .storage_store_managed_buffer_raw(key_handle, source_handle);
         0
     }
 
     fn mbuffer_storage_load(&self, key_handle: i32, destination_handle: i32) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .storage_load_managed_buffer_raw(key_handle, destination_handle);
         0
     }
@@ -1425,50 +1465,58 @@ impl VMHooks for VMHooksDispatcher {
         key_handle: i32,
         destination_handle: i32,
     ) {
-        self.handler
-            .storage_load_from_address(address_handle, key_handle, destination_handle);
+        self.managed_types_handler().storage_load_from_address(
+            address_handle,
+            key_handle,
+            destination_handle,
+        );
     }
 
     fn mbuffer_get_argument(&self, id: i32, destination_handle: i32) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .load_argument_managed_buffer(id, destination_handle);
         0
     }
 
     fn mbuffer_finish(&self, source_handle: i32) -> i32 {
-        self.handler.finish_managed_buffer_raw(source_handle);
+        self.managed_types_handler()
+            .finish_managed_buffer_raw(source_handle);
         0
     }
 
     fn mbuffer_set_random(&self, destination_handle: i32, length: i32) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .mb_set_random(destination_handle, length as usize);
         0
     }
 
     fn managed_map_new(&self) -> i32 {
-        self.handler.mm_new()
+        self.managed_types_handler().mm_new()
     }
 
     fn managed_map_put(&self, map_handle: i32, key_handle: i32, value_handle: i32) -> i32 {
-        self.handler.mm_put(map_handle, key_handle, value_handle);
+        self.managed_types_handler()
+            .mm_put(map_handle, key_handle, value_handle);
         0
     }
 
     fn managed_map_get(&self, map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .mm_get(map_handle, key_handle, out_value_handle);
         0
     }
 
     fn managed_map_remove(&self, map_handle: i32, key_handle: i32, out_value_handle: i32) -> i32 {
-        self.handler
+        self.managed_types_handler()
             .mm_remove(map_handle, key_handle, out_value_handle);
         0
     }
 
     fn managed_map_contains(&self, map_handle: i32, key_handle: i32) -> i32 {
-        bool_to_i32(self.handler.mm_contains(map_handle, key_handle))
+        bool_to_i32(
+            self.managed_types_handler()
+                .mm_contains(map_handle, key_handle),
+        )
     }
 
     fn small_int_get_unsigned_argument(&self, id: i32) -> i64 {
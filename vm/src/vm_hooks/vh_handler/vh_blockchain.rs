@@ -36,7 +36,8 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     }
 
     fn get_shard_of_address(&self, address_bytes: &[u8]) -> i32 {
-        (address_bytes[address_bytes.len() - 1] % 3).into()
+        self.shard_model()
+            .compute_shard_id(&VMAddress::from_slice(address_bytes)) as i32
     }
 
     fn is_smart_contract(&self, address_bytes: &[u8]) -> bool {
@@ -58,7 +59,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.input_ref().gas_limit
+        self.gas_left()
     }
 
     fn get_block_timestamp(&self) -> u64 {
@@ -5,6 +5,7 @@ use crate::{
     },
     tx_mock::{AsyncCallTxData, Promise, TxFunctionName, TxTokenTransfer},
     types::{top_encode_big_uint, top_encode_u64, RawHandle, VMAddress, VMCodeMetadata},
+    vm_err_msg,
     vm_hooks::VMHooksHandlerSource,
 };
 use num_traits::Zero;
@@ -26,7 +27,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         to: VMAddress,
         token: Vec<u8>,
         amount: num_bigint::BigUint,
-        _gas_limit: u64,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) {
@@ -36,6 +37,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_transfer_execute(
             to,
             num_bigint::BigUint::zero(),
+            gas_limit,
             ESDT_TRANSFER_FUNC_NAME.into(),
             args,
         );
@@ -48,7 +50,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         token: Vec<u8>,
         nonce: u64,
         amount: num_bigint::BigUint,
-        _gas_limit: u64,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) {
@@ -66,6 +68,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_transfer_execute(
             contract_address,
             num_bigint::BigUint::zero(),
+            gas_limit,
             ESDT_NFT_TRANSFER_FUNC_NAME.into(),
             args,
         );
@@ -75,7 +78,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         &self,
         to: VMAddress,
         payments: Vec<TxTokenTransfer>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) {
@@ -97,6 +100,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_transfer_execute(
             contract_address,
             num_bigint::BigUint::zero(),
+            gas_limit,
             ESDT_MULTI_TRANSFER_FUNC_NAME.into(),
             args,
         );
@@ -106,20 +110,37 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         contract_code: Vec<u8>,
         code_metadata: VMCodeMetadata,
         args: Vec<Vec<u8>>,
     ) -> ! {
         let mut arguments = vec![contract_code, code_metadata.to_vec()];
         arguments.extend(args);
-        self.perform_async_call(to, egld_value, UPGRADE_CONTRACT_FUNC_NAME.into(), arguments)
+        self.perform_async_call(
+            to,
+            egld_value,
+            gas_limit,
+            UPGRADE_CONTRACT_FUNC_NAME.into(),
+            arguments,
+        )
+    }
+
+    /// The code to be copied in a deploy/upgrade from source.
+    /// Fails the transaction if the source is not a smart contract.
+    fn source_contract_code(&self, source_contract_address: &VMAddress) -> Vec<u8> {
+        let code = self.account_code(source_contract_address);
+        if code.is_empty() {
+            self.vm_error(vm_err_msg::CONTRACT_NOT_FOUND);
+        }
+        code
     }
 
     fn transfer_value_execute(
         &self,
         to_handle: RawHandle,
         amount_handle: RawHandle,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> Result<(), &'static [u8]> {
@@ -130,7 +151,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             .mb_to_function_name(endpoint_name_handle);
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
-        self.perform_transfer_execute(recipient, egld_value, endpoint_name, arg_buffer);
+        self.perform_transfer_execute(recipient, egld_value, gas_limit, endpoint_name, arg_buffer);
 
         Ok(())
     }
@@ -193,7 +214,9 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             .mb_to_function_name(endpoint_name_handle);
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
-        self.perform_async_call(to, egld_value, endpoint_name, arg_buffer)
+        // legacy async calls get all the gas left
+        let gas_limit = self.gas_left();
+        self.perform_async_call(to, egld_value, gas_limit, endpoint_name, arg_buffer)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -205,8 +228,8 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         arg_buffer_handle: RawHandle,
         success_callback: &[u8],
         error_callback: &[u8],
-        _gas: u64,
-        _extra_gas_for_callback: u64,
+        gas: u64,
+        extra_gas_for_callback: u64,
         callback_closure_handle: RawHandle,
     ) {
         let contract_address = self.current_address().clone();
@@ -226,12 +249,14 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             endpoint_name,
             arguments: arg_buffer,
             tx_hash,
+            gas_limit: self.nested_call_gas_limit(gas),
         };
 
         let promise = Promise {
             call,
             success_callback: success_callback.into(),
             error_callback: error_callback.into(),
+            extra_gas_for_callback,
             callback_closure_data,
         };
 
@@ -243,7 +268,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
     #[allow(clippy::too_many_arguments)]
     fn deploy_contract(
         &self,
-        _gas: u64,
+        gas: u64,
         egld_value_handle: RawHandle,
         code_handle: RawHandle,
         code_metadata_handle: RawHandle,
@@ -259,7 +284,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
         let (new_address, result) =
-            self.perform_deploy(egld_value, code, code_metadata, arg_buffer);
+            self.perform_deploy(egld_value, gas, code, code_metadata, arg_buffer);
 
         self.m_types_lock()
             .mb_set(new_address_handle, new_address.to_vec());
@@ -270,7 +295,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
     #[allow(clippy::too_many_arguments)]
     fn deploy_from_source_contract(
         &self,
-        _gas: u64,
+        gas: u64,
         egld_value_handle: RawHandle,
         source_contract_address_handle: RawHandle,
         code_metadata_handle: RawHandle,
@@ -282,14 +307,19 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         let source_contract_address = self
             .m_types_lock()
             .mb_to_address(source_contract_address_handle);
-        let source_contract_code = self.account_code(&source_contract_address);
+        let source_contract_code = self.source_contract_code(&source_contract_address);
         let code_metadata = self
             .m_types_lock()
             .mb_to_code_metadata(code_metadata_handle);
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
-        let (new_address, result) =
-            self.perform_deploy(egld_value, source_contract_code, code_metadata, arg_buffer);
+        let (new_address, result) = self.perform_deploy(
+            egld_value,
+            gas,
+            source_contract_code,
+            code_metadata,
+            arg_buffer,
+        );
 
         self.m_types_lock()
             .mb_set(new_address_handle, new_address.to_vec());
@@ -300,7 +330,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
     fn upgrade_from_source_contract(
         &self,
         sc_address_handle: RawHandle,
-        _gas: u64,
+        gas: u64,
         egld_value_handle: RawHandle,
         source_contract_address_handle: RawHandle,
         code_metadata_handle: RawHandle,
@@ -311,7 +341,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         let source_contract_address = self
             .m_types_lock()
             .mb_to_address(source_contract_address_handle);
-        let source_contract_code = self.account_code(&source_contract_address);
+        let source_contract_code = self.source_contract_code(&source_contract_address);
         let code_metadata = self
             .m_types_lock()
             .mb_to_code_metadata(code_metadata_handle);
@@ -320,6 +350,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         self.perform_upgrade_contract(
             to,
             egld_value,
+            gas,
             source_contract_code,
             code_metadata,
             arg_buffer,
@@ -329,7 +360,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
     fn upgrade_contract(
         &self,
         sc_address_handle: RawHandle,
-        _gas: u64,
+        gas: u64,
         egld_value_handle: RawHandle,
         code_handle: RawHandle,
         code_metadata_handle: RawHandle,
@@ -343,12 +374,12 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             .mb_to_code_metadata(code_metadata_handle);
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
-        self.perform_upgrade_contract(to, egld_value, code, code_metadata, arg_buffer)
+        self.perform_upgrade_contract(to, egld_value, gas, code, code_metadata, arg_buffer)
     }
 
     fn execute_on_dest_context_raw(
         &self,
-        _gas: u64,
+        gas: u64,
         to_handle: RawHandle,
         egld_value_handle: RawHandle,
         endpoint_name_handle: RawHandle,
@@ -363,7 +394,7 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
 
         let result =
-            self.perform_execute_on_dest_context(to, egld_value, endpoint_name, arg_buffer);
+            self.perform_execute_on_dest_context(to, egld_value, gas, endpoint_name, arg_buffer);
 
         self.m_types_lock()
             .mb_set_vec_of_bytes(result_handle, result);
@@ -5,8 +5,8 @@ use multiversx_chain_vm_executor::BreakpointValue;
 use crate::{
     tx_execution::execute_current_tx_context_input,
     tx_mock::{
-        async_call_tx_input, AsyncCallTxData, BlockchainUpdate, TxCache, TxContext, TxFunctionName,
-        TxInput, TxManagedTypes, TxPanic, TxResult,
+        async_call_tx_input, AsyncCallTxData, BlockchainUpdate, GasCost, TxCache, TxContext,
+        TxFunctionName, TxInput, TxManagedTypes, TxPanic, TxResult,
     },
     types::{VMAddress, VMCodeMetadata},
     vm_err_msg,
@@ -16,7 +16,7 @@ use crate::{
         VMHooksHandler, VMHooksHandlerSource, VMHooksLog, VMHooksManagedBuffer, VMHooksManagedMap,
         VMHooksManagedTypes, VMHooksSend, VMHooksStorageRead, VMHooksStorageWrite,
     },
-    world_mock::{reserved::STORAGE_RESERVED_PREFIX, AccountData, BlockInfo},
+    world_mock::{reserved::STORAGE_RESERVED_PREFIX, AccountData, BlockInfo, ShardModel},
 };
 
 /// A simple wrapper around a managed type container RefCell.
@@ -45,6 +45,21 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         std::panic::panic_any(breakpoint);
     }
 
+    fn use_gas(&self, gas_cost: GasCost) {
+        let enough_gas = self.0.gas_meter_lock().use_gas(gas_cost);
+        if !enough_gas {
+            self.out_of_gas();
+        }
+    }
+
+    fn gas_left(&self) -> u64 {
+        self.0.gas_meter_lock().gas_left()
+    }
+
+    fn nested_call_gas_limit(&self, requested_gas: u64) -> u64 {
+        self.0.gas_meter_lock().nested_call_gas_limit(requested_gas)
+    }
+
     fn input_ref(&self) -> &TxInput {
         self.0.input_ref()
     }
@@ -58,16 +73,32 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
     }
 
     fn storage_read_any_address(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
-        self.0.with_account_mut(address, |account| {
+        let is_own_storage = address == &self.input_ref().to;
+        self.result_lock().storage_access.record_read(address, key);
+        let value = self.0.with_account_mut(address, |account| {
+            // same as the real VM: the storage of non-readable contracts is seen as empty
+            if !is_own_storage && !account.code_metadata.is_readable() {
+                return Vec::new();
+            }
             account.storage.get(key).cloned().unwrap_or_default()
-        })
+        });
+        self.use_gas(GasCost::StorageLoad {
+            value_len: value.len(),
+        });
+        value
     }
 
     fn storage_write(&self, key: &[u8], value: &[u8]) {
         self.check_reserved_key(key);
+        self.use_gas(GasCost::StorageStore {
+            value_len: value.len(),
+        });
 
+        self.result_lock()
+            .storage_access
+            .record_write(self.current_address(), key);
         self.0.with_contract_account_mut(|account| {
-            account.storage.insert(key.to_vec(), value.to_vec());
+            account.storage_write(key, value);
         });
     }
 
@@ -79,25 +110,35 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         &self.0.blockchain_ref().current_block_info
     }
 
+    fn shard_model(&self) -> ShardModel {
+        self.0.blockchain_ref().shard_model
+    }
+
     fn account_data(&self, address: &VMAddress) -> AccountData {
         self.0.with_account(address, |account| account.clone())
     }
 
     fn account_code(&self, address: &VMAddress) -> Vec<u8> {
-        self.0
-            .blockchain_cache()
+        let blockchain_cache = self.0.blockchain_cache();
+        if !blockchain_cache.account_exists(address) {
+            return Vec::new();
+        }
+        blockchain_cache
             .with_account(address, |account| account.contract_path.clone())
-            .unwrap_or_else(|| panic!("Account is not a smart contract, it has no code"))
+            .unwrap_or_default()
     }
 
     fn perform_async_call(
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> ! {
-        let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
+        self.use_gas(GasCost::ContractCall);
+        let async_call_data =
+            self.create_async_call_data(to, egld_value, gas_limit, func_name, arguments);
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.result_lock();
         tx_result.all_calls.push(async_call_data.clone());
@@ -110,10 +151,13 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
-        let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
+        self.use_gas(GasCost::ContractCall);
+        let async_call_data =
+            self.create_async_call_data(to, egld_value, gas_limit, func_name, arguments);
         let tx_input = async_call_tx_input(&async_call_data);
         let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
         let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
@@ -121,6 +165,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.use_nested_execution_gas(&tx_result);
 
         if tx_result.result_status == 0 {
             self.sync_call_post_processing(tx_result, blockchain_updates)
@@ -133,10 +178,12 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
     fn perform_deploy(
         &self,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         contract_code: Vec<u8>,
-        _code_metadata: VMCodeMetadata,
+        code_metadata: VMCodeMetadata,
         args: Vec<Vec<u8>>,
     ) -> (VMAddress, Vec<Vec<u8>>) {
+        self.use_gas(GasCost::ContractCall);
         let contract_address = self.current_address();
         let tx_hash = self.tx_hash();
         let tx_input = TxInput {
@@ -146,7 +193,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             esdt_values: Vec::new(),
             func_name: TxFunctionName::EMPTY,
             args,
-            gas_limit: 1000,
+            gas_limit: self.nested_call_gas_limit(gas_limit),
             gas_price: 0,
             tx_hash,
             ..Default::default()
@@ -157,9 +204,11 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         let (tx_result, new_address, blockchain_updates) = self.0.vm_ref.deploy_contract(
             tx_input,
             contract_code,
+            code_metadata,
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.use_nested_execution_gas(&tx_result);
 
         match tx_result.result_status {
             0 => (
@@ -175,10 +224,13 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) {
-        let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
+        self.use_gas(GasCost::ContractCall);
+        let async_call_data =
+            self.create_async_call_data(to, egld_value, gas_limit, func_name, arguments);
         let tx_input = async_call_tx_input(&async_call_data);
         let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
         let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
@@ -186,6 +238,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             tx_cache,
             execute_current_tx_context_input,
         );
+        self.use_nested_execution_gas(&tx_result);
 
         match tx_result.result_status {
             0 => {
@@ -204,6 +257,7 @@ impl DebugApiVMHooksHandler {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> AsyncCallTxData {
@@ -216,6 +270,16 @@ impl DebugApiVMHooksHandler {
             endpoint_name: func_name,
             arguments,
             tx_hash,
+            gas_limit: self.nested_call_gas_limit(gas_limit),
+        }
+    }
+
+    /// The caller pays for the gas used by the synchronous calls it makes.
+    fn use_nested_execution_gas(&self, tx_result: &TxResult) {
+        if let Some(gas_usage) = &tx_result.gas_usage {
+            self.use_gas(GasCost::NestedExecution {
+                gas_used: gas_usage.gas_used,
+            });
         }
     }
 
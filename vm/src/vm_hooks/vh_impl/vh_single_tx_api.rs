@@ -81,7 +81,7 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
 
     fn storage_write(&self, key: &[u8], value: &[u8]) {
         self.0.with_account_mut(&self.0.tx_input_box.to, |account| {
-            account.storage.insert(key.to_vec(), value.to_vec());
+            account.storage_write(key, value);
         });
     }
 
@@ -105,6 +105,7 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         &self,
         _to: VMAddress,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _func_name: TxFunctionName,
         _args: Vec<Vec<u8>>,
     ) -> ! {
@@ -115,6 +116,7 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         &self,
         _to: VMAddress,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _func_name: TxFunctionName,
         _args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
@@ -124,6 +126,7 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
     fn perform_deploy(
         &self,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _contract_code: Vec<u8>,
         _code_metadata: VMCodeMetadata,
         _args: Vec<Vec<u8>>,
@@ -135,6 +138,7 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         &self,
         _to: VMAddress,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _func_name: TxFunctionName,
         _arguments: Vec<Vec<u8>>,
     ) {
@@ -80,6 +80,7 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         &self,
         _to: VMAddress,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _func_name: TxFunctionName,
         _args: Vec<Vec<u8>>,
     ) -> ! {
@@ -90,6 +91,7 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         &self,
         _to: VMAddress,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _func_name: TxFunctionName,
         _args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
@@ -99,6 +101,7 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
     fn perform_deploy(
         &self,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _contract_code: Vec<u8>,
         _code_metadata: VMCodeMetadata,
         _args: Vec<Vec<u8>>,
@@ -110,6 +113,7 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         &self,
         _to: VMAddress,
         _egld_value: num_bigint::BigUint,
+        _gas_limit: u64,
         _func_name: TxFunctionName,
         _arguments: Vec<Vec<u8>>,
     ) {
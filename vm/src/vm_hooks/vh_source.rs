@@ -1,9 +1,11 @@
 use std::{fmt::Debug, sync::MutexGuard};
 
 use crate::{
-    tx_mock::{TxFunctionName, TxInput, TxLog, TxManagedTypes, TxResult},
+    tx_mock::{
+        GasCost, TxFunctionName, TxInput, TxLog, TxManagedTypes, TxResult, UNMETERED_CALL_GAS_LIMIT,
+    },
     types::{VMAddress, VMCodeMetadata, H256},
-    world_mock::{AccountData, BlockInfo},
+    world_mock::{AccountData, BlockInfo, ShardModel},
 };
 
 /// Abstracts away the borrowing of a managed types structure.
@@ -16,6 +18,22 @@ pub trait VMHooksHandlerSource: Debug {
         self.halt_with_error(10, message)
     }
 
+    fn out_of_gas(&self) -> ! {
+        self.halt_with_error(5, "not enough gas")
+    }
+
+    /// Handlers that do not meter gas ignore it.
+    fn use_gas(&self, _gas_cost: GasCost) {}
+
+    fn gas_left(&self) -> u64 {
+        self.input_ref().gas_limit
+    }
+
+    /// The gas limit for calls to other contracts, at most `requested_gas`.
+    fn nested_call_gas_limit(&self, _requested_gas: u64) -> u64 {
+        UNMETERED_CALL_GAS_LIMIT
+    }
+
     fn input_ref(&self) -> &TxInput;
 
     fn current_address(&self) -> &VMAddress {
@@ -47,6 +65,11 @@ pub trait VMHooksHandlerSource: Debug {
 
     fn get_current_block_info(&self) -> &BlockInfo;
 
+    /// Handlers without access to the blockchain state fall back to the default shard configuration.
+    fn shard_model(&self) -> ShardModel {
+        ShardModel::default()
+    }
+
     /// For ownership reasons, needs to return a clone.
     ///
     /// Can be optimized, but is not a priority right now.
@@ -59,12 +82,14 @@ pub trait VMHooksHandlerSource: Debug {
         self.account_data(&self.input_ref().to)
     }
 
+    /// Empty if the account does not exist, or is not a smart contract.
     fn account_code(&self, address: &VMAddress) -> Vec<u8>;
 
     fn perform_async_call(
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         args: Vec<Vec<u8>>,
     ) -> !;
@@ -73,6 +98,7 @@ pub trait VMHooksHandlerSource: Debug {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>>;
@@ -80,6 +106,7 @@ pub trait VMHooksHandlerSource: Debug {
     fn perform_deploy(
         &self,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         contract_code: Vec<u8>,
         code_metadata: VMCodeMetadata,
         args: Vec<Vec<u8>>,
@@ -89,6 +116,7 @@ pub trait VMHooksHandlerSource: Debug {
         &self,
         to: VMAddress,
         egld_value: num_bigint::BigUint,
+        gas_limit: u64,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     );
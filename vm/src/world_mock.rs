@@ -3,21 +3,35 @@ mod block_info;
 mod blockchain_mock;
 mod blockchain_mock_account_util;
 mod blockchain_state;
+mod commit_observer;
+mod cross_shard_queue;
 mod esdt_data;
+mod esdt_global_settings;
 mod esdt_instance;
 mod esdt_instance_metadata;
 mod esdt_instances;
 mod esdt_roles;
 mod failing_executor;
+mod gas_schedule;
+mod new_address_generator;
+mod protocol_config;
 pub mod reserved;
+mod shard_model;
 
 pub use account_data::*;
 pub use block_info::*;
 pub use blockchain_mock::*;
 pub use blockchain_state::BlockchainState;
+pub use commit_observer::CommitObserver;
+pub use cross_shard_queue::{CrossShardQueue, CrossShardStep, CrossShardValue};
 pub use esdt_data::*;
+pub use esdt_global_settings::EsdtGlobalSettings;
 pub use esdt_instance::*;
 pub use esdt_instance_metadata::*;
 pub use esdt_instances::*;
 pub use esdt_roles::*;
 pub use failing_executor::FailingExecutor;
+pub use gas_schedule::GasSchedule;
+pub use new_address_generator::{NewAddressGenerator, ProtocolNewAddressGenerator};
+pub use protocol_config::{ProtocolConfig, ProtocolFeature};
+pub use shard_model::{ShardModel, METACHAIN_SHARD_ID};
@@ -2,12 +2,15 @@ use num_bigint::BigUint;
 use num_traits::Zero;
 
 use super::AccountEsdt;
-use crate::{display_util::key_hex, types::VMAddress};
+use crate::{
+    display_util::{key_hex, sorted_by_bytes_key, DebugMap},
+    types::{VMAddress, VMCodeMetadata},
+};
 use std::{collections::HashMap, fmt, fmt::Write};
 
 pub type AccountStorage = HashMap<Vec<u8>, Vec<u8>>;
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct AccountData {
     pub address: VMAddress,
     pub nonce: u64,
@@ -17,6 +20,7 @@ pub struct AccountData {
     pub username: Vec<u8>,
     pub contract_path: Option<Vec<u8>>,
     pub contract_owner: Option<VMAddress>,
+    pub code_metadata: VMCodeMetadata,
     pub developer_rewards: BigUint,
 }
 
@@ -31,19 +35,43 @@ impl AccountData {
             username: vec![],
             contract_path: None,
             contract_owner: None,
+            code_metadata: VMCodeMetadata::DEFAULT,
             developer_rewards: BigUint::zero(),
         }
     }
+
+    /// Writes a storage value, the same way as the protocol does:
+    /// writing an empty value deletes the key, so "empty" and "absent" are never distinguishable.
+    pub fn storage_write(&mut self, key: &[u8], value: &[u8]) {
+        if value.is_empty() {
+            self.storage.remove(key);
+        } else {
+            self.storage.insert(key.to_vec(), value.to_vec());
+        }
+    }
+}
+
+impl fmt::Debug for AccountData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccountData")
+            .field("address", &self.address)
+            .field("nonce", &self.nonce)
+            .field("egld_balance", &self.egld_balance)
+            .field("esdt", &self.esdt)
+            .field("storage", &DebugMap(sorted_by_bytes_key(&self.storage)))
+            .field("username", &self.username)
+            .field("contract_path", &self.contract_path)
+            .field("contract_owner", &self.contract_owner)
+            .field("code_metadata", &self.code_metadata)
+            .field("developer_rewards", &self.developer_rewards)
+            .finish()
+    }
 }
 
 impl fmt::Display for AccountData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut storage_buf = String::new();
-        let mut storage_keys: Vec<Vec<u8>> = self.storage.keys().cloned().collect();
-        storage_keys.sort();
-
-        for key in &storage_keys {
-            let value = self.storage.get(key).unwrap();
+        for (key, value) in sorted_by_bytes_key(&self.storage) {
             write!(
                 storage_buf,
                 "\n\t\t\t{} -> 0x{}",
@@ -1,6 +1,9 @@
 use std::{collections::HashMap, fmt::Write};
 
-use crate::{display_util::address_hex, types::VMAddress};
+use crate::{
+    display_util::{address_hex, sorted_by_address},
+    types::VMAddress,
+};
 
 use super::{AccountData, BlockchainState};
 
@@ -21,7 +24,7 @@ impl BlockchainState {
 
     pub fn print_accounts(&self) {
         let mut accounts_buf = String::new();
-        for (address, account) in &self.accounts {
+        for (address, account) in sorted_by_address(&self.accounts) {
             write!(accounts_buf, "\n\t{} -> {account}", address_hex(address)).unwrap();
         }
         println!("Accounts: {}", &accounts_buf);
@@ -42,9 +45,16 @@ impl BlockchainState {
         creator_address: VMAddress,
         creator_nonce: u64,
     ) -> Option<VMAddress> {
-        self.new_addresses
-            .get(&(creator_address, creator_nonce))
-            .cloned()
+        if let Some(new_address) = self
+            .new_addresses
+            .get(&(creator_address.clone(), creator_nonce))
+        {
+            return Some(new_address.clone());
+        }
+
+        self.new_address_generator
+            .as_ref()
+            .map(|generator| generator.generate_new_address(&creator_address, creator_nonce))
     }
 
     pub fn validate_account(&self, account: &AccountData) {
@@ -1,19 +1,39 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
 
 use num_bigint::BigUint;
 use num_traits::Zero;
 
-use crate::{tx_mock::BlockchainUpdate, types::VMAddress};
+use crate::{
+    display_util::{sorted_by_address, sorted_by_bytes_key, DebugMap},
+    tx_mock::BlockchainUpdate,
+    types::VMAddress,
+};
 
-use super::{reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo};
+use super::{
+    reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo, CommitObserver, CrossShardQueue,
+    CrossShardStep, EsdtGlobalSettings, GasSchedule, NewAddressGenerator, ProtocolConfig,
+    ProtocolFeature, ShardModel,
+};
 
 #[derive(Default, Clone)]
 pub struct BlockchainState {
     pub accounts: HashMap<VMAddress, AccountData>,
     pub new_addresses: HashMap<(VMAddress, u64), VMAddress>,
+    /// Fallback for deploys that have no explicit new address configured.
+    /// If missing, such deploys are not allowed.
+    pub new_address_generator: Option<Arc<dyn NewAddressGenerator>>,
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
     pub new_token_identifiers: Vec<String>,
+    pub esdt_global_settings: HashMap<Vec<u8>, EsdtGlobalSettings>,
+    pub shard_model: ShardModel,
+    /// Async calls and callbacks between shards, waiting for a later block, if cross-shard simulation is enabled.
+    pub cross_shard_queue: CrossShardQueue,
+    pub protocol_config: ProtocolConfig,
+    /// Gas is only metered if a gas schedule is configured.
+    pub gas_schedule: Option<GasSchedule>,
+    /// Notified, in order, each time a blockchain update is committed.
+    pub commit_observers: Vec<Arc<dyn CommitObserver>>,
 }
 
 impl BlockchainState {
@@ -21,6 +41,27 @@ impl BlockchainState {
         updates.apply(self);
     }
 
+    pub fn add_commit_observer(&mut self, observer: Arc<dyn CommitObserver>) {
+        self.commit_observers.push(observer);
+    }
+
+    /// Checks the protocol config against the current block epoch.
+    pub fn is_feature_active(&self, feature: ProtocolFeature) -> bool {
+        self.protocol_config
+            .is_active(feature, self.current_block_info.block_epoch)
+    }
+
+    /// Only ever true if cross-shard simulation is enabled.
+    pub fn is_cross_shard(&self, from: &VMAddress, to: &VMAddress) -> bool {
+        self.cross_shard_queue.enabled && !self.shard_model.same_shard(from, to)
+    }
+
+    /// The step gets executed in the next block, at the earliest.
+    pub fn queue_cross_shard_step(&mut self, step: CrossShardStep) {
+        let block_nonce = self.current_block_info.block_nonce + 1;
+        self.cross_shard_queue.push(block_nonce, step);
+    }
+
     pub fn account_exists(&self, address: &VMAddress) -> bool {
         self.accounts.contains_key(address)
     }
@@ -81,14 +122,52 @@ impl BlockchainState {
     pub fn update_new_token_identifiers(&mut self, token_identifiers: Vec<String>) {
         self.new_token_identifiers = token_identifiers;
     }
+
+    pub fn put_esdt_global_settings(
+        &mut self,
+        token_identifier: Vec<u8>,
+        settings: EsdtGlobalSettings,
+    ) {
+        self.esdt_global_settings.insert(token_identifier, settings);
+    }
+
+    pub fn get_esdt_global_settings(&self, token_identifier: &[u8]) -> Option<&EsdtGlobalSettings> {
+        self.esdt_global_settings.get(token_identifier)
+    }
+
+    /// Number of decimals of a token, if it is known to the token registry.
+    pub fn get_token_decimals(&self, token_identifier: &[u8]) -> Option<u32> {
+        self.get_esdt_global_settings(token_identifier)
+            .map(|settings| settings.num_decimals)
+    }
+
+    pub fn get_token_supply(&self, token_identifier: &[u8]) -> Option<BigUint> {
+        self.get_esdt_global_settings(token_identifier)
+            .map(EsdtGlobalSettings::supply)
+    }
+
+    pub fn get_token_owner(&self, token_identifier: &[u8]) -> Option<VMAddress> {
+        self.get_esdt_global_settings(token_identifier)
+            .and_then(|settings| settings.owner.clone())
+    }
 }
 
 impl Debug for BlockchainState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut new_addresses: Vec<(&(VMAddress, u64), &VMAddress)> =
+            self.new_addresses.iter().collect();
+        new_addresses.sort_by(|((a, a_nonce), _), ((b, b_nonce), _)| {
+            (a.as_bytes(), a_nonce).cmp(&(b.as_bytes(), b_nonce))
+        });
+
         f.debug_struct("BlockchainState")
-            .field("accounts", &self.accounts)
-            .field("new_addresses", &self.new_addresses)
+            .field("accounts", &DebugMap(sorted_by_address(&self.accounts)))
+            .field("new_addresses", &DebugMap(new_addresses))
             .field("current_block_info", &self.current_block_info)
+            .field(
+                "esdt_global_settings",
+                &DebugMap(sorted_by_bytes_key(&self.esdt_global_settings)),
+            )
             .finish()
     }
 }
@@ -0,0 +1,24 @@
+use crate::types::VMAddress;
+
+use super::BlockchainState;
+
+/// Gets notified whenever the updates of a transaction are committed to the blockchain state.
+///
+/// Allows test harnesses to build custom bookkeeping on top of the VM,
+/// e.g. indexers or invariant checks.
+pub trait CommitObserver: Send + Sync {
+    /// Called right after the updates were applied, with the resulting state.
+    ///
+    /// `changed_accounts` are the addresses of all the accounts touched by the committed updates,
+    /// sorted by address bytes.
+    fn on_commit(&self, changed_accounts: &[VMAddress], state: &BlockchainState);
+}
+
+impl<F> CommitObserver for F
+where
+    F: Fn(&[VMAddress], &BlockchainState) + Send + Sync,
+{
+    fn on_commit(&self, changed_accounts: &[VMAddress], state: &BlockchainState) {
+        self(changed_accounts, state)
+    }
+}
@@ -0,0 +1,91 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+use std::collections::VecDeque;
+
+use crate::{
+    tx_mock::{AsyncCallTxData, Promise, TxInput, TxTokenTransfer},
+    types::VMAddress,
+};
+
+use super::EsdtInstanceMetadata;
+
+/// A part of an async call that crosses shards, which only gets executed in a later block.
+#[derive(Clone, Debug)]
+pub enum CrossShardStep {
+    /// Legacy async call. Its callback gets queued in turn, once the call is executed.
+    AsyncCall {
+        async_data: AsyncCallTxData,
+        value: CrossShardValue,
+    },
+
+    /// Promise registered by the `caller` contract. Its callback gets queued in turn, if there is one.
+    PromiseCall {
+        caller: VMAddress,
+        promise: Promise,
+        value: CrossShardValue,
+    },
+
+    /// Callback of a legacy async call or of a promise, executed back on the shard of the caller.
+    ///
+    /// The refund is the value of a failed call, credited back to the caller before the callback.
+    Callback {
+        callback_input: TxInput,
+        refund: CrossShardValue,
+    },
+}
+
+/// The EGLD and ESDT value sent with a cross-shard call.
+///
+/// It leaves the sender when the call is queued, and is only credited to the destination when the call is executed.
+#[derive(Clone, Debug, Default)]
+pub struct CrossShardValue {
+    pub egld_value: BigUint,
+    pub esdt_values: Vec<(TxTokenTransfer, EsdtInstanceMetadata)>,
+}
+
+impl CrossShardValue {
+    pub fn is_empty(&self) -> bool {
+        self.egld_value.is_zero() && self.esdt_values.is_empty()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CrossShardCall {
+    block_nonce: u64,
+    step: CrossShardStep,
+}
+
+/// Async calls and callbacks that cross shards, in the order in which they were sent.
+///
+/// Only used if enabled, otherwise all async calls and callbacks are executed right away,
+/// as if all accounts were in the same shard.
+#[derive(Clone, Debug, Default)]
+pub struct CrossShardQueue {
+    pub enabled: bool,
+    calls: VecDeque<CrossShardCall>,
+}
+
+impl CrossShardQueue {
+    /// Queues a step, to be executed once the block nonce reaches `block_nonce`.
+    pub fn push(&mut self, block_nonce: u64, step: CrossShardStep) {
+        self.calls.push_back(CrossShardCall { block_nonce, step });
+    }
+
+    /// Removes the steps that are due at the given block nonce, in the order in which they were queued.
+    pub fn take_due(&mut self, block_nonce: u64) -> Vec<CrossShardStep> {
+        let (due, pending): (VecDeque<CrossShardCall>, VecDeque<CrossShardCall>) = self
+            .calls
+            .drain(..)
+            .partition(|call| call.block_nonce <= block_nonce);
+        self.calls = pending;
+        due.into_iter().map(|call| call.step).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+}
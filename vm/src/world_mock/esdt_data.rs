@@ -1,10 +1,13 @@
 use num_bigint::BigUint;
 use num_traits::Zero;
 
-use crate::{display_util::key_hex, types::VMTokenType};
+use crate::{
+    display_util::{key_hex, sorted_by_bytes_key, DebugMap},
+    types::VMTokenType,
+};
 use std::{
     collections::{hash_map::Iter, HashMap},
-    fmt::{self, Write},
+    fmt,
 };
 
 use super::{EsdtInstanceMetadata, EsdtInstances, EsdtRoles};
@@ -30,7 +33,7 @@ impl EsdtData {
     }
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default)]
 pub struct AccountEsdt(HashMap<Vec<u8>, EsdtData>);
 
 impl AccountEsdt {
@@ -204,10 +207,9 @@ impl AccountEsdt {
 }
 
 impl fmt::Display for EsdtData {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut esdt_buf = String::new();
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
-            esdt_buf,
+            f,
             "{{
                 instances: [{}],
                 last_nonce: {},
@@ -215,19 +217,22 @@ impl fmt::Display for EsdtData {
                 frozen: {},
             }}",
             self.instances, self.last_nonce, self.roles, self.frozen
-        )?;
-        Ok(())
+        )
     }
 }
 
-impl fmt::Display for AccountEsdt {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut esdt_buf = String::new();
-        let esdt_keys: Vec<Vec<u8>> = self.0.keys().cloned().collect();
+impl fmt::Debug for AccountEsdt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AccountEsdt")
+            .field(&DebugMap(sorted_by_bytes_key(&self.0)))
+            .finish()
+    }
+}
 
-        for key in &esdt_keys {
-            let value = self.0.get(key).unwrap();
-            write!(esdt_buf, "\n\t\t\t{} -> {value}", key_hex(key.as_slice()))?;
+impl fmt::Display for AccountEsdt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in sorted_by_bytes_key(&self.0) {
+            write!(f, "\n\t\t\t{} -> {value}", key_hex(key.as_slice()))?;
         }
         Ok(())
     }
@@ -0,0 +1,82 @@
+use std::{collections::HashMap, fmt};
+
+use num_bigint::BigUint;
+
+use crate::{
+    display_util::{sorted_by_address, DebugMap},
+    types::{VMAddress, VMTokenType},
+};
+
+/// Token properties that are not tied to a single account, as kept by the mock token registry.
+#[derive(Clone, Default)]
+pub struct EsdtGlobalSettings {
+    pub paused: bool,
+    pub limited_transfer: bool,
+    pub roles: HashMap<VMAddress, Vec<Vec<u8>>>,
+    pub last_nonce: u64,
+    pub token_name: Vec<u8>,
+    pub token_type: Option<VMTokenType>,
+    pub owner: Option<VMAddress>,
+    pub minted_value: BigUint,
+    pub burnt_value: BigUint,
+    pub num_decimals: u32,
+}
+
+impl EsdtGlobalSettings {
+    pub fn has_role(&self, address: &VMAddress, role: &[u8]) -> bool {
+        self.roles
+            .get(address)
+            .map(|roles| roles.iter().any(|r| r.as_slice() == role))
+            .unwrap_or(false)
+    }
+
+    /// Minted minus burnt value, i.e. the supply created through the token registry and the local mint/burn builtin functions.
+    ///
+    /// Balances set directly in `setState` are not counted.
+    pub fn supply(&self) -> BigUint {
+        if self.minted_value > self.burnt_value {
+            &self.minted_value - &self.burnt_value
+        } else {
+            BigUint::default()
+        }
+    }
+
+    /// The token properties, formatted as the ESDT system SC `getTokenProperties` function returns them.
+    ///
+    /// Only the leading properties are modelled: the capability flags are not included.
+    pub fn token_properties(&self) -> Vec<Vec<u8>> {
+        vec![
+            self.token_name.clone(),
+            self.token_type
+                .map(VMTokenType::properties_name)
+                .unwrap_or_default()
+                .as_bytes()
+                .to_vec(),
+            self.owner
+                .as_ref()
+                .map(VMAddress::to_vec)
+                .unwrap_or_default(),
+            self.minted_value.to_string().into_bytes(),
+            self.burnt_value.to_string().into_bytes(),
+            format!("NumDecimals-{}", self.num_decimals).into_bytes(),
+            format!("IsPaused-{}", self.paused).into_bytes(),
+        ]
+    }
+}
+
+impl fmt::Debug for EsdtGlobalSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EsdtGlobalSettings")
+            .field("paused", &self.paused)
+            .field("limited_transfer", &self.limited_transfer)
+            .field("roles", &DebugMap(sorted_by_address(&self.roles)))
+            .field("last_nonce", &self.last_nonce)
+            .field("token_name", &self.token_name)
+            .field("token_type", &self.token_type)
+            .field("owner", &self.owner)
+            .field("minted_value", &self.minted_value)
+            .field("burnt_value", &self.burnt_value)
+            .field("num_decimals", &self.num_decimals)
+            .finish()
+    }
+}
@@ -2,10 +2,7 @@ use super::{EsdtInstance, EsdtInstanceMetadata};
 use crate::display_util::verbose_hex_list;
 use num_bigint::BigUint;
 use num_traits::Zero;
-use std::{
-    collections::BTreeMap,
-    fmt::{self, Write},
-};
+use std::{collections::BTreeMap, fmt};
 
 #[derive(Clone, Debug, Default)]
 pub struct EsdtInstances(BTreeMap<u64, EsdtInstance>);
@@ -102,8 +99,7 @@ impl EsdtInstances {
 }
 
 impl fmt::Display for EsdtInstances {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut instance_buf = String::new();
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for (_, value) in self.0.iter() {
             let creator_encoded = if let Some(creator) = &value.metadata.creator {
                 hex::encode(creator)
@@ -111,7 +107,7 @@ impl fmt::Display for EsdtInstances {
                 "".to_string()
             };
             write!(
-                instance_buf,
+                f,
                 "{{
                     nonce: {},
                     balance: {},
@@ -1,4 +1,4 @@
-use std::fmt::{self, Write};
+use std::fmt;
 
 #[derive(Clone, Default, Debug)]
 pub struct EsdtRoles(Vec<Vec<u8>>);
@@ -18,12 +18,12 @@ impl EsdtRoles {
 }
 
 impl fmt::Display for EsdtRoles {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut esdt_buf = String::new();
-        let esdt_keys: Vec<Vec<u8>> = self.clone().0.to_vec();
-
-        for value in &esdt_keys {
-            write!(esdt_buf, "{}", hex::encode(value.as_slice()))?;
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, role) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", hex::encode(role.as_slice()))?;
         }
         Ok(())
     }
@@ -0,0 +1,50 @@
+use crate::tx_mock::GasCost;
+
+/// The gas costs charged by the mock VM, if gas metering is enabled.
+///
+/// The defaults are in the same range as the mainnet gas schedule,
+/// close enough for comparing the gas usage of contracts, but not meant to reproduce it exactly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasSchedule {
+    pub storage_load: u64,
+    pub storage_load_per_byte: u64,
+    pub storage_store: u64,
+    pub storage_store_per_byte: u64,
+    /// Charged for each operation on managed buffers, big numbers and the other managed types.
+    pub managed_type_op: u64,
+    /// Charged for each builtin function call, e.g. `ESDTTransfer`, on top of the execution it triggers.
+    pub builtin_call: u64,
+    /// Charged for each call to another contract, on top of the gas used by the call itself.
+    pub contract_call: u64,
+}
+
+impl Default for GasSchedule {
+    fn default() -> Self {
+        GasSchedule {
+            storage_load: 50_000,
+            storage_load_per_byte: 50,
+            storage_store: 75_000,
+            storage_store_per_byte: 10_000,
+            managed_type_op: 2_000,
+            builtin_call: 200_000,
+            contract_call: 100_000,
+        }
+    }
+}
+
+impl GasSchedule {
+    pub fn cost(&self, gas_cost: GasCost) -> u64 {
+        match gas_cost {
+            GasCost::StorageLoad { value_len } => {
+                self.storage_load + self.storage_load_per_byte * value_len as u64
+            },
+            GasCost::StorageStore { value_len } => {
+                self.storage_store + self.storage_store_per_byte * value_len as u64
+            },
+            GasCost::ManagedTypeOp => self.managed_type_op,
+            GasCost::BuiltinCall => self.builtin_call,
+            GasCost::ContractCall => self.contract_call,
+            GasCost::NestedExecution { gas_used } => gas_used,
+        }
+    }
+}
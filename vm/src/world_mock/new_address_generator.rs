@@ -0,0 +1,43 @@
+use crate::{crypto_functions::keccak256, types::VMAddress};
+
+/// Number of leading zero bytes in a smart contract address.
+const SC_ADDRESS_NUM_LEADING_ZEROS: usize = 8;
+
+/// The VM type of WASM contracts, as it appears in smart contract addresses.
+const VM_TYPE_WASM: [u8; 2] = [5, 0];
+
+/// Produces the addresses of newly deployed contracts,
+/// whenever no explicit new address was configured for the creator and nonce.
+pub trait NewAddressGenerator: Send + Sync {
+    fn generate_new_address(&self, creator_address: &VMAddress, creator_nonce: u64) -> VMAddress;
+}
+
+impl<F> NewAddressGenerator for F
+where
+    F: Fn(&VMAddress, u64) -> VMAddress + Send + Sync,
+{
+    fn generate_new_address(&self, creator_address: &VMAddress, creator_nonce: u64) -> VMAddress {
+        self(creator_address, creator_nonce)
+    }
+}
+
+/// Derives new addresses the same way the protocol does:
+/// the hash of the creator address and nonce, with the smart contract prefix and the creator's shard suffix.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProtocolNewAddressGenerator;
+
+impl NewAddressGenerator for ProtocolNewAddressGenerator {
+    fn generate_new_address(&self, creator_address: &VMAddress, creator_nonce: u64) -> VMAddress {
+        let mut hash_input = creator_address.to_vec();
+        hash_input.extend_from_slice(&creator_nonce.to_le_bytes());
+        let mut result = keccak256(hash_input.as_slice());
+
+        result[..SC_ADDRESS_NUM_LEADING_ZEROS].fill(0);
+        result[SC_ADDRESS_NUM_LEADING_ZEROS..SC_ADDRESS_NUM_LEADING_ZEROS + VM_TYPE_WASM.len()]
+            .copy_from_slice(&VM_TYPE_WASM);
+        let creator_bytes = creator_address.as_bytes();
+        result[30..].copy_from_slice(&creator_bytes[30..]);
+
+        VMAddress::from(result)
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+/// Protocol features whose activation can be scheduled in the mock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProtocolFeature {
+    /// The `ESDTNFTAddURI` builtin function.
+    EsdtNftAddUri,
+
+    /// The `ESDTNFTUpdateAttributes` builtin function.
+    EsdtNftUpdateAttributes,
+
+    /// Plain value transfers to contracts that are not payable are rejected,
+    /// unless they come from another contract and the receiver is payable by SC.
+    PayableCheck,
+}
+
+impl ProtocolFeature {
+    /// Builtin functions are active from genesis, to match the behavior of the mock before the config existed.
+    ///
+    /// Checks that the mock did not perform before are disabled by default.
+    fn default_activation_epoch(self) -> Option<u64> {
+        match self {
+            ProtocolFeature::EsdtNftAddUri | ProtocolFeature::EsdtNftUpdateAttributes => Some(0),
+            ProtocolFeature::PayableCheck => None,
+        }
+    }
+}
+
+/// Keeps the epochs at which protocol features become active.
+///
+/// Allows testing contract behavior across a protocol upgrade boundary.
+#[derive(Clone, Debug, Default)]
+pub struct ProtocolConfig {
+    activation_epochs: HashMap<ProtocolFeature, Option<u64>>,
+}
+
+impl ProtocolConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The feature becomes active starting with the given epoch.
+    pub fn activate_at_epoch(mut self, feature: ProtocolFeature, epoch: u64) -> Self {
+        self.activation_epochs.insert(feature, Some(epoch));
+        self
+    }
+
+    /// The feature is never active.
+    pub fn deactivate(mut self, feature: ProtocolFeature) -> Self {
+        self.activation_epochs.insert(feature, None);
+        self
+    }
+
+    pub fn activation_epoch(&self, feature: ProtocolFeature) -> Option<u64> {
+        self.activation_epochs
+            .get(&feature)
+            .copied()
+            .unwrap_or_else(|| feature.default_activation_epoch())
+    }
+
+    pub fn is_active(&self, feature: ProtocolFeature, epoch: u64) -> bool {
+        self.activation_epoch(feature)
+            .map_or(false, |activation_epoch| epoch >= activation_epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protocol_config_defaults_test() {
+        let config = ProtocolConfig::new();
+        assert!(config.is_active(ProtocolFeature::EsdtNftAddUri, 0));
+        assert!(!config.is_active(ProtocolFeature::PayableCheck, 1000));
+    }
+
+    #[test]
+    fn protocol_config_activation_epoch_test() {
+        let config = ProtocolConfig::new()
+            .activate_at_epoch(ProtocolFeature::PayableCheck, 5)
+            .deactivate(ProtocolFeature::EsdtNftAddUri);
+        assert!(!config.is_active(ProtocolFeature::PayableCheck, 4));
+        assert!(config.is_active(ProtocolFeature::PayableCheck, 5));
+        assert!(!config.is_active(ProtocolFeature::EsdtNftAddUri, 5));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::types::VMAddress;
+
+/// Shard id of the metachain, as returned by the protocol.
+pub const METACHAIN_SHARD_ID: u32 = u32::MAX;
+
+/// Metachain smart contracts have these bytes zeroed, right after the VM type.
+const METACHAIN_SC_ZERO_BYTES_RANGE: core::ops::Range<usize> = 10..15;
+
+/// Describes how the mocked blockchain is split into shards.
+///
+/// Addresses are mapped to shards the same way the protocol does:
+/// the last bytes of the address are masked with the smallest power of two
+/// that covers the number of shards, falling back to a narrower mask if the result is out of range.
+/// System smart contracts are always on the metachain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShardModel {
+    pub num_shards: u32,
+}
+
+impl Default for ShardModel {
+    fn default() -> Self {
+        ShardModel { num_shards: 3 }
+    }
+}
+
+impl ShardModel {
+    pub fn new(num_shards: u32) -> Self {
+        assert!(num_shards > 0, "the number of shards must be positive");
+        ShardModel { num_shards }
+    }
+
+    pub fn compute_shard_id(&self, address: &VMAddress) -> u32 {
+        if is_smart_contract_on_metachain(address) {
+            return METACHAIN_SHARD_ID;
+        }
+
+        let num_bits = u32::BITS - (self.num_shards - 1).leading_zeros();
+        let mask_high = (1u32 << num_bits) - 1;
+        let mask_low = mask_high >> 1;
+
+        let bytes = address.as_bytes();
+        let num_bytes_needed = (num_bits / 8 + 1) as usize;
+        let last_bytes = bytes[bytes.len() - num_bytes_needed..]
+            .iter()
+            .fold(0u32, |acc, byte| (acc << 8) | *byte as u32);
+
+        let shard = last_bytes & mask_high;
+        if shard > self.num_shards - 1 {
+            last_bytes & mask_low
+        } else {
+            shard
+        }
+    }
+
+    pub fn same_shard(&self, address_1: &VMAddress, address_2: &VMAddress) -> bool {
+        self.compute_shard_id(address_1) == self.compute_shard_id(address_2)
+    }
+}
+
+fn is_smart_contract_on_metachain(address: &VMAddress) -> bool {
+    address.is_smart_contract_address()
+        && address.as_bytes()[METACHAIN_SC_ZERO_BYTES_RANGE]
+            .iter()
+            .all(|byte| *byte == 0)
+}